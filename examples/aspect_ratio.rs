@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use ui::{
+    components::{heading::Heading, text::Text, AspectRatio},
+    plugin::{ForgeUiPlugin, UiState},
+    theme::color::{accent_palette, theme},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(ui_root("AspectRatio Root"))
+        .with_children(|parent| {
+            parent.spawn(Heading::h1("AspectRatio Component Examples").build());
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(24.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (label, builder) in [
+                        ("16:9", AspectRatio::new(1.0).ratio_16_9()),
+                        ("4:3", AspectRatio::new(1.0).ratio_4_3()),
+                        ("1:1", AspectRatio::new(1.0).ratio_1_1()),
+                    ] {
+                        parent
+                            .spawn(Node {
+                                width: Val::Px(240.0),
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(8.0),
+                                ..default()
+                            })
+                            .with_children(|parent| {
+                                parent.spawn(Text::label(label).build());
+                                parent
+                                    .spawn(builder.name(format!("Ratio {}", label)).build())
+                                    .with_children(|parent| {
+                                        // Stretched to the container's size automatically
+                                        parent.spawn((
+                                            Node::default(),
+                                            BackgroundColor(accent_palette().bg_active),
+                                            BorderColor(theme().gray.border),
+                                        ));
+                                    });
+                            });
+                    }
+                });
+        });
+}
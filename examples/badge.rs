@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use ui::{
-    components::{BadgeBuilder, FlexComponent, Heading},
+    components::{Badge, BadgeBuilder, FlexComponent, Heading},
     plugin::{ForgeUiPlugin, UiState},
     theme::{
         color::{accent_palette, error_palette, success_palette, theme, warning_palette},
@@ -239,6 +239,30 @@ fn setup(mut commands: Commands) {
     );
     commands.entity(main_entity).add_child(status_section);
 
+    // Count and Dot Section
+    let count_section = create_section(
+        &mut commands,
+        "Counts and Dots",
+        vec![
+            Badge::count(3).color(error_palette()).build(),
+            Badge::count(42).color(error_palette()).build(),
+            Badge::count(150).color(error_palette()).build(),
+            Badge::count(12).max_count(9).size_1().build(),
+        ],
+    );
+    commands.entity(main_entity).add_child(count_section);
+
+    let dot_section = create_section(
+        &mut commands,
+        "Status Dots",
+        vec![
+            Badge::dot().color(success_palette()).build(),
+            Badge::dot().color(warning_palette()).build(),
+            Badge::dot().color(error_palette()).build(),
+        ],
+    );
+    commands.entity(main_entity).add_child(dot_section);
+
     // Setup UI Root
     commands
         .spawn(ui_root("badge_demo_root"))
@@ -1,13 +1,19 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
 use ui::{
+    assets::{ChevronRight, Save, Settings, X},
     components::{
-        text::Text, ButtonBuilder, ButtonClickEvent, ButtonSize, ButtonVariant, Heading, HeadingExt,
+        button::Button, set_disabled, text::Text, ButtonBuilder, ButtonClickEvent, ButtonSize,
+        ButtonVariant, Heading, IconPosition,
     },
     plugin::{ForgeUiPlugin, UiState},
     theme::{
-        color::{error_palette, theme, TextColor as TextColorEnum},
+        color::{error_palette, theme, TextColor as TextColorEnum, UiColorPalettesName},
+        radix_theme::RadixTheme,
         typography::{FontFamily, TextWeight},
     },
+    utilities::transition::Easing,
 };
 fn main() {
     App::new()
@@ -15,25 +21,93 @@ fn main() {
         .add_plugins(ForgeUiPlugin)
         // Set theme mode at startup - change this to ThemeMode::Dark for dark theme
         .add_systems(OnEnter(UiState::Ready), setup)
-        .add_systems(Update, handle_button_events)
+        .add_systems(
+            Update,
+            (
+                handle_button_events,
+                cycle_accent_color,
+                toggle_loading,
+                toggle_disabled,
+            ),
+        )
         .run();
 }
 
+/// Press `A` to cycle the accent color, `B` to toggle a generated brand accent;
+/// accent buttons update live.
+fn cycle_accent_color(keyboard: Res<ButtonInput<KeyCode>>, mut radix_theme: ResMut<RadixTheme>) {
+    const ACCENTS: [UiColorPalettesName; 4] = [
+        UiColorPalettesName::Indigo,
+        UiColorPalettesName::Crimson,
+        UiColorPalettesName::Teal,
+        UiColorPalettesName::Amber,
+    ];
+
+    if keyboard.just_pressed(KeyCode::KeyA) {
+        let current = ACCENTS
+            .iter()
+            .position(|accent| *accent == radix_theme.accent_color)
+            .unwrap_or(0);
+        radix_theme.accent_color = ACCENTS[(current + 1) % ACCENTS.len()];
+    }
+    if keyboard.just_pressed(KeyCode::KeyB) {
+        radix_theme.custom_accent = match radix_theme.custom_accent {
+            Some(_) => None,
+            None => Some(Color::srgb_u8(0xE0, 0x5A, 0x2B)),
+        };
+    }
+}
+
+/// Marks the buttons whose loading state is toggled with `L`.
+#[derive(Component)]
+struct LoadingDemo;
+
+/// Press `L` to toggle loading; the original labels come back afterwards.
+fn toggle_loading(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut buttons: Query<&mut Button, With<LoadingDemo>>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyL) {
+        for mut button in &mut buttons {
+            button.loading = !button.loading;
+        }
+    }
+}
+
+/// Press `D` to disable or re-enable the loading demo buttons; clicks on
+/// disabled buttons fall through and emit no events.
+fn toggle_disabled(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    buttons: Query<(Entity, &Button), With<LoadingDemo>>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyD) {
+        for (entity, button) in &buttons {
+            set_disabled(&mut commands, entity, !button.disabled);
+        }
+    }
+}
+
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
 
     // Demo verschiedener Button-Features using new theme system and TextBuilder integration
+    // Runs a one-shot system on click, next to the ButtonClickEvent
+    let greet = commands.register_system(greet_on_click);
     let solid_button = ButtonBuilder::new("Solid")
         .variant(ButtonVariant::Solid)
         .text("Primary Solid")
         .text_weight(TextWeight::Bold)
         .text_accessible()
+        .on_click(greet)
         .build();
 
+    // Fades between its hover and pressed colors
     let secondary_button = ButtonBuilder::new("Secondary")
         .variant(ButtonVariant::Soft)
         .text("Secondary Soft")
         .text_accessible()
+        .transition(Duration::from_millis(150), Easing::EaseOut)
         .build();
 
     let disabled_button = ButtonBuilder::new("Disabled")
@@ -67,6 +141,17 @@ fn setup(mut commands: Commands) {
         .text_accessible()
         .build();
 
+    // Loading presentations, toggled with `L`
+    let saving_button = ButtonBuilder::new("Saving")
+        .text("Save")
+        .loading_text("Saving…")
+        .build();
+    let spinner_only_button = ButtonBuilder::new("SpinnerOnly")
+        .variant(ButtonVariant::Outline)
+        .text("Upload")
+        .loading_replaces_content()
+        .build();
+
     // Destructive button example with enhanced text styling
     let destructive_button = ButtonBuilder::new("Destructive")
         .variant(ButtonVariant::Solid)
@@ -91,6 +176,34 @@ fn setup(mut commands: Commands) {
         .size(ButtonSize::Small) // Button size small but text should be X4l
         .build();
 
+    // Icon buttons: leading / trailing icon and square icon-only buttons
+    let icon_buttons = [
+        ButtonBuilder::new("IconSave")
+            .text("Save")
+            .icon(FontFamily::InterfaceFont, Save)
+            .build(),
+        ButtonBuilder::new("IconNext")
+            .variant(ButtonVariant::Soft)
+            .text("Next")
+            .icon(FontFamily::InterfaceFont, ChevronRight)
+            .icon_position(IconPosition::Trailing)
+            .build(),
+    ];
+    let icon_only_buttons =
+        [ButtonSize::Small, ButtonSize::Default, ButtonSize::Large].map(|size| {
+            ButtonBuilder::new("IconSettings")
+                .variant(ButtonVariant::Outline)
+                .size(size)
+                .icon_only(FontFamily::InterfaceFont, Settings)
+                .accessible_name("Settings")
+                .build()
+        });
+    let close_button = ButtonBuilder::new("IconClose")
+        .variant(ButtonVariant::Ghost)
+        .icon_only(FontFamily::InterfaceFont, X)
+        .accessible_name("Close")
+        .build();
+
     // Container für die Buttons with themed background
     commands
         .spawn((Node {
@@ -113,8 +226,8 @@ fn setup(mut commands: Commands) {
                 .with_children(|title_parent| {
                     title_parent.spawn(
                         Heading::h1("Button TextBuilder Demo")
-                            .heading_accessible()
-                            .heading_center()
+                            .accessible()
+                            .center()
                             .build(),
                     );
                 });
@@ -176,11 +289,47 @@ fn setup(mut commands: Commands) {
                     row.spawn(size_test_button);
                 });
 
+            // Button Grid - Icons
+            parent
+                .spawn((Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(12.0),
+                    margin: UiRect::bottom(Val::Px(16.0)),
+                    ..default()
+                },))
+                .with_children(|row| {
+                    for button in icon_buttons {
+                        row.spawn(button);
+                    }
+                    for button in icon_only_buttons {
+                        row.spawn(button);
+                    }
+                    row.spawn(close_button);
+                });
+
+            // Button Grid - Loading (press L) and disabling (press D)
+            parent
+                .spawn((Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(12.0),
+                    margin: UiRect::bottom(Val::Px(16.0)),
+                    ..default()
+                },))
+                .with_children(|row| {
+                    row.spawn((saving_button, LoadingDemo));
+                    row.spawn((spinner_only_button, LoadingDemo));
+                });
+
             // Destructive action
             parent.spawn(destructive_button);
         });
 }
 
+fn greet_on_click() {
+    info!("Primary button callback ran");
+}
+
 fn handle_button_events(mut events: EventReader<ButtonClickEvent>) {
     for event in events.read() {
         info!(
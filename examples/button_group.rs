@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use ui::{
+    components::{
+        heading::Heading, text::Text, ButtonBuilder, ButtonClickEvent, ButtonGroupBuilder,
+        ButtonVariant,
+    },
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_clicks)
+        .run();
+}
+
+/// Spawns one outline button per label.
+fn spawn_buttons<const N: usize>(commands: &mut Commands, labels: [&str; N]) -> [Entity; N] {
+    labels.map(|label| {
+        let button = ButtonBuilder::new(label)
+            .variant(ButtonVariant::Outline)
+            .text(label)
+            .build();
+        commands.spawn(button).id()
+    })
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("ButtonGroup Root")).id();
+    let title = commands
+        .spawn(Heading::h1("ButtonGroup Component Examples").build())
+        .id();
+    commands.entity(root).add_child(title);
+
+    // Attached: one toolbar with shared inner borders
+    let label = commands.spawn(Text::label("Attached").build()).id();
+    let buttons = spawn_buttons(&mut commands, ["Bold", "Italic", "Underline"]);
+    let toolbar = ButtonGroupBuilder::new("Formatting")
+        .buttons(buttons)
+        .build(&mut commands);
+    commands.entity(root).add_children(&[label, toolbar]);
+
+    // Spaced: the same layout with separate buttons
+    let label = commands.spawn(Text::label("Spaced").build()).id();
+    let buttons = spawn_buttons(&mut commands, ["Cut", "Copy", "Paste"]);
+    let spaced = ButtonGroupBuilder::new("Clipboard")
+        .buttons(buttons)
+        .spaced()
+        .build(&mut commands);
+    commands.entity(root).add_children(&[label, spaced]);
+
+    // Vertical
+    let label = commands.spawn(Text::label("Vertical").build()).id();
+    let buttons = spawn_buttons(&mut commands, ["Top", "Middle", "Bottom"]);
+    let vertical = ButtonGroupBuilder::new("Position")
+        .buttons(buttons)
+        .vertical()
+        .build(&mut commands);
+    commands.entity(root).add_children(&[label, vertical]);
+}
+
+/// Grouped buttons still emit their own click events.
+fn log_clicks(mut events: EventReader<ButtonClickEvent>) {
+    for event in events.read() {
+        info!("Clicked: {:?}", event.button_entity);
+    }
+}
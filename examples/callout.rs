@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use ui::{
+    assets::Lightbulb,
+    components::{heading::Heading, Callout, CalloutDismissEvent, CalloutSeverity},
+    plugin::{ForgeUiPlugin, UiState},
+    theme::color::theme,
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_dismissals)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("Callout Root")).id();
+    let title = commands
+        .spawn(Heading::h1("Callout Component Examples").build())
+        .id();
+
+    let column = commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(12.0),
+            width: Val::Px(480.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(
+                Callout::new("Info")
+                    .title("Heads up")
+                    .description("A new version is available.")
+                    .build(),
+            );
+            parent.spawn(
+                Callout::new("Success")
+                    .severity(CalloutSeverity::Success)
+                    .description("Your changes have been saved.")
+                    .dismissible()
+                    .build(),
+            );
+            parent.spawn(
+                Callout::new("Warning")
+                    .severity(CalloutSeverity::Warning)
+                    .outline()
+                    .title("Low disk space")
+                    .description(
+                        "Only a few hundred megabytes are left on this drive. Long \
+                         descriptions wrap onto several lines while the icon stays \
+                         aligned with the top of the callout.",
+                    )
+                    .dismissible()
+                    .build(),
+            );
+            parent.spawn(
+                Callout::new("Error")
+                    .severity(CalloutSeverity::Error)
+                    .title("Connection lost")
+                    .description("Reconnecting…")
+                    .build(),
+            );
+            parent.spawn(
+                Callout::new("Tip")
+                    .icon(Lightbulb)
+                    .color(theme().amber)
+                    .description("Custom icon and palette.")
+                    .build(),
+            );
+        })
+        .id();
+
+    commands.entity(root).add_children(&[title, column]);
+}
+
+fn log_dismissals(mut events: EventReader<CalloutDismissEvent>) {
+    for event in events.read() {
+        info!("Callout {:?} dismissed", event.callout_entity);
+    }
+}
@@ -9,6 +9,8 @@ use ui::{
     plugin::{ForgeUiPlugin, UiState},
     theme::{
         color::theme,
+        layout::Elevation,
+        radix_theme::{PanelBackground, RadixTheme},
         typography::{TextSize, TextWeight},
     },
     utilities::ui_root,
@@ -19,9 +21,23 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_plugins(ForgeUiPlugin)
         .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, toggle_panel_background)
         .run();
 }
 
+/// Press `P` to switch between solid and translucent panels.
+fn toggle_panel_background(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut radix_theme: ResMut<RadixTheme>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyP) {
+        radix_theme.panel_background = match radix_theme.panel_background {
+            PanelBackground::Solid => PanelBackground::Translucent,
+            PanelBackground::Translucent => PanelBackground::Solid,
+        };
+    }
+}
+
 fn setup(mut commands: Commands) {
     // Camera
     commands.spawn(Camera2d);
@@ -139,6 +155,38 @@ fn setup(mut commands: Commands) {
                     });
                 });
 
+                // Top accent stripe
+                parent.spawn(
+                    CardBuilder::new("AccentCard")
+                        .size_2()
+                        .color_palette(theme().blue)
+                        .border_top(4.0)
+                        .width(Val::Px(240.0))
+                        .build()
+                ).with_children(|parent| {
+                    parent.spawn(
+                        Heading::h3("Accent Card")
+                            .size(TextSize::Xl)
+                            .weight(TextWeight::Bold)
+                            .build()
+                    );
+                });
+
+                // Header, body and footer slots
+                let open = parent
+                    .commands()
+                    .spawn(ButtonBuilder::new("Open").variant(ButtonVariant::Soft).build())
+                    .id();
+                parent.spawn(
+                    CardBuilder::new("SlotCard")
+                        .size_2()
+                        .width(Val::Px(240.0))
+                        .header("Slotted Card")
+                        .body("Header, body and footer are laid out and padded by the card.")
+                        .footer(open)
+                        .build()
+                );
+
                 // Size Demonstration
                 parent.spawn((
                     Node {
@@ -196,6 +244,38 @@ fn setup(mut commands: Commands) {
                         );
                     });
                 });
+
+                // Elevation Demonstration
+                parent.spawn((
+                    Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(16.0),
+                        ..default()
+                    },
+                )).with_children(|parent| {
+                    for (label, elevation) in [
+                        ("Elevation Sm", Elevation::Sm),
+                        ("Elevation Md", Elevation::Md),
+                        ("Elevation Lg", Elevation::Lg),
+                        ("Elevation Xl", Elevation::Xl),
+                    ] {
+                        parent.spawn(
+                            CardBuilder::new(label)
+                                .surface()
+                                .elevation(elevation)
+                                .width(Val::Px(200.0))
+                                .build()
+                        ).with_children(|parent| {
+                            parent.spawn(
+                                TextBuilder::new(label)
+                                    .size(TextSize::Sm)
+                                    .weight(TextWeight::Medium)
+                                    .build()
+                            );
+                        });
+                    }
+                });
             });
         });
     });
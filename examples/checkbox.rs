@@ -3,6 +3,7 @@ use ui::{
     components::{
         checkbox::{Checkbox, CheckboxChangeEvent},
         text::Text,
+        validation::{show_validation_errors, ValidationChangedEvent},
         BoxComponent, FlexComponent,
     },
     plugin::{ForgeUiPlugin, UiState},
@@ -15,7 +16,10 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_plugins(ForgeUiPlugin)
         .add_systems(OnEnter(UiState::Ready), setup)
-        .add_systems(Update, handle_checkbox_events)
+        .add_systems(
+            Update,
+            (handle_checkbox_events, submit_form, log_validation_changes),
+        )
         .run();
 }
 
@@ -190,10 +194,19 @@ fn setup(mut commands: Commands) {
                                 .build(),
                         )
                         .with_children(|row| {
-                            row.spawn(Checkbox::new(id).build());
-                            row.spawn(Text::body(label).build());
+                            // Clicking the label toggles the checkbox
+                            row.spawn(Checkbox::new(id).label(label).build());
                         });
                     }
+
+                    // Required: shows a red border after submitting unchecked
+                    form.spawn(
+                        Checkbox::new("terms")
+                            .label("I accept the terms (required, Enter submits)")
+                            .required()
+                            .margin_y(Val::Px(8.0))
+                            .build(),
+                    );
                 });
 
             // Section spacing
@@ -246,3 +259,16 @@ fn handle_checkbox_events(mut checkbox_events: EventReader<CheckboxChangeEvent>)
         );
     }
 }
+
+/// Shows the errors of required checkboxes on Enter, like a form submit.
+fn submit_form(mut commands: Commands, keyboard: Res<ButtonInput<KeyCode>>) {
+    if keyboard.just_pressed(KeyCode::Enter) {
+        commands.queue(show_validation_errors());
+    }
+}
+
+fn log_validation_changes(mut events: EventReader<ValidationChangedEvent>) {
+    for event in events.read() {
+        println!("Checkbox {:?} valid: {}", event.entity, event.valid);
+    }
+}
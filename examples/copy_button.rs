@@ -0,0 +1,62 @@
+//! Run with `--features clipboard` to actually write to the clipboard.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use ui::{
+    components::{heading::Heading, text::Text, CopyButton, CopyEvent},
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_copies)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(ui_root("Copy Button Root"))
+        .with_children(|parent| {
+            parent.spawn(Heading::h1("Copy Button Examples").build());
+
+            for (snippet, button) in [
+                ("cargo add ui", CopyButton::new("cargo add ui")),
+                (
+                    "sk-live-0123456789",
+                    CopyButton::new("sk-live-0123456789").icon_only(),
+                ),
+                (
+                    "#3e63dd",
+                    CopyButton::new("#3e63dd")
+                        .label("Copy color")
+                        .copied_label("Done")
+                        .feedback_duration(Duration::from_millis(800)),
+                ),
+            ] {
+                parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(16.0),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn(Text::code(snippet).build());
+                        row.spawn(button.build());
+                    });
+            }
+        });
+}
+
+fn log_copies(mut events: EventReader<CopyEvent>) {
+    for event in events.read() {
+        info!("Copied {:?}: {}", event.text, event.success);
+    }
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use ui::{
+    assets::SearchX,
+    components::{button::ButtonBuilder, EmptyState},
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("EmptyState Root")).id();
+    let clear = commands
+        .spawn(ButtonBuilder::new("Clear filters").build())
+        .id();
+    let empty_state = commands
+        .spawn(
+            EmptyState::new("NoResults")
+                .icon(SearchX)
+                .title("No results")
+                .description(
+                    "Nothing matches your search. Try a different term or clear the filters \
+                     to see all items again.",
+                )
+                .action(clear)
+                .build(),
+        )
+        .id();
+
+    commands.entity(root).add_child(empty_state);
+}
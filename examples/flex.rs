@@ -174,5 +174,32 @@ fn setup(mut commands: Commands) {
                         item.spawn(Text::body("Fixed 2").build());
                     });
             });
+
+        // Demo 7: Settings list with hoverable rows
+        parent
+            .spawn(
+                FlexComponent::column("Settings List")
+                    .padding(Val::Px(8.0))
+                    .radius(Val::Px(8.0))
+                    .margin_y(Val::Px(10.0))
+                    .width(Val::Px(300.0))
+                    .build(),
+            )
+            .with_children(|parent| {
+                for setting in ["Account", "Notifications", "Privacy"] {
+                    parent
+                        .spawn(
+                            FlexComponent::row(setting)
+                                .justify_between()
+                                .padding(Val::Px(8.0))
+                                .hoverable_row()
+                                .build(),
+                        )
+                        .with_children(|row| {
+                            row.spawn(Text::body(setting).build());
+                            row.spawn(Text::body(">").build());
+                        });
+                }
+            });
     });
 }
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+use ui::{
+    components::{
+        heading::Heading, text::Text, ButtonBuilder, Checkbox, Focusable, NumberInput, Switch,
+        TextField, UiFocus,
+    },
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_focus)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("Focus Root")).id();
+    let title = commands.spawn(Heading::h1("Keyboard Focus").build()).id();
+    let hint = commands
+        .spawn(
+            Text::body(
+                "Press Tab / Shift+Tab or a gamepad's D-pad to move the focus ring, \
+                 South to activate. Clicking focuses without it.",
+            )
+            .build(),
+        )
+        .id();
+
+    let column = commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::FlexStart,
+            row_gap: Val::Px(12.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(TextField::new("Name").placeholder("Your name").build());
+            parent.spawn(NumberInput::new("Amount").max(10.0).build());
+            parent.spawn(Checkbox::new("Terms").build());
+            parent.spawn(Switch::new("Notifications").build());
+            parent.spawn(ButtonBuilder::new("Cancel").text("Cancel").build());
+            parent.spawn(
+                ButtonBuilder::new("Disabled")
+                    .text("Skipped")
+                    .disabled()
+                    .build(),
+            );
+            // An explicit tab index puts this button first in the tab order
+            parent
+                .spawn(
+                    ButtonBuilder::new("Submit")
+                        .text("Submit (tab index 1)")
+                        .build(),
+                )
+                .insert(Focusable::new().tab_index(1));
+        })
+        .id();
+
+    commands.entity(root).add_children(&[title, hint, column]);
+}
+
+fn log_focus(focus: Res<UiFocus>, names: Query<&Name>) {
+    if !focus.is_changed() {
+        return;
+    }
+    if let Some(name) = focus.focused.and_then(|entity| names.get(entity).ok()) {
+        info!("Focused {name} (ring visible: {})", focus.focus_visible);
+    }
+}
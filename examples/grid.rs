@@ -19,7 +19,7 @@ fn setup_ui(mut commands: Commands) {
     commands.spawn(Camera2d);
 
     // Create UI root
-    commands
+    let root = commands
         .spawn(ui_root("Grid Example"))
         .with_children(|parent| {
             // Title
@@ -118,5 +118,106 @@ fn setup_ui(mut commands: Commands) {
                         }
                     }
                 });
-        });
+
+            // Demo 4: Column-major auto placement
+            parent
+                .spawn(
+                    GridComponent::layout("column-flow-grid", 3, 2)
+                        .auto_flow(GridAutoFlow::Column)
+                        .gap(8.0)
+                        .height(Val::Px(160.0))
+                        .padding(Val::Px(20.0))
+                        .background_color(Color::srgba(0.3, 0.4, 0.2, 0.5))
+                        .radius(Val::Px(8.0))
+                        .margin_y(Val::Px(10.0))
+                        .build(),
+                )
+                .with_children(|parent| {
+                    // Fills top to bottom, then left to right
+                    for i in 1..=6 {
+                        parent
+                            .spawn(
+                                BoxComponent::new(&format!("column-flow-item-{}", i))
+                                    .panel()
+                                    .padding(Val::Px(8.0))
+                                    .build(),
+                            )
+                            .with_children(|item| {
+                                item.spawn(Text::body(&format!("Item {}", i)).build());
+                            });
+                    }
+                });
+
+            // Demo 5: As many columns of at least 160px as fit; resize the window
+            parent
+                .spawn(
+                    GridComponent::new("auto-fit-grid")
+                        .columns_auto_fit(160.0, GridTrackSize::Fr(1.0))
+                        .gap(8.0)
+                        .padding(Val::Px(20.0))
+                        .background_color(Color::srgba(0.4, 0.3, 0.2, 0.5))
+                        .radius(Val::Px(8.0))
+                        .margin_y(Val::Px(10.0))
+                        .build(),
+                )
+                .with_children(|parent| {
+                    for i in 1..=5 {
+                        parent
+                            .spawn(
+                                BoxComponent::new(&format!("auto-fit-item-{}", i))
+                                    .panel()
+                                    .height(Val::Px(60.0))
+                                    .padding(Val::Px(8.0))
+                                    .build(),
+                            )
+                            .with_children(|item| {
+                                item.spawn(Text::body(&format!("Item {}", i)).build());
+                            });
+                    }
+                });
+        })
+        .id();
+
+    // Demo 6: Dashboard placed by named areas
+    let mut area_panel = |name: &str| {
+        commands
+            .spawn(
+                BoxComponent::new(&format!("area-{}", name))
+                    .panel()
+                    .padding(Val::Px(8.0))
+                    .build(),
+            )
+            .with_children(|item| {
+                item.spawn(Text::body(name).build());
+            })
+            .id()
+    };
+    let header = area_panel("header");
+    let sidebar = area_panel("sidebar");
+    let main = area_panel("main");
+    let footer = area_panel("footer");
+
+    let dashboard = commands
+        .spawn(
+            GridComponent::new("dashboard-grid")
+                .columns_sizes(vec![GridTrackSize::Px(160.0), GridTrackSize::Fr(1.0)])
+                .rows_sizes(vec![
+                    GridTrackSize::Px(40.0),
+                    GridTrackSize::Fr(1.0),
+                    GridTrackSize::Px(40.0),
+                ])
+                .template_areas(&["header header", "sidebar main", "sidebar footer"])
+                .with_child_area(header, "header")
+                .with_child_area(sidebar, "sidebar")
+                .with_child_area(main, "main")
+                .with_child_area(footer, "footer")
+                .gap(8.0)
+                .height(Val::Px(240.0))
+                .pad(20.0)
+                .background_color(Color::srgba(0.2, 0.4, 0.3, 0.5))
+                .margin_y(Val::Px(10.0))
+                .build(),
+        )
+        .id();
+    commands.entity(root).add_child(dashboard);
 }
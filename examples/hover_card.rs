@@ -28,6 +28,7 @@ fn setup_hover_card_demo(mut commands: Commands) {
 
     // Create hover card root
     let hover_card = commands.spawn(HoverCardBuilder::new("demo").build()).id();
+    let cursor_card = commands.spawn(HoverCardBuilder::new("cursor").build()).id();
 
     // Create a container for the demo
     commands
@@ -72,5 +73,22 @@ fn setup_hover_card_demo(mut commands: Commands) {
                         .build(),
                 );
             });
+
+            // Hover card following the cursor, e.g. for map or canvas tooltips
+            parent.spawn(
+                HoverCardTriggerBuilder::new("cursor_trigger", cursor_card)
+                    .text("Move the cursor over me")
+                    .build(),
+            );
+            parent
+                .spawn(
+                    HoverCardContentBuilder::new("cursor_content", cursor_card)
+                        .follow_cursor()
+                        .theme(accent_palette())
+                        .build(),
+                )
+                .with_children(|content_parent| {
+                    content_parent.spawn(Text::body("I follow the cursor").build());
+                });
         });
 }
@@ -7,6 +7,7 @@ use ui::{
                 generic, generic::Generic, keyboard_mouse, keyboard_mouse::KeyboardMouse,
                 playstation, playstation::Playstation, xbox::Xbox,
             },
+            gamepad::{ControllerButton, ControllerIcon},
             interface::{AArrowDown, Anchor},
         },
         Interface,
@@ -212,6 +213,13 @@ fn create_controller_section(parent: &mut ChildSpawnerCommands) {
                         parent.spawn(Generic::new(generic::Joystick).build());
                     });
 
+                    // Follows the connected gamepad
+                    create_controller_card(parent, "Connected Gamepad", |parent| {
+                        parent.spawn(ControllerIcon::button(ControllerButton::South));
+                        parent.spawn(ControllerIcon::button(ControllerButton::East));
+                        parent.spawn(ControllerIcon::button(ControllerButton::Start));
+                    });
+
                     create_controller_card(parent, "Keyboard & Mouse", |parent| {
                         parent.spawn(KeyboardMouse::new(keyboard_mouse::KeyboardW).build());
                         parent.spawn(KeyboardMouse::new(keyboard_mouse::Mouse).build());
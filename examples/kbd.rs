@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use ui::{
+    components::{heading::Heading, text::Text, Kbd, KbdSize},
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(ui_root("Kbd Root"))
+        .with_children(|parent| {
+            parent.spawn(Heading::h1("Kbd Component Examples").build());
+
+            for (label, kbd) in [
+                ("Single key", Kbd::new("Esc")),
+                ("Combination", Kbd::combo(&["Ctrl", "K"])),
+                ("Three keys", Kbd::combo(&["Ctrl", "Shift", "P"])),
+                ("Small", Kbd::combo(&["Alt", "F4"]).size(KbdSize::Size1)),
+                ("Large", Kbd::combo(&["Cmd", "Space"]).size(KbdSize::Size3)),
+                ("Custom separator", Kbd::combo(&["G", "G"]).separator("then")),
+                ("Icon glyphs", Kbd::combo(&["Up", "Up", "Down", "Down"]).separator("").glyphs()),
+            ] {
+                parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(16.0),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn(Text::label(label).build());
+                        row.spawn(kbd.build());
+                    });
+            }
+        });
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use ui::{
+    components::{heading::Heading, text::Text, Link, LinkActivateEvent},
+    plugin::{ForgeUiPlugin, UiState},
+    theme::typography::{TextSize, TextWeight},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_link_activations)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(ui_root("Link Root"))
+        .with_children(|parent| {
+            parent.spawn(Heading::h1("Link Examples").build());
+
+            for (label, link) in [
+                ("Internal", Link::new("Settings").href("app://settings")),
+                (
+                    "External",
+                    Link::new("Bevy website")
+                        .href("https://bevyengine.org")
+                        .external(),
+                ),
+                (
+                    "Large, bold",
+                    Link::new("Documentation")
+                        .href("https://docs.rs/bevy")
+                        .size(TextSize::Lg)
+                        .weight(TextWeight::Bold)
+                        .external(),
+                ),
+            ] {
+                parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(16.0),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn(Text::label(label).build());
+                        row.spawn(link.build());
+                    });
+            }
+
+            parent.spawn(
+                Text::new("Activated links turn muted. Tab to a link and press Enter to open it.")
+                    .build(),
+            );
+        });
+}
+
+fn log_link_activations(mut events: EventReader<LinkActivateEvent>) {
+    for event in events.read() {
+        info!("Open {}", event.href);
+    }
+}
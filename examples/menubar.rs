@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+use ui::{
+    components::{heading::Heading, text::Text, MenubarBuilder, MenubarItem, MenubarSelectEvent},
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_selections)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("Menubar Root")).id();
+    let title = commands
+        .spawn(Heading::h1("Menubar Component Example").build())
+        .id();
+    let hint = commands
+        .spawn(
+            Text::body(
+                "Press Alt to focus the menubar, Left / Right to switch menus, \
+                 Down to open one and Enter to select.",
+            )
+            .build(),
+        )
+        .id();
+
+    let menubar = MenubarBuilder::new()
+        .menu(
+            "File",
+            vec![
+                MenubarItem::new("new", "New File").shortcut("Ctrl+N"),
+                MenubarItem::new("open", "Open…").shortcut("Ctrl+O"),
+                MenubarItem::new("save", "Save").shortcut("Ctrl+S"),
+                MenubarItem::separator(),
+                MenubarItem::new("quit", "Quit").shortcut("Ctrl+Q"),
+            ],
+        )
+        .menu(
+            "Edit",
+            vec![
+                MenubarItem::new("undo", "Undo").shortcut("Ctrl+Z"),
+                MenubarItem::new("redo", "Redo")
+                    .shortcut("Ctrl+Shift+Z")
+                    .disabled(true),
+                MenubarItem::separator(),
+                MenubarItem::new("cut", "Cut").shortcut("Ctrl+X"),
+                MenubarItem::new("copy", "Copy").shortcut("Ctrl+C"),
+                MenubarItem::new("paste", "Paste").shortcut("Ctrl+V"),
+            ],
+        )
+        .menu(
+            "View",
+            vec![
+                MenubarItem::new("zoom_in", "Zoom In"),
+                MenubarItem::new("zoom_out", "Zoom Out"),
+                MenubarItem::new("fullscreen", "Toggle Fullscreen").shortcut("F11"),
+            ],
+        )
+        .menu("Help", vec![MenubarItem::new("about", "About")])
+        .build(&mut commands);
+
+    commands.entity(root).add_children(&[title, hint, menubar]);
+}
+
+fn log_selections(mut select_events: EventReader<MenubarSelectEvent>) {
+    for event in select_events.read() {
+        info!("Selected {} from menu {}", event.value, event.menu);
+    }
+}
@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use ui::{
+    components::{
+        heading::Heading, text::Text, ButtonSize, NumberInput, NumberInputBuilder,
+        NumberInputChangeEvent,
+    },
+    plugin::{ForgeUiPlugin, UiState},
+    theme::color::theme,
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_changes)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("NumberInput Root")).id();
+    let title = commands
+        .spawn(Heading::h1("NumberInput Component Examples").build())
+        .id();
+
+    let column = commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(16.0),
+            ..default()
+        })
+        .id();
+
+    let quantity = labeled(
+        &mut commands,
+        "Quantity (0 – 10)",
+        NumberInput::new("Quantity").min(0.0).max(10.0).value(1.0),
+    );
+    let price = labeled(
+        &mut commands,
+        "Price: step 0.25, two decimals, mouse wheel",
+        NumberInput::new("Price")
+            .min(0.0)
+            .step(0.25)
+            .precision(2)
+            .value(9.99)
+            .wheel_step(),
+    );
+    let large = labeled(
+        &mut commands,
+        "Large, gray",
+        NumberInput::new("Large")
+            .size(ButtonSize::Large)
+            .color(theme().gray)
+            .value(42.0),
+    );
+    let disabled = labeled(
+        &mut commands,
+        "Disabled",
+        NumberInput::new("Disabled").value(5.0).disabled(),
+    );
+
+    commands
+        .entity(column)
+        .add_children(&[quantity, price, large, disabled]);
+    commands.entity(root).add_children(&[title, column]);
+}
+
+fn labeled(commands: &mut Commands, label: &str, input: NumberInputBuilder) -> Entity {
+    commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(Text::label(label).build());
+            parent.spawn(input.build());
+        })
+        .id()
+}
+
+fn log_changes(mut events: EventReader<NumberInputChangeEvent>) {
+    for event in events.read() {
+        info!(
+            "NumberInput {:?} changed to {}",
+            event.number_input_entity, event.value
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use ui::{
+    components::{
+        heading::Heading, text::Text, ButtonSize, PageChangeEvent, Pagination, PaginationBuilder,
+    },
+    plugin::{ForgeUiPlugin, UiState},
+    theme::color::theme,
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_page_changes)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("Pagination Root")).id();
+    let title = commands
+        .spawn(Heading::h1("Pagination Component Examples").build())
+        .id();
+
+    let column = commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(16.0),
+            ..default()
+        })
+        .id();
+
+    let examples = [
+        labeled(
+            &mut commands,
+            "Five pages",
+            Pagination::new("Few").total_pages(5),
+        ),
+        labeled(
+            &mut commands,
+            "Twenty pages, starting on page 10",
+            Pagination::new("Many").total_pages(20).current(10),
+        ),
+        labeled(
+            &mut commands,
+            "Two siblings, small, gray",
+            Pagination::new("Siblings")
+                .total_pages(50)
+                .current(25)
+                .sibling_count(2)
+                .size(ButtonSize::Small)
+                .color(theme().gray),
+        ),
+    ];
+
+    commands.entity(column).add_children(&examples);
+    commands.entity(root).add_children(&[title, column]);
+}
+
+fn labeled(commands: &mut Commands, label: &str, pagination: PaginationBuilder) -> Entity {
+    commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(Text::label(label).build());
+            parent.spawn(pagination.build());
+        })
+        .id()
+}
+
+fn log_page_changes(mut events: EventReader<PageChangeEvent>) {
+    for event in events.read() {
+        info!(
+            "Pagination {:?} moved to page {}",
+            event.pagination_entity, event.page
+        );
+    }
+}
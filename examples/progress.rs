@@ -82,6 +82,8 @@ fn setup_ui(mut commands: Commands) {
                     .progress(750.0, 1000.0)
                     .size2()
                     .label("Downloading large file...")
+                    // Announced as "750 of 1000 MB" instead of "75%"
+                    .value_text_formatter(|value, _, max| format!("{value} of {max} MB"))
                     .build(),
             );
 
@@ -189,6 +191,26 @@ fn setup_ui(mut commands: Commands) {
             //         .build(),
             // );
 
+            // Circular progress section
+            parent.spawn(Heading::h2("Circular Progress").build());
+            parent.spawn(
+                ProgressBuilder::new()
+                    .name("CircularPercentage")
+                    .circular()
+                    .percentage(65.0)
+                    .diameter(48.0)
+                    .show_percentage()
+                    .build(),
+            );
+            parent.spawn(
+                ProgressBuilder::new()
+                    .name("CircularIndeterminate")
+                    .circular()
+                    .indeterminate()
+                    .thickness(3.0)
+                    .build(),
+            );
+
             // Simulated dynamic progress
             parent.spawn(Heading::h2("Dynamic Progress").build());
             parent.spawn(Text::body("This progress bar updates over time (simulation)").build());
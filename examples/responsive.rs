@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use ui::{
+    components::{heading::Heading, text::Text, CardBuilder, Grid},
+    plugin::{ForgeUiPlugin, UiState},
+    theme::{
+        breakpoints::{ActiveBreakpoint, Breakpoint},
+        typography::TextSize,
+    },
+    utilities::ui_root::ui_root,
+};
+
+/// Label showing the active breakpoint.
+#[derive(Component)]
+struct BreakpointLabel;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, show_active_breakpoint)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("Responsive Root")).id();
+    let title = commands
+        .spawn(
+            Heading::h1("Resize the window")
+                .size_responsive([
+                    (Breakpoint::Base, TextSize::Xl),
+                    (Breakpoint::Md, TextSize::X3l),
+                    (Breakpoint::Xl, TextSize::X5l),
+                ])
+                .build(),
+        )
+        .id();
+    let label = commands
+        .spawn((Text::label("").build(), BreakpointLabel))
+        .id();
+
+    let cards: Vec<Entity> = (1..=8)
+        .map(|index| {
+            commands
+                .spawn(CardBuilder::new(format!("Card{index}")).build())
+                .with_child(Text::body(format!("Card {index}")).build())
+                .id()
+        })
+        .collect();
+
+    let grid = commands
+        .spawn(
+            Grid::new("Cards")
+                .columns_responsive([
+                    (Breakpoint::Base, 1),
+                    (Breakpoint::Sm, 2),
+                    (Breakpoint::Lg, 4),
+                ])
+                .gap(12.0)
+                .fill_width()
+                .build(),
+        )
+        .add_children(&cards)
+        .id();
+
+    commands.entity(root).add_children(&[title, label, grid]);
+}
+
+/// User systems can branch on the active breakpoint like any other resource.
+fn show_active_breakpoint(
+    active: Res<ActiveBreakpoint>,
+    mut labels: Query<&mut bevy::prelude::Text, With<BreakpointLabel>>,
+) {
+    if !active.is_changed() {
+        return;
+    }
+    for mut text in &mut labels {
+        text.0 = format!("Active breakpoint: {:?}", active.0);
+    }
+}
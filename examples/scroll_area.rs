@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use ui::{
+    components::{heading::Heading, text::Text, ScrollArea, ScrollbarVisibility},
+    plugin::{ForgeUiPlugin, UiState},
+    theme::color::theme,
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("ScrollArea Root")).id();
+    let title = commands
+        .spawn(Heading::h1("ScrollArea Component Examples").build())
+        .id();
+
+    let row = commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(24.0),
+            ..default()
+        })
+        .id();
+
+    // Vertical list that only shows its scrollbar when needed
+    let items: Vec<Entity> = (1..=40)
+        .map(|i| {
+            commands
+                .spawn(Text::body(format!("List item {}", i)).build())
+                .id()
+        })
+        .collect();
+    let vertical = ScrollArea::new("Vertical")
+        .vertical()
+        .width(Val::Px(240.0))
+        .height(Val::Px(300.0))
+        .children(items)
+        .build(&mut commands);
+
+    // Wide row that scrolls sideways, scrollbar revealed on hover
+    let chips: Vec<Entity> = (1..=30)
+        .map(|i| {
+            commands
+                .spawn((
+                    Node {
+                        width: Val::Px(80.0),
+                        height: Val::Px(60.0),
+                        margin: UiRect::all(Val::Px(4.0)),
+                        flex_shrink: 0.0,
+                        ..default()
+                    },
+                    BackgroundColor(theme().gray.bg_hover),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(Text::label(format!("#{}", i)).build());
+                })
+                .id()
+        })
+        .collect();
+    let horizontal = ScrollArea::new("Horizontal")
+        .horizontal()
+        .scrollbar_visibility(ScrollbarVisibility::Hover)
+        .width(Val::Px(300.0))
+        .height(Val::Px(90.0))
+        .children(chips)
+        .build(&mut commands);
+
+    // Large canvas scrolling on both axes with always-visible scrollbars
+    let canvas = commands
+        .spawn((
+            Node {
+                width: Val::Px(800.0),
+                height: Val::Px(800.0),
+                flex_shrink: 0.0,
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            BackgroundColor(theme().gray.bg_subtle),
+        ))
+        .with_children(|parent| {
+            parent.spawn(Text::body("Drag the thumbs or use the mouse wheel.").build());
+        })
+        .id();
+    let both = ScrollArea::new("Both")
+        .both()
+        .scrollbar_visibility(ScrollbarVisibility::Always)
+        .width(Val::Px(300.0))
+        .height(Val::Px(300.0))
+        .child(canvas)
+        .build(&mut commands);
+
+    commands.entity(row).add_children(&[vertical, horizontal, both]);
+    commands.entity(root).add_children(&[title, row]);
+}
@@ -62,7 +62,7 @@ fn setup(mut commands: Commands) {
 
                     // Usage Section - Default spacing
                     parent
-                        .spawn(Section::size_3("usage").build())
+                        .spawn(Section::size_3("usage").label("Usage examples").build())
                         .with_children(|parent| {
                             parent.spawn(Heading::h2("Usage Examples").build());
                             parent.spawn(Text::body("Section components are ideal for organizing content hierarchically.").build());
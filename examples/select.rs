@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use ui::{
     components::*,
-    utilities::ui_root,
+    utilities::{ui_root, OverlayAnimation},
     plugin::ForgeUiPlugin,
 };
 
@@ -9,11 +9,42 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(ForgeUiPlugin)
+        .init_resource::<Preferences>()
+        .add_plugins(ValueBindingPlugin::<Preferences>::default())
         .add_systems(Startup, setup)
-        .add_systems(Update, log_select_events)
+        .add_systems(Update, (log_select_events, cycle_region))
         .run();
 }
 
+/// App state a select is bound to
+#[derive(Resource)]
+struct Preferences {
+    region: String,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            region: "eu".to_string(),
+        }
+    }
+}
+
+/// Changes the bound region from outside the select on R
+fn cycle_region(keys: Res<ButtonInput<KeyCode>>, mut preferences: ResMut<Preferences>) {
+    if keys.just_pressed(KeyCode::KeyR) {
+        preferences.region = match preferences.region.as_str() {
+            "eu" => "us",
+            "us" => "apac",
+            _ => "eu",
+        }
+        .to_string();
+    }
+    if preferences.is_changed() {
+        info!("Preferences region: {}", preferences.region);
+    }
+}
+
 /// Log select events to verify they're working
 fn log_select_events(
     mut select_change_events: EventReader<SelectChangeEvent>,
@@ -65,6 +96,18 @@ fn setup(mut commands: Commands) {
                         .build(),
                 );
 
+                // Dropdown fading and scaling in on open, and out on close
+                container.spawn(
+                    Select::new()
+                        .placeholder("Choose a planet...")
+                        .option("mercury", "Mercury")
+                        .option("venus", "Venus")
+                        .option("earth", "Earth")
+                        .animation(OverlayAnimation::fade_scale())
+                        .width(Val::Px(200.0))
+                        .build(),
+                );
+
                 // Select with pre-selected value (test Radix positioning)
                 container.spawn(
                     Select::new()
@@ -77,6 +120,65 @@ fn setup(mut commands: Commands) {
                         .build(),
                 );
 
+                // Grouped options with headers and a separator; Up / Down skip the headers
+                container.spawn(
+                    Select::new()
+                        .placeholder("Choose a food...")
+                        .group(
+                            "Fruits",
+                            vec![
+                                SelectOption::new("apple", "Apple"),
+                                SelectOption::new("banana", "Banana"),
+                            ],
+                        )
+                        .separator()
+                        .group(
+                            "Vegetables",
+                            vec![
+                                SelectOption::new("carrot", "Carrot"),
+                                SelectOption::new("leek", "Leek"),
+                            ],
+                        )
+                        .width(Val::Px(200.0))
+                        .build(),
+                );
+
+                // Long list: the dropdown is capped at five rows and scrolls,
+                // following the highlight while navigating with the keyboard
+                container.spawn(
+                    Select::new()
+                        .placeholder("Choose a country...")
+                        .options(vec![
+                            ("at", "Austria"),
+                            ("be", "Belgium"),
+                            ("ch", "Switzerland"),
+                            ("de", "Germany"),
+                            ("dk", "Denmark"),
+                            ("es", "Spain"),
+                            ("fr", "France"),
+                            ("it", "Italy"),
+                            ("nl", "Netherlands"),
+                            ("pl", "Poland"),
+                            ("pt", "Portugal"),
+                            ("se", "Sweden"),
+                        ])
+                        .selected_value("it")
+                        .max_dropdown_height(190.0)
+                        .width(Val::Px(200.0))
+                        .build(),
+                );
+
+                // Select bound to the Preferences resource (R cycles it externally)
+                container.spawn(
+                    Select::new()
+                        .option("eu", "Europe")
+                        .option("us", "United States")
+                        .option("apac", "Asia Pacific")
+                        .bind::<Preferences>(|preferences| &mut preferences.region)
+                        .width(Val::Px(200.0))
+                        .build(),
+                );
+
                 // Size variants
                 container.spawn(
                     Select::new()
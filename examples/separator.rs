@@ -192,5 +192,24 @@ fn setup_ui(mut commands: Commands) {
                     parent.spawn(SeparatorBuilder::new().vertical().build());
                     parent.spawn(Text::body("Colors").build());
                 });
+
+            // Orientation taken from the flex direction of the parent
+            parent.spawn(Heading::h2("Auto Orientation").build());
+            for direction in [FlexDirection::Row, FlexDirection::Column] {
+                parent
+                    .spawn(Node {
+                        flex_direction: direction,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(12.0),
+                        row_gap: Val::Px(12.0),
+                        height: Val::Px(80.0),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn(Text::body("Before").build());
+                        parent.spawn(SeparatorBuilder::new().auto_orientation().build());
+                        parent.spawn(Text::body("After").build());
+                    });
+            }
         });
 }
@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use ui::{
+    components::{text::Text, FlexComponent},
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::{ui_root, update_show_when, ShowWhen},
+};
+
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+enum Screen {
+    #[default]
+    Menu,
+    Game,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .init_state::<Screen>()
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, (switch_screen, update_show_when::<Screen>).chain())
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(ui_root("ShowWhenDemo"))
+        .with_children(|parent| {
+            parent.spawn(Text::title("Press Space to switch screens").build());
+
+            // Shown only in the menu
+            parent
+                .spawn((
+                    FlexComponent::column("Menu").gap(8.0).build(),
+                    ShowWhen::in_state(Screen::Menu),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(Text::body("Main menu").build());
+                    parent.spawn(Text::body("New game").build());
+                    parent.spawn(Text::body("Settings").build());
+                });
+
+            // Shown only in game
+            parent
+                .spawn((
+                    FlexComponent::row("Hud").gap(16.0).build(),
+                    ShowWhen::in_state(Screen::Game),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(Text::body("Health: 100").build());
+                    parent.spawn(Text::body("Score: 0").build());
+                });
+
+            // Visibility decided once, when building
+            parent
+                .spawn(
+                    FlexComponent::row("Debug")
+                        .visible_if(cfg!(debug_assertions))
+                        .build(),
+                )
+                .with_children(|parent| {
+                    parent.spawn(Text::body("Debug build").build());
+                });
+        });
+}
+
+fn switch_screen(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    screen: Res<State<Screen>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    if keyboard.just_pressed(KeyCode::Space) {
+        next_screen.set(match screen.get() {
+            Screen::Menu => Screen::Game,
+            Screen::Game => Screen::Menu,
+        });
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
 use ui::{
     components::{
@@ -42,6 +44,17 @@ fn setup_ui(mut commands: Commands) {
                     .build(),
             );
 
+            // Paced events: commit once the value rests, at most 10 changes per second
+            parent.spawn(Text::label("Debounced commit, throttled changes").build());
+            parent.spawn(
+                SliderBuilder::new()
+                    .name("PacedSlider")
+                    .value(50.0)
+                    .commit_debounce(Duration::from_millis(300))
+                    .throttle_changes(Duration::from_millis(100))
+                    .build(),
+            );
+
             // Size variants section
             parent.spawn(Heading::h2("Size Variants").build());
 
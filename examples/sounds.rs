@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+use ui::{
+    components::{
+        heading::Heading, text::Text, ButtonBuilder, Checkbox, PlayUiSound, Switch, UiSound,
+        UiSoundSettings, UiSounds,
+    },
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, handle_keys)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>, mut sounds: ResMut<UiSounds>) {
+    // Only a click sound ships by default; reuse it for the other interactions
+    let tap: Handle<AudioSource> = asset_server.load("audio/sfx/tap.ogg");
+    sounds.hover = Some(tap.clone());
+    sounds.toggle_on = Some(tap.clone());
+    sounds.toggle_off = Some(tap.clone());
+    sounds.error = Some(tap);
+
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("Sounds Root")).id();
+    let title = commands
+        .spawn(Heading::h1("Interaction Sounds").build())
+        .id();
+    let hint = commands
+        .spawn(Text::body("M toggles all sounds, E plays the error sound.").build())
+        .id();
+
+    let column = commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::FlexStart,
+            row_gap: Val::Px(12.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(ButtonBuilder::new("Loud").text("With sound").build());
+            parent.spawn(
+                ButtonBuilder::new("Quiet")
+                    .text("Muted")
+                    .mute_sounds()
+                    .build(),
+            );
+            parent.spawn(Checkbox::new("Terms").build());
+            parent.spawn(Switch::new("Notifications").build());
+        })
+        .id();
+
+    commands.entity(root).add_children(&[title, hint, column]);
+}
+
+fn handle_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<UiSoundSettings>,
+    mut sounds: EventWriter<PlayUiSound>,
+) {
+    if keys.just_pressed(KeyCode::KeyM) {
+        settings.enabled = !settings.enabled;
+        info!("Sounds enabled: {}", settings.enabled);
+    }
+    if keys.just_pressed(KeyCode::KeyE) {
+        sounds.write(PlayUiSound::new(UiSound::Error));
+    }
+}
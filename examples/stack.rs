@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use ui::{
+    components::{
+        badge::BadgeBuilder,
+        stack::{HStack, VStack, ZStack},
+        text::Text,
+    },
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::{ui_root, NodeSizing},
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(ui_root("StackDemo"))
+        .with_children(|parent| {
+            parent
+                .spawn(
+                    VStack::new("Page")
+                        .spacing(24.0)
+                        .padding(Val::Px(32.0))
+                        .build(),
+                )
+                .with_children(|parent| {
+                    parent.spawn(Text::title("Stacks").build());
+
+                    // Rows of label and value
+                    parent
+                        .spawn(VStack::new("Stats").spacing(8.0).build())
+                        .with_children(|parent| {
+                            for (label, value) in [("Health", "100"), ("Score", "4200")] {
+                                parent
+                                    .spawn(HStack::new(label).spacing(12.0).build())
+                                    .with_children(|parent| {
+                                        parent.spawn(Text::label(label).build());
+                                        parent.spawn(Text::body(value).build());
+                                    });
+                            }
+                        });
+
+                    // Badge over the corner of a tile
+                    parent
+                        .spawn(
+                            ZStack::new("Tile")
+                                .top()
+                                .trailing()
+                                .width_px(120.0)
+                                .height_px(120.0)
+                                .build(),
+                        )
+                        .with_children(|parent| {
+                            parent.spawn(Text::body("Inbox").build());
+                            parent.spawn(BadgeBuilder::new("3").build());
+                        });
+                });
+        });
+}
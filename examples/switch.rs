@@ -4,6 +4,7 @@ use bevy::{
     winit::WinitSettings,
 };
 use ui::{
+    assets::icons::interface,
     components::{SwitchChangeEvent, SwitchComponent},
     plugin::{ForgeUiPlugin, UiState},
     theme::color::{accent_palette, error_palette, success_palette, warning_palette},
@@ -16,7 +17,7 @@ fn main() {
         .add_plugins(ForgeUiPlugin)
         .insert_resource(WinitSettings::desktop_app())
         .add_systems(OnEnter(UiState::Ready), setup)
-        .add_systems(Update, handle_switch_events)
+        .add_systems(Update, (handle_switch_events, set_all_switches))
         .run();
 }
 
@@ -259,6 +260,37 @@ fn setup(mut commands: Commands) {
                             });
                     });
 
+                    // Labels and thumb icons section
+                    create_section(parent, "Labels & Icons", |parent| {
+                        parent
+                            .spawn(Node {
+                                flex_direction: FlexDirection::Row,
+                                column_gap: Val::Px(20.0),
+                                align_items: AlignItems::Center,
+                                ..default()
+                            })
+                            .with_children(|parent| {
+                                create_labeled_switch(
+                                    parent,
+                                    "On/Off labels",
+                                    SwitchComponent::new("labels")
+                                        .off_label("Off")
+                                        .on_label("On")
+                                        .build(),
+                                );
+
+                                create_labeled_switch(
+                                    parent,
+                                    "Thumb icons",
+                                    SwitchComponent::new("icons")
+                                        .size_3()
+                                        .thumb_icon(interface::Check, interface::X)
+                                        .checked()
+                                        .build(),
+                                );
+                            });
+                    });
+
                     // Interactive demo section
                     create_section(parent, "Interactive Demo", |parent| {
                         parent
@@ -374,3 +406,20 @@ fn handle_switch_events(mut switch_events: EventReader<SwitchChangeEvent>) {
         );
     }
 }
+
+/// Turns every switch on with O and off with P, from outside the switches.
+fn set_all_switches(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut switches: Query<&mut SwitchComponent>,
+) {
+    let checked = if keyboard.just_pressed(KeyCode::KeyO) {
+        true
+    } else if keyboard.just_pressed(KeyCode::KeyP) {
+        false
+    } else {
+        return;
+    };
+    for mut switch in &mut switches {
+        switch.checked = checked;
+    }
+}
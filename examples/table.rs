@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use ui::{
+    components::{
+        heading::Heading, sort_rows, CellContent, ColumnDef, GridTrackSize, Table, TableComponent,
+        TableSortEvent,
+    },
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, sort_on_header_click)
+        .run();
+}
+
+fn inventory() -> Vec<Vec<CellContent>> {
+    [
+        ("Iron Sword", "Weapon", 1, 12.5),
+        ("Healing Potion", "Consumable", 8, 3.0),
+        ("Oak Shield", "Armor", 1, 9.75),
+        ("Arrow", "Ammunition", 64, 0.1),
+        ("Leather Boots", "Armor", 2, 4.2),
+        ("Mana Potion", "Consumable", 3, 3.5),
+    ]
+    .into_iter()
+    .map(|(item, kind, quantity, weight)| {
+        vec![
+            CellContent::from(item),
+            CellContent::from(kind),
+            CellContent::from(quantity),
+            CellContent::from(weight),
+        ]
+    })
+    .collect()
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("Table Root")).id();
+    let title = commands
+        .spawn(Heading::h1("Table Component Examples").build())
+        .id();
+
+    let table = commands
+        .spawn(
+            Table::new("Inventory")
+                .columns(vec![
+                    ColumnDef::new("Item").sortable(),
+                    ColumnDef::new("Type"),
+                    ColumnDef::new("Qty")
+                        .width(GridTrackSize::Px(80.0))
+                        .sortable(),
+                    ColumnDef::new("Weight")
+                        .width(GridTrackSize::Px(100.0))
+                        .sortable(),
+                ])
+                .set_rows(inventory())
+                .striped()
+                .width(Val::Px(560.0))
+                .build(),
+        )
+        .id();
+
+    commands.entity(root).add_children(&[title, table]);
+}
+
+/// The table only reports the requested order; sorting the data is up to the app.
+fn sort_on_header_click(
+    mut events: EventReader<TableSortEvent>,
+    mut tables: Query<&mut TableComponent>,
+) {
+    for event in events.read() {
+        if let Ok(mut table) = tables.get_mut(event.table_entity) {
+            let mut rows = inventory();
+            sort_rows(&mut rows, event.column, event.direction);
+            table.set_rows(rows);
+        }
+    }
+}
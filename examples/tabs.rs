@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 use ui::{
-    components::{TabsBuilder, text::Text},
+    components::{
+        add_tab, text::Text, TabAddEvent, TabCloseEvent, TabsBuilder, TabsIndicator,
+        TabsPanelTransition, TabsValue,
+    },
     plugin::{ForgeUiPlugin, UiState},
     theme::color::theme,
 };
@@ -10,6 +13,7 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_plugins(ForgeUiPlugin)
         .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, (add_tabs, log_closed_tabs, switch_lazy_tabs))
         .run();
 }
 
@@ -74,10 +78,92 @@ fn setup(mut commands: Commands) {
         })
         .id();
 
+    // Closeable tabs; hover a tab (or focus it and press Ctrl+W) to close it.
+    // Panels slide in the direction of the selected tab.
     let tabs = TabsBuilder::new()
         .triggers(vec!["Tab 1", "Tab 2", "Tab 3"])
         .contents(vec![tab1_content, tab2_content, tab3_content])
+        .indicator(TabsIndicator::Underline)
+        .panel_transition(TabsPanelTransition::Slide)
+        .closeable()
+        .with_add_button()
         .build(&mut commands);
 
-    commands.entity(root).add_child(tabs);
-}
\ No newline at end of file
+    // Panels of these tabs are spawned on first activation and kept afterwards.
+    // They are controlled: they open on "Lazy 2" and keys 1-3 switch them.
+    // Switching cross-fades the panels.
+    let lazy_tabs = TabsBuilder::new()
+        .triggers(vec!["Lazy 1", "Lazy 2", "Lazy 3"])
+        .value("Lazy 2")
+        .lazy(|commands, value| {
+            info!("Spawning panel for {value}");
+            commands
+                .spawn((
+                    Node {
+                        width: Val::Px(300.0),
+                        height: Val::Px(150.0),
+                        padding: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    BackgroundColor(theme().gray.bg),
+                ))
+                .with_children(|parent| {
+                    parent
+                        .spawn(Text::body(format!("Lazily spawned content for {value}.")).build());
+                })
+                .id()
+        })
+        .keep_alive()
+        .indicator(TabsIndicator::Pill)
+        .panel_transition(TabsPanelTransition::Fade)
+        .build(&mut commands);
+
+    commands.entity(root).add_children(&[tabs, lazy_tabs]);
+}
+fn add_tabs(
+    mut commands: Commands,
+    mut add_events: EventReader<TabAddEvent>,
+    mut added: Local<usize>,
+) {
+    for event in add_events.read() {
+        *added += 1;
+        let label = format!("New {}", *added);
+        let content = commands
+            .spawn((
+                Node {
+                    width: Val::Px(300.0),
+                    height: Val::Px(150.0),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+                BackgroundColor(theme().gray.bg),
+            ))
+            .with_children(|parent| {
+                parent.spawn(Text::body(format!("This is the content for {label}.")).build());
+            })
+            .id();
+        add_tab(&mut commands, event.tabs, &label, Some(content));
+    }
+}
+
+fn log_closed_tabs(mut close_events: EventReader<TabCloseEvent>) {
+    for event in close_events.read() {
+        info!("Closed {}", event.value);
+    }
+}
+
+/// Switches the controlled tabs by writing their value.
+fn switch_lazy_tabs(keyboard: Res<ButtonInput<KeyCode>>, mut tabs: Query<&mut TabsValue>) {
+    let value = if keyboard.just_pressed(KeyCode::Digit1) {
+        "Lazy 1"
+    } else if keyboard.just_pressed(KeyCode::Digit2) {
+        "Lazy 2"
+    } else if keyboard.just_pressed(KeyCode::Digit3) {
+        "Lazy 3"
+    } else {
+        return;
+    };
+    for mut tabs in tabs.iter_mut().filter(|tabs| tabs.is_controlled) {
+        tabs.value = value.to_string();
+    }
+}
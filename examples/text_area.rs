@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use ui::{
+    components::{
+        heading::Heading, text::Text, TextArea, TextAreaBuilder, TextAreaChangeEvent, TextFieldSize,
+    },
+    plugin::{ForgeUiPlugin, UiState},
+    theme::color::theme,
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_changes)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("TextArea Root")).id();
+    let title = commands
+        .spawn(Heading::h1("TextArea Component Examples").build())
+        .id();
+
+    let column = commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(16.0),
+            ..default()
+        })
+        .id();
+
+    let areas = [
+        labeled(
+            &mut commands,
+            "Four rows",
+            TextArea::new("Notes")
+                .placeholder("Write something…")
+                .rows(4),
+        ),
+        labeled(
+            &mut commands,
+            "Auto-grow (2 – 6 rows)",
+            TextArea::new("AutoGrow")
+                .placeholder("Grows up to six rows, then scrolls")
+                .rows(2)
+                .max_rows(6),
+        ),
+        labeled(
+            &mut commands,
+            "Large, gray, prefilled",
+            TextArea::new("Prefilled")
+                .size(TextFieldSize::Size3)
+                .color(theme().gray)
+                .width(Val::Px(420.0))
+                .value(
+                    "Long lines wrap at the edge of the area. Use Up and Down to move \
+                     the caret between wrapped lines.\n\nEmpty lines are reachable too.",
+                ),
+        ),
+        labeled(
+            &mut commands,
+            "Disabled",
+            TextArea::new("Disabled")
+                .value("Read only")
+                .rows(2)
+                .disabled(),
+        ),
+    ];
+
+    commands.entity(column).add_children(&areas);
+    commands.entity(root).add_children(&[title, column]);
+}
+
+fn labeled(commands: &mut Commands, label: &str, area: TextAreaBuilder) -> Entity {
+    commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(Text::label(label).build());
+            parent.spawn(area.build());
+        })
+        .id()
+}
+
+fn log_changes(mut events: EventReader<TextAreaChangeEvent>) {
+    for event in events.read() {
+        info!(
+            "TextArea {:?} changed: {:?}",
+            event.text_area_entity, event.value
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+use ui::{
+    components::{
+        heading::Heading, text::Text, TextField, TextFieldBuilder, TextFieldChangeEvent,
+        TextFieldSize, TextFieldSubmitEvent,
+    },
+    plugin::{ForgeUiPlugin, UiState},
+    theme::color::theme,
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_events)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("TextField Root")).id();
+    let title = commands
+        .spawn(Heading::h1("TextField Component Examples").build())
+        .id();
+
+    let column = commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(16.0),
+            ..default()
+        })
+        .id();
+
+    let fields = [
+        labeled(
+            &mut commands,
+            "Name",
+            TextField::new("Name").placeholder("Your name"),
+        ),
+        labeled(
+            &mut commands,
+            "Password (max. 16 characters)",
+            TextField::new("Password")
+                .placeholder("Password")
+                .password()
+                .max_length(16),
+        ),
+        labeled(
+            &mut commands,
+            "Large, gray, prefilled",
+            TextField::new("Prefilled")
+                .size(TextFieldSize::Size3)
+                .color(theme().gray)
+                .value("Select me with Shift + arrows"),
+        ),
+        labeled(
+            &mut commands,
+            "Disabled",
+            TextField::new("Disabled").value("Read only").disabled(),
+        ),
+    ];
+
+    commands.entity(column).add_children(&fields);
+    commands.entity(root).add_children(&[title, column]);
+}
+
+fn labeled(commands: &mut Commands, label: &str, field: TextFieldBuilder) -> Entity {
+    commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(Text::label(label).build());
+            parent.spawn(field.build());
+        })
+        .id()
+}
+
+fn log_events(
+    mut changes: EventReader<TextFieldChangeEvent>,
+    mut submits: EventReader<TextFieldSubmitEvent>,
+) {
+    for event in changes.read() {
+        info!("{:?} changed: {}", event.text_field_entity, event.value);
+    }
+    for event in submits.read() {
+        info!("{:?} submitted: {}", event.text_field_entity, event.value);
+    }
+}
@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use ui::{
+    components::{heading::Heading, text::Text},
+    plugin::{ForgeUiPlugin, UiState},
+    theme::typography::TextSize,
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(ui_root("Text Marquee Root"))
+        .with_children(|parent| {
+            parent.spawn(Heading::h1("Marquee Text").build());
+
+            // Texts that fit stay still
+            for text in [
+                Text::label("Fits"),
+                Text::label("A rather long track title that overflows"),
+                Text::label("Fast and with a short rest at each end")
+                    .marquee_speed(80.0)
+                    .marquee_pause(Duration::from_millis(400)),
+            ] {
+                // The cell is the visible window of the marquee
+                parent
+                    .spawn(Node {
+                        width: Val::Px(160.0),
+                        ..default()
+                    })
+                    .with_children(|cell| {
+                        cell.spawn(text.marquee().build());
+                    });
+            }
+
+            // Instead of scrolling, a fitted text shrinks into its cell
+            for width in [120.0, 240.0, 480.0] {
+                parent
+                    .spawn(Node {
+                        width: Val::Px(width),
+                        ..default()
+                    })
+                    .with_children(|cell| {
+                        cell.spawn(
+                            Text::title("Fitted heading")
+                                .fit_text(TextSize::Sm, TextSize::X5l)
+                                .build(),
+                        );
+                    });
+            }
+        });
+}
@@ -0,0 +1,50 @@
+//! Drag across a text to select it, then press Ctrl+C (Cmd+C) to copy.
+//! Run with `--features clipboard` to actually write to the clipboard.
+
+use bevy::prelude::*;
+use ui::{
+    components::{heading::Heading, text::Text, CopyEvent, TextSelectEvent},
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_selections)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(ui_root("Text Selection Root"))
+        .with_children(|parent| {
+            parent.spawn(Heading::h1("Selectable Text").build());
+            parent.spawn(
+                Text::body("Select part of this sentence and copy it.")
+                    .selectable()
+                    .build(),
+            );
+            parent.spawn(Text::code("sk-live-0123456789").selectable().build());
+            parent.spawn(Text::caption("This caption is not selectable.").build());
+        });
+}
+
+fn log_selections(
+    mut select_events: EventReader<TextSelectEvent>,
+    mut copy_events: EventReader<CopyEvent>,
+    texts: Query<&bevy::prelude::Text>,
+) {
+    for event in select_events.read() {
+        if let Ok(text) = texts.get(event.text_entity) {
+            info!("Selected {:?}", &text.0[event.range.clone()]);
+        }
+    }
+    for event in copy_events.read() {
+        info!("Copied {:?}: {}", event.text, event.success);
+    }
+}
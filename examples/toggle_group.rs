@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+use ui::{
+    components::{
+        heading::Heading, text::Text, ToggleComponent, ToggleGroup, ToggleGroupChangeEvent,
+    },
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_group_changes)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(ui_root("ToggleGroup Root"))
+        .with_children(|parent| {
+            parent.spawn(Heading::h1("ToggleGroup Component Examples").build());
+
+            // Single selection: text alignment picker
+            parent.spawn(Text::label("Single selection").build());
+            parent.spawn(
+                ToggleGroup::new("alignment")
+                    .single()
+                    .item("left", "Left")
+                    .item("center", "Center")
+                    .item("right", "Right")
+                    .value("left")
+                    .build(),
+            );
+
+            // Multiple selection: text formatting with custom member builders
+            parent.spawn(Text::label("Multiple selection").build());
+            parent.spawn(
+                ToggleGroup::new("formatting")
+                    .multiple()
+                    .item_with("bold", ToggleComponent::new("bold").text("B"))
+                    .item_with("italic", ToggleComponent::new("italic").text("I"))
+                    .item_with("underline", ToggleComponent::new("underline").text("U"))
+                    .value("bold")
+                    .build(),
+            );
+
+            // Vertical orientation
+            parent.spawn(Text::label("Vertical").build());
+            parent.spawn(
+                ToggleGroup::new("view")
+                    .vertical()
+                    .item("list", "List")
+                    .item("grid", "Grid")
+                    .item("board", "Board")
+                    .build(),
+            );
+        });
+}
+
+fn log_group_changes(mut events: EventReader<ToggleGroupChangeEvent>) {
+    for event in events.read() {
+        info!(
+            "ToggleGroup '{}' -> {:?} (toggled '{}' to {})",
+            event.group_name, event.selected_values, event.value, event.pressed
+        );
+    }
+}
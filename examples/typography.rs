@@ -2,12 +2,12 @@ use bevy::prelude::*;
 use ui::{
     components::{
         heading::{Heading, HeadingExt, HeadingLevel},
-        text::Text,
+        text::{Span, Text},
         ButtonBuilder,
     },
     plugin::{ForgeUiPlugin, UiState},
     theme::{
-        color::TextColor,
+        color::{theme, TextColor},
         typography::{FontFamily, TextSize, TextWeight},
     },
     utilities::ui_root,
@@ -121,6 +121,35 @@ fn setup_typography_showcase(mut commands: Commands) {
                 .build()
         );
 
+        // Documentation Presets Section
+        parent.spawn(
+            Heading::h2("Documentation Presets")
+                .build()
+        );
+
+        parent.spawn(Text::code("let x = 1;").build());
+
+        parent.spawn(
+            Text::code_block("fn main() {\n    println!(\"Hello, world!\");\n}")
+                .build()
+        );
+
+        parent.spawn(
+            Text::blockquote("Simplicity is prerequisite for reliability.")
+                .build()
+        );
+
+        parent.spawn(
+            Text::rich(vec![
+                Span::text("Rich text mixes "),
+                Span::bold("bold"),
+                Span::text(" and "),
+                Span::colored("colored", theme().red),
+                Span::text(" runs"),
+            ])
+            .build()
+        );
+
         // Text Weights Section
         parent.spawn(
             Heading::h2("Font Weights")
@@ -175,6 +204,19 @@ fn setup_typography_showcase(mut commands: Commands) {
                 .build()
         );
 
+        parent.spawn(
+            Text::new("Underlined text")
+                .underline()
+                .build()
+        );
+
+        parent.spawn(
+            Text::new("Struck through text")
+                .strikethrough()
+                .color(TextColor::Muted)
+                .build()
+        );
+
         // Text Colors Section
         parent.spawn(
             Heading::h2("Text Colors")
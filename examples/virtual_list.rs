@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use ui::{
+    components::{heading::Heading, text::Text, VirtualListBuilder, VirtualListRangeEvent},
+    plugin::{ForgeUiPlugin, UiState},
+    utilities::ui_root::ui_root,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(ForgeUiPlugin)
+        .add_systems(OnEnter(UiState::Ready), setup)
+        .add_systems(Update, log_visible_range)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands.spawn(ui_root("VirtualList Root")).id();
+    let title = commands
+        .spawn(Heading::h1("VirtualList Component Example").build())
+        .id();
+
+    // 100 000 rows, of which only the visible ones (plus overscan) exist;
+    // rows scrolled out of view are rebound to the rows scrolling in
+    let list = VirtualListBuilder::new("Rows")
+        .count(100_000)
+        .item_height(28.0)
+        .width(Val::Px(320.0))
+        .height(Val::Px(400.0))
+        .spawn_item(|commands, index| {
+            commands
+                .spawn(Text::body(format!("Row {}", index + 1)).build())
+                .id()
+        })
+        .update_item(|commands, row, index| {
+            commands
+                .entity(row)
+                .insert(bevy::ui::widget::Text::new(format!("Row {}", index + 1)));
+        })
+        .build(&mut commands);
+
+    commands.entity(root).add_children(&[title, list]);
+}
+
+fn log_visible_range(mut range_events: EventReader<VirtualListRangeEvent>) {
+    for event in range_events.read() {
+        info!("Rows {}..{} instantiated", event.start, event.end);
+    }
+}
@@ -1,6 +1,17 @@
+//! Controller icon glyphs, one module per input device.
+//!
+//! Each module is compiled in with its `icons-*` cargo feature, which also
+//! loads the device's font. The default `icons-all` feature enables all of
+//! them; games showing only some devices can pick their features instead:
+//!
+//! ```toml
+//! ui = { version = "0.1", default-features = false, features = ["light_mode", "icons-xbox"] }
+//! ```
+
 #![allow(non_snake_case)]
 #![allow(non_upper_case_globals)]
 
+#[cfg(feature = "icons-generic")]
 pub mod generic {
     use crate::components::{text::Text, TextBuilder};
     use crate::theme::typography::{FontFamily, TextSize};
@@ -55,6 +66,7 @@ pub mod generic {
 }
 
 // ===== KEYBOARD & MOUSE ICONS =====
+#[cfg(feature = "icons-keyboard-mouse")]
 pub mod keyboard_mouse {
     use crate::components::{text::Text, TextBuilder};
     use crate::theme::typography::FontFamily;
@@ -312,6 +324,7 @@ pub mod keyboard_mouse {
 }
 
 // ===== NINTENDO SWITCH ICONS =====
+#[cfg(feature = "icons-switch")]
 pub mod switch {
     use crate::components::{text::Text, TextBuilder};
     use crate::theme::typography::FontFamily;
@@ -439,6 +452,7 @@ pub mod switch {
 }
 
 // ===== NINTENDO SWITCH 2 ICONS =====
+#[cfg(feature = "icons-switch-2")]
 pub mod switch_2 {
     use crate::components::{text::Text, TextBuilder};
     use crate::theme::typography::FontFamily;
@@ -576,6 +590,7 @@ pub mod switch_2 {
 }
 
 // ===== PLAYSTATION ICONS =====
+#[cfg(feature = "icons-playstation")]
 pub mod playstation {
     use bevy::color::palettes::css::*;
     use bevy::color::Color;
@@ -729,6 +744,7 @@ pub mod playstation {
 }
 
 // ===== XBOX ICONS =====
+#[cfg(feature = "icons-xbox")]
 pub mod xbox {
     use crate::components::{text::Text, TextBuilder};
     use crate::theme::typography::FontFamily;
@@ -843,6 +859,7 @@ pub mod xbox {
 }
 
 // ===== STEAM CONTROLLER ICONS =====
+#[cfg(feature = "icons-steam-controller")]
 pub mod steam_controller {
     use crate::components::{text::Text, TextBuilder};
     use crate::theme::typography::FontFamily;
@@ -924,6 +941,7 @@ pub mod steam_controller {
 }
 
 // ===== STEAM DECK ICONS =====
+#[cfg(feature = "icons-steam-deck")]
 pub mod steam_deck {
     use crate::components::{text::Text, TextBuilder};
     use crate::theme::typography::FontFamily;
@@ -1055,6 +1073,7 @@ pub mod steam_deck {
 }
 
 // ===== GAMECUBE ICONS =====
+#[cfg(feature = "icons-gamecube")]
 pub mod gamecube {
     use crate::components::{text::Text, TextBuilder};
     use crate::theme::typography::FontFamily;
@@ -1143,6 +1162,7 @@ pub mod gamecube {
 }
 
 // ===== WII ICONS =====
+#[cfg(feature = "icons-wii")]
 pub mod wii {
     use crate::components::{text::Text, TextBuilder};
     use crate::theme::typography::FontFamily;
@@ -1242,6 +1262,7 @@ pub mod wii {
 }
 
 // ===== WIIU ICONS =====
+#[cfg(feature = "icons-wii-u")]
 pub mod wiiu {
     use crate::components::{text::Text, TextBuilder};
     use crate::theme::typography::FontFamily;
@@ -1325,6 +1346,7 @@ pub mod wiiu {
 }
 
 // ===== TOUCH ICONS =====
+#[cfg(feature = "icons-touch")]
 pub mod touch {
     use crate::components::{text::Text, TextBuilder};
     use crate::theme::typography::FontFamily;
@@ -1368,6 +1390,7 @@ pub mod touch {
 }
 
 // ===== INDICATORS/FLAIRS =====
+#[cfg(feature = "icons-indicators")]
 pub mod indicators {
     use crate::components::{text::Text, TextBuilder};
     use crate::theme::typography::FontFamily;
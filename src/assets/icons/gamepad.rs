@@ -0,0 +1,496 @@
+//! Button prompts that follow the connected gamepad.
+//!
+//! [`ActiveControllerIcons`] tracks which icon family fits the gamepad
+//! connected last, detected from its USB vendor and product ids with
+//! [`controller_family`]. Unknown pads use [`FontFamily::GenericController`];
+//! games can pin a family with [`ActiveControllerIcons::override_family`],
+//! e.g. from a settings menu.
+//!
+//! Icons spawned with [`ControllerIcon::button`] name a button by its
+//! position rather than its label, and show the matching glyph of the active
+//! family, switching when another pad connects:
+//!
+//! ```rust
+//! // Cross on PlayStation, A on Xbox, B on Switch
+//! parent.spawn(ControllerIcon::button(ControllerButton::South));
+//! ```
+//!
+//! Prompts for families whose glyphs are not compiled in (see the `icons-*`
+//! features), or that lack a button, fall back to the generic glyph.
+//! [`glyph_for`] looks up the glyph of one family directly.
+
+use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent};
+use bevy::prelude::*;
+
+#[cfg(feature = "icons-generic")]
+use super::controllers::generic;
+#[cfg(feature = "icons-playstation")]
+use super::controllers::playstation;
+#[cfg(feature = "icons-steam-deck")]
+use super::controllers::steam_deck;
+#[cfg(feature = "icons-switch")]
+use super::controllers::switch;
+#[cfg(feature = "icons-switch-2")]
+use super::controllers::switch_2;
+#[cfg(feature = "icons-xbox")]
+use super::controllers::xbox;
+use crate::components::text::{Text, TextFontInfo};
+use crate::theme::typography::{FontFamily, TextSize, TextWeight};
+
+/// USB vendor ids of the controller makers with their own glyphs
+const VENDOR_MICROSOFT: u16 = 0x045e;
+const VENDOR_SONY: u16 = 0x054c;
+const VENDOR_NINTENDO: u16 = 0x057e;
+const VENDOR_VALVE: u16 = 0x28de;
+
+/// Nintendo and Valve product ids that don't use the vendor's default glyphs
+const PRODUCT_WII_REMOTE: u16 = 0x0306;
+const PRODUCT_WII_U_PRO: u16 = 0x0330;
+const PRODUCT_GAMECUBE_ADAPTER: u16 = 0x0337;
+const PRODUCT_STEAM_DECK: u16 = 0x1205;
+
+/// Gamepad button named by its position, shown with each family's label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ControllerButton {
+    /// Bottom face button: Cross, A on Xbox, B on Nintendo
+    South,
+    /// Right face button: Circle, B on Xbox, A on Nintendo
+    East,
+    /// Left face button: Square, X on Xbox, Y on Nintendo
+    West,
+    /// Top face button: Triangle, Y on Xbox, X on Nintendo
+    North,
+    LeftBumper,
+    RightBumper,
+    LeftTrigger,
+    RightTrigger,
+    /// View, Create, Minus
+    Select,
+    /// Menu, Options, Plus
+    Start,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    /// Pressing the left stick
+    LeftStick,
+    /// Pressing the right stick
+    RightStick,
+}
+
+impl ControllerButton {
+    /// PlayStation names of the shoulder buttons
+    pub const L1: Self = Self::LeftBumper;
+    pub const R1: Self = Self::RightBumper;
+    pub const L2: Self = Self::LeftTrigger;
+    pub const R2: Self = Self::RightTrigger;
+}
+
+/// Icon family of a gamepad from its USB vendor and product ids,
+/// [`FontFamily::GenericController`] when unknown.
+pub fn controller_family(vendor_id: Option<u16>, product_id: Option<u16>) -> FontFamily {
+    match (vendor_id, product_id) {
+        (Some(VENDOR_MICROSOFT), _) => FontFamily::Xbox,
+        (Some(VENDOR_SONY), _) => FontFamily::PlayStation,
+        (Some(VENDOR_NINTENDO), Some(PRODUCT_WII_REMOTE)) => FontFamily::Wii,
+        (Some(VENDOR_NINTENDO), Some(PRODUCT_WII_U_PRO)) => FontFamily::WiiU,
+        (Some(VENDOR_NINTENDO), Some(PRODUCT_GAMECUBE_ADAPTER)) => FontFamily::Gamecube,
+        (Some(VENDOR_NINTENDO), _) => FontFamily::NintendoSwitch,
+        (Some(VENDOR_VALVE), Some(PRODUCT_STEAM_DECK)) => FontFamily::SteamDeck,
+        (Some(VENDOR_VALVE), _) => FontFamily::SteamController,
+        _ => FontFamily::GenericController,
+    }
+}
+
+/// Glyph of `button` in the icon font of `family`, `None` when the family has
+/// none or its glyphs are not compiled in.
+// Without any icons-* feature only the fallback arm is left
+#[allow(unused_imports, unused_variables)]
+pub fn controller_glyph(family: FontFamily, button: ControllerButton) -> Option<&'static str> {
+    use ControllerButton::*;
+
+    match family {
+        #[cfg(feature = "icons-xbox")]
+        FontFamily::Xbox => Some(match button {
+            South => xbox::ButtonA,
+            East => xbox::ButtonB,
+            West => xbox::ButtonX,
+            North => xbox::ButtonY,
+            LeftBumper => xbox::Lb,
+            RightBumper => xbox::Rb,
+            LeftTrigger => xbox::Lt,
+            RightTrigger => xbox::Rt,
+            Select => xbox::ButtonView,
+            Start => xbox::ButtonMenu,
+            DPadUp => xbox::DpadUp,
+            DPadDown => xbox::DpadDown,
+            DPadLeft => xbox::DpadLeft,
+            DPadRight => xbox::DpadRight,
+            LeftStick => xbox::StickLPress,
+            RightStick => xbox::StickRPress,
+        }),
+        #[cfg(feature = "icons-playstation")]
+        FontFamily::PlayStation => Some(match button {
+            South => playstation::ButtonCross,
+            East => playstation::ButtonCircle,
+            West => playstation::ButtonSquare,
+            North => playstation::ButtonTriangle,
+            LeftBumper => playstation::TriggerL1,
+            RightBumper => playstation::TriggerR1,
+            LeftTrigger => playstation::TriggerL2,
+            RightTrigger => playstation::TriggerR2,
+            Select => playstation::Playstation5ButtonCreate,
+            Start => playstation::Playstation5ButtonOptions,
+            DPadUp => playstation::DpadUp,
+            DPadDown => playstation::DpadDown,
+            DPadLeft => playstation::DpadLeft,
+            DPadRight => playstation::DpadRight,
+            LeftStick => playstation::StickLPress,
+            RightStick => playstation::StickRPress,
+        }),
+        #[cfg(feature = "icons-switch")]
+        FontFamily::NintendoSwitch => Some(match button {
+            South => switch::ButtonB,
+            East => switch::ButtonA,
+            West => switch::ButtonY,
+            North => switch::ButtonX,
+            LeftBumper => switch::ButtonL,
+            RightBumper => switch::ButtonR,
+            LeftTrigger => switch::ButtonZl,
+            RightTrigger => switch::ButtonZr,
+            Select => switch::ButtonMinus,
+            Start => switch::ButtonPlus,
+            DPadUp => switch::DpadUp,
+            DPadDown => switch::DpadDown,
+            DPadLeft => switch::DpadLeft,
+            DPadRight => switch::DpadRight,
+            LeftStick => switch::StickLPress,
+            RightStick => switch::StickRPress,
+        }),
+        #[cfg(feature = "icons-switch-2")]
+        FontFamily::NintendoSwitch2 => Some(match button {
+            South => switch_2::ButtonB,
+            East => switch_2::ButtonA,
+            West => switch_2::ButtonY,
+            North => switch_2::ButtonX,
+            LeftBumper => switch_2::ButtonL,
+            RightBumper => switch_2::ButtonR,
+            LeftTrigger => switch_2::ButtonZl,
+            RightTrigger => switch_2::ButtonZr,
+            Select => switch_2::ButtonMinus,
+            Start => switch_2::ButtonPlus,
+            DPadUp => switch_2::DpadUp,
+            DPadDown => switch_2::DpadDown,
+            DPadLeft => switch_2::DpadLeft,
+            DPadRight => switch_2::DpadRight,
+            LeftStick => switch_2::StickLPress,
+            RightStick => switch_2::StickRPress,
+        }),
+        #[cfg(feature = "icons-steam-deck")]
+        FontFamily::SteamDeck => Some(match button {
+            South => steam_deck::ButtonA,
+            East => steam_deck::ButtonB,
+            West => steam_deck::ButtonX,
+            North => steam_deck::ButtonY,
+            LeftBumper => steam_deck::ButtonL1,
+            RightBumper => steam_deck::ButtonR1,
+            LeftTrigger => steam_deck::ButtonL2,
+            RightTrigger => steam_deck::ButtonR2,
+            Select => steam_deck::ButtonView,
+            Start => steam_deck::ButtonOptions,
+            DPadUp => steam_deck::DpadUp,
+            DPadDown => steam_deck::DpadDown,
+            DPadLeft => steam_deck::DpadLeft,
+            DPadRight => steam_deck::DpadRight,
+            LeftStick => steam_deck::StickLPress,
+            RightStick => steam_deck::StickRPress,
+        }),
+        // Shapes only, the same for all face buttons
+        #[cfg(feature = "icons-generic")]
+        FontFamily::GenericController => Some(match button {
+            South | East | West | North => generic::ButtonCircle,
+            LeftBumper | RightBumper => generic::ButtonTriggerA,
+            LeftTrigger | RightTrigger => generic::ButtonTriggerB,
+            Select | Start => generic::ButtonSquare,
+            DPadUp => generic::StickUp,
+            DPadDown => generic::StickDown,
+            DPadLeft => generic::StickLeft,
+            DPadRight => generic::StickRight,
+            LeftStick | RightStick => generic::StickPress,
+        }),
+        _ => None,
+    }
+}
+
+/// Glyph of `button` in the icon font of `family`, empty when there is none.
+///
+/// ```rust
+/// let confirm = glyph_for(ControllerButton::South, FontFamily::PlayStation);
+/// parent.spawn(Playstation::new(confirm).build());
+/// ```
+pub fn glyph_for(button: ControllerButton, family: FontFamily) -> &'static str {
+    controller_glyph(family, button).unwrap_or_default()
+}
+
+/// Font and glyph showing `button` for `family`, falling back to the generic
+/// glyph.
+pub fn resolve_controller_glyph(
+    family: FontFamily,
+    button: ControllerButton,
+) -> Option<(FontFamily, &'static str)> {
+    [family, FontFamily::GenericController]
+        .into_iter()
+        .find_map(|family| controller_glyph(family, button).map(|glyph| (family, glyph)))
+}
+
+/// Icon family for button prompts, following the connected gamepads.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ActiveControllerIcons {
+    /// Family used regardless of the connected gamepads
+    pub override_family: Option<FontFamily>,
+    /// Connected gamepads with their family, the latest last
+    connected: Vec<(Entity, FontFamily)>,
+}
+
+impl ActiveControllerIcons {
+    /// Family prompts are shown in: the override, else the family of the
+    /// gamepad connected last, else [`FontFamily::GenericController`].
+    pub fn family(&self) -> FontFamily {
+        self.override_family
+            .or_else(|| self.connected.last().map(|(_, family)| *family))
+            .unwrap_or(FontFamily::GenericController)
+    }
+
+    fn connect(&mut self, gamepad: Entity, family: FontFamily) {
+        self.disconnect(gamepad);
+        self.connected.push((gamepad, family));
+    }
+
+    fn disconnect(&mut self, gamepad: Entity) {
+        self.connected.retain(|(entity, _)| *entity != gamepad);
+    }
+}
+
+/// Controller button prompt, kept in the glyph of the active family.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ControllerButtonIcon {
+    pub button: ControllerButton,
+}
+
+pub struct ControllerIcon;
+
+impl ControllerIcon {
+    /// Prompt for `button`, in the glyph of the active gamepad's family.
+    pub fn button(button: ControllerButton) -> impl Bundle {
+        (
+            Text::new("")
+                .family(FontFamily::GenericController)
+                .size(TextSize::Base)
+                .build(),
+            ControllerButtonIcon { button },
+        )
+    }
+}
+
+/// System that tracks connected gamepads in [`ActiveControllerIcons`].
+pub fn update_active_controller_icons(
+    mut events: EventReader<GamepadConnectionEvent>,
+    mut icons: ResMut<ActiveControllerIcons>,
+) {
+    for event in events.read() {
+        match &event.connection {
+            GamepadConnection::Connected {
+                vendor_id,
+                product_id,
+                ..
+            } => icons.connect(event.gamepad, controller_family(*vendor_id, *product_id)),
+            GamepadConnection::Disconnected => icons.disconnect(event.gamepad),
+        }
+    }
+}
+
+/// System that shows the glyph of the active family in new button prompts
+/// and in all of them when the family changes.
+pub fn update_controller_button_icons(
+    mut commands: Commands,
+    icons: Res<ActiveControllerIcons>,
+    mut prompts: Query<(
+        Entity,
+        Ref<ControllerButtonIcon>,
+        &mut bevy::ui::widget::Text,
+    )>,
+    mut family: Local<Option<FontFamily>>,
+) {
+    let active = icons.family();
+    let family_changed = *family != Some(active);
+    *family = Some(active);
+
+    for (entity, prompt, mut text) in &mut prompts {
+        if !family_changed && !prompt.is_changed() {
+            continue;
+        }
+        let (font_family, glyph) =
+            resolve_controller_glyph(active, prompt.button).unwrap_or((FontFamily::Sans, ""));
+        text.0 = glyph.to_string();
+        // Picked up by apply_text_fonts, icon fonts come in a single weight
+        commands.entity(entity).insert(TextFontInfo {
+            family: font_family,
+            weight: TextWeight::Regular,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_app;
+
+    #[test]
+    fn test_vendor_ids_pick_family() {
+        // DualSense
+        assert_eq!(
+            controller_family(Some(0x054c), Some(0x0ce6)),
+            FontFamily::PlayStation
+        );
+        // Xbox Series controller
+        assert_eq!(
+            controller_family(Some(0x045e), Some(0x0b12)),
+            FontFamily::Xbox
+        );
+        // Switch Pro Controller
+        assert_eq!(
+            controller_family(Some(0x057e), Some(0x2009)),
+            FontFamily::NintendoSwitch
+        );
+        assert_eq!(
+            controller_family(Some(0x28de), Some(0x1205)),
+            FontFamily::SteamDeck
+        );
+        assert_eq!(
+            controller_family(Some(0x1234), Some(0x5678)),
+            FontFamily::GenericController
+        );
+        assert_eq!(controller_family(None, None), FontFamily::GenericController);
+    }
+
+    #[cfg(all(
+        feature = "icons-xbox",
+        feature = "icons-playstation",
+        feature = "icons-switch"
+    ))]
+    #[test]
+    fn test_buttons_map_to_platform_glyphs() {
+        use ControllerButton::*;
+
+        let glyphs = |button| {
+            [
+                FontFamily::PlayStation,
+                FontFamily::Xbox,
+                FontFamily::NintendoSwitch,
+            ]
+            .map(|family| glyph_for(button, family))
+        };
+
+        assert_eq!(
+            glyphs(South),
+            [playstation::ButtonCross, xbox::ButtonA, switch::ButtonB]
+        );
+        assert_eq!(
+            glyphs(East),
+            [playstation::ButtonCircle, xbox::ButtonB, switch::ButtonA]
+        );
+        assert_eq!(
+            glyphs(ControllerButton::R1),
+            [playstation::TriggerR1, xbox::Rb, switch::ButtonR]
+        );
+        assert_eq!(
+            glyphs(DPadUp),
+            [playstation::DpadUp, xbox::DpadUp, switch::DpadUp]
+        );
+        assert_eq!(
+            glyphs(Start),
+            [
+                playstation::Playstation5ButtonOptions,
+                xbox::ButtonMenu,
+                switch::ButtonPlus
+            ]
+        );
+
+        // No glyphs for text families
+        assert_eq!(glyph_for(South, FontFamily::Sans), "");
+    }
+
+    #[test]
+    fn test_connections_switch_active_family() {
+        let mut app = test_app();
+        let connect = |app: &mut App, vendor_id| {
+            let gamepad = app.world_mut().spawn_empty().id();
+            app.world_mut().send_event(GamepadConnectionEvent::new(
+                gamepad,
+                GamepadConnection::Connected {
+                    name: "Test pad".to_string(),
+                    vendor_id: Some(vendor_id),
+                    product_id: None,
+                },
+            ));
+            app.update();
+            gamepad
+        };
+        let family = |app: &App| app.world().resource::<ActiveControllerIcons>().family();
+        assert_eq!(family(&app), FontFamily::GenericController);
+
+        connect(&mut app, 0x045e);
+        assert_eq!(family(&app), FontFamily::Xbox);
+        let playstation = connect(&mut app, 0x054c);
+        assert_eq!(family(&app), FontFamily::PlayStation);
+
+        // Back to the pad still connected
+        app.world_mut().send_event(GamepadConnectionEvent::new(
+            playstation,
+            GamepadConnection::Disconnected,
+        ));
+        app.update();
+        assert_eq!(family(&app), FontFamily::Xbox);
+
+        app.world_mut()
+            .resource_mut::<ActiveControllerIcons>()
+            .override_family = Some(FontFamily::NintendoSwitch);
+        assert_eq!(family(&app), FontFamily::NintendoSwitch);
+    }
+
+    #[cfg(all(feature = "icons-xbox", feature = "icons-playstation"))]
+    #[test]
+    fn test_prompts_follow_active_family() {
+        let mut app = test_app();
+        let prompt = app
+            .world_mut()
+            .spawn(ControllerIcon::button(ControllerButton::South))
+            .id();
+        let shown = |app: &mut App| {
+            app.update();
+            app.world()
+                .get::<bevy::ui::widget::Text>(prompt)
+                .unwrap()
+                .0
+                .clone()
+        };
+
+        let mut show_family = |family| {
+            app.world_mut()
+                .resource_mut::<ActiveControllerIcons>()
+                .override_family = Some(family);
+            shown(&mut app)
+        };
+
+        assert_eq!(show_family(FontFamily::Xbox), xbox::ButtonA);
+        assert_eq!(
+            show_family(FontFamily::PlayStation),
+            playstation::ButtonCross
+        );
+        // No glyphs of its own
+        assert_eq!(
+            show_family(FontFamily::Touch),
+            controller_glyph(FontFamily::GenericController, ControllerButton::South).unwrap_or("")
+        );
+    }
+}
@@ -3,7 +3,10 @@ pub mod interface;
 
 // Controller Icons
 pub mod controllers;
+pub mod gamepad;
 
 // Re-export aller Icon-Kategorien
+#[cfg(feature = "icons-playstation")]
 pub use controllers::playstation::*;
+pub use gamepad::*;
 pub use interface::*;
@@ -12,11 +12,16 @@ pub struct AssetsPlugin;
 
 impl Plugin for AssetsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.init_resource::<ActiveControllerIcons>().add_systems(
             Update,
             (
                 check_assets_loaded.run_if(in_state(UiState::LoadingAssets)),
                 check_theme_ready.run_if(in_state(UiState::LoadingTheme)),
+                (
+                    update_active_controller_icons,
+                    update_controller_button_icons,
+                )
+                    .chain(),
             ),
         );
         info!("AssetPlugin loaded. Font-based Interface icons and Atlas-based Controller icons will be available after startup.");
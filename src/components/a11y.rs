@@ -0,0 +1,366 @@
+//! Semantic accessibility metadata.
+//!
+//! Interactive builders attach a [`UiA11y`] describing what the widget is
+//! (its [`UiRole`]), what it is called and what state it is in. Nothing reads
+//! it yet: it is the data model for a future screen-reader backend, and lets
+//! automated UI tests find widgets by meaning instead of by entity layout.
+//!
+//! The label defaults to the widget's visible label (or its name) and can be
+//! overridden with the builders' `.a11y_label()`. Values follow the widgets'
+//! state through [`sync_a11y_values`].
+//!
+//! Sliders and progress bars also describe their value in words, e.g. "45%",
+//! in [`UiA11y::value_text`]. The text follows the value at most every
+//! [`A11Y_VALUE_TEXT_THROTTLE`], so dragging a slider isn't announced on
+//! every frame, and can be localized with the builders'
+//! `.value_text_formatter()`.
+//!
+//! ```rust
+//! fn find_checked(widgets: Query<&UiA11y>) {
+//!     for a11y in &widgets {
+//!         if a11y.role == UiRole::Checkbox && a11y.value == Some(UiA11yValue::Checked(true)) {
+//!             info!("{} is checked", a11y.label);
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::{fmt, sync::Arc, time::Duration};
+
+use bevy::prelude::*;
+
+use crate::components::tabs::{ActiveTab, TabTrigger};
+
+/// Least time between two updates of a value text
+pub const A11Y_VALUE_TEXT_THROTTLE: Duration = Duration::from_millis(250);
+
+/// What kind of widget an entity is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiRole {
+    Button,
+    Checkbox,
+    Radio,
+    Switch,
+    Slider,
+    ProgressBar,
+    Tab,
+    /// Landmark of a labelled page area, like a section
+    Region,
+}
+
+/// State of a widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UiA11yValue {
+    /// Checkboxes, radios and switches
+    Checked(bool),
+    /// Tabs
+    Selected(bool),
+    /// Sliders and progress bars
+    Number { value: f32, min: f32, max: f32 },
+    /// Progress of unknown length
+    Busy,
+}
+
+/// Accessibility metadata of a widget.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiA11y {
+    pub role: UiRole,
+    /// Name announced for the widget
+    pub label: String,
+    /// Longer explanation of what the widget does
+    pub description: Option<String>,
+    pub value: Option<UiA11yValue>,
+    /// The value in words, e.g. "45%", for numeric values
+    pub value_text: Option<String>,
+}
+
+impl UiA11y {
+    pub fn new(role: UiRole, label: impl Into<String>) -> Self {
+        Self {
+            role,
+            label: label.into(),
+            description: None,
+            value: None,
+            value_text: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn value(mut self, value: UiA11yValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+}
+
+/// Widget component whose state is reported as [`UiA11y::value`].
+pub trait A11yState: Component {
+    fn a11y_value(&self) -> UiA11yValue;
+}
+
+/// Keeps the [`UiA11y`] value of `C` widgets in sync with their state.
+pub fn sync_a11y_values<C: A11yState>(mut widgets: Query<(&C, &mut UiA11y), Changed<C>>) {
+    for (state, mut a11y) in &mut widgets {
+        let value = Some(state.a11y_value());
+        if a11y.value != value {
+            a11y.value = value;
+        }
+    }
+}
+
+/// Turns a numeric value, its minimum and maximum into words.
+pub type ValueTextFormatter = Arc<dyn Fn(f32, f32, f32) -> String + Send + Sync>;
+
+/// Share of the range from `min` to `max` that `value` covers, e.g. "45%".
+pub fn percent_value_text(value: f32, min: f32, max: f32) -> String {
+    let range = max - min;
+    let fraction = if range > 0.0 {
+        ((value - min) / range).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    format!("{}%", (fraction * 100.0).round())
+}
+
+/// Describes the numeric [`UiA11y::value`] of its widget in
+/// [`UiA11y::value_text`], see [`update_a11y_value_texts`].
+#[derive(Component, Clone)]
+pub struct A11yValueText {
+    /// Formats the value; `None` uses [`percent_value_text`]
+    pub formatter: Option<ValueTextFormatter>,
+    /// Least time between two updates of the text
+    pub throttle: Duration,
+    /// Time of the last update, `None` before the first
+    last_update: Option<Duration>,
+}
+
+impl Default for A11yValueText {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl fmt::Debug for A11yValueText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("A11yValueText")
+            .field("custom_formatter", &self.formatter.is_some())
+            .field("throttle", &self.throttle)
+            .finish()
+    }
+}
+
+impl A11yValueText {
+    pub fn new(formatter: Option<ValueTextFormatter>) -> Self {
+        Self {
+            formatter,
+            throttle: A11Y_VALUE_TEXT_THROTTLE,
+            last_update: None,
+        }
+    }
+
+    /// The words for `value`, `None` unless it is numeric.
+    pub fn format(&self, value: &UiA11yValue) -> Option<String> {
+        let UiA11yValue::Number { value, min, max } = *value else {
+            return None;
+        };
+        Some(match &self.formatter {
+            Some(formatter) => formatter(value, min, max),
+            None => percent_value_text(value, min, max),
+        })
+    }
+}
+
+/// Updates the [`UiA11y::value_text`] of widgets with an [`A11yValueText`],
+/// at most once per throttle interval. The latest value is always described
+/// eventually, once the interval has passed.
+pub fn update_a11y_value_texts(
+    time: Res<Time>,
+    mut widgets: Query<(&mut UiA11y, &mut A11yValueText)>,
+) {
+    let now = time.elapsed();
+    for (mut a11y, mut value_text) in &mut widgets {
+        let text = a11y.value.and_then(|value| value_text.format(&value));
+        if a11y.value_text == text {
+            continue;
+        }
+        let throttled = value_text
+            .last_update
+            .is_some_and(|last| now.saturating_sub(last) < value_text.throttle);
+        if throttled {
+            continue;
+        }
+        value_text.last_update = Some(now);
+        a11y.value_text = text;
+    }
+}
+
+/// Keeps the selected state of tab triggers in sync with the active tab.
+pub fn sync_tab_a11y_values(mut triggers: Query<(Has<ActiveTab>, &mut UiA11y), With<TabTrigger>>) {
+    for (active, mut a11y) in &mut triggers {
+        let value = Some(UiA11yValue::Selected(active));
+        if a11y.value != value {
+            a11y.value = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{
+        button::ButtonBuilder,
+        checkbox::{CheckboxBuilder, CheckboxComponent},
+        progress::ProgressBuilder,
+        radio::RadioBuilder,
+        slider::{SliderBuilder, SliderComponent},
+        switch::SwitchBuilder,
+        tabs::TabsBuilder,
+    };
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn a11y(world: &World, entity: Entity) -> UiA11y {
+        world.get::<UiA11y>(entity).unwrap().clone()
+    }
+
+    #[test]
+    fn test_builders_attach_roles_and_labels() {
+        let mut world = World::new();
+        let button = world
+            .spawn(ButtonBuilder::new("Save").text("Save changes").build())
+            .id();
+        let checkbox = world
+            .spawn(CheckboxBuilder::new("Terms").label("Accept terms").build())
+            .id();
+        let radio = world.spawn(RadioBuilder::new("dark").build()).id();
+        let switch = world
+            .spawn(
+                SwitchBuilder::new("Notifications")
+                    .a11y_label("Email notifications")
+                    .build(),
+            )
+            .id();
+        let slider = world
+            .spawn(SliderBuilder::new().name("Volume").build())
+            .id();
+
+        assert_eq!(a11y(&world, button).role, UiRole::Button);
+        assert_eq!(a11y(&world, button).label, "Save changes");
+        assert_eq!(a11y(&world, checkbox).role, UiRole::Checkbox);
+        assert_eq!(a11y(&world, checkbox).label, "Accept terms");
+        assert_eq!(
+            a11y(&world, checkbox).value,
+            Some(UiA11yValue::Checked(false))
+        );
+        assert_eq!(a11y(&world, radio).role, UiRole::Radio);
+        assert_eq!(a11y(&world, radio).label, "dark");
+        assert_eq!(a11y(&world, switch).role, UiRole::Switch);
+        assert_eq!(a11y(&world, switch).label, "Email notifications");
+        assert_eq!(a11y(&world, slider).role, UiRole::Slider);
+        assert_eq!(a11y(&world, slider).label, "Volume");
+        assert!(matches!(
+            a11y(&world, slider).value,
+            Some(UiA11yValue::Number { .. })
+        ));
+    }
+
+    #[test]
+    fn test_tab_triggers_are_tabs() {
+        let mut world = World::new();
+        {
+            let mut commands = world.commands();
+            TabsBuilder::new()
+                .triggers(vec!["One", "Two"])
+                .contents(vec![
+                    commands.spawn(Node::default()).id(),
+                    commands.spawn(Node::default()).id(),
+                ])
+                .build(&mut commands);
+        }
+        world.flush();
+
+        let mut tabs: Vec<UiA11y> = world
+            .query_filtered::<&UiA11y, With<TabTrigger>>()
+            .iter(&world)
+            .cloned()
+            .collect();
+        tabs.sort_by(|a, b| a.label.cmp(&b.label));
+        assert_eq!(tabs.len(), 2);
+        assert!(tabs.iter().all(|tab| tab.role == UiRole::Tab));
+        assert_eq!(tabs[0].value, Some(UiA11yValue::Selected(true)));
+        assert_eq!(tabs[1].value, Some(UiA11yValue::Selected(false)));
+    }
+
+    #[test]
+    fn test_value_follows_state() {
+        let mut world = World::new();
+        let checkbox = world.spawn(CheckboxBuilder::new("Terms").build()).id();
+
+        world
+            .get_mut::<CheckboxComponent>(checkbox)
+            .unwrap()
+            .checked = true;
+        world
+            .run_system_once(sync_a11y_values::<CheckboxComponent>)
+            .unwrap();
+
+        assert_eq!(
+            a11y(&world, checkbox).value,
+            Some(UiA11yValue::Checked(true))
+        );
+    }
+
+    #[test]
+    fn test_value_text_formatter_is_used() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let progress = world
+            .spawn(
+                ProgressBuilder::new()
+                    .progress(12.0, 20.0)
+                    .value_text_formatter(|value, _, max| format!("{value} of {max}"))
+                    .build(),
+            )
+            .id();
+        let slider = world.spawn(SliderBuilder::new().value(45.0).build()).id();
+
+        world.run_system_once(update_a11y_value_texts).unwrap();
+
+        assert_eq!(a11y(&world, progress).role, UiRole::ProgressBar);
+        assert_eq!(
+            a11y(&world, progress).value_text.as_deref(),
+            Some("12 of 20")
+        );
+        // Percent of the range by default
+        assert_eq!(a11y(&world, slider).value_text.as_deref(), Some("45%"));
+    }
+
+    #[test]
+    fn test_value_text_is_throttled() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let slider = world.spawn(SliderBuilder::new().value(10.0).build()).id();
+        world.run_system_once(update_a11y_value_texts).unwrap();
+
+        let set_value = |world: &mut World, value: f32| {
+            world.get_mut::<SliderComponent>(slider).unwrap().value = value;
+            world
+                .run_system_once(sync_a11y_values::<SliderComponent>)
+                .unwrap();
+            world.run_system_once(update_a11y_value_texts).unwrap();
+        };
+
+        // Too soon after the first text
+        set_value(&mut world, 20.0);
+        assert_eq!(a11y(&world, slider).value_text.as_deref(), Some("10%"));
+
+        // The latest value is described once the interval has passed
+        world
+            .resource_mut::<Time>()
+            .advance_by(A11Y_VALUE_TEXT_THROTTLE);
+        set_value(&mut world, 30.0);
+        assert_eq!(a11y(&world, slider).value_text.as_deref(), Some("30%"));
+    }
+}
@@ -0,0 +1,168 @@
+use crate::utilities::naming::Namespace;
+use bevy::prelude::*;
+
+/// Container that keeps a fixed width-to-height ratio regardless of the
+/// width it is given, e.g. for media thumbnails and video embeds.
+///
+/// The container takes the full width of its parent and derives its height
+/// from [`ratio`](Self::ratio). When [`fill`](Self::fill) is set, children
+/// are stretched to cover the whole box.
+#[derive(Component, Debug, Clone)]
+pub struct AspectRatioComponent {
+    /// Width divided by height (e.g. `16.0 / 9.0`)
+    pub ratio: f32,
+    /// Whether children are resized to fill the container
+    pub fill: bool,
+}
+
+impl Default for AspectRatioComponent {
+    fn default() -> Self {
+        Self {
+            ratio: 1.0,
+            fill: true,
+        }
+    }
+}
+
+impl AspectRatioComponent {
+    pub fn new(ratio: f32) -> AspectRatioBuilder {
+        AspectRatioBuilder::new(ratio)
+    }
+}
+
+pub struct AspectRatioBuilder {
+    name: String,
+    aspect_ratio: AspectRatioComponent,
+    width: Val,
+    max_width: Val,
+}
+
+impl AspectRatioBuilder {
+    pub fn new(ratio: f32) -> Self {
+        Self {
+            name: "AspectRatio".to_string(),
+            aspect_ratio: AspectRatioComponent {
+                ratio: sanitize_ratio(ratio),
+                ..default()
+            },
+            width: Val::Percent(100.0),
+            max_width: Val::Auto,
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn ratio(mut self, ratio: f32) -> Self {
+        self.aspect_ratio.ratio = sanitize_ratio(ratio);
+        self
+    }
+
+    pub fn ratio_16_9(self) -> Self {
+        self.ratio(16.0 / 9.0)
+    }
+
+    pub fn ratio_4_3(self) -> Self {
+        self.ratio(4.0 / 3.0)
+    }
+
+    pub fn ratio_1_1(self) -> Self {
+        self.ratio(1.0)
+    }
+
+    /// Controls whether children are stretched to fill the container (default: `true`).
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.aspect_ratio.fill = fill;
+        self
+    }
+
+    pub fn width(mut self, width: Val) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn max_width(mut self, max_width: Val) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let node = self.calculate_style();
+
+        (Name::new(self.name), self.aspect_ratio, node)
+    }
+}
+
+impl AspectRatioBuilder {
+    fn calculate_style(&self) -> Node {
+        Node {
+            width: self.width,
+            max_width: self.max_width,
+            height: Val::Auto,
+            aspect_ratio: Some(self.aspect_ratio.ratio),
+            position_type: PositionType::Relative,
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Stretch,
+            overflow: Overflow::clip(),
+            ..default()
+        }
+    }
+}
+
+fn sanitize_ratio(ratio: f32) -> f32 {
+    if ratio.is_finite() && ratio > 0.0 {
+        ratio
+    } else {
+        warn!("Invalid aspect ratio {ratio}, falling back to 1:1");
+        1.0
+    }
+}
+
+/// Stretches the children of filling aspect-ratio containers to 100% × 100%.
+pub fn fill_aspect_ratio_children(
+    containers: Query<(&AspectRatioComponent, &Children), Changed<Children>>,
+    mut nodes: Query<&mut Node, Without<AspectRatioComponent>>,
+) {
+    for (aspect_ratio, children) in &containers {
+        if !aspect_ratio.fill {
+            continue;
+        }
+
+        for child in children.iter() {
+            if let Ok(mut node) = nodes.get_mut(child) {
+                node.width = Val::Percent(100.0);
+                node.height = Val::Percent(100.0);
+            }
+        }
+    }
+}
+
+pub type AspectRatio = AspectRatioComponent;
+
+impl Namespace for AspectRatioBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_uses_requested_ratio() {
+        let node = AspectRatio::new(16.0 / 9.0).calculate_style();
+        assert_eq!(node.aspect_ratio, Some(16.0 / 9.0));
+    }
+
+    #[test]
+    fn test_presets_override_ratio() {
+        let node = AspectRatio::new(2.0).ratio_4_3().calculate_style();
+        assert_eq!(node.aspect_ratio, Some(4.0 / 3.0));
+
+        let node = AspectRatio::new(2.0).ratio_1_1().calculate_style();
+        assert_eq!(node.aspect_ratio, Some(1.0));
+    }
+}
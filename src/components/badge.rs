@@ -36,6 +36,18 @@
 //!     .build();
 //! ```
 //!
+//! ### Counts and Dots
+//! ```rust
+//! // Solid pill showing "99+" from 100 unread messages on
+//! let unread = Badge::count(unread_messages).accent().build();
+//!
+//! // Caps at "9+" instead
+//! let compact = Badge::count(12).max_count(9).size_1().build();
+//!
+//! // Small status circle without text
+//! let online = Badge::dot().color(theme().green).build();
+//! ```
+//!
 //! ### Advanced Configuration
 //! ```rust
 //! use forge_ui::{Badge, BadgeVariant, BadgeSize, theme};
@@ -75,11 +87,27 @@ use crate::{
     components::text::{Text, TextBuilder},
     theme::{
         color::{accent_palette, theme, TextColor, UiColorPalette},
+        radix_theme::{AccentPaletteUser, ThemeTokens, UsesAccentPalette},
         typography::{TextSize, TextWeight},
     },
+    utilities::naming::Namespace,
 };
 use bevy::{ecs::spawn::SpawnWith, prelude::*};
 
+/// Largest count shown in full by [`Badge::count`] unless set otherwise
+pub const BADGE_MAX_COUNT: u32 = 99;
+/// Diameter of a [`Badge::dot`] in pixels
+pub const BADGE_DOT_SIZE: f32 = 8.0;
+
+/// Text of a count badge: the count, or `max+` above `max`.
+pub fn format_badge_count(count: u32, max: u32) -> String {
+    if count > max {
+        format!("{max}+")
+    } else {
+        count.to_string()
+    }
+}
+
 /// Core Badge component that defines the appearance and behavior of a badge UI element.
 /// 
 /// Badges are small, compact UI elements used to display status information, counts,
@@ -98,6 +126,9 @@ pub struct Badge {
     pub variant: BadgeVariant,
     pub size: BadgeSize,
     pub color: UiColorPalette,
+    /// Whether `color` follows the accent color of the theme; set by
+    /// [`BadgeBuilder::accent`]
+    pub follows_accent: bool,
     pub high_contrast: bool,
     pub radius: BadgeRadius,
 }
@@ -114,12 +145,24 @@ impl Default for Badge {
             variant: BadgeVariant::Surface,
             size: BadgeSize::Size2,
             color: theme().gray,
+            follows_accent: false,
             high_contrast: false,
             radius: BadgeRadius::Full,
         }
     }
 }
 
+impl AccentPaletteUser for Badge {
+    fn follows_accent(&self) -> bool {
+        self.follows_accent
+    }
+}
+
+/// Marker for the label spawned from [`BadgeBuilder::text`], whose color
+/// follows the badge palette. Custom text builders keep their own color.
+#[derive(Component, Debug)]
+pub struct BadgeManagedText;
+
 /// Visual variant options for Badge appearance.
 /// 
 /// Each variant provides different levels of visual prominence and contrast,
@@ -164,6 +207,17 @@ pub enum BadgeSize {
     Size3,
 }
 
+impl BadgeSize {
+    /// Minimum width of a count badge, its height with a single digit.
+    pub fn count_min_width(self) -> f32 {
+        match self {
+            BadgeSize::Size1 => 16.0,
+            BadgeSize::Size2 => 20.0,
+            BadgeSize::Size3 => 24.0,
+        }
+    }
+}
+
 /// Border radius options for Badge appearance.
 /// 
 /// Controls the roundness of badge corners, from sharp rectangular
@@ -219,6 +273,12 @@ pub struct BadgeBuilder {
     text_builder: Option<TextBuilder>,
     /// Additional child entities to include in the badge
     children: Vec<Entity>,
+    /// Count shown instead of text, capped at `max_count`
+    count: Option<u32>,
+    /// Largest count shown in full
+    max_count: u32,
+    /// Whether the badge is a dot without content
+    dot: bool,
 }
 
 impl BadgeBuilder {
@@ -236,6 +296,9 @@ impl BadgeBuilder {
             text: None,
             text_builder: None,
             children: Vec::new(),
+            count: None,
+            max_count: BADGE_MAX_COUNT,
+            dot: false,
         }
     }
 
@@ -263,6 +326,7 @@ impl BadgeBuilder {
     /// * `color` - A UiColorPalette from the theme system
     pub fn color(mut self, color: UiColorPalette) -> Self {
         self.badge.color = color;
+        self.badge.follows_accent = false;
         self
     }
 
@@ -308,6 +372,13 @@ impl BadgeBuilder {
         self
     }
 
+    /// Sets the largest count a [`Badge::count`] shows in full; larger
+    /// counts show as `max+`.
+    pub fn max_count(mut self, max: u32) -> Self {
+        self.max_count = max;
+        self
+    }
+
     /// Adds a child entity to the badge.
     /// 
     /// # Arguments
@@ -404,8 +475,10 @@ impl BadgeBuilder {
     
     /// Convenience method to use the accent color palette.
     /// Sets the badge to use the theme's primary accent color.
-    pub fn accent(self) -> Self {
-        self.color(accent_palette())
+    pub fn accent(mut self) -> Self {
+        self.badge.color = accent_palette();
+        self.badge.follows_accent = true;
+        self
     }
 
     /// Convenience method to use the gray color palette.
@@ -450,64 +523,7 @@ impl BadgeBuilder {
         };
 
         // Calculate colors based on variant and contrast
-        let (background_color, border_color, _text_color) = match badge.variant {
-            BadgeVariant::Solid => {
-                if badge.high_contrast {
-                    (
-                        badge.color.solid.with_alpha(1.0),
-                        badge.color.solid,
-                        badge.color.text_contrast,
-                    )
-                } else {
-                    (
-                        badge.color.solid.with_alpha(0.9),
-                        badge.color.solid,
-                        badge.color.text_contrast,
-                    )
-                }
-            }
-            BadgeVariant::Soft => {
-                if badge.high_contrast {
-                    (
-                        badge.color.bg.with_alpha(0.8),
-                        Color::NONE,
-                        badge.color.text,
-                    )
-                } else {
-                    (
-                        badge.color.bg_subtle.with_alpha(0.6),
-                        Color::NONE,
-                        badge.color.text,
-                    )
-                }
-            }
-            BadgeVariant::Surface => {
-                if badge.high_contrast {
-                    (
-                        badge.color.bg_subtle.with_alpha(0.5),
-                        badge.color.border,
-                        badge.color.text,
-                    )
-                } else {
-                    (
-                        badge.color.bg_subtle.with_alpha(0.3),
-                        badge.color.border.with_alpha(0.7),
-                        badge.color.text,
-                    )
-                }
-            }
-            BadgeVariant::Outline => {
-                if badge.high_contrast {
-                    (Color::NONE, badge.color.border, badge.color.text)
-                } else {
-                    (
-                        Color::NONE,
-                        badge.color.border.with_alpha(0.8),
-                        badge.color.text.with_alpha(0.9),
-                    )
-                }
-            }
-        };
+        let (background_color, border_color, _text_color) = badge.calculate_colors();
 
         // Calculate border width for outline variant
         let border = if badge.variant == BadgeVariant::Outline {
@@ -523,26 +539,43 @@ impl BadgeBuilder {
         };
 
         let text_color_enum = TextColor::Custom(_text_color);
-        let display_text = self.text.clone().unwrap_or_default();
+        let display_text = match self.count {
+            Some(count) => format_badge_count(count, self.max_count),
+            None => self.text.clone().unwrap_or_default(),
+        };
         let text_builder = self.text_builder.clone();
 
+        let mut node = Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            padding: UiRect {
+                left: Val::Px(horizontal_padding),
+                right: Val::Px(horizontal_padding),
+                top: Val::Px(vertical_padding),
+                bottom: Val::Px(vertical_padding),
+            },
+            border,
+            ..Node::default()
+        };
+        if self.count.is_some() {
+            // Single digits stay circles, longer counts grow into pills
+            node.min_width = Val::Px(badge.size.count_min_width());
+        }
+        let (display_text, text_builder) = if self.dot {
+            node.width = Val::Px(BADGE_DOT_SIZE);
+            node.height = Val::Px(BADGE_DOT_SIZE);
+            node.padding = UiRect::ZERO;
+            (String::new(), None)
+        } else {
+            (display_text, text_builder)
+        };
+
         (
             badge,
             Name::new(self.name),
-            Node {
-                display: Display::Flex,
-                flex_direction: FlexDirection::Row,
-                align_items: AlignItems::Center,
-                justify_content: JustifyContent::Center,
-                padding: UiRect {
-                    left: Val::Px(horizontal_padding),
-                    right: Val::Px(horizontal_padding),
-                    top: Val::Px(vertical_padding),
-                    bottom: Val::Px(vertical_padding),
-                },
-                border,
-                ..Node::default()
-            },
+            node,
             BackgroundColor(background_color),
             BorderColor(border_color),
             BorderRadius::from(border_radius),
@@ -550,14 +583,15 @@ impl BadgeBuilder {
                 if let Some(builder) = text_builder {
                     parent.spawn(builder.center().build());
                 } else if !display_text.is_empty() {
-                    parent.spawn(
+                    parent.spawn((
                         Text::label(display_text)
                             .color(text_color_enum)
                             .size(text_size)
                             .weight(TextWeight::Medium)
                             .center()
                             .build(),
-                    );
+                        BadgeManagedText,
+                    ));
                 }
             })),
         )
@@ -585,4 +619,164 @@ impl Badge {
     pub fn builder(name: impl Into<String>) -> BadgeBuilder {
         BadgeBuilder::new(name)
     }
+
+    /// Solid pill badge showing `count`, capped at `99+` (see
+    /// [`BadgeBuilder::max_count`]), e.g. for unread notifications.
+    pub fn count(count: u32) -> BadgeBuilder {
+        let mut builder = BadgeBuilder::new("Badge Count").solid().pill();
+        builder.count = Some(count);
+        builder
+    }
+
+    /// Small solid circle without text, e.g. for an online status.
+    pub fn dot() -> BadgeBuilder {
+        let mut builder = BadgeBuilder::new("Badge Dot").solid().pill();
+        builder.dot = true;
+        builder
+    }
+
+    /// Background, border and text colors for the badge's variant and contrast.
+    pub fn calculate_colors(&self) -> (Color, Color, Color) {
+        match self.variant {
+            BadgeVariant::Solid => {
+                if self.high_contrast {
+                    (
+                        self.color.solid.with_alpha(1.0),
+                        self.color.solid,
+                        self.color.text_contrast,
+                    )
+                } else {
+                    (
+                        self.color.solid.with_alpha(0.9),
+                        self.color.solid,
+                        self.color.text_contrast,
+                    )
+                }
+            }
+            BadgeVariant::Soft => {
+                if self.high_contrast {
+                    (
+                        self.color.bg.with_alpha(0.8),
+                        Color::NONE,
+                        self.color.text,
+                    )
+                } else {
+                    (
+                        self.color.bg_subtle.with_alpha(0.6),
+                        Color::NONE,
+                        self.color.text,
+                    )
+                }
+            }
+            BadgeVariant::Surface => {
+                if self.high_contrast {
+                    (
+                        self.color.bg_subtle.with_alpha(0.5),
+                        self.color.border,
+                        self.color.text,
+                    )
+                } else {
+                    (
+                        self.color.bg_subtle.with_alpha(0.3),
+                        self.color.border.with_alpha(0.7),
+                        self.color.text,
+                    )
+                }
+            }
+            BadgeVariant::Outline => {
+                if self.high_contrast {
+                    (Color::NONE, self.color.border, self.color.text)
+                } else {
+                    (
+                        Color::NONE,
+                        self.color.border.with_alpha(0.8),
+                        self.color.text.with_alpha(0.9),
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Re-applies the accent palette to badges built with `.accent()` after the
+/// accent color of the `RadixTheme` changed.
+pub fn refresh_badge_accent(
+    tokens: Res<ThemeTokens>,
+    mut badges: Query<
+        (
+            &mut Badge,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            Option<&Children>,
+        ),
+        With<UsesAccentPalette>,
+    >,
+    mut labels: Query<&mut bevy::prelude::TextColor, With<BadgeManagedText>>,
+) {
+    for (mut badge, mut background, mut border, children) in &mut badges {
+        if badge.color == tokens.accent {
+            continue;
+        }
+        badge.color = tokens.accent.clone();
+
+        let (background_color, border_color, text_color) = badge.calculate_colors();
+        background.0 = background_color;
+        border.0 = border_color;
+        let Some(children) = children else {
+            continue;
+        };
+        for child in children.iter() {
+            if let Ok(mut label) = labels.get_mut(child) {
+                label.0 = text_color;
+            }
+        }
+    }
+}
+
+impl Namespace for BadgeBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_above_max_are_capped() {
+        assert_eq!(format_badge_count(0, BADGE_MAX_COUNT), "0");
+        assert_eq!(format_badge_count(99, BADGE_MAX_COUNT), "99");
+        assert_eq!(format_badge_count(100, BADGE_MAX_COUNT), "99+");
+        assert_eq!(format_badge_count(12, 9), "9+");
+    }
+
+    #[test]
+    fn test_count_badge_spawns_capped_label() {
+        let mut world = World::new();
+        let badge = world.spawn(Badge::count(150).build()).id();
+        let label = world.get::<Children>(badge).unwrap()[0];
+        assert_eq!(world.get::<bevy::ui::widget::Text>(label).unwrap().0, "99+");
+        let node = world.get::<Node>(badge).unwrap();
+        assert_eq!(node.min_width, Val::Px(BadgeSize::Size2.count_min_width()));
+        assert_eq!(node.width, Val::Auto);
+
+        let badge = world.spawn(Badge::count(150).max_count(999).build()).id();
+        let label = world.get::<Children>(badge).unwrap()[0];
+        assert_eq!(world.get::<bevy::ui::widget::Text>(label).unwrap().0, "150");
+    }
+
+    #[test]
+    fn test_dot_is_small_circle_without_text() {
+        let mut world = World::new();
+        let dot = world.spawn(Badge::dot().build()).id();
+        assert!(world
+            .get::<Children>(dot)
+            .is_none_or(|children| children.is_empty()));
+        let node = world.get::<Node>(dot).unwrap();
+        assert_eq!(node.width, Val::Px(BADGE_DOT_SIZE));
+        assert_eq!(node.height, Val::Px(BADGE_DOT_SIZE));
+        assert_eq!(node.padding, UiRect::ZERO);
+        assert_eq!(world.get::<Badge>(dot).unwrap().radius, BadgeRadius::Full);
+    }
 }
@@ -0,0 +1,362 @@
+//! Two-way binding of control values to resource fields.
+//!
+//! A bound control starts out with the value of a `String` field of an app
+//! resource, writes its value back into the field whenever the user changes
+//! it, and follows the field when the app changes it. Selects bind their
+//! selected value, tabs their active tab (the trigger label).
+//!
+//! Bindings are created with the builders' `.bind()`, which takes an accessor
+//! to the field. Syncing needs [`ValueBindingPlugin`] once per bound resource
+//! type:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use ui::components::{SelectBuilder, ValueBindingPlugin};
+//!
+//! #[derive(Resource, Default)]
+//! struct Filters {
+//!     region: String,
+//! }
+//!
+//! app.init_resource::<Filters>()
+//!     .add_plugins(ValueBindingPlugin::<Filters>::default());
+//!
+//! commands.spawn(
+//!     SelectBuilder::new()
+//!         .options(vec![("eu", "Europe"), ("us", "United States")])
+//!         .bind::<Filters>(|filters| &mut filters.region)
+//!         .build(),
+//! );
+//! ```
+//!
+//! A value the control can't take (no such option or tab, or a disabled tab)
+//! is reverted in the resource to the control's current value.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use crate::components::{
+    button::Button as UiButton,
+    select::{set_select_trigger_label, SelectComponent},
+    tabs::{
+        handle_trigger_clicks, mirror_tabs_value, ActiveTab, TabSwitcher, TabTrigger, TabsRoot,
+        TabsValue,
+    },
+};
+
+/// Inserts a [`BoundValue`] of the bound resource type into a control.
+pub type ValueBinder = Arc<dyn Fn(&mut EntityCommands) + Send + Sync>;
+
+/// Binding to insert once a control is spawned; removed once inserted.
+#[derive(Component, Clone, Default)]
+pub struct ValueBindingSpawner(pub Option<ValueBinder>);
+
+/// Binder for [`BoundValue`]s accessing their field with `accessor`.
+pub fn value_binder<R: Resource>(accessor: fn(&mut R) -> &mut String) -> ValueBinder {
+    Arc::new(move |entity| {
+        entity.insert(BoundValue::new(accessor));
+    })
+}
+
+/// Binds the value of the control it sits on to a field of resource `R`.
+#[derive(Component)]
+pub struct BoundValue<R: Resource> {
+    accessor: fn(&mut R) -> &mut String,
+    /// Value both sides agreed on at the last sync; `None` before the first
+    synced: Option<String>,
+}
+
+impl<R: Resource> BoundValue<R> {
+    pub fn new(accessor: fn(&mut R) -> &mut String) -> Self {
+        Self {
+            accessor,
+            synced: None,
+        }
+    }
+}
+
+/// Which side of a binding has to be updated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BindingSync {
+    /// Both sides agree
+    None,
+    /// The control changed; write its value into the resource
+    ToResource(String),
+    /// The resource changed (or the binding is new); apply it to the control
+    ToControl(String),
+}
+
+/// Decides the sync direction from the last synced value and both sides.
+///
+/// New bindings take the resource value. Afterwards the control wins when
+/// both changed, as that change was made by the user.
+pub fn binding_sync(synced: Option<&str>, control: &str, resource: &str) -> BindingSync {
+    match synced {
+        None if control == resource => BindingSync::None,
+        None => BindingSync::ToControl(resource.to_string()),
+        Some(synced) if control != synced => BindingSync::ToResource(control.to_string()),
+        Some(synced) if resource != synced => BindingSync::ToControl(resource.to_string()),
+        Some(_) => BindingSync::None,
+    }
+}
+
+/// Syncs `binding` for the control's `value`, returning the value to apply to
+/// the control, if any.
+fn sync_binding<R: Resource>(
+    binding: &mut BoundValue<R>,
+    resource: &mut ResMut<R>,
+    value: &str,
+) -> Option<String> {
+    // Reading through the accessor must not mark the resource as changed
+    let field = (binding.accessor)(resource.bypass_change_detection());
+    match binding_sync(binding.synced.as_deref(), value, field) {
+        BindingSync::None => {
+            binding.synced = Some(value.to_string());
+            None
+        }
+        BindingSync::ToResource(value) => {
+            *(binding.accessor)(&mut **resource) = value.clone();
+            binding.synced = Some(value);
+            None
+        }
+        BindingSync::ToControl(value) => {
+            binding.synced = Some(value.clone());
+            Some(value)
+        }
+    }
+}
+
+/// System that inserts the bindings of new controls.
+pub fn insert_value_bindings(
+    mut commands: Commands,
+    query: Query<(Entity, &ValueBindingSpawner), Added<ValueBindingSpawner>>,
+) {
+    for (entity, spawner) in &query {
+        let mut entity = commands.entity(entity);
+        entity.remove::<ValueBindingSpawner>();
+        if let Some(bind) = &spawner.0 {
+            bind(&mut entity);
+        }
+    }
+}
+
+/// System that syncs selects bound to resource `R`.
+///
+/// An unselected select counts as the empty string.
+pub fn sync_select_bindings<R: Resource>(
+    resource: Option<ResMut<R>>,
+    mut selects: Query<(Entity, &mut SelectComponent, &mut BoundValue<R>)>,
+    children_query: Query<&Children>,
+    mut text_query: Query<&mut bevy::ui::widget::Text>,
+) {
+    let Some(mut resource) = resource else {
+        return;
+    };
+
+    for (entity, mut select, mut binding) in &mut selects {
+        let current = select.selected_value.clone().unwrap_or_default();
+        let Some(value) = sync_binding(&mut binding, &mut resource, &current) else {
+            continue;
+        };
+
+        let label = if value.is_empty() {
+            Some(select.placeholder.clone())
+        } else {
+            select
+                .options
+                .iter()
+                .find(|(option, _)| *option == value)
+                .map(|(_, label)| label.clone())
+        };
+        // Unknown values are written back over on the next sync
+        if let Some(label) = label {
+            select.selected_value = (!value.is_empty()).then_some(value);
+            set_select_trigger_label(entity, &label, &children_query, &mut text_query);
+        }
+    }
+}
+
+/// System that syncs tabs bound to resource `R`.
+///
+/// External changes activate the matching trigger like a click would, but
+/// emit no [`ButtonClickEvent`](crate::components::ButtonClickEvent).
+pub fn sync_tabs_bindings<R: Resource>(
+    resource: Option<ResMut<R>>,
+    mut roots: Query<(Entity, &mut BoundValue<R>), With<TabsRoot>>,
+    triggers: Query<(Entity, &TabTrigger, &UiButton, Has<ActiveTab>)>,
+    mut values: Query<&mut TabsValue>,
+    mut tabs: TabSwitcher,
+) {
+    let Some(mut resource) = resource else {
+        return;
+    };
+
+    for (root, mut binding) in &mut roots {
+        let tab_triggers = || {
+            triggers
+                .iter()
+                .filter(move |(_, trigger, _, _)| trigger.tabs == root)
+        };
+        let current = tab_triggers()
+            .find(|(_, _, _, active)| *active)
+            .map(|(_, trigger, _, _)| trigger.value.clone())
+            .unwrap_or_default();
+        let Some(value) = sync_binding(&mut binding, &mut resource, &current) else {
+            continue;
+        };
+
+        // Disabled tabs can't be activated, so their value is reverted
+        if let Some((entity, trigger, _, _)) = tab_triggers()
+            .find(|(_, trigger, button, _)| trigger.value == value && button.is_interactive())
+        {
+            if tabs.switch_to(entity, trigger) {
+                mirror_tabs_value(&mut values, trigger);
+            }
+        }
+    }
+}
+
+/// Syncs the controls bound to resource `R`; add once per bound resource type.
+pub struct ValueBindingPlugin<R: Resource>(PhantomData<fn() -> R>);
+
+impl<R: Resource> Default for ValueBindingPlugin<R> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R: Resource> Plugin for ValueBindingPlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                sync_select_bindings::<R>,
+                sync_tabs_bindings::<R>.before(handle_trigger_clicks),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{button::ButtonClickEvent, tabs::TabsBuilder};
+
+    #[derive(Resource, Default)]
+    struct MockState {
+        region: String,
+        section: String,
+    }
+
+    #[test]
+    fn test_binding_sync_direction() {
+        assert_eq!(
+            binding_sync(None, "", "eu"),
+            BindingSync::ToControl("eu".to_string())
+        );
+        assert_eq!(binding_sync(None, "eu", "eu"), BindingSync::None);
+        assert_eq!(
+            binding_sync(Some("eu"), "us", "eu"),
+            BindingSync::ToResource("us".to_string())
+        );
+        assert_eq!(
+            binding_sync(Some("eu"), "eu", "us"),
+            BindingSync::ToControl("us".to_string())
+        );
+        // The user's change wins over a simultaneous external one
+        assert_eq!(
+            binding_sync(Some("eu"), "us", "apac"),
+            BindingSync::ToResource("us".to_string())
+        );
+    }
+
+    #[test]
+    fn test_select_binding_syncs_both_ways() {
+        let mut world = World::new();
+        world.insert_resource(MockState {
+            region: "eu".to_string(),
+            ..default()
+        });
+        let select = world
+            .spawn((
+                SelectComponent {
+                    options: vec![
+                        ("eu".to_string(), "Europe".to_string()),
+                        ("us".to_string(), "United States".to_string()),
+                    ],
+                    ..default()
+                },
+                BoundValue::<MockState>::new(|state| &mut state.region),
+            ))
+            .id();
+        let sync = world.register_system(sync_select_bindings::<MockState>);
+        let selected = |world: &World| {
+            world
+                .get::<SelectComponent>(select)
+                .unwrap()
+                .selected_value
+                .clone()
+        };
+
+        // Initialized from the resource
+        world.run_system(sync).unwrap();
+        assert_eq!(selected(&world), Some("eu".to_string()));
+
+        // External change
+        world.resource_mut::<MockState>().region = "us".to_string();
+        world.run_system(sync).unwrap();
+        assert_eq!(selected(&world), Some("us".to_string()));
+
+        // User change
+        world
+            .get_mut::<SelectComponent>(select)
+            .unwrap()
+            .selected_value = Some("eu".to_string());
+        world.run_system(sync).unwrap();
+        assert_eq!(world.resource::<MockState>().region, "eu");
+
+        // Unknown values are reverted
+        world.resource_mut::<MockState>().region = "mars".to_string();
+        world.run_system(sync).unwrap();
+        world.run_system(sync).unwrap();
+        assert_eq!(selected(&world), Some("eu".to_string()));
+        assert_eq!(world.resource::<MockState>().region, "eu");
+    }
+
+    #[test]
+    fn test_tabs_binding_activates_tab_from_resource() {
+        let mut world = World::new();
+        world.init_resource::<Events<ButtonClickEvent>>();
+        world.insert_resource(MockState {
+            section: "Two".to_string(),
+            ..default()
+        });
+        {
+            let mut commands = world.commands();
+            TabsBuilder::new()
+                .triggers(vec!["One", "Two"])
+                .contents(vec![
+                    commands.spawn(Node::default()).id(),
+                    commands.spawn(Node::default()).id(),
+                ])
+                .bind::<MockState>(|state| &mut state.section)
+                .build(&mut commands);
+        }
+        world.flush();
+        let sync = world.register_system(sync_tabs_bindings::<MockState>);
+        let active = |world: &mut World| {
+            world
+                .query_filtered::<&TabTrigger, With<ActiveTab>>()
+                .iter(world)
+                .map(|trigger| trigger.value.clone())
+                .collect::<Vec<_>>()
+        };
+
+        world.run_system(sync).unwrap();
+        assert_eq!(active(&mut world), vec!["Two"]);
+        assert_eq!(world.resource::<MockState>().section, "Two");
+        // Nobody clicked, so click listeners and sounds stay quiet
+        assert!(world.resource::<Events<ButtonClickEvent>>().is_empty());
+    }
+}
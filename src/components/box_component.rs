@@ -1,7 +1,14 @@
 use crate::{
+    components::hover_highlight::{spawn_hover_highlight, HoverableRow},
     theme::{
         color::{accent_palette, UiColorPalette},
-        layout::{UiLayout, UiRadius, UiSpacing},
+        deferred_style::DeferredThemeStyle,
+        layout::{Elevation, ElevationShadow, UiLayout, UiRadius, UiSpacing},
+    },
+    utilities::{
+        naming::Namespace,
+        node_edit::{NodeEdits, WithNode},
+        sizing::NodeSizing,
     },
 };
 use bevy::prelude::*;
@@ -57,7 +64,7 @@ use bevy_picking::prelude::Pickable;
 ///
 /// ## Responsive container with max-width
 /// ```rust
-/// // Content container with responsive width constraints
+/// // Content container with responsive width constraints, centered horizontally
 /// let container = BoxComponent::container_3("main-content")  // 880px max-width
 ///     .padding_x(Val::Px(20.0))
 ///     .padding_y(Val::Px(40.0))
@@ -118,11 +125,15 @@ use bevy_picking::prelude::Pickable;
 /// - Keyboard navigation support for interactive variants
 /// - Color contrast maintained across all theme variants
 /// - Screen reader compatibility through semantic markup patterns
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+#[component(on_add = spawn_hover_highlight::<BoxComponent>)]
 pub struct BoxComponent {
     pub variant: BoxVariant,
     pub color_palette: UiColorPalette,
     pub styling_config: BoxStyling,
+    /// Background while hovered, set by [`BoxBuilder::hoverable_row`]
+    pub hover_highlight: Option<Color>,
 }
 
 impl Default for BoxComponent {
@@ -131,10 +142,17 @@ impl Default for BoxComponent {
             variant: BoxVariant::Surface,
             color_palette: accent_palette(),
             styling_config: BoxStyling::default(),
+            hover_highlight: None,
         }
     }
 }
 
+impl HoverableRow for BoxComponent {
+    fn hover_color(&self) -> Option<Color> {
+        self.hover_highlight
+    }
+}
+
 /// Container size presets following Radix UI specifications
 ///
 /// Provides standardized max-width constraints for content containers:
@@ -142,7 +160,7 @@ impl Default for BoxComponent {
 /// - Size2: 688px (tablet, medium content)
 /// - Size3: 880px (desktop, standard content)
 /// - Size4: 1136px (wide desktop, large content)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 pub enum ContainerSize {
     /// 448px max-width for mobile-first, compact content
     Size1,
@@ -182,7 +200,7 @@ pub const CONTAINER_SIZE_4: f32 = 1136.0;
 /// - Ghost: Transparent with hover background for minimal cards
 /// - Outline: Border-only appearance for lightweight containers
 /// - Container: Max-width constrained layout containers
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
 pub enum BoxVariant {
     /// Subtle background for content areas (default)
     #[default]
@@ -212,6 +230,7 @@ pub enum BoxVariant {
 /// - `background_alpha`: Controls background transparency (0.0 = fully transparent, 1.0 = fully opaque)
 /// - `border_width`: Optional border thickness in pixels
 /// - `has_shadow`: Whether to apply drop shadow effect
+/// - `elevation`: Explicit shadow level; `has_shadow` alone means `Elevation::Sm`
 /// - `explicit_background`: Override theme background color with custom color
 /// - `explicit_border`: Override theme border color with custom color
 ///
@@ -226,11 +245,12 @@ pub enum BoxVariant {
 /// When explicit colors are not provided, the styling system automatically selects
 /// appropriate colors from the current theme palette based on the component variant
 /// and the active color scheme (light/dark mode).
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Reflect)]
 pub struct BoxStyling {
     pub background_alpha: f32,
     pub border_width: Option<f32>,
     pub has_shadow: bool,
+    pub elevation: Option<Elevation>,
     pub explicit_background: Option<Color>,
     pub explicit_border: Option<Color>,
 }
@@ -435,6 +455,10 @@ pub struct BoxBuilder {
     node: Node,
     explicit_colors: ExplicitColors,
     children: Vec<Entity>,
+    deferred: DeferredThemeStyle,
+    visibility: Visibility,
+    hoverable: bool,
+    node_edits: NodeEdits,
 }
 
 #[derive(Default)]
@@ -478,9 +502,33 @@ impl BoxBuilder {
             node: Node::default(),
             explicit_colors: ExplicitColors::default(),
             children: Vec::new(),
+            deferred: DeferredThemeStyle::default(),
+            visibility: Visibility::Inherited,
+            hoverable: false,
+            node_edits: NodeEdits::default(),
         }
     }
 
+    /// Builds the node visible or hidden; hidden nodes keep their space in
+    /// the layout. See [`ShowWhen`](crate::utilities::ShowWhen) to follow an
+    /// app state instead.
+    pub fn visible_if(mut self, visible: bool) -> Self {
+        self.visibility = if visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        self
+    }
+
+    /// Makes the node a list row that shows the palette's `bg_hover` as its
+    /// background while hovered. See
+    /// [`HoverHighlight`](crate::components::hover_highlight::HoverHighlight).
+    pub fn hoverable_row(mut self) -> Self {
+        self.hoverable = true;
+        self
+    }
+
     // =========================================================================
     // VARIANT CONTROL METHODS
     // =========================================================================
@@ -522,10 +570,20 @@ impl BoxBuilder {
     }
 
     /// Use container variant with specified size
+    ///
+    /// The container fills the available width up to the size's max-width and
+    /// is centered horizontally with auto margins.
     pub fn container(mut self, size: ContainerSize) -> Self {
         self.box_config.variant = BoxVariant::Container(size);
         self.node.max_width = Val::Px(size.to_pixels());
         self.node.width = Val::Percent(100.0);
+        self.centered()
+    }
+
+    /// Center the box horizontally (auto left and right margins)
+    pub fn centered(mut self) -> Self {
+        self.node.margin.left = Val::Auto;
+        self.node.margin.right = Val::Auto;
         self
     }
 
@@ -679,27 +737,23 @@ impl BoxBuilder {
         self
     }
 
-    // Theme-integrated padding methods
+    // Theme-integrated padding methods, resolved after spawn (see DeferredThemeStyle);
+    // they win over pixel paddings whatever the call order
     /// Set padding using theme spacing level
     pub fn pad(mut self, level: SpacingLevel) -> Self {
-        let padding = level.to_val(&UiLayout::default().padding);
-        self.node.padding = UiRect::all(padding);
+        self.deferred = self.deferred.pad(level);
         self
     }
 
     /// Set horizontal padding using theme spacing level
     pub fn pad_x(mut self, level: SpacingLevel) -> Self {
-        let padding = level.to_val(&UiLayout::default().padding);
-        self.node.padding.left = padding;
-        self.node.padding.right = padding;
+        self.deferred = self.deferred.pad_x(level);
         self
     }
 
     /// Set vertical padding using theme spacing level
     pub fn pad_y(mut self, level: SpacingLevel) -> Self {
-        let padding = level.to_val(&UiLayout::default().padding);
-        self.node.padding.top = padding;
-        self.node.padding.bottom = padding;
+        self.deferred = self.deferred.pad_y(level);
         self
     }
 
@@ -868,6 +922,12 @@ impl BoxBuilder {
         self
     }
 
+    /// Center the box in its grid cell on both axes
+    pub fn center_self(self) -> Self {
+        self.justify_self(JustifySelf::Center)
+            .align_self(AlignSelf::Center)
+    }
+
     // =========================================================================
     // GRID CHILD CONTROL METHODS
     // =========================================================================
@@ -952,10 +1012,9 @@ impl BoxBuilder {
     // BORDER RADIUS CONTROL METHODS
     // =========================================================================
 
-    /// Set border radius using theme radius level
-    pub fn radius(self, level: RadiusLevel) -> Self {
-        let _radius = level.to_val(&UiLayout::default().radius);
-        // Note: BorderRadius will be calculated in build() method
+    /// Set border radius using theme radius level, resolved after spawn
+    pub fn radius(mut self, level: RadiusLevel) -> Self {
+        self.deferred = self.deferred.radius(level);
         self
     }
 
@@ -995,6 +1054,48 @@ impl BoxBuilder {
         self.border_width(1.0)
     }
 
+    /// Set the border width of each side, e.g. `(2.0, 0.0, 0.0, 0.0)` for a
+    /// top accent. Rounded corners stay: the inner curve of each corner
+    /// narrows with the widths of its two sides, fading out where they're 0.
+    pub fn border_sides(mut self, top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        self.node.border =
+            UiRect::new(Val::Px(left), Val::Px(right), Val::Px(top), Val::Px(bottom));
+        self.with_side_border(top.max(right).max(bottom).max(left))
+    }
+
+    /// Set the top border width, keeping the other sides
+    pub fn border_top(mut self, width: f32) -> Self {
+        self.node.border.top = Val::Px(width);
+        self.with_side_border(width)
+    }
+
+    /// Set the bottom border width, keeping the other sides
+    pub fn border_bottom(mut self, width: f32) -> Self {
+        self.node.border.bottom = Val::Px(width);
+        self.with_side_border(width)
+    }
+
+    /// Set the left and right border widths, keeping top and bottom
+    pub fn border_x(mut self, width: f32) -> Self {
+        self.node.border.left = Val::Px(width);
+        self.node.border.right = Val::Px(width);
+        self.with_side_border(width)
+    }
+
+    /// Set the top and bottom border widths, keeping left and right
+    pub fn border_y(mut self, width: f32) -> Self {
+        self.node.border.top = Val::Px(width);
+        self.node.border.bottom = Val::Px(width);
+        self.with_side_border(width)
+    }
+
+    /// Records a border, so it gets the palette's border color
+    fn with_side_border(mut self, width: f32) -> Self {
+        let styling = &mut self.box_config.styling_config;
+        styling.border_width = Some(styling.border_width.unwrap_or(0.0).max(width));
+        self
+    }
+
     // =========================================================================
     // VISUAL ENHANCEMENT METHODS
     // =========================================================================
@@ -1005,6 +1106,12 @@ impl BoxBuilder {
         self
     }
 
+    /// Set the shadow level (overrides the variant's default shadow)
+    pub fn elevation(mut self, elevation: Elevation) -> Self {
+        self.box_config.styling_config.elevation = Some(elevation);
+        self
+    }
+
     /// Set background alpha for transparency
     pub fn background_alpha(mut self, alpha: f32) -> Self {
         self.box_config.styling_config.background_alpha = alpha.clamp(0.0, 1.0);
@@ -1088,8 +1195,19 @@ impl BoxBuilder {
         styling
     }
 
+    /// Calculate the drop shadow from the elevation or the variant's shadow
+    fn calculate_shadow(&self) -> (ElevationShadow, BoxShadow) {
+        let styling = self.calculate_styling();
+        let elevation = styling.elevation.unwrap_or(if styling.has_shadow {
+            Elevation::Sm
+        } else {
+            Elevation::None
+        });
+        ElevationShadow::bundle(elevation)
+    }
+
     /// Calculate background color based on variant and configuration
-    fn calculate_background_color(&self) -> BackgroundColor {
+    pub(crate) fn calculate_background_color(&self) -> BackgroundColor {
         if let Some(explicit_color) = self.box_config.styling_config.explicit_background {
             return BackgroundColor(explicit_color);
         }
@@ -1144,10 +1262,15 @@ impl BoxBuilder {
 }
 
 impl BoxBuilder {
-    pub fn build(self) -> impl Bundle {
+    pub fn build(mut self) -> impl Bundle {
         let background_color = self.calculate_background_color();
         let border_color = self.calculate_border_color();
         let border_radius = self.calculate_border_radius();
+        let shadow = self.calculate_shadow();
+        if self.hoverable {
+            self.box_config.hover_highlight = Some(self.box_config.color_palette.bg_hover);
+        }
+        self.node_edits.apply(&mut self.node);
 
         (
             Name::new(self.name),
@@ -1156,10 +1279,91 @@ impl BoxBuilder {
             background_color,
             border_color,
             border_radius,
+            shadow,
             Pickable::default(),
+            self.deferred,
+            self.visibility,
         )
     }
 }
 
+impl NodeSizing for BoxBuilder {
+    fn node_mut(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl Namespace for BoxBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+impl WithNode for BoxBuilder {
+    fn node_edits_mut(&mut self) -> &mut NodeEdits {
+        &mut self.node_edits
+    }
+}
+
 // Convenience type alias
 pub type Box = BoxComponent;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_container_presets_cap_width_and_center() {
+        let presets = [
+            (BoxComponent::container_1("shell"), CONTAINER_SIZE_1),
+            (BoxComponent::container_2("shell"), CONTAINER_SIZE_2),
+            (BoxComponent::container_3("shell"), CONTAINER_SIZE_3),
+            (BoxComponent::container_4("shell"), CONTAINER_SIZE_4),
+        ];
+        for (builder, max_width) in presets {
+            assert_eq!(builder.node.max_width, Val::Px(max_width));
+            assert_eq!(builder.node.width, Val::Percent(100.0));
+            assert_eq!(builder.node.margin.left, Val::Auto);
+            assert_eq!(builder.node.margin.right, Val::Auto);
+        }
+    }
+
+    #[test]
+    fn test_centered_keeps_vertical_margins() {
+        let builder = BoxComponent::new("shell")
+            .margin_y(Val::Px(16.0))
+            .centered();
+        assert_eq!(builder.node.margin.top, Val::Px(16.0));
+        assert_eq!(builder.node.margin.left, Val::Auto);
+        assert_eq!(builder.node.margin.right, Val::Auto);
+    }
+
+    #[test]
+    fn test_border_sides_set_node_border() {
+        let builder = BoxComponent::new("accent").border_sides(4.0, 1.0, 2.0, 3.0);
+        assert_eq!(
+            builder.node.border,
+            UiRect::new(Val::Px(3.0), Val::Px(1.0), Val::Px(4.0), Val::Px(2.0))
+        );
+        assert_eq!(builder.box_config.styling_config.border_width, Some(4.0));
+
+        // Single sides keep the others
+        let row = BoxComponent::new("row").border_x(1.0).border_bottom(2.0);
+        assert_eq!(
+            row.node.border,
+            UiRect::new(Val::Px(1.0), Val::Px(1.0), Val::Px(0.0), Val::Px(2.0))
+        );
+        let card = BoxComponent::new("card").border_y(1.0).border_top(3.0);
+        assert_eq!(
+            card.node.border,
+            UiRect::new(Val::Px(0.0), Val::Px(0.0), Val::Px(3.0), Val::Px(1.0))
+        );
+
+        // Side borders get the palette color and keep the rounded corners
+        assert_ne!(card.calculate_border_color().0, Color::NONE);
+        assert_eq!(
+            card.calculate_border_radius(),
+            BoxComponent::new("plain").calculate_border_radius()
+        );
+    }
+}
@@ -5,6 +5,8 @@
 
 use bevy::prelude::*;
 
+use crate::theme::motion::UiMotion;
+
 /// Component that defines a loading spinner animation for buttons.
 ///
 /// The spinner rotates continuously when the button is in a loading state,
@@ -50,8 +52,12 @@ impl Default for SpinnerAnimation {
 /// - `spinners`: Query for all entities with spinner animations
 pub fn animate_loading_spinners(
     time: Res<Time>,
+    motion: Res<UiMotion>,
     mut spinners: Query<(&mut Transform, &SpinnerAnimation)>,
 ) {
+    if motion.reduced {
+        return;
+    }
     for (mut transform, spinner) in spinners.iter_mut() {
         let rotation_delta = spinner.rotation_speed * time.delta_secs();
         transform.rotation *= Quat::from_rotation_z(rotation_delta.to_radians());
@@ -64,8 +70,8 @@ pub fn animate_loading_spinners(
 /// It loads the spinner texture asset and applies it to all entities that have
 /// a `SpinnerAnimation` component but don't yet have an `ImageNode`.
 ///
-/// The spinner texture is loaded from "texture/spinner_loading_icon.png" and
-/// displayed as a 16x16 pixel image.
+/// The spinner texture is loaded from "texture/spinner_loading_icon.png"; the
+/// spinner's own `Node` (16x16 pixels, hidden while not loading) is kept.
 ///
 /// # Parameters
 /// - `commands`: Commands for spawning and modifying entities
@@ -79,13 +85,8 @@ pub fn setup_spinner_textures(
     let spinner_texture: Handle<Image> = asset_server.load("texture/spinner_loading_icon.png");
 
     for entity in spinners.iter() {
-        commands.entity(entity).insert((
-            ImageNode::new(spinner_texture.clone()),
-            Node {
-                width: Val::Px(16.0),
-                height: Val::Px(16.0),
-                ..default()
-            },
-        ));
+        commands
+            .entity(entity)
+            .insert(ImageNode::new(spinner_texture.clone()));
     }
 }
\ No newline at end of file
@@ -1,13 +1,30 @@
+use std::time::Duration;
+
 use crate::{
-    components::text::{Text, TextBuilder},
+    components::{
+        a11y::{UiA11y, UiRole},
+        focus::Focusable,
+        sounds::MuteSounds,
+        text::{Text, TextBuilder},
+    },
     theme::{
-        color::{TextColor as TextColorEnum, TextContrastLevel, UiColorPalette},
+        color::{accent_palette, TextColor as TextColorEnum, TextContrastLevel, UiColorPalette},
         typography::{FontFamily, TextSize, TextWeight},
     },
+    utilities::{
+        naming::{strip_namespace, Namespace},
+        node_edit::{NodeEdits, WithNode},
+        transition::{color_transitions, Easing},
+    },
 };
-use bevy::prelude::*;
+use accesskit::{Node as AccessKitNode, Role};
+use bevy::{a11y::AccessibilityNode, ecs::system::SystemId, prelude::*};
 
-use super::core::{Button, ButtonRadius, ButtonSize, ButtonVariant};
+use super::{
+    core::{Button, ButtonLoadingContent, ButtonRadius, ButtonSize, ButtonVariant, IconPosition},
+    events::ButtonCallbacks,
+    styling::button_text_size,
+};
 
 /// Builder for constructing Button components using a fluent API.
 ///
@@ -33,8 +50,10 @@ use super::core::{Button, ButtonRadius, ButtonSize, ButtonVariant};
 /// - **Appearance**: `variant()`, `color()`, `radius()`
 /// - **Size**: `size()`, convenience methods like `size_large()`
 /// - **Text**: `text()`, `text_builder()`, text styling methods
+/// - **Icon**: `icon()`, `icon_position()`, `icon_only()`, `accessible_name()`
 /// - **State**: `loading()`, `disabled()`, `high_contrast()`
 /// - **Content**: `child()`, `children()`
+/// - **Callbacks**: `on_click()`, `on_hover()`
 pub struct ButtonBuilder {
     /// Component name for debugging and identification
     name: String,
@@ -44,8 +63,24 @@ pub struct ButtonBuilder {
     text: Option<String>,
     /// Advanced text configuration (overrides simple text)
     text_builder: Option<TextBuilder>,
+    /// Icon font and glyph shown next to (or instead of) the label
+    icon: Option<(FontFamily, String)>,
+    /// Side of the label the icon is placed on
+    icon_position: IconPosition,
+    /// Name announced by screen readers (defaults to the text, then the name)
+    accessible_name: Option<String>,
+    /// Loading presentation (label is captured from `text` at build time)
+    loading_content: ButtonLoadingContent,
+    /// Whether the button plays no interaction sounds
+    mute_sounds: bool,
+    /// Duration and easing of color changes, instant when unset
+    transition: Option<(Duration, Easing)>,
+    /// Systems run on click and hover
+    callbacks: ButtonCallbacks,
     /// Additional child entities to include in the button
     children: Vec<Entity>,
+    /// Raw node edits applied last
+    node_edits: NodeEdits,
 }
 
 impl ButtonBuilder {
@@ -67,7 +102,15 @@ impl ButtonBuilder {
             button: Button::default(),
             text: None,
             text_builder: None,
+            icon: None,
+            icon_position: IconPosition::default(),
+            accessible_name: None,
+            loading_content: ButtonLoadingContent::default(),
+            mute_sounds: false,
+            transition: None,
+            callbacks: ButtonCallbacks::default(),
             children: Vec::new(),
+            node_edits: NodeEdits::default(),
         }
     }
 
@@ -116,6 +159,14 @@ impl ButtonBuilder {
     /// ```
     pub fn color(mut self, color: UiColorPalette) -> Self {
         self.button.color = color;
+        self.button.follows_accent = false;
+        self
+    }
+
+    /// Uses the accent palette, following runtime changes of the accent color.
+    pub fn accent(mut self) -> Self {
+        self.button.color = accent_palette();
+        self.button.follows_accent = true;
         self
     }
 
@@ -171,13 +222,14 @@ impl ButtonBuilder {
 
     /// Sets the button to loading state.
     ///
-    /// Loading state typically shows a spinner and prevents interaction
-    /// while an asynchronous operation is in progress.
+    /// Loading state shows a spinner before the label and prevents interaction
+    /// while an asynchronous operation is in progress. Setting
+    /// `Button::loading` back to `false` restores the label.
     ///
     /// # Example
     /// ```rust
     /// let loading_button = Button::builder("submit")
-    ///     .text("Submitting...")
+    ///     .text("Submit")
     ///     .loading()
     ///     .build();
     /// ```
@@ -186,6 +238,26 @@ impl ButtonBuilder {
         self
     }
 
+    /// Sets the label shown while the button is loading.
+    ///
+    /// # Example
+    /// ```rust
+    /// let save_button = Button::builder("save")
+    ///     .text("Save")
+    ///     .loading_text("Saving…")
+    ///     .build();
+    /// ```
+    pub fn loading_text(mut self, text: impl Into<String>) -> Self {
+        self.loading_content.loading_text = Some(text.into());
+        self
+    }
+
+    /// Hides the label and icon while loading, showing only the centered spinner.
+    pub fn loading_replaces_content(mut self) -> Self {
+        self.loading_content.replaces_content = true;
+        self
+    }
+
     /// Sets the button to disabled state.
     ///
     /// Disabled buttons cannot be interacted with and typically
@@ -203,6 +275,48 @@ impl ButtonBuilder {
         self
     }
 
+    /// Keeps the button silent, whatever the global [`UiSoundSettings`].
+    ///
+    /// [`UiSoundSettings`]: crate::components::sounds::UiSoundSettings
+    pub fn mute_sounds(mut self) -> Self {
+        self.mute_sounds = true;
+        self
+    }
+
+    /// Fades between the state colors (hover, pressed, …) instead of
+    /// switching at once.
+    ///
+    /// # Example
+    /// ```rust
+    /// let button = ButtonBuilder::new("fade")
+    ///     .transition(Duration::from_millis(150), Easing::EaseOut)
+    ///     .build();
+    /// ```
+    pub fn transition(mut self, duration: Duration, easing: Easing) -> Self {
+        self.transition = Some((duration, easing));
+        self
+    }
+
+    /// Runs the one-shot system `system` whenever the button is clicked.
+    ///
+    /// ```text
+    /// let save = commands.register_system(save_document);
+    /// Button::builder("save").text("Save").on_click(save).build()
+    /// ```
+    ///
+    /// [`ButtonClickEvent`](super::events::ButtonClickEvent) is sent as well.
+    pub fn on_click(mut self, system: SystemId) -> Self {
+        self.callbacks.on_click = Some(system);
+        self
+    }
+
+    /// Runs the one-shot system `system` whenever the pointer enters the
+    /// button.
+    pub fn on_hover(mut self, system: SystemId) -> Self {
+        self.callbacks.on_hover = Some(system);
+        self
+    }
+
     /// Sets simple text content for the button.
     ///
     /// This is a convenience method for basic text. For more complex text
@@ -222,6 +336,23 @@ impl ButtonBuilder {
         self
     }
 
+    /// Sets the label from a fully configured TextBuilder.
+    ///
+    /// Replaces text set with `text()`; the text styling methods below
+    /// apply on top of it.
+    ///
+    /// # Example
+    /// ```rust
+    /// let button = Button::builder("styled")
+    ///     .text_builder(Text::label("Save").italic())
+    ///     .build();
+    /// ```
+    pub fn text_builder(mut self, builder: TextBuilder) -> Self {
+        self.text = None;
+        self.text_builder = Some(builder);
+        self
+    }
+
     /// Sets the text size for the button text.
     ///
     /// This method configures a TextBuilder for advanced text styling.
@@ -389,6 +520,62 @@ impl ButtonBuilder {
         self
     }
 
+    /// Adds an icon glyph next to the label.
+    ///
+    /// The icon is sized for the button size and colored like the label.
+    ///
+    /// # Arguments
+    /// * `family` - The icon font, e.g. `FontFamily::InterfaceFont`
+    /// * `glyph` - The glyph to display
+    ///
+    /// # Example
+    /// ```rust
+    /// let button = Button::builder("save")
+    ///     .text("Save")
+    ///     .icon(FontFamily::InterfaceFont, Save)
+    ///     .build();
+    /// ```
+    pub fn icon(mut self, family: FontFamily, glyph: &str) -> Self {
+        self.icon = Some((family, glyph.to_string()));
+        self
+    }
+
+    /// Places the icon before (default) or after the label.
+    pub fn icon_position(mut self, position: IconPosition) -> Self {
+        self.icon_position = position;
+        self
+    }
+
+    /// Makes a square button showing only a centered icon glyph.
+    ///
+    /// Icon-only buttons have no visible label, so give them an
+    /// `accessible_name()` describing the action.
+    ///
+    /// # Example
+    /// ```rust
+    /// let close = Button::builder("close")
+    ///     .icon_only(FontFamily::InterfaceFont, X)
+    ///     .accessible_name("Close")
+    ///     .variant(ButtonVariant::Ghost)
+    ///     .build();
+    /// ```
+    pub fn icon_only(mut self, family: FontFamily, glyph: &str) -> Self {
+        self.icon = Some((family, glyph.to_string()));
+        self.button.icon_only = true;
+        self
+    }
+
+    /// Sets the name announced by screen readers.
+    pub fn accessible_name(mut self, name: impl Into<String>) -> Self {
+        self.accessible_name = Some(name.into());
+        self
+    }
+
+    /// Overrides the [`UiA11y`] label; same as [`accessible_name`](Self::accessible_name).
+    pub fn a11y_label(self, label: impl Into<String>) -> Self {
+        self.accessible_name(label)
+    }
+
     pub fn child(mut self, entity: Entity) -> Self {
         self.children.push(entity);
         self
@@ -409,11 +596,7 @@ impl ButtonBuilder {
             // Return a default - the actual size will be preserved from TextBuilder
             TextSize::Base
         } else {
-            match self.button.size {
-                ButtonSize::Small => TextSize::Xs,
-                ButtonSize::Default => TextSize::Base,
-                ButtonSize::Large => TextSize::X2l,
-            }
+            button_text_size(self.button.size)
         }
     }
 
@@ -433,6 +616,18 @@ impl ButtonBuilder {
         TextColorEnum::Custom(calculated_color.0)
     }
 
+    /// Name announced by screen readers: the explicit accessible name, the
+    /// label text, or the builder name.
+    fn get_accessible_name(&self) -> String {
+        self.accessible_name
+            .clone()
+            .or_else(|| self.text.clone())
+            .unwrap_or_else(|| {
+                let name = strip_namespace(&self.name);
+                name.strip_suffix("_Button").unwrap_or(name).to_string()
+            })
+    }
+
     fn calculate_style(&self) -> Node {
         super::styling::calculate_button_style(&self.button)
     }
@@ -494,21 +689,39 @@ impl Button {
 impl ButtonBuilder {
     pub fn build(self) -> impl Bundle {
         use super::{
-            animations::SpinnerAnimation, interactions::ButtonManagedText,
+            animations::SpinnerAnimation,
+            interactions::{ButtonIcon, ButtonLabel, ButtonManagedText},
             styling::calculate_border_radius,
         };
         use crate::components::text::Text;
         use bevy::{ecs::spawn::SpawnWith, prelude::*};
 
-        let node = self.calculate_style();
+        let mut node = self.calculate_style();
         let background_color = self.calculate_background_color();
         let border_color = self.calculate_border_color();
         let border_radius = calculate_border_radius(self.button.radius);
         let display_text = self.text.clone().unwrap_or_default();
-        let is_loading = self.button.loading;
+        let loading_content = ButtonLoadingContent {
+            label: self.text.clone(),
+            ..self.loading_content.clone()
+        };
         let text_size = self.get_button_text_size();
         let text_weight = self.get_button_text_weight();
         let text_color_enum = self.get_text_color_enum();
+        let focusable = Focusable::new().disabled(self.button.disabled || self.button.loading);
+        let icon = self.icon.clone();
+        let icon_size = button_text_size(self.button.size);
+        let trailing_icon = self.icon_position == IconPosition::Trailing;
+        // Icon-only buttons and icon buttons without text get no label
+        let show_label = !self.button.icon_only
+            && (self.text.is_some() || self.text_builder.is_some() || icon.is_none());
+
+        let a11y = UiA11y::new(UiRole::Button, self.get_accessible_name());
+        let mut accessibility = AccessKitNode::new(Role::Button);
+        accessibility.set_label(a11y.label.clone());
+        if self.button.disabled {
+            accessibility.set_disabled();
+        }
 
         // Prepare TextBuilder with automatic contrast optimization if text_builder is used
         let text_builder = if let Some(builder) = self.text_builder.clone() {
@@ -528,50 +741,96 @@ impl ButtonBuilder {
         } else {
             None
         };
+        self.node_edits.apply(&mut node);
 
         (
-            Name::new(format!("{}_Button", self.name)),
+            Name::new(self.name),
             self.button,
             node,
             border_color,
             border_radius,
             background_color,
             bevy_picking::prelude::Pickable::default(),
+            focusable,
+            AccessibilityNode(accessibility),
+            a11y,
+            loading_content,
+            MuteSounds(self.mute_sounds),
+            color_transitions(self.transition),
+            self.callbacks,
             Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
-                if is_loading {
-                    // Spawn rotating spinner image
-                    parent.spawn((
-                        Name::new("Button Spinner"),
-                        Node {
-                            width: Val::Px(16.0),
-                            height: Val::Px(16.0),
-                            justify_content: JustifyContent::Center,
-                            align_items: AlignItems::Center,
-                            ..default()
-                        },
-                        SpinnerAnimation::default(),
-                    ));
-                } else {
-                    // Use advanced TextBuilder if available, otherwise fallback to simple text
-                    if let Some(builder) = text_builder {
-                        parent.spawn((
-                            builder.center().build(),
-                            ButtonManagedText, // Always add marker for now - will be refined later
-                        ));
-                    } else {
-                        // Fallback text is always managed by button
+                // Rotating spinner image, shown by `sync_button_loading` while loading
+                parent.spawn((
+                    Name::new("Button Spinner"),
+                    Node {
+                        width: Val::Px(16.0),
+                        height: Val::Px(16.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        display: Display::None,
+                        ..default()
+                    },
+                    SpinnerAnimation::default(),
+                ));
+
+                let spawn_icon = |parent: &mut ChildSpawner| {
+                    if let Some((family, glyph)) = &icon {
+                        // Managed like the label, so it follows the computed text color
                         parent.spawn((
-                            Text::label(display_text.clone())
+                            Name::new("Button Icon"),
+                            Text::new(glyph.clone())
+                                .family(*family)
+                                .size(icon_size)
                                 .color(text_color_enum)
-                                .size(text_size)
-                                .weight(text_weight)
                                 .center()
                                 .build(),
                             ButtonManagedText,
+                            ButtonIcon,
                         ));
                     }
+                };
+
+                if !trailing_icon {
+                    spawn_icon(parent);
+                }
+
+                // Use advanced TextBuilder if available, otherwise fallback to simple text
+                if let Some(builder) = text_builder.filter(|_| show_label) {
+                    parent.spawn((
+                        builder.center().build(),
+                        ButtonManagedText, // Always add marker for now - will be refined later
+                        ButtonLabel,
+                    ));
+                } else if show_label {
+                    // Fallback text is always managed by button
+                    parent.spawn((
+                        Text::label(display_text.clone())
+                            .color(text_color_enum)
+                            .size(text_size)
+                            .weight(text_weight)
+                            .center()
+                            .build(),
+                        ButtonManagedText,
+                        ButtonLabel,
+                    ));
+                }
+
+                if trailing_icon {
+                    spawn_icon(parent);
                 }
             })),
         )
     }
 }
+
+impl Namespace for ButtonBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+impl WithNode for ButtonBuilder {
+    fn node_edits_mut(&mut self) -> &mut NodeEdits {
+        &mut self.node_edits
+    }
+}
@@ -1,4 +1,10 @@
-use crate::theme::color::{accent_palette, UiColorPalette};
+use crate::{
+    components::disabled::DisabledInteraction,
+    theme::{
+        color::{accent_palette, UiColorPalette},
+        radix_theme::AccentPaletteUser,
+    },
+};
 use bevy::prelude::*;
 
 /// Represents the current interactive state of a button component.
@@ -12,7 +18,7 @@ use bevy::prelude::*;
 /// * `Active` - Button is being pressed or activated
 /// * `Disabled` - Button is disabled and cannot be interacted with
 /// * `Loading` - Button is in a loading state, showing a loading indicator
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum ButtonState {
     /// Default state when button is ready for interaction
     Normal,
@@ -41,6 +47,7 @@ pub enum ButtonState {
 /// * `radius` - Border radius configuration
 /// * `loading` - Whether the button is in a loading state
 /// * `disabled` - Whether the button is disabled
+/// * `icon_only` - Square layout for a single centered icon glyph
 /// * `current_state` - Current interactive state of the button
 ///
 /// # Examples
@@ -61,15 +68,20 @@ pub enum ButtonState {
 ///     ..Default::default()
 /// };
 /// ```
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct Button {
     pub variant: ButtonVariant,
     pub size: ButtonSize,
     pub color: UiColorPalette,
+    /// Whether `color` follows the accent color of the theme; cleared by an
+    /// explicit palette
+    pub follows_accent: bool,
     pub high_contrast: bool,
     pub radius: ButtonRadius,
     pub loading: bool,
     pub disabled: bool,
+    pub icon_only: bool,
     pub current_state: ButtonState,
 }
 
@@ -81,21 +93,51 @@ impl Default for Button {
     /// - Normal contrast for standard visibility
     /// - Base radius for modern appearance
     /// - Not loading or disabled
+    /// - Text layout (not icon-only)
     /// - Normal state
     fn default() -> Self {
         Self {
             variant: ButtonVariant::Solid,
             size: ButtonSize::Default,
             color: accent_palette(),
+            follows_accent: true,
             high_contrast: false,
             radius: ButtonRadius::Base,
             loading: false,
             disabled: false,
+            icon_only: false,
             current_state: ButtonState::Normal,
         }
     }
 }
 
+impl Button {
+    /// Whether the button reacts to hover, press and click (not disabled or loading).
+    pub fn is_interactive(&self) -> bool {
+        !self.disabled && !self.loading
+    }
+}
+
+impl AccentPaletteUser for Button {
+    fn follows_accent(&self) -> bool {
+        self.follows_accent
+    }
+}
+
+impl DisabledInteraction for Button {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    fn ignores_input(&self) -> bool {
+        !self.is_interactive()
+    }
+}
+
 /// Defines the visual style variant of a button.
 ///
 /// Buttons can have different visual styles which affect their background,
@@ -118,7 +160,7 @@ impl Default for Button {
 /// let tertiary_variant = ButtonVariant::Outline;   // Subtle with border
 /// let minimal_variant = ButtonVariant::Ghost;      // Minimal visual impact
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Component)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Component, Reflect)]
 pub enum ButtonVariant {
     /// Full background color with high contrast text (default) - Use for primary actions
     #[default]
@@ -157,7 +199,7 @@ pub enum ButtonVariant {
 /// let standard_button = ButtonSize::Default; // General use
 /// let prominent_button = ButtonSize::Large;  // CTAs, mobile
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Component)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Component, Reflect)]
 pub enum ButtonSize {
     /// Compact size for space-constrained areas
     Small,
@@ -168,6 +210,46 @@ pub enum ButtonSize {
     Large,
 }
 
+/// How a button presents its loading state, and the label to restore
+/// afterwards.
+///
+/// By default a loading button keeps its label and shows a leading spinner.
+/// Toggling [`Button::loading`] at runtime switches between both presentations.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct ButtonLoadingContent {
+    /// Label shown while not loading, captured at build time (or when loading
+    /// starts, for labels built with `text_builder()`)
+    pub label: Option<String>,
+    /// Label shown while loading instead of `label`
+    pub loading_text: Option<String>,
+    /// Hide the label and icon while loading, showing only the centered spinner
+    pub replaces_content: bool,
+}
+
+/// Placement of an icon relative to the button label.
+///
+/// # Examples
+///
+/// ```rust
+/// use ui::assets::ChevronRight;
+/// use ui::components::button::{Button, IconPosition};
+/// use ui::theme::typography::FontFamily;
+///
+/// let next = Button::builder("next")
+///     .text("Next")
+///     .icon(FontFamily::InterfaceFont, ChevronRight)
+///     .icon_position(IconPosition::Trailing)
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IconPosition {
+    /// Icon before the label (default)
+    #[default]
+    Leading,
+    /// Icon after the label
+    Trailing,
+}
+
 /// Defines the border radius options for button appearance.
 ///
 /// Controls the roundness of button corners, from sharp rectangular
@@ -196,7 +278,7 @@ pub enum ButtonSize {
 /// let friendly_button = ButtonRadius::Large;    // Approachable design
 /// let pill_button = ButtonRadius::Full;         // Distinctive style
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum ButtonRadius {
     /// Sharp corners (0px radius)
     None,
@@ -4,7 +4,7 @@
 //! with button components, allowing for decoupled event handling throughout
 //! the application.
 
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemId, prelude::*};
 
 use super::core::ButtonVariant;
 
@@ -36,4 +36,26 @@ pub struct ButtonClickEvent {
     pub button_entity: Entity,
     /// The variant type of the button that was clicked.
     pub button_variant: ButtonVariant,
-}
\ No newline at end of file
+}
+
+/// One-shot systems a button runs when it is clicked or hovered.
+///
+/// Set with [`ButtonBuilder::on_click`] and [`ButtonBuilder::on_hover`]. The
+/// systems run from the button's pointer observers, under the same conditions
+/// as [`ButtonClickEvent`]: never while the button is disabled or loading.
+/// The event is still sent, so both styles can be mixed.
+///
+/// ```rust
+/// let save = commands.register_system(save_document);
+/// commands.spawn(Button::builder("save").text("Save").on_click(save).build());
+/// ```
+///
+/// [`ButtonBuilder::on_click`]: super::builder::ButtonBuilder::on_click
+/// [`ButtonBuilder::on_hover`]: super::builder::ButtonBuilder::on_hover
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ButtonCallbacks {
+    /// System run when the button is clicked
+    pub on_click: Option<SystemId>,
+    /// System run when the pointer enters the button
+    pub on_hover: Option<SystemId>,
+}
@@ -1,16 +1,17 @@
 //! Button interaction systems and components for handling user input.
 //!
 //! This module manages button interactions including hover, click, press, and release
-//! events. It handles visual state changes, sound effects, and text color management
+//! events. It handles visual state changes and text color management
 //! for button components.
 
-use crate::assets::audio::{sound_effect, SfxAssets};
-use bevy::prelude::*;
+use crate::theme::radix_theme::{ThemeTokens, UsesAccentPalette};
+use bevy::{ecs::system::SystemParam, prelude::*};
 use bevy_picking::prelude::{Click, Out, Over, Pickable, Pointer, Pressed, Released};
 
 use super::{
-    core::{Button, ButtonState},
-    events::ButtonClickEvent,
+    animations::SpinnerAnimation,
+    core::{Button, ButtonLoadingContent, ButtonState},
+    events::{ButtonCallbacks, ButtonClickEvent},
 };
 
 /// Marker component for text that should automatically update colors based on button state.
@@ -24,6 +25,15 @@ use super::{
 #[derive(Component, Debug)]
 pub struct ButtonManagedText;
 
+/// Marker for the label text of a button, swapped for the loading text while
+/// the button is loading.
+#[derive(Component, Debug)]
+pub struct ButtonLabel;
+
+/// Marker for the icon glyph text of a button.
+#[derive(Component, Debug)]
+pub struct ButtonIcon;
+
 /// System that sets up interaction observers for newly added buttons.
 ///
 /// This system runs when new buttons are added to the world and attaches
@@ -71,50 +81,165 @@ pub fn setup_button_interactions(
     }
 }
 
+/// System that re-applies the accent palette to buttons following it.
+///
+/// Runs after the accent color of the `RadixTheme` changed; buttons with an
+/// explicit palette don't carry [`UsesAccentPalette`] and are left untouched.
+pub fn refresh_button_accent(
+    tokens: Res<ThemeTokens>,
+    mut buttons: Query<(Entity, &mut Button, &mut BorderColor), With<UsesAccentPalette>>,
+    mut bg_colors: Query<&mut BackgroundColor>,
+    mut text_colors: Query<&mut TextColor>,
+    children_query: Query<&Children>,
+    managed_text_query: Query<&ButtonManagedText>,
+) {
+    for (entity, mut button, mut border_color) in &mut buttons {
+        if button.color == tokens.accent {
+            continue;
+        }
+        button.color = tokens.accent.clone();
+
+        let state = button.current_state;
+        *border_color = button.get_styling(state).border_color;
+        apply_button_styling(
+            entity,
+            &button,
+            state,
+            &mut bg_colors,
+            &mut text_colors,
+            &children_query,
+            &managed_text_query,
+        );
+    }
+}
+
 /// Event handler for button click interactions.
 ///
 /// This function is called when a button receives a click event. It validates
-/// that the button is not disabled or loading, plays a sound effect, and
-/// sends a `ButtonClickEvent` for other systems to handle.
+/// that the button is not disabled or loading, sends a `ButtonClickEvent`
+/// for other systems to handle and runs the button's click callback.
 ///
 /// # Parameters
 /// - `trigger`: The click event trigger
 /// - `buttons`: Query for button components
 /// - `events`: Event writer for button click events
-/// - `commands`: Commands for spawning sound effects
-/// - `sfx_assets`: Sound effect assets
+/// - `commands`: Commands for running the click callback
 fn on_button_click(
     trigger: Trigger<Pointer<Click>>,
-    buttons: Query<&Button>,
+    buttons: Query<(&Button, Option<&ButtonCallbacks>)>,
     mut events: EventWriter<ButtonClickEvent>,
     mut commands: Commands,
-    sfx_assets: Res<SfxAssets>,
 ) {
     let entity = trigger.target();
-    if let Ok(button) = buttons.get(entity) {
-        // Don't trigger if disabled or loading
-        if button.disabled || button.loading {
-            return;
-        }
+    let Ok((button, callbacks)) = buttons.get(entity) else {
+        return;
+    };
+    let Some(event) = button_click_event(entity, button) else {
+        return;
+    };
+
+    info!("Button clicked! Variant: {:?}", event.button_variant);
+
+    // Send custom event; the click sound is played by `play_ui_sounds`
+    events.write(event);
+    if let Some(system) = callbacks.and_then(|callbacks| callbacks.on_click) {
+        commands.run_system(system);
+    }
+}
+
+/// The event a click on `button` emits, or `None` while it is disabled or
+/// loading.
+pub fn button_click_event(entity: Entity, button: &Button) -> Option<ButtonClickEvent> {
+    button.is_interactive().then_some(ButtonClickEvent {
+        button_entity: entity,
+        button_variant: button.variant,
+    })
+}
+
+/// Children of a button that [`sync_button_loading`] shows, hides and
+/// relabels.
+#[derive(SystemParam)]
+pub struct ButtonLoadingParts<'w, 's> {
+    spinners: Query<'w, 's, (), With<SpinnerAnimation>>,
+    nodes: Query<'w, 's, &'static mut Node, Without<Button>>,
+    labels: Query<'w, 's, &'static mut Text, With<ButtonLabel>>,
+}
+
+/// System that re-applies button styling when a button changes, and switches
+/// its content between the loading and the normal presentation when
+/// [`Button::loading`] changes.
+///
+/// While loading, the spinner is shown before the label and the label is
+/// swapped for the loading text (if any). Buttons whose loading replaces the
+/// content (and icon-only buttons) show only the spinner. Turning loading off
+/// restores the label captured at build time, or for labels built with
+/// `text_builder()`, when loading started.
+pub fn sync_button_loading(
+    mut buttons: Query<(Entity, &Button, &mut ButtonLoadingContent, &Children), Changed<Button>>,
+    mut parts: ButtonLoadingParts,
+    mut bg_colors: Query<&mut BackgroundColor>,
+    mut text_colors: Query<&mut TextColor>,
+    children_query: Query<&Children>,
+    managed_text_query: Query<&ButtonManagedText>,
+) {
+    for (entity, button, mut content, children) in &mut buttons {
+        // Covers disabled and accent changes made outside the pointer observers
+        apply_button_styling(
+            entity,
+            button,
+            button.current_state,
+            &mut bg_colors,
+            &mut text_colors,
+            &children_query,
+            &managed_text_query,
+        );
 
-        info!("Button clicked! Variant: {:?}", button.variant);
+        let Some(spinner) = children
+            .iter()
+            .find(|child| parts.spinners.contains(*child))
+        else {
+            continue;
+        };
+        let spinner_shown = parts
+            .nodes
+            .get(spinner)
+            .is_ok_and(|node| node.display != Display::None);
+        if spinner_shown == button.loading {
+            continue;
+        }
 
-        // Play tap sound effect
-        commands.spawn(sound_effect(sfx_assets.tap.clone()));
+        let hide_content = button.loading && (content.replaces_content || button.icon_only);
+        for child in children.iter() {
+            let shown = if child == spinner {
+                button.loading
+            } else {
+                !hide_content
+            };
+            if let Ok(mut node) = parts.nodes.get_mut(child) {
+                node.display = if shown { Display::Flex } else { Display::None };
+            }
 
-        // Send custom event
-        events.write(ButtonClickEvent {
-            button_entity: entity,
-            button_variant: button.variant,
-        });
+            let Ok(mut text) = parts.labels.get_mut(child) else {
+                continue;
+            };
+            if content.label.is_none() && button.loading {
+                content.label = Some(text.0.clone());
+            }
+            if let Some(label) = &content.label {
+                text.0 = match (&content.loading_text, button.loading) {
+                    (Some(loading_text), true) => loading_text.clone(),
+                    _ => label.clone(),
+                };
+            }
+        }
     }
 }
 
 /// Event handler for button hover (mouse over) interactions.
 ///
 /// This function is called when the mouse cursor enters a button's area.
-/// It updates the button's visual state to the hover state if the button
-/// is not disabled or loading.
+/// It updates the button's visual state to the hover state and runs the
+/// button's hover callback if the button is not disabled or loading.
 ///
 /// # Parameters
 /// - `trigger`: The hover event trigger
@@ -123,19 +248,24 @@ fn on_button_click(
 /// - `text_colors`: Query for text color components
 /// - `children_query`: Query for child entities
 /// - `managed_text_query`: Query for managed text components
+/// - `commands`: Commands for running the hover callback
 fn on_button_hover(
     trigger: Trigger<Pointer<Over>>,
-    mut buttons: Query<&mut Button>,
+    mut buttons: Query<(&mut Button, Option<&ButtonCallbacks>)>,
     mut bg_colors: Query<&mut BackgroundColor>,
     mut text_colors: Query<&mut TextColor>,
     children_query: Query<&Children>,
     managed_text_query: Query<&ButtonManagedText>,
+    mut commands: Commands,
 ) {
     let entity = trigger.target();
-    if let Ok(mut button) = buttons.get_mut(entity) {
+    if let Ok((mut button, callbacks)) = buttons.get_mut(entity) {
         if button.disabled || button.loading {
             return;
         }
+        if let Some(system) = callbacks.and_then(|callbacks| callbacks.on_hover) {
+            commands.run_system(system);
+        }
 
         button.current_state = ButtonState::Hover;
         apply_button_styling(
@@ -356,4 +486,114 @@ fn update_text_colors_recursive(
             );
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::text::Text as UiText,
+        test_support::{advance_frames, drain_events, pointer_click, test_app},
+    };
+
+    #[derive(Resource, Default)]
+    struct Clicks(u32);
+
+    fn count_click(mut clicks: ResMut<Clicks>) {
+        clicks.0 += 1;
+    }
+
+    #[test]
+    fn test_click_runs_callback_and_sends_event() {
+        let mut app = test_app();
+        app.init_resource::<Clicks>();
+        let callback = app.world_mut().register_system(count_click);
+        let button = app
+            .world_mut()
+            .spawn(
+                Button::builder("save")
+                    .text("Save")
+                    .on_click(callback)
+                    .build(),
+            )
+            .id();
+        let disabled = app
+            .world_mut()
+            .spawn(
+                Button::builder("delete")
+                    .text("Delete")
+                    .disabled()
+                    .on_click(callback)
+                    .build(),
+            )
+            .id();
+        advance_frames(&mut app, 1);
+
+        pointer_click(&mut app, button);
+        assert_eq!(app.world().resource::<Clicks>().0, 1);
+        let events = drain_events::<ButtonClickEvent>(&mut app);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].button_entity, button);
+
+        // Disabled buttons neither run the callback nor send the event
+        pointer_click(&mut app, disabled);
+        assert_eq!(app.world().resource::<Clicks>().0, 1);
+        assert!(drain_events::<ButtonClickEvent>(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_loading_button_click_emits_no_event() {
+        let mut app = test_app();
+        let button = app
+            .world_mut()
+            .spawn(Button::builder("save").text("Save").loading().build())
+            .id();
+        advance_frames(&mut app, 1);
+
+        pointer_click(&mut app, button);
+        assert!(drain_events::<ButtonClickEvent>(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_loading_text_replaces_a_text_builder_label() {
+        let mut app = test_app();
+        let button = app
+            .world_mut()
+            .spawn(
+                Button::builder("save")
+                    .text_builder(UiText::label("Save"))
+                    .loading_text("Saving…")
+                    .build(),
+            )
+            .id();
+        advance_frames(&mut app, 1);
+        let label = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<&Text, With<ButtonLabel>>()
+                .single(app.world())
+                .unwrap()
+                .0
+                .clone()
+        };
+
+        app.world_mut().get_mut::<Button>(button).unwrap().loading = true;
+        advance_frames(&mut app, 1);
+        assert_eq!(label(&mut app), "Saving…");
+
+        app.world_mut().get_mut::<Button>(button).unwrap().loading = false;
+        advance_frames(&mut app, 1);
+        assert_eq!(label(&mut app), "Save");
+    }
+
+    #[test]
+    fn test_disabled_button_click_emits_no_event() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+
+        let button = Button {
+            disabled: true,
+            ..Default::default()
+        };
+        assert!(button_click_event(entity, &button).is_none());
+    }
+}
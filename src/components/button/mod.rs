@@ -14,7 +14,8 @@
 //! - **High Contrast Mode**: Enhanced accessibility with increased contrast
 //! - **Flexible Border Radius**: From sharp corners to pill-shaped buttons
 //! - **Text Integration**: Built-in text support with comprehensive typography options
-//! - **Event System**: Built-in click handling and custom event support
+//! - **Event System**: Built-in click handling, `ButtonClickEvent` and
+//!   `on_click` / `on_hover` callback systems
 //! - **Animations**: Smooth state transitions and loading indicators
 //! - **Accessibility**: ARIA attributes and keyboard navigation support
 //!
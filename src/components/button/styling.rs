@@ -5,8 +5,9 @@
 //! across different button types and interaction states.
 
 use crate::theme::{
-    color::{TextContrastLevel, UiColorPalette},
+    color::{disabled_color, disabled_colors, TextContrastLevel, UiColorPalette},
     layout::UiLayout,
+    typography::{get_font_size_pixels, FontSize, TextSize},
 };
 use bevy::prelude::*;
 
@@ -54,6 +55,7 @@ impl Button {
     /// #   disabled: false,
     /// #   loading: false,
     /// #   high_contrast: false,
+    /// #   icon_only: false,
     /// #   current_state: ButtonState::Normal,
     /// };
     /// let styling = button.get_styling(ButtonState::Hover);
@@ -112,14 +114,14 @@ impl Button {
             },
         };
 
-        let mut bg_color = BackgroundColor(base_color);
-
         if state == ButtonState::Disabled {
-            let srgba = bg_color.0.to_srgba();
-            bg_color.0 = Color::srgba(srgba.red, srgba.green, srgba.blue, 0.6);
+            return BackgroundColor(match self.variant {
+                ButtonVariant::Solid => disabled_color(base_color),
+                _ => disabled_colors(&self.color).0,
+            });
         }
 
-        bg_color
+        BackgroundColor(base_color)
     }
 
     /// Calculates the border color for the button based on its variant and state.
@@ -141,7 +143,7 @@ impl Button {
                     BorderColor(self.color.border)
                 }
                 ButtonState::Hover => BorderColor(self.color.border_hover),
-                ButtonState::Disabled => BorderColor(self.color.border),
+                ButtonState::Disabled => BorderColor(disabled_colors(&self.color).1),
             },
         }
     }
@@ -211,20 +213,47 @@ impl Button {
         };
 
         if state == ButtonState::Disabled {
-            // Keep the same text color but reduce opacity for disabled state
-            let srgba = text_color.to_srgba();
-            text_color = Color::srgba(srgba.red, srgba.green, srgba.blue, 0.8);
+            // Keep the same text color but fade it like the background
+            text_color = disabled_color(text_color);
         }
 
         TextColor(text_color)
     }
 }
 
+/// Text size of a button's label and icon for the given button size.
+pub fn button_text_size(size: ButtonSize) -> TextSize {
+    match size {
+        ButtonSize::Small => TextSize::Xs,
+        ButtonSize::Default => TextSize::Base,
+        ButtonSize::Large => TextSize::X2l,
+    }
+}
+
+/// Vertical padding of a button for the given button size.
+fn button_padding_y(size: ButtonSize) -> f32 {
+    match size {
+        ButtonSize::Default => UiLayout::default().padding.base,
+        ButtonSize::Small => UiLayout::default().padding.sm,
+        ButtonSize::Large => UiLayout::default().padding.lg,
+    }
+}
+
+/// Side length of an icon-only button, matching the height of a text button
+/// of the same size (glyph, vertical padding and border).
+pub fn icon_button_size(size: ButtonSize) -> f32 {
+    get_font_size_pixels(&FontSize::default(), button_text_size(size))
+        + 2.0 * button_padding_y(size)
+        + 2.0
+}
+
 /// Calculates the layout style properties for a button.
 ///
 /// This function determines padding, alignment, and border properties
 /// based on the button's size configuration. It ensures consistent
-/// spacing and alignment across different button sizes.
+/// spacing and alignment across different button sizes. Icon-only buttons
+/// are square with the glyph centered; an icon next to a label is spaced
+/// with the layout gap scale.
 ///
 /// # Parameters
 /// - `button`: The button to calculate styling for
@@ -248,26 +277,43 @@ impl Button {
 /// #   disabled: false,
 /// #   loading: false,
 /// #   high_contrast: false,
+/// #   icon_only: false,
 /// #   current_state: Default::default(),
 /// };
 /// let node_style = calculate_button_style(&button);
 /// ```
 pub fn calculate_button_style(button: &Button) -> Node {
+    if button.icon_only {
+        let side = Val::Px(icon_button_size(button.size));
+        return Node {
+            width: side,
+            height: side,
+            flex_shrink: 0.0,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        };
+    }
+
     let padding = UiRect::axes(
         Val::Px(match button.size {
             ButtonSize::Default => UiLayout::default().padding.base + 2.0,
             ButtonSize::Small => UiLayout::default().padding.sm + 2.0,
             ButtonSize::Large => UiLayout::default().padding.lg + 8.0,
         }),
-        Val::Px(match button.size {
-            ButtonSize::Default => UiLayout::default().padding.base,
-            ButtonSize::Small => UiLayout::default().padding.sm,
-            ButtonSize::Large => UiLayout::default().padding.lg,
-        }),
+        Val::Px(button_padding_y(button.size)),
     );
 
+    let column_gap = Val::Px(match button.size {
+        ButtonSize::Default => UiLayout::default().gap.base,
+        ButtonSize::Small => UiLayout::default().gap.sm,
+        ButtonSize::Large => UiLayout::default().gap.lg,
+    });
+
     Node {
         padding,
+        column_gap,
         justify_content: JustifyContent::Center,
         align_items: AlignItems::Center,
         border: UiRect::all(Val::Px(1.0)),
@@ -314,4 +360,30 @@ pub fn calculate_border_radius(radius: ButtonRadius) -> BorderRadius {
         bottom_left: border_radius,
         bottom_right: border_radius,
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icon_only_button_is_square() {
+        for size in [ButtonSize::Small, ButtonSize::Default, ButtonSize::Large] {
+            let button = Button {
+                size,
+                icon_only: true,
+                ..Default::default()
+            };
+            let node = calculate_button_style(&button);
+
+            assert_eq!(node.width, Val::Px(icon_button_size(size)));
+            assert_eq!(node.width, node.height);
+        }
+    }
+
+    #[test]
+    fn test_icon_button_grows_with_size() {
+        assert!(icon_button_size(ButtonSize::Small) < icon_button_size(ButtonSize::Default));
+        assert!(icon_button_size(ButtonSize::Default) < icon_button_size(ButtonSize::Large));
+    }
+}
@@ -0,0 +1,257 @@
+//! Button groups: several buttons laid out as one cluster, e.g. a toolbar.
+//!
+//! [`ButtonGroupBuilder`] takes already built buttons and reparents them under
+//! a row or column. Attached groups (the default) merge the buttons: inner
+//! corners lose their radius and neighbouring borders overlap, so only the
+//! outer corners of the first and last button stay rounded. Spaced groups
+//! keep every button as is, separated by a gap.
+//!
+//! The buttons keep their own interactions and still emit their
+//! [`ButtonClickEvent`](crate::components::button::ButtonClickEvent)s.
+//!
+//! ```rust
+//! let buttons = ["Bold", "Italic", "Underline"].map(|label| {
+//!     let button = ButtonBuilder::new(label)
+//!         .text(label)
+//!         .variant(ButtonVariant::Outline)
+//!         .build();
+//!     commands.spawn(button).id()
+//! });
+//!
+//! let toolbar = ButtonGroupBuilder::new("Formatting")
+//!     .buttons(buttons)
+//!     .build(&mut commands);
+//! ```
+
+use crate::utilities::naming::Namespace;
+use bevy::prelude::*;
+
+/// Gap between the buttons of a spaced group, in logical pixels
+pub const BUTTON_GROUP_GAP: f32 = 8.0;
+
+/// Layout direction of a button group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ButtonGroupOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+/// A row or column of buttons.
+#[derive(Component, Debug, Clone)]
+pub struct ButtonGroupComponent {
+    pub name: String,
+    pub orientation: ButtonGroupOrientation,
+    /// Whether the buttons share their inner borders instead of being spaced
+    pub attached: bool,
+}
+
+/// Member of a button group, attached next to its button.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ButtonGroupItem {
+    pub group: Entity,
+    pub index: usize,
+    pub count: usize,
+}
+
+/// Corner radii of member `index` of `count` attached buttons whose corners
+/// are `outer`: only the outer corners of the first and last member are kept.
+pub fn button_group_radius(
+    index: usize,
+    count: usize,
+    orientation: ButtonGroupOrientation,
+    outer: Val,
+) -> BorderRadius {
+    let first = index == 0;
+    let last = index + 1 == count;
+    let corner = |keep: bool| if keep { outer } else { Val::Px(0.0) };
+
+    match orientation {
+        ButtonGroupOrientation::Horizontal => BorderRadius {
+            top_left: corner(first),
+            bottom_left: corner(first),
+            top_right: corner(last),
+            bottom_right: corner(last),
+        },
+        ButtonGroupOrientation::Vertical => BorderRadius {
+            top_left: corner(first),
+            top_right: corner(first),
+            bottom_left: corner(last),
+            bottom_right: corner(last),
+        },
+    }
+}
+
+pub struct ButtonGroupBuilder {
+    name: String,
+    group: ButtonGroupComponent,
+    buttons: Vec<Entity>,
+    gap: f32,
+}
+
+impl ButtonGroupBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            name: format!("{}_ButtonGroup", name),
+            group: ButtonGroupComponent {
+                name,
+                orientation: ButtonGroupOrientation::Horizontal,
+                attached: true,
+            },
+            buttons: Vec::new(),
+            gap: BUTTON_GROUP_GAP,
+        }
+    }
+
+    /// Adds a spawned button to the end of the group.
+    pub fn button(mut self, button: Entity) -> Self {
+        self.buttons.push(button);
+        self
+    }
+
+    /// Adds spawned buttons to the end of the group, in order.
+    pub fn buttons(mut self, buttons: impl IntoIterator<Item = Entity>) -> Self {
+        self.buttons.extend(buttons);
+        self
+    }
+
+    pub fn horizontal(mut self) -> Self {
+        self.group.orientation = ButtonGroupOrientation::Horizontal;
+        self
+    }
+
+    pub fn vertical(mut self) -> Self {
+        self.group.orientation = ButtonGroupOrientation::Vertical;
+        self
+    }
+
+    /// Merges the buttons into one cluster with shared inner borders (default).
+    pub fn attached(mut self) -> Self {
+        self.group.attached = true;
+        self
+    }
+
+    /// Keeps the buttons apart, separated by a gap.
+    pub fn spaced(mut self) -> Self {
+        self.group.attached = false;
+        self
+    }
+
+    /// Sets the gap between the buttons of a spaced group.
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    fn calculate_style(&self) -> Node {
+        let gap = if self.group.attached {
+            Val::Px(0.0)
+        } else {
+            Val::Px(self.gap)
+        };
+        Node {
+            flex_direction: match self.group.orientation {
+                ButtonGroupOrientation::Horizontal => FlexDirection::Row,
+                ButtonGroupOrientation::Vertical => FlexDirection::Column,
+            },
+            align_items: AlignItems::Stretch,
+            column_gap: gap,
+            row_gap: gap,
+            ..default()
+        }
+    }
+
+    /// Spawns the group, moves the buttons into it and returns its entity.
+    pub fn build(self, commands: &mut Commands) -> Entity {
+        let node = self.calculate_style();
+        let count = self.buttons.len();
+        let group = commands
+            .spawn((Name::new(self.name), self.group, node))
+            .id();
+
+        for (index, button) in self.buttons.iter().enumerate() {
+            commands.entity(*button).insert(ButtonGroupItem {
+                group,
+                index,
+                count,
+            });
+        }
+        commands.entity(group).add_children(&self.buttons);
+        group
+    }
+}
+
+/// Joins the members of attached groups: inner corners lose their radius and
+/// neighbouring borders overlap so they are drawn only once.
+pub fn style_button_group_items(
+    mut items: Query<(&ButtonGroupItem, &mut Node, &mut BorderRadius), Added<ButtonGroupItem>>,
+    groups: Query<&ButtonGroupComponent>,
+) {
+    for (item, mut node, mut radius) in &mut items {
+        let Ok(group) = groups.get(item.group) else {
+            continue;
+        };
+        if !group.attached {
+            continue;
+        }
+
+        *radius = button_group_radius(item.index, item.count, group.orientation, radius.top_left);
+        if item.index > 0 {
+            match group.orientation {
+                ButtonGroupOrientation::Horizontal => node.margin.left = -node.border.left,
+                ButtonGroupOrientation::Vertical => node.margin.top = -node.border.top,
+            }
+        }
+    }
+}
+
+impl Namespace for ButtonGroupBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_outer_corners_are_rounded() {
+        let outer = Val::Px(6.0);
+        let zero = Val::Px(0.0);
+        let horizontal = ButtonGroupOrientation::Horizontal;
+
+        let first = button_group_radius(0, 3, horizontal, outer);
+        assert_eq!((first.top_left, first.bottom_left), (outer, outer));
+        assert_eq!((first.top_right, first.bottom_right), (zero, zero));
+
+        let middle = button_group_radius(1, 3, horizontal, outer);
+        assert_eq!(middle, BorderRadius::all(zero));
+
+        let last = button_group_radius(2, 3, horizontal, outer);
+        assert_eq!((last.top_left, last.bottom_left), (zero, zero));
+        assert_eq!((last.top_right, last.bottom_right), (outer, outer));
+
+        // A single button keeps all its corners
+        assert_eq!(
+            button_group_radius(0, 1, horizontal, outer),
+            BorderRadius::all(outer)
+        );
+    }
+
+    #[test]
+    fn test_vertical_groups_round_top_and_bottom() {
+        let outer = Val::Px(6.0);
+        let zero = Val::Px(0.0);
+        let vertical = ButtonGroupOrientation::Vertical;
+
+        let first = button_group_radius(0, 2, vertical, outer);
+        assert_eq!((first.top_left, first.top_right), (outer, outer));
+        assert_eq!((first.bottom_left, first.bottom_right), (zero, zero));
+
+        let last = button_group_radius(1, 2, vertical, outer);
+        assert_eq!((last.top_left, last.top_right), (zero, zero));
+        assert_eq!((last.bottom_left, last.bottom_right), (outer, outer));
+    }
+}
@@ -0,0 +1,331 @@
+//! Callout Component for Forge UI
+//!
+//! An inline alert banner with a leading icon, an optional title and a
+//! description, colored by severity.
+//!
+//! ## Features
+//!
+//! - **Severities**: Info (accent), Success, Warning and Error palettes
+//! - **Variants**: Soft (tinted background) and Outline (border only)
+//! - **Icons**: A default icon per severity, overridable with `.icon()`
+//! - **Dismissible**: An optional close button emitting [`CalloutDismissEvent`]
+//!
+//! Long descriptions wrap within the callout's width; the icon stays aligned
+//! with the first line.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ui::components::{Callout, CalloutSeverity};
+//!
+//! let callout = Callout::new("SaveFailed")
+//!     .severity(CalloutSeverity::Error)
+//!     .title("Could not save")
+//!     .description("The disk is full. Free up some space and try again.")
+//!     .dismissible()
+//!     .build();
+//! ```
+
+use crate::{
+    assets::{CircleAlert, CircleCheck, Info, Interface, TriangleAlert, X},
+    components::{
+        button::{ButtonBuilder, ButtonClickEvent, ButtonSize, ButtonVariant},
+        text::Text as UiText,
+    },
+    theme::{
+        color::{
+            accent_palette, error_palette, success_palette, warning_palette,
+            TextColor as TextColorEnum, UiColorPalette,
+        },
+        layout::UiLayout,
+        typography::{FontFamily, TextSize},
+    },
+    utilities::naming::Namespace,
+};
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
+
+/// Event emitted when a dismissible callout is closed; the callout is
+/// despawned afterwards.
+#[derive(Event, Debug, Clone)]
+pub struct CalloutDismissEvent {
+    pub callout_entity: Entity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CalloutSeverity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl CalloutSeverity {
+    pub fn palette(self) -> UiColorPalette {
+        match self {
+            CalloutSeverity::Info => accent_palette(),
+            CalloutSeverity::Success => success_palette(),
+            CalloutSeverity::Warning => warning_palette(),
+            CalloutSeverity::Error => error_palette(),
+        }
+    }
+
+    /// Default leading icon glyph.
+    pub fn icon(self) -> &'static str {
+        match self {
+            CalloutSeverity::Info => Info,
+            CalloutSeverity::Success => CircleCheck,
+            CalloutSeverity::Warning => TriangleAlert,
+            CalloutSeverity::Error => CircleAlert,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CalloutVariant {
+    /// Tinted background without border
+    #[default]
+    Soft,
+    /// Transparent background with a colored border
+    Outline,
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct CalloutComponent {
+    pub severity: CalloutSeverity,
+    pub variant: CalloutVariant,
+    pub dismissible: bool,
+    pub color: UiColorPalette,
+}
+
+impl Default for CalloutComponent {
+    fn default() -> Self {
+        Self {
+            severity: CalloutSeverity::Info,
+            variant: CalloutVariant::Soft,
+            dismissible: false,
+            color: CalloutSeverity::Info.palette(),
+        }
+    }
+}
+
+impl CalloutComponent {
+    pub fn new(name: impl Into<String>) -> CalloutBuilder {
+        CalloutBuilder::new(name)
+    }
+
+    /// Background and border colors for the variant.
+    pub fn calculate_colors(&self) -> (Color, Color) {
+        match self.variant {
+            CalloutVariant::Soft => (self.color.bg, Color::NONE),
+            CalloutVariant::Outline => (Color::NONE, self.color.border),
+        }
+    }
+}
+
+/// Close button of a dismissible callout.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CalloutDismissButton {
+    pub callout: Entity,
+}
+
+pub struct CalloutBuilder {
+    name: String,
+    callout: CalloutComponent,
+    title: Option<String>,
+    description: Option<String>,
+    icon: Option<String>,
+    explicit_color: bool,
+    width: Val,
+}
+
+impl CalloutBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: format!("{}_Callout", name.into()),
+            callout: CalloutComponent::default(),
+            title: None,
+            description: None,
+            icon: None,
+            explicit_color: false,
+            width: Val::Percent(100.0),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the severity, which picks the palette and default icon.
+    pub fn severity(mut self, severity: CalloutSeverity) -> Self {
+        self.callout.severity = severity;
+        self
+    }
+
+    pub fn variant(mut self, variant: CalloutVariant) -> Self {
+        self.callout.variant = variant;
+        self
+    }
+
+    pub fn soft(self) -> Self {
+        self.variant(CalloutVariant::Soft)
+    }
+
+    pub fn outline(self) -> Self {
+        self.variant(CalloutVariant::Outline)
+    }
+
+    /// Adds a close button that emits [`CalloutDismissEvent`].
+    pub fn dismissible(mut self) -> Self {
+        self.callout.dismissible = true;
+        self
+    }
+
+    /// Replaces the severity's default icon with an interface icon glyph.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Overrides the severity palette.
+    pub fn color(mut self, color: UiColorPalette) -> Self {
+        self.callout.color = color;
+        self.explicit_color = true;
+        self
+    }
+
+    pub fn width(mut self, width: Val) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+impl CalloutBuilder {
+    fn calculate_style(&self) -> Node {
+        let layout = UiLayout::default();
+        Node {
+            width: self.width,
+            flex_direction: FlexDirection::Row,
+            // Keeps the icon next to the first line when the text wraps
+            align_items: AlignItems::FlexStart,
+            column_gap: Val::Px(layout.gap.sm),
+            padding: UiRect::all(Val::Px(layout.padding.base)),
+            border: UiRect::all(Val::Px(layout.border.xs)),
+            ..default()
+        }
+    }
+
+    pub fn build(mut self) -> impl Bundle {
+        if !self.explicit_color {
+            self.callout.color = self.callout.severity.palette();
+        }
+
+        let node = self.calculate_style();
+        let (background, border) = self.callout.calculate_colors();
+        let palette = self.callout.color.clone();
+        let dismissible = self.callout.dismissible;
+        let icon = self
+            .icon
+            .unwrap_or_else(|| self.callout.severity.icon().to_string());
+        let title = self.title;
+        let description = self.description;
+
+        (
+            Name::new(self.name),
+            self.callout,
+            node,
+            BackgroundColor(background),
+            BorderColor(border),
+            BorderRadius::all(Val::Px(UiLayout::default().radius.base)),
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                let callout = parent.target_entity();
+                let text_color = TextColorEnum::Custom(palette.text);
+
+                parent.spawn((
+                    Name::new("CalloutIcon"),
+                    Interface::new(icon)
+                        .size(TextSize::Base)
+                        .color(text_color)
+                        .build(),
+                ));
+
+                parent
+                    .spawn((
+                        Name::new("CalloutContent"),
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(UiLayout::default().gap.xs),
+                            flex_grow: 1.0,
+                            flex_shrink: 1.0,
+                            // Lets long descriptions wrap instead of widening the callout
+                            min_width: Val::Px(0.0),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|content| {
+                        if let Some(title) = title {
+                            content.spawn(
+                                UiText::label(title)
+                                    .size(TextSize::Sm)
+                                    .color(TextColorEnum::Custom(palette.text_contrast))
+                                    .build(),
+                            );
+                        }
+                        if let Some(description) = description {
+                            content.spawn(
+                                UiText::body(description)
+                                    .size(TextSize::Sm)
+                                    .color(text_color)
+                                    .build(),
+                            );
+                        }
+                    });
+
+                if dismissible {
+                    parent.spawn((
+                        ButtonBuilder::new("CalloutDismiss")
+                            .variant(ButtonVariant::Ghost)
+                            .size(ButtonSize::Small)
+                            .color(palette.clone())
+                            .icon_only(FontFamily::InterfaceFont, X)
+                            .accessible_name("Dismiss")
+                            .build(),
+                        CalloutDismissButton { callout },
+                    ));
+                }
+            })),
+        )
+    }
+}
+
+/// Emits [`CalloutDismissEvent`] and despawns the callout when its close
+/// button is clicked.
+pub fn handle_callout_dismiss(
+    mut commands: Commands,
+    mut button_events: EventReader<ButtonClickEvent>,
+    buttons: Query<&CalloutDismissButton>,
+    mut dismiss_events: EventWriter<CalloutDismissEvent>,
+) {
+    for event in button_events.read() {
+        if let Ok(button) = buttons.get(event.button_entity) {
+            dismiss_events.write(CalloutDismissEvent {
+                callout_entity: button.callout,
+            });
+            commands.entity(button.callout).despawn();
+        }
+    }
+}
+
+pub type Callout = CalloutComponent;
+
+impl Namespace for CalloutBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
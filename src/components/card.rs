@@ -1,8 +1,21 @@
 use crate::{
-    components::box_component::{BoxBuilder, BoxComponent, BoxVariant},
-    theme::color::UiColorPalette,
+    components::{
+        box_component::{BoxBuilder, BoxComponent, BoxVariant},
+        separator::SeparatorBuilder,
+        text::{Text as UiText, TextBuilder},
+    },
+    theme::{
+        color::{accent_palette, UiColorPalette},
+        layout::Elevation,
+        radix_theme::PanelSurface,
+    },
+    utilities::{
+        naming::Namespace,
+        node_edit::{NodeEdits, WithNode},
+        sizing::NodeSizing,
+    },
 };
-use bevy::prelude::*;
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
 
 /// Card component - content container inspired by Radix UI Card
 ///
@@ -45,6 +58,17 @@ impl Default for CardSize {
     }
 }
 
+impl CardSize {
+    /// Padding of the card, or of each slot of a card with slots
+    pub fn padding(self) -> f32 {
+        match self {
+            CardSize::Size1 => 8.0,
+            CardSize::Size2 => 16.0,
+            CardSize::Size3 => 24.0,
+        }
+    }
+}
+
 /// Card visual variants following Radix UI design
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CardVariant {
@@ -72,10 +96,67 @@ impl From<CardVariant> for BoxVariant {
     }
 }
 
+/// Content of a card's header, body or footer slot
+#[derive(Debug, Clone)]
+pub enum CardSlot {
+    /// An existing entity, placed in the slot
+    Entity(Entity),
+    /// Plain text: a title in the header, body text in the body and footer
+    Text(String),
+    /// Styled text, boxed as the builder is large
+    Styled(Box<TextBuilder>),
+}
+
+impl From<Entity> for CardSlot {
+    fn from(entity: Entity) -> Self {
+        CardSlot::Entity(entity)
+    }
+}
+
+impl From<&str> for CardSlot {
+    fn from(text: &str) -> Self {
+        CardSlot::Text(text.to_string())
+    }
+}
+
+impl From<String> for CardSlot {
+    fn from(text: String) -> Self {
+        CardSlot::Text(text)
+    }
+}
+
+impl From<TextBuilder> for CardSlot {
+    fn from(text: TextBuilder) -> Self {
+        CardSlot::Styled(Box::new(text))
+    }
+}
+
+/// Header slot of a card
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CardHeader;
+
+/// Divider between the header and the rest of a card
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CardDivider;
+
+/// Body slot of a card
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CardBody;
+
+/// Footer slot of a card, laying out its content in a row at the end
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CardFooter;
+
 /// Builder for creating Card components with fluent API
 pub struct CardBuilder {
     box_builder: BoxBuilder,
     card_size: CardSize,
+    variant: CardVariant,
+    palette: UiColorPalette,
+    explicit_background: bool,
+    header: Option<CardSlot>,
+    body: Option<CardSlot>,
+    footer: Option<CardSlot>,
 }
 
 impl CardBuilder {
@@ -89,11 +170,18 @@ impl CardBuilder {
         Self {
             box_builder,
             card_size: CardSize::default(),
+            variant: CardVariant::default(),
+            palette: accent_palette(),
+            explicit_background: false,
+            header: None,
+            body: None,
+            footer: None,
         }
     }
 
     /// Set the card variant
     pub fn variant(mut self, variant: CardVariant) -> Self {
+        self.variant = variant;
         self.box_builder = match variant {
             CardVariant::Surface => self.box_builder.surface(),
             CardVariant::Classic => self.box_builder.classic(),
@@ -122,11 +210,7 @@ impl CardBuilder {
         self.card_size = size;
 
         // Apply size-specific styling
-        self.box_builder = match size {
-            CardSize::Size1 => self.box_builder.padding(Val::Px(8.0)),
-            CardSize::Size2 => self.box_builder.padding(Val::Px(16.0)),
-            CardSize::Size3 => self.box_builder.padding(Val::Px(24.0)),
-        };
+        self.box_builder = self.box_builder.padding(Val::Px(size.padding()));
 
         self
     }
@@ -148,6 +232,7 @@ impl CardBuilder {
 
     /// Set the color palette for the card
     pub fn color_palette(mut self, palette: UiColorPalette) -> Self {
+        self.palette = palette.clone();
         self.box_builder = self.box_builder.color(palette);
         self
     }
@@ -184,6 +269,7 @@ impl CardBuilder {
 
     /// Set explicit background color (overrides theme)
     pub fn background_color(mut self, color: Color) -> Self {
+        self.explicit_background = true;
         self.box_builder = self.box_builder.background_color(color);
         self
     }
@@ -193,6 +279,63 @@ impl CardBuilder {
         self.box_builder = self.box_builder.border_color(color);
         self
     }
+
+    /// Set the border width of each side, see [`BoxBuilder::border_sides`]
+    pub fn border_sides(mut self, top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        self.box_builder = self.box_builder.border_sides(top, right, bottom, left);
+        self
+    }
+
+    /// Set the top border width, e.g. for an accent stripe
+    pub fn border_top(mut self, width: f32) -> Self {
+        self.box_builder = self.box_builder.border_top(width);
+        self
+    }
+
+    /// Set the bottom border width
+    pub fn border_bottom(mut self, width: f32) -> Self {
+        self.box_builder = self.box_builder.border_bottom(width);
+        self
+    }
+
+    /// Set the left and right border widths
+    pub fn border_x(mut self, width: f32) -> Self {
+        self.box_builder = self.box_builder.border_x(width);
+        self
+    }
+
+    /// Set the top and bottom border widths
+    pub fn border_y(mut self, width: f32) -> Self {
+        self.box_builder = self.box_builder.border_y(width);
+        self
+    }
+
+    /// Set the shadow level of the card
+    pub fn elevation(mut self, elevation: Elevation) -> Self {
+        self.box_builder = self.box_builder.elevation(elevation);
+        self
+    }
+
+    // === SLOTS ===
+
+    /// Set the header, separated from the rest by a divider; text becomes a
+    /// title
+    pub fn header(mut self, header: impl Into<CardSlot>) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Set the main content
+    pub fn body(mut self, body: impl Into<CardSlot>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Set the footer, e.g. a row of action buttons
+    pub fn footer(mut self, footer: impl Into<CardSlot>) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
 }
 
 impl Default for CardBuilder {
@@ -202,7 +345,25 @@ impl Default for CardBuilder {
 }
 
 impl CardBuilder {
-    pub fn build(self) -> impl Bundle {
+    pub fn build(mut self) -> impl Bundle {
+        // Surface and classic cards are panels and follow the theme's panel background
+        let panel = PanelSurface {
+            palette: self.palette.clone(),
+            solid: self.box_builder.calculate_background_color().0,
+            active: self.variant != CardVariant::Ghost && !self.explicit_background,
+        };
+
+        // Slots are padded themselves, so the divider spans the whole card
+        let (header, body, footer) = (self.header.take(), self.body.take(), self.footer.take());
+        let has_slots = header.is_some() || body.is_some() || footer.is_some();
+        if has_slots {
+            let node = self.box_builder.node_mut();
+            node.padding = UiRect::ZERO;
+            node.flex_direction = FlexDirection::Column;
+            node.align_items = AlignItems::Stretch;
+        }
+        let padding = Val::Px(self.card_size.padding());
+
         // Since BoxBuilder now returns impl Bundle, we need to build it directly
         // and add our Card component alongside it
         (
@@ -211,6 +372,142 @@ impl CardBuilder {
                 box_component: Default::default(), // We'll use a placeholder since we can't access the components directly
             },
             self.box_builder.build(),
+            panel,
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                let has_body = body.is_some();
+                if let Some(header) = header {
+                    let container = (
+                        Name::new("CardHeader"),
+                        CardHeader,
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            padding: UiRect::all(padding),
+                            ..default()
+                        },
+                    );
+                    spawn_slot(parent, container, header, true);
+                    if has_body || footer.is_some() {
+                        parent.spawn((
+                            SeparatorBuilder::new().name("CardDivider").build(),
+                            CardDivider,
+                        ));
+                    }
+                }
+                if let Some(body) = body {
+                    let container = (
+                        Name::new("CardBody"),
+                        CardBody,
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            flex_grow: 1.0,
+                            padding: UiRect::all(padding),
+                            ..default()
+                        },
+                    );
+                    spawn_slot(parent, container, body, false);
+                }
+                if let Some(footer) = footer {
+                    // The body's padding already separates the footer
+                    let top = if has_body { Val::ZERO } else { padding };
+                    let container = (
+                        Name::new("CardFooter"),
+                        CardFooter,
+                        Node {
+                            justify_content: JustifyContent::End,
+                            align_items: AlignItems::Center,
+                            column_gap: padding,
+                            padding: UiRect::new(padding, padding, top, padding),
+                            ..default()
+                        },
+                    );
+                    spawn_slot(parent, container, footer, false);
+                }
+            })),
         )
     }
 }
+
+/// Spawns a slot container with its content.
+fn spawn_slot(parent: &mut ChildSpawner, container: impl Bundle, slot: CardSlot, title: bool) {
+    let mut slot_entity = parent.spawn(container);
+    match slot {
+        CardSlot::Entity(entity) => {
+            slot_entity.add_child(entity);
+        }
+        CardSlot::Text(text) if title => {
+            slot_entity.with_child(UiText::title(text).build());
+        }
+        CardSlot::Text(text) => {
+            slot_entity.with_child(UiText::body(text).build());
+        }
+        CardSlot::Styled(text) => {
+            slot_entity.with_child((*text).build());
+        }
+    }
+}
+
+impl Namespace for CardBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        self.box_builder.name_mut()
+    }
+}
+
+impl WithNode for CardBuilder {
+    fn node_edits_mut(&mut self) -> &mut NodeEdits {
+        self.box_builder.node_edits_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slots_spawn_in_order_with_divider() {
+        let mut world = World::new();
+        let action = world.spawn(Node::default()).id();
+        let card = world
+            .spawn(
+                CardBuilder::new("Media")
+                    .header("Sunset")
+                    .body("Taken on the coast.")
+                    .footer(action)
+                    .build(),
+            )
+            .id();
+
+        let children: Vec<Entity> = world.get::<Children>(card).unwrap().iter().collect();
+        assert_eq!(children.len(), 4);
+        assert!(world.get::<CardHeader>(children[0]).is_some());
+        assert!(world.get::<CardDivider>(children[1]).is_some());
+        assert!(world.get::<CardBody>(children[2]).is_some());
+        assert!(world.get::<CardFooter>(children[3]).is_some());
+        assert_eq!(world.get::<ChildOf>(action).unwrap().parent(), children[3]);
+
+        // Text in the header is a title
+        let title = world.get::<Children>(children[0]).unwrap()[0];
+        let expected = world.spawn(UiText::title("Sunset").build()).id();
+        assert_eq!(
+            world.get::<TextFont>(title).unwrap().font_size,
+            world.get::<TextFont>(expected).unwrap().font_size
+        );
+        assert_eq!(world.get::<Node>(card).unwrap().padding, UiRect::ZERO);
+    }
+
+    #[test]
+    fn test_slots_are_optional() {
+        let mut world = World::new();
+        let card = world.spawn(CardBuilder::new("Plain").build()).id();
+        assert!(world
+            .get::<Children>(card)
+            .is_none_or(|children| children.is_empty()));
+
+        // No divider without anything below the header
+        let card = world
+            .spawn(CardBuilder::new("Titled").header("Title").build())
+            .id();
+        let children = world.get::<Children>(card).unwrap();
+        assert_eq!(children.len(), 1);
+        assert!(world.get::<CardDivider>(children[0]).is_none());
+    }
+}
@@ -1,9 +1,20 @@
 use crate::{
     assets::{Check, Interface},
+    components::{
+        a11y::{A11yState, UiA11y, UiA11yValue, UiRole},
+        control_label::{ControlInteractionChanged, ControlLabel, ControlLabelSpawner},
+        disabled::DisabledInteraction,
+        focus::Focusable,
+        sounds::MuteSounds,
+        validation::Validate,
+    },
     theme::{
-        color::{accent_palette, UiColorPalette},
+        color::{accent_palette, disabled_colors, UiColorPalette},
+        density::ui_density,
         layout::UiLayout,
+        radix_theme::{AccentPaletteUser, ThemeTokens, UsesAccentPalette},
     },
+    utilities::naming::{strip_namespace, Namespace},
 };
 use bevy::prelude::*;
 use bevy_picking::prelude::Pickable;
@@ -17,7 +28,7 @@ pub struct CheckboxChangeEvent {
 }
 
 /// Checkbox interaction state
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum CheckboxState {
     Normal,
     Hover,
@@ -29,12 +40,18 @@ pub enum CheckboxState {
 ///
 /// The Checkbox component provides a binary selection interface with visual feedback
 /// and keyboard support. Follows WAI-ARIA checkbox pattern for accessibility.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct CheckboxComponent {
     pub size: CheckboxSize,
     pub checked: bool,
     pub disabled: bool,
+    /// Whether the checkbox must be checked for a form to be valid
+    pub required: bool,
     pub color_palette: UiColorPalette,
+    /// Whether `color_palette` follows the accent color of the theme;
+    /// cleared by an explicit palette
+    pub follows_accent: bool,
     pub current_state: CheckboxState,
 }
 
@@ -44,19 +61,93 @@ impl Default for CheckboxComponent {
             size: CheckboxSize::Size2,
             checked: false,
             disabled: false,
+            required: false,
             color_palette: accent_palette(),
+            follows_accent: true,
             current_state: CheckboxState::Normal,
         }
     }
 }
 
+impl AccentPaletteUser for CheckboxComponent {
+    fn follows_accent(&self) -> bool {
+        self.follows_accent
+    }
+}
+
+impl A11yState for CheckboxComponent {
+    fn a11y_value(&self) -> UiA11yValue {
+        UiA11yValue::Checked(self.checked)
+    }
+}
+
+impl DisabledInteraction for CheckboxComponent {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    fn interaction_colors(&self) -> Option<(BackgroundColor, BorderColor)> {
+        Some(if self.disabled {
+            let (background, border, _) = disabled_colors(&self.color_palette);
+            (BackgroundColor(background), BorderColor(border))
+        } else {
+            self.calculate_colors()
+        })
+    }
+}
+
+impl Validate for CheckboxComponent {
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn validate(&self) -> bool {
+        !self.required || self.checked
+    }
+
+    fn valid_border_color(&self) -> BorderColor {
+        self.calculate_colors().1
+    }
+}
+
+impl CheckboxComponent {
+    /// Background and border colors for the current checked/interaction state.
+    pub fn calculate_colors(&self) -> (BackgroundColor, BorderColor) {
+        let palette = &self.color_palette;
+
+        let background = match (self.checked, self.current_state) {
+            (true, CheckboxState::Normal) => BackgroundColor(palette.solid),
+            (true, CheckboxState::Hover) => BackgroundColor(palette.solid_hover),
+            (true, CheckboxState::Active) => BackgroundColor(palette.solid_hover),
+            (false, CheckboxState::Normal) => BackgroundColor(Color::NONE),
+            (false, CheckboxState::Hover) => BackgroundColor(palette.bg_hover),
+            (false, CheckboxState::Active) => BackgroundColor(palette.bg_active),
+            _ => BackgroundColor(Color::NONE),
+        };
+
+        let border = match (self.checked, self.current_state) {
+            (true, _) => BorderColor(palette.solid),
+            (false, CheckboxState::Normal) => BorderColor(palette.border),
+            (false, CheckboxState::Hover) => BorderColor(palette.border_hover),
+            (false, CheckboxState::Active) => BorderColor(palette.solid),
+            _ => BorderColor(palette.border),
+        };
+
+        (background, border)
+    }
+}
+
 /// Size variants for Checkbox component
 ///
 /// Provides standardized sizing following Radix UI specifications:
 /// - Size1: 16px (compact)
 /// - Size2: 20px (default)
 /// - Size3: 24px (large)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
 pub enum CheckboxSize {
     /// 16px - Compact size for dense layouts
     Size1,
@@ -68,13 +159,14 @@ pub enum CheckboxSize {
 }
 
 impl CheckboxSize {
-    /// Convert size to pixel dimensions
+    /// Convert size to pixel dimensions at the current UI density
     pub fn to_pixels(self) -> f32 {
-        match self {
+        let size = match self {
             CheckboxSize::Size1 => 16.0,
             CheckboxSize::Size2 => 20.0,
             CheckboxSize::Size3 => 24.0,
-        }
+        };
+        size * ui_density().control_scale()
     }
 
     /// Get appropriate checkmark size (80% of checkbox size)
@@ -97,6 +189,9 @@ pub struct CheckboxBuilder {
     name: String,
     checkbox_config: CheckboxComponent,
     node: Node,
+    label_text: Option<String>,
+    a11y_label: Option<String>,
+    mute_sounds: bool,
 }
 
 impl CheckboxComponent {
@@ -143,6 +238,9 @@ impl CheckboxBuilder {
                 align_items: AlignItems::Center,
                 ..default()
             },
+            label_text: None,
+            a11y_label: None,
+            mute_sounds: false,
         }
     }
 
@@ -192,6 +290,30 @@ impl CheckboxBuilder {
         self
     }
 
+    /// Set checkbox as required for forms; it is valid once checked
+    pub fn required(mut self) -> Self {
+        self.checkbox_config.required = true;
+        self
+    }
+
+    /// Keep the checkbox silent, whatever the global sound settings
+    pub fn mute_sounds(mut self) -> Self {
+        self.mute_sounds = true;
+        self
+    }
+
+    /// Add label text beside the checkbox; clicking it toggles the checkbox
+    pub fn label(mut self, text: impl Into<String>) -> Self {
+        self.label_text = Some(text.into());
+        self
+    }
+
+    /// Overrides the [`UiA11y`] label, which defaults to the label text or the name
+    pub fn a11y_label(mut self, label: impl Into<String>) -> Self {
+        self.a11y_label = Some(label.into());
+        self
+    }
+
     // =========================================================================
     // STYLING METHODS
     // =========================================================================
@@ -199,12 +321,15 @@ impl CheckboxBuilder {
     /// Set color palette for theming
     pub fn color(mut self, palette: UiColorPalette) -> Self {
         self.checkbox_config.color_palette = palette;
+        self.checkbox_config.follows_accent = false;
         self
     }
 
     /// Use accent color palette
-    pub fn accent(self) -> Self {
-        self.color(accent_palette())
+    pub fn accent(mut self) -> Self {
+        self.checkbox_config.color_palette = accent_palette();
+        self.checkbox_config.follows_accent = true;
+        self
     }
 
     // =========================================================================
@@ -240,7 +365,7 @@ impl CheckboxBuilder {
         let palette = &self.checkbox_config.color_palette;
 
         if self.checkbox_config.disabled {
-            return BackgroundColor(palette.bg_subtle.with_alpha(0.5));
+            return BackgroundColor(disabled_colors(palette).0);
         }
 
         match (
@@ -262,7 +387,7 @@ impl CheckboxBuilder {
         let palette = &self.checkbox_config.color_palette;
 
         if self.checkbox_config.disabled {
-            return BorderColor(palette.border.with_alpha(0.5));
+            return BorderColor(disabled_colors(palette).1);
         }
 
         match (
@@ -289,6 +414,16 @@ impl CheckboxBuilder {
         let background_color = self.calculate_background_color();
         let border_color = self.calculate_border_color();
         let border_radius = self.calculate_border_radius();
+        let focusable = Focusable::new().disabled(self.checkbox_config.disabled);
+        let a11y_label = self
+            .a11y_label
+            .or_else(|| self.label_text.clone())
+            .unwrap_or_else(|| {
+                let name = strip_namespace(&self.name);
+                name.strip_suffix("_Checkbox").unwrap_or(name).to_string()
+            });
+        let a11y = UiA11y::new(UiRole::Checkbox, a11y_label)
+            .value(self.checkbox_config.a11y_value());
 
         (
             Name::new(self.name),
@@ -298,6 +433,10 @@ impl CheckboxBuilder {
             border_color,
             border_radius,
             Pickable::default(),
+            focusable,
+            MuteSounds(self.mute_sounds),
+            ControlLabelSpawner(self.label_text),
+            a11y,
             Button,
             Interaction::None,
         )
@@ -308,23 +447,27 @@ impl CheckboxBuilder {
 // INTERACTION SYSTEMS
 // =========================================================================
 
-/// System to handle checkbox interactions
+/// System to handle checkbox interactions, on the checkbox or its label
 pub fn handle_checkbox_interactions(
-    mut interaction_query: Query<
-        (
-            Entity,
-            &Interaction,
-            &mut CheckboxComponent,
-            &mut BackgroundColor,
-            &mut BorderColor,
-        ),
-        (With<CheckboxComponent>, Changed<Interaction>),
-    >,
+    interaction_query: Query<(Entity, &Interaction), ControlInteractionChanged<CheckboxComponent>>,
+    label_query: Query<(&ControlLabel, &Interaction), Changed<Interaction>>,
+    mut checkbox_query: Query<(
+        &mut CheckboxComponent,
+        &mut BackgroundColor,
+        &mut BorderColor,
+    )>,
     mut checkbox_events: EventWriter<CheckboxChangeEvent>,
 ) {
-    for (entity, interaction, mut checkbox, mut bg_color, mut border_color) in
-        &mut interaction_query
-    {
+    let interactions = interaction_query.iter().chain(
+        label_query
+            .iter()
+            .map(|(label, interaction)| (label.control, interaction)),
+    );
+    for (entity, interaction) in interactions {
+        let Ok((mut checkbox, mut bg_color, mut border_color)) = checkbox_query.get_mut(entity)
+        else {
+            continue;
+        };
         if checkbox.disabled {
             continue;
         }
@@ -357,27 +500,7 @@ pub fn handle_checkbox_interactions(
 
         // Update visual appearance if state changed
         if old_state != checkbox.current_state || old_checked != checkbox.checked {
-            let palette = &checkbox.color_palette;
-
-            // Update background color
-            *bg_color = match (checkbox.checked, checkbox.current_state) {
-                (true, CheckboxState::Normal) => BackgroundColor(palette.solid),
-                (true, CheckboxState::Hover) => BackgroundColor(palette.solid_hover),
-                (true, CheckboxState::Active) => BackgroundColor(palette.solid_hover),
-                (false, CheckboxState::Normal) => BackgroundColor(Color::NONE),
-                (false, CheckboxState::Hover) => BackgroundColor(palette.bg_hover),
-                (false, CheckboxState::Active) => BackgroundColor(palette.bg_active),
-                _ => BackgroundColor(Color::NONE),
-            };
-
-            // Update border color
-            *border_color = match (checkbox.checked, checkbox.current_state) {
-                (true, _) => BorderColor(palette.solid),
-                (false, CheckboxState::Normal) => BorderColor(palette.border),
-                (false, CheckboxState::Hover) => BorderColor(palette.border_hover),
-                (false, CheckboxState::Active) => BorderColor(palette.solid),
-                _ => BorderColor(palette.border),
-            };
+            (*bg_color, *border_color) = checkbox.calculate_colors();
         }
     }
 }
@@ -431,6 +554,42 @@ pub fn update_checkmarks(
 #[derive(Component)]
 pub struct CheckmarkComponent;
 
+/// System to re-apply the accent palette to checkboxes following it after the
+/// accent color of the `RadixTheme` changed
+pub fn refresh_checkbox_accent(
+    tokens: Res<ThemeTokens>,
+    mut checkbox_query: Query<
+        (
+            &mut CheckboxComponent,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            Option<&Children>,
+        ),
+        With<UsesAccentPalette>,
+    >,
+    mut checkmark_query: Query<&mut TextColor, With<CheckmarkComponent>>,
+) {
+    for (mut checkbox, mut bg_color, mut border_color, children) in &mut checkbox_query {
+        if checkbox.color_palette == tokens.accent {
+            continue;
+        }
+        checkbox.color_palette = tokens.accent.clone();
+
+        if let Some(colors) = checkbox.interaction_colors() {
+            (*bg_color, *border_color) = colors;
+        }
+
+        let Some(children) = children else {
+            continue;
+        };
+        for child in children.iter() {
+            if let Ok(mut text_color) = checkmark_query.get_mut(child) {
+                text_color.0 = checkbox.color_palette.text_contrast;
+            }
+        }
+    }
+}
+
 /// Helper function to spawn a checkmark icon from the texture atlas
 fn spawn_checkmark_with_atlas(commands: &mut Commands, parent_entity: Entity) {
     // Use the Check icon from the interface font
@@ -463,3 +622,9 @@ fn spawn_checkmark_with_atlas(commands: &mut Commands, parent_entity: Entity) {
 
 // Convenience type alias
 pub type Checkbox = CheckboxComponent;
+
+impl Namespace for CheckboxBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
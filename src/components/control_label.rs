@@ -0,0 +1,124 @@
+//! Clickable labels of checkboxes and radios.
+//!
+//! Like an HTML `<label for>`, the label text spawned by the checkbox and
+//! radio builders' `.label()` forwards its pointer interaction to the control:
+//! hovering it highlights the control and clicking it toggles the control.
+//! The label carries an [`Interaction`] and a [`ControlLabel`] pointing at the
+//! control, which the controls' interaction systems handle like their own.
+
+use crate::{
+    components::text::Text as UiText,
+    utilities::side_label::{LabelSide, SideLabel},
+};
+use bevy::prelude::*;
+
+/// Gap between a control and its label
+pub const CONTROL_LABEL_GAP: f32 = 8.0;
+
+/// Label whose interaction is forwarded to `control`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ControlLabel {
+    pub control: Entity,
+}
+
+/// Controls of type `C` whose own interaction changed.
+pub type ControlInteractionChanged<C> = (With<C>, Changed<Interaction>);
+
+/// Label text to spawn beside a control; removed once spawned.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ControlLabelSpawner(pub Option<String>);
+
+/// System that spawns the label text of new checkboxes and radios.
+pub fn spawn_control_labels(
+    mut commands: Commands,
+    query: Query<(Entity, &ControlLabelSpawner), Added<ControlLabelSpawner>>,
+) {
+    for (control, spawner) in &query {
+        commands.entity(control).remove::<ControlLabelSpawner>();
+        let Some(label) = &spawner.0 else {
+            continue;
+        };
+
+        // Anchored just outside the control, which makes room for it
+        let side_label = SideLabel::new(LabelSide::Right, CONTROL_LABEL_GAP);
+        let label_entity = commands
+            .spawn((
+                Name::new("ControlLabel"),
+                UiText::label(label.clone()).build(),
+                ControlLabel { control },
+                Interaction::None,
+            ))
+            .insert((side_label.node(), side_label))
+            .id();
+        commands.entity(control).add_child(label_entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{
+        checkbox::{handle_checkbox_interactions, CheckboxChangeEvent, CheckboxComponent},
+        radio::{handle_radio_interactions, RadioChangeEvent, RadioComponent},
+    };
+
+    fn spawn_labelled(world: &mut World, control: impl Bundle) -> (Entity, Entity) {
+        let control = world
+            .spawn((
+                control,
+                BackgroundColor::default(),
+                BorderColor::default(),
+                Interaction::None,
+            ))
+            .id();
+        let label = world
+            .spawn((ControlLabel { control }, Interaction::None))
+            .id();
+        (control, label)
+    }
+
+    fn set_interaction(world: &mut World, entity: Entity, interaction: Interaction) {
+        *world.get_mut::<Interaction>(entity).unwrap() = interaction;
+    }
+
+    #[test]
+    fn test_label_click_toggles_checkbox() {
+        let mut world = World::new();
+        world.init_resource::<Events<CheckboxChangeEvent>>();
+        let (checkbox, label) = spawn_labelled(&mut world, CheckboxComponent::default());
+        let system = world.register_system(handle_checkbox_interactions);
+        world.run_system(system).unwrap();
+
+        // Press and release on the label
+        set_interaction(&mut world, label, Interaction::Pressed);
+        world.run_system(system).unwrap();
+        set_interaction(&mut world, label, Interaction::Hovered);
+        world.run_system(system).unwrap();
+
+        assert!(world.get::<CheckboxComponent>(checkbox).unwrap().checked);
+        assert_eq!(world.resource::<Events<CheckboxChangeEvent>>().len(), 1);
+    }
+
+    #[test]
+    fn test_label_click_ignores_disabled_radio() {
+        let mut world = World::new();
+        world.init_resource::<Events<RadioChangeEvent>>();
+        let (radio, label) = spawn_labelled(
+            &mut world,
+            RadioComponent {
+                disabled: true,
+                ..Default::default()
+            },
+        );
+        let system = world.register_system(handle_radio_interactions);
+        world.run_system(system).unwrap();
+
+        set_interaction(&mut world, label, Interaction::Pressed);
+        world.run_system(system).unwrap();
+        set_interaction(&mut world, label, Interaction::Hovered);
+        world.run_system(system).unwrap();
+
+        assert!(!world.get::<RadioComponent>(radio).unwrap().checked);
+        assert!(world.resource::<Events<RadioChangeEvent>>().is_empty());
+    }
+}
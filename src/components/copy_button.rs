@@ -0,0 +1,340 @@
+//! Button copying text to the system clipboard.
+//!
+//! Clicking a [`CopyButton`] writes its text to the clipboard and emits a
+//! [`CopyEvent`]. After a successful copy the button shows a check and
+//! "Copied!" for [`CopyButtonBuilder::feedback_duration`], then reverts; the
+//! revert is driven by a [`CopyFeedback`] timer, nothing blocks.
+//!
+//! Clipboard access uses `arboard` behind the `clipboard` feature. Without the
+//! feature (and on the web) copying is a no-op that reports `success: false`.
+//!
+//! ```rust
+//! use ui::components::CopyButton;
+//!
+//! commands.spawn(CopyButton::new("cargo add ui").build());
+//! ```
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    assets::icons::interface::{Check as CHECK_ICON, Copy as COPY_ICON},
+    components::button::{
+        ButtonBuilder, ButtonClickEvent, ButtonIcon, ButtonLabel, ButtonSize, ButtonVariant,
+    },
+    theme::typography::FontFamily,
+    utilities::naming::Namespace,
+};
+
+/// Default time the "copied" feedback is shown
+pub const COPY_FEEDBACK_DURATION: Duration = Duration::from_secs(2);
+/// Default label of a copy button
+pub const COPY_LABEL: &str = "Copy";
+/// Default label shown after copying
+pub const COPIED_LABEL: &str = "Copied!";
+
+/// Button that copies `text` to the clipboard when clicked.
+#[derive(Component, Debug, Clone)]
+pub struct CopyButton {
+    pub text: String,
+    /// Label shown normally; `None` for icon-only buttons
+    pub label: Option<String>,
+    pub copied_label: String,
+    pub feedback_duration: Duration,
+}
+
+impl CopyButton {
+    /// Builder of a button copying `text`.
+    pub fn new(text: impl Into<String>) -> CopyButtonBuilder {
+        CopyButtonBuilder::new(text)
+    }
+}
+
+/// Running "copied" feedback; removed when the timer finishes.
+#[derive(Component, Debug)]
+pub struct CopyFeedback {
+    pub timer: Timer,
+}
+
+/// Event emitted when a copy button was clicked.
+#[derive(Event, Debug, Clone)]
+pub struct CopyEvent {
+    pub copy_entity: Entity,
+    pub text: String,
+    /// Whether the text reached the clipboard
+    pub success: bool,
+}
+
+/// Builder of a [`CopyButton`], a small soft button labelled "Copy" by
+/// default.
+pub struct CopyButtonBuilder {
+    name: String,
+    copy: CopyButton,
+    variant: ButtonVariant,
+    size: ButtonSize,
+    icon_only: bool,
+}
+
+impl CopyButtonBuilder {
+    /// Builder of a button copying `text`.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            name: "Copy".to_string(),
+            copy: CopyButton {
+                text: text.into(),
+                label: Some(COPY_LABEL.to_string()),
+                copied_label: COPIED_LABEL.to_string(),
+                feedback_duration: COPY_FEEDBACK_DURATION,
+            },
+            variant: ButtonVariant::Soft,
+            size: ButtonSize::Small,
+            icon_only: false,
+        }
+    }
+
+    /// Entity name of the button, "Copy" by default
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Label shown beside the copy icon until the text is copied
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.copy.label = Some(label.into());
+        self
+    }
+
+    /// Label shown after copying, "Copied!" by default
+    pub fn copied_label(mut self, label: impl Into<String>) -> Self {
+        self.copy.copied_label = label.into();
+        self
+    }
+
+    /// How long the check and copied label are shown after copying
+    pub fn feedback_duration(mut self, duration: Duration) -> Self {
+        self.copy.feedback_duration = duration;
+        self
+    }
+
+    /// Shows only the copy icon, swapped for a check after copying
+    pub fn icon_only(mut self) -> Self {
+        self.icon_only = true;
+        self.copy.label = None;
+        self
+    }
+
+    /// Button variant, [`ButtonVariant::Soft`] by default
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Button size, [`ButtonSize::Small`] by default
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Button bundle with the [`CopyButton`]; clicks are handled by the
+    /// copy systems of the components plugin.
+    pub fn build(self) -> impl Bundle {
+        let button = ButtonBuilder::new(self.name)
+            .variant(self.variant)
+            .size(self.size);
+        let button = match &self.copy.label {
+            Some(label) if !self.icon_only => button
+                .icon(FontFamily::InterfaceFont, COPY_ICON)
+                .text(label),
+            _ => button
+                .icon_only(FontFamily::InterfaceFont, COPY_ICON)
+                .accessible_name(COPY_LABEL),
+        };
+
+        (button.build(), self.copy)
+    }
+}
+
+/// Handle to the system clipboard, stored as a non-send resource.
+///
+/// Kept alive between copies: on X11 the copied text is only available as
+/// long as the handle that set it exists.
+#[derive(Default)]
+pub struct SystemClipboard {
+    #[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+    clipboard: Option<arboard::Clipboard>,
+}
+
+impl SystemClipboard {
+    /// Writes `text` to the clipboard, returning whether it succeeded.
+    #[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+    pub fn set_text(&mut self, text: &str) -> bool {
+        if self.clipboard.is_none() {
+            self.clipboard = arboard::Clipboard::new()
+                .map_err(|error| warn!("Clipboard unavailable: {error}"))
+                .ok();
+        }
+        self.clipboard.as_mut().is_some_and(|clipboard| {
+            clipboard
+                .set_text(text)
+                .map_err(|error| warn!("Copying to the clipboard failed: {error}"))
+                .is_ok()
+        })
+    }
+
+    /// Clipboard access is unsupported here; nothing is copied.
+    #[cfg(not(all(feature = "clipboard", not(target_arch = "wasm32"))))]
+    pub fn set_text(&mut self, _text: &str) -> bool {
+        false
+    }
+}
+
+/// Shows the copied (check + copied label) or the normal content of a copy
+/// button.
+fn show_copied(
+    copy: &CopyButton,
+    children: &Children,
+    copied: bool,
+    texts: &mut Query<(&mut Text, Has<ButtonLabel>, Has<ButtonIcon>)>,
+) {
+    for child in children.iter() {
+        let Ok((mut text, is_label, is_icon)) = texts.get_mut(child) else {
+            continue;
+        };
+        if is_icon {
+            text.0 = if copied { CHECK_ICON } else { COPY_ICON }.to_string();
+        } else if let (true, Some(label)) = (is_label, &copy.label) {
+            text.0 = if copied {
+                copy.copied_label.clone()
+            } else {
+                label.clone()
+            };
+        }
+    }
+}
+
+/// System that copies the text of clicked copy buttons and starts their
+/// feedback.
+pub fn handle_copy_button_clicks(
+    mut commands: Commands,
+    mut click_events: EventReader<ButtonClickEvent>,
+    copy_buttons: Query<(&CopyButton, &Children)>,
+    mut texts: Query<(&mut Text, Has<ButtonLabel>, Has<ButtonIcon>)>,
+    mut clipboard: NonSendMut<SystemClipboard>,
+    mut copy_events: EventWriter<CopyEvent>,
+) {
+    for event in click_events.read() {
+        let Ok((copy, children)) = copy_buttons.get(event.button_entity) else {
+            continue;
+        };
+
+        let success = clipboard.set_text(&copy.text);
+        copy_events.write(CopyEvent {
+            copy_entity: event.button_entity,
+            text: copy.text.clone(),
+            success,
+        });
+
+        if success {
+            // Copying again restarts the feedback
+            commands.entity(event.button_entity).insert(CopyFeedback {
+                timer: Timer::new(copy.feedback_duration, TimerMode::Once),
+            });
+            show_copied(copy, children, true, &mut texts);
+        }
+    }
+}
+
+/// System that reverts copy buttons once their feedback time is over.
+pub fn revert_copy_feedback(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut copy_buttons: Query<(Entity, &CopyButton, &Children, &mut CopyFeedback)>,
+    mut texts: Query<(&mut Text, Has<ButtonLabel>, Has<ButtonIcon>)>,
+) {
+    for (entity, copy, children, mut feedback) in &mut copy_buttons {
+        if feedback.timer.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<CopyFeedback>();
+            show_copied(copy, children, false, &mut texts);
+        }
+    }
+}
+
+impl Namespace for CopyButtonBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn spawn_copy_button(world: &mut World, feedback: Option<f32>) -> (Entity, Entity, Entity) {
+        let copy = CopyButton::new("secret-token").copy;
+        let button = world.spawn(copy).id();
+        let icon = world.spawn((Text::new(COPY_ICON), ButtonIcon)).id();
+        let label = world.spawn((Text::new(COPY_LABEL), ButtonLabel)).id();
+        world.entity_mut(button).add_children(&[icon, label]);
+        if let Some(seconds) = feedback {
+            world.entity_mut(button).insert(CopyFeedback {
+                timer: Timer::from_seconds(seconds, TimerMode::Once),
+            });
+        }
+        (button, icon, label)
+    }
+
+    fn text(world: &World, entity: Entity) -> &str {
+        &world.get::<Text>(entity).unwrap().0
+    }
+
+    #[test]
+    fn test_click_emits_copy_event() {
+        let mut world = World::new();
+        world.init_non_send_resource::<SystemClipboard>();
+        world.init_resource::<Events<ButtonClickEvent>>();
+        world.init_resource::<Events<CopyEvent>>();
+        let (button, _, _) = spawn_copy_button(&mut world, None);
+
+        world.send_event(ButtonClickEvent {
+            button_entity: button,
+            button_variant: ButtonVariant::Soft,
+        });
+        world.run_system_once(handle_copy_button_clicks).unwrap();
+
+        let events: Vec<CopyEvent> = world.resource_mut::<Events<CopyEvent>>().drain().collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].copy_entity, button);
+        assert_eq!(events[0].text, "secret-token");
+        // Feedback only follows a successful copy
+        assert_eq!(
+            world.get::<CopyFeedback>(button).is_some(),
+            events[0].success
+        );
+    }
+
+    #[test]
+    fn test_feedback_reverts_after_its_duration() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let (button, icon, label) = spawn_copy_button(&mut world, Some(0.5));
+        world.get_mut::<Text>(icon).unwrap().0 = CHECK_ICON.to_string();
+        world.get_mut::<Text>(label).unwrap().0 = COPIED_LABEL.to_string();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(0.25));
+        world.run_system_once(revert_copy_feedback).unwrap();
+        assert_eq!(text(&world, label), COPIED_LABEL);
+        assert!(world.get::<CopyFeedback>(button).is_some());
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(0.5));
+        world.run_system_once(revert_copy_feedback).unwrap();
+        assert_eq!(text(&world, label), COPY_LABEL);
+        assert_eq!(text(&world, icon), COPY_ICON);
+        assert!(world.get::<CopyFeedback>(button).is_none());
+    }
+}
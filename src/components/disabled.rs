@@ -0,0 +1,221 @@
+//! Shared handling of disabled controls.
+//!
+//! Components implementing [`DisabledInteraction`] get their pointer and
+//! keyboard input switched off while disabled: [`apply_disabled_interaction`]
+//! makes them [`Pickable::IGNORE`] (clicks fall through to whatever is below),
+//! lets `Interaction` pass through, resets it, and takes them out of the tab
+//! order. Re-enabling restores all of it.
+//!
+//! Buttons, checkboxes, radios, switches, toggles, selects and hoverable rows
+//! are handled out of the box. Use [`set_disabled`] to flip a control at
+//! runtime:
+//!
+//! ```rust
+//! use ui::components::set_disabled;
+//!
+//! fn lock_form(mut commands: Commands, submit: Res<SubmitButton>) {
+//!     set_disabled(&mut commands, submit.0, true);
+//! }
+//! ```
+
+use crate::components::{
+    button::Button, checkbox::CheckboxComponent, focus::Focusable, hover_highlight::HoverHighlight,
+    radio::RadioComponent, select::SelectComponent, switch::SwitchComponent,
+    toggle::ToggleComponent,
+};
+use bevy::{ecs::component::Mutable, prelude::*, ui::FocusPolicy};
+use bevy_picking::prelude::Pickable;
+
+/// A component with a `disabled` flag that should suppress pointer input.
+pub trait DisabledInteraction: Component<Mutability = Mutable> {
+    /// Whether the control is disabled.
+    fn is_disabled(&self) -> bool;
+
+    /// Sets the disabled flag of the control.
+    fn set_disabled(&mut self, disabled: bool);
+
+    /// Whether pointer and keyboard input is ignored; defaults to `is_disabled`.
+    fn ignores_input(&self) -> bool {
+        self.is_disabled()
+    }
+
+    /// Background and border colors to apply when input is switched off or on,
+    /// for components whose colors are not refreshed on change by their own
+    /// systems.
+    fn interaction_colors(&self) -> Option<(BackgroundColor, BorderColor)> {
+        None
+    }
+}
+
+/// Sets the disabled flag of whichever control `entity` is.
+///
+/// Pickability, focus and styling follow in the same frame through
+/// [`apply_disabled_interaction`] and the components' styling systems.
+pub fn set_disabled(commands: &mut Commands, entity: Entity, disabled: bool) {
+    commands.queue(move |world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+        set_disabled_flag::<Button>(&mut entity, disabled);
+        set_disabled_flag::<CheckboxComponent>(&mut entity, disabled);
+        set_disabled_flag::<RadioComponent>(&mut entity, disabled);
+        set_disabled_flag::<SwitchComponent>(&mut entity, disabled);
+        set_disabled_flag::<ToggleComponent>(&mut entity, disabled);
+        set_disabled_flag::<SelectComponent>(&mut entity, disabled);
+        set_disabled_flag::<HoverHighlight>(&mut entity, disabled);
+    });
+}
+
+fn set_disabled_flag<C: DisabledInteraction>(entity: &mut EntityWorldMut, disabled: bool) {
+    if let Some(mut component) = entity.get_mut::<C>() {
+        // Only touch the component when the flag flips, to keep change detection quiet
+        if component.is_disabled() != disabled {
+            component.set_disabled(disabled);
+        }
+    }
+}
+
+/// Input and color state of a `C` control, switched by
+/// [`apply_disabled_interaction`].
+type DisabledInputState<C> = (
+    &'static C,
+    &'static mut Pickable,
+    Option<&'static mut Interaction>,
+    Option<&'static mut FocusPolicy>,
+    Option<&'static mut Focusable>,
+    Option<&'static mut BackgroundColor>,
+    Option<&'static mut BorderColor>,
+);
+
+/// `C` controls whose flag changed or that just became pickable.
+type DisabledInputChanged<C> = Or<(Changed<C>, Added<Pickable>)>;
+
+/// System that switches pointer input of `C` controls off while they ignore
+/// input, and back on afterwards.
+pub fn apply_disabled_interaction<C: DisabledInteraction>(
+    mut query: Query<DisabledInputState<C>, DisabledInputChanged<C>>,
+) {
+    for (component, mut pickable, interaction, focus_policy, focusable, background, border) in
+        &mut query
+    {
+        let ignores_input = component.ignores_input();
+        if pickable.is_hoverable != ignores_input {
+            continue;
+        }
+
+        *pickable = if ignores_input {
+            Pickable::IGNORE
+        } else {
+            Pickable::default()
+        };
+        if let Some(mut interaction) = interaction {
+            interaction.set_if_neq(Interaction::None);
+        }
+        if let Some(mut focus_policy) = focus_policy {
+            *focus_policy = if ignores_input {
+                FocusPolicy::Pass
+            } else {
+                FocusPolicy::Block
+            };
+        }
+        if let Some(mut focusable) = focusable {
+            focusable.disabled = ignores_input;
+        }
+
+        if let Some((new_background, new_border)) = component.interaction_colors() {
+            if let Some(mut background) = background {
+                *background = new_background;
+            }
+            if let Some(mut border) = border {
+                *border = new_border;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::{
+            button::ButtonClickEvent,
+            toggle::{ToggleState, ToggleVariant},
+        },
+        test_support::{advance_frames, drain_events, pointer_click, test_app},
+        theme::color::{disabled_colors, theme},
+    };
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_disabled_button_is_not_pickable() {
+        let mut world = World::new();
+        let button = world
+            .spawn((
+                Button {
+                    disabled: true,
+                    ..Default::default()
+                },
+                Pickable::default(),
+                Focusable::new(),
+            ))
+            .id();
+
+        world
+            .run_system_once(apply_disabled_interaction::<Button>)
+            .unwrap();
+        let pickable = world.get::<Pickable>(button).unwrap();
+        assert!(!pickable.is_hoverable && !pickable.should_block_lower);
+        assert!(world.get::<Focusable>(button).unwrap().disabled);
+
+        world.get_mut::<Button>(button).unwrap().disabled = false;
+        world
+            .run_system_once(apply_disabled_interaction::<Button>)
+            .unwrap();
+        assert!(world.get::<Pickable>(button).unwrap().is_hoverable);
+        assert!(!world.get::<Focusable>(button).unwrap().disabled);
+    }
+
+    #[test]
+    fn test_click_on_disabled_button_emits_no_event() {
+        let mut app = test_app();
+        let button = app
+            .world_mut()
+            .spawn(Button::builder("delete").text("Delete").build())
+            .id();
+        advance_frames(&mut app, 1);
+
+        let mut commands = app.world_mut().commands();
+        set_disabled(&mut commands, button, true);
+        app.world_mut().flush();
+        pointer_click(&mut app, button);
+        assert!(drain_events::<ButtonClickEvent>(&mut app).is_empty());
+
+        let mut commands = app.world_mut().commands();
+        set_disabled(&mut commands, button, false);
+        app.world_mut().flush();
+        pointer_click(&mut app, button);
+        assert_eq!(drain_events::<ButtonClickEvent>(&mut app).len(), 1);
+    }
+
+    #[test]
+    fn test_disabled_controls_share_border_color() {
+        let palette = theme().blue;
+        let checkbox = CheckboxComponent {
+            disabled: true,
+            color_palette: palette.clone(),
+            ..Default::default()
+        };
+        let toggle = ToggleComponent {
+            disabled: true,
+            variant: ToggleVariant::Outline,
+            color: palette.clone(),
+            ..Default::default()
+        };
+
+        let (_, checkbox_border) = checkbox.interaction_colors().unwrap();
+        let toggle_border = toggle.get_styling(ToggleState::Normal).border_color;
+        let (_, disabled_border, _) = disabled_colors(&palette);
+        assert_eq!(checkbox_border.0, disabled_border);
+        assert_eq!(toggle_border.0, disabled_border);
+    }
+}
@@ -0,0 +1,194 @@
+//! Escape routing between stacked overlays.
+//!
+//! Open overlays (hover cards, select dropdowns, menubar menus) are layers
+//! of the [`DismissStack`], in the order they opened. Escape closes only the
+//! topmost layer: [`handle_escape`] pops it, sends a [`DismissEvent`] to the
+//! overlay and consumes the key, so systems running later in the frame don't
+//! see the press and nothing below the top layer closes with it. With no
+//! open layer, Escape passes through untouched.
+//!
+//! An overlay joins the stack by implementing [`Dismissable`] and adding
+//! [`track_dismissable_layers`] for its component; it closes itself when it
+//! reads a [`DismissEvent`] for its entity.
+//!
+//! ```rust
+//! impl Dismissable for Popover {
+//!     fn is_open(&self) -> bool {
+//!         self.open
+//!     }
+//! }
+//!
+//! app.add_systems(Update, (track_dismissable_layers::<Popover>, close_dismissed_popovers));
+//! ```
+
+use bevy::prelude::*;
+
+/// Component of an overlay that Escape can close.
+pub trait Dismissable: Component {
+    fn is_open(&self) -> bool;
+}
+
+/// Open dismissable layers, from bottom to top.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DismissStack {
+    layers: Vec<Entity>,
+}
+
+impl DismissStack {
+    /// Puts `layer` on top of the stack, unless it is in the stack already.
+    pub fn push(&mut self, layer: Entity) {
+        if !self.contains(layer) {
+            self.layers.push(layer);
+        }
+    }
+
+    /// Takes `layer` out of the stack, wherever it is.
+    pub fn remove(&mut self, layer: Entity) -> bool {
+        let len = self.layers.len();
+        self.layers.retain(|entity| *entity != layer);
+        self.layers.len() != len
+    }
+
+    pub fn pop(&mut self) -> Option<Entity> {
+        self.layers.pop()
+    }
+
+    /// The topmost layer, which Escape closes next.
+    pub fn top(&self) -> Option<Entity> {
+        self.layers.last().copied()
+    }
+
+    pub fn contains(&self, layer: Entity) -> bool {
+        self.layers.contains(&layer)
+    }
+
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+}
+
+/// Event asking the overlay `layer` to close.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DismissEvent {
+    pub layer: Entity,
+}
+
+/// Pushes `C` overlays onto the [`DismissStack`] when they open and removes
+/// them when they close or are despawned.
+pub fn track_dismissable_layers<C: Dismissable>(
+    mut stack: ResMut<DismissStack>,
+    layers: Query<(Entity, &C), Changed<C>>,
+    mut removed: RemovedComponents<C>,
+) {
+    for (entity, layer) in &layers {
+        if layer.is_open() {
+            stack.push(entity);
+        } else {
+            stack.remove(entity);
+        }
+    }
+    for entity in removed.read() {
+        stack.remove(entity);
+    }
+}
+
+/// Closes the topmost layer on Escape and consumes the key press.
+pub fn handle_escape(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut stack: ResMut<DismissStack>,
+    mut dismiss_events: EventWriter<DismissEvent>,
+) {
+    if !keyboard.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    let Some(layer) = stack.pop() else {
+        return;
+    };
+    keyboard.clear_just_pressed(KeyCode::Escape);
+    dismiss_events.write(DismissEvent { layer });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::menubar::{
+        dismiss_menubars, MenubarBuilder, MenubarComponent, MenubarItem, MenubarOpenEvent,
+    };
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn spawn_menubar(world: &mut World) -> Entity {
+        let root = {
+            let mut commands = world.commands();
+            MenubarBuilder::new()
+                .menu("File", vec![MenubarItem::new("open", "Open")])
+                .build(&mut commands)
+        };
+        world.flush();
+        root
+    }
+
+    fn open(world: &mut World, menubar: Entity) {
+        world
+            .get_mut::<MenubarComponent>(menubar)
+            .unwrap()
+            .set_open(Some(0));
+        world
+            .run_system_once(track_dismissable_layers::<MenubarComponent>)
+            .unwrap();
+    }
+
+    fn press_escape(world: &mut World) {
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        keyboard.press(KeyCode::Escape);
+        world.insert_resource(keyboard);
+        world.run_system_once(handle_escape).unwrap();
+        world.run_system_once(dismiss_menubars).unwrap();
+        world
+            .run_system_once(track_dismissable_layers::<MenubarComponent>)
+            .unwrap();
+    }
+
+    fn is_open(world: &World, menubar: Entity) -> bool {
+        world
+            .get::<MenubarComponent>(menubar)
+            .unwrap()
+            .open
+            .is_some()
+    }
+
+    #[test]
+    fn test_escape_closes_only_top_layer() {
+        let mut world = World::new();
+        world.init_resource::<DismissStack>();
+        world.init_resource::<Events<DismissEvent>>();
+        world.init_resource::<Events<MenubarOpenEvent>>();
+
+        let bottom = spawn_menubar(&mut world);
+        let top = spawn_menubar(&mut world);
+        open(&mut world, bottom);
+        open(&mut world, top);
+        assert_eq!(world.resource::<DismissStack>().top(), Some(top));
+
+        press_escape(&mut world);
+        assert!(!is_open(&world, top));
+        assert!(is_open(&world, bottom));
+        // The press is consumed for later systems
+        assert!(!world
+            .resource::<ButtonInput<KeyCode>>()
+            .just_pressed(KeyCode::Escape));
+
+        press_escape(&mut world);
+        assert!(!is_open(&world, bottom));
+        assert!(world.resource::<DismissStack>().is_empty());
+
+        // With nothing open, Escape passes through
+        press_escape(&mut world);
+        assert!(world
+            .resource::<ButtonInput<KeyCode>>()
+            .just_pressed(KeyCode::Escape));
+    }
+}
@@ -0,0 +1,240 @@
+//! Empty State Component for Forge UI
+//!
+//! A placeholder for views without content, such as "No results" after a
+//! search or an empty inbox: an optional icon, a title, a description and an
+//! optional action, stacked and centered within the parent.
+//!
+//! ## Features
+//!
+//! - **Muted colors**: Gray palette by default, overridable with `.color()`
+//! - **Centered**: Fills its parent and centers the content on both axes
+//! - **Wrapping**: Long descriptions wrap at a readable width
+//! - **Action slot**: Any existing entity, typically a button, placed below
+//!   the text
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ui::components::EmptyState;
+//!
+//! let clear = commands.spawn(ButtonBuilder::new("Clear filters").build()).id();
+//! commands.spawn(
+//!     EmptyState::new("NoResults")
+//!         .icon(SearchX)
+//!         .title("No results")
+//!         .description("Try a different search term or clear the filters.")
+//!         .action(clear)
+//!         .build(),
+//! );
+//! ```
+
+use crate::{
+    assets::Interface,
+    components::text::Text as UiText,
+    theme::{
+        color::{theme, TextColor as TextColorEnum, UiColorPalette},
+        layout::UiLayout,
+        typography::TextSize,
+    },
+    utilities::naming::Namespace,
+};
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
+
+/// Width at which descriptions wrap
+const DESCRIPTION_MAX_WIDTH: f32 = 360.0;
+
+#[derive(Component, Debug, Clone)]
+pub struct EmptyStateComponent {
+    pub color: UiColorPalette,
+}
+
+impl Default for EmptyStateComponent {
+    fn default() -> Self {
+        Self {
+            color: theme().gray,
+        }
+    }
+}
+
+impl EmptyStateComponent {
+    pub fn new(name: impl Into<String>) -> EmptyStateBuilder {
+        EmptyStateBuilder::new(name)
+    }
+}
+
+/// Container the action entity is placed in.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EmptyStateAction;
+
+pub struct EmptyStateBuilder {
+    name: String,
+    empty_state: EmptyStateComponent,
+    icon: Option<String>,
+    title: Option<String>,
+    description: Option<String>,
+    action: Option<Entity>,
+}
+
+impl EmptyStateBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: format!("{}_EmptyState", name.into()),
+            empty_state: EmptyStateComponent::default(),
+            icon: None,
+            title: None,
+            description: None,
+            action: None,
+        }
+    }
+
+    /// Shows an interface icon glyph above the title.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Places an existing entity, e.g. a button, below the description.
+    pub fn action(mut self, action: Entity) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Overrides the gray palette.
+    pub fn color(mut self, color: UiColorPalette) -> Self {
+        self.empty_state.color = color;
+        self
+    }
+}
+
+impl EmptyStateBuilder {
+    fn calculate_style(&self) -> Node {
+        let layout = UiLayout::default();
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(layout.gap.sm),
+            padding: UiRect::all(Val::Px(layout.padding.lg)),
+            ..default()
+        }
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let node = self.calculate_style();
+        let palette = self.empty_state.color.clone();
+        let icon = self.icon;
+        let title = self.title;
+        let description = self.description;
+        let action = self.action;
+
+        (
+            Name::new(self.name),
+            self.empty_state,
+            node,
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                let muted = TextColorEnum::Custom(palette.text);
+
+                if let Some(icon) = icon {
+                    parent.spawn((
+                        Name::new("EmptyStateIcon"),
+                        Interface::new(icon)
+                            .size(TextSize::X4l)
+                            .color(TextColorEnum::Custom(palette.solid))
+                            .build(),
+                    ));
+                }
+
+                if let Some(title) = title {
+                    parent.spawn((
+                        Name::new("EmptyStateTitle"),
+                        UiText::title(title)
+                            .size(TextSize::Lg)
+                            .color(TextColorEnum::Custom(palette.text_contrast))
+                            .center()
+                            .build(),
+                    ));
+                }
+
+                if let Some(description) = description {
+                    parent
+                        .spawn((
+                            Name::new("EmptyStateDescription"),
+                            Node {
+                                max_width: Val::Px(DESCRIPTION_MAX_WIDTH),
+                                ..default()
+                            },
+                        ))
+                        .with_children(|wrapper| {
+                            wrapper.spawn(
+                                UiText::body(description)
+                                    .size(TextSize::Sm)
+                                    .color(muted)
+                                    .center()
+                                    .build(),
+                            );
+                        });
+                }
+
+                if let Some(action) = action {
+                    parent
+                        .spawn((
+                            Name::new("EmptyStateAction"),
+                            EmptyStateAction,
+                            Node {
+                                margin: UiRect::top(Val::Px(UiLayout::default().gap.sm)),
+                                ..default()
+                            },
+                        ))
+                        .add_child(action);
+                }
+            })),
+        )
+    }
+}
+
+pub type EmptyState = EmptyStateComponent;
+
+impl Namespace for EmptyStateBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hierarchy_includes_action() {
+        let mut world = World::new();
+        let action = world.spawn(Node::default()).id();
+        let empty_state = world
+            .spawn(
+                EmptyState::new("NoResults")
+                    .title("No results")
+                    .description("Try a different search term.")
+                    .action(action)
+                    .build(),
+            )
+            .id();
+
+        let slot = world.get::<ChildOf>(action).unwrap().parent();
+        assert!(world.get::<EmptyStateAction>(slot).is_some());
+        assert_eq!(world.get::<ChildOf>(slot).unwrap().parent(), empty_state);
+
+        // Title, description and action, without an icon
+        assert_eq!(world.get::<Children>(empty_state).unwrap().len(), 3);
+    }
+}
@@ -1,9 +1,18 @@
 use crate::{
-    components::box_component::{RadiusLevel, SpacingLevel},
+    components::{
+        box_component::{RadiusLevel, SpacingLevel},
+        hover_highlight::{spawn_hover_highlight, HoverableRow},
+    },
     theme::{
         color::{accent_palette, UiColorPalette},
+        deferred_style::DeferredThemeStyle,
         layout::UiLayout,
     },
+    utilities::{
+        naming::Namespace,
+        node_edit::{NodeEdits, WithNode},
+        sizing::NodeSizing,
+    },
 };
 use bevy::prelude::*;
 use bevy_picking::prelude::Pickable;
@@ -162,6 +171,7 @@ use bevy_picking::prelude::Pickable;
 /// - Focus management respects logical tab order
 /// - Responsive layouts adapt to user accessibility preferences
 #[derive(Component, Debug, Clone)]
+#[component(on_add = spawn_hover_highlight::<FlexComponent>)]
 pub struct FlexComponent {
     pub direction: FlexDirection,
     pub wrap: FlexWrap,
@@ -171,6 +181,8 @@ pub struct FlexComponent {
     pub gap: FlexGap,
     pub color_palette: UiColorPalette,
     pub styling_config: FlexStyling,
+    /// Background while hovered, set by [`FlexBuilder::hoverable_row`]
+    pub hover_highlight: Option<Color>,
 }
 
 impl Default for FlexComponent {
@@ -184,10 +196,17 @@ impl Default for FlexComponent {
             gap: FlexGap::None,
             color_palette: accent_palette(),
             styling_config: FlexStyling::default(),
+            hover_highlight: None,
         }
     }
 }
 
+impl HoverableRow for FlexComponent {
+    fn hover_color(&self) -> Option<Color> {
+        self.hover_highlight
+    }
+}
+
 /// Gap configuration for flex layouts
 ///
 /// FlexGap defines how spacing is applied between flex children. It supports
@@ -362,6 +381,10 @@ pub struct FlexBuilder {
     node: Node,
     explicit_colors: ExplicitColors,
     children: Vec<Entity>,
+    deferred: DeferredThemeStyle,
+    visibility: Visibility,
+    hoverable: bool,
+    node_edits: NodeEdits,
 }
 
 impl FlexComponent {
@@ -373,6 +396,10 @@ impl FlexComponent {
             node: Node::default(),
             explicit_colors: ExplicitColors::default(),
             children: Vec::new(),
+            deferred: DeferredThemeStyle::default(),
+            visibility: Visibility::Inherited,
+            hoverable: false,
+            node_edits: NodeEdits::default(),
         }
     }
 
@@ -393,6 +420,26 @@ impl FlexComponent {
 }
 
 impl FlexBuilder {
+    /// Builds the node visible or hidden; hidden nodes keep their space in
+    /// the layout. See [`ShowWhen`](crate::utilities::ShowWhen) to follow an
+    /// app state instead.
+    pub fn visible_if(mut self, visible: bool) -> Self {
+        self.visibility = if visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        self
+    }
+
+    /// Makes the node a list row that shows the palette's `bg_hover` as its
+    /// background while hovered. See
+    /// [`HoverHighlight`](crate::components::hover_highlight::HoverHighlight).
+    pub fn hoverable_row(mut self) -> Self {
+        self.hoverable = true;
+        self
+    }
+
     // === FLEX DIRECTION METHODS ===
 
     /// Set flex direction to row (default)
@@ -583,6 +630,20 @@ impl FlexBuilder {
         self
     }
 
+    // === SELF ALIGNMENT METHODS ===
+
+    /// Override the parent's item alignment on the cross axis
+    pub fn align_self(mut self, align: AlignSelf) -> Self {
+        self.node.align_self = align;
+        self
+    }
+
+    /// Override the parent's item alignment within a grid cell
+    pub fn justify_self(mut self, justify: JustifySelf) -> Self {
+        self.node.justify_self = justify;
+        self
+    }
+
     // === GAP METHODS ===
 
     /// Set uniform gap for both row and column
@@ -591,10 +652,9 @@ impl FlexBuilder {
         self
     }
 
-    /// Set gap using theme spacing level
-    pub fn gap_level(mut self, _level: SpacingLevel) -> Self {
-        // We'll resolve this during build when we have access to the theme
-        self.flex_config.gap = FlexGap::Uniform(0.0); // Placeholder
+    /// Set gap using theme spacing level, resolved after spawn
+    pub fn gap_level(mut self, level: SpacingLevel) -> Self {
+        self.deferred = self.deferred.gap(level);
         self
     }
 
@@ -749,21 +809,21 @@ impl FlexBuilder {
         self
     }
 
-    /// Set padding using theme spacing level
-    pub fn pad(self, _level: SpacingLevel) -> Self {
-        // Note: We'll resolve the actual value during build when we have theme access
+    /// Set padding using theme spacing level, resolved after spawn
+    pub fn pad(mut self, level: SpacingLevel) -> Self {
+        self.deferred = self.deferred.pad(level);
         self
     }
 
-    /// Set horizontal padding using theme spacing level
-    pub fn pad_x(self, _level: SpacingLevel) -> Self {
-        // Note: We'll resolve the actual value during build when we have theme access
+    /// Set horizontal padding using theme spacing level, resolved after spawn
+    pub fn pad_x(mut self, level: SpacingLevel) -> Self {
+        self.deferred = self.deferred.pad_x(level);
         self
     }
 
-    /// Set vertical padding using theme spacing level
-    pub fn pad_y(self, _level: SpacingLevel) -> Self {
-        // Note: We'll resolve the actual value during build when we have theme access
+    /// Set vertical padding using theme spacing level, resolved after spawn
+    pub fn pad_y(mut self, level: SpacingLevel) -> Self {
+        self.deferred = self.deferred.pad_y(level);
         self
     }
 
@@ -913,15 +973,14 @@ impl FlexBuilder {
         self
     }
 
-    /// Set border radius using theme radius level
+    /// Set border radius using the base theme radius level
     pub fn rounded(self) -> Self {
-        // Note: We'll resolve the actual value during build when we have theme access
-        self
+        self.rounded_level(RadiusLevel::Base)
     }
 
-    /// Set border radius using specific theme radius level
-    pub fn rounded_level(self, _level: RadiusLevel) -> Self {
-        // Note: We'll resolve the actual value during build when we have theme access
+    /// Set border radius using specific theme radius level, resolved after spawn
+    pub fn rounded_level(mut self, level: RadiusLevel) -> Self {
+        self.deferred = self.deferred.radius(level);
         self
     }
 
@@ -980,6 +1039,11 @@ impl FlexBuilder {
             .border
             .unwrap_or(self.flex_config.color_palette.border);
 
+        if self.hoverable {
+            self.flex_config.hover_highlight = Some(self.flex_config.color_palette.bg_hover);
+        }
+        self.node_edits.apply(&mut self.node);
+
         (
             Name::new(self.name),
             self.flex_config,
@@ -988,9 +1052,84 @@ impl FlexBuilder {
             BorderColor(border_color),
             BorderRadius::all(Val::Px(UiLayout::default().radius.base)),
             Pickable::IGNORE,
+            self.deferred,
+            self.visibility,
         )
     }
 }
 
+impl NodeSizing for FlexBuilder {
+    fn node_mut(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl Namespace for FlexBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+impl WithNode for FlexBuilder {
+    fn node_edits_mut(&mut self) -> &mut NodeEdits {
+        &mut self.node_edits
+    }
+}
+
 // Type alias for convenience
 pub type Flex = FlexComponent;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn built_node(builder: FlexBuilder) -> Node {
+        let mut world = World::new();
+        let entity = world.spawn(builder.build()).id();
+        world.get::<Node>(entity).unwrap().clone()
+    }
+
+    #[test]
+    fn test_wrap_methods_set_flex_wrap() {
+        assert_eq!(
+            built_node(FlexComponent::row("default")).flex_wrap,
+            FlexWrap::NoWrap
+        );
+        assert_eq!(
+            built_node(FlexComponent::row("cards").wrap()).flex_wrap,
+            FlexWrap::Wrap
+        );
+        assert_eq!(
+            built_node(FlexComponent::row("reverse").wrap_reverse()).flex_wrap,
+            FlexWrap::WrapReverse
+        );
+        assert_eq!(
+            built_node(FlexComponent::row("single").wrap().nowrap()).flex_wrap,
+            FlexWrap::NoWrap
+        );
+    }
+
+    #[test]
+    fn test_content_distribution_shortcuts() {
+        let justify = |builder: FlexBuilder| built_node(builder).justify_content;
+        assert_eq!(
+            justify(FlexComponent::row("between").justify_between()),
+            JustifyContent::SpaceBetween
+        );
+        assert_eq!(
+            justify(FlexComponent::row("around").justify_around()),
+            JustifyContent::SpaceAround
+        );
+        assert_eq!(
+            justify(FlexComponent::row("evenly").justify_evenly()),
+            JustifyContent::SpaceEvenly
+        );
+
+        let node = built_node(
+            FlexComponent::row("cards")
+                .wrap()
+                .align_content(AlignContent::SpaceBetween),
+        );
+        assert_eq!(node.align_content, AlignContent::SpaceBetween);
+    }
+}
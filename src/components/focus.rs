@@ -0,0 +1,343 @@
+//! Keyboard focus and focus ring.
+//!
+//! Entities carrying [`Focusable`] take part in keyboard navigation: Tab and
+//! Shift+Tab move [`UiFocus`] through them, first those with an explicit
+//! `tab_index` (ascending), then all others in spawn order. Hidden and
+//! disabled entities are skipped.
+//!
+//! The focused entity gets an outline in the accent color, but only while the
+//! focus was moved with the keyboard; pressing a pointer on a focusable entity
+//! focuses it without showing the ring (like CSS `:focus-visible`). An
+//! outline the entity had before comes back once the ring moves on.
+//!
+//! Buttons, checkboxes, radios, switches, sliders and the text and number
+//! inputs are focusable out of the box. Inputs listen for [`FocusChangeEvent`] to start or
+//! stop editing when tabbed into or out of.
+//!
+//! ```rust
+//! use ui::components::Focusable;
+//!
+//! // Make a custom widget reachable with Tab, before all others
+//! commands.spawn((Node::default(), Interaction::default(), Focusable::new().tab_index(1)));
+//! ```
+
+use crate::theme::radix_theme::ThemeTokens;
+use bevy::{
+    ecs::{component::HookContext, world::DeferredWorld},
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+};
+use bevy_picking::prelude::{Pointer, Pressed};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Width of the focus ring
+pub const FOCUS_RING_WIDTH: f32 = 2.0;
+/// Gap between the focused element's border and the ring
+pub const FOCUS_RING_OFFSET: f32 = 2.0;
+
+static NEXT_FOCUS_ORDER: AtomicU64 = AtomicU64::new(0);
+
+/// Marks an entity as reachable with Tab / Shift+Tab.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+#[component(on_insert = assign_focus_order)]
+pub struct Focusable {
+    /// Explicit position in the tab order; entities with an index come first
+    pub tab_index: Option<u32>,
+    /// Disabled entities are skipped by keyboard navigation
+    pub disabled: bool,
+    /// Insertion counter, used as spawn order (replacing the component re-counts it)
+    order: u64,
+}
+
+impl Default for Focusable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Focusable {
+    pub fn new() -> Self {
+        Self {
+            tab_index: None,
+            disabled: false,
+            order: 0,
+        }
+    }
+
+    pub fn tab_index(mut self, tab_index: u32) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sort key for the tab order.
+    pub(crate) fn sort_key(&self) -> (bool, u32, u64) {
+        (
+            self.tab_index.is_none(),
+            self.tab_index.unwrap_or(0),
+            self.order,
+        )
+    }
+}
+
+fn assign_focus_order(mut world: DeferredWorld, context: HookContext) {
+    if let Some(mut focusable) = world.get_mut::<Focusable>(context.entity) {
+        focusable.order = NEXT_FOCUS_ORDER.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// The currently focused entity.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UiFocus {
+    pub focused: Option<Entity>,
+    /// Whether the focus ring is shown, i.e. focus was last moved with the keyboard
+    pub focus_visible: bool,
+}
+
+/// Event emitted when keyboard navigation moves the focus.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct FocusChangeEvent {
+    pub previous: Option<Entity>,
+    pub focused: Option<Entity>,
+}
+
+impl UiFocus {
+    /// Moves the focus with the keyboard, showing the focus ring, and emits a
+    /// [`FocusChangeEvent`] if the focused entity changed.
+    pub fn move_to(
+        &mut self,
+        focused: Option<Entity>,
+        change_events: &mut EventWriter<FocusChangeEvent>,
+    ) {
+        let previous = self.focused;
+        self.focused = focused;
+        self.focus_visible = focused.is_some();
+        if focused != previous {
+            change_events.write(FocusChangeEvent { previous, focused });
+        }
+    }
+}
+
+/// Next entity in the tab order after `current`, wrapping around; backwards
+/// for Shift+Tab. `candidates` must be sorted in tab order.
+pub fn next_in_tab_order(
+    candidates: &[Entity],
+    current: Option<Entity>,
+    backwards: bool,
+) -> Option<Entity> {
+    if candidates.is_empty() {
+        return None;
+    }
+    let last = candidates.len() - 1;
+    let index = match current.and_then(|entity| candidates.iter().position(|c| *c == entity)) {
+        Some(index) if backwards => index.checked_sub(1).unwrap_or(last),
+        Some(index) => (index + 1) % candidates.len(),
+        None if backwards => last,
+        None => 0,
+    };
+    Some(candidates[index])
+}
+
+/// Moves the focus on Tab / Shift+Tab.
+///
+/// Runs after the inputs' keyboard handlers, so an input reacting to Tab
+/// (e.g. a number input committing its value) still sees the key.
+pub fn handle_focus_navigation(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    keys: Res<ButtonInput<KeyCode>>,
+    focusables: Query<(Entity, &Focusable, &InheritedVisibility)>,
+    mut focus: ResMut<UiFocus>,
+    mut change_events: EventWriter<FocusChangeEvent>,
+) {
+    let tab_presses = keyboard_events
+        .read()
+        .filter(|event| event.state.is_pressed() && event.logical_key == Key::Tab)
+        .count();
+    if tab_presses == 0 {
+        return;
+    }
+
+    let mut candidates: Vec<_> = focusables
+        .iter()
+        .filter(|(_, focusable, visibility)| !focusable.disabled && visibility.get())
+        .map(|(entity, focusable, _)| (focusable.sort_key(), entity))
+        .collect();
+    candidates.sort_unstable();
+    let candidates: Vec<Entity> = candidates.into_iter().map(|(_, entity)| entity).collect();
+
+    let backwards = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let previous = focus.focused;
+    let mut focused = previous;
+    for _ in 0..tab_presses {
+        focused = next_in_tab_order(&candidates, focused, backwards);
+    }
+
+    focus.focused = focused;
+    focus.focus_visible = focused.is_some();
+    if focused != previous {
+        change_events.write(FocusChangeEvent { previous, focused });
+    }
+}
+
+/// Focuses the focusable entity (or ancestor) under a pressed pointer and
+/// hides the focus ring.
+pub fn focus_on_pointer_press(
+    trigger: Trigger<Pointer<Pressed>>,
+    focusables: Query<&Focusable>,
+    parents: Query<&ChildOf>,
+    mut focus: ResMut<UiFocus>,
+) {
+    // The event bubbles up the hierarchy; only handle it once, at the hit entity
+    if trigger.target() != trigger.event().target {
+        return;
+    }
+
+    let focused = std::iter::once(trigger.target())
+        .chain(parents.iter_ancestors(trigger.target()))
+        .find(|entity| {
+            focusables
+                .get(*entity)
+                .is_ok_and(|focusable| !focusable.disabled)
+        });
+
+    focus.set_if_neq(UiFocus {
+        focused,
+        focus_visible: false,
+    });
+}
+
+/// Marks an entity whose [`Outline`] is the focus ring, with the outline it
+/// had before, which comes back once the ring is gone.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct FocusRing {
+    previous: Option<Outline>,
+}
+
+/// Draws the focus ring as an outline around the keyboard-focused entity.
+pub fn update_focus_ring(
+    mut commands: Commands,
+    focus: Res<UiFocus>,
+    tokens: Res<ThemeTokens>,
+    rings: Query<(Entity, &FocusRing)>,
+    outlines: Query<&Outline, Without<FocusRing>>,
+) {
+    if !focus.is_changed() && !tokens.is_changed() {
+        return;
+    }
+
+    let ring_target = focus.focused.filter(|_| focus.focus_visible);
+    for (entity, ring) in &rings {
+        if Some(entity) == ring_target {
+            continue;
+        }
+        let mut entity = commands.entity(entity);
+        entity.remove::<FocusRing>();
+        match ring.previous {
+            Some(outline) => entity.insert(outline),
+            None => entity.remove::<Outline>(),
+        };
+    }
+
+    if let Some(entity) = ring_target {
+        let previous = outlines.get(entity).ok().copied();
+        let has_ring = rings.contains(entity);
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            if !has_ring {
+                entity.insert(FocusRing { previous });
+            }
+            entity.insert(Outline::new(
+                Val::Px(FOCUS_RING_WIDTH),
+                Val::Px(FOCUS_RING_OFFSET),
+                tokens.system.focus_ring,
+            ));
+        }
+    }
+}
+
+/// Drops the focus when the focused entity is despawned.
+pub fn clear_removed_focus(mut removed: RemovedComponents<Focusable>, mut focus: ResMut<UiFocus>) {
+    for entity in removed.read() {
+        if focus.focused == Some(entity) {
+            focus.focused = None;
+            focus.focus_visible = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_in_tab_order_wraps_both_ways() {
+        let mut world = World::new();
+        let entities: Vec<Entity> = (0..3).map(|_| world.spawn_empty().id()).collect();
+
+        assert_eq!(next_in_tab_order(&entities, None, false), Some(entities[0]));
+        assert_eq!(next_in_tab_order(&entities, None, true), Some(entities[2]));
+        assert_eq!(
+            next_in_tab_order(&entities, Some(entities[2]), false),
+            Some(entities[0])
+        );
+        assert_eq!(
+            next_in_tab_order(&entities, Some(entities[0]), true),
+            Some(entities[2])
+        );
+        assert_eq!(next_in_tab_order(&[], Some(entities[0]), false), None);
+    }
+
+    #[test]
+    fn test_explicit_tab_index_comes_before_spawn_order() {
+        let mut world = World::new();
+        let first = world.spawn(Focusable::new()).id();
+        let second = world.spawn(Focusable::new()).id();
+        let indexed = world.spawn(Focusable::new().tab_index(1)).id();
+
+        let mut order: Vec<_> = [first, second, indexed]
+            .into_iter()
+            .map(|entity| (world.get::<Focusable>(entity).unwrap().sort_key(), entity))
+            .collect();
+        order.sort_unstable();
+        let order: Vec<Entity> = order.into_iter().map(|(_, entity)| entity).collect();
+
+        assert_eq!(order, vec![indexed, first, second]);
+    }
+
+    #[test]
+    fn test_focus_ring_restores_the_app_outline() {
+        let mut world = World::new();
+        world.init_resource::<ThemeTokens>();
+        let app_outline = Outline::new(Val::Px(1.0), Val::ZERO, Color::BLACK);
+        let outlined = world.spawn((Focusable::new(), app_outline)).id();
+        let plain = world.spawn(Focusable::new()).id();
+        let ring = world.register_system(update_focus_ring);
+        let mut focus_on = |world: &mut World, focused: Entity| {
+            world.insert_resource(UiFocus {
+                focused: Some(focused),
+                focus_visible: true,
+            });
+            world.run_system(ring).unwrap();
+        };
+
+        focus_on(&mut world, outlined);
+        assert_eq!(
+            world.get::<Outline>(outlined).unwrap().color,
+            world.resource::<ThemeTokens>().system.focus_ring
+        );
+
+        focus_on(&mut world, plain);
+        assert_eq!(
+            world.get::<Outline>(outlined).unwrap().color,
+            app_outline.color
+        );
+        assert!(world.get::<FocusRing>(outlined).is_none());
+        assert!(world.get::<Outline>(plain).is_some());
+
+        focus_on(&mut world, outlined);
+        assert!(world.get::<Outline>(plain).is_none());
+    }
+}
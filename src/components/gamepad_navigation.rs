@@ -0,0 +1,219 @@
+//! Gamepad navigation between focusable entities.
+//!
+//! The D-pad and the left stick move [`UiFocus`] to the nearest [`Focusable`]
+//! entity in the pressed direction (see [`next_focus`]), and the South button activates the
+//! focused entity. Focus moved with the gamepad shows the focus ring, just like
+//! keyboard focus.
+//!
+//! Activating presses the entity's [`Interaction`] while the button is held
+//! and releases it to `Hovered`, the same transitions a pointer click causes,
+//! so checkboxes, radios, links and the other widgets reacting to
+//! `Interaction` behave as if clicked. Buttons additionally emit their
+//! [`ButtonClickEvent`].
+//!
+//! ```rust
+//! // Only listen to one gamepad, and activate with East instead of South
+//! commands.insert_resource(GamepadUiConfig {
+//!     gamepad: Some(gamepad_entity),
+//!     activate: GamepadButton::East,
+//!     ..default()
+//! });
+//! ```
+
+use bevy::prelude::*;
+
+use crate::{
+    components::{
+        button::{button_click_event, Button, ButtonClickEvent},
+        focus::{FocusChangeEvent, Focusable, UiFocus},
+    },
+    utilities::focus_nav::{next_focus, NavDirection},
+};
+
+/// Stick deflection that counts as a direction press
+pub const STICK_THRESHOLD: f32 = 0.5;
+
+/// Which gamepad drives the UI, and with which buttons.
+#[derive(Resource, Debug, Clone)]
+pub struct GamepadUiConfig {
+    /// Gamepad entity to listen to; `None` listens to all
+    pub gamepad: Option<Entity>,
+    pub up: GamepadButton,
+    pub down: GamepadButton,
+    pub left: GamepadButton,
+    pub right: GamepadButton,
+    pub activate: GamepadButton,
+    /// Whether the left stick moves the focus too
+    pub left_stick: bool,
+    /// Stick deflection that counts as a direction press
+    pub stick_threshold: f32,
+}
+
+impl Default for GamepadUiConfig {
+    fn default() -> Self {
+        Self {
+            gamepad: None,
+            up: GamepadButton::DPadUp,
+            down: GamepadButton::DPadDown,
+            left: GamepadButton::DPadLeft,
+            right: GamepadButton::DPadRight,
+            activate: GamepadButton::South,
+            left_stick: true,
+            stick_threshold: STICK_THRESHOLD,
+        }
+    }
+}
+
+/// Direction of a stick deflection beyond `threshold`, along its dominant
+/// axis.
+pub fn stick_direction(stick: Vec2, threshold: f32) -> Option<NavDirection> {
+    if stick.length() < threshold {
+        return None;
+    }
+    Some(if stick.x.abs() > stick.y.abs() {
+        if stick.x > 0.0 {
+            NavDirection::Right
+        } else {
+            NavDirection::Left
+        }
+    } else if stick.y > 0.0 {
+        // Stick y points up, UI y points down
+        NavDirection::Up
+    } else {
+        NavDirection::Down
+    })
+}
+
+/// Moves the focus with the D-pad and left stick and activates the focused
+/// entity with the activate button.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_gamepad_navigation(
+    config: Res<GamepadUiConfig>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    focusables: Query<(
+        Entity,
+        &Focusable,
+        &InheritedVisibility,
+        &ComputedNode,
+        &GlobalTransform,
+    )>,
+    mut interactions: Query<&mut Interaction>,
+    buttons: Query<&Button>,
+    mut focus: ResMut<UiFocus>,
+    mut change_events: EventWriter<FocusChangeEvent>,
+    mut click_events: EventWriter<ButtonClickEvent>,
+    mut last_stick: Local<Option<NavDirection>>,
+) {
+    let gamepads: Vec<&Gamepad> = gamepads
+        .iter()
+        .filter(|(entity, _)| config.gamepad.is_none_or(|gamepad| gamepad == *entity))
+        .map(|(_, gamepad)| gamepad)
+        .collect();
+
+    // The stick moves the focus once per deflection, not every frame
+    let threshold = config.stick_threshold;
+    let stick = gamepads
+        .iter()
+        .filter(|_| config.left_stick)
+        .find_map(|gamepad| stick_direction(gamepad.left_stick(), threshold));
+    let stick_press = stick.filter(|direction| *last_stick != Some(*direction));
+    *last_stick = stick;
+
+    let pressed =
+        |button: GamepadButton| gamepads.iter().any(|gamepad| gamepad.just_pressed(button));
+    let direction = [
+        (config.up, NavDirection::Up),
+        (config.down, NavDirection::Down),
+        (config.left, NavDirection::Left),
+        (config.right, NavDirection::Right),
+    ]
+    .into_iter()
+    .find(|(button, _)| pressed(*button))
+    .map(|(_, direction)| direction)
+    .or(stick_press);
+
+    if let Some(direction) = direction {
+        let mut candidates: Vec<_> = focusables
+            .iter()
+            .filter(|(_, focusable, visibility, ..)| !focusable.disabled && visibility.get())
+            .map(|(entity, focusable, _, computed, transform)| {
+                let rect =
+                    Rect::from_center_size(transform.translation().truncate(), computed.size());
+                (focusable.sort_key(), entity, rect)
+            })
+            .collect();
+        candidates.sort_unstable_by_key(|(key, entity, _)| (*key, *entity));
+
+        let previous = focus.focused;
+        let from = previous.and_then(|focused| {
+            candidates
+                .iter()
+                .find(|(_, entity, _)| *entity == focused)
+                .map(|(_, _, rect)| *rect)
+        });
+        let focused = match from {
+            Some(from) => {
+                let others: Vec<(Entity, Rect)> = candidates
+                    .iter()
+                    .filter(|(_, entity, _)| Some(*entity) != previous)
+                    .map(|(_, entity, rect)| (*entity, *rect))
+                    .collect();
+                next_focus(from, &others, direction).or(previous)
+            }
+            // Without focus, start at the first entity in tab order
+            None => candidates.first().map(|(_, entity, _)| *entity),
+        };
+
+        focus.focused = focused;
+        focus.focus_visible = focused.is_some();
+        if focused != previous {
+            change_events.write(FocusChangeEvent { previous, focused });
+        }
+    }
+
+    let Some(focused) = focus.focused else {
+        return;
+    };
+    if pressed(config.activate) {
+        if let Ok(mut interaction) = interactions.get_mut(focused) {
+            *interaction = Interaction::Pressed;
+        }
+    }
+    if gamepads
+        .iter()
+        .any(|gamepad| gamepad.just_released(config.activate))
+    {
+        if let Ok(mut interaction) = interactions.get_mut(focused) {
+            *interaction = Interaction::Hovered;
+        }
+        if let Some(event) = buttons
+            .get(focused)
+            .ok()
+            .and_then(|button| button_click_event(focused, button))
+        {
+            click_events.write(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stick_direction_needs_threshold() {
+        assert_eq!(stick_direction(Vec2::new(0.2, 0.1), 0.5), None);
+        assert_eq!(
+            stick_direction(Vec2::new(0.9, 0.3), 0.5),
+            Some(NavDirection::Right)
+        );
+        assert_eq!(
+            stick_direction(Vec2::new(-0.1, 0.8), 0.5),
+            Some(NavDirection::Up)
+        );
+        assert_eq!(
+            stick_direction(Vec2::new(0.2, -0.7), 0.5),
+            Some(NavDirection::Down)
+        );
+    }
+}
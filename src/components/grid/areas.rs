@@ -0,0 +1,271 @@
+//! Named grid areas, like CSS `grid-template-areas`.
+//!
+//! `GridBuilder::template_areas` takes one string per row, naming the area of
+//! each cell (`.` leaves a cell empty). Every name must cover a rectangle:
+//!
+//! ```text
+//! "header header"
+//! "sidebar main"
+//! ```
+//!
+//! Children added with `GridBuilder::with_child_area` are placed into their
+//! area by [`place_grid_area_children`] once the grid is spawned.
+
+use std::{collections::HashMap, fmt};
+
+use bevy::prelude::*;
+use bevy::ui::GridPlacement;
+
+/// Cell name that leaves a cell outside of every area.
+pub const EMPTY_GRID_CELL: &str = ".";
+
+/// Position of a named area, as 1-based start lines and spans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridAreaSpan {
+    pub row_start: u16,
+    pub row_span: u16,
+    pub column_start: u16,
+    pub column_span: u16,
+}
+
+impl GridAreaSpan {
+    /// `grid_row` placement covering the area
+    pub fn row_placement(&self) -> GridPlacement {
+        GridPlacement::start_span(self.row_start as i16, self.row_span)
+    }
+
+    /// `grid_column` placement covering the area
+    pub fn column_placement(&self) -> GridPlacement {
+        GridPlacement::start_span(self.column_start as i16, self.column_span)
+    }
+}
+
+/// Why a `template_areas` definition was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridAreasError {
+    /// No rows, or a row without cells
+    Empty,
+    /// A row has a different number of cells than the first one
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The cells of an area don't form a single rectangle
+    NotRectangular(String),
+}
+
+impl fmt::Display for GridAreasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "grid areas need at least one non-empty row"),
+            Self::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "grid area row {} has {found} cells, expected {expected}",
+                row + 1
+            ),
+            Self::NotRectangular(name) => {
+                write!(f, "grid area \"{name}\" is not a single rectangle")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GridAreasError {}
+
+/// Parsed `template_areas`: the grid size and the span of every area.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GridAreas {
+    pub rows: u16,
+    pub columns: u16,
+    pub areas: HashMap<String, GridAreaSpan>,
+}
+
+impl GridAreas {
+    /// Parses one string per row, cells separated by whitespace.
+    pub fn parse(rows: &[&str]) -> Result<Self, GridAreasError> {
+        let cells: Vec<Vec<&str>> = rows
+            .iter()
+            .map(|row| row.split_whitespace().collect())
+            .collect();
+        let columns = cells.first().map_or(0, Vec::len);
+        if columns == 0 {
+            return Err(GridAreasError::Empty);
+        }
+        if let Some((row, found)) = cells
+            .iter()
+            .map(Vec::len)
+            .enumerate()
+            .find(|(_, found)| *found != columns)
+        {
+            return Err(if found == 0 {
+                GridAreasError::Empty
+            } else {
+                GridAreasError::RaggedRow {
+                    row,
+                    expected: columns,
+                    found,
+                }
+            });
+        }
+
+        // Bounding box and cell count per area
+        let mut bounds: HashMap<&str, (usize, usize, usize, usize, usize)> = HashMap::new();
+        for (row, names) in cells.iter().enumerate() {
+            for (column, name) in names.iter().enumerate() {
+                if *name == EMPTY_GRID_CELL {
+                    continue;
+                }
+                let entry = bounds.entry(*name).or_insert((row, row, column, column, 0));
+                entry.0 = entry.0.min(row);
+                entry.1 = entry.1.max(row);
+                entry.2 = entry.2.min(column);
+                entry.3 = entry.3.max(column);
+                entry.4 += 1;
+            }
+        }
+
+        let mut areas = HashMap::new();
+        for (name, (first_row, last_row, first_column, last_column, count)) in bounds {
+            let row_span = last_row - first_row + 1;
+            let column_span = last_column - first_column + 1;
+            // Cells are distinct, so filling the bounding box means being it
+            if count != row_span * column_span {
+                return Err(GridAreasError::NotRectangular(name.to_string()));
+            }
+            areas.insert(
+                name.to_string(),
+                GridAreaSpan {
+                    row_start: first_row as u16 + 1,
+                    row_span: row_span as u16,
+                    column_start: first_column as u16 + 1,
+                    column_span: column_span as u16,
+                },
+            );
+        }
+
+        Ok(Self {
+            rows: cells.len() as u16,
+            columns: columns as u16,
+            areas,
+        })
+    }
+
+    pub fn span(&self, name: &str) -> Option<GridAreaSpan> {
+        self.areas.get(name).copied()
+    }
+}
+
+/// Named areas of a grid and the children to place into them.
+///
+/// Empty for grids without `template_areas`.
+#[derive(Component, Debug, Clone, Default)]
+pub struct GridTemplateAreas {
+    pub areas: GridAreas,
+    pub children: Vec<(Entity, String)>,
+}
+
+/// System that adds the area children of new grids and places them into
+/// their areas.
+pub fn place_grid_area_children(
+    mut commands: Commands,
+    grids: Query<(Entity, &GridTemplateAreas), Changed<GridTemplateAreas>>,
+    mut nodes: Query<&mut Node>,
+) {
+    for (grid, template) in &grids {
+        for (child, area) in &template.children {
+            let Some(span) = template.areas.span(area) else {
+                warn!("Grid has no area named \"{area}\"; leaving {child} unplaced");
+                continue;
+            };
+            if let Ok(mut node) = nodes.get_mut(*child) {
+                node.grid_row = span.row_placement();
+                node.grid_column = span.column_placement();
+            }
+            commands.entity(grid).add_child(*child);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_from_areas() {
+        let areas = GridAreas::parse(&[
+            "header header header",
+            "sidebar main main",
+            "sidebar main main",
+            ". footer footer",
+        ])
+        .unwrap();
+
+        assert_eq!((areas.rows, areas.columns), (4, 3));
+        assert_eq!(areas.areas.len(), 4);
+        assert_eq!(
+            areas.span("header"),
+            Some(GridAreaSpan {
+                row_start: 1,
+                row_span: 1,
+                column_start: 1,
+                column_span: 3,
+            })
+        );
+        assert_eq!(
+            areas.span("sidebar"),
+            Some(GridAreaSpan {
+                row_start: 2,
+                row_span: 2,
+                column_start: 1,
+                column_span: 1,
+            })
+        );
+        assert_eq!(
+            areas.span("main"),
+            Some(GridAreaSpan {
+                row_start: 2,
+                row_span: 2,
+                column_start: 2,
+                column_span: 2,
+            })
+        );
+        assert_eq!(
+            areas.span("footer"),
+            Some(GridAreaSpan {
+                row_start: 4,
+                row_span: 1,
+                column_start: 2,
+                column_span: 2,
+            })
+        );
+        assert_eq!(areas.span(EMPTY_GRID_CELL), None);
+    }
+
+    #[test]
+    fn test_invalid_areas_are_rejected() {
+        assert_eq!(GridAreas::parse(&[]), Err(GridAreasError::Empty));
+        assert_eq!(
+            GridAreas::parse(&["a b", "a"]),
+            Err(GridAreasError::RaggedRow {
+                row: 1,
+                expected: 2,
+                found: 1,
+            })
+        );
+        // L-shape
+        assert_eq!(
+            GridAreas::parse(&["a a", "a b"]),
+            Err(GridAreasError::NotRectangular("a".to_string()))
+        );
+        // Two separate pieces
+        assert_eq!(
+            GridAreas::parse(&["a b a"]),
+            Err(GridAreasError::NotRectangular("a".to_string()))
+        );
+    }
+}
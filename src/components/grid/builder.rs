@@ -6,12 +6,22 @@
 
 use crate::{
     components::box_component::{RadiusLevel, SpacingLevel},
-    theme::color::UiColorPalette,
+    theme::{
+        breakpoints::{Breakpoint, Responsive},
+        color::UiColorPalette,
+        deferred_style::DeferredThemeStyle,
+    },
+    utilities::{
+        naming::Namespace,
+        node_edit::{NodeEdits, WithNode},
+        sizing::NodeSizing,
+    },
 };
 use bevy::prelude::*;
 use bevy::ui::GridPlacement;
 
 use super::{
+    areas::{GridAreas, GridTemplateAreas},
     core::GridComponent,
     styling::ExplicitColors,
     tracks::{GridGap, GridTrack, GridTrackSize},
@@ -51,6 +61,16 @@ pub struct GridBuilder {
     pub(super) explicit_colors: ExplicitColors,
     /// Child entities to include in the grid
     pub(super) children: Vec<Entity>,
+    /// Equal-width column counts per breakpoint
+    pub(super) responsive_columns: Responsive<u16>,
+    /// Named areas and the children placed into them
+    pub(super) template_areas: GridTemplateAreas,
+    /// Theme levels resolved after spawn
+    pub(super) deferred: DeferredThemeStyle,
+    /// Initial visibility of the grid
+    pub(super) visibility: Visibility,
+    /// Raw node edits applied last
+    pub(super) node_edits: NodeEdits,
 }
 
 impl GridComponent {
@@ -71,6 +91,11 @@ impl GridComponent {
             node: Node::default(),
             explicit_colors: ExplicitColors::default(),
             children: Vec::new(),
+            responsive_columns: Responsive::default(),
+            template_areas: GridTemplateAreas::default(),
+            deferred: DeferredThemeStyle::default(),
+            visibility: Visibility::Inherited,
+            node_edits: NodeEdits::default(),
         }
     }
 
@@ -138,6 +163,18 @@ impl GridComponent {
 }
 
 impl GridBuilder {
+    /// Builds the node visible or hidden; hidden nodes keep their space in
+    /// the layout. See [`ShowWhen`](crate::utilities::ShowWhen) to follow an
+    /// app state instead.
+    pub fn visible_if(mut self, visible: bool) -> Self {
+        self.visibility = if visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        self
+    }
+
     // === Grid Template Columns Configuration ===
 
     /// Sets grid template columns to repeat fractional units.
@@ -193,6 +230,51 @@ impl GridBuilder {
         self
     }
 
+    /// Sets grid template columns to as many columns as fit the grid.
+    /// 
+    /// The column count follows the width of the grid: each column is at
+    /// least `min_px` wide and at most `max`. Columns left without items
+    /// collapse, so the items stretch across the row.
+    /// 
+    /// # Arguments
+    /// * `min_px` - Minimum width of each column in pixels
+    /// * `max` - Maximum width of each column
+    /// 
+    /// # Example
+    /// ```rust
+    /// // CSS: repeat(auto-fit, minmax(200px, 1fr))
+    /// let gallery = Grid::new("gallery")
+    ///     .columns_auto_fit(200.0, GridTrackSize::Fr(1.0))
+    ///     .build();
+    /// ```
+    pub fn columns_auto_fit(mut self, min_px: f32, max: GridTrackSize) -> Self {
+        self.grid_config.template_columns = GridTrack::auto_fit(min_px, max);
+        self
+    }
+
+    /// Sets grid template columns to as many columns as fit the grid,
+    /// keeping empty ones.
+    /// 
+    /// Like [`columns_auto_fit`](Self::columns_auto_fit), but columns left
+    /// without items keep their space, so items on a short row keep their
+    /// width.
+    /// 
+    /// # Arguments
+    /// * `min_px` - Minimum width of each column in pixels
+    /// * `max` - Maximum width of each column
+    /// 
+    /// # Example
+    /// ```rust
+    /// // CSS: repeat(auto-fill, minmax(120px, 1fr))
+    /// let swatches = Grid::new("swatches")
+    ///     .columns_auto_fill(120.0, GridTrackSize::Fr(1.0))
+    ///     .build();
+    /// ```
+    pub fn columns_auto_fill(mut self, min_px: f32, max: GridTrackSize) -> Self {
+        self.grid_config.template_columns = GridTrack::auto_fill(min_px, max);
+        self
+    }
+
     /// Sets grid template columns with explicit individual track sizes.
     /// 
     /// Provides full control over each column's sizing, allowing mixed
@@ -249,6 +331,29 @@ impl GridBuilder {
         self
     }
 
+    /// Sets the number of equal-width columns per breakpoint.
+    /// 
+    /// The column count follows the `ActiveBreakpoint` resource; until it is
+    /// known, the narrowest count is used.
+    /// 
+    /// # Arguments
+    /// * `counts` - `(Breakpoint, count)` pairs, each applying from its breakpoint upwards
+    /// 
+    /// # Example
+    /// ```rust
+    /// // 1 column on phones, 2 on tablets, 4 on desktops
+    /// let cards = Grid::new("cards")
+    ///     .columns_responsive([(Breakpoint::Base, 1), (Breakpoint::Sm, 2), (Breakpoint::Lg, 4)])
+    ///     .build();
+    /// ```
+    pub fn columns_responsive(mut self, counts: impl Into<Responsive<u16>>) -> Self {
+        self.responsive_columns = counts.into();
+        if let Some(count) = self.responsive_columns.resolve(Breakpoint::Base) {
+            self.grid_config.template_columns = GridTrack::fr(count);
+        }
+        self
+    }
+
     // === Grid Template Rows Configuration ===
 
     /// Sets grid template rows to repeat fractional units.
@@ -402,6 +507,27 @@ impl GridBuilder {
         self
     }
 
+    /// Sets how implicitly placed children fill the grid.
+    /// 
+    /// `Row` (the default) fills row by row and `Column` column by column.
+    /// The dense variants backfill earlier holes left by larger items, which
+    /// allows masonry-like packing at the cost of source order.
+    /// 
+    /// # Arguments
+    /// * `flow` - Auto-placement direction and packing
+    /// 
+    /// # Example
+    /// ```rust
+    /// let grid = Grid::new("gallery")
+    ///     .columns_fr(4)
+    ///     .auto_flow(GridAutoFlow::RowDense)  // Fill gaps left by wide tiles
+    ///     .build();
+    /// ```
+    pub fn auto_flow(mut self, flow: GridAutoFlow) -> Self {
+        self.grid_config.auto_flow = flow;
+        self
+    }
+
     // === Grid Gap Configuration ===
 
     /// Sets uniform gap between all grid items.
@@ -435,12 +561,12 @@ impl GridBuilder {
     /// ```rust
     /// let grid = Grid::new("themed-grid")
     ///     .columns_fr(2)
-    ///     .gap_level(SpacingLevel::Medium)
+    ///     .gap_level(SpacingLevel::Base)
     ///     .build();
     /// ```
-    pub fn gap_level(mut self, _level: SpacingLevel) -> Self {
-        // We'll resolve this during build when we have access to the theme
-        self.grid_config.gap = GridGap::Uniform(0.0); // Placeholder
+    pub fn gap_level(mut self, level: SpacingLevel) -> Self {
+        // Resolved after spawn, see DeferredThemeStyle
+        self.deferred = self.deferred.gap(level);
         self
     }
 
@@ -1121,6 +1247,91 @@ impl GridBuilder {
         self
     }
 
+    // === Item Alignment ===
+
+    /// Sets how the grid aligns its children along the row axis within
+    /// their cells.
+    /// 
+    /// # Example
+    /// ```rust
+    /// let grid = Grid::new("centered-cells")
+    ///     .justify_items(JustifyItems::Center)
+    ///     .build();
+    /// ```
+    pub fn justify_items(mut self, justify: JustifyItems) -> Self {
+        self.node.justify_items = justify;
+        self
+    }
+
+    /// Sets how the grid aligns its children along the column axis within
+    /// their cells.
+    /// 
+    /// # Example
+    /// ```rust
+    /// let grid = Grid::new("top-aligned")
+    ///     .align_items(AlignItems::Start)
+    ///     .build();
+    /// ```
+    pub fn align_items(mut self, align: AlignItems) -> Self {
+        self.node.align_items = align;
+        self
+    }
+
+    /// Overrides the parent's `justify_items` for this grid.
+    pub fn justify_self(mut self, justify: JustifySelf) -> Self {
+        self.node.justify_self = justify;
+        self
+    }
+
+    /// Overrides the parent's `align_items` for this grid.
+    pub fn align_self(mut self, align: AlignSelf) -> Self {
+        self.node.align_self = align;
+        self
+    }
+
+    /// Centers this grid in its parent's cell on both axes.
+    pub fn center_self(self) -> Self {
+        self.justify_self(JustifySelf::Center)
+            .align_self(AlignSelf::Center)
+    }
+
+    // === Named Areas ===
+
+    /// Names the areas of the grid, one string per row.
+    ///
+    /// Each row lists a name per cell, `.` for cells outside of any area.
+    /// Every area must be a single rectangle; otherwise the definition is
+    /// rejected with a warning. Track sizes still come from the `columns_*`
+    /// and `rows_*` methods.
+    ///
+    /// # Example
+    /// ```rust
+    /// let dashboard = Grid::new("dashboard")
+    ///     .columns_sizes(vec![GridTrackSize::Px(200.0), GridTrackSize::Fr(1.0)])
+    ///     .rows_sizes(vec![GridTrackSize::Px(60.0), GridTrackSize::Fr(1.0)])
+    ///     .template_areas(&["header header", "sidebar main"])
+    ///     .with_child_area(header, "header")
+    ///     .with_child_area(sidebar, "sidebar")
+    ///     .with_child_area(content, "main")
+    ///     .build();
+    /// ```
+    pub fn template_areas(mut self, rows: &[&str]) -> Self {
+        match GridAreas::parse(rows) {
+            Ok(areas) => self.template_areas.areas = areas,
+            Err(error) => warn!("Grid \"{}\": ignoring template areas: {error}", self.name),
+        }
+        self
+    }
+
+    /// Adds a child entity placed into the named area.
+    ///
+    /// The child is added to the grid and positioned once the grid is spawned.
+    pub fn with_child_area(mut self, child: Entity, area: impl Into<String>) -> Self {
+        self.children.push(child);
+        self.template_areas.children.push((child, area.into()));
+        self
+    }
+
     // === Color Configuration ===
 
     /// Sets the color theme for the grid.
@@ -1194,6 +1405,46 @@ impl GridBuilder {
         self
     }
 
+    /// Sets the border width of each side.
+    ///
+    /// # Example
+    /// ```rust
+    /// let grid = Grid::new("table")
+    ///     .border_sides(1.0, 0.0, 1.0, 0.0)
+    ///     .build();
+    /// ```
+    pub fn border_sides(mut self, top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        self.node.border =
+            UiRect::new(Val::Px(left), Val::Px(right), Val::Px(top), Val::Px(bottom));
+        self
+    }
+
+    /// Sets the top border width, keeping the other sides.
+    pub fn border_top(mut self, width: f32) -> Self {
+        self.node.border.top = Val::Px(width);
+        self
+    }
+
+    /// Sets the bottom border width, keeping the other sides.
+    pub fn border_bottom(mut self, width: f32) -> Self {
+        self.node.border.bottom = Val::Px(width);
+        self
+    }
+
+    /// Sets the left and right border widths, keeping top and bottom.
+    pub fn border_x(mut self, width: f32) -> Self {
+        self.node.border.left = Val::Px(width);
+        self.node.border.right = Val::Px(width);
+        self
+    }
+
+    /// Sets the top and bottom border widths, keeping left and right.
+    pub fn border_y(mut self, width: f32) -> Self {
+        self.node.border.top = Val::Px(width);
+        self.node.border.bottom = Val::Px(width);
+        self
+    }
+
     /// Sets border radius on all corners.
     /// 
     /// # Arguments
@@ -1218,11 +1469,12 @@ impl GridBuilder {
     /// # Example
     /// ```rust
     /// let grid = Grid::new("themed-rounded")
-    ///     .rounded_level(RadiusLevel::Medium)
+    ///     .rounded_level(RadiusLevel::Base)
     ///     .build();
     /// ```
-    pub fn rounded_level(mut self, _level: RadiusLevel) -> Self {
-        // Border radius will be applied during build
+    pub fn rounded_level(mut self, level: RadiusLevel) -> Self {
+        // Resolved after spawn, see DeferredThemeStyle
+        self.deferred = self.deferred.radius(level);
         self
     }
 
@@ -1286,4 +1538,22 @@ impl GridBuilder {
         self.children.extend(children);
         self
     }
-}
\ No newline at end of file
+}
+
+impl NodeSizing for GridBuilder {
+    fn node_mut(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl Namespace for GridBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+impl WithNode for GridBuilder {
+    fn node_edits_mut(&mut self) -> &mut NodeEdits {
+        &mut self.node_edits
+    }
+}
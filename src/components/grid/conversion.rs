@@ -7,12 +7,14 @@
 use crate::theme::layout::UiLayout;
 use bevy::prelude::*;
 use bevy::ui::{
-    GridPlacement, MaxTrackSizingFunction, MinTrackSizingFunction, RepeatedGridTrack,
+    GridPlacement, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
+    RepeatedGridTrack,
 };
 use bevy_picking::prelude::Pickable;
 
 use super::{
     builder::GridBuilder,
+    responsive::ResponsiveColumns,
     tracks::{GridGap, GridTrack, GridTrackSize},
 };
 
@@ -53,6 +55,19 @@ impl GridBuilder {
                 // Fallback to single flexible track
                 self.node.grid_template_columns = vec![RepeatedGridTrack::fr(1, 1.0)];
             }
+            GridTrack::AutoFit(min, max) => {
+                // As many tracks as fit, empty ones collapse
+                self.node.grid_template_columns =
+                    vec![auto_repeat_to_bevy(GridTrackRepetition::AutoFit, *min, max)];
+            }
+            GridTrack::AutoFill(min, max) => {
+                // As many tracks as fit, empty ones keep their space
+                self.node.grid_template_columns = vec![auto_repeat_to_bevy(
+                    GridTrackRepetition::AutoFill,
+                    *min,
+                    max,
+                )];
+            }
         }
 
         // Convert grid template rows to Bevy's format
@@ -76,8 +91,24 @@ impl GridBuilder {
                 // Fallback to single flexible track
                 self.node.grid_template_rows = vec![RepeatedGridTrack::fr(1, 1.0)];
             }
+            GridTrack::AutoFit(min, max) => {
+                // As many tracks as fit, empty ones collapse
+                self.node.grid_template_rows =
+                    vec![auto_repeat_to_bevy(GridTrackRepetition::AutoFit, *min, max)];
+            }
+            GridTrack::AutoFill(min, max) => {
+                // As many tracks as fit, empty ones keep their space
+                self.node.grid_template_rows = vec![auto_repeat_to_bevy(
+                    GridTrackRepetition::AutoFill,
+                    *min,
+                    max,
+                )];
+            }
         }
 
+        // Apply auto-placement direction
+        self.node.grid_auto_flow = self.grid_config.auto_flow;
+
         // Apply gap configuration
         match self.grid_config.gap {
             GridGap::None => {
@@ -106,6 +137,9 @@ impl GridBuilder {
             .border
             .unwrap_or(self.grid_config.color_palette.border);
 
+        // Raw node edits override everything above
+        self.node_edits.apply(&mut self.node);
+
         (
             Name::new(self.name),
             self.grid_config,
@@ -114,6 +148,10 @@ impl GridBuilder {
             BorderColor(border_color),
             BorderRadius::all(Val::Px(UiLayout::default().radius.base)),
             Pickable::IGNORE,
+            ResponsiveColumns(self.responsive_columns),
+            self.template_areas,
+            self.deferred,
+            self.visibility,
         )
     }
 }
@@ -130,7 +168,7 @@ impl GridBuilder {
 /// 
 /// # Returns
 /// A RepeatedGridTrack compatible with Bevy's grid system
-fn track_size_to_bevy(repetition: u16, size: &GridTrackSize) -> RepeatedGridTrack {
+pub(super) fn track_size_to_bevy(repetition: u16, size: &GridTrackSize) -> RepeatedGridTrack {
     match size {
         GridTrackSize::Auto => RepeatedGridTrack::auto(repetition),
         GridTrackSize::Px(px) => RepeatedGridTrack::px(repetition, *px),
@@ -148,17 +186,181 @@ fn track_size_to_bevy(repetition: u16, size: &GridTrackSize) -> RepeatedGridTrac
                 GridTrackSize::MaxContent => MinTrackSizingFunction::MaxContent,
                 _ => MinTrackSizingFunction::Auto, // Fallback for complex cases
             };
-            let max_fn = match **max {
-                GridTrackSize::Auto => MaxTrackSizingFunction::Auto,
-                GridTrackSize::Px(px) => MaxTrackSizingFunction::Px(px),
-                GridTrackSize::Fr(fr) => MaxTrackSizingFunction::Fraction(fr),
-                GridTrackSize::Percent(percent) => MaxTrackSizingFunction::Percent(percent),
-                GridTrackSize::MinContent => MaxTrackSizingFunction::MinContent,
-                GridTrackSize::MaxContent => MaxTrackSizingFunction::MaxContent,
-                GridTrackSize::FitContent(size) => MaxTrackSizingFunction::FitContentPx(size),
-                _ => MaxTrackSizingFunction::Auto, // Fallback for complex cases
-            };
+            let max_fn = max_track_sizing(max);
             RepeatedGridTrack::minmax(repetition, min_fn, max_fn)
         }
     }
-}
\ No newline at end of file
+}
+
+/// Converts the maximum of a `minmax` track to Bevy's sizing function.
+fn max_track_sizing(size: &GridTrackSize) -> MaxTrackSizingFunction {
+    match *size {
+        GridTrackSize::Auto => MaxTrackSizingFunction::Auto,
+        GridTrackSize::Px(px) => MaxTrackSizingFunction::Px(px),
+        GridTrackSize::Fr(fr) => MaxTrackSizingFunction::Fraction(fr),
+        GridTrackSize::Percent(percent) => MaxTrackSizingFunction::Percent(percent),
+        GridTrackSize::MinContent => MaxTrackSizingFunction::MinContent,
+        GridTrackSize::MaxContent => MaxTrackSizingFunction::MaxContent,
+        GridTrackSize::FitContent(size) => MaxTrackSizingFunction::FitContentPx(size),
+        _ => MaxTrackSizingFunction::Auto, // Fallback for complex cases
+    }
+}
+
+/// Converts an auto-repeated track, `repeat(auto-fit | auto-fill,
+/// minmax({min}px, {max}))`, to Bevy's RepeatedGridTrack format.
+///
+/// Auto repetition needs a definite minimum, so the minimum is in pixels.
+pub(super) fn auto_repeat_to_bevy(
+    repetition: GridTrackRepetition,
+    min: f32,
+    max: &GridTrackSize,
+) -> RepeatedGridTrack {
+    RepeatedGridTrack::minmax(
+        repetition,
+        MinTrackSizingFunction::Px(min),
+        max_track_sizing(max),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{box_component::BoxComponent, grid::core::GridComponent};
+
+    #[test]
+    fn test_auto_fit_and_fill_columns_repeat_automatically() {
+        let mut world = World::new();
+        let fit = world
+            .spawn(
+                GridComponent::new("gallery")
+                    .columns_auto_fit(200.0, GridTrackSize::Fr(1.0))
+                    .build(),
+            )
+            .id();
+        let fill = world
+            .spawn(
+                GridComponent::new("swatches")
+                    .columns_auto_fill(120.0, GridTrackSize::Px(160.0))
+                    .build(),
+            )
+            .id();
+
+        assert_eq!(
+            world.get::<Node>(fit).unwrap().grid_template_columns,
+            vec![RepeatedGridTrack::minmax(
+                GridTrackRepetition::AutoFit,
+                MinTrackSizingFunction::Px(200.0),
+                MaxTrackSizingFunction::Fraction(1.0),
+            )]
+        );
+        assert_eq!(
+            world.get::<Node>(fill).unwrap().grid_template_columns,
+            vec![RepeatedGridTrack::minmax(
+                GridTrackRepetition::AutoFill,
+                MinTrackSizingFunction::Px(120.0),
+                MaxTrackSizingFunction::Px(160.0),
+            )]
+        );
+        assert_eq!(
+            world.get::<GridComponent>(fit).unwrap().template_columns,
+            GridTrack::AutoFit(200.0, GridTrackSize::Fr(1.0))
+        );
+    }
+
+    #[test]
+    fn test_auto_flow_is_applied_to_node() {
+        let mut world = World::new();
+        let default = world.spawn(GridComponent::new("default").build()).id();
+        assert_eq!(
+            world.get::<Node>(default).unwrap().grid_auto_flow,
+            GridAutoFlow::Row
+        );
+
+        for flow in [
+            GridAutoFlow::Row,
+            GridAutoFlow::Column,
+            GridAutoFlow::RowDense,
+            GridAutoFlow::ColumnDense,
+        ] {
+            let grid = world
+                .spawn(GridComponent::new("flow").auto_flow(flow).build())
+                .id();
+            assert_eq!(world.get::<Node>(grid).unwrap().grid_auto_flow, flow);
+            assert_eq!(world.get::<GridComponent>(grid).unwrap().auto_flow, flow);
+        }
+    }
+
+    #[test]
+    fn test_border_sides_are_applied_to_node() {
+        let mut world = World::new();
+        let grid = world
+            .spawn(
+                GridComponent::new("rows")
+                    .border_sides(1.0, 2.0, 3.0, 4.0)
+                    .border_x(5.0)
+                    .build(),
+            )
+            .id();
+        assert_eq!(
+            world.get::<Node>(grid).unwrap().border,
+            UiRect::new(Val::Px(5.0), Val::Px(5.0), Val::Px(1.0), Val::Px(3.0))
+        );
+    }
+
+    #[test]
+    fn test_item_alignment_is_applied_to_node() {
+        let mut world = World::new();
+        for align in [
+            AlignItems::Start,
+            AlignItems::Center,
+            AlignItems::End,
+            AlignItems::Stretch,
+        ] {
+            let grid = world
+                .spawn(GridComponent::new("grid").align_items(align).build())
+                .id();
+            assert_eq!(world.get::<Node>(grid).unwrap().align_items, align);
+        }
+        for justify in [
+            JustifyItems::Start,
+            JustifyItems::Center,
+            JustifyItems::End,
+            JustifyItems::Stretch,
+        ] {
+            let grid = world
+                .spawn(GridComponent::new("grid").justify_items(justify).build())
+                .id();
+            assert_eq!(world.get::<Node>(grid).unwrap().justify_items, justify);
+        }
+    }
+
+    #[test]
+    fn test_self_alignment_is_applied_to_node() {
+        let mut world = World::new();
+        for (align, justify) in [
+            (AlignSelf::Start, JustifySelf::Start),
+            (AlignSelf::Center, JustifySelf::Center),
+            (AlignSelf::End, JustifySelf::End),
+            (AlignSelf::Stretch, JustifySelf::Stretch),
+        ] {
+            let grid = world
+                .spawn(
+                    GridComponent::new("cell")
+                        .align_self(align)
+                        .justify_self(justify)
+                        .build(),
+                )
+                .id();
+            let node = world.get::<Node>(grid).unwrap();
+            assert_eq!(node.align_self, align);
+            assert_eq!(node.justify_self, justify);
+        }
+
+        let centered = world
+            .spawn(BoxComponent::new("centered").center_self().build())
+            .id();
+        let node = world.get::<Node>(centered).unwrap();
+        assert_eq!(node.align_self, AlignSelf::Center);
+        assert_eq!(node.justify_self, JustifySelf::Center);
+    }
+}
@@ -37,7 +37,8 @@ use super::{styling::GridStyling, tracks::{GridTrack, GridGap}};
 /// - Auto tracks: Implicitly created tracks for overflow content
 /// - Repeat patterns: Repeated track definitions
 /// - Mixed sizing: Combination of different track types
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct GridComponent {
     /// Grid template columns definition (explicit column tracks)
     pub template_columns: GridTrack,
@@ -47,6 +48,8 @@ pub struct GridComponent {
     pub auto_columns: Option<GridTrack>,
     /// Auto rows configuration for implicit tracks
     pub auto_rows: Option<GridTrack>,
+    /// How implicitly placed children fill the grid (CSS `grid-auto-flow`)
+    pub auto_flow: GridAutoFlow,
     /// Gap configuration between grid items
     pub gap: GridGap,
     /// Color palette for theme-based styling
@@ -58,6 +61,7 @@ pub struct GridComponent {
 impl Default for GridComponent {
     /// Creates a default Grid component with sensible defaults:
     /// - No explicit grid tracks (content will create implicit tracks)
+    /// - Row-major auto placement
     /// - No gaps between grid items
     /// - Accent color palette for theming
     /// - Default styling configuration
@@ -67,6 +71,7 @@ impl Default for GridComponent {
             template_rows: GridTrack::none(),
             auto_columns: None,
             auto_rows: None,
+            auto_flow: GridAutoFlow::Row,
             gap: GridGap::None,
             color_palette: accent_palette(),
             styling_config: GridStyling::default(),
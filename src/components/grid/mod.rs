@@ -9,7 +9,8 @@
 //!
 //! - **CSS Grid Model**: Complete implementation of CSS Grid layout capabilities
 //! - **Flexible Track Sizing**: Support for fr units, pixels, auto, percentages, and more
-//! - **Grid Areas**: Explicit grid positioning with start/end/span syntax
+//! - **Grid Areas**: Explicit grid positioning with start/end/span syntax, or named
+//!   areas like CSS `grid-template-areas`
 //! - **Gap Control**: Uniform and directional gap configuration
 //! - **Theme Integration**: Automatic styling with color palettes and spacing
 //! - **Builder Pattern**: Fluent API for intuitive grid construction
@@ -96,15 +97,21 @@
 //! ```
 
 // Module declarations
+pub mod areas;
 pub mod builder;
 pub mod conversion;
 pub mod core;
+pub mod responsive;
 pub mod styling;
 pub mod tracks;
 
 // Re-export all public types for backward compatibility
+pub use self::areas::{
+    place_grid_area_children, GridAreaSpan, GridAreas, GridAreasError, GridTemplateAreas,
+};
 pub use self::core::GridComponent;
 pub use self::builder::GridBuilder;
+pub use self::responsive::{apply_responsive_columns, ResponsiveColumns};
 pub use self::styling::GridStyling;
 pub use self::tracks::{GridGap, GridTrack, GridTrackSize};
 
@@ -0,0 +1,42 @@
+//! Breakpoint-dependent grid configuration.
+//!
+//! Grids built with `GridBuilder::columns_responsive` carry a
+//! [`ResponsiveColumns`] component; [`apply_responsive_columns`] rewrites their
+//! column tracks whenever the active breakpoint changes.
+
+use crate::theme::breakpoints::{ActiveBreakpoint, Responsive};
+use bevy::prelude::*;
+
+use super::{
+    conversion::track_size_to_bevy,
+    core::GridComponent,
+    tracks::{GridTrack, GridTrackSize},
+};
+
+/// Equal-width column counts per breakpoint.
+///
+/// Empty for grids with fixed columns.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ResponsiveColumns(pub Responsive<u16>);
+
+/// System that updates the column tracks of responsive grids for the active
+/// breakpoint.
+pub fn apply_responsive_columns(
+    active: Res<ActiveBreakpoint>,
+    mut grids: Query<(Ref<ResponsiveColumns>, &mut GridComponent, &mut Node)>,
+) {
+    for (responsive, mut grid, mut node) in grids.iter_mut() {
+        if !active.is_changed() && !responsive.is_changed() {
+            continue;
+        }
+        let Some(count) = responsive.0.resolve(active.0) else {
+            continue;
+        };
+
+        let columns = GridTrack::fr(count);
+        if grid.template_columns != columns {
+            grid.template_columns = columns;
+            node.grid_template_columns = vec![track_size_to_bevy(count, &GridTrackSize::Fr(1.0))];
+        }
+    }
+}
@@ -10,7 +10,7 @@ use bevy::prelude::*;
 /// 
 /// GridStyling contains visual styling options that can override
 /// theme-based defaults, providing fine control over grid appearance.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Reflect)]
 pub struct GridStyling {
     /// Alpha/opacity for background color (0.0 = transparent, 1.0 = opaque)
     pub background_alpha: f32,
@@ -11,7 +11,7 @@ use bevy::prelude::*;
 /// GridTrack represents different ways to define grid tracks (rows or columns),
 /// providing flexibility from simple repeated patterns to complex custom layouts.
 /// This maps closely to CSS Grid track definition syntax.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Reflect)]
 pub enum GridTrack {
     /// No explicit tracks - grid will create implicit tracks as needed.
     /// Use when you want the grid to automatically size based on content.
@@ -34,6 +34,20 @@ pub enum GridTrack {
     /// **Example**: "minmax(200px, 1fr) repeat(3, 100px) auto"
     /// **Usage**: Complex responsive patterns (currently limited support)
     Template(String),
+    
+    /// As many tracks as fit the container, each between `min` pixels and
+    /// the maximum size; tracks left without items collapse.
+    /// 
+    /// **Example**: `AutoFit(200.0, Fr(1.0))` creates "repeat(auto-fit, minmax(200px, 1fr))"
+    /// **Usage**: Responsive galleries whose items stretch to fill the row
+    AutoFit(f32, GridTrackSize),
+    
+    /// As many tracks as fit the container, each between `min` pixels and
+    /// the maximum size; tracks left without items keep their space.
+    /// 
+    /// **Example**: `AutoFill(200.0, Fr(1.0))` creates "repeat(auto-fill, minmax(200px, 1fr))"
+    /// **Usage**: Responsive layouts whose items keep their size on short rows
+    AutoFill(f32, GridTrackSize),
 }
 
 impl GridTrack {
@@ -68,6 +82,28 @@ impl GridTrack {
         Self::Template(template.into())
     }
 
+    /// Creates as many tracks as fit the container, collapsing empty ones.
+    /// 
+    /// Equivalent to CSS: `repeat(auto-fit, minmax({min}px, {max}))`
+    /// 
+    /// # Arguments
+    /// * `min` - Minimum size of each track in pixels
+    /// * `max` - Maximum size of each track
+    pub fn auto_fit(min: f32, max: GridTrackSize) -> Self {
+        Self::AutoFit(min, max)
+    }
+
+    /// Creates as many tracks as fit the container, keeping empty ones.
+    /// 
+    /// Equivalent to CSS: `repeat(auto-fill, minmax({min}px, {max}))`
+    /// 
+    /// # Arguments
+    /// * `min` - Minimum size of each track in pixels
+    /// * `max` - Maximum size of each track
+    pub fn auto_fill(min: f32, max: GridTrackSize) -> Self {
+        Self::AutoFill(min, max)
+    }
+
     /// Convenience method: Creates equal fractional unit tracks.
     /// 
     /// Creates `count` tracks, each taking equal portions of available space.
@@ -108,7 +144,8 @@ impl GridTrack {
 /// GridTrackSize defines how a single grid track (row or column) should be sized.
 /// These correspond directly to CSS Grid track sizing functions and provide
 /// flexible control over layout behavior.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[reflect(opaque, Debug, PartialEq)]
 pub enum GridTrackSize {
     /// Auto-sized track that fits content.
     /// 
@@ -166,7 +203,7 @@ pub enum GridTrackSize {
 /// GridGap controls the spacing between grid tracks (rows and columns),
 /// providing options for uniform spacing or different row/column gaps.
 /// This corresponds to CSS Grid's `gap`, `row-gap`, and `column-gap` properties.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
 pub enum GridGap {
     /// No spacing between grid items.
     /// Grid items will be adjacent with no gaps.
@@ -18,33 +18,53 @@
 //!
 //! | Level | Purpose | Size | Weight | Accessibility |
 //! |-------|---------|------|--------|--------------|
-//! | **H1** | Main page title | X5l (48px+) | Bold | WCAG AAA |
-//! | **H2** | Major sections | X3l (36px+) | Bold | WCAG AAA |
-//! | **H3** | Subsections | X2l (30px+) | Medium | WCAG AAA |
-//! | **H4** | Minor subsections | Xl (24px+) | Medium | WCAG AA |
-//! | **H5** | Small headings | Lg (20px+) | Medium | WCAG AA |
+//! | **H1** | Main page title | X4l (36px) | Bold | WCAG AAA |
+//! | **H2** | Major sections | X3l (30px) | Bold | WCAG AAA |
+//! | **H3** | Subsections | X2l (24px) | Medium | WCAG AAA |
+//! | **H4** | Minor subsections | Xl (20px) | Medium | WCAG AA |
+//! | **H5** | Small headings | Lg (18px) | Medium | WCAG AA |
 //! | **H6** | Smallest headings | Base (16px) | Medium | WCAG AA |
 //!
 //! ## Examples
 //!
 //! ### Basic Usage
 //! ```rust
-//! use forge_ui::Heading;
+//! use ui::components::Heading;
 //!
 //! // Direct heading creation
 //! let main_title = Heading::h1("Welcome to My App").build();
 //! let section = Heading::h2("Getting Started").build();
 //! let subsection = Heading::h3("Installation").build();
 //!
-//! // Using the generic method
-//! let custom = Heading::new(HeadingLevel::H2, "Custom Title")
+//! // Using the generic method with a level from 1 to 6
+//! let custom = Heading::new(2, "Custom Title")
 //!     .center()
 //!     .build();
 //! ```
 //!
+//! ### Anchors
+//! ```rust
+//! use ui::components::{Heading, HeadingComponent};
+//!
+//! // Tag a heading so it can be found later, e.g. by a table of contents
+//! let install = Heading::h2("Installation").id("installation").build();
+//!
+//! // Every heading carries a HeadingComponent with its level and anchor
+//! fn anchors(headings: Query<&HeadingComponent>) {
+//!     for heading in &headings {
+//!         if let Some(anchor) = &heading.anchor {
+//!             info!("#{anchor} ({:?})", heading.level);
+//!         }
+//!     }
+//! }
+//! ```
+//!
 //! ### Advanced Styling
 //! ```rust
-//! use forge_ui::{Heading, FontFamily, TextColor};
+//! use ui::{
+//!     components::Heading,
+//!     theme::{color::TextColor, typography::FontFamily},
+//! };
 //!
 //! // Styled heading with custom font and color
 //! let styled_heading = Heading::h1("Styled Title")
@@ -62,7 +82,7 @@
 //!
 //! ### Extension Trait Usage
 //! ```rust
-//! use forge_ui::{Text, HeadingExt, HeadingLevel};
+//! use ui::components::{HeadingExt, HeadingLevel, Text};
 //!
 //! // Convert existing text to heading
 //! let converted = Text::new("My Title")
@@ -104,6 +124,7 @@ use bevy::prelude::*;
 use crate::{
     components::text::{Text, TextBuilder},
     theme::{
+        breakpoints::Responsive,
         color::{TextColor as TextColorEnum, TextContrastLevel},
         typography::{FontFamily, TextSize, TextWeight},
     },
@@ -120,32 +141,32 @@ pub enum HeadingLevel {
     /// Main page title - the most important heading on the page.
     /// 
     /// **Usage**: Primary page titles, hero headings, main content titles
-    /// **Size**: X5l (48px+), **Weight**: Bold, **Accessibility**: WCAG AAA
+    /// **Size**: X4l (36px), **Weight**: Bold, **Accessibility**: WCAG AAA
     H1,
     
     /// Major section heading - primary content divisions.
     /// 
     /// **Usage**: Main sections, primary content areas, major topics
-    /// **Size**: X3l (36px+), **Weight**: Bold, **Accessibility**: WCAG AAA
+    /// **Size**: X3l (30px), **Weight**: Bold, **Accessibility**: WCAG AAA
     #[default]
     H2,
     
     /// Subsection heading - secondary content divisions.
     /// 
     /// **Usage**: Subsections within major sections, secondary topics
-    /// **Size**: X2l (30px+), **Weight**: Medium, **Accessibility**: WCAG AAA
+    /// **Size**: X2l (24px), **Weight**: Medium, **Accessibility**: WCAG AAA
     H3,
     
     /// Minor subsection heading - tertiary content divisions.
     /// 
     /// **Usage**: Sub-subsections, detailed breakdowns, specific topics
-    /// **Size**: Xl (24px+), **Weight**: Medium, **Accessibility**: WCAG AA
+    /// **Size**: Xl (20px), **Weight**: Medium, **Accessibility**: WCAG AA
     H4,
     
     /// Small heading - quaternary content divisions.
     /// 
     /// **Usage**: Minor sections, notes, detailed categorization
-    /// **Size**: Lg (20px+), **Weight**: Medium, **Accessibility**: WCAG AA
+    /// **Size**: Lg (18px), **Weight**: Medium, **Accessibility**: WCAG AA
     H5,
     
     /// Smallest heading - minimal content divisions.
@@ -155,6 +176,79 @@ pub enum HeadingLevel {
     H6,
 }
 
+impl HeadingLevel {
+    /// Returns the level as a number from 1 (H1) to 6 (H6).
+    pub fn number(self) -> u8 {
+        match self {
+            HeadingLevel::H1 => 1,
+            HeadingLevel::H2 => 2,
+            HeadingLevel::H3 => 3,
+            HeadingLevel::H4 => 4,
+            HeadingLevel::H5 => 5,
+            HeadingLevel::H6 => 6,
+        }
+    }
+
+    /// Default type scale step for this level.
+    pub fn size(self) -> TextSize {
+        match self {
+            HeadingLevel::H1 => TextSize::X4l,
+            HeadingLevel::H2 => TextSize::X3l,
+            HeadingLevel::H3 => TextSize::X2l,
+            HeadingLevel::H4 => TextSize::Xl,
+            HeadingLevel::H5 => TextSize::Lg,
+            HeadingLevel::H6 => TextSize::Base,
+        }
+    }
+
+    /// Default font weight for this level.
+    pub fn weight(self) -> TextWeight {
+        match self {
+            HeadingLevel::H1 | HeadingLevel::H2 => TextWeight::Bold,
+            _ => TextWeight::Medium,
+        }
+    }
+}
+
+/// Converts a numeric level into a [`HeadingLevel`].
+///
+/// Values outside 1-6 are clamped, so `0` becomes H1 and `9` becomes H6.
+impl From<u8> for HeadingLevel {
+    fn from(level: u8) -> Self {
+        match level {
+            0 | 1 => HeadingLevel::H1,
+            2 => HeadingLevel::H2,
+            3 => HeadingLevel::H3,
+            4 => HeadingLevel::H4,
+            5 => HeadingLevel::H5,
+            _ => HeadingLevel::H6,
+        }
+    }
+}
+
+/// Component attached to every entity built from a [`HeadingBuilder`].
+///
+/// Stores the semantic level and the optional anchor id set with
+/// [`HeadingBuilder::id`], so systems such as a table of contents can
+/// query all headings of a page and link to the anchored ones.
+///
+/// # Example
+/// ```rust
+/// fn list_sections(headings: Query<(&HeadingComponent, &bevy::prelude::Text)>) {
+///     for (heading, text) in &headings {
+///         if let Some(anchor) = &heading.anchor {
+///             info!("{:?} #{}: {}", heading.level, anchor, text.0);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct HeadingComponent {
+    pub level: HeadingLevel,
+    /// Anchor id of the heading, e.g. `"installation"`
+    pub anchor: Option<String>,
+}
+
 /// Main Heading component interface for creating semantic heading elements.
 /// 
 /// The Heading struct provides factory methods for creating headings with
@@ -167,7 +261,7 @@ pub enum HeadingLevel {
 /// The component follows the principle of "semantic meaning drives visual presentation":
 /// 1. Choose the appropriate semantic level (H1-H6) based on content hierarchy
 /// 2. The system automatically applies appropriate typography and accessibility settings
-/// 3. Customize appearance as needed using the returned TextBuilder
+/// 3. Customize appearance as needed using the returned HeadingBuilder
 /// 4. Build the final component with `.build()`
 /// 
 /// # Accessibility Considerations
@@ -181,7 +275,7 @@ pub enum HeadingLevel {
 /// ```rust
 /// // Semantic approach - choose level based on content hierarchy
 /// let page_title = Heading::h1("My Application").center().build();
-/// let section = Heading::h2("Features").build();
+/// let section = Heading::new(2, "Features").id("features").build();
 /// let subsection = Heading::h3("Core Features").build();
 /// 
 /// // Custom styling while maintaining semantics
@@ -195,71 +289,32 @@ pub struct Heading;
 impl Heading {
     /// Creates a new heading with the specified semantic level and content.
     /// 
-    /// This is the core method that applies appropriate typography settings
-    /// based on the semantic importance of the heading level. Each level
-    /// has predefined size, weight, and accessibility settings optimized
-    /// for clear visual hierarchy and readability.
+    /// The level picks the default size and weight from the type scale
+    /// (see [`HeadingLevel::size`] and [`HeadingLevel::weight`]); both can be
+    /// overridden on the returned builder.
     /// 
     /// # Arguments
-    /// * `level` - The semantic heading level (H1-H6)
+    /// * `level` - The semantic heading level, either a [`HeadingLevel`] or a number from 1 to 6
     /// * `content` - The text content for the heading
     /// 
     /// # Returns
-    /// A TextBuilder configured with heading-appropriate typography
+    /// A HeadingBuilder configured with heading-appropriate typography
     /// 
     /// # Example
     /// ```rust
-    /// use forge_ui::{Heading, HeadingLevel};
+    /// use ui::components::{Heading, HeadingLevel};
     /// 
     /// let main_title = Heading::new(HeadingLevel::H1, "Welcome")
     ///     .center()
     ///     .build();
     /// 
-    /// let section = Heading::new(HeadingLevel::H2, "Getting Started")
-    ///     .color(TextColor::Accent)
+    /// let section = Heading::new(2, "Getting Started")
+    ///     .size(TextSize::X4l)
+    ///     .id("getting-started")
     ///     .build();
     /// ```
-    pub fn new(level: HeadingLevel, content: impl Into<String>) -> TextBuilder {
-        let content = content.into();
-
-        match level {
-            HeadingLevel::H1 => {
-                Text::new(content)
-                    .size(TextSize::X5l) // Largest heading
-                    .weight(TextWeight::Bold)
-                    .color(TextColorEnum::Default)
-            }
-            HeadingLevel::H2 => {
-                Text::new(content)
-                    .size(TextSize::X3l) // Large heading
-                    .weight(TextWeight::Bold)
-                    .color(TextColorEnum::Default)
-            }
-            HeadingLevel::H3 => {
-                Text::new(content)
-                    .size(TextSize::X2l) // Medium-large heading
-                    .weight(TextWeight::Medium)
-                    .color(TextColorEnum::Default)
-            }
-            HeadingLevel::H4 => {
-                Text::new(content)
-                    .size(TextSize::Xl) // Medium heading
-                    .weight(TextWeight::Medium)
-                    .color(TextColorEnum::Default)
-            }
-            HeadingLevel::H5 => {
-                Text::new(content)
-                    .size(TextSize::Lg) // Small heading
-                    .weight(TextWeight::Medium)
-                    .color(TextColorEnum::Default)
-            }
-            HeadingLevel::H6 => {
-                Text::new(content)
-                    .size(TextSize::Base) // Smallest heading
-                    .weight(TextWeight::Medium)
-                    .color(TextColorEnum::Default)
-            }
-        }
+    pub fn new(level: impl Into<HeadingLevel>, content: impl Into<String>) -> HeadingBuilder {
+        HeadingBuilder::new(level, content)
     }
 
     // === Semantic Heading Factory Methods ===
@@ -268,7 +323,7 @@ impl Heading {
     /// 
     /// H1 headings are the most important on the page and should be used
     /// for primary page titles, hero sections, or main content headings.
-    /// **Typography**: X5l size, Bold weight, WCAG AAA contrast
+    /// **Typography**: X4l size, Bold weight, WCAG AAA contrast
     /// 
     /// # Arguments
     /// * `content` - The heading text content
@@ -279,7 +334,7 @@ impl Heading {
     ///     .center()
     ///     .build();
     /// ```
-    pub fn h1(content: impl Into<String>) -> TextBuilder {
+    pub fn h1(content: impl Into<String>) -> HeadingBuilder {
         Self::new(HeadingLevel::H1, content)
     }
 
@@ -298,7 +353,7 @@ impl Heading {
     ///     .color(TextColor::Accent)
     ///     .build();
     /// ```
-    pub fn h2(content: impl Into<String>) -> TextBuilder {
+    pub fn h2(content: impl Into<String>) -> HeadingBuilder {
         Self::new(HeadingLevel::H2, content)
     }
 
@@ -317,7 +372,7 @@ impl Heading {
     ///     .family(FontFamily::Sans)
     ///     .build();
     /// ```
-    pub fn h3(content: impl Into<String>) -> TextBuilder {
+    pub fn h3(content: impl Into<String>) -> HeadingBuilder {
         Self::new(HeadingLevel::H3, content)
     }
 
@@ -336,7 +391,7 @@ impl Heading {
     ///     .high_contrast()
     ///     .build();
     /// ```
-    pub fn h4(content: impl Into<String>) -> TextBuilder {
+    pub fn h4(content: impl Into<String>) -> HeadingBuilder {
         Self::new(HeadingLevel::H4, content)
     }
 
@@ -355,7 +410,7 @@ impl Heading {
     ///     .right()
     ///     .build();
     /// ```
-    pub fn h5(content: impl Into<String>) -> TextBuilder {
+    pub fn h5(content: impl Into<String>) -> HeadingBuilder {
         Self::new(HeadingLevel::H5, content)
     }
 
@@ -374,11 +429,157 @@ impl Heading {
     ///     .color(TextColor::Muted)
     ///     .build();
     /// ```
-    pub fn h6(content: impl Into<String>) -> TextBuilder {
+    pub fn h6(content: impl Into<String>) -> HeadingBuilder {
         Self::new(HeadingLevel::H6, content)
     }
 }
 
+/// Builder for heading entities, returned by the [`Heading`] factory methods.
+///
+/// Wraps a [`TextBuilder`] preconfigured for the heading level and adds the
+/// heading-specific anchor id. All typography, alignment and contrast methods
+/// forward to the underlying text builder.
+#[derive(Debug, Clone)]
+pub struct HeadingBuilder {
+    level: HeadingLevel,
+    anchor: Option<String>,
+    text: TextBuilder,
+}
+
+impl HeadingBuilder {
+    /// Creates a heading builder with the level's default size and weight.
+    pub fn new(level: impl Into<HeadingLevel>, content: impl Into<String>) -> Self {
+        let level = level.into();
+        Self {
+            level,
+            anchor: None,
+            text: Text::new(content)
+                .size(level.size())
+                .weight(level.weight())
+                .color(TextColorEnum::Default),
+        }
+    }
+
+    /// Tags the heading with an anchor id, stored in its [`HeadingComponent`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let section = Heading::h2("Installation").id("installation").build();
+    /// ```
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.anchor = Some(id.into());
+        self
+    }
+
+    // === Typography Methods ===
+
+    /// Overrides the level's default text size.
+    pub fn size(mut self, size: TextSize) -> Self {
+        self.text = self.text.size(size);
+        self
+    }
+
+    /// Sets the text size per breakpoint, see [`TextBuilder::size_responsive`].
+    pub fn size_responsive(mut self, sizes: impl Into<Responsive<TextSize>>) -> Self {
+        self.text = self.text.size_responsive(sizes);
+        self
+    }
+
+    /// Overrides the level's default font weight.
+    pub fn weight(mut self, weight: TextWeight) -> Self {
+        self.text = self.text.weight(weight);
+        self
+    }
+
+    /// Sets the font family for the heading.
+    pub fn family(mut self, family: FontFamily) -> Self {
+        self.text = self.text.family(family);
+        self
+    }
+
+    /// Makes the heading text italic.
+    pub fn italic(mut self) -> Self {
+        self.text = self.text.italic();
+        self
+    }
+
+    /// Sets an explicit text color for the heading.
+    pub fn color(mut self, color: TextColorEnum) -> Self {
+        self.text = self.text.color(color);
+        self
+    }
+
+    // === Alignment Methods ===
+
+    /// Sets custom text alignment for the heading.
+    pub fn align(mut self, align: JustifyText) -> Self {
+        self.text = self.text.align(align);
+        self
+    }
+
+    /// Centers the heading text.
+    pub fn center(mut self) -> Self {
+        self.text = self.text.center();
+        self
+    }
+
+    /// Right-aligns the heading text.
+    pub fn right(mut self) -> Self {
+        self.text = self.text.right();
+        self
+    }
+
+    // === Accessibility & Contrast Methods ===
+
+    /// Sets background context for intelligent contrast calculation.
+    pub fn on_background(mut self, background_color: Color) -> Self {
+        self.text = self.text.on_background(background_color);
+        self
+    }
+
+    /// Sets the desired contrast level for accessibility.
+    pub fn contrast_level(mut self, level: TextContrastLevel) -> Self {
+        self.text = self.text.contrast_level(level);
+        self
+    }
+
+    /// Enables high contrast mode (WCAG AA compliance).
+    pub fn high_contrast(mut self) -> Self {
+        self.text = self.text.high_contrast();
+        self
+    }
+
+    /// Enables accessible contrast mode (WCAG AAA compliance).
+    pub fn accessible(mut self) -> Self {
+        self.text = self.text.accessible();
+        self
+    }
+
+    /// Enables automatic contrast optimization.
+    pub fn auto_contrast(mut self) -> Self {
+        self.text = self.text.auto_contrast();
+        self
+    }
+
+    /// Disables automatic contrast optimization.
+    pub fn manual_color(mut self) -> Self {
+        self.text = self.text.manual_color();
+        self
+    }
+
+    /// Builds the heading into a Bevy Bundle: the text bundle plus a
+    /// [`HeadingComponent`] carrying the level and anchor.
+    pub fn build(self) -> impl Bundle {
+        (
+            self.text.build(),
+            HeadingComponent {
+                level: self.level,
+                anchor: self.anchor,
+            },
+        )
+    }
+}
+
 /// Extension trait that adds heading functionality to TextBuilder.
 /// 
 /// This trait allows converting existing TextBuilder instances into headings
@@ -395,7 +596,7 @@ impl Heading {
 /// 
 /// # Example
 /// ```rust
-/// use forge_ui::{Text, HeadingExt, HeadingLevel};
+/// use ui::components::{HeadingExt, HeadingLevel, Text};
 /// 
 /// // Convert existing text to heading
 /// let converted = Text::new("Important Section")
@@ -486,30 +687,7 @@ impl HeadingExt for TextBuilder {
     /// - H4-H6: WCAG AA contrast (high_contrast)
     fn as_heading(self, level: HeadingLevel) -> TextBuilder {
         // Apply typography settings based on heading level
-        let base_builder = match level {
-            HeadingLevel::H1 => self
-                .size(TextSize::X5l)  // Largest heading size
-                .weight(TextWeight::Bold)
-                .high_contrast(),
-            HeadingLevel::H2 => self
-                .size(TextSize::X3l)  // Large section heading
-                .weight(TextWeight::Bold)
-                .high_contrast(),
-            HeadingLevel::H3 => self
-                .size(TextSize::X2l)  // Medium-large subsection
-                .weight(TextWeight::Medium)
-                .high_contrast(),
-            HeadingLevel::H4 => self
-                .size(TextSize::Xl)   // Medium heading
-                .weight(TextWeight::Medium)
-                .high_contrast(),
-            HeadingLevel::H5 => self
-                .size(TextSize::Lg)   // Small heading
-                .weight(TextWeight::Medium),
-            HeadingLevel::H6 => self
-                .size(TextSize::Base) // Smallest heading
-                .weight(TextWeight::Medium),
-        };
+        let base_builder = self.size(level.size()).weight(level.weight());
 
         // Apply semantic-aware accessibility defaults
         // Higher importance headings get better contrast
@@ -589,3 +767,32 @@ impl HeadingExt for TextBuilder {
         self.color(color)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels_map_to_documented_type_scale() {
+        let expected = [
+            (1, TextSize::X4l, TextWeight::Bold),
+            (2, TextSize::X3l, TextWeight::Bold),
+            (3, TextSize::X2l, TextWeight::Medium),
+            (4, TextSize::Xl, TextWeight::Medium),
+            (5, TextSize::Lg, TextWeight::Medium),
+            (6, TextSize::Base, TextWeight::Medium),
+        ];
+        for (number, size, weight) in expected {
+            let level = HeadingLevel::from(number);
+            assert_eq!(level.number(), number);
+            assert_eq!(level.size(), size, "size of H{number}");
+            assert_eq!(level.weight(), weight, "weight of H{number}");
+        }
+    }
+
+    #[test]
+    fn test_numeric_levels_are_clamped() {
+        assert_eq!(HeadingLevel::from(0), HeadingLevel::H1);
+        assert_eq!(HeadingLevel::from(9), HeadingLevel::H6);
+    }
+}
@@ -1,13 +1,25 @@
 use crate::{
-    components::text::{Text, TextBuilder},
+    components::{
+        dismiss::{DismissEvent, Dismissable},
+        text::{Text, TextBuilder},
+    },
     theme::{
         color::{accent_palette, TextColor as TextColorEnum, TextContrastLevel, UiColorPalette},
-        layout::UiLayout,
+        layout::{Elevation, ElevationShadow, UiLayout},
+        motion::UiMotion,
+        radix_theme::{PanelSurface, RadixTheme},
         typography::{FontFamily, TextSize, TextWeight},
     },
-    utilities::{portal::Portal, ui_root::UIRoot},
+    utilities::{
+        naming::Namespace,
+        placement::{place_floating, PlacementAlign, PlacementOptions, PlacementSide},
+        portal::{PortalLayer, PortalOwner, PortalRoots},
+    },
+};
+use bevy::{
+    ecs::{entity::Entities, spawn::SpawnWith},
+    prelude::*,
 };
-use bevy::{ecs::spawn::SpawnWith, input::keyboard::KeyCode, prelude::*};
 use bevy_picking::prelude::Pickable;
 use std::time::Duration;
 
@@ -23,32 +35,11 @@ pub struct HoverCardCloseEvent {
 }
 
 // Enumerations
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum HoverCardSide {
-    Top,
-    Right,
-    Bottom,
-    Left,
-}
+/// Preferred side of the trigger the card opens on
+pub type HoverCardSide = PlacementSide;
 
-impl Default for HoverCardSide {
-    fn default() -> Self {
-        Self::Bottom
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum HoverCardAlign {
-    Start,
-    Center,
-    End,
-}
-
-impl Default for HoverCardAlign {
-    fn default() -> Self {
-        Self::Center
-    }
-}
+/// Alignment of the card with its trigger
+pub type HoverCardAlign = PlacementAlign;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HoverCardSticky {
@@ -79,6 +70,8 @@ impl Default for HoverCardState {
 // Timer component for managing delays
 #[derive(Component, Debug)]
 pub struct HoverCardTimer {
+    /// Hover card opened or closed when the timer finishes
+    pub hover_card: Entity,
     pub timer: Timer,
     pub action: HoverCardTimerAction,
 }
@@ -111,6 +104,12 @@ impl Default for HoverCard {
     }
 }
 
+impl Dismissable for HoverCard {
+    fn is_open(&self) -> bool {
+        self.state == HoverCardState::Open
+    }
+}
+
 #[derive(Component, Debug, Clone)]
 pub struct HoverCardTrigger {
     pub hover_card: Entity,
@@ -137,12 +136,21 @@ pub struct HoverCardContent {
     pub align_offset: f32,
     pub avoid_collisions: bool,
     pub collision_boundary: Option<Entity>,
-    pub collision_padding: UiRect,
+    /// Minimum distance kept from the window edges
+    pub collision_padding: f32,
     pub arrow: bool,
     pub arrow_width: f32,
     pub arrow_height: f32,
     pub sticky: HoverCardSticky,
     pub hide_when_detached: bool,
+    /// Whether the pointer is over the card, keeping it open
+    pub is_hovered: bool,
+    /// Portal layer the content is moved into
+    pub portal_layer: PortalLayer,
+    /// Whether the content follows the cursor instead of the trigger
+    pub follow_cursor: bool,
+    /// Offset of the content's top-left corner from the cursor
+    pub cursor_offset: Vec2,
 }
 
 impl HoverCardContent {
@@ -155,12 +163,16 @@ impl HoverCardContent {
             align_offset: 0.0,
             avoid_collisions: true,
             collision_boundary: None,
-            collision_padding: UiRect::all(Val::Px(10.0)),
+            collision_padding: 10.0,
             arrow: false,
             arrow_width: 10.0,
             arrow_height: 5.0,
             sticky: HoverCardSticky::default(),
             hide_when_detached: true,
+            is_hovered: false,
+            portal_layer: PortalLayer::Overlay,
+            follow_cursor: false,
+            cursor_offset: Vec2::new(12.0, 16.0),
         }
     }
 }
@@ -422,6 +434,7 @@ pub struct HoverCardContentBuilder {
     custom_theme: Option<HoverCardTheme>,
     radius: f32,
     padding: f32,
+    elevation: Elevation,
     children: Vec<Entity>,
     keyboard_navigable: bool,
 }
@@ -435,6 +448,7 @@ impl HoverCardContentBuilder {
             custom_theme: None,
             radius: UiLayout::default().radius.base,
             padding: UiLayout::default().padding.base,
+            elevation: Elevation::Md,
             children: Vec::new(),
             keyboard_navigable: true,
         }
@@ -470,7 +484,7 @@ impl HoverCardContentBuilder {
         self
     }
 
-    pub fn collision_padding(mut self, padding: UiRect) -> Self {
+    pub fn collision_padding(mut self, padding: f32) -> Self {
         self.content.collision_padding = padding;
         self
     }
@@ -496,6 +510,25 @@ impl HoverCardContentBuilder {
         self
     }
 
+    /// Moves the content with the cursor, at `cursor_offset` from it, instead
+    /// of anchoring it to the trigger. It still stays inside the window.
+    pub fn follow_cursor(mut self) -> Self {
+        self.content.follow_cursor = true;
+        self
+    }
+
+    /// Offset from the cursor while following it (12, 16 by default).
+    pub fn cursor_offset(mut self, offset: Vec2) -> Self {
+        self.content.cursor_offset = offset;
+        self
+    }
+
+    /// Sets the portal layer the content renders into (Overlay by default).
+    pub fn portal_layer(mut self, layer: PortalLayer) -> Self {
+        self.content.portal_layer = layer;
+        self
+    }
+
     pub fn theme(mut self, theme: UiColorPalette) -> Self {
         self.theme = theme;
         self
@@ -511,6 +544,12 @@ impl HoverCardContentBuilder {
         self
     }
 
+    /// Set the shadow level of the card (`Md` by default)
+    pub fn elevation(mut self, elevation: Elevation) -> Self {
+        self.elevation = elevation;
+        self
+    }
+
     pub fn child(mut self, entity: Entity) -> Self {
         self.children.push(entity);
         self
@@ -533,9 +572,16 @@ impl HoverCardContentBuilder {
 
     pub fn build(self) -> impl Bundle {
         let layout = UiLayout::default();
+        // Custom themes bring their own background, the default one follows the panel background
         let effective_theme = self
             .custom_theme
+            .clone()
             .unwrap_or_else(|| HoverCardTheme::from_palette(&self.theme));
+        let panel = PanelSurface {
+            palette: self.theme.clone(),
+            solid: effective_theme.background,
+            active: self.custom_theme.is_none(),
+        };
 
         let mut bundle = (
             Name::new(self.name),
@@ -551,8 +597,12 @@ impl HoverCardContentBuilder {
             BorderRadius::all(Val::Px(self.radius)),
             BackgroundColor(effective_theme.background),
             BorderColor(effective_theme.border),
+            ElevationShadow::bundle(self.elevation),
+            panel,
             Visibility::Hidden, // Start hidden
             ZIndex(1000),       // Ensure it renders above other content
+            // Hovering the card keeps it open
+            Interaction::default(),
         );
 
         // Add keyboard navigation components if enabled
@@ -573,109 +623,130 @@ impl Default for HoverCardBuilder {
 
 // System Implementations
 
-/// System to handle hover card trigger interactions and timing
+/// Whether the pointer is over an element with this interaction.
+fn is_pointer_over(interaction: &Interaction) -> bool {
+    !matches!(interaction, Interaction::None)
+}
+
+/// System to handle hover card trigger interactions and timing.
+///
+/// A card opens once the pointer has rested on its trigger for `open_delay`
+/// and closes once the pointer has left both the trigger and the card for
+/// `close_delay`, so the pointer can travel from the trigger to the card.
 pub fn hover_card_interaction_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut trigger_query: Query<(Entity, &mut HoverCardTrigger, &Interaction), Changed<Interaction>>,
+    mut trigger_query: Query<(&mut HoverCardTrigger, Ref<Interaction>)>,
+    mut content_query: Query<(&mut HoverCardContent, Ref<Interaction>)>,
     mut hover_card_query: Query<&mut HoverCard>,
     mut timer_query: Query<(Entity, &mut HoverCardTimer)>,
 ) {
-    // Handle trigger interaction changes
-    for (_trigger_entity, mut trigger, interaction) in trigger_query.iter_mut() {
-        if trigger.disabled {
+    // Cards whose trigger or content the pointer entered or left
+    let mut changed_cards: Vec<Entity> = Vec::new();
+    for (mut trigger, interaction) in trigger_query.iter_mut() {
+        if trigger.disabled || !interaction.is_changed() {
             continue;
         }
-
-        let hover_card_entity = trigger.hover_card;
-        let Ok(hover_card) = hover_card_query.get_mut(hover_card_entity) else {
+        let hovered = is_pointer_over(&interaction);
+        if trigger.is_hovered != hovered {
+            trigger.is_hovered = hovered;
+            changed_cards.push(trigger.hover_card);
+        }
+    }
+    for (mut content, interaction) in content_query.iter_mut() {
+        if !interaction.is_changed() {
             continue;
-        };
-
-        match interaction {
-            Interaction::Hovered => {
-                if !trigger.is_hovered {
-                    trigger.is_hovered = true;
-
-                    // Cancel any existing close timer
-                    for (timer_entity, timer) in timer_query.iter() {
-                        if matches!(timer.action, HoverCardTimerAction::Close) {
-                            commands.entity(timer_entity).despawn();
-                        }
-                    }
+        }
+        let hovered = is_pointer_over(&interaction);
+        if content.is_hovered != hovered {
+            content.is_hovered = hovered;
+            changed_cards.push(content.hover_card);
+        }
+    }
+    changed_cards.sort();
+    changed_cards.dedup();
 
-                    // Start open timer if not controlled and not already open
-                    if !hover_card.controlled && hover_card.state == HoverCardState::Closed {
-                        let mut timer = Timer::new(hover_card.open_delay, TimerMode::Once);
-                        timer.tick(Duration::ZERO);
+    let mut cancelled: Vec<Entity> = Vec::new();
 
-                        commands.spawn(HoverCardTimer {
-                            timer,
-                            action: HoverCardTimerAction::Open,
-                        });
-                    }
-                }
-            }
-            Interaction::None => {
-                if trigger.is_hovered {
-                    trigger.is_hovered = false;
-
-                    // Cancel any existing open timer
-                    for (timer_entity, timer) in timer_query.iter() {
-                        if matches!(timer.action, HoverCardTimerAction::Open) {
-                            commands.entity(timer_entity).despawn();
-                        }
-                    }
+    for hover_card_entity in changed_cards {
+        let Ok(hover_card) = hover_card_query.get(hover_card_entity) else {
+            continue;
+        };
+        if hover_card.controlled {
+            continue;
+        }
 
-                    // Start close timer if not controlled and currently open
-                    if !hover_card.controlled && hover_card.state == HoverCardState::Open {
-                        let mut timer = Timer::new(hover_card.close_delay, TimerMode::Once);
-                        timer.tick(Duration::ZERO);
+        let hovered = trigger_query
+            .iter()
+            .any(|(trigger, _)| trigger.hover_card == hover_card_entity && trigger.is_hovered)
+            || content_query
+                .iter()
+                .any(|(content, _)| content.hover_card == hover_card_entity && content.is_hovered);
+        // Entering cancels a pending close and leaving a pending open
+        let (cancel, start, delay) = if hovered {
+            (
+                HoverCardTimerAction::Close,
+                HoverCardTimerAction::Open,
+                hover_card.open_delay,
+            )
+        } else {
+            (
+                HoverCardTimerAction::Open,
+                HoverCardTimerAction::Close,
+                hover_card.close_delay,
+            )
+        };
 
-                        commands.spawn(HoverCardTimer {
-                            timer,
-                            action: HoverCardTimerAction::Close,
-                        });
-                    }
-                }
+        let mut pending = false;
+        for (timer_entity, timer) in timer_query.iter() {
+            if timer.hover_card != hover_card_entity {
+                continue;
             }
-            Interaction::Pressed => {
-                // Optional: Handle click interactions if needed
+            if timer.action == cancel {
+                commands.entity(timer_entity).despawn();
+                cancelled.push(timer_entity);
+            } else {
+                pending = true;
             }
         }
+
+        let needs_timer = match start {
+            HoverCardTimerAction::Open => hover_card.state == HoverCardState::Closed,
+            HoverCardTimerAction::Close => hover_card.state == HoverCardState::Open,
+        };
+        if needs_timer && !pending {
+            commands.spawn(HoverCardTimer {
+                hover_card: hover_card_entity,
+                timer: Timer::new(delay, TimerMode::Once),
+                action: start,
+            });
+        }
     }
 
     // Update timers
     for (timer_entity, mut hover_timer) in timer_query.iter_mut() {
+        if cancelled.contains(&timer_entity) {
+            continue;
+        }
         hover_timer.timer.tick(time.delta());
+        if !hover_timer.timer.finished() {
+            continue;
+        }
 
-        if hover_timer.timer.finished() {
+        if let Ok(mut hover_card) = hover_card_query.get_mut(hover_timer.hover_card) {
             match hover_timer.action {
-                HoverCardTimerAction::Open => {
-                    // Find the associated hover card and open it
-                    // This is a simplified approach - in a full implementation,
-                    // we'd need to track which timer belongs to which hover card
-                    for mut hover_card in hover_card_query.iter_mut() {
-                        if hover_card.state == HoverCardState::Closed {
-                            hover_card.state = HoverCardState::Opening;
-                            break;
-                        }
-                    }
+                HoverCardTimerAction::Open if hover_card.state == HoverCardState::Closed => {
+                    hover_card.state = HoverCardState::Opening;
                 }
-                HoverCardTimerAction::Close => {
-                    // Find the associated hover card and close it
-                    for mut hover_card in hover_card_query.iter_mut() {
-                        if hover_card.state == HoverCardState::Open {
-                            hover_card.state = HoverCardState::Closing;
-                            break;
-                        }
-                    }
+                HoverCardTimerAction::Close if hover_card.state == HoverCardState::Open => {
+                    hover_card.state = HoverCardState::Closing;
                 }
+                _ => {}
             }
-
-            // Remove the finished timer
-            commands.entity(timer_entity).despawn();
         }
+
+        // Remove the finished timer
+        commands.entity(timer_entity).despawn();
     }
 }
 
@@ -683,7 +754,7 @@ pub fn hover_card_interaction_system(
 pub fn hover_card_state_system(
     _commands: Commands,
     mut hover_card_query: Query<(Entity, &mut HoverCard), Changed<HoverCard>>,
-    content_query: Query<Entity, With<HoverCardContent>>,
+    content_query: Query<(Entity, &HoverCardContent)>,
     mut visibility_query: Query<&mut Visibility>,
     mut open_events: EventWriter<HoverCardOpenEvent>,
     mut close_events: EventWriter<HoverCardCloseEvent>,
@@ -695,7 +766,10 @@ pub fn hover_card_state_system(
                 hover_card.state = HoverCardState::Open;
 
                 // Show content
-                for content_entity in content_query.iter() {
+                for (content_entity, content) in content_query.iter() {
+                    if content.hover_card != hover_card_entity {
+                        continue;
+                    }
                     if let Ok(mut visibility) = visibility_query.get_mut(content_entity) {
                         *visibility = Visibility::Inherited;
                     }
@@ -711,7 +785,10 @@ pub fn hover_card_state_system(
                 hover_card.state = HoverCardState::Closed;
 
                 // Hide content
-                for content_entity in content_query.iter() {
+                for (content_entity, content) in content_query.iter() {
+                    if content.hover_card != hover_card_entity {
+                        continue;
+                    }
                     if let Ok(mut visibility) = visibility_query.get_mut(content_entity) {
                         *visibility = Visibility::Hidden;
                     }
@@ -727,14 +804,44 @@ pub fn hover_card_state_system(
     }
 }
 
-/// System to position hover card content relative to triggers
+/// Top-left corner of content following the cursor: `offset` below and to
+/// the right of it, flipped above and shifted to stay inside `bounds`.
+pub fn cursor_follow_position(
+    cursor: Vec2,
+    content_size: Vec2,
+    bounds: Rect,
+    content: &HoverCardContent,
+) -> Vec2 {
+    place_floating(
+        Rect::from_center_size(cursor, Vec2::ZERO),
+        content_size,
+        bounds,
+        &PlacementOptions {
+            side: PlacementSide::Bottom,
+            side_offset: content.cursor_offset.y,
+            align: PlacementAlign::Start,
+            align_offset: content.cursor_offset.x,
+            avoid_collisions: content.avoid_collisions,
+            collision_padding: content.collision_padding,
+        },
+    )
+    .position
+}
+
+/// System to position hover card content relative to triggers, flipping and
+/// shifting it to stay inside the window
+///
+/// Content following the cursor is moved every frame while open.
 pub fn hover_card_positioning_system(
-    mut content_query: Query<(Entity, &HoverCardContent, &mut Node), With<HoverCardContentMarker>>,
+    mut content_query: Query<
+        (&HoverCardContent, &mut Node, Option<&ComputedNode>),
+        With<HoverCardContentMarker>,
+    >,
     trigger_query: Query<
         (Entity, &HoverCardTrigger, &GlobalTransform),
         (With<HoverCardTrigger>, Without<HoverCardContentMarker>),
     >,
-    node_query: Query<&Node, Without<HoverCardContentMarker>>,
+    node_query: Query<(&Node, Option<&ComputedNode>), Without<HoverCardContentMarker>>,
     hover_card_query: Query<&HoverCard>,
     hover_card_changed: Query<Entity, (With<HoverCard>, Changed<HoverCard>)>,
     window_query: Query<&Window>,
@@ -746,11 +853,14 @@ pub fn hover_card_positioning_system(
     // Check if any hover card state changed
     let has_state_changes = hover_card_changed.iter().any(|_| true);
 
-    if !has_state_changes {
-        return; // No positioning updates needed
-    }
+    let bounds = Rect::new(0.0, 0.0, window.width(), window.height());
+
+    for (content, mut content_node, content_computed) in content_query.iter_mut() {
+        // Anchored content only moves when a hover card state changed
+        if !has_state_changes && !content.follow_cursor {
+            continue;
+        }
 
-    for (_content_entity, content, mut content_node) in content_query.iter_mut() {
         // Get hover card state
         let Ok(hover_card) = hover_card_query.get(content.hover_card) else {
             continue;
@@ -758,53 +868,68 @@ pub fn hover_card_positioning_system(
 
         // Update position for open or opening hover cards
         if hover_card.state == HoverCardState::Open || hover_card.state == HoverCardState::Opening {
+            if content.follow_cursor {
+                // Keeps the last position while the cursor is outside the window
+                let Some(cursor) = window.cursor_position() else {
+                    continue;
+                };
+                let content_size = content_computed
+                    .map(|computed| computed.size() * window.scale_factor().recip())
+                    .unwrap_or_default();
+                let position = cursor_follow_position(cursor, content_size, bounds, content);
+                let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+                if content_node.left != left || content_node.top != top {
+                    content_node.left = left;
+                    content_node.top = top;
+                }
+                continue;
+            }
+
             // Find the matching trigger for this content
             let trigger_data = trigger_query
                 .iter()
                 .find(|(_, trigger, _)| trigger.hover_card == content.hover_card);
 
             if let Some((trigger_entity, _, trigger_transform)) = trigger_data {
-                // Get trigger position and size
-                let trigger_pos = trigger_transform.translation();
-                let trigger_size = if let Ok(trigger_node) = node_query.get(trigger_entity) {
-                    extract_node_size(trigger_node)
-                } else {
-                    Vec2::new(100.0, 40.0) // Fallback size
+                // UI transforms and computed sizes are in physical pixels
+                let to_logical = window.scale_factor().recip();
+                let trigger_center = trigger_transform.translation().truncate() * to_logical;
+                let trigger_size = match node_query.get(trigger_entity) {
+                    Ok((_, Some(computed))) if computed.size() != Vec2::ZERO => {
+                        computed.size() * to_logical
+                    }
+                    Ok((trigger_node, _)) => extract_node_size(trigger_node),
+                    Err(_) => Vec2::new(100.0, 40.0), // Fallback size
                 };
-
-                // Calculate content position based on trigger position and side preference
-                let (offset_x, offset_y) = calculate_content_position_improved(
-                    trigger_pos,
-                    trigger_size,
-                    content.side,
-                    content.side_offset,
-                    content.align,
-                    content.align_offset,
+                // Zero until the content has been laid out once
+                let content_size = content_computed
+                    .map(|computed| computed.size() * to_logical)
+                    .unwrap_or_default();
+
+                let placement = place_floating(
+                    Rect::from_center_size(trigger_center, trigger_size),
+                    content_size,
+                    bounds,
+                    &PlacementOptions {
+                        side: content.side,
+                        side_offset: content.side_offset,
+                        align: content.align,
+                        align_offset: content.align_offset,
+                        avoid_collisions: content.avoid_collisions,
+                        collision_padding: content.collision_padding,
+                    },
                 );
 
-                // Apply collision detection if enabled
-                let (final_x, final_y) = if content.avoid_collisions {
-                    apply_collision_detection(
-                        offset_x,
-                        offset_y,
-                        window.width(),
-                        window.height(),
-                        content.collision_padding,
-                    )
-                } else {
-                    (offset_x, offset_y)
-                };
-
                 // Update position - ensure it's positioned relative to the window
-                content_node.left = Val::Px(final_x);
-                content_node.top = Val::Px(final_y);
+                content_node.left = Val::Px(placement.position.x);
+                content_node.top = Val::Px(placement.position.y);
 
                 // Also ensure the content uses absolute positioning
                 content_node.position_type = PositionType::Absolute;
 
                 info!(
-                    "Positioning hover card: trigger_pos={:?}, trigger_size={:?}, side={:?}, final_pos=({}, {})",
-                    trigger_pos, trigger_size, content.side, final_x, final_y
+                    "Positioning hover card: trigger_center={:?}, trigger_size={:?}, side={:?}, placement={:?}",
+                    trigger_center, trigger_size, content.side, placement
                 );
             }
         }
@@ -828,163 +953,49 @@ fn extract_node_size(node: &Node) -> Vec2 {
     Vec2::new(width, height)
 }
 
-/// Calculate the initial position for hover card content (legacy)
-fn calculate_content_position(
-    trigger_pos: Vec3,
-    side: HoverCardSide,
-    side_offset: f32,
-    _align: HoverCardAlign,
-    align_offset: f32,
-) -> (f32, f32) {
-    let base_x = trigger_pos.x;
-    let base_y = trigger_pos.y;
-
-    match side {
-        HoverCardSide::Top => (base_x + align_offset, base_y - side_offset),
-        HoverCardSide::Bottom => (base_x + align_offset, base_y + side_offset),
-        HoverCardSide::Left => (base_x - side_offset, base_y + align_offset),
-        HoverCardSide::Right => (base_x + side_offset, base_y + align_offset),
-    }
-}
-
-/// Calculate the improved position for hover card content with proper trigger size consideration
-fn calculate_content_position_improved(
-    trigger_pos: Vec3,
-    trigger_size: Vec2,
-    side: HoverCardSide,
-    side_offset: f32,
-    align: HoverCardAlign,
-    align_offset: f32,
-) -> (f32, f32) {
-    // Convert Bevy world coordinates to UI coordinates
-    // Bevy UI has origin at top-left, Y increases downward
-    let base_x = trigger_pos.x;
-    let base_y = trigger_pos.y;
-
-    let half_width = trigger_size.x / 2.0;
-    let half_height = trigger_size.y / 2.0;
-
-    let (mut content_x, mut content_y) = match side {
-        HoverCardSide::Top => {
-            // Position above the trigger
-            (base_x, base_y - half_height - side_offset)
-        }
-        HoverCardSide::Bottom => {
-            // Position below the trigger
-            (base_x, base_y + half_height + side_offset)
-        }
-        HoverCardSide::Left => {
-            // Position to the left of the trigger
-            (base_x - half_width - side_offset, base_y)
-        }
-        HoverCardSide::Right => {
-            // Position to the right of the trigger
-            (base_x + half_width + side_offset, base_y)
-        }
-    };
-
-    // Apply alignment
-    match side {
-        HoverCardSide::Top | HoverCardSide::Bottom => {
-            // For top/bottom positioning, align horizontally
-            match align {
-                HoverCardAlign::Start => content_x = base_x - half_width + align_offset,
-                HoverCardAlign::Center => content_x = base_x + align_offset,
-                HoverCardAlign::End => content_x = base_x + half_width + align_offset,
-            }
-        }
-        HoverCardSide::Left | HoverCardSide::Right => {
-            // For left/right positioning, align vertically
-            match align {
-                HoverCardAlign::Start => content_y = base_y - half_height + align_offset,
-                HoverCardAlign::Center => content_y = base_y + align_offset,
-                HoverCardAlign::End => content_y = base_y + half_height + align_offset,
-            }
-        }
+/// System moving new hover card content into the root of its portal layer,
+/// so it draws above the regular UI and is positioned in window coordinates.
+pub fn hover_card_portal_system(
+    mut commands: Commands,
+    content_query: Query<(Entity, &HoverCardContent), Added<HoverCardContentMarker>>,
+    mut portal_roots: ResMut<PortalRoots>,
+    entities: &Entities,
+) {
+    for (content_entity, content) in content_query.iter() {
+        let root = portal_roots.root(&mut commands, entities, content.portal_layer);
+        commands.entity(root).add_child(content_entity);
     }
-
-    (content_x, content_y)
 }
 
-/// Apply collision detection to prevent content from going off-screen
-fn apply_collision_detection(
-    x: f32,
-    y: f32,
-    window_width: f32,
-    window_height: f32,
-    padding: UiRect,
-) -> (f32, f32) {
-    let padding_left = match padding.left {
-        Val::Px(p) => p,
-        _ => 10.0,
-    };
-    let padding_right = match padding.right {
-        Val::Px(p) => p,
-        _ => 10.0,
-    };
-    let padding_top = match padding.top {
-        Val::Px(p) => p,
-        _ => 10.0,
-    };
-    let padding_bottom = match padding.bottom {
-        Val::Px(p) => p,
-        _ => 10.0,
-    };
-
-    let final_x = x.clamp(padding_left, window_width - padding_right);
-    let final_y = y.clamp(padding_top, window_height - padding_bottom);
-
-    (final_x, final_y)
-}
-
-/// System to integrate hover card content with the portal system
-pub fn hover_card_portal_system(
+/// System making the trigger of a hover card the [`PortalOwner`] of its
+/// content, so the portaled card is despawned along with the trigger.
+pub fn link_hover_card_owners(
     mut commands: Commands,
-    content_query: Query<Entity, (With<HoverCardContent>, Added<HoverCardContentMarker>)>,
-    ui_root_query: Query<Entity, With<UIRoot>>,
+    content_query: Query<(Entity, &HoverCardContent), Without<PortalOwner>>,
+    trigger_query: Query<(Entity, &HoverCardTrigger)>,
 ) {
-    for content_entity in content_query.iter() {
-        // Find or create a UI root for portals
-        if let Ok(_ui_root_entity) = ui_root_query.single() {
-            // Move the hover card content to the portal layer
-            commands.entity(content_entity).insert(Portal {
-                container: Some("HoverCard_UIRoot".to_string()),
-            });
-        } else {
-            // Create a UI root if none exists
-            let _ui_root = commands
-                .spawn((
-                    Name::new("HoverCard_UIRoot"),
-                    UIRoot {
-                        name: "HoverCard_UIRoot".to_string(),
-                    },
-                    Node {
-                        position_type: PositionType::Absolute,
-                        width: Val::Percent(100.0),
-                        height: Val::Percent(100.0),
-                        ..default()
-                    },
-                    ZIndex(999), // Below hover cards but above normal UI
-                ))
-                .id();
-
-            commands.entity(content_entity).insert(Portal {
-                container: Some("HoverCard_UIRoot".to_string()),
-            });
+    for (content_entity, content) in content_query.iter() {
+        if let Some((trigger_entity, _)) = trigger_query
+            .iter()
+            .find(|(_, trigger)| trigger.hover_card == content.hover_card)
+        {
+            commands
+                .entity(content_entity)
+                .insert(PortalOwner(trigger_entity));
         }
     }
 }
 
 /// System to handle keyboard navigation for hover cards
 pub fn hover_card_keyboard_system(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut dismiss_events: EventReader<DismissEvent>,
     mut hover_card_query: Query<&mut HoverCard>,
     content_query: Query<(&HoverCardContent, &HoverCardKeyboardNavigable)>,
     trigger_query: Query<(&HoverCardTrigger, &Interaction), Changed<Interaction>>,
 ) {
-    // Handle Escape key to close hover cards
-    if keyboard_input.just_pressed(KeyCode::Escape) {
-        for mut hover_card in hover_card_query.iter_mut() {
+    // Escape closes the hover card when it is the topmost dismissable layer
+    for event in dismiss_events.read() {
+        if let Ok(mut hover_card) = hover_card_query.get_mut(event.layer) {
             if hover_card.state == HoverCardState::Open {
                 hover_card.state = HoverCardState::Closing;
             }
@@ -1016,15 +1027,33 @@ pub fn hover_card_keyboard_system(
 /// System to add smooth animations for hover card transitions
 pub fn hover_card_animation_system(
     time: Res<Time>,
+    motion: Res<UiMotion>,
+    radix_theme: Res<RadixTheme>,
     mut content_query: Query<
-        (&HoverCardContent, &mut Transform, &mut BackgroundColor),
+        (
+            &HoverCardContent,
+            &mut Transform,
+            &mut BackgroundColor,
+            Option<&PanelSurface>,
+        ),
         With<HoverCardContentMarker>,
     >,
     hover_card_query: Query<&HoverCard>,
 ) {
-    for (content, mut transform, mut bg_color) in content_query.iter_mut() {
+    for (content, mut transform, mut bg_color, panel) in content_query.iter_mut() {
+        // Fades end at the panel's own opacity so translucent panels stay translucent
+        let max_alpha = panel
+            .filter(|panel| panel.active)
+            .map(|panel| panel.background(radix_theme.panel_background).alpha())
+            .unwrap_or(1.0);
+
         if let Ok(hover_card) = hover_card_query.get(content.hover_card) {
-            match hover_card.state {
+            // Reduced motion shows opening cards as open right away
+            let state = match hover_card.state {
+                HoverCardState::Opening if motion.reduced => HoverCardState::Open,
+                state => state,
+            };
+            match state {
                 HoverCardState::Opening => {
                     // Animate scale and opacity on open
                     let scale = 0.95 + 0.05 * time.elapsed_secs().sin().abs();
@@ -1032,7 +1061,7 @@ pub fn hover_card_animation_system(
 
                     // Fade in effect
                     if let Color::Srgba(srgba) = bg_color.0 {
-                        let alpha = (time.elapsed_secs() * 4.0).min(1.0);
+                        let alpha = (time.elapsed_secs() * 4.0).min(max_alpha);
                         *bg_color = BackgroundColor(Color::srgba(
                             srgba.red,
                             srgba.green,
@@ -1045,8 +1074,12 @@ pub fn hover_card_animation_system(
                     // Stable state
                     transform.scale = Vec3::ONE;
                     if let Color::Srgba(srgba) = bg_color.0 {
-                        *bg_color =
-                            BackgroundColor(Color::srgba(srgba.red, srgba.green, srgba.blue, 1.0));
+                        *bg_color = BackgroundColor(Color::srgba(
+                            srgba.red,
+                            srgba.green,
+                            srgba.blue,
+                            max_alpha,
+                        ));
                     }
                 }
                 HoverCardState::Closing => {
@@ -1055,7 +1088,7 @@ pub fn hover_card_animation_system(
                     transform.scale = Vec3::splat(scale);
 
                     if let Color::Srgba(srgba) = bg_color.0 {
-                        let alpha = (1.0 - time.elapsed_secs() * 6.0).max(0.0);
+                        let alpha = (max_alpha - time.elapsed_secs() * 6.0).max(0.0);
                         *bg_color = BackgroundColor(Color::srgba(
                             srgba.red,
                             srgba.green,
@@ -1069,3 +1102,220 @@ pub fn hover_card_animation_system(
         }
     }
 }
+
+impl Namespace for HoverCardBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+impl Namespace for HoverCardTriggerBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+impl Namespace for HoverCardContentBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::SystemId;
+
+    #[derive(Resource)]
+    struct HoverCardSystems {
+        interaction: SystemId,
+        state: SystemId,
+    }
+
+    fn setup() -> (World, Entity, Entity, Entity) {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<HoverCardOpenEvent>>();
+        world.init_resource::<Events<HoverCardCloseEvent>>();
+        let hover_card = world.spawn(HoverCardBuilder::new("Profile").build()).id();
+        let trigger = world
+            .spawn((HoverCardTrigger::new(hover_card), Interaction::None))
+            .id();
+        let content = world
+            .spawn((
+                HoverCardContent::new(hover_card),
+                Interaction::None,
+                Visibility::Hidden,
+            ))
+            .id();
+        let systems = HoverCardSystems {
+            interaction: world.register_system(hover_card_interaction_system),
+            state: world.register_system(hover_card_state_system),
+        };
+        world.insert_resource(systems);
+        (world, hover_card, trigger, content)
+    }
+
+    /// Advances the time by `millis` and runs the hover card systems, returning
+    /// the number of open and close events sent.
+    fn step(world: &mut World, millis: u64) -> (usize, usize) {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(millis));
+        let systems = world.resource::<HoverCardSystems>();
+        let (interaction, state) = (systems.interaction, systems.state);
+        world.run_system(interaction).unwrap();
+        world.run_system(state).unwrap();
+        let opened = world
+            .resource_mut::<Events<HoverCardOpenEvent>>()
+            .drain()
+            .count();
+        let closed = world
+            .resource_mut::<Events<HoverCardCloseEvent>>()
+            .drain()
+            .count();
+        (opened, closed)
+    }
+
+    fn set_interaction(world: &mut World, entity: Entity, interaction: Interaction) {
+        *world.get_mut::<Interaction>(entity).unwrap() = interaction;
+    }
+
+    #[test]
+    fn test_follow_cursor_tracks_cursor_with_offset() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        let mut window = Window::default();
+        window.set_cursor_position(Some(Vec2::new(100.0, 200.0)));
+        let window = world.spawn(window).id();
+        let hover_card = world
+            .spawn(HoverCardBuilder::new("Marker").default_open(true).build())
+            .id();
+        let content = world
+            .spawn(
+                HoverCardContentBuilder::new("Marker", hover_card)
+                    .follow_cursor()
+                    .cursor_offset(Vec2::new(8.0, 12.0))
+                    .build(),
+            )
+            .id();
+
+        let position = |world: &mut World| {
+            world
+                .run_system_once(hover_card_positioning_system)
+                .unwrap();
+            let node = world.get::<Node>(content).unwrap();
+            (node.left, node.top)
+        };
+        assert_eq!(position(&mut world), (Val::Px(108.0), Val::Px(212.0)));
+
+        world
+            .get_mut::<Window>(window)
+            .unwrap()
+            .set_cursor_position(Some(Vec2::new(300.0, 50.0)));
+        assert_eq!(position(&mut world), (Val::Px(308.0), Val::Px(62.0)));
+
+        // Near the right edge the content is shifted back inside the window
+        let width = world.get::<Window>(window).unwrap().width();
+        world
+            .get_mut::<Window>(window)
+            .unwrap()
+            .set_cursor_position(Some(Vec2::new(width - 1.0, 50.0)));
+        let (left, _) = position(&mut world);
+        assert_eq!(left, Val::Px(width - 10.0));
+    }
+
+    #[test]
+    fn test_open_and_close_wait_for_delays() {
+        let (mut world, hover_card, trigger, content) = setup();
+
+        set_interaction(&mut world, trigger, Interaction::Hovered);
+        assert_eq!(step(&mut world, 0), (0, 0));
+        assert_eq!(step(&mut world, 600), (0, 0));
+        assert_eq!(step(&mut world, 100), (1, 0));
+        assert_eq!(
+            world.get::<HoverCard>(hover_card).unwrap().state,
+            HoverCardState::Open
+        );
+        assert_eq!(
+            world.get::<Visibility>(content),
+            Some(&Visibility::Inherited)
+        );
+
+        set_interaction(&mut world, trigger, Interaction::None);
+        assert_eq!(step(&mut world, 0), (0, 0));
+        assert_eq!(step(&mut world, 200), (0, 0));
+        assert_eq!(step(&mut world, 100), (0, 1));
+        assert_eq!(world.get::<Visibility>(content), Some(&Visibility::Hidden));
+    }
+
+    #[test]
+    fn test_moving_to_content_keeps_card_open() {
+        let (mut world, hover_card, trigger, content) = setup();
+        world
+            .entity_mut(hover_card)
+            .insert(HoverCardBuilder::new("Profile").default_open(true).build());
+
+        // The pointer crosses the gap between trigger and card within the close delay
+        set_interaction(&mut world, trigger, Interaction::Hovered);
+        step(&mut world, 0);
+        set_interaction(&mut world, trigger, Interaction::None);
+        assert_eq!(step(&mut world, 0), (0, 0));
+        assert_eq!(step(&mut world, 200), (0, 0));
+        set_interaction(&mut world, content, Interaction::Hovered);
+        assert_eq!(step(&mut world, 0), (0, 0));
+        assert_eq!(step(&mut world, 1000), (0, 0));
+        assert_eq!(
+            world.get::<HoverCard>(hover_card).unwrap().state,
+            HoverCardState::Open
+        );
+
+        set_interaction(&mut world, content, Interaction::None);
+        assert_eq!(step(&mut world, 0), (0, 0));
+        assert_eq!(step(&mut world, 300), (0, 1));
+    }
+
+    #[test]
+    fn test_custom_delays() {
+        let (mut world, hover_card, trigger, _) = setup();
+        world.entity_mut(hover_card).insert(
+            HoverCardBuilder::new("Profile")
+                .open_delay(Duration::from_millis(100))
+                .build(),
+        );
+
+        set_interaction(&mut world, trigger, Interaction::Hovered);
+        step(&mut world, 0);
+        assert_eq!(step(&mut world, 100), (1, 0));
+    }
+
+    #[test]
+    fn test_despawning_trigger_despawns_portaled_card() {
+        let mut app = crate::test_support::test_app();
+        let world = app.world_mut();
+        let hover_card = world.spawn(HoverCardBuilder::new("Profile").build()).id();
+        let trigger = world
+            .spawn(HoverCardTriggerBuilder::new("Profile", hover_card).build())
+            .id();
+        let content = world
+            .spawn(HoverCardContentBuilder::new("Profile", hover_card).build())
+            .id();
+        app.update();
+
+        let root = app
+            .world()
+            .resource::<PortalRoots>()
+            .get(PortalLayer::Overlay)
+            .unwrap();
+        assert_eq!(app.world().get::<ChildOf>(content).unwrap().parent(), root);
+        assert_eq!(
+            app.world().get::<PortalOwner>(content),
+            Some(&PortalOwner(trigger))
+        );
+
+        app.world_mut().despawn(trigger);
+        app.update();
+        assert!(app.world().get_entity(content).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+//! Hover backgrounds for list rows.
+//!
+//! Rows of settings or item lists often only need to light up under the
+//! pointer, without the press styling and events of a full button. Box and
+//! flex builders' `.hoverable_row()` sets the hover color of the built
+//! component, and only those rows get an [`Interaction`] and a
+//! [`HoverHighlight`] when spawned: while hovered or pressed the background
+//! turns to the palette's `bg_hover`, and leaving restores the background it
+//! had before.
+//!
+//! Disabled rows ([`set_disabled`](crate::components::set_disabled)) ignore
+//! the pointer and keep their background.
+
+use crate::components::disabled::DisabledInteraction;
+use bevy::{
+    ecs::{component::HookContext, world::DeferredWorld},
+    prelude::*,
+};
+use bevy_picking::prelude::Pickable;
+
+/// Background to show while the pointer is over a row.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct HoverHighlight {
+    pub hover: Color,
+    pub disabled: bool,
+    /// Background to restore on leave; set while highlighted
+    rest: Option<Color>,
+}
+
+impl HoverHighlight {
+    pub fn new(hover: Color) -> Self {
+        Self {
+            hover,
+            disabled: false,
+            rest: None,
+        }
+    }
+
+    /// Whether the hover background is shown.
+    pub fn is_highlighted(&self) -> bool {
+        self.rest.is_some()
+    }
+}
+
+impl DisabledInteraction for HoverHighlight {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+}
+
+/// Component of a node that can be built as a hoverable row.
+pub trait HoverableRow: Component {
+    /// Background while hovered, `None` unless built with `.hoverable_row()`
+    fn hover_color(&self) -> Option<Color>;
+}
+
+/// Hook making a new hoverable row pickable and giving it its
+/// [`HoverHighlight`]; other nodes are left alone.
+pub(crate) fn spawn_hover_highlight<C: HoverableRow>(
+    mut world: DeferredWorld,
+    context: HookContext,
+) {
+    let Some(hover) = world
+        .get::<C>(context.entity)
+        .and_then(HoverableRow::hover_color)
+    else {
+        return;
+    };
+    world.commands().entity(context.entity).insert((
+        HoverHighlight::new(hover),
+        Interaction::None,
+        Pickable::default(),
+    ));
+}
+
+/// System that shows the hover background of rows under the pointer and
+/// restores their background on leave.
+pub fn update_hover_highlights(
+    mut query: Query<
+        (&Interaction, &mut HoverHighlight, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+) {
+    for (interaction, mut highlight, mut background) in &mut query {
+        let hovered = matches!(interaction, Interaction::Hovered | Interaction::Pressed);
+        if hovered && !highlight.disabled {
+            if highlight.rest.is_none() {
+                highlight.rest = Some(background.0);
+            }
+            background.0 = highlight.hover;
+        } else if let Some(rest) = highlight.rest.take() {
+            background.0 = rest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::{box_component::BoxComponent, flex::FlexComponent, set_disabled},
+        test_support::{advance_frames, set_interaction, test_app},
+        theme::color::accent_palette,
+    };
+
+    fn background(app: &App, entity: Entity) -> Color {
+        app.world().get::<BackgroundColor>(entity).unwrap().0
+    }
+
+    #[test]
+    fn test_hover_sets_bg_hover_and_leave_restores() {
+        let mut app = test_app();
+        let row = app
+            .world_mut()
+            .spawn(FlexComponent::row("settings_row").hoverable_row().build())
+            .id();
+        advance_frames(&mut app, 2);
+        let rest = background(&app, row);
+
+        set_interaction(&mut app, row, Interaction::Hovered);
+        assert_eq!(background(&app, row), accent_palette().bg_hover);
+
+        set_interaction(&mut app, row, Interaction::None);
+        assert_eq!(background(&app, row), rest);
+    }
+
+    #[test]
+    fn test_box_row_becomes_interactive() {
+        let mut app = test_app();
+        let row = app
+            .world_mut()
+            .spawn(BoxComponent::new("row").hoverable_row().build())
+            .id();
+        advance_frames(&mut app, 2);
+
+        let world = app.world();
+        assert!(world.get::<HoverHighlight>(row).is_some());
+        assert_eq!(world.get::<Interaction>(row), Some(&Interaction::None));
+    }
+
+    #[test]
+    fn test_plain_nodes_get_no_highlight() {
+        let mut app = test_app();
+        let world = app.world_mut();
+        let boxed = world.spawn(BoxComponent::new("panel").build()).id();
+        let flex = world.spawn(FlexComponent::row("toolbar").build()).id();
+        advance_frames(&mut app, 1);
+
+        for node in [boxed, flex] {
+            assert!(app.world().get::<HoverHighlight>(node).is_none());
+            assert!(app.world().get::<Interaction>(node).is_none());
+        }
+    }
+
+    #[test]
+    fn test_disabled_row_keeps_background() {
+        let mut app = test_app();
+        let row = app
+            .world_mut()
+            .spawn(FlexComponent::row("row").hoverable_row().build())
+            .id();
+        advance_frames(&mut app, 2);
+        let rest = background(&app, row);
+
+        let world = app.world_mut();
+        set_disabled(&mut world.commands(), row, true);
+        world.flush();
+        advance_frames(&mut app, 1);
+
+        set_interaction(&mut app, row, Interaction::Hovered);
+        assert_eq!(background(&app, row), rest);
+        assert_eq!(
+            app.world().get::<Pickable>(row).map(|p| p.is_hoverable),
+            Some(false)
+        );
+    }
+}
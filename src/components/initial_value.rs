@@ -0,0 +1,229 @@
+//! Warnings for initial values that match no option.
+//!
+//! A radio group's default value, a select's selected value or the first
+//! tab of a tabs system name an option by its value. When no option has that
+//! value nothing looks selected (tabs fall back to the first tab), which is
+//! easy to miss. Once a component is spawned, its initial value is checked
+//! against its options; an unmatched value logs a warning naming the
+//! component and the value, and sends an [`UnmatchedInitialValueEvent`].
+//!
+//! Each component is checked once, in the frame after it spawned.
+
+use crate::components::{
+    radio::{RadioComponent, RadioGroupComponent},
+    select::SelectComponent,
+    tabs::{TabTrigger, TabsInitialValue},
+};
+use bevy::prelude::*;
+
+/// Sent once for a component whose initial value matches none of its
+/// options.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct UnmatchedInitialValueEvent {
+    pub entity: Entity,
+    /// Kind of component, e.g. `RadioGroup`
+    pub component: &'static str,
+    /// Name of the component, or its entity if it has none
+    pub name: String,
+    pub value: String,
+}
+
+impl UnmatchedInitialValueEvent {
+    /// Warning logged for the event.
+    pub fn message(&self) -> String {
+        format!(
+            "{} \"{}\": initial value \"{}\" matches none of its options",
+            self.component, self.name, self.value
+        )
+    }
+}
+
+/// Logs the warning of `event` and sends it.
+pub fn report_unmatched_initial_value(
+    events: &mut EventWriter<UnmatchedInitialValueEvent>,
+    event: UnmatchedInitialValueEvent,
+) {
+    warn!("{}", event.message());
+    events.write(event);
+}
+
+/// Name shown in the warning: the entity's [`Name`], or the entity itself.
+fn display_name(entity: Entity, name: Option<&Name>) -> String {
+    name.map(|name| name.as_str().to_string())
+        .unwrap_or_else(|| entity.to_string())
+}
+
+/// Checks the default value of new radio groups against the values of the
+/// radios below them.
+pub fn check_radio_group_initial_values(
+    groups: Query<(Entity, &RadioGroupComponent), Added<RadioGroupComponent>>,
+    children: Query<&Children>,
+    radios: Query<&RadioComponent>,
+    mut events: EventWriter<UnmatchedInitialValueEvent>,
+) {
+    for (entity, group) in &groups {
+        let initial = group
+            .default_value
+            .as_ref()
+            .or(group.selected_value.as_ref());
+        let Some(value) = initial else {
+            continue;
+        };
+        let matched = children
+            .iter_descendants(entity)
+            .filter_map(|child| radios.get(child).ok())
+            .any(|radio| radio.value == *value);
+        if !matched {
+            report_unmatched_initial_value(
+                &mut events,
+                UnmatchedInitialValueEvent {
+                    entity,
+                    component: "RadioGroup",
+                    name: group.name.clone(),
+                    value: value.clone(),
+                },
+            );
+        }
+    }
+}
+
+/// Checks the selected value of new selects against their options.
+pub fn check_select_initial_values(
+    selects: Query<(Entity, &SelectComponent, Option<&Name>), Added<SelectComponent>>,
+    mut events: EventWriter<UnmatchedInitialValueEvent>,
+) {
+    for (entity, select, name) in &selects {
+        let Some(value) = &select.selected_value else {
+            continue;
+        };
+        if !select.options.iter().any(|(option, _)| option == value) {
+            report_unmatched_initial_value(
+                &mut events,
+                UnmatchedInitialValueEvent {
+                    entity,
+                    component: "Select",
+                    name: display_name(entity, name),
+                    value: value.clone(),
+                },
+            );
+        }
+    }
+}
+
+/// Checks the value requested for new tabs against their triggers.
+pub fn check_tabs_initial_values(
+    mut commands: Commands,
+    tabs: Query<(Entity, &TabsInitialValue, Option<&Name>)>,
+    triggers: Query<&TabTrigger>,
+    mut events: EventWriter<UnmatchedInitialValueEvent>,
+) {
+    for (entity, TabsInitialValue(value), name) in &tabs {
+        let matched = triggers
+            .iter()
+            .any(|trigger| trigger.tabs == entity && trigger.value == *value);
+        if !matched {
+            report_unmatched_initial_value(
+                &mut events,
+                UnmatchedInitialValueEvent {
+                    entity,
+                    component: "Tabs",
+                    name: display_name(entity, name),
+                    value: value.clone(),
+                },
+            );
+        }
+        commands.entity(entity).remove::<TabsInitialValue>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::{
+            radio::{RadioBuilder, RadioGroupBuilder},
+            select::SelectBuilder,
+            tabs::TabsBuilder,
+        },
+        test_support::{advance_frames, drain_events, test_app},
+    };
+
+    fn spawn_radio_group(app: &mut App, default_value: &str) -> Entity {
+        let world = app.world_mut();
+        let group = world
+            .spawn(
+                RadioGroupBuilder::new("fruits")
+                    .default_value(default_value)
+                    .build(),
+            )
+            .id();
+        for value in ["apple", "pear"] {
+            let radio = world.spawn(RadioBuilder::new(value).build()).id();
+            world.entity_mut(group).add_child(radio);
+        }
+        group
+    }
+
+    #[test]
+    fn test_unmatched_radio_default_warns_once() {
+        let mut app = test_app();
+        let group = spawn_radio_group(&mut app, "kiwi");
+        advance_frames(&mut app, 2);
+
+        let events = drain_events::<UnmatchedInitialValueEvent>(&mut app);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].entity, group);
+        assert_eq!(
+            events[0].message(),
+            "RadioGroup \"fruits\": initial value \"kiwi\" matches none of its options"
+        );
+    }
+
+    #[test]
+    fn test_matched_radio_default_does_not_warn() {
+        let mut app = test_app();
+        spawn_radio_group(&mut app, "pear");
+        advance_frames(&mut app, 3);
+
+        assert!(drain_events::<UnmatchedInitialValueEvent>(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_unmatched_select_value_warns() {
+        let mut app = test_app();
+        app.world_mut().spawn(
+            SelectBuilder::new()
+                .option("apple", "Apple")
+                .selected_value("kiwi")
+                .build(),
+        );
+        advance_frames(&mut app, 2);
+
+        let events = drain_events::<UnmatchedInitialValueEvent>(&mut app);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].component, "Select");
+    }
+
+    #[test]
+    fn test_unmatched_tabs_value_warns_once() {
+        let mut app = test_app();
+        let root = {
+            let world = app.world_mut();
+            let mut commands = world.commands();
+            let panel = commands.spawn(Node::default()).id();
+            let root = TabsBuilder::new()
+                .triggers(vec!["Account"])
+                .contents(vec![panel])
+                .value("Billing")
+                .build(&mut commands);
+            world.flush();
+            root
+        };
+        advance_frames(&mut app, 2);
+
+        let events = drain_events::<UnmatchedInitialValueEvent>(&mut app);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].entity, root);
+        assert!(app.world().get::<TabsInitialValue>(root).is_none());
+    }
+}
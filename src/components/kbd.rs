@@ -0,0 +1,278 @@
+#[cfg(feature = "icons-keyboard-mouse")]
+use crate::assets::icons::controllers::keyboard_mouse;
+use crate::{
+    components::text::Text,
+    theme::{
+        color::{theme, TextColor, UiColorPalette},
+        typography::{FontFamily, TextSize},
+    },
+    utilities::naming::Namespace,
+};
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
+
+/// Keyboard shortcut display: one or more keycap-styled chips, e.g. `Ctrl + K`.
+#[derive(Component, Debug, Clone)]
+pub struct KbdComponent {
+    pub keys: Vec<String>,
+    pub size: KbdSize,
+    /// Text placed between two keys of a combination
+    pub separator: String,
+    /// Render named keys with their keyboard_mouse icon glyph when one exists
+    pub use_glyphs: bool,
+    pub color: UiColorPalette,
+}
+
+impl Default for KbdComponent {
+    fn default() -> Self {
+        Self {
+            keys: Vec::new(),
+            size: KbdSize::Size2,
+            separator: "+".to_string(),
+            use_glyphs: false,
+            color: theme().gray,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KbdSize {
+    /// Extra small text, tight padding
+    Size1,
+    /// Small text, default padding
+    #[default]
+    Size2,
+    /// Base text, roomy padding
+    Size3,
+}
+
+impl KbdSize {
+    pub fn text_size(self) -> TextSize {
+        match self {
+            KbdSize::Size1 => TextSize::Xs,
+            KbdSize::Size2 => TextSize::Sm,
+            KbdSize::Size3 => TextSize::Base,
+        }
+    }
+
+    pub fn padding(self) -> (f32, f32) {
+        match self {
+            KbdSize::Size1 => (4.0, 1.0),
+            KbdSize::Size2 => (6.0, 2.0),
+            KbdSize::Size3 => (8.0, 3.0),
+        }
+    }
+
+    pub fn min_width(self) -> f32 {
+        match self {
+            KbdSize::Size1 => 16.0,
+            KbdSize::Size2 => 20.0,
+            KbdSize::Size3 => 24.0,
+        }
+    }
+}
+
+/// Marker for a single keycap chip inside a [`KbdComponent`].
+#[derive(Component, Debug)]
+pub struct KbdKey;
+
+impl KbdComponent {
+    /// A single key.
+    pub fn new(key: impl Into<String>) -> KbdBuilder {
+        KbdBuilder::new(vec![key.into()])
+    }
+
+    /// A key combination such as `&["Ctrl", "K"]`.
+    pub fn combo(keys: &[&str]) -> KbdBuilder {
+        KbdBuilder::new(keys.iter().map(|key| key.to_string()).collect())
+    }
+}
+
+pub struct KbdBuilder {
+    name: String,
+    kbd: KbdComponent,
+}
+
+impl KbdBuilder {
+    pub fn new(keys: Vec<String>) -> Self {
+        Self {
+            name: format!("{}_Kbd", keys.join("+")),
+            kbd: KbdComponent {
+                keys,
+                ..default()
+            },
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn size(mut self, size: KbdSize) -> Self {
+        self.kbd.size = size;
+        self
+    }
+
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.kbd.separator = separator.into();
+        self
+    }
+
+    /// Uses keyboard_mouse icon glyphs (e.g. `KeyboardArrowUp`) for named keys.
+    pub fn glyphs(mut self) -> Self {
+        self.kbd.use_glyphs = true;
+        self
+    }
+
+    pub fn color(mut self, color: UiColorPalette) -> Self {
+        self.kbd.color = color;
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let kbd = self.kbd.clone();
+
+        (
+            Name::new(self.name),
+            self.kbd,
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(4.0),
+                ..default()
+            },
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                let text_color = TextColor::Custom(kbd.color.text_contrast);
+                let separator_color = TextColor::Custom(kbd.color.text);
+
+                for (index, key) in kbd.keys.iter().enumerate() {
+                    if index > 0 {
+                        parent.spawn(
+                            Text::new(kbd.separator.clone())
+                                .family(FontFamily::Mono)
+                                .size(kbd.size.text_size())
+                                .color(separator_color)
+                                .build(),
+                        );
+                    }
+
+                    let glyph = kbd
+                        .use_glyphs
+                        .then(|| keyboard_glyph(key))
+                        .flatten();
+
+                    match glyph {
+                        // Glyphs are drawn as keycaps already, no chip needed
+                        Some(glyph) => {
+                            parent.spawn((
+                                KbdKey,
+                                Text::new(glyph)
+                                    .family(FontFamily::KeyboardMouse)
+                                    .size(kbd.size.text_size())
+                                    .color(text_color)
+                                    .build(),
+                            ));
+                        }
+                        None => {
+                            let (pad_x, pad_y) = kbd.size.padding();
+                            parent
+                                .spawn((
+                                    KbdKey,
+                                    Node {
+                                        min_width: Val::Px(kbd.size.min_width()),
+                                        padding: UiRect::axes(Val::Px(pad_x), Val::Px(pad_y)),
+                                        border: UiRect {
+                                            left: Val::Px(1.0),
+                                            right: Val::Px(1.0),
+                                            top: Val::Px(1.0),
+                                            // Thicker bottom edge gives the keycap look
+                                            bottom: Val::Px(2.0),
+                                        },
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },
+                                    BackgroundColor(kbd.color.bg_subtle),
+                                    BorderColor(kbd.color.border),
+                                    BorderRadius::all(Val::Px(4.0)),
+                                ))
+                                .with_children(|chip| {
+                                    chip.spawn(
+                                        Text::new(key.clone())
+                                            .family(FontFamily::Mono)
+                                            .size(kbd.size.text_size())
+                                            .color(text_color)
+                                            .build(),
+                                    );
+                                });
+                        }
+                    }
+                }
+            })),
+        )
+    }
+}
+
+/// Maps a key name (case-insensitive, common aliases accepted) to its
+/// keyboard_mouse icon glyph.
+#[cfg(feature = "icons-keyboard-mouse")]
+pub fn keyboard_glyph(name: &str) -> Option<&'static str> {
+    let glyph = match name.to_lowercase().as_str() {
+        "up" | "arrowup" => keyboard_mouse::KeyboardArrowUp,
+        "down" | "arrowdown" => keyboard_mouse::KeyboardArrowDown,
+        "left" | "arrowleft" => keyboard_mouse::KeyboardArrowLeft,
+        "right" | "arrowright" => keyboard_mouse::KeyboardArrowRight,
+        "enter" => keyboard_mouse::KeyboardEnter,
+        "return" => keyboard_mouse::KeyboardReturn,
+        "tab" => keyboard_mouse::KeyboardTab,
+        "shift" => keyboard_mouse::KeyboardShift,
+        "ctrl" | "control" => keyboard_mouse::KeyboardCtrl,
+        "alt" => keyboard_mouse::KeyboardAlt,
+        "option" => keyboard_mouse::KeyboardOption,
+        "cmd" | "command" => keyboard_mouse::KeyboardCommand,
+        "win" | "super" => keyboard_mouse::KeyboardWin,
+        "fn" => keyboard_mouse::KeyboardFunction,
+        "esc" | "escape" => keyboard_mouse::KeyboardEscape,
+        "space" => keyboard_mouse::KeyboardSpace,
+        "backspace" => keyboard_mouse::KeyboardBackspace,
+        "del" | "delete" => keyboard_mouse::KeyboardDelete,
+        "ins" | "insert" => keyboard_mouse::KeyboardInsert,
+        "home" => keyboard_mouse::KeyboardHome,
+        "end" => keyboard_mouse::KeyboardEnd,
+        "pageup" | "pgup" => keyboard_mouse::KeyboardPageUp,
+        "pagedown" | "pgdn" => keyboard_mouse::KeyboardPageDown,
+        "capslock" | "caps" => keyboard_mouse::KeyboardCapslock,
+        "numlock" => keyboard_mouse::KeyboardNumlock,
+        "printscreen" | "prtsc" => keyboard_mouse::KeyboardPrintscreen,
+        "f1" => keyboard_mouse::KeyboardF1,
+        "f2" => keyboard_mouse::KeyboardF2,
+        "f3" => keyboard_mouse::KeyboardF3,
+        "f4" => keyboard_mouse::KeyboardF4,
+        "f5" => keyboard_mouse::KeyboardF5,
+        "f6" => keyboard_mouse::KeyboardF6,
+        "f7" => keyboard_mouse::KeyboardF7,
+        "f8" => keyboard_mouse::KeyboardF8,
+        "f9" => keyboard_mouse::KeyboardF9,
+        "f10" => keyboard_mouse::KeyboardF10,
+        "f11" => keyboard_mouse::KeyboardF11,
+        "f12" => keyboard_mouse::KeyboardF12,
+        _ => return None,
+    };
+
+    Some(glyph)
+}
+
+/// Without the `icons-keyboard-mouse` feature there are no glyphs, so keys
+/// are always drawn as text chips.
+#[cfg(not(feature = "icons-keyboard-mouse"))]
+pub fn keyboard_glyph(_name: &str) -> Option<&'static str> {
+    None
+}
+
+pub type Kbd = KbdComponent;
+
+impl Namespace for KbdBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
@@ -0,0 +1,296 @@
+//! Inline text link.
+//!
+//! A link is accent-colored text that is underlined while hovered. Clicking
+//! it, or pressing Enter while it has keyboard focus, emits a
+//! [`LinkActivateEvent`] with its href; opening the target is up to the app.
+//! Activated hrefs are remembered in [`VisitedLinks`], and links to visited
+//! hrefs are drawn in a muted tone.
+//!
+//! ```rust
+//! use ui::components::Link;
+//!
+//! commands.spawn(
+//!     Link::new("Bevy website")
+//!         .href("https://bevyengine.org")
+//!         .external()
+//!         .build(),
+//! );
+//! ```
+
+use std::collections::HashSet;
+
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
+use bevy_picking::prelude::Pickable;
+
+use crate::{
+    assets::icons::interface::ExternalLink,
+    components::{
+        focus::{Focusable, UiFocus},
+        text::{Text, TextBuilder},
+    },
+    theme::{
+        color::{accent_palette, theme, TextColor as TextColorEnum},
+        typography::{FontFamily, TextSize, TextWeight},
+    },
+};
+
+/// Thickness of the hover underline
+pub const LINK_UNDERLINE_WIDTH: f32 = 1.0;
+
+/// A link; its children are the label and the optional external glyph.
+#[derive(Component, Debug, Clone, Default)]
+pub struct LinkComponent {
+    pub href: String,
+    /// Whether the link leaves the app, marked with an external-link glyph
+    pub external: bool,
+    /// Whether a press started on the link, so releasing it activates
+    pressed: bool,
+}
+
+/// Event emitted when a link is clicked or activated with Enter.
+#[derive(Event, Debug, Clone)]
+pub struct LinkActivateEvent {
+    pub link_entity: Entity,
+    pub href: String,
+}
+
+/// Hrefs of links activated so far.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct VisitedLinks(pub HashSet<String>);
+
+impl VisitedLinks {
+    pub fn is_visited(&self, href: &str) -> bool {
+        self.0.contains(href)
+    }
+
+    pub fn visit(&mut self, href: impl Into<String>) {
+        self.0.insert(href.into());
+    }
+}
+
+/// Text color of a link: the accent color, muted once visited.
+pub fn link_text_color(visited: bool) -> Color {
+    if visited {
+        theme().gray.text
+    } else {
+        accent_palette().text
+    }
+}
+
+pub struct LinkBuilder {
+    label: String,
+    text: TextBuilder,
+    size: Option<TextSize>,
+    link: LinkComponent,
+}
+
+impl LinkBuilder {
+    pub fn new(label: impl Into<String>) -> Self {
+        let label = label.into();
+        Self {
+            text: Text::new(label.clone()),
+            label,
+            size: None,
+            link: LinkComponent::default(),
+        }
+    }
+
+    pub fn href(mut self, href: impl Into<String>) -> Self {
+        self.link.href = href.into();
+        self
+    }
+
+    /// Marks the link as leading out of the app, appending an external-link glyph
+    pub fn external(mut self) -> Self {
+        self.link.external = true;
+        self
+    }
+
+    pub fn size(mut self, size: TextSize) -> Self {
+        self.size = Some(size);
+        self.text = self.text.size(size);
+        self
+    }
+
+    pub fn weight(mut self, weight: TextWeight) -> Self {
+        self.text = self.text.weight(weight);
+        self
+    }
+
+    pub fn family(mut self, family: FontFamily) -> Self {
+        self.text = self.text.family(family);
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let color = TextColorEnum::Custom(link_text_color(false));
+        let text = self.text.color(color);
+        let icon = self.link.external.then(|| {
+            let icon = Text::new(ExternalLink)
+                .family(FontFamily::InterfaceFont)
+                .color(color);
+            match self.size {
+                Some(size) => icon.size(size),
+                None => icon,
+            }
+        });
+
+        (
+            Name::new(format!("{}_Link", self.label)),
+            self.link,
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(2.0),
+                // The underline, colored while hovered
+                border: UiRect::bottom(Val::Px(LINK_UNDERLINE_WIDTH)),
+                ..default()
+            },
+            BorderColor(Color::NONE),
+            Interaction::None,
+            Focusable::new(),
+            Pickable::default(),
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                parent.spawn((text.build(), Pickable::IGNORE));
+                if let Some(icon) = icon {
+                    parent.spawn((icon.build(), Pickable::IGNORE));
+                }
+            })),
+        )
+    }
+}
+
+/// Entry point for links.
+pub struct Link;
+
+impl Link {
+    pub fn new(label: impl Into<String>) -> LinkBuilder {
+        LinkBuilder::new(label)
+    }
+}
+
+fn activate_link(
+    entity: Entity,
+    link: &LinkComponent,
+    visited: &mut VisitedLinks,
+    events: &mut EventWriter<LinkActivateEvent>,
+) {
+    visited.visit(link.href.clone());
+    events.write(LinkActivateEvent {
+        link_entity: entity,
+        href: link.href.clone(),
+    });
+}
+
+/// System that activates links when a press on them is released.
+pub fn handle_link_interactions(
+    mut links: Query<(Entity, &Interaction, &mut LinkComponent), Changed<Interaction>>,
+    mut visited: ResMut<VisitedLinks>,
+    mut events: EventWriter<LinkActivateEvent>,
+) {
+    for (entity, interaction, mut link) in &mut links {
+        match interaction {
+            Interaction::Pressed => link.pressed = true,
+            Interaction::Hovered if link.pressed => {
+                link.pressed = false;
+                activate_link(entity, &link, &mut visited, &mut events);
+            }
+            _ => link.pressed = false,
+        }
+    }
+}
+
+/// System that activates the keyboard-focused link on Enter.
+pub fn activate_focused_link(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    focus: Res<UiFocus>,
+    links: Query<&LinkComponent>,
+    mut visited: ResMut<VisitedLinks>,
+    mut events: EventWriter<LinkActivateEvent>,
+) {
+    if !keyboard.just_pressed(KeyCode::Enter) {
+        return;
+    }
+    let Some(focused) = focus.focused else {
+        return;
+    };
+    if let Ok(link) = links.get(focused) {
+        activate_link(focused, link, &mut visited, &mut events);
+    }
+}
+
+/// System that colors links for their visited state and underlines hovered
+/// ones.
+pub fn update_link_styles(
+    visited: Res<VisitedLinks>,
+    mut links: Query<(
+        &LinkComponent,
+        Ref<Interaction>,
+        &Children,
+        &mut BorderColor,
+    )>,
+    mut text_colors: Query<&mut TextColor>,
+) {
+    for (link, interaction, children, mut underline) in &mut links {
+        if !interaction.is_changed() && !visited.is_changed() {
+            continue;
+        }
+
+        let color = link_text_color(visited.is_visited(&link.href));
+        for child in children.iter() {
+            if let Ok(mut text_color) = text_colors.get_mut(child) {
+                text_color.0 = color;
+            }
+        }
+        let hovered = matches!(*interaction, Interaction::Hovered | Interaction::Pressed);
+        underline.0 = if hovered { color } else { Color::NONE };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_click_activates_and_visits_link() {
+        let mut world = World::new();
+        world.init_resource::<VisitedLinks>();
+        world.init_resource::<Events<LinkActivateEvent>>();
+        let link = world
+            .spawn((
+                LinkComponent {
+                    href: "https://bevyengine.org".to_string(),
+                    ..default()
+                },
+                Interaction::None,
+            ))
+            .id();
+        let system = world.register_system(handle_link_interactions);
+
+        for interaction in [
+            Interaction::Hovered,
+            Interaction::Pressed,
+            Interaction::Hovered,
+        ] {
+            *world.get_mut::<Interaction>(link).unwrap() = interaction;
+            world.run_system(system).unwrap();
+        }
+
+        let events: Vec<LinkActivateEvent> = world
+            .resource_mut::<Events<LinkActivateEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].link_entity, link);
+        assert_eq!(events[0].href, "https://bevyengine.org");
+        assert!(world
+            .resource::<VisitedLinks>()
+            .is_visited("https://bevyengine.org"));
+    }
+
+    #[test]
+    fn test_visited_links_are_muted() {
+        assert_eq!(link_text_color(false), accent_palette().text);
+        assert_eq!(link_text_color(true), theme().gray.text);
+    }
+}
@@ -0,0 +1,848 @@
+//! Menubar Component for Forge UI
+//!
+//! A horizontal row of menu triggers, like the menu bar at the top of a
+//! desktop application. Each trigger opens a dropdown of [`MenubarItem`]s;
+//! selecting one emits [`MenubarSelectEvent`].
+//!
+//! ## Interaction model
+//!
+//! At most one menu of a menubar is open at a time:
+//!
+//! - Clicking a trigger opens its menu, clicking it again closes it. While a
+//!   menu is open, hovering another trigger opens that trigger's menu instead.
+//! - Tapping the left Alt key on its own focuses the first trigger of the
+//!   menubar, or gives the focus up when a trigger already has it. Alt used
+//!   in a shortcut, AltGr and Alt while typing in a text field or text area
+//!   leave the menubar alone.
+//! - While a trigger has keyboard focus, Left / Right move the focus to the
+//!   previous / next trigger, wrapping around. If a menu is open, the menu of
+//!   the newly focused trigger opens in its place, so the menus can be browsed
+//!   sideways.
+//! - Down, Enter or Space open the focused trigger's menu and highlight its
+//!   first enabled item.
+//! - In an open menu, Up / Down move the highlight over the enabled items,
+//!   Enter selects the highlighted item and Escape closes the menu, keeping the
+//!   focus on its trigger. An open menubar is a layer of the
+//!   [`DismissStack`](crate::components::dismiss::DismissStack), so Escape
+//!   only reaches it when no overlay opened above it.
+//! - Selecting an item, by click or with Enter, closes the menu. Pressing the
+//!   pointer outside the menubar closes it as well.
+//!
+//! ```rust
+//! let menubar = MenubarBuilder::new()
+//!     .menu(
+//!         "File",
+//!         vec![
+//!             MenubarItem::new("new", "New").shortcut("Ctrl+N"),
+//!             MenubarItem::new("open", "Open…").shortcut("Ctrl+O"),
+//!             MenubarItem::separator(),
+//!             MenubarItem::new("quit", "Quit"),
+//!         ],
+//!     )
+//!     .menu("Edit", vec![MenubarItem::new("undo", "Undo").disabled(true)])
+//!     .build(&mut commands);
+//! ```
+
+use bevy::prelude::*;
+use bevy_picking::{
+    hover::HoverMap,
+    prelude::{Click, Over, Pickable, Pointer},
+};
+
+use crate::{
+    components::{
+        button::{ButtonBuilder, ButtonClickEvent, ButtonVariant},
+        dismiss::{DismissEvent, Dismissable},
+        focus::{FocusChangeEvent, UiFocus},
+        text::Text as UiText,
+        text_area::TextAreaComponent,
+        text_field::TextFieldComponent,
+    },
+    theme::color::{theme, TextColor as TextColorEnum},
+};
+
+/// Minimum width of an open menu
+pub const MENUBAR_MENU_MIN_WIDTH: f32 = 200.0;
+
+/// One entry of a menubar menu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MenubarItem {
+    pub value: String,
+    pub label: String,
+    /// Shortcut hint shown right-aligned, e.g. "Ctrl+S"; display only
+    pub shortcut: Option<String>,
+    pub disabled: bool,
+    /// Draws a divider line instead of an item
+    pub separator: bool,
+}
+
+impl MenubarItem {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            shortcut: None,
+            disabled: false,
+            separator: false,
+        }
+    }
+
+    /// A divider between groups of items.
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Self::new("", "")
+        }
+    }
+
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Whether the item can be highlighted and selected.
+    pub fn is_selectable(&self) -> bool {
+        !self.disabled && !self.separator
+    }
+}
+
+/// Root of a menubar, tracking its open menu.
+#[derive(Component, Debug, Default)]
+pub struct MenubarComponent {
+    /// Index of the open menu
+    pub open: Option<usize>,
+    /// Index of the highlighted item in the open menu
+    pub highlighted: Option<usize>,
+    /// Triggers of the menus, in order
+    pub triggers: Vec<Entity>,
+}
+
+impl MenubarComponent {
+    /// Opens `menu` (closing any other) or closes the menubar with `None`.
+    /// Returns whether the open menu changed.
+    pub fn set_open(&mut self, menu: Option<usize>) -> bool {
+        self.highlighted = None;
+        if self.open == menu {
+            return false;
+        }
+        self.open = menu;
+        true
+    }
+}
+
+impl Dismissable for MenubarComponent {
+    fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+}
+
+/// Trigger button of one menu.
+#[derive(Component, Debug)]
+pub struct MenubarTrigger {
+    pub menubar: Entity,
+    pub index: usize,
+    pub items: Vec<MenubarItem>,
+    // The dropdown panel of this menu.
+    pub content: Entity,
+}
+
+/// Dropdown panel of one menu, shown while its menu is open.
+#[derive(Component, Debug)]
+pub struct MenubarContent {
+    pub menubar: Entity,
+    pub menu: usize,
+}
+
+/// A selectable row in a menu's dropdown.
+#[derive(Component, Debug)]
+pub struct MenubarMenuItem {
+    pub menubar: Entity,
+    pub menu: usize,
+    pub index: usize,
+}
+
+/// Event emitted when a menu of a menubar opens or the menubar closes.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MenubarOpenEvent {
+    pub menubar: Entity,
+    /// Index of the opened menu, `None` when the menubar closed
+    pub menu: Option<usize>,
+}
+
+/// Event emitted when a menu item is selected.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct MenubarSelectEvent {
+    pub menubar: Entity,
+    /// Index of the menu the item belongs to
+    pub menu: usize,
+    pub value: String,
+}
+
+/// Index of the next selectable item after `from` in `step` direction,
+/// wrapping around; without `from`, the first (or last) selectable item.
+pub fn next_menu_item(items: &[MenubarItem], from: Option<usize>, step: isize) -> Option<usize> {
+    let len = items.len() as isize;
+    if len == 0 {
+        return None;
+    }
+    let start = match from {
+        Some(from) => from as isize,
+        None if step > 0 => -1,
+        None => len,
+    };
+    (1..=len)
+        .map(|offset| (start + offset * step.signum()).rem_euclid(len) as usize)
+        .find(|index| items[*index].is_selectable())
+}
+
+#[derive(Default)]
+pub struct MenubarBuilder {
+    menus: Vec<(String, Vec<MenubarItem>)>,
+}
+
+impl MenubarBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a menu with trigger `label` and dropdown `items`.
+    pub fn menu(mut self, label: impl Into<String>, items: Vec<MenubarItem>) -> Self {
+        self.menus.push((label.into(), items));
+        self
+    }
+
+    pub fn build(self, commands: &mut Commands) -> Entity {
+        let root = commands.spawn_empty().id();
+        let palette = theme().gray;
+
+        let mut triggers = Vec::new();
+        let mut menus = Vec::new();
+        for (index, (label, items)) in self.menus.into_iter().enumerate() {
+            let content = commands
+                .spawn((
+                    Name::new(format!("Menubar Menu {label}")),
+                    MenubarContent {
+                        menubar: root,
+                        menu: index,
+                    },
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Percent(100.0),
+                        left: Val::Px(0.0),
+                        min_width: Val::Px(MENUBAR_MENU_MIN_WIDTH),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(4.0)),
+                        margin: UiRect::top(Val::Px(4.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        display: Display::None,
+                        ..default()
+                    },
+                    BackgroundColor(palette.surface),
+                    BorderColor(palette.border),
+                    BorderRadius::all(Val::Px(6.0)),
+                    GlobalZIndex(1),
+                ))
+                .id();
+
+            let rows: Vec<Entity> = items
+                .iter()
+                .enumerate()
+                .map(|(item_index, item)| spawn_menu_item(commands, root, index, item_index, item))
+                .collect();
+            commands.entity(content).add_children(&rows);
+
+            let trigger = commands
+                .spawn((
+                    ButtonBuilder::new(format!("Menubar_{label}"))
+                        .variant(ButtonVariant::Ghost)
+                        .text(&label)
+                        .build(),
+                    MenubarTrigger {
+                        menubar: root,
+                        index,
+                        items,
+                        content,
+                    },
+                ))
+                .observe(on_menubar_trigger_hover)
+                .id();
+            triggers.push(trigger);
+
+            // The dropdown hangs below its trigger
+            let menu = commands
+                .spawn(Node {
+                    position_type: PositionType::Relative,
+                    ..default()
+                })
+                .add_children(&[trigger, content])
+                .id();
+            menus.push(menu);
+        }
+
+        commands
+            .entity(root)
+            .insert((
+                Name::new("Menubar"),
+                MenubarComponent {
+                    triggers,
+                    ..default()
+                },
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(2.0),
+                    padding: UiRect::all(Val::Px(4.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(palette.bg_subtle),
+                BorderColor(palette.line),
+                BorderRadius::all(Val::Px(6.0)),
+            ))
+            .add_children(&menus);
+        root
+    }
+}
+
+/// Spawns the row (or divider) for `item` of menu `menu`.
+fn spawn_menu_item(
+    commands: &mut Commands,
+    menubar: Entity,
+    menu: usize,
+    index: usize,
+    item: &MenubarItem,
+) -> Entity {
+    let palette = theme().gray;
+    if item.separator {
+        return commands
+            .spawn((
+                Node {
+                    height: Val::Px(1.0),
+                    margin: UiRect::axes(Val::Px(-4.0), Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(palette.line),
+            ))
+            .id();
+    }
+
+    let color = if item.disabled {
+        TextColorEnum::Muted
+    } else {
+        TextColorEnum::Default
+    };
+    commands
+        .spawn((
+            MenubarMenuItem {
+                menubar,
+                menu,
+                index,
+            },
+            Node {
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(24.0),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+            BorderRadius::all(Val::Px(4.0)),
+            Pickable::default(),
+        ))
+        .observe(on_menubar_item_hover)
+        .observe(on_menubar_item_click)
+        .with_children(|row| {
+            row.spawn(UiText::body(&item.label).color(color).build());
+            if let Some(shortcut) = &item.shortcut {
+                row.spawn(
+                    UiText::caption(shortcut)
+                        .color(TextColorEnum::Muted)
+                        .build(),
+                );
+            }
+        })
+        .id()
+}
+
+/// While a menu is open, hovering another trigger opens its menu instead.
+fn on_menubar_trigger_hover(
+    trigger: Trigger<Pointer<Over>>,
+    triggers: Query<&MenubarTrigger>,
+    mut menubars: Query<&mut MenubarComponent>,
+    mut focus: ResMut<UiFocus>,
+    mut open_events: EventWriter<MenubarOpenEvent>,
+) {
+    let Ok(menu) = triggers.get(trigger.target()) else {
+        return;
+    };
+    let Ok(mut menubar) = menubars.get_mut(menu.menubar) else {
+        return;
+    };
+    if menubar.open.is_none_or(|open| open == menu.index) {
+        return;
+    }
+
+    menubar.set_open(Some(menu.index));
+    focus.focused = Some(trigger.target());
+    open_events.write(MenubarOpenEvent {
+        menubar: menu.menubar,
+        menu: Some(menu.index),
+    });
+}
+
+fn on_menubar_item_hover(
+    trigger: Trigger<Pointer<Over>>,
+    items: Query<&MenubarMenuItem>,
+    mut menubars: Query<&mut MenubarComponent>,
+) {
+    let Ok(item) = items.get(trigger.target()) else {
+        return;
+    };
+    if let Ok(mut menubar) = menubars.get_mut(item.menubar) {
+        if menubar.open == Some(item.menu) {
+            menubar.highlighted = Some(item.index);
+        }
+    }
+}
+
+fn on_menubar_item_click(
+    trigger: Trigger<Pointer<Click>>,
+    items: Query<&MenubarMenuItem>,
+    triggers: Query<&MenubarTrigger>,
+    mut menubars: Query<&mut MenubarComponent>,
+    mut open_events: EventWriter<MenubarOpenEvent>,
+    mut select_events: EventWriter<MenubarSelectEvent>,
+) {
+    let Ok(item) = items.get(trigger.target()) else {
+        return;
+    };
+    let Ok(mut menubar) = menubars.get_mut(item.menubar) else {
+        return;
+    };
+    let Some(entry) = menubar
+        .triggers
+        .get(item.menu)
+        .and_then(|entity| triggers.get(*entity).ok())
+        .and_then(|menu| menu.items.get(item.index))
+    else {
+        return;
+    };
+    if !entry.is_selectable() {
+        return;
+    }
+
+    select_events.write(MenubarSelectEvent {
+        menubar: item.menubar,
+        menu: item.menu,
+        value: entry.value.clone(),
+    });
+    menubar.set_open(None);
+    open_events.write(MenubarOpenEvent {
+        menubar: item.menubar,
+        menu: None,
+    });
+}
+
+/// Toggles the menu of a clicked trigger.
+pub fn handle_menubar_trigger_clicks(
+    mut button_events: EventReader<ButtonClickEvent>,
+    triggers: Query<&MenubarTrigger>,
+    mut menubars: Query<&mut MenubarComponent>,
+    mut open_events: EventWriter<MenubarOpenEvent>,
+) {
+    for event in button_events.read() {
+        let Ok(menu) = triggers.get(event.button_entity) else {
+            continue;
+        };
+        let Ok(mut menubar) = menubars.get_mut(menu.menubar) else {
+            continue;
+        };
+        let open = (menubar.open != Some(menu.index)).then_some(menu.index);
+        menubar.set_open(open);
+        open_events.write(MenubarOpenEvent {
+            menubar: menu.menubar,
+            menu: open,
+        });
+    }
+}
+
+/// Keyboard interaction of menubars, see the module docs.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn handle_menubar_keyboard(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<UiFocus>,
+    mut menubars: Query<(Entity, &mut MenubarComponent)>,
+    triggers: Query<&MenubarTrigger>,
+    text_inputs: Query<(), Or<(With<TextFieldComponent>, With<TextAreaComponent>)>>,
+    mut open_events: EventWriter<MenubarOpenEvent>,
+    mut select_events: EventWriter<MenubarSelectEvent>,
+    mut change_events: EventWriter<FocusChangeEvent>,
+    // Whether the left Alt key is held without any other key pressed since
+    mut alt_alone: Local<bool>,
+) {
+    let focused_trigger = focus.focused.and_then(|entity| triggers.get(entity).ok());
+
+    let other_key = keyboard
+        .get_just_pressed()
+        .any(|key| *key != KeyCode::AltLeft);
+    if keyboard.just_pressed(KeyCode::AltLeft) {
+        *alt_alone = !other_key;
+    } else if other_key {
+        *alt_alone = false;
+    }
+    let alt_tapped = keyboard.just_released(KeyCode::AltLeft) && std::mem::take(&mut *alt_alone);
+    let typing = focus
+        .focused
+        .is_some_and(|entity| text_inputs.contains(entity));
+
+    if alt_tapped && !typing {
+        if let Some(menu) = focused_trigger {
+            if let Ok((entity, mut menubar)) = menubars.get_mut(menu.menubar) {
+                if menubar.set_open(None) {
+                    open_events.write(MenubarOpenEvent {
+                        menubar: entity,
+                        menu: None,
+                    });
+                }
+            }
+            focus.move_to(None, &mut change_events);
+        } else if let Some(first) = menubars
+            .iter()
+            .find_map(|(_, menubar)| menubar.triggers.first().copied())
+        {
+            focus.move_to(Some(first), &mut change_events);
+        }
+        return;
+    }
+
+    let Some(menu) = focused_trigger else {
+        return;
+    };
+    let Ok((entity, mut menubar)) = menubars.get_mut(menu.menubar) else {
+        return;
+    };
+
+    let step: isize = if keyboard.just_pressed(KeyCode::ArrowRight) {
+        1
+    } else if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        -1
+    } else {
+        0
+    };
+    if step != 0 && !menubar.triggers.is_empty() {
+        let len = menubar.triggers.len() as isize;
+        let next = (menu.index as isize + step).rem_euclid(len) as usize;
+        let next_trigger = menubar.triggers[next];
+        focus.move_to(Some(next_trigger), &mut change_events);
+
+        // Browsing sideways keeps the menubar open
+        if menubar.open.is_some() {
+            menubar.set_open(Some(next));
+            menubar.highlighted = triggers
+                .get(next_trigger)
+                .ok()
+                .and_then(|next_menu| next_menu_item(&next_menu.items, None, 1));
+            open_events.write(MenubarOpenEvent {
+                menubar: entity,
+                menu: Some(next),
+            });
+        }
+        return;
+    }
+
+    if menubar.open != Some(menu.index) {
+        if keyboard.any_just_pressed([KeyCode::ArrowDown, KeyCode::Enter, KeyCode::Space]) {
+            menubar.set_open(Some(menu.index));
+            menubar.highlighted = next_menu_item(&menu.items, None, 1);
+            open_events.write(MenubarOpenEvent {
+                menubar: entity,
+                menu: Some(menu.index),
+            });
+        }
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::ArrowDown) {
+        menubar.highlighted = next_menu_item(&menu.items, menubar.highlighted, 1);
+    } else if keyboard.just_pressed(KeyCode::ArrowUp) {
+        menubar.highlighted = next_menu_item(&menu.items, menubar.highlighted, -1);
+    } else if keyboard.just_pressed(KeyCode::Enter) {
+        let Some(item) = menubar
+            .highlighted
+            .and_then(|index| menu.items.get(index))
+            .filter(|item| item.is_selectable())
+        else {
+            return;
+        };
+        select_events.write(MenubarSelectEvent {
+            menubar: entity,
+            menu: menu.index,
+            value: item.value.clone(),
+        });
+        menubar.set_open(None);
+        open_events.write(MenubarOpenEvent {
+            menubar: entity,
+            menu: None,
+        });
+    }
+}
+
+/// Closes menubars dismissed with Escape.
+pub fn dismiss_menubars(
+    mut dismiss_events: EventReader<DismissEvent>,
+    mut menubars: Query<&mut MenubarComponent>,
+    mut open_events: EventWriter<MenubarOpenEvent>,
+) {
+    for event in dismiss_events.read() {
+        let Ok(mut menubar) = menubars.get_mut(event.layer) else {
+            continue;
+        };
+        if menubar.set_open(None) {
+            open_events.write(MenubarOpenEvent {
+                menubar: event.layer,
+                menu: None,
+            });
+        }
+    }
+}
+
+/// Closes open menubars when the pointer is pressed outside of them.
+pub fn close_menubars_on_outside_click(
+    mouse: Res<ButtonInput<MouseButton>>,
+    hover_map: Option<Res<HoverMap>>,
+    parents: Query<&ChildOf>,
+    mut menubars: Query<(Entity, &mut MenubarComponent)>,
+    mut open_events: EventWriter<MenubarOpenEvent>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    for (entity, mut menubar) in &mut menubars {
+        if menubar.open.is_none() {
+            continue;
+        }
+        let inside = hover_map.as_ref().is_some_and(|hover_map| {
+            hover_map.values().flat_map(|hits| hits.keys()).any(|hit| {
+                std::iter::once(*hit)
+                    .chain(parents.iter_ancestors(*hit))
+                    .any(|ancestor| ancestor == entity)
+            })
+        });
+        if !inside {
+            menubar.set_open(None);
+            open_events.write(MenubarOpenEvent {
+                menubar: entity,
+                menu: None,
+            });
+        }
+    }
+}
+
+/// Shows the open menu of each menubar and paints its trigger and
+/// highlighted item.
+pub fn update_menubar_menus(
+    menubars: Query<&MenubarComponent, Changed<MenubarComponent>>,
+    mut contents: Query<(&MenubarContent, &mut Node)>,
+    mut backgrounds: Query<&mut BackgroundColor>,
+    items: Query<(Entity, &MenubarMenuItem)>,
+) {
+    let palette = theme().gray;
+    for (content, mut node) in &mut contents {
+        let Ok(menubar) = menubars.get(content.menubar) else {
+            continue;
+        };
+        let open = menubar.open == Some(content.menu);
+        let display = if open { Display::Flex } else { Display::None };
+        if node.display != display {
+            node.display = display;
+        }
+
+        if let Some(mut background) = menubar
+            .triggers
+            .get(content.menu)
+            .and_then(|trigger| backgrounds.get_mut(*trigger).ok())
+        {
+            background.0 = if open { palette.bg_active } else { Color::NONE };
+        }
+    }
+
+    for (entity, item) in &items {
+        let Ok(menubar) = menubars.get(item.menubar) else {
+            continue;
+        };
+        let highlighted =
+            menubar.open == Some(item.menu) && menubar.highlighted == Some(item.index);
+        if let Ok(mut background) = backgrounds.get_mut(entity) {
+            background.0 = if highlighted {
+                palette.bg_hover
+            } else {
+                Color::NONE
+            };
+        }
+    }
+}
+
+pub type Menubar = MenubarComponent;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::dismiss::{handle_escape, track_dismissable_layers, DismissStack};
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn items() -> Vec<MenubarItem> {
+        vec![
+            MenubarItem::new("a", "A").disabled(true),
+            MenubarItem::new("b", "B"),
+            MenubarItem::separator(),
+            MenubarItem::new("c", "C"),
+        ]
+    }
+
+    fn press(world: &mut World, key: KeyCode) {
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        keyboard.press(key);
+        world.insert_resource(keyboard);
+        world.run_system_once(handle_escape).unwrap();
+        world.run_system_once(dismiss_menubars).unwrap();
+        world.run_system_once(handle_menubar_keyboard).unwrap();
+        world
+            .run_system_once(track_dismissable_layers::<MenubarComponent>)
+            .unwrap();
+        world.run_system_once(update_menubar_menus).unwrap();
+    }
+
+    #[test]
+    fn test_next_menu_item_skips_disabled_and_separators() {
+        let items = items();
+        assert_eq!(next_menu_item(&items, None, 1), Some(1));
+        assert_eq!(next_menu_item(&items, Some(1), 1), Some(3));
+        assert_eq!(next_menu_item(&items, Some(3), 1), Some(1));
+        assert_eq!(next_menu_item(&items, None, -1), Some(3));
+        assert_eq!(next_menu_item(&[MenubarItem::separator()], None, 1), None);
+    }
+
+    #[test]
+    fn test_left_right_switches_open_menus() {
+        let mut world = World::new();
+        world.init_resource::<UiFocus>();
+        world.init_resource::<DismissStack>();
+        world.init_resource::<Events<DismissEvent>>();
+        world.init_resource::<Events<MenubarOpenEvent>>();
+        world.init_resource::<Events<MenubarSelectEvent>>();
+        world.init_resource::<Events<FocusChangeEvent>>();
+
+        let root = {
+            let mut commands = world.commands();
+            MenubarBuilder::new()
+                .menu("File", items())
+                .menu("Edit", items())
+                .menu("View", items())
+                .build(&mut commands)
+        };
+        world.flush();
+        let triggers = world
+            .get::<MenubarComponent>(root)
+            .unwrap()
+            .triggers
+            .clone();
+        let display = |world: &World, menu: usize| {
+            let content = world.get::<MenubarTrigger>(triggers[menu]).unwrap().content;
+            world.get::<Node>(content).unwrap().display
+        };
+
+        world.resource_mut::<UiFocus>().focused = Some(triggers[0]);
+        press(&mut world, KeyCode::ArrowDown);
+        let menubar = world.get::<MenubarComponent>(root).unwrap();
+        assert_eq!(menubar.open, Some(0));
+        assert_eq!(menubar.highlighted, Some(1));
+
+        press(&mut world, KeyCode::ArrowRight);
+        assert_eq!(world.get::<MenubarComponent>(root).unwrap().open, Some(1));
+        assert_eq!(world.resource::<UiFocus>().focused, Some(triggers[1]));
+        assert_eq!(display(&world, 0), Display::None);
+        assert_eq!(display(&world, 1), Display::Flex);
+
+        // Wraps around from the first menu to the last
+        press(&mut world, KeyCode::ArrowLeft);
+        press(&mut world, KeyCode::ArrowLeft);
+        assert_eq!(world.get::<MenubarComponent>(root).unwrap().open, Some(2));
+        assert_eq!(world.resource::<UiFocus>().focused, Some(triggers[2]));
+
+        press(&mut world, KeyCode::Escape);
+        assert_eq!(world.get::<MenubarComponent>(root).unwrap().open, None);
+        // Closed, Left / Right only move the focus
+        press(&mut world, KeyCode::ArrowRight);
+        assert_eq!(world.get::<MenubarComponent>(root).unwrap().open, None);
+        assert_eq!(world.resource::<UiFocus>().focused, Some(triggers[0]));
+    }
+
+    #[test]
+    fn test_only_a_lone_left_alt_tap_toggles_the_focus() {
+        let mut world = World::new();
+        world.init_resource::<UiFocus>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Events<MenubarOpenEvent>>();
+        world.init_resource::<Events<MenubarSelectEvent>>();
+        world.init_resource::<Events<FocusChangeEvent>>();
+
+        let root = {
+            let mut commands = world.commands();
+            MenubarBuilder::new()
+                .menu("File", items())
+                .build(&mut commands)
+        };
+        world.flush();
+        let first = world.get::<MenubarComponent>(root).unwrap().triggers[0];
+        let text_field = world.spawn(TextFieldComponent::default()).id();
+
+        // A registered system keeps its state from frame to frame
+        let system = world.register_system(handle_menubar_keyboard);
+        let frame = |world: &mut World, pressed: &[KeyCode], released: &[KeyCode]| {
+            let mut keyboard = world.resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.clear();
+            for key in pressed {
+                keyboard.press(*key);
+            }
+            for key in released {
+                keyboard.release(*key);
+            }
+            world.run_system(system).unwrap();
+        };
+        let focused = |world: &World| world.resource::<UiFocus>().focused;
+
+        // AltGr and Alt used in a shortcut
+        frame(&mut world, &[KeyCode::AltRight], &[]);
+        frame(&mut world, &[], &[KeyCode::AltRight]);
+        frame(&mut world, &[KeyCode::AltLeft], &[]);
+        frame(&mut world, &[KeyCode::KeyF], &[]);
+        frame(&mut world, &[], &[KeyCode::KeyF, KeyCode::AltLeft]);
+        assert_eq!(focused(&world), None);
+
+        frame(&mut world, &[KeyCode::AltLeft], &[]);
+        assert_eq!(focused(&world), None);
+        frame(&mut world, &[], &[KeyCode::AltLeft]);
+        assert_eq!(focused(&world), Some(first));
+        let events: Vec<FocusChangeEvent> = world
+            .resource_mut::<Events<FocusChangeEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].focused, Some(first));
+
+        frame(&mut world, &[KeyCode::AltLeft], &[]);
+        frame(&mut world, &[], &[KeyCode::AltLeft]);
+        assert_eq!(focused(&world), None);
+
+        // Typing keeps the focus in the text field
+        world.resource_mut::<UiFocus>().focused = Some(text_field);
+        frame(&mut world, &[KeyCode::AltLeft], &[]);
+        frame(&mut world, &[], &[KeyCode::AltLeft]);
+        assert_eq!(focused(&world), Some(text_field));
+    }
+}
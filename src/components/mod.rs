@@ -1,70 +1,178 @@
-use bevy::prelude::*;
+use crate::theme::breakpoints::update_active_breakpoint;
+use crate::theme::radix_theme::{tag_accent_palette_users, update_theme_tokens, RadixTheme};
+use crate::utilities::{
+    overlay,
+    portal::{self, PortalRoots},
+    side_label, transition,
+};
+use bevy::{input::InputSystem, prelude::*, ui::UiSystem};
 
+pub mod a11y;
+pub mod aspect_ratio;
 pub mod badge;
+pub mod binding;
 pub mod box_component;
 pub mod button;
+pub mod button_group;
+pub mod callout;
 pub mod card;
 pub mod checkbox;
+pub mod control_label;
+pub mod copy_button;
+pub mod disabled;
+pub mod dismiss;
+pub mod empty_state;
 pub mod flex;
+pub mod focus;
+pub mod gamepad_navigation;
 pub mod grid;
 pub mod heading;
 pub mod hover_card;
+pub mod hover_highlight;
+pub mod initial_value;
+pub mod kbd;
+pub mod link;
+pub mod menubar;
+pub mod number_input;
+pub mod pagination;
 pub mod progress;
 pub mod radio;
+pub mod scroll_area;
 pub mod section;
 pub mod select;
 pub mod separator;
 pub mod slider;
+pub mod sounds;
+pub mod stack;
 pub mod switch;
+pub mod table;
 pub mod tabs;
 pub mod text;
+pub mod text_area;
+pub mod text_field;
+pub mod text_fit;
+pub mod text_marquee;
+pub mod text_selection;
 pub mod toggle;
+pub mod toggle_group;
+pub mod validation;
+pub mod virtual_list;
 
+pub use a11y::*;
+pub use aspect_ratio::*;
 pub use badge::*;
+pub use binding::*;
 pub use box_component::*;
 pub use button::*;
+pub use button_group::*;
+pub use callout::*;
 pub use card::*;
 pub use checkbox::*;
+pub use control_label::*;
+pub use copy_button::*;
+pub use disabled::*;
+pub use dismiss::*;
+pub use empty_state::*;
 pub use flex::*;
+pub use focus::*;
+pub use gamepad_navigation::*;
 pub use grid::*;
 pub use heading::*;
 pub use hover_card::*;
+pub use hover_highlight::*;
+pub use initial_value::*;
+pub use kbd::*;
+pub use link::*;
+pub use menubar::*;
+pub use number_input::*;
+pub use pagination::*;
 pub use progress::*;
 pub use radio::*;
+pub use scroll_area::*;
 pub use section::*;
 pub use select::*;
 pub use separator::*;
 pub use slider::*;
+pub use sounds::*;
+pub use stack::*;
 pub use switch::*;
+pub use table::*;
 pub use tabs::*;
 pub use text::*;
+pub use text_area::*;
+pub use text_field::*;
+pub use text_fit::*;
+pub use text_marquee::*;
+pub use text_selection::*;
 pub use toggle::*;
+pub use toggle_group::*;
+pub use validation::*;
+pub use virtual_list::*;
 
 pub struct ComponentsPlugin;
 
 impl Plugin for ComponentsPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<button::ButtonClickEvent>()
+            .add_event::<callout::CalloutDismissEvent>()
             .add_event::<checkbox::CheckboxChangeEvent>()
+            .add_event::<copy_button::CopyEvent>()
+            .add_event::<dismiss::DismissEvent>()
+            .add_event::<focus::FocusChangeEvent>()
             .add_event::<hover_card::HoverCardOpenEvent>()
             .add_event::<hover_card::HoverCardCloseEvent>()
+            .add_event::<initial_value::UnmatchedInitialValueEvent>()
+            .add_event::<link::LinkActivateEvent>()
+            .add_event::<menubar::MenubarOpenEvent>()
+            .add_event::<menubar::MenubarSelectEvent>()
+            .add_event::<number_input::NumberInputChangeEvent>()
+            .add_event::<pagination::PageChangeEvent>()
             .add_event::<radio::RadioChangeEvent>()
             .add_event::<radio::RadioGroupValueChangeEvent>()
             .add_event::<select::SelectOpenEvent>()
             .add_event::<select::SelectChangeEvent>()
             .add_event::<slider::SliderValueChangeEvent>()
             .add_event::<slider::SliderValueCommitEvent>()
+            .add_event::<sounds::PlayUiSound>()
             .add_event::<switch::SwitchChangeEvent>()
+            .add_event::<table::TableSortEvent>()
+            .add_event::<tabs::TabAddEvent>()
+            .add_event::<tabs::TabCloseEvent>()
+            .add_event::<text_area::TextAreaChangeEvent>()
+            .add_event::<text_field::TextFieldChangeEvent>()
+            .add_event::<text_field::TextFieldSubmitEvent>()
+            .add_event::<text_selection::TextSelectEvent>()
             .add_event::<toggle::ToggleChangeEvent>()
+            .add_event::<toggle_group::ToggleGroupChangeEvent>()
+            .add_event::<validation::ValidationChangedEvent>()
+            .add_event::<virtual_list::VirtualListRangeEvent>()
+            // Reflection for runtime inspection, e.g. with bevy-inspector-egui
+            .register_type::<box_component::BoxComponent>()
+            .register_type::<box_component::BoxVariant>()
+            .register_type::<button::Button>()
+            .register_type::<button::ButtonVariant>()
+            .register_type::<button::ButtonSize>()
+            .register_type::<checkbox::CheckboxComponent>()
+            .register_type::<grid::GridComponent>()
+            .register_type::<grid::GridGap>()
+            .register_type::<grid::GridTrack>()
+            .register_type::<grid::GridTrackSize>()
+            .register_type::<radio::RadioComponent>()
+            .register_type::<radio::RadioVariant>()
             .add_systems(
                 Update,
                 (
                     button::setup_button_interactions,
                     button::setup_spinner_textures,
                     button::animate_loading_spinners,
+                    button::sync_button_loading,
                     text::apply_text_fonts,
+                    text::apply_text_decorations,
                     hover_card::hover_card_interaction_system,
-                    hover_card::hover_card_state_system,
+                    hover_card::hover_card_state_system
+                        .after(hover_card::hover_card_interaction_system),
+                    control_label::spawn_control_labels,
+                    hover_highlight::update_hover_highlights,
                     checkbox::handle_checkbox_interactions,
                     checkbox::spawn_checkmarks,
                     checkbox::update_checkmarks,
@@ -79,19 +187,259 @@ impl Plugin for ComponentsPlugin {
                     radio::update_radio_indicators,
                     radio::setup_radio_interactions,
                     radio::link_radios_to_groups,
+                    initial_value::check_radio_group_initial_values,
+                    initial_value::check_select_initial_values,
+                    initial_value::check_tabs_initial_values,
                 ),
             )
             .add_systems(
                 Update,
                 (
+                    binding::insert_value_bindings,
                     select::setup_select_interactions,
                     select::update_select_trigger_text,
+                    (
+                        select::handle_select_keyboard,
+                        select::handle_select_typeahead,
+                        select::update_select_option_highlights,
+                        select::scroll_to_highlighted_select_option,
+                    )
+                        .chain(),
                 ),
             )
+            .add_systems(
+                Update,
+                (
+                    aspect_ratio::fill_aspect_ratio_children,
+                    grid::place_grid_area_children,
+                    separator::resolve_separator_orientations,
+                    stack::stack_zstack_children,
+                    scroll_area::update_scroll_area_scrollbars,
+                    virtual_list::update_virtual_lists,
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    text::apply_responsive_text_sizes,
+                    grid::apply_responsive_columns,
+                )
+                    .after(update_active_breakpoint),
+            )
+            .add_systems(
+                Update,
+                (
+                    disabled::apply_disabled_interaction::<button::Button>,
+                    disabled::apply_disabled_interaction::<checkbox::CheckboxComponent>,
+                    disabled::apply_disabled_interaction::<radio::RadioComponent>,
+                    disabled::apply_disabled_interaction::<switch::SwitchComponent>,
+                    disabled::apply_disabled_interaction::<toggle::ToggleComponent>,
+                    disabled::apply_disabled_interaction::<select::SelectComponent>,
+                    disabled::apply_disabled_interaction::<hover_highlight::HoverHighlight>,
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    (
+                        validation::update_validation_states::<checkbox::CheckboxComponent>,
+                        validation::update_validation_states::<radio::RadioGroupComponent>,
+                        validation::update_validation_states::<switch::SwitchComponent>,
+                    ),
+                    (
+                        validation::style_validation_errors::<checkbox::CheckboxComponent>,
+                        validation::style_validation_errors::<radio::RadioGroupComponent>,
+                        validation::style_validation_errors::<switch::SwitchComponent>,
+                    ),
+                )
+                    .chain()
+                    // Error borders win over the interaction styling
+                    .after(checkbox::handle_checkbox_interactions)
+                    .after(radio::update_radio_groups)
+                    .after(switch::update_switch_styling),
+            )
+            .add_systems(
+                Update,
+                (
+                    tag_accent_palette_users::<button::Button>,
+                    tag_accent_palette_users::<badge::Badge>,
+                    tag_accent_palette_users::<checkbox::CheckboxComponent>,
+                    tag_accent_palette_users::<radio::RadioComponent>,
+                    tag_accent_palette_users::<radio::RadioGroupComponent>,
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    button::refresh_button_accent,
+                    badge::refresh_badge_accent,
+                    checkbox::refresh_checkbox_accent,
+                    radio::refresh_radio_accent,
+                )
+                    .after(update_theme_tokens)
+                    .run_if(resource_changed::<RadixTheme>),
+            )
+            .add_systems(
+                Update,
+                (
+                    number_input::setup_number_input_interactions,
+                    number_input::repeat_held_number_input_steps,
+                    number_input::handle_number_input_keyboard,
+                    number_input::update_number_input_display,
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    pagination::handle_pagination_clicks,
+                    pagination::update_pagination_items,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    table::handle_table_header_clicks,
+                    table::update_table_header_styles,
+                    table::update_table_body,
+                    table::update_table_row_colors,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    text_field::handle_text_field_focus,
+                    text_field::handle_text_field_keyboard,
+                    text_field::update_text_field_display,
+                    text_field::blink_text_field_caret,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    text_area::sync_text_area_layout,
+                    text_area::handle_text_area_focus,
+                    text_area::handle_text_area_keyboard,
+                    text_area::update_text_area_display,
+                    text_area::blink_text_area_caret,
+                )
+                    .chain(),
+            )
+            // After all styling systems, which write the transition targets
+            .add_systems(
+                PostUpdate,
+                (
+                    transition::animate_transitions::<BackgroundColor>,
+                    transition::animate_transitions::<BorderColor>,
+                    transition::animate_transitions::<Node>,
+                    overlay::animate_overlays,
+                )
+                    .before(UiSystem::Layout),
+            )
+            .init_resource::<sounds::UiSounds>()
+            .init_resource::<sounds::UiSoundSettings>()
+            .add_observer(sounds::play_hover_sound)
+            .add_systems(Update, sounds::play_ui_sounds)
+            .init_resource::<focus::UiFocus>()
+            .init_resource::<PortalRoots>()
+            .add_systems(Update, portal::despawn_orphaned_portal_content)
+            .init_resource::<dismiss::DismissStack>()
+            // Escape is routed before any Update system can see it
+            .add_systems(PreUpdate, dismiss::handle_escape.after(InputSystem))
+            .add_systems(
+                Update,
+                (
+                    dismiss::track_dismissable_layers::<hover_card::HoverCard>,
+                    dismiss::track_dismissable_layers::<menubar::MenubarComponent>,
+                    dismiss::track_dismissable_layers::<select::SelectComponent>,
+                    select::dismiss_select_dropdowns,
+                ),
+            )
+            .init_resource::<gamepad_navigation::GamepadUiConfig>()
+            .add_observer(focus::focus_on_pointer_press)
+            .add_systems(
+                Update,
+                (
+                    focus::clear_removed_focus,
+                    focus::handle_focus_navigation,
+                    gamepad_navigation::handle_gamepad_navigation,
+                    (
+                        text_field::follow_text_field_keyboard_focus,
+                        text_area::follow_text_area_keyboard_focus,
+                        number_input::follow_number_input_keyboard_focus,
+                        focus::update_focus_ring,
+                    ),
+                )
+                    .chain()
+                    // Inputs reacting to Tab see the key before focus moves on
+                    .after(text_field::handle_text_field_keyboard)
+                    .after(text_area::handle_text_area_keyboard)
+                    .after(number_input::handle_number_input_keyboard),
+            )
+            .add_systems(Update, callout::handle_callout_dismiss)
+            .add_systems(
+                Update,
+                (
+                    menubar::dismiss_menubars,
+                    menubar::handle_menubar_keyboard,
+                    menubar::handle_menubar_trigger_clicks,
+                    menubar::close_menubars_on_outside_click,
+                    menubar::update_menubar_menus,
+                )
+                    .chain(),
+            )
+            .init_non_send_resource::<copy_button::SystemClipboard>()
+            .add_systems(
+                Update,
+                (
+                    copy_button::handle_copy_button_clicks,
+                    copy_button::revert_copy_feedback,
+                    text_marquee::setup_text_marquees,
+                    text_marquee::animate_text_marquees,
+                    text_fit::fit_texts_to_containers.after(text::apply_responsive_text_sizes),
+                    (
+                        text_selection::handle_text_selection_drag,
+                        text_selection::copy_selected_text,
+                        text_selection::update_text_selection_highlights,
+                    )
+                        .chain(),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    a11y::sync_a11y_values::<checkbox::CheckboxComponent>,
+                    a11y::sync_a11y_values::<radio::RadioComponent>,
+                    a11y::sync_a11y_values::<switch::SwitchComponent>,
+                    a11y::sync_a11y_values::<slider::SliderComponent>,
+                    a11y::sync_a11y_values::<progress::ProgressComponent>,
+                    a11y::sync_tab_a11y_values,
+                    section::sync_section_landmarks,
+                    a11y::update_a11y_value_texts
+                        .after(a11y::sync_a11y_values::<slider::SliderComponent>)
+                        .after(a11y::sync_a11y_values::<progress::ProgressComponent>),
+                ),
+            )
+            .init_resource::<link::VisitedLinks>()
+            .add_systems(
+                Update,
+                (
+                    (link::handle_link_interactions, link::activate_focused_link),
+                    link::update_link_styles,
+                )
+                    .chain(),
+            )
             .add_systems(Update, (switch::setup_switch_interactions,))
             .add_systems(
                 Update,
-                (switch::spawn_switch_children, switch::update_switch_styling),
+                (
+                    switch::spawn_switch_children,
+                    switch::update_switch_styling,
+                    switch::update_switch_from_external,
+                    side_label::reserve_side_label_space,
+                ),
             )
             .add_systems(
                 Update,
@@ -101,25 +449,84 @@ impl Plugin for ComponentsPlugin {
                     toggle::update_toggle_styling,
                 ),
             )
+            .add_systems(
+                Update,
+                (
+                    toggle_group::style_toggle_group_items,
+                    button_group::style_button_group_items,
+                    toggle_group::handle_toggle_group_keyboard,
+                    toggle_group::update_toggle_groups,
+                ),
+            )
             .add_systems(
                 Update,
                 (
                     progress::setup_progress_components,
                     progress::animate_indeterminate_progress,
                     progress::update_progress_values,
+                    progress::update_circular_progress,
+                    progress::spin_circular_progress,
                     hover_card::hover_card_positioning_system,
                     hover_card::hover_card_portal_system,
+                    hover_card::link_hover_card_owners,
                     hover_card::hover_card_keyboard_system,
                     hover_card::hover_card_animation_system,
                     select::position_select_dropdowns,
                     select::handle_click_outside_select,
                     slider::handle_slider_drag,
                     slider::handle_track_click,
+                    slider::handle_slider_keyboard,
+                    slider::pace_slider_events,
                     slider::update_slider_visuals,
-                    tabs::handle_trigger_clicks,
-                    tabs::style_active_triggers,
                     // Icon interaction system removed - handled by individual icon systems
                 ),
+            )
+            .add_systems(
+                Update,
+                (
+                    tabs::sync_controlled_tabs,
+                    tabs::handle_tabs_keyboard,
+                    tabs::handle_tab_close_clicks,
+                    tabs::handle_tab_add_clicks,
+                    tabs::handle_trigger_clicks,
+                    tabs::style_active_triggers,
+                    tabs::show_tab_close_buttons,
+                    tabs::animate_tab_indicators,
+                    tabs::animate_tab_panels,
+                )
+                    .chain(),
             );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::any::TypeId;
+
+    #[test]
+    fn test_plugin_registers_reflected_types() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<AudioSource>()
+            .add_plugins(ComponentsPlugin);
+
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        for type_id in [
+            TypeId::of::<BoxComponent>(),
+            TypeId::of::<BoxVariant>(),
+            TypeId::of::<button::Button>(),
+            TypeId::of::<CheckboxComponent>(),
+            TypeId::of::<GridComponent>(),
+            TypeId::of::<GridGap>(),
+            TypeId::of::<GridTrackSize>(),
+            TypeId::of::<RadioComponent>(),
+            TypeId::of::<RadioVariant>(),
+        ] {
+            assert!(registry.contains(type_id));
+        }
+        assert!(registry
+            .get_type_data::<ReflectComponent>(TypeId::of::<GridComponent>())
+            .is_some());
+    }
+}
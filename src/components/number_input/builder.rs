@@ -0,0 +1,215 @@
+//! Builder for the NumberInput component.
+
+use super::core::{
+    NumberInputComponent, NumberInputField, NumberInputStepButton, NumberInputValueText,
+    StepDirection,
+};
+use crate::{
+    components::{
+        button::{ButtonBuilder, ButtonSize, ButtonVariant},
+        focus::Focusable,
+        text::Text,
+    },
+    theme::{
+        color::{TextColor, UiColorPalette},
+        layout::UiLayout,
+        typography::TextSize,
+    },
+    utilities::naming::Namespace,
+};
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
+use bevy_picking::prelude::Pickable;
+
+pub struct NumberInputBuilder {
+    name: String,
+    number_input: NumberInputComponent,
+    size: ButtonSize,
+    width: Val,
+}
+
+impl NumberInputComponent {
+    pub fn new(name: impl Into<String>) -> NumberInputBuilder {
+        NumberInputBuilder::new(name)
+    }
+}
+
+impl NumberInputBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: format!("{}_NumberInput", name.into()),
+            number_input: NumberInputComponent::default(),
+            size: ButtonSize::Default,
+            width: Val::Px(80.0),
+        }
+    }
+
+    /// Initial value, clamped and rounded when built.
+    pub fn value(mut self, value: f32) -> Self {
+        self.number_input.value = value;
+        self
+    }
+
+    pub fn min(mut self, min: f32) -> Self {
+        self.number_input.min = min;
+        self
+    }
+
+    pub fn max(mut self, max: f32) -> Self {
+        self.number_input.max = max;
+        self
+    }
+
+    pub fn step(mut self, step: f32) -> Self {
+        self.number_input.step = step.abs();
+        self
+    }
+
+    /// Number of decimal places, e.g. `precision(2)` for `1.25`.
+    pub fn precision(mut self, precision: u32) -> Self {
+        self.number_input.precision = precision;
+        self
+    }
+
+    /// Steps the value with the mouse wheel while hovering the field.
+    pub fn wheel_step(mut self) -> Self {
+        self.number_input.wheel_step = true;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn color(mut self, color: UiColorPalette) -> Self {
+        self.number_input.color = color;
+        self
+    }
+
+    /// Width of the value field.
+    pub fn width(mut self, width: Val) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.number_input.disabled = true;
+        self
+    }
+}
+
+impl NumberInputBuilder {
+    fn text_size(&self) -> TextSize {
+        match self.size {
+            ButtonSize::Small => TextSize::Sm,
+            ButtonSize::Default => TextSize::Base,
+            ButtonSize::Large => TextSize::Lg,
+        }
+    }
+
+    fn step_button(&self, direction: StepDirection) -> ButtonBuilder {
+        let label = match direction {
+            StepDirection::Decrement => "−",
+            StepDirection::Increment => "+",
+        };
+        let mut button = ButtonBuilder::new(format!("{}_{:?}", self.name, direction))
+            .variant(ButtonVariant::Soft)
+            .size(self.size)
+            .color(self.number_input.color.clone())
+            .text(label);
+        if self.number_input.disabled {
+            button = button.disabled();
+        }
+        button
+    }
+
+    pub fn build(mut self) -> impl Bundle {
+        let layout = UiLayout::default();
+        self.number_input.min = self.number_input.min.min(self.number_input.max);
+        self.number_input.value = self.number_input.sanitize(self.number_input.value);
+
+        let decrement = self.step_button(StepDirection::Decrement);
+        let increment = self.step_button(StepDirection::Increment);
+        let text_size = self.text_size();
+        let display = self.number_input.formatted_value();
+        let palette = self.number_input.color.clone();
+        let width = self.width;
+        let disabled = self.number_input.disabled;
+
+        (
+            Name::new(self.name),
+            self.number_input,
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Stretch,
+                column_gap: Val::Px(layout.gap.xs),
+                ..default()
+            },
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                let input = parent.target_entity();
+
+                // The steppers duplicate the arrow keys, so only the field is a tab stop
+                parent
+                    .spawn((
+                        decrement.build(),
+                        NumberInputStepButton {
+                            input,
+                            direction: StepDirection::Decrement,
+                        },
+                    ))
+                    .remove::<Focusable>();
+
+                parent
+                    .spawn((
+                        Name::new("NumberInputField"),
+                        NumberInputField { input },
+                        Node {
+                            width,
+                            padding: UiRect::axes(
+                                Val::Px(layout.padding.sm),
+                                Val::Px(layout.padding.xs),
+                            ),
+                            border: UiRect::all(Val::Px(layout.border.xs)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(palette.bg_subtle),
+                        BorderColor(palette.border),
+                        BorderRadius::all(Val::Px(layout.radius.base)),
+                        Pickable::default(),
+                        Focusable::new().disabled(disabled),
+                    ))
+                    .with_children(|field| {
+                        field.spawn((
+                            Text::body(display)
+                                .size(text_size)
+                                .color(TextColor::Custom(palette.text_contrast))
+                                .center()
+                                .build(),
+                            NumberInputValueText { input },
+                            Pickable::IGNORE,
+                        ));
+                    });
+
+                parent
+                    .spawn((
+                        increment.build(),
+                        NumberInputStepButton {
+                            input,
+                            direction: StepDirection::Increment,
+                        },
+                    ))
+                    .remove::<Focusable>();
+            })),
+        )
+    }
+}
+
+pub type NumberInput = NumberInputComponent;
+
+impl Namespace for NumberInputBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
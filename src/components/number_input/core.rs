@@ -0,0 +1,156 @@
+//! Core types of the NumberInput component.
+
+use crate::theme::color::{accent_palette, UiColorPalette};
+use bevy::prelude::*;
+
+/// Event emitted whenever the value of a number input changes.
+#[derive(Event, Debug, Clone)]
+pub struct NumberInputChangeEvent {
+    pub number_input_entity: Entity,
+    pub value: f32,
+}
+
+/// Numeric stepper: a value field flanked by decrement/increment buttons.
+///
+/// The value is always kept within `[min, max]` and rounded to `precision`
+/// decimal places.
+#[derive(Component, Debug, Clone)]
+pub struct NumberInputComponent {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    /// Number of decimal places the value is rounded to and displayed with
+    pub precision: u32,
+    /// Whether the mouse wheel over the field steps the value
+    pub wheel_step: bool,
+    pub disabled: bool,
+    pub color: UiColorPalette,
+    /// Whether the field receives keyboard input
+    pub focused: bool,
+    /// Text typed into the focused field, committed on Enter or blur
+    pub edit_buffer: Option<String>,
+}
+
+impl Default for NumberInputComponent {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            min: f32::MIN,
+            max: f32::MAX,
+            step: 1.0,
+            precision: 0,
+            wheel_step: false,
+            disabled: false,
+            color: accent_palette(),
+            focused: false,
+            edit_buffer: None,
+        }
+    }
+}
+
+impl NumberInputComponent {
+    /// Clamps `value` to the bounds and rounds it to the configured precision.
+    pub fn sanitize(&self, value: f32) -> f32 {
+        let factor = 10f32.powi(self.precision as i32);
+        let rounded = (value * factor).round() / factor;
+        rounded.clamp(self.min, self.max)
+    }
+
+    /// The value after `steps` steps (negative steps decrement).
+    pub fn stepped(&self, steps: i32) -> f32 {
+        self.sanitize(self.value + self.step * steps as f32)
+    }
+
+    /// The value formatted with the configured precision.
+    pub fn formatted_value(&self) -> String {
+        format!("{:.*}", self.precision as usize, self.value)
+    }
+
+    /// Text shown in the field: the edit buffer while typing, the value otherwise.
+    pub fn display_text(&self) -> String {
+        self.edit_buffer
+            .clone()
+            .unwrap_or_else(|| self.formatted_value())
+    }
+}
+
+/// Direction of a step button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepDirection {
+    Decrement,
+    Increment,
+}
+
+impl StepDirection {
+    pub fn steps(self) -> i32 {
+        match self {
+            StepDirection::Decrement => -1,
+            StepDirection::Increment => 1,
+        }
+    }
+}
+
+/// Marker for the value field of a number input.
+#[derive(Component, Debug)]
+pub struct NumberInputField {
+    pub input: Entity,
+}
+
+/// Marker for the text displaying the value.
+#[derive(Component, Debug)]
+pub struct NumberInputValueText {
+    pub input: Entity,
+}
+
+/// Marker for the +/- buttons of a number input.
+#[derive(Component, Debug)]
+pub struct NumberInputStepButton {
+    pub input: Entity,
+    pub direction: StepDirection,
+}
+
+/// Present on a step button while it is held down.
+#[derive(Component, Debug)]
+pub struct NumberInputRepeat {
+    pub timer: Timer,
+}
+
+/// Delay before a held step button starts repeating.
+pub const NUMBER_INPUT_REPEAT_DELAY: f32 = 0.4;
+/// Interval between repeated steps while a button is held.
+pub const NUMBER_INPUT_REPEAT_INTERVAL: f32 = 0.06;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_clamps_and_rounds() {
+        let input = NumberInputComponent {
+            min: 0.0,
+            max: 10.0,
+            precision: 1,
+            ..default()
+        };
+
+        assert_eq!(input.sanitize(12.0), 10.0);
+        assert_eq!(input.sanitize(-3.0), 0.0);
+        assert_eq!(input.sanitize(2.46), 2.5);
+    }
+
+    #[test]
+    fn test_stepped_respects_bounds() {
+        let input = NumberInputComponent {
+            value: 9.5,
+            min: 0.0,
+            max: 10.0,
+            step: 1.0,
+            precision: 1,
+            ..default()
+        };
+
+        assert_eq!(input.stepped(1), 10.0);
+        assert_eq!(input.stepped(-1), 8.5);
+    }
+}
@@ -0,0 +1,33 @@
+//! NumberInput Component for Forge UI
+//!
+//! A numeric stepper made of a value field between a decrement and an
+//! increment button.
+//!
+//! ## Features
+//!
+//! - **Bounds & Precision**: Values are clamped to `min`/`max` and rounded to `precision`
+//! - **Step Buttons**: Click to step, hold to auto-repeat
+//! - **Keyboard**: Click or tab into the field to focus, Up/Down to step, type to edit, Enter to commit
+//! - **Mouse Wheel**: Optional stepping with the wheel via `.wheel_step()`
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ui::components::NumberInput;
+//!
+//! let quantity = NumberInput::new("Quantity")
+//!     .min(0.0)
+//!     .max(10.0)
+//!     .step(0.5)
+//!     .precision(1)
+//!     .wheel_step()
+//!     .build();
+//! ```
+
+pub mod builder;
+pub mod core;
+mod systems;
+
+pub use builder::*;
+pub use core::*;
+pub use systems::*;
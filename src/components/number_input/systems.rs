@@ -0,0 +1,300 @@
+//! Interaction systems of the NumberInput component.
+
+use super::core::{
+    NumberInputChangeEvent, NumberInputComponent, NumberInputField, NumberInputRepeat,
+    NumberInputStepButton, NumberInputValueText, NUMBER_INPUT_REPEAT_DELAY,
+    NUMBER_INPUT_REPEAT_INTERVAL,
+};
+use crate::components::focus::FocusChangeEvent;
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+};
+use bevy_picking::prelude::{Click, Out, Pointer, Pressed, Released, Scroll};
+
+/// Sets `value` on the input and emits a change event if it actually changed.
+fn set_value(
+    entity: Entity,
+    input: &mut NumberInputComponent,
+    value: f32,
+    events: &mut EventWriter<NumberInputChangeEvent>,
+) {
+    let value = input.sanitize(value);
+    if value != input.value {
+        input.value = value;
+        events.write(NumberInputChangeEvent {
+            number_input_entity: entity,
+            value,
+        });
+    }
+}
+
+/// Parses the edit buffer into the value; invalid text restores the last value.
+fn commit_edit(
+    entity: Entity,
+    input: &mut NumberInputComponent,
+    events: &mut EventWriter<NumberInputChangeEvent>,
+) {
+    if let Some(buffer) = input.edit_buffer.take() {
+        if let Ok(value) = buffer.trim().parse::<f32>() {
+            set_value(entity, input, value, events);
+        }
+    }
+}
+
+fn step(
+    entity: Entity,
+    input: &mut NumberInputComponent,
+    steps: i32,
+    events: &mut EventWriter<NumberInputChangeEvent>,
+) {
+    commit_edit(entity, input, events);
+    let value = input.stepped(steps);
+    set_value(entity, input, value, events);
+}
+
+/// Attaches pointer observers to newly spawned step buttons and fields.
+pub fn setup_number_input_interactions(
+    mut commands: Commands,
+    buttons: Query<Entity, Added<NumberInputStepButton>>,
+    fields: Query<Entity, Added<NumberInputField>>,
+) {
+    for entity in &buttons {
+        commands
+            .entity(entity)
+            .observe(on_step_pressed)
+            .observe(on_step_released)
+            .observe(on_step_out);
+    }
+
+    for entity in &fields {
+        commands
+            .entity(entity)
+            .observe(on_field_click)
+            .observe(on_field_scroll);
+    }
+}
+
+fn on_step_pressed(
+    trigger: Trigger<Pointer<Pressed>>,
+    mut commands: Commands,
+    buttons: Query<&NumberInputStepButton>,
+    mut inputs: Query<&mut NumberInputComponent>,
+    mut events: EventWriter<NumberInputChangeEvent>,
+) {
+    let Ok(button) = buttons.get(trigger.target()) else {
+        return;
+    };
+    let Ok(mut input) = inputs.get_mut(button.input) else {
+        return;
+    };
+    if input.disabled {
+        return;
+    }
+
+    step(
+        button.input,
+        &mut input,
+        button.direction.steps(),
+        &mut events,
+    );
+    commands.entity(trigger.target()).insert(NumberInputRepeat {
+        timer: Timer::from_seconds(NUMBER_INPUT_REPEAT_DELAY, TimerMode::Once),
+    });
+}
+
+fn on_step_released(trigger: Trigger<Pointer<Released>>, mut commands: Commands) {
+    commands
+        .entity(trigger.target())
+        .remove::<NumberInputRepeat>();
+}
+
+fn on_step_out(trigger: Trigger<Pointer<Out>>, mut commands: Commands) {
+    commands
+        .entity(trigger.target())
+        .remove::<NumberInputRepeat>();
+}
+
+/// Focuses the clicked field; any other focused number input commits and blurs.
+fn on_field_click(
+    trigger: Trigger<Pointer<Click>>,
+    fields: Query<&NumberInputField>,
+    mut inputs: Query<(Entity, &mut NumberInputComponent)>,
+    mut events: EventWriter<NumberInputChangeEvent>,
+) {
+    let Ok(field) = fields.get(trigger.target()) else {
+        return;
+    };
+
+    for (entity, mut input) in &mut inputs {
+        if entity == field.input {
+            if !input.disabled && !input.focused {
+                input.focused = true;
+            }
+        } else if input.focused {
+            commit_edit(entity, &mut input, &mut events);
+            input.focused = false;
+        }
+    }
+}
+
+fn on_field_scroll(
+    mut trigger: Trigger<Pointer<Scroll>>,
+    fields: Query<&NumberInputField>,
+    mut inputs: Query<&mut NumberInputComponent>,
+    mut events: EventWriter<NumberInputChangeEvent>,
+) {
+    let Ok(field) = fields.get(trigger.target()) else {
+        return;
+    };
+    let Ok(mut input) = inputs.get_mut(field.input) else {
+        return;
+    };
+    if !input.wheel_step || input.disabled {
+        return;
+    }
+    // Don't scroll a surrounding scroll area while stepping
+    trigger.propagate(false);
+
+    let delta = trigger.event().y;
+    if delta != 0.0 {
+        let steps = if delta > 0.0 { 1 } else { -1 };
+        step(field.input, &mut input, steps, &mut events);
+    }
+}
+
+/// Repeats the step of a held button after an initial delay.
+pub fn repeat_held_number_input_steps(
+    time: Res<Time>,
+    mut buttons: Query<(&NumberInputStepButton, &mut NumberInputRepeat)>,
+    mut inputs: Query<&mut NumberInputComponent>,
+    mut events: EventWriter<NumberInputChangeEvent>,
+) {
+    for (button, mut repeat) in &mut buttons {
+        repeat.timer.tick(time.delta());
+        if !repeat.timer.just_finished() {
+            continue;
+        }
+        if repeat.timer.mode() == TimerMode::Once {
+            repeat.timer = Timer::from_seconds(NUMBER_INPUT_REPEAT_INTERVAL, TimerMode::Repeating);
+        }
+
+        if let Ok(mut input) = inputs.get_mut(button.input) {
+            if !input.disabled {
+                step(
+                    button.input,
+                    &mut input,
+                    button.direction.steps(),
+                    &mut events,
+                );
+            }
+        }
+    }
+}
+
+/// Keyboard input for the focused number input: Up/Down step, typing edits
+/// the value, Enter commits and Escape discards the typed text.
+pub fn handle_number_input_keyboard(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut inputs: Query<(Entity, &mut NumberInputComponent)>,
+    mut events: EventWriter<NumberInputChangeEvent>,
+) {
+    let Some((entity, mut input)) = inputs.iter_mut().find(|(_, input)| input.focused) else {
+        keyboard_events.clear();
+        return;
+    };
+
+    for event in keyboard_events.read() {
+        if !input.focused {
+            break;
+        }
+        if !event.state.is_pressed() {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::ArrowUp => step(entity, &mut input, 1, &mut events),
+            Key::ArrowDown => step(entity, &mut input, -1, &mut events),
+            Key::Enter | Key::Tab => {
+                commit_edit(entity, &mut input, &mut events);
+                input.focused = false;
+            }
+            Key::Escape => {
+                input.edit_buffer = None;
+                input.focused = false;
+            }
+            Key::Backspace => {
+                let mut buffer = input.display_text();
+                buffer.pop();
+                input.edit_buffer = Some(buffer);
+            }
+            Key::Character(text) => {
+                let allowed = text
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || c == '.' || c == '-');
+                if allowed {
+                    let mut buffer = input.edit_buffer.clone().unwrap_or_default();
+                    buffer.push_str(text);
+                    input.edit_buffer = Some(buffer);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Focuses the input whose field was tabbed into; the input tabbed out of
+/// commits and blurs.
+pub fn follow_number_input_keyboard_focus(
+    mut focus_events: EventReader<FocusChangeEvent>,
+    fields: Query<&NumberInputField>,
+    mut inputs: Query<&mut NumberInputComponent>,
+    mut events: EventWriter<NumberInputChangeEvent>,
+) {
+    for event in focus_events.read() {
+        let input_of =
+            |entity: Option<Entity>| entity.and_then(|e| fields.get(e).ok()).map(|f| f.input);
+
+        if let Some(entity) = input_of(event.previous) {
+            if let Ok(mut input) = inputs.get_mut(entity) {
+                if input.focused {
+                    commit_edit(entity, &mut input, &mut events);
+                    input.focused = false;
+                }
+            }
+        }
+        if let Some(entity) = input_of(event.focused) {
+            if let Ok(mut input) = inputs.get_mut(entity) {
+                if !input.disabled {
+                    input.focused = true;
+                }
+            }
+        }
+    }
+}
+
+/// Mirrors value and focus state into the field's text and border.
+pub fn update_number_input_display(
+    inputs: Query<&NumberInputComponent, Changed<NumberInputComponent>>,
+    mut texts: Query<(&NumberInputValueText, &mut Text)>,
+    mut fields: Query<(&NumberInputField, &mut BorderColor)>,
+) {
+    for (value_text, mut text) in &mut texts {
+        if let Ok(input) = inputs.get(value_text.input) {
+            let display = input.display_text();
+            if text.0 != display {
+                text.0 = display;
+            }
+        }
+    }
+
+    for (field, mut border) in &mut fields {
+        if let Ok(input) = inputs.get(field.input) {
+            border.0 = if input.focused {
+                input.color.solid
+            } else {
+                input.color.border
+            };
+        }
+    }
+}
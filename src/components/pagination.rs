@@ -0,0 +1,391 @@
+use crate::{
+    assets::{ChevronLeft, ChevronRight, ChevronsLeft, ChevronsRight, Ellipsis, Interface},
+    components::button::{ButtonBuilder, ButtonClickEvent, ButtonSize, ButtonVariant},
+    theme::{
+        color::{accent_palette, TextColor as TextColorEnum, UiColorPalette},
+        layout::UiLayout,
+        typography::FontFamily,
+    },
+    utilities::naming::Namespace,
+};
+use bevy::prelude::*;
+use bevy_picking::prelude::Pickable;
+
+/// Event emitted when the user selects another page.
+#[derive(Event, Debug, Clone)]
+pub struct PageChangeEvent {
+    pub pagination_entity: Entity,
+    /// The new page, starting at 1
+    pub page: usize,
+}
+
+/// An entry in the row of numbered pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaginationItem {
+    Page(usize),
+    Ellipsis,
+}
+
+/// Computes the numbered pages and ellipses shown for `current` out of
+/// `total_pages` (both 1-based).
+///
+/// The first and last page are always shown, together with `sibling_count`
+/// pages on each side of the current one. Gaps collapse into an ellipsis,
+/// except a gap of a single page, which is shown instead. Once the page
+/// count exceeds `2 * sibling_count + 5`, the result always has that many
+/// items so the control does not change width while paging.
+pub fn pagination_items(
+    total_pages: usize,
+    current: usize,
+    sibling_count: usize,
+) -> Vec<PaginationItem> {
+    let total = total_pages.max(1);
+    let current = current.clamp(1, total);
+    let slots = 2 * sibling_count + 5;
+    let pages = |from: usize, to: usize| (from..=to).map(PaginationItem::Page);
+
+    if total <= slots {
+        return pages(1, total).collect();
+    }
+
+    let left_sibling = current.saturating_sub(sibling_count).max(1);
+    let right_sibling = (current + sibling_count).min(total);
+    let left_ellipsis = left_sibling > 3;
+    let right_ellipsis = right_sibling < total - 2;
+    // First/last page, an ellipsis and the sibling window around the current page
+    let edge_count = 2 * sibling_count + 3;
+
+    let mut items = Vec::with_capacity(slots);
+    match (left_ellipsis, right_ellipsis) {
+        (false, _) => {
+            items.extend(pages(1, edge_count));
+            items.push(PaginationItem::Ellipsis);
+            items.push(PaginationItem::Page(total));
+        }
+        (true, false) => {
+            items.push(PaginationItem::Page(1));
+            items.push(PaginationItem::Ellipsis);
+            items.extend(pages(total - edge_count + 1, total));
+        }
+        (true, true) => {
+            items.push(PaginationItem::Page(1));
+            items.push(PaginationItem::Ellipsis);
+            items.extend(pages(left_sibling, right_sibling));
+            items.push(PaginationItem::Ellipsis);
+            items.push(PaginationItem::Page(total));
+        }
+    }
+    items
+}
+
+/// Page navigation: first/previous buttons, numbered pages, next/last buttons.
+#[derive(Component, Debug, Clone)]
+pub struct PaginationComponent {
+    pub total_pages: usize,
+    /// Current page, starting at 1
+    pub current: usize,
+    /// Pages shown on each side of the current page
+    pub sibling_count: usize,
+    pub size: ButtonSize,
+    pub color: UiColorPalette,
+}
+
+impl Default for PaginationComponent {
+    fn default() -> Self {
+        Self {
+            total_pages: 1,
+            current: 1,
+            sibling_count: 1,
+            size: ButtonSize::Default,
+            color: accent_palette(),
+        }
+    }
+}
+
+impl PaginationComponent {
+    pub fn new(name: impl Into<String>) -> PaginationBuilder {
+        PaginationBuilder::new(name)
+    }
+
+    /// Moves to `page`, clamped to the valid range. Returns whether the page changed.
+    pub fn set_page(&mut self, page: usize) -> bool {
+        let page = page.clamp(1, self.total_pages.max(1));
+        let changed = page != self.current;
+        self.current = page;
+        changed
+    }
+
+    pub fn is_first(&self) -> bool {
+        self.current <= 1
+    }
+
+    pub fn is_last(&self) -> bool {
+        self.current >= self.total_pages
+    }
+}
+
+/// A button that navigates to `page` when clicked.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PaginationButton {
+    pub pagination: Entity,
+    pub page: usize,
+}
+
+pub struct PaginationBuilder {
+    name: String,
+    pagination: PaginationComponent,
+}
+
+impl PaginationBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: format!("{}_Pagination", name.into()),
+            pagination: PaginationComponent::default(),
+        }
+    }
+
+    pub fn total_pages(mut self, total_pages: usize) -> Self {
+        self.pagination.total_pages = total_pages.max(1);
+        self
+    }
+
+    /// Initially selected page, starting at 1.
+    pub fn current(mut self, current: usize) -> Self {
+        self.pagination.current = current;
+        self
+    }
+
+    pub fn sibling_count(mut self, sibling_count: usize) -> Self {
+        self.pagination.sibling_count = sibling_count;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.pagination.size = size;
+        self
+    }
+
+    pub fn color(mut self, color: UiColorPalette) -> Self {
+        self.pagination.color = color;
+        self
+    }
+}
+
+impl PaginationBuilder {
+    fn calculate_style(&self) -> Node {
+        Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(UiLayout::default().gap.xs),
+            ..default()
+        }
+    }
+
+    /// The buttons are spawned by [`update_pagination_items`].
+    pub fn build(mut self) -> impl Bundle {
+        self.pagination.current = self
+            .pagination
+            .current
+            .clamp(1, self.pagination.total_pages);
+
+        (
+            Name::new(self.name.clone()),
+            self.calculate_style(),
+            self.pagination,
+        )
+    }
+}
+
+fn page_button(pagination: &PaginationComponent, label: String) -> ButtonBuilder {
+    ButtonBuilder::new(format!("Page_{label}"))
+        .size(pagination.size)
+        .color(pagination.color.clone())
+        .text(label)
+}
+
+fn nav_button(pagination: &PaginationComponent, icon: &str, disabled: bool) -> ButtonBuilder {
+    let button = page_button(pagination, icon.to_string())
+        .variant(ButtonVariant::Soft)
+        .text_family(FontFamily::InterfaceFont);
+    if disabled {
+        button.disabled()
+    } else {
+        button
+    }
+}
+
+fn spawn_pagination_button(
+    commands: &mut Commands,
+    pagination: Entity,
+    button: ButtonBuilder,
+    page: usize,
+) -> Entity {
+    commands
+        .spawn((button.build(), PaginationButton { pagination, page }))
+        .id()
+}
+
+/// Respawns the buttons of paginations whose page or settings changed.
+pub fn update_pagination_items(
+    mut commands: Commands,
+    paginations: Query<
+        (Entity, &PaginationComponent, Option<&Children>),
+        Changed<PaginationComponent>,
+    >,
+) {
+    for (entity, pagination, children) in &paginations {
+        for child in children.into_iter().flat_map(|children| children.iter()) {
+            commands.entity(child).despawn();
+        }
+
+        let current = pagination.current;
+        let last = pagination.total_pages.max(1);
+
+        let mut items = vec![
+            spawn_pagination_button(
+                &mut commands,
+                entity,
+                nav_button(pagination, ChevronsLeft, pagination.is_first()),
+                1,
+            ),
+            spawn_pagination_button(
+                &mut commands,
+                entity,
+                nav_button(pagination, ChevronLeft, pagination.is_first()),
+                current.saturating_sub(1).max(1),
+            ),
+        ];
+
+        for item in pagination_items(last, current, pagination.sibling_count) {
+            let item = match item {
+                PaginationItem::Page(page) => {
+                    let variant = if page == current {
+                        ButtonVariant::Solid
+                    } else {
+                        ButtonVariant::Soft
+                    };
+                    let button = page_button(pagination, page.to_string()).variant(variant);
+                    spawn_pagination_button(&mut commands, entity, button, page)
+                }
+                PaginationItem::Ellipsis => commands
+                    .spawn((
+                        Interface::new(Ellipsis)
+                            .color(TextColorEnum::Custom(pagination.color.text))
+                            .build(),
+                        Pickable::IGNORE,
+                    ))
+                    .id(),
+            };
+            items.push(item);
+        }
+
+        items.push(spawn_pagination_button(
+            &mut commands,
+            entity,
+            nav_button(pagination, ChevronRight, pagination.is_last()),
+            (current + 1).min(last),
+        ));
+        items.push(spawn_pagination_button(
+            &mut commands,
+            entity,
+            nav_button(pagination, ChevronsRight, pagination.is_last()),
+            last,
+        ));
+
+        commands.entity(entity).add_children(&items);
+    }
+}
+
+/// Switches pages when a pagination button is clicked.
+pub fn handle_pagination_clicks(
+    mut button_events: EventReader<ButtonClickEvent>,
+    buttons: Query<&PaginationButton>,
+    mut paginations: Query<&mut PaginationComponent>,
+    mut page_events: EventWriter<PageChangeEvent>,
+) {
+    for event in button_events.read() {
+        let Ok(button) = buttons.get(event.button_entity) else {
+            continue;
+        };
+        let Ok(mut pagination) = paginations.get_mut(button.pagination) else {
+            continue;
+        };
+        // Only mark the pagination changed (and respawn it) when the page moves
+        if pagination.current != button.page && pagination.set_page(button.page) {
+            page_events.write(PageChangeEvent {
+                pagination_entity: button.pagination,
+                page: pagination.current,
+            });
+        }
+    }
+}
+
+pub type Pagination = PaginationComponent;
+
+impl Namespace for PaginationBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PaginationItem::{Ellipsis as Gap, Page};
+
+    #[test]
+    fn test_few_pages_show_all() {
+        assert_eq!(
+            pagination_items(5, 3, 1),
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5)]
+        );
+        assert_eq!(pagination_items(0, 1, 1), vec![Page(1)]);
+    }
+
+    #[test]
+    fn test_ellipses_around_sibling_window() {
+        assert_eq!(
+            pagination_items(20, 10, 1),
+            vec![Page(1), Gap, Page(9), Page(10), Page(11), Gap, Page(20)]
+        );
+        assert_eq!(
+            pagination_items(20, 10, 2),
+            vec![
+                Page(1),
+                Gap,
+                Page(8),
+                Page(9),
+                Page(10),
+                Page(11),
+                Page(12),
+                Gap,
+                Page(20)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_single_page_gaps_are_not_collapsed() {
+        // Page 2 is shown instead of an ellipsis standing in for it
+        assert_eq!(
+            pagination_items(10, 4, 1),
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5), Gap, Page(10)]
+        );
+        assert_eq!(
+            pagination_items(10, 7, 1),
+            vec![Page(1), Gap, Page(6), Page(7), Page(8), Page(9), Page(10)]
+        );
+    }
+
+    #[test]
+    fn test_set_page_clamps() {
+        let mut pagination = PaginationComponent {
+            total_pages: 3,
+            ..default()
+        };
+        assert!(pagination.set_page(7));
+        assert_eq!(pagination.current, 3);
+        assert!(pagination.is_last());
+        assert!(!pagination.set_page(3));
+    }
+}
@@ -1,9 +1,23 @@
 use crate::{
+    components::{
+        a11y::{A11yState, A11yValueText, UiA11y, UiA11yValue, UiRole},
+        text::Text as UiText,
+    },
     theme::{
         color::{accent_palette, UiColorPalette},
+        motion::UiMotion,
+        typography::TextSize,
     },
+    utilities::naming::{strip_namespace, Namespace},
 };
 use bevy::{ecs::spawn::SpawnWith, prelude::*};
+use std::{
+    f32::consts::{PI, TAU},
+    sync::Arc,
+};
+
+/// Number of segments a circular progress ring is drawn with
+pub const CIRCULAR_PROGRESS_SEGMENTS: usize = 32;
 
 #[derive(Component, Debug, Clone)]
 pub struct ProgressComponent {
@@ -13,6 +27,13 @@ pub struct ProgressComponent {
     pub color: UiColorPalette,
     pub indeterminate: bool,
     pub label: Option<String>,
+    pub shape: ProgressShape,
+    /// Bar height or ring width; `None` uses the size's default
+    pub thickness: Option<f32>,
+    /// Ring diameter of circular progress; `None` uses the size's default
+    pub diameter: Option<f32>,
+    /// Whether circular progress shows its percentage in the center
+    pub show_percentage: bool,
 }
 
 impl Default for ProgressComponent {
@@ -24,10 +45,78 @@ impl Default for ProgressComponent {
             color: accent_palette(),
             indeterminate: false,
             label: None,
+            shape: ProgressShape::Linear,
+            thickness: None,
+            diameter: None,
+            show_percentage: false,
+        }
+    }
+}
+
+impl ProgressComponent {
+    /// Bar height (linear) or ring width (circular) in pixels.
+    pub fn thickness(&self) -> f32 {
+        self.thickness.unwrap_or(match (self.shape, self.size) {
+            (ProgressShape::Linear, ProgressSize::Size1) => 4.0,
+            (ProgressShape::Linear, ProgressSize::Size2) => 6.0,
+            (ProgressShape::Linear, ProgressSize::Size3) => 8.0,
+            (ProgressShape::Circular, ProgressSize::Size1) => 3.0,
+            (ProgressShape::Circular, ProgressSize::Size2) => 4.0,
+            (ProgressShape::Circular, ProgressSize::Size3) => 5.0,
+        })
+    }
+
+    /// Ring diameter of circular progress in pixels.
+    pub fn diameter(&self) -> f32 {
+        self.diameter.unwrap_or(match self.size {
+            ProgressSize::Size1 => 24.0,
+            ProgressSize::Size2 => 32.0,
+            ProgressSize::Size3 => 40.0,
+        })
+    }
+
+    /// Completed fraction in `0.0..=1.0`; `0.0` for an empty range.
+    pub fn fraction(&self) -> f32 {
+        if self.max <= 0.0 {
+            return 0.0;
+        }
+        (self.value / self.max).clamp(0.0, 1.0)
+    }
+
+    /// Number of visible ring segments out of `segments`.
+    ///
+    /// Indeterminate rings show a quarter arc, which spins.
+    pub fn filled_segments(&self, segments: usize) -> usize {
+        if self.indeterminate {
+            segments / 4
+        } else {
+            (self.fraction() * segments as f32).round() as usize
         }
     }
 }
 
+impl A11yState for ProgressComponent {
+    fn a11y_value(&self) -> UiA11yValue {
+        if self.indeterminate {
+            return UiA11yValue::Busy;
+        }
+        UiA11yValue::Number {
+            value: self.value,
+            min: 0.0,
+            max: self.max,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressShape {
+    /// Horizontal bar filling from the left
+    #[default]
+    Linear,
+    /// Ring filling clockwise from the top
+    Circular,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ProgressSize {
     Size1, // Small - 4px height
@@ -54,6 +143,7 @@ impl Default for ProgressAnimation {
 pub struct ProgressBuilder {
     name: String,
     progress: ProgressComponent,
+    value_text: A11yValueText,
 }
 
 impl ProgressBuilder {
@@ -61,6 +151,7 @@ impl ProgressBuilder {
         Self {
             name: "Progress".to_string(),
             progress: ProgressComponent::default(),
+            value_text: A11yValueText::default(),
         }
     }
 
@@ -134,6 +225,49 @@ impl ProgressBuilder {
         self
     }
 
+    pub fn shape(mut self, shape: ProgressShape) -> Self {
+        self.progress.shape = shape;
+        self
+    }
+
+    pub fn circular(self) -> Self {
+        self.shape(ProgressShape::Circular)
+    }
+
+    /// Bar height (linear) or ring width (circular) in pixels
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.progress.thickness = Some(thickness.max(1.0));
+        self
+    }
+
+    /// Ring diameter in pixels; only affects circular progress
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.progress.diameter = Some(diameter.max(1.0));
+        self
+    }
+
+    /// Shows the percentage in the center of circular progress
+    pub fn show_percentage(mut self) -> Self {
+        self.progress.show_percentage = true;
+        self
+    }
+
+    /// Formats the value announced in [`UiA11y::value_text`] from the value,
+    /// minimum and maximum; a percentage by default
+    ///
+    /// ```text
+    /// ProgressBuilder::new().progress(12.0, 20.0).value_text_formatter(|value, _, max| {
+    ///     format!("{value} of {max}")
+    /// })
+    /// ```
+    pub fn value_text_formatter(
+        mut self,
+        formatter: impl Fn(f32, f32, f32) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.value_text.formatter = Some(Arc::new(formatter));
+        self
+    }
+
     pub fn build(self) -> impl Bundle {
         // Clamp the final values
         let mut final_progress = self.progress.clone();
@@ -141,6 +275,7 @@ impl ProgressBuilder {
         
         let track_node = self.calculate_track_style();
         let track_background = self.calculate_track_background();
+        let track_border_color = self.calculate_track_border_color();
         let track_border_radius = self.calculate_track_border_radius();
 
         let progress_percentage = if final_progress.indeterminate {
@@ -149,13 +284,27 @@ impl ProgressBuilder {
             final_progress.value / final_progress.max
         };
 
+        let a11y_label = final_progress
+            .label
+            .clone()
+            .unwrap_or_else(|| strip_namespace(&self.name).to_string());
+        let a11y = UiA11y::new(UiRole::ProgressBar, a11y_label).value(final_progress.a11y_value());
+
         let bundle = (
             Name::new(self.name.clone()),
             final_progress.clone(),
+            a11y,
+            self.value_text.clone(),
             track_node,
             track_background,
+            track_border_color,
             track_border_radius,
             Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                if final_progress.shape == ProgressShape::Circular {
+                    self.spawn_ring(parent, &final_progress);
+                    return;
+                }
+
                 // Progress indicator (filled portion)
                 let indicator_node = self.calculate_indicator_style(progress_percentage);
                 let indicator_background = self.calculate_indicator_background();
@@ -185,17 +334,35 @@ impl ProgressBuilder {
 #[derive(Component, Debug)]
 pub struct ProgressIndicator;
 
+/// Rotating container of a circular progress' segments.
+#[derive(Component, Debug)]
+pub struct ProgressRing;
+
+/// One segment of a circular progress ring, numbered clockwise from the top.
+#[derive(Component, Debug)]
+pub struct ProgressSegment(pub usize);
+
+/// Percentage text in the center of a circular progress.
+#[derive(Component, Debug)]
+pub struct ProgressPercentageLabel;
+
 impl ProgressBuilder {
     fn calculate_track_style(&self) -> Node {
-        let height = match self.progress.size {
-            ProgressSize::Size1 => Val::Px(4.0),
-            ProgressSize::Size2 => Val::Px(6.0), 
-            ProgressSize::Size3 => Val::Px(8.0),
-        };
+        let thickness = self.progress.thickness();
+        if self.progress.shape == ProgressShape::Circular {
+            let diameter = Val::Px(self.progress.diameter());
+            return Node {
+                width: diameter,
+                height: diameter,
+                border: UiRect::all(Val::Px(thickness)),
+                position_type: PositionType::Relative,
+                ..default()
+            };
+        }
 
         Node {
             width: Val::Percent(100.0),
-            height,
+            height: Val::Px(thickness),
             overflow: Overflow::clip(),
             position_type: PositionType::Relative,
             ..default()
@@ -203,16 +370,105 @@ impl ProgressBuilder {
     }
 
     fn calculate_track_background(&self) -> BackgroundColor {
-        BackgroundColor(self.progress.color.bg)
+        match self.progress.shape {
+            ProgressShape::Linear => BackgroundColor(self.progress.color.bg),
+            // The ring track is drawn by the border
+            ProgressShape::Circular => BackgroundColor(Color::NONE),
+        }
+    }
+
+    fn calculate_track_border_color(&self) -> BorderColor {
+        match self.progress.shape {
+            ProgressShape::Linear => BorderColor(Color::NONE),
+            ProgressShape::Circular => BorderColor(self.progress.color.bg),
+        }
     }
 
     fn calculate_track_border_radius(&self) -> BorderRadius {
-        let radius = match self.progress.size {
-            ProgressSize::Size1 => Val::Px(2.0),
-            ProgressSize::Size2 => Val::Px(3.0), 
-            ProgressSize::Size3 => Val::Px(4.0),
+        match self.progress.shape {
+            ProgressShape::Linear => BorderRadius::all(Val::Px(self.progress.thickness() / 2.0)),
+            ProgressShape::Circular => BorderRadius::MAX,
+        }
+    }
+
+    /// Spawns the segmented ring (and percentage label) of circular progress.
+    ///
+    /// Bevy UI can't draw arcs, so the ring is made of short bars, each in a
+    /// full-size node rotated to its position; filled segments are visible.
+    fn spawn_ring(&self, parent: &mut ChildSpawner, progress: &ProgressComponent) {
+        let diameter = progress.diameter();
+        let thickness = progress.thickness();
+        let filled = progress.filled_segments(CIRCULAR_PROGRESS_SEGMENTS);
+        // Arc length per segment along the ring's center line, plus a little
+        // overlap so the bars read as one arc
+        let segment_length = PI * (diameter - thickness) / CIRCULAR_PROGRESS_SEGMENTS as f32 + 0.5;
+        // Children are laid out inside the track border; cover the whole ring
+        let full_size = Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(-thickness),
+            top: Val::Px(-thickness),
+            width: Val::Px(diameter),
+            height: Val::Px(diameter),
+            ..default()
         };
-        BorderRadius::all(radius)
+        let color = self.calculate_indicator_background();
+
+        let mut ring = parent.spawn((
+            Name::new(format!("{}_Ring", self.name)),
+            ProgressRing,
+            full_size.clone(),
+        ));
+        if progress.indeterminate {
+            ring.insert(ProgressAnimation::default());
+        }
+        ring.with_children(|segments| {
+            for index in 0..CIRCULAR_PROGRESS_SEGMENTS {
+                let angle = index as f32 / CIRCULAR_PROGRESS_SEGMENTS as f32 * TAU;
+                segments
+                    .spawn((
+                        ProgressSegment(index),
+                        Node {
+                            left: Val::Px(0.0),
+                            top: Val::Px(0.0),
+                            ..full_size.clone()
+                        },
+                        // UI space is y-down, so positive angles turn clockwise
+                        Transform::from_rotation(Quat::from_rotation_z(angle)),
+                        if index < filled {
+                            Visibility::Inherited
+                        } else {
+                            Visibility::Hidden
+                        },
+                    ))
+                    .with_child((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px((diameter - segment_length) / 2.0),
+                            top: Val::Px(0.0),
+                            width: Val::Px(segment_length),
+                            height: Val::Px(thickness),
+                            ..default()
+                        },
+                        color,
+                        BorderRadius::all(Val::Px(thickness / 2.0)),
+                    ));
+            }
+        });
+
+        if progress.show_percentage && !progress.indeterminate {
+            parent
+                .spawn(Node {
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..full_size
+                })
+                .with_child((
+                    UiText::label(percentage_text(progress))
+                        .size(TextSize::Xs)
+                        .build(),
+                    ProgressPercentageLabel,
+                ));
+        }
     }
 
     fn calculate_indicator_style(&self, percentage: f32) -> Node {
@@ -251,10 +507,14 @@ impl Default for ProgressBuilder {
 // Animation system for indeterminate progress
 pub fn animate_indeterminate_progress(
     time: Res<Time>,
+    motion: Res<UiMotion>,
     mut query: Query<(&mut Node, &mut ProgressAnimation), With<ProgressIndicator>>,
 ) {
     for (mut node, mut animation) in query.iter_mut() {
-        animation.offset += animation.speed * time.delta_secs();
+        // Reduced motion holds the indicator where it is
+        if !motion.reduced {
+            animation.offset += animation.speed * time.delta_secs();
+        }
         
         // Create a smooth back-and-forth animation
         let cycle = (animation.offset * std::f32::consts::PI).sin();
@@ -289,6 +549,57 @@ pub fn setup_progress_components(
     }
 }
 
+/// Percentage shown in the center of circular progress.
+pub fn percentage_text(progress: &ProgressComponent) -> String {
+    format!("{}%", (progress.fraction() * 100.0).round())
+}
+
+/// Spins the ring of indeterminate circular progress.
+pub fn spin_circular_progress(
+    time: Res<Time>,
+    motion: Res<UiMotion>,
+    mut query: Query<(&mut Transform, &mut ProgressAnimation), With<ProgressRing>>,
+) {
+    if motion.reduced {
+        return;
+    }
+    for (mut transform, mut animation) in &mut query {
+        animation.offset += animation.speed * time.delta_secs();
+        // Same pace as the linear indicator: one turn per cycle
+        transform.rotation = Quat::from_rotation_z(animation.offset * PI);
+    }
+}
+
+/// Shows the segments and percentage of circular progress matching its value.
+pub fn update_circular_progress(
+    progress_query: Query<(Entity, &ProgressComponent), Changed<ProgressComponent>>,
+    children_query: Query<&Children>,
+    mut segments: Query<(&ProgressSegment, &mut Visibility)>,
+    mut labels: Query<&mut Text, With<ProgressPercentageLabel>>,
+) {
+    for (progress_entity, progress) in &progress_query {
+        if progress.shape != ProgressShape::Circular {
+            continue;
+        }
+        let filled = progress.filled_segments(CIRCULAR_PROGRESS_SEGMENTS);
+        for descendant in children_query.iter_descendants(progress_entity) {
+            if let Ok((segment, mut visibility)) = segments.get_mut(descendant) {
+                visibility.set_if_neq(if segment.0 < filled {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                });
+            }
+            if let Ok(mut text) = labels.get_mut(descendant) {
+                let percentage = percentage_text(progress);
+                if text.0 != percentage {
+                    text.0 = percentage;
+                }
+            }
+        }
+    }
+}
+
 // System to update progress values dynamically
 pub fn update_progress_values(
     mut indicator_query: Query<&mut Node, (With<ProgressIndicator>, Without<ProgressAnimation>)>,
@@ -310,4 +621,80 @@ pub fn update_progress_values(
             }
         }
     }
-}
\ No newline at end of file
+}
+
+impl Namespace for ProgressBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{advance_frames, test_app};
+
+    fn progress(value: f32, max: f32) -> ProgressComponent {
+        ProgressComponent {
+            value,
+            max,
+            shape: ProgressShape::Circular,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_filled_segments_follow_value() {
+        assert_eq!(progress(0.0, 1.0).filled_segments(32), 0);
+        assert_eq!(progress(0.5, 1.0).filled_segments(32), 16);
+        assert_eq!(progress(10.0, 10.0).filled_segments(32), 32);
+        // Clamped like the linear bar
+        assert_eq!(progress(15.0, 10.0).filled_segments(32), 32);
+        assert_eq!(progress(-1.0, 10.0).filled_segments(32), 0);
+
+        let spinning = ProgressComponent {
+            indeterminate: true,
+            ..progress(0.0, 1.0)
+        };
+        assert_eq!(spinning.filled_segments(32), 8);
+    }
+
+    #[test]
+    fn test_percentage_text_is_rounded() {
+        assert_eq!(percentage_text(&progress(2.0, 3.0)), "67%");
+        assert_eq!(percentage_text(&progress(1.0, 1.0)), "100%");
+    }
+
+    #[test]
+    fn test_empty_range_shows_no_progress() {
+        let empty = progress(0.0, 0.0);
+        assert_eq!(empty.fraction(), 0.0);
+        assert_eq!(empty.filled_segments(32), 0);
+        assert_eq!(percentage_text(&empty), "0%");
+    }
+
+    #[test]
+    fn test_reduced_motion_holds_indeterminate_progress() {
+        let mut app = test_app();
+        app.world_mut()
+            .spawn(ProgressBuilder::new().indeterminate().build());
+        let offset = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<&ProgressAnimation, With<ProgressIndicator>>()
+                .single(app.world())
+                .unwrap()
+                .offset
+        };
+
+        app.world_mut().resource_mut::<UiMotion>().reduced = true;
+        advance_frames(&mut app, 3);
+        let held = offset(&mut app);
+        advance_frames(&mut app, 5);
+        assert_eq!(offset(&mut app), held);
+
+        // Animates again once motion is allowed
+        app.world_mut().resource_mut::<UiMotion>().reduced = false;
+        advance_frames(&mut app, 2);
+        assert!(offset(&mut app) > held);
+    }
+}
@@ -1,6 +1,22 @@
+use std::time::Duration;
+
 use crate::{
+    components::{
+        a11y::{A11yState, UiA11y, UiA11yValue, UiRole},
+        control_label::{ControlInteractionChanged, ControlLabel, ControlLabelSpawner},
+        disabled::DisabledInteraction,
+        focus::Focusable,
+        sounds::MuteSounds,
+        validation::Validate,
+    },
     theme::{
-        color::{accent_palette, UiColorPalette},
+        color::{accent_palette, disabled_colors, UiColorPalette},
+        density::ui_density,
+        radix_theme::{AccentPaletteUser, ThemeTokens, UsesAccentPalette},
+    },
+    utilities::{
+        naming::Namespace,
+        transition::{color_transitions, Easing},
     },
 };
 use bevy::prelude::*;
@@ -26,7 +42,7 @@ pub struct RadioGroupValueChangeEvent {
 }
 
 /// Radio interaction state
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum RadioState {
     Normal,
     Hover,
@@ -63,7 +79,7 @@ impl Default for SelectionMode {
 }
 
 /// Radio button visual variants
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
 pub enum RadioVariant {
     /// Default background with gray border
     #[default]
@@ -75,7 +91,7 @@ pub enum RadioVariant {
 }
 
 /// Size variants for Radio component
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
 pub enum RadioSize {
     /// 14px - Compact size for dense layouts
     Size1,
@@ -87,13 +103,14 @@ pub enum RadioSize {
 }
 
 impl RadioSize {
-    /// Convert size to pixel dimensions
+    /// Convert size to pixel dimensions at the current UI density
     pub fn to_pixels(self) -> f32 {
-        match self {
+        let size = match self {
             RadioSize::Size1 => 14.0,
             RadioSize::Size2 => 16.0,
             RadioSize::Size3 => 20.0,
-        }
+        };
+        size * ui_density().control_scale()
     }
 
     /// Get indicator dot size (40% of radio size)
@@ -112,13 +129,17 @@ impl RadioSize {
 }
 
 /// Individual Radio Component
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct RadioComponent {
     pub size: RadioSize,
     pub variant: RadioVariant,
     pub checked: bool,
     pub disabled: bool,
     pub color_palette: UiColorPalette,
+    /// Whether `color_palette` follows the accent color of the theme;
+    /// cleared by an explicit palette
+    pub follows_accent: bool,
     pub current_state: RadioState,
     pub value: String,
     pub group_entity: Option<Entity>,
@@ -133,6 +154,7 @@ impl Default for RadioComponent {
             checked: false,
             disabled: false,
             color_palette: accent_palette(),
+            follows_accent: true,
             current_state: RadioState::Normal,
             value: String::new(),
             group_entity: None,
@@ -140,6 +162,35 @@ impl Default for RadioComponent {
     }
 }
 
+impl AccentPaletteUser for RadioComponent {
+    fn follows_accent(&self) -> bool {
+        self.follows_accent
+    }
+}
+
+impl A11yState for RadioComponent {
+    fn a11y_value(&self) -> UiA11yValue {
+        UiA11yValue::Checked(self.checked)
+    }
+}
+
+impl DisabledInteraction for RadioComponent {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    fn interaction_colors(&self) -> Option<(BackgroundColor, BorderColor)> {
+        let mut background = BackgroundColor::default();
+        let mut border = BorderColor::default();
+        update_radio_appearance(self, &mut background, &mut border);
+        Some((background, border))
+    }
+}
+
 /// RadioGroup Container Component
 #[derive(Component, Debug, Clone)]
 pub struct RadioGroupComponent {
@@ -154,6 +205,9 @@ pub struct RadioGroupComponent {
     pub size: RadioSize,
     pub variant: RadioVariant,
     pub color_palette: UiColorPalette,
+    /// Whether `color_palette` follows the accent color of the theme;
+    /// cleared by an explicit palette
+    pub follows_accent: bool,
 }
 
 impl Default for RadioGroupComponent {
@@ -170,8 +224,35 @@ impl Default for RadioGroupComponent {
             size: RadioSize::Size2,
             variant: RadioVariant::Surface,
             color_palette: accent_palette(),
+            follows_accent: true,
+        }
+    }
+}
+
+impl AccentPaletteUser for RadioGroupComponent {
+    fn follows_accent(&self) -> bool {
+        self.follows_accent
+    }
+}
+
+impl Validate for RadioGroupComponent {
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn validate(&self) -> bool {
+        if !self.required {
+            return true;
+        }
+        match self.selection_mode {
+            SelectionMode::Multiple => !self.selected_values.is_empty(),
+            SelectionMode::Single => self.selected_value.is_some(),
         }
     }
+
+    fn valid_border_color(&self) -> BorderColor {
+        BorderColor(Color::NONE)
+    }
 }
 
 /// Builder for creating Radio components with fluent API
@@ -180,6 +261,9 @@ pub struct RadioBuilder {
     radio_config: RadioComponent,
     node: Node,
     label_text: Option<String>,
+    a11y_label: Option<String>,
+    mute_sounds: bool,
+    transition: Option<(Duration, Easing)>,
 }
 
 impl RadioComponent {
@@ -228,6 +312,9 @@ impl RadioBuilder {
                 ..default()
             },
             label_text: None,
+            a11y_label: None,
+            mute_sounds: false,
+            transition: None,
         }
     }
 
@@ -302,6 +389,18 @@ impl RadioBuilder {
         self
     }
 
+    /// Keep the radio silent, whatever the global sound settings
+    pub fn mute_sounds(mut self) -> Self {
+        self.mute_sounds = true;
+        self
+    }
+
+    /// Fade between the state colors instead of switching at once
+    pub fn transition(mut self, duration: Duration, easing: Easing) -> Self {
+        self.transition = Some((duration, easing));
+        self
+    }
+
     // Removed allow_deselect and toggle methods - ALL radios are toggle-able by default
 
     // =========================================================================
@@ -311,20 +410,29 @@ impl RadioBuilder {
     /// Set color palette for theming
     pub fn color(mut self, palette: UiColorPalette) -> Self {
         self.radio_config.color_palette = palette;
+        self.radio_config.follows_accent = false;
         self
     }
 
     /// Use accent color palette
-    pub fn accent(self) -> Self {
-        self.color(accent_palette())
+    pub fn accent(mut self) -> Self {
+        self.radio_config.color_palette = accent_palette();
+        self.radio_config.follows_accent = true;
+        self
     }
 
-    /// Add label text to the radio
+    /// Add label text beside the radio; clicking it selects the radio
     pub fn label(mut self, text: impl Into<String>) -> Self {
         self.label_text = Some(text.into());
         self
     }
 
+    /// Overrides the [`UiA11y`] label, which defaults to the label text or the value
+    pub fn a11y_label(mut self, label: impl Into<String>) -> Self {
+        self.a11y_label = Some(label.into());
+        self
+    }
+
     // =========================================================================
     // LAYOUT METHODS
     // =========================================================================
@@ -358,7 +466,7 @@ impl RadioBuilder {
         let palette = &self.radio_config.color_palette;
         
         if self.radio_config.disabled {
-            return BackgroundColor(palette.bg_subtle.with_alpha(0.5));
+            return BackgroundColor(disabled_colors(palette).0);
         }
 
         match (self.radio_config.checked, self.radio_config.variant, self.radio_config.current_state) {
@@ -393,7 +501,7 @@ impl RadioBuilder {
         let palette = &self.radio_config.color_palette;
 
         if self.radio_config.disabled {
-            return BorderColor(palette.border.with_alpha(0.5));
+            return BorderColor(disabled_colors(palette).1);
         }
 
         match (self.radio_config.checked, self.radio_config.current_state) {
@@ -416,6 +524,13 @@ impl RadioBuilder {
         let background_color = self.calculate_background_color();
         let border_color = self.calculate_border_color();
         let border_radius = self.calculate_border_radius();
+        let focusable = Focusable::new().disabled(self.radio_config.disabled);
+        let a11y_label = self
+            .a11y_label
+            .or_else(|| self.label_text.clone())
+            .unwrap_or_else(|| self.radio_config.value.clone());
+        let a11y =
+            UiA11y::new(UiRole::Radio, a11y_label).value(self.radio_config.a11y_value());
 
         (
             Name::new(self.name),
@@ -425,6 +540,11 @@ impl RadioBuilder {
             border_color,
             border_radius,
             Pickable::default(),
+            focusable,
+            MuteSounds(self.mute_sounds),
+            color_transitions(self.transition),
+            ControlLabelSpawner(self.label_text),
+            a11y,
             Button,
             Interaction::None,
         )
@@ -515,9 +635,11 @@ impl RadioGroupBuilder {
         self
     }
 
-    /// Set radio group as required for forms
+    /// Set radio group as required for forms; it is valid once a value is
+    /// selected and shows its errors as a border around the group
     pub fn required(mut self) -> Self {
         self.group_config.required = true;
+        self.node.border = UiRect::all(Val::Px(1.0));
         self
     }
 
@@ -600,12 +722,15 @@ impl RadioGroupBuilder {
     /// Set color palette for all radio items in group
     pub fn color(mut self, palette: UiColorPalette) -> Self {
         self.group_config.color_palette = palette;
+        self.group_config.follows_accent = false;
         self
     }
 
     /// Use accent color palette
-    pub fn accent(self) -> Self {
-        self.color(accent_palette())
+    pub fn accent(mut self) -> Self {
+        self.group_config.color_palette = accent_palette();
+        self.group_config.follows_accent = true;
+        self
     }
 
     // =========================================================================
@@ -655,6 +780,7 @@ impl RadioGroupBuilder {
             Name::new(self.name),
             self.group_config,
             self.node,
+            BorderColor(Color::NONE),
         )
     }
 }
@@ -669,20 +795,22 @@ pub struct RadioIndicatorComponent;
 
 /// System to handle radio interactions
 pub fn handle_radio_interactions(
-    mut interaction_query: Query<
-        (
-            Entity,
-            &Interaction,
-            &mut RadioComponent,
-            &mut BackgroundColor,
-            &mut BorderColor,
-        ),
-        (With<RadioComponent>, Changed<Interaction>),
-    >,
+    interaction_query: Query<(Entity, &Interaction), ControlInteractionChanged<RadioComponent>>,
+    label_query: Query<(&ControlLabel, &Interaction), Changed<Interaction>>,
+    mut radio_query: Query<(&mut RadioComponent, &mut BackgroundColor, &mut BorderColor)>,
     mut radio_events: EventWriter<RadioChangeEvent>,
     radio_group_query: Query<&RadioGroupComponent>,
 ) {
-    for (entity, interaction, mut radio, mut bg_color, mut border_color) in &mut interaction_query {
+    // Label interactions count as interactions with their radio
+    let interactions = interaction_query.iter().chain(
+        label_query
+            .iter()
+            .map(|(label, interaction)| (label.control, interaction)),
+    );
+    for (entity, interaction) in interactions {
+        let Ok((mut radio, mut bg_color, mut border_color)) = radio_query.get_mut(entity) else {
+            continue;
+        };
         if radio.disabled {
             continue;
         }
@@ -978,8 +1106,9 @@ fn find_and_link_radios_recursive(
                 if radio.variant == RadioVariant::Surface && group.variant != RadioVariant::Surface {
                     radio.variant = group.variant;
                 }
-                if radio.color_palette.solid == accent_palette().solid && group.color_palette.solid != accent_palette().solid {
+                if radio.follows_accent && !group.follows_accent {
                     radio.color_palette = group.color_palette.clone();
+                    radio.follows_accent = false;
                 }
                 
                 // Set initial checked state based on group's default_value
@@ -1006,6 +1135,54 @@ fn find_and_link_radios_recursive(
     }
 }
 
+/// Colors of a radio refreshed by [`refresh_radio_accent`].
+type RadioAccentState = (
+    &'static mut RadioComponent,
+    &'static mut BackgroundColor,
+    &'static mut BorderColor,
+    Option<&'static Children>,
+);
+
+/// System to re-apply the accent palette to radios and radio groups following
+/// it after the accent color of the `RadixTheme` changed
+pub fn refresh_radio_accent(
+    tokens: Res<ThemeTokens>,
+    mut group_query: Query<&mut RadioGroupComponent, With<UsesAccentPalette>>,
+    mut radio_query: Query<
+        RadioAccentState,
+        (With<UsesAccentPalette>, Without<RadioIndicatorComponent>),
+    >,
+    mut indicator_query: Query<&mut BackgroundColor, With<RadioIndicatorComponent>>,
+) {
+    for mut group in &mut group_query {
+        if group.color_palette != tokens.accent {
+            group.color_palette = tokens.accent.clone();
+        }
+    }
+
+    for (mut radio, mut bg_color, mut border_color, children) in &mut radio_query {
+        // Radios tagged before taking an explicit palette from their group
+        if !radio.follows_accent || radio.color_palette == tokens.accent {
+            continue;
+        }
+        radio.color_palette = tokens.accent.clone();
+        update_radio_appearance(&radio, &mut bg_color, &mut border_color);
+
+        // Only soft indicators are drawn with a palette color
+        if radio.variant != RadioVariant::Soft {
+            continue;
+        }
+        let Some(children) = children else {
+            continue;
+        };
+        for child in children.iter() {
+            if let Ok(mut indicator_color) = indicator_query.get_mut(child) {
+                indicator_color.0 = radio.color_palette.text_contrast;
+            }
+        }
+    }
+}
+
 /// Helper function to update radio visual appearance
 fn update_radio_appearance(
     radio: &RadioComponent,
@@ -1016,7 +1193,7 @@ fn update_radio_appearance(
     
     // Update background color
     *bg_color = if radio.disabled {
-        BackgroundColor(palette.bg_subtle.with_alpha(0.5))
+        BackgroundColor(disabled_colors(palette).0)
     } else {
         match (radio.checked, radio.variant, radio.current_state) {
             // Checked states
@@ -1047,7 +1224,7 @@ fn update_radio_appearance(
 
     // Update border color
     *border_color = if radio.disabled {
-        BorderColor(palette.border.with_alpha(0.5))
+        BorderColor(disabled_colors(palette).1)
     } else {
         match (radio.checked, radio.current_state) {
             (true, _) => BorderColor(palette.solid),
@@ -1097,4 +1274,16 @@ fn spawn_radio_indicator(
 
 // Convenience type aliases
 pub type Radio = RadioComponent;
-pub type RadioGroup = RadioGroupComponent;
\ No newline at end of file
+pub type RadioGroup = RadioGroupComponent;
+
+impl Namespace for RadioBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+impl Namespace for RadioGroupBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
@@ -0,0 +1,653 @@
+use crate::theme::{
+    color::UiColorPalette,
+    radix_theme::{SystemColors, ThemeTokens},
+};
+use crate::utilities::naming::Namespace;
+use bevy::{input::mouse::MouseScrollUnit, prelude::*};
+use bevy_picking::prelude::{Drag, DragEnd, DragStart, Out, Over, Pickable, Pointer, Scroll};
+
+/// Pixels scrolled per wheel "line" when the input device reports line units.
+const SCROLL_LINE_HEIGHT: f32 = 21.0;
+
+/// Which axes of a scroll area can be scrolled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollAreaDirection {
+    /// Content scrolls top to bottom only
+    #[default]
+    Vertical,
+    /// Content scrolls left to right only
+    Horizontal,
+    /// Content scrolls along both axes
+    Both,
+}
+
+impl ScrollAreaDirection {
+    pub fn scrolls_x(self) -> bool {
+        matches!(self, ScrollAreaDirection::Horizontal | ScrollAreaDirection::Both)
+    }
+
+    pub fn scrolls_y(self) -> bool {
+        matches!(self, ScrollAreaDirection::Vertical | ScrollAreaDirection::Both)
+    }
+}
+
+/// Controls when the themed scrollbars are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollbarVisibility {
+    /// Scrollbars appear only when the content overflows the viewport
+    #[default]
+    Auto,
+    /// Scrollbars are always visible, even without overflow
+    Always,
+    /// Scrollbars appear while the pointer is over an overflowing scroll area
+    Hover,
+}
+
+/// A single scrollbar axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollAxis {
+    Horizontal,
+    Vertical,
+}
+
+/// Root component of a scroll area.
+///
+/// The root clips its children and hosts two layers: the scrolling viewport
+/// (marked with [`ScrollAreaViewport`]) and absolutely positioned scrollbar
+/// tracks that are drawn on top of the content.
+#[derive(Component, Debug, Clone)]
+pub struct ScrollAreaComponent {
+    pub direction: ScrollAreaDirection,
+    pub scrollbar_visibility: ScrollbarVisibility,
+    /// Thickness of the scrollbar tracks in pixels
+    pub scrollbar_size: f32,
+    /// Palette used for the track and the idle thumb; `None` uses the
+    /// theme's [`SystemColors`]
+    pub track_color: Option<UiColorPalette>,
+    /// Palette used for the thumb while hovered or dragged; `None` uses the
+    /// theme's [`SystemColors`]
+    pub thumb_color: Option<UiColorPalette>,
+    /// Whether the pointer is currently over the scroll area
+    pub hovered: bool,
+}
+
+impl Default for ScrollAreaComponent {
+    fn default() -> Self {
+        Self {
+            direction: ScrollAreaDirection::Vertical,
+            scrollbar_visibility: ScrollbarVisibility::Auto,
+            scrollbar_size: 8.0,
+            track_color: None,
+            thumb_color: None,
+            hovered: false,
+        }
+    }
+}
+
+/// Marker for the node that actually scrolls (carries `Overflow::scroll`).
+#[derive(Component, Debug)]
+pub struct ScrollAreaViewport {
+    pub area: Entity,
+}
+
+/// Scrollbar track, positioned along one edge of the scroll area.
+#[derive(Component, Debug)]
+pub struct ScrollbarTrack {
+    pub area: Entity,
+    pub axis: ScrollAxis,
+}
+
+/// Draggable scrollbar thumb living inside a [`ScrollbarTrack`].
+#[derive(Component, Debug)]
+pub struct ScrollbarThumb {
+    pub area: Entity,
+    pub viewport: Entity,
+    pub axis: ScrollAxis,
+    pub hovered: bool,
+    pub is_dragging: bool,
+}
+
+impl ScrollAreaComponent {
+    pub fn new(name: impl Into<String>) -> ScrollAreaBuilder {
+        ScrollAreaBuilder::new(name)
+    }
+
+    /// Track, idle thumb and active thumb colors: from the custom palettes if
+    /// set, else from `system`.
+    pub fn scrollbar_colors(&self, system: &SystemColors) -> (Color, Color, Color) {
+        let track = self.track_color.as_ref();
+        let thumb = self.thumb_color.as_ref();
+        (
+            track.map_or(system.scrollbar_track, |palette| palette.bg_subtle),
+            track.map_or(system.scrollbar_thumb, |palette| palette.border_hover),
+            thumb.map_or(system.scrollbar_thumb_active, |palette| palette.solid_hover),
+        )
+    }
+}
+
+pub struct ScrollAreaBuilder {
+    name: String,
+    scroll_area: ScrollAreaComponent,
+    node: Node,
+    children: Vec<Entity>,
+}
+
+impl ScrollAreaBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: format!("{}_ScrollArea", name.into()),
+            scroll_area: ScrollAreaComponent::default(),
+            node: Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            children: Vec::new(),
+        }
+    }
+
+    pub fn direction(mut self, direction: ScrollAreaDirection) -> Self {
+        self.scroll_area.direction = direction;
+        self
+    }
+
+    pub fn vertical(self) -> Self {
+        self.direction(ScrollAreaDirection::Vertical)
+    }
+
+    pub fn horizontal(self) -> Self {
+        self.direction(ScrollAreaDirection::Horizontal)
+    }
+
+    pub fn both(self) -> Self {
+        self.direction(ScrollAreaDirection::Both)
+    }
+
+    pub fn scrollbar_visibility(mut self, visibility: ScrollbarVisibility) -> Self {
+        self.scroll_area.scrollbar_visibility = visibility;
+        self
+    }
+
+    pub fn scrollbar_size(mut self, size: f32) -> Self {
+        self.scroll_area.scrollbar_size = size.max(2.0);
+        self
+    }
+
+    pub fn track_color(mut self, color: UiColorPalette) -> Self {
+        self.scroll_area.track_color = Some(color);
+        self
+    }
+
+    pub fn thumb_color(mut self, color: UiColorPalette) -> Self {
+        self.scroll_area.thumb_color = Some(color);
+        self
+    }
+
+    pub fn width(mut self, width: Val) -> Self {
+        self.node.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Val) -> Self {
+        self.node.height = height;
+        self
+    }
+
+    pub fn max_height(mut self, max_height: Val) -> Self {
+        self.node.max_height = max_height;
+        self
+    }
+
+    pub fn max_width(mut self, max_width: Val) -> Self {
+        self.node.max_width = max_width;
+        self
+    }
+
+    /// Adds an already spawned entity to the scrolling content.
+    pub fn child(mut self, entity: Entity) -> Self {
+        self.children.push(entity);
+        self
+    }
+
+    pub fn children(mut self, entities: impl IntoIterator<Item = Entity>) -> Self {
+        self.children.extend(entities);
+        self
+    }
+}
+
+impl ScrollAreaBuilder {
+    fn calculate_viewport_style(&self) -> Node {
+        let direction = self.scroll_area.direction;
+        let overflow = match direction {
+            ScrollAreaDirection::Vertical => Overflow::scroll_y(),
+            ScrollAreaDirection::Horizontal => Overflow::scroll_x(),
+            ScrollAreaDirection::Both => Overflow::scroll(),
+        };
+
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: match direction {
+                ScrollAreaDirection::Horizontal => FlexDirection::Row,
+                _ => FlexDirection::Column,
+            },
+            overflow,
+            ..default()
+        }
+    }
+
+    fn calculate_track_style(&self, axis: ScrollAxis) -> Node {
+        let size = Val::Px(self.scroll_area.scrollbar_size);
+        // Leave room for the other track in the corner when both axes scroll
+        let corner = if self.scroll_area.direction == ScrollAreaDirection::Both {
+            size
+        } else {
+            Val::Px(0.0)
+        };
+
+        match axis {
+            ScrollAxis::Vertical => Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                right: Val::Px(0.0),
+                bottom: corner,
+                width: size,
+                ..default()
+            },
+            ScrollAxis::Horizontal => Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                right: corner,
+                bottom: Val::Px(0.0),
+                height: size,
+                ..default()
+            },
+        }
+    }
+
+    fn calculate_thumb_style(&self, axis: ScrollAxis) -> Node {
+        match axis {
+            ScrollAxis::Vertical => Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Px(0.0),
+                top: Val::Px(0.0),
+                ..default()
+            },
+            ScrollAxis::Horizontal => Node {
+                position_type: PositionType::Absolute,
+                height: Val::Percent(100.0),
+                width: Val::Px(0.0),
+                left: Val::Px(0.0),
+                ..default()
+            },
+        }
+    }
+
+    fn calculate_border_radius(&self) -> BorderRadius {
+        BorderRadius::all(Val::Px(self.scroll_area.scrollbar_size / 2.0))
+    }
+}
+
+impl ScrollAreaBuilder {
+    /// Spawns the scroll area and returns the root entity.
+    ///
+    /// Entities passed via [`child`](Self::child) are re-parented into the
+    /// scrolling viewport.
+    pub fn build(self, commands: &mut Commands) -> Entity {
+        let viewport_node = self.calculate_viewport_style();
+        let radius = self.calculate_border_radius();
+        let direction = self.scroll_area.direction;
+        // Corrected to the current theme by `update_scroll_area_scrollbars`
+        let (track_color, thumb_color, _) = self
+            .scroll_area
+            .scrollbar_colors(&ThemeTokens::default().system);
+        let track_bg = BackgroundColor(track_color);
+        let thumb_bg = BackgroundColor(thumb_color);
+
+        let mut root_node = self.node.clone();
+        root_node.position_type = PositionType::Relative;
+        root_node.overflow = Overflow::clip();
+
+        let root = commands
+            .spawn((
+                Name::new(self.name.clone()),
+                self.scroll_area.clone(),
+                root_node,
+                Pickable::default(),
+            ))
+            .observe(on_scroll_area_hover_start)
+            .observe(on_scroll_area_hover_end)
+            .observe(on_scroll_area_wheel)
+            .id();
+
+        let viewport = commands
+            .spawn((
+                Name::new(format!("{}_Viewport", self.name)),
+                ScrollAreaViewport { area: root },
+                viewport_node,
+                ScrollPosition::default(),
+                Pickable::default(),
+            ))
+            .add_children(&self.children)
+            .id();
+        commands.entity(root).add_child(viewport);
+
+        let mut axes = Vec::new();
+        if direction.scrolls_y() {
+            axes.push(ScrollAxis::Vertical);
+        }
+        if direction.scrolls_x() {
+            axes.push(ScrollAxis::Horizontal);
+        }
+
+        for axis in axes {
+            let thumb = commands
+                .spawn((
+                    Name::new(format!("{}_Thumb{:?}", self.name, axis)),
+                    ScrollbarThumb {
+                        area: root,
+                        viewport,
+                        axis,
+                        hovered: false,
+                        is_dragging: false,
+                    },
+                    self.calculate_thumb_style(axis),
+                    thumb_bg,
+                    radius,
+                    Pickable::default(),
+                ))
+                .observe(on_thumb_hover_start)
+                .observe(on_thumb_hover_end)
+                .observe(on_thumb_drag_start)
+                .observe(on_thumb_drag)
+                .observe(on_thumb_drag_end)
+                .id();
+
+            let track = commands
+                .spawn((
+                    Name::new(format!("{}_Track{:?}", self.name, axis)),
+                    ScrollbarTrack { area: root, axis },
+                    self.calculate_track_style(axis),
+                    track_bg,
+                    radius,
+                    Visibility::Hidden,
+                    Pickable::default(),
+                ))
+                .add_child(thumb)
+                .id();
+            commands.entity(root).add_child(track);
+        }
+
+        root
+    }
+}
+
+/// Length and offset of a scrollbar thumb along its track.
+///
+/// Returns `None` when the content fits inside the viewport.
+pub fn scrollbar_thumb_metrics(
+    viewport_len: f32,
+    content_len: f32,
+    track_len: f32,
+    scroll_offset: f32,
+    min_thumb_len: f32,
+) -> Option<(f32, f32)> {
+    let max_scroll = content_len - viewport_len;
+    if max_scroll <= 0.5 || track_len <= 0.0 {
+        return None;
+    }
+
+    let thumb_len = (track_len * viewport_len / content_len)
+        .max(min_thumb_len)
+        .min(track_len);
+    let progress = (scroll_offset / max_scroll).clamp(0.0, 1.0);
+
+    Some((thumb_len, (track_len - thumb_len) * progress))
+}
+
+/// Syncs thumb size/position and track visibility with the viewport's
+/// content size and current [`ScrollPosition`].
+pub fn update_scroll_area_scrollbars(
+    tokens: Res<ThemeTokens>,
+    areas: Query<&ScrollAreaComponent>,
+    viewports: Query<(&ComputedNode, &ScrollPosition), With<ScrollAreaViewport>>,
+    mut tracks: Query<
+        (
+            &ScrollbarTrack,
+            &ComputedNode,
+            &mut Visibility,
+            &mut BackgroundColor,
+            &Children,
+        ),
+        Without<ScrollAreaViewport>,
+    >,
+    mut thumbs: Query<(&ScrollbarThumb, &mut Node, &mut BackgroundColor), Without<ScrollbarTrack>>,
+) {
+    for (track, track_computed, mut visibility, mut track_background, children) in &mut tracks {
+        let Ok(area) = areas.get(track.area) else {
+            continue;
+        };
+        let (track_color, idle_thumb_color, active_thumb_color) =
+            area.scrollbar_colors(&tokens.system);
+        if track_background.0 != track_color {
+            track_background.0 = track_color;
+        }
+
+        for child in children.iter() {
+            let Ok((thumb, mut node, mut background)) = thumbs.get_mut(child) else {
+                continue;
+            };
+            let Ok((viewport_computed, scroll)) = viewports.get(thumb.viewport) else {
+                continue;
+            };
+
+            // ComputedNode sizes are physical pixels; Node and ScrollPosition are logical
+            let scale = viewport_computed.inverse_scale_factor();
+            let viewport_size = viewport_computed.size() * scale;
+            let content_size = viewport_computed.content_size() * scale;
+            let track_size = track_computed.size() * track_computed.inverse_scale_factor();
+
+            let (viewport_len, content_len, track_len, offset) = match thumb.axis {
+                ScrollAxis::Vertical => (
+                    viewport_size.y,
+                    content_size.y,
+                    track_size.y,
+                    scroll.offset_y,
+                ),
+                ScrollAxis::Horizontal => (
+                    viewport_size.x,
+                    content_size.x,
+                    track_size.x,
+                    scroll.offset_x,
+                ),
+            };
+
+            let metrics = scrollbar_thumb_metrics(
+                viewport_len,
+                content_len,
+                track_len,
+                offset,
+                area.scrollbar_size * 2.0,
+            );
+            let overflowing = metrics.is_some();
+            let (thumb_len, thumb_offset) = metrics.unwrap_or((track_len, 0.0));
+
+            match thumb.axis {
+                ScrollAxis::Vertical => {
+                    node.height = Val::Px(thumb_len);
+                    node.top = Val::Px(thumb_offset);
+                }
+                ScrollAxis::Horizontal => {
+                    node.width = Val::Px(thumb_len);
+                    node.left = Val::Px(thumb_offset);
+                }
+            }
+
+            let active = thumb.hovered || thumb.is_dragging;
+            let thumb_color = if active {
+                active_thumb_color
+            } else {
+                idle_thumb_color
+            };
+            if background.0 != thumb_color {
+                background.0 = thumb_color;
+            }
+
+            let visible = match area.scrollbar_visibility {
+                ScrollbarVisibility::Always => true,
+                ScrollbarVisibility::Auto => overflowing,
+                ScrollbarVisibility::Hover => overflowing && (area.hovered || active),
+            };
+            let target = if visible {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+            if *visibility != target {
+                *visibility = target;
+            }
+        }
+    }
+}
+
+fn scroll_viewport_by(
+    viewport: Entity,
+    delta: Vec2,
+    viewports: &mut Query<(&ComputedNode, &mut ScrollPosition), With<ScrollAreaViewport>>,
+) {
+    let Ok((computed, mut scroll)) = viewports.get_mut(viewport) else {
+        return;
+    };
+
+    let scale = computed.inverse_scale_factor();
+    let max_scroll = ((computed.content_size() - computed.size()) * scale).max(Vec2::ZERO);
+
+    scroll.offset_x = (scroll.offset_x + delta.x).clamp(0.0, max_scroll.x);
+    scroll.offset_y = (scroll.offset_y + delta.y).clamp(0.0, max_scroll.y);
+}
+
+fn on_scroll_area_hover_start(
+    trigger: Trigger<Pointer<Over>>,
+    mut areas: Query<&mut ScrollAreaComponent>,
+) {
+    if let Ok(mut area) = areas.get_mut(trigger.target()) {
+        area.hovered = true;
+    }
+}
+
+fn on_scroll_area_hover_end(
+    trigger: Trigger<Pointer<Out>>,
+    mut areas: Query<&mut ScrollAreaComponent>,
+) {
+    if let Ok(mut area) = areas.get_mut(trigger.target()) {
+        area.hovered = false;
+    }
+}
+
+fn on_scroll_area_wheel(
+    mut trigger: Trigger<Pointer<Scroll>>,
+    areas: Query<(&ScrollAreaComponent, &Children)>,
+    viewport_markers: Query<(), With<ScrollAreaViewport>>,
+    mut viewports: Query<(&ComputedNode, &mut ScrollPosition), With<ScrollAreaViewport>>,
+) {
+    let Ok((area, children)) = areas.get(trigger.target()) else {
+        return;
+    };
+    // Nested scroll areas: the innermost one consumes the wheel event
+    trigger.propagate(false);
+
+    let event = trigger.event();
+    let mut delta = Vec2::new(-event.x, -event.y);
+    if event.unit == MouseScrollUnit::Line {
+        delta *= SCROLL_LINE_HEIGHT;
+    }
+
+    match area.direction {
+        // Vertical wheel input scrolls horizontal-only areas sideways
+        ScrollAreaDirection::Horizontal => delta = Vec2::new(delta.x + delta.y, 0.0),
+        ScrollAreaDirection::Vertical => delta.x = 0.0,
+        ScrollAreaDirection::Both => {}
+    }
+
+    for child in children.iter() {
+        if viewport_markers.contains(child) {
+            scroll_viewport_by(child, delta, &mut viewports);
+        }
+    }
+}
+
+fn on_thumb_hover_start(trigger: Trigger<Pointer<Over>>, mut thumbs: Query<&mut ScrollbarThumb>) {
+    if let Ok(mut thumb) = thumbs.get_mut(trigger.target()) {
+        thumb.hovered = true;
+    }
+}
+
+fn on_thumb_hover_end(trigger: Trigger<Pointer<Out>>, mut thumbs: Query<&mut ScrollbarThumb>) {
+    if let Ok(mut thumb) = thumbs.get_mut(trigger.target()) {
+        thumb.hovered = false;
+    }
+}
+
+fn on_thumb_drag_start(
+    mut trigger: Trigger<Pointer<DragStart>>,
+    mut thumbs: Query<&mut ScrollbarThumb>,
+) {
+    trigger.propagate(false);
+    if let Ok(mut thumb) = thumbs.get_mut(trigger.target()) {
+        thumb.is_dragging = true;
+    }
+}
+
+fn on_thumb_drag_end(
+    mut trigger: Trigger<Pointer<DragEnd>>,
+    mut thumbs: Query<&mut ScrollbarThumb>,
+) {
+    trigger.propagate(false);
+    if let Ok(mut thumb) = thumbs.get_mut(trigger.target()) {
+        thumb.is_dragging = false;
+    }
+}
+
+fn on_thumb_drag(
+    mut trigger: Trigger<Pointer<Drag>>,
+    thumbs: Query<(&ScrollbarThumb, &ComputedNode, &ChildOf)>,
+    tracks: Query<&ComputedNode, With<ScrollbarTrack>>,
+    mut viewports: Query<(&ComputedNode, &mut ScrollPosition), With<ScrollAreaViewport>>,
+) {
+    trigger.propagate(false);
+    let Ok((thumb, thumb_computed, child_of)) = thumbs.get(trigger.target()) else {
+        return;
+    };
+    let Ok(track_computed) = tracks.get(child_of.parent()) else {
+        return;
+    };
+    let Ok((viewport_computed, _)) = viewports.get(thumb.viewport) else {
+        return;
+    };
+
+    // Map pointer movement along the free track space onto the scrollable range
+    let free_track = track_computed.size() - thumb_computed.size();
+    let max_scroll = viewport_computed.content_size() - viewport_computed.size();
+    let drag = trigger.event().delta;
+
+    let delta = match thumb.axis {
+        ScrollAxis::Vertical if free_track.y > 0.0 => {
+            Vec2::new(0.0, drag.y * max_scroll.y / free_track.y)
+        }
+        ScrollAxis::Horizontal if free_track.x > 0.0 => {
+            Vec2::new(drag.x * max_scroll.x / free_track.x, 0.0)
+        }
+        _ => return,
+    };
+
+    scroll_viewport_by(thumb.viewport, delta, &mut viewports);
+}
+
+pub type ScrollArea = ScrollAreaComponent;
+
+impl Namespace for ScrollAreaBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
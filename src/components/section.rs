@@ -128,6 +128,16 @@
 //!
 //! ## Accessibility Features
 //!
+//! A section marked with `.landmark()` or named with `.label()` carries a
+//! [`UiA11y`] with the [`UiRole::Region`] role, so page areas can be found and
+//! announced by name:
+//!
+//! ```rust
+//! let settings = Section::size_3("settings")
+//!     .label("Account settings")
+//!     .build();
+//! ```
+//!
 //! - **Semantic Structure**: Provides meaningful content organization
 //! - **Consistent Spacing**: Predictable layout aids navigation
 //! - **Focus Management**: Proper tab order and focus handling
@@ -141,11 +151,18 @@
 //! - Lazy evaluation of colors and styling properties
 
 use crate::{
-    components::box_component::{RadiusLevel, SpacingLevel},
+    components::{
+        a11y::{UiA11y, UiRole},
+        box_component::{RadiusLevel, SpacingLevel},
+    },
     theme::{
         color::{accent_palette, UiColorPalette},
         layout::UiLayout,
     },
+    utilities::{
+        naming::Namespace,
+        node_edit::{NodeEdits, WithNode},
+    },
 };
 use bevy::prelude::*;
 use bevy_picking::prelude::Pickable;
@@ -180,6 +197,10 @@ pub struct SectionComponent {
     pub color_palette: UiColorPalette,
     /// Additional styling configuration for backgrounds, borders, and effects
     pub styling_config: SectionStyling,
+    /// Whether the section is a region landmark for accessibility
+    pub landmark: bool,
+    /// Accessible name of the landmark
+    pub label: Option<String>,
 }
 
 impl Default for SectionComponent {
@@ -194,6 +215,8 @@ impl Default for SectionComponent {
             size: SectionSize::Size3, // Default to medium spacing
             color_palette: accent_palette(),
             styling_config: SectionStyling::default(),
+            landmark: false,
+            label: None,
         }
     }
 }
@@ -331,6 +354,10 @@ pub struct SectionBuilder {
     node: Node,
     /// Tracking for explicit color overrides
     explicit_colors: ExplicitColors,
+    /// Initial visibility of the section
+    visibility: Visibility,
+    /// Raw node edits applied last
+    node_edits: NodeEdits,
 }
 
 /// Internal tracking for explicit color overrides.
@@ -484,6 +511,8 @@ impl SectionBuilder {
                 ..default()
             },
             explicit_colors: ExplicitColors::default(),
+            visibility: Visibility::Inherited,
+            node_edits: NodeEdits::default(),
         }
     }
 
@@ -610,6 +639,30 @@ impl SectionBuilder {
     // SPACING METHODS (ADDITIONAL TO SIZE-BASED PADDING)
     // =========================================================================
 
+    /// Builds the node visible or hidden; hidden nodes keep their space in
+    /// the layout. See [`ShowWhen`](crate::utilities::ShowWhen) to follow an
+    /// app state instead.
+    pub fn visible_if(mut self, visible: bool) -> Self {
+        self.visibility = if visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        self
+    }
+
+    /// Marks the section as a region landmark in its [`UiA11y`] metadata.
+    pub fn landmark(mut self) -> Self {
+        self.section_config.landmark = true;
+        self
+    }
+
+    /// Sets the accessible name of the section, making it a landmark.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.section_config.label = Some(label.into());
+        self.landmark()
+    }
+
     /// Add horizontal padding (in addition to vertical spacing)
     pub fn padding_x(mut self, padding: Val) -> Self {
         self.node.padding.left = padding;
@@ -794,10 +847,11 @@ impl SectionBuilder {
 }
 
 impl SectionBuilder {
-    pub fn build(self) -> impl Bundle {
+    pub fn build(mut self) -> impl Bundle {
         let background_color = self.calculate_background_color();
         let border_color = self.calculate_border_color();
         let border_radius = self.calculate_border_radius();
+        self.node_edits.apply(&mut self.node);
 
         (
             Name::new(self.name),
@@ -807,10 +861,29 @@ impl SectionBuilder {
             border_color,
             border_radius,
             Pickable::default(),
+            self.visibility,
         )
     }
 }
 
+/// Keeps the [`UiA11y`] landmark of sections in sync with their
+/// `landmark` flag and label.
+pub fn sync_section_landmarks(
+    mut commands: Commands,
+    sections: Query<(Entity, &SectionComponent, Option<&UiA11y>), Changed<SectionComponent>>,
+) {
+    for (entity, section, a11y) in &sections {
+        if section.landmark {
+            let landmark = UiA11y::new(UiRole::Region, section.label.clone().unwrap_or_default());
+            if a11y != Some(&landmark) {
+                commands.entity(entity).insert(landmark);
+            }
+        } else if a11y.is_some_and(|a11y| a11y.role == UiRole::Region) {
+            commands.entity(entity).remove::<UiA11y>();
+        }
+    }
+}
+
 /// Convenience type alias for SectionComponent.
 /// 
 /// This allows using `Section` instead of `SectionComponent` in most contexts,
@@ -822,4 +895,63 @@ impl SectionBuilder {
 /// 
 /// let my_section = Section::size_3("content").build();
 /// ```
-pub type Section = SectionComponent;
\ No newline at end of file
+pub type Section = SectionComponent;
+impl Namespace for SectionBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+impl WithNode for SectionBuilder {
+    fn node_edits_mut(&mut self) -> &mut NodeEdits {
+        &mut self.node_edits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_sizes_set_vertical_padding() {
+        let mut world = World::new();
+        for (builder, expected) in [
+            (Section::size_1("compact"), 16.0),
+            (Section::size_2("small"), 24.0),
+            (Section::size_3("default"), 32.0),
+            (Section::size_4("hero"), 48.0),
+        ] {
+            let section = world.spawn(builder.build()).id();
+            let padding = world.get::<Node>(section).unwrap().padding;
+            assert_eq!(padding.top, Val::Px(expected));
+            assert_eq!(padding.bottom, Val::Px(expected));
+        }
+    }
+
+    #[test]
+    fn test_labelled_section_is_region_landmark() {
+        let mut world = World::new();
+        let labelled = world
+            .spawn(
+                Section::size_3("settings")
+                    .label("Account settings")
+                    .build(),
+            )
+            .id();
+        let plain = world.spawn(Section::size_3("body").build()).id();
+        world.run_system_once(sync_section_landmarks).unwrap();
+
+        let a11y = world.get::<UiA11y>(labelled).unwrap();
+        assert_eq!(a11y.role, UiRole::Region);
+        assert_eq!(a11y.label, "Account settings");
+        assert!(world.get::<UiA11y>(plain).is_none());
+
+        world
+            .get_mut::<SectionComponent>(labelled)
+            .unwrap()
+            .landmark = false;
+        world.run_system_once(sync_section_landmarks).unwrap();
+        assert!(world.get::<UiA11y>(labelled).is_none());
+    }
+}
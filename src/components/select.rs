@@ -1,9 +1,21 @@
-use crate::components::text::Text;
+use crate::components::{
+    binding::{value_binder, ValueBinder, ValueBindingSpawner},
+    disabled::DisabledInteraction,
+    dismiss::{DismissEvent, Dismissable},
+    scroll_area::{ScrollAreaBuilder, ScrollAreaViewport},
+    text::Text,
+};
 use crate::theme::color::{
-    theme_mode, ThemeMode, UiColorPalette, UiColorPalettes, UiColorPalettesName,
+    disabled_color, theme_mode, ThemeMode, UiColorPalette, UiColorPalettes, UiColorPalettesName,
+};
+use crate::utilities::{
+    overlay::{close_overlay, OverlayAnimation, OverlayTransition},
+    typeahead::{typeahead_match, Typeahead},
 };
+use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
+use bevy::window::PrimaryWindow;
 use bevy_picking::prelude::{Click, Out, Over, Pickable, Pointer};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -39,8 +51,26 @@ pub struct SelectComponent {
     pub color: UiColorPalettesName,
     pub state: SelectState,
     pub options: Vec<(String, String)>, // (value, label) pairs
+    /// Rows of the dropdown; empty shows `options` as a flat list
+    pub items: Vec<SelectItem>,
+    /// Index of the row highlighted with the keyboard
+    pub highlighted: Option<usize>,
+    /// Letters typed to jump to an option
+    pub typeahead: Typeahead,
+    /// Height the dropdown is capped at, scrolling its rows beyond it;
+    /// `None` fits eight options
+    pub max_dropdown_height: Option<f32>,
+    /// Enter and exit animation of the dropdown; `None` shows and removes it
+    /// at once
+    pub animation: Option<OverlayAnimation>,
 }
 
+/// Options the dropdown shows before it scrolls, unless capped otherwise
+const DEFAULT_VISIBLE_OPTIONS: f32 = 8.0;
+
+/// Padding plus border of the dropdown, above and below its rows
+const DROPDOWN_CHROME: f32 = 2.0 * (4.0 + 1.0);
+
 impl Default for SelectComponent {
     fn default() -> Self {
         Self {
@@ -53,10 +83,98 @@ impl Default for SelectComponent {
             color: UiColorPalettesName::Gray,
             state: SelectState::Closed,
             options: Vec::new(),
+            items: Vec::new(),
+            highlighted: None,
+            typeahead: Typeahead::default(),
+            max_dropdown_height: None,
+            animation: None,
         }
     }
 }
 
+/// An option of a select group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectOption {
+    pub value: String,
+    pub label: String,
+}
+
+impl SelectOption {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+        }
+    }
+}
+
+/// One row of a select dropdown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectItem {
+    /// A selectable option; grouped options are indented below their header
+    Option {
+        value: String,
+        label: String,
+        grouped: bool,
+    },
+    /// Non-selectable header of a group, like `<optgroup>`
+    GroupLabel(String),
+    /// A divider line
+    Separator,
+}
+
+impl SelectItem {
+    /// Whether the row can be highlighted and selected.
+    pub fn is_selectable(&self) -> bool {
+        matches!(self, SelectItem::Option { .. })
+    }
+}
+
+/// Index of the next selectable row after `from` in `step` direction,
+/// wrapping around; without `from`, the first (or last) selectable row.
+pub fn next_select_item(items: &[SelectItem], from: Option<usize>, step: isize) -> Option<usize> {
+    let len = items.len() as isize;
+    if len == 0 {
+        return None;
+    }
+    let start = match from {
+        Some(from) => from as isize,
+        None if step > 0 => -1,
+        None => len,
+    };
+    (1..=len)
+        .map(|offset| (start + offset * step.signum()).rem_euclid(len) as usize)
+        .find(|index| items[*index].is_selectable())
+}
+
+impl Dismissable for SelectComponent {
+    fn is_open(&self) -> bool {
+        self.open
+    }
+}
+
+impl DisabledInteraction for SelectComponent {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    fn interaction_colors(&self) -> Option<(BackgroundColor, BorderColor)> {
+        let (background, border, _) = self.calculate_trigger_colors();
+        let fade = |color: Color| {
+            if self.disabled {
+                disabled_color(color)
+            } else {
+                color
+            }
+        };
+        Some((BackgroundColor(fade(background)), BorderColor(fade(border))))
+    }
+}
+
 #[derive(Component, Debug, Clone)]
 pub struct SelectOptionComponent {
     pub value: String,
@@ -64,6 +182,8 @@ pub struct SelectOptionComponent {
     pub disabled: bool,
     pub selected: bool,
     pub select_entity: Option<Entity>,
+    /// Row of the option in its dropdown
+    pub index: usize,
 }
 
 impl SelectOptionComponent {
@@ -74,6 +194,7 @@ impl SelectOptionComponent {
             disabled: false,
             selected: false,
             select_entity: None,
+            index: 0,
         }
     }
 }
@@ -87,6 +208,12 @@ pub struct SelectTrigger {
 pub struct SelectDropdownComponent {
     pub select_entity: Entity,
     pub is_open: bool,
+    /// Scroll area holding the rows
+    pub scroll_area: Entity,
+    /// Height of the dropdown, capped at the select's maximum
+    pub height: f32,
+    /// Offset from the trigger's top aligning the selected option with it
+    pub offset: f32,
 }
 
 #[derive(Component, Debug)]
@@ -136,6 +263,59 @@ impl SelectComponent {
         }
     }
 
+    /// Height of a dropdown row
+    pub fn calculate_row_height(&self, item: &SelectItem) -> f32 {
+        match item {
+            // 1px line with 4px space above and below
+            SelectItem::Separator => 9.0,
+            _ => self.calculate_option_height(),
+        }
+    }
+
+    /// Height the dropdown is capped at.
+    pub fn dropdown_max_height(&self) -> f32 {
+        self.max_dropdown_height
+            .unwrap_or(DEFAULT_VISIBLE_OPTIONS * self.calculate_option_height() + DROPDOWN_CHROME)
+    }
+
+    /// Height of a dropdown showing `rows`, at most
+    /// [`dropdown_max_height`](Self::dropdown_max_height).
+    pub fn calculate_dropdown_height(&self, rows: &[SelectItem]) -> f32 {
+        let content: f32 = rows.iter().map(|row| self.calculate_row_height(row)).sum();
+        (content + DROPDOWN_CHROME).min(self.dropdown_max_height())
+    }
+
+    /// Distance of row `index` from the top of the first row.
+    pub fn calculate_row_top(&self, rows: &[SelectItem], index: usize) -> f32 {
+        rows[..index.min(rows.len())]
+            .iter()
+            .map(|row| self.calculate_row_height(row))
+            .sum()
+    }
+
+    /// Rows of the dropdown, `options` as a flat list without explicit items.
+    pub fn rows(&self) -> Vec<SelectItem> {
+        if !self.items.is_empty() {
+            return self.items.clone();
+        }
+        self.options
+            .iter()
+            .map(|(value, label)| SelectItem::Option {
+                value: value.clone(),
+                label: label.clone(),
+                grouped: false,
+            })
+            .collect()
+    }
+
+    /// Index of the row showing the selected option.
+    pub fn find_selected_row_index(&self) -> Option<usize> {
+        let selected_value = self.selected_value.as_ref()?;
+        self.rows().iter().position(
+            |item| matches!(item, SelectItem::Option { value, .. } if value == selected_value),
+        )
+    }
+
     pub fn find_selected_option_index(&self) -> Option<usize> {
         if let Some(ref selected_value) = self.selected_value {
             self.options.iter().position(|(value, _label)| value == selected_value)
@@ -192,6 +372,7 @@ impl SelectComponent {
 pub struct SelectBuilder {
     component: SelectComponent,
     node: Node,
+    binding: Option<ValueBinder>,
 }
 
 impl SelectBuilder {
@@ -206,6 +387,7 @@ impl SelectBuilder {
                 position_type: PositionType::Relative,
                 ..default()
             },
+            binding: None,
         }
     }
 
@@ -219,6 +401,13 @@ impl SelectBuilder {
         self
     }
 
+    /// Binds the selected value to a field of resource `R` (see
+    /// [`binding`](crate::components::binding)); needs a `ValueBindingPlugin::<R>`.
+    pub fn bind<R: Resource>(mut self, accessor: fn(&mut R) -> &mut String) -> Self {
+        self.binding = Some(value_binder(accessor));
+        self
+    }
+
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.component.disabled = disabled;
         self
@@ -306,17 +495,56 @@ impl SelectBuilder {
         self
     }
 
+    /// Caps the height of the dropdown; its rows scroll beyond it. By default
+    /// the dropdown fits eight options.
+    pub fn max_dropdown_height(mut self, height: f32) -> Self {
+        self.component.max_dropdown_height = Some(height);
+        self
+    }
+
+    /// Animates the dropdown in when it opens and out when it closes.
+    pub fn animation(mut self, animation: OverlayAnimation) -> Self {
+        self.component.animation = Some(animation);
+        self
+    }
+
     pub fn option(mut self, value: impl Into<String>, label: impl Into<String>) -> Self {
-        self.component.options.push((value.into(), label.into()));
+        self.push_option(value.into(), label.into(), false);
         self
     }
 
     pub fn options(mut self, options: Vec<(impl Into<String>, impl Into<String>)>) -> Self {
         for (value, label) in options {
-            self.component.options.push((value.into(), label.into()));
+            self.push_option(value.into(), label.into(), false);
         }
         self
     }
+
+    /// Adds a group of options below a non-selectable `label` header.
+    pub fn group(mut self, label: impl Into<String>, options: Vec<SelectOption>) -> Self {
+        self.component
+            .items
+            .push(SelectItem::GroupLabel(label.into()));
+        for option in options {
+            self.push_option(option.value, option.label, true);
+        }
+        self
+    }
+
+    /// Adds a divider line between options or groups.
+    pub fn separator(mut self) -> Self {
+        self.component.items.push(SelectItem::Separator);
+        self
+    }
+
+    fn push_option(&mut self, value: String, label: String, grouped: bool) {
+        self.component.options.push((value.clone(), label.clone()));
+        self.component.items.push(SelectItem::Option {
+            value,
+            label,
+            grouped,
+        });
+    }
 }
 
 impl SelectBuilder {
@@ -339,6 +567,7 @@ impl SelectBuilder {
             SelectTrigger {
                 select_entity: Entity::PLACEHOLDER, // Will be updated by system
             },
+            ValueBindingSpawner(self.binding),
         )
     }
 }
@@ -441,10 +670,16 @@ fn on_select_trigger_click(
     mut select_open_events: EventWriter<SelectOpenEvent>,
 ) {
     if let Ok(mut select) = select_query.get_mut(trigger.target()) {
+        if select.disabled {
+            return;
+        }
+
         // Only allow opening when closed, prevent immediate reopening
         if !select.open {
             select.open = true;
             select.state = SelectState::Open;
+            select.highlighted = select.find_selected_row_index();
+            select.typeahead.clear();
 
             // Send open event
             select_open_events.write(SelectOpenEvent {
@@ -467,7 +702,7 @@ fn on_select_trigger_click(
 
             // Close existing dropdown
             for dropdown_entity in &dropdown_query {
-                commands.entity(dropdown_entity).despawn();
+                close_select_dropdown(&mut commands, dropdown_entity);
             }
         }
     }
@@ -509,85 +744,121 @@ fn spawn_select_dropdown(commands: &mut Commands, select_entity: Entity, select:
     let option_height = select.calculate_option_height();
     
     // Use dynamic options or fallback to default options if none provided
-    let options = if select.options.is_empty() {
+    let rows = if select.options.is_empty() {
         // Fallback to default options for demonstration
-        vec![
-            ("apple".to_string(), "Apple".to_string()),
-            ("orange".to_string(), "Orange".to_string()),
-            ("grape".to_string(), "Grape".to_string()),
-            ("carrot".to_string(), "Carrot".to_string()),
-            ("potato".to_string(), "Potato".to_string()),
+        [
+            ("apple", "Apple"),
+            ("orange", "Orange"),
+            ("grape", "Grape"),
+            ("carrot", "Carrot"),
+            ("potato", "Potato"),
         ]
+        .into_iter()
+        .map(|(value, label)| SelectItem::Option {
+            value: value.to_string(),
+            label: label.to_string(),
+            grouped: false,
+        })
+        .collect()
     } else {
-        select.options.clone()
+        select.rows()
     };
 
-    // Find the index of the selected option
-    let selected_index = select.find_selected_option_index();
+    // Find the row of the selected option
+    let selected_index = select.find_selected_row_index();
 
-    // Calculate Y offset so selected option aligns with trigger
+    // Scroll the selected option into view and align it with the trigger
+    let dropdown_height = select.calculate_dropdown_height(&rows);
     let y_offset = if let Some(index) = selected_index {
+        let row_top = select.calculate_row_top(&rows, index);
+        let scroll = scroll_row_into_view(
+            0.0,
+            row_top,
+            select.calculate_row_height(&rows[index]),
+            dropdown_height - DROPDOWN_CHROME,
+        );
         // Negative offset to move dropdown up so selected option aligns with trigger
-        -(index as f32 * option_height)
+        scroll - row_top
     } else {
         // No selection - default positioning (first option aligns with trigger)
         0.0
     };
 
-    // Create dropdown with simple positioning - no Portal for now
-    let dropdown_entity = commands
-        .spawn((
-            SelectDropdownComponent {
-                select_entity,
-                is_open: true,
-            },
-            Node {
-                position_type: PositionType::Absolute,
-                flex_direction: FlexDirection::Column,
-                padding: UiRect::all(Val::Px(4.0)),
-                border: UiRect::all(Val::Px(1.0)),
-                width: Val::Px(200.0),
-                height: Val::Auto,
-                top: Val::Px(40.0 + y_offset - 40.0), // Position below trigger with offset
-                left: Val::Px(0.0),
-                ..default()
-            },
-            BackgroundColor(select.get_color_palette().surface),
-            BorderColor(select.get_color_palette().border),
-            BorderRadius::all(Val::Px(6.0)),
-            GlobalZIndex(1), // Very high Z-index
-        ))
-        .id();
-
-    info!(
-        "Created dropdown with Radix-style offset {} for selected index {:?} (selected_value: {:?})",
-        y_offset, selected_index, select.selected_value
-    );
+    let palette = select.get_color_palette();
 
-    // Create option entities from dynamic options
-    let option_entities = options
+    // Create row entities from dynamic options
+    let option_entities = rows
         .iter()
         .enumerate()
-        .map(|(i, (option_value, option_label))| {
+        .map(|(i, item)| {
+            let (option_value, option_label, grouped) = match item {
+                SelectItem::Option {
+                    value,
+                    label,
+                    grouped,
+                } => (value, label, *grouped),
+                SelectItem::GroupLabel(label) => {
+                    return commands
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(option_height),
+                            flex_shrink: 0.0,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                            align_items: AlignItems::Center,
+                            ..default()
+                        })
+                        .with_children(|header| {
+                            header.spawn(Text::caption(label.clone()).build());
+                        })
+                        .id();
+                }
+                SelectItem::Separator => {
+                    return commands
+                        .spawn((
+                            Node {
+                                width: Val::Percent(100.0),
+                                height: Val::Px(1.0),
+                                flex_shrink: 0.0,
+                                margin: UiRect::vertical(Val::Px(4.0)),
+                                ..default()
+                            },
+                            BackgroundColor(palette.border),
+                        ))
+                        .id();
+                }
+            };
+
             let is_selected = select.selected_value.as_ref().map_or(false, |selected| {
                 selected == option_value
             });
+            // Grouped options are indented below their header
+            let padding_left = if grouped { 24.0 } else { 12.0 };
 
             let option_entity = commands
                 .spawn((
-                    SelectOptionComponent::new(option_value.clone(), option_label.clone()),
+                    SelectOptionComponent {
+                        select_entity: Some(select_entity),
+                        index: i,
+                        ..SelectOptionComponent::new(option_value.clone(), option_label.clone())
+                    },
                     Button,
                     Node {
                         width: Val::Percent(100.0),
                         height: Val::Px(option_height),
-                        padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                        flex_shrink: 0.0,
+                        padding: UiRect::new(
+                            Val::Px(padding_left),
+                            Val::Px(12.0),
+                            Val::Px(8.0),
+                            Val::Px(8.0),
+                        ),
                         display: Display::Flex,
                         align_items: AlignItems::Center,
                         justify_content: JustifyContent::FlexStart,
                         ..default()
                     },
                     BackgroundColor(if is_selected {
-                        select.get_color_palette().bg_subtle
+                        palette.bg_subtle
                     } else {
                         Color::NONE
                     }),
@@ -614,25 +885,172 @@ fn spawn_select_dropdown(commands: &mut Commands, select_entity: Entity, select:
         })
         .collect::<Vec<_>>();
 
-    // Add all option entities as children to the dropdown
-    commands
-        .entity(dropdown_entity)
-        .add_children(&option_entities);
+    // Rows beyond the capped height scroll
+    let scroll_area = ScrollAreaBuilder::new("SelectDropdown")
+        .vertical()
+        .children(option_entities)
+        .build(commands);
+
+    // Create dropdown with simple positioning - no Portal for now
+    let dropdown_entity = commands
+        .spawn((
+            SelectDropdownComponent {
+                select_entity,
+                is_open: true,
+                scroll_area,
+                height: dropdown_height,
+                offset: y_offset,
+            },
+            Node {
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(4.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                width: Val::Px(200.0),
+                height: Val::Px(dropdown_height),
+                max_height: Val::Px(select.dropdown_max_height()),
+                // Moved up when there is no room below by `position_select_dropdowns`
+                top: Val::Px(y_offset),
+                left: Val::Px(0.0),
+                ..default()
+            },
+            BackgroundColor(palette.surface),
+            BorderColor(palette.border),
+            BorderRadius::all(Val::Px(6.0)),
+            GlobalZIndex(1), // Very high Z-index
+        ))
+        .add_child(scroll_area)
+        .id();
+    if let Some(animation) = select.animation {
+        commands
+            .entity(dropdown_entity)
+            .insert(OverlayTransition::new(animation));
+    }
+
+    info!(
+        "Created dropdown with Radix-style offset {} for selected index {:?} (selected_value: {:?})",
+        y_offset, selected_index, select.selected_value
+    );
 
     // Add as child to select for simple relative positioning
     commands.entity(select_entity).add_child(dropdown_entity);
 }
 
-/// Simple positioning system - not needed anymore with relative positioning
+/// Closes a dropdown, after its exit animation if it has one. It stops
+/// being a [`SelectDropdownComponent`] at once, so a reopened select gets a
+/// new dropdown.
+fn close_select_dropdown(commands: &mut Commands, dropdown: Entity) {
+    commands
+        .entity(dropdown)
+        .remove::<SelectDropdownComponent>();
+    close_overlay(commands, dropdown);
+}
+
+/// Top of a dropdown relative to its trigger.
+///
+/// The dropdown prefers `offset`, aligning the selected option with the
+/// trigger. When its `height` doesn't fit below that within the window it
+/// opens upward, ending at the trigger's bottom edge. It never starts above
+/// the window's top edge.
+pub fn select_dropdown_top(
+    trigger_top: f32,
+    trigger_height: f32,
+    offset: f32,
+    height: f32,
+    window_height: f32,
+) -> f32 {
+    let top = if trigger_top + offset + height <= window_height {
+        offset
+    } else {
+        trigger_height - height
+    };
+    top.max(-trigger_top)
+}
+
+/// Opens dropdowns upward when their capped height doesn't fit below the
+/// trigger.
 pub fn position_select_dropdowns(
-    _select_query: Query<
-        (&GlobalTransform, &SelectComponent, &Node),
-        (With<SelectComponent>, Without<SelectDropdownComponent>),
-    >,
-    _dropdown_query: Query<(&mut Node, &SelectDropdownComponent)>,
-    _window_query: Query<&Window>,
+    select_query: Query<(&ComputedNode, &GlobalTransform), With<SelectComponent>>,
+    mut dropdown_query: Query<(&mut Node, &SelectDropdownComponent)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+) {
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+    for (mut node, dropdown) in &mut dropdown_query {
+        let Ok((computed, transform)) = select_query.get(dropdown.select_entity) else {
+            continue;
+        };
+        // Layout is in physical pixels, dropdown nodes in logical ones
+        let scale = computed.inverse_scale_factor();
+        let trigger_height = computed.size().y * scale;
+        let trigger_top = transform.translation().y * scale - trigger_height / 2.0;
+        let top = Val::Px(select_dropdown_top(
+            trigger_top,
+            trigger_height,
+            dropdown.offset,
+            dropdown.height,
+            window.height(),
+        ));
+        if node.top != top {
+            node.top = top;
+        }
+    }
+}
+
+/// Scroll offset showing the row at `row_top` with `row_height` within a
+/// viewport of `visible_height` currently scrolled by `scroll`, moving as
+/// little as possible.
+pub fn scroll_row_into_view(
+    scroll: f32,
+    row_top: f32,
+    row_height: f32,
+    visible_height: f32,
+) -> f32 {
+    if row_top < scroll {
+        row_top
+    } else if row_top + row_height > scroll + visible_height {
+        row_top + row_height - visible_height
+    } else {
+        scroll
+    }
+}
+
+/// Keeps the highlighted option of open dropdowns scrolled into view, e.g.
+/// while moving through it with the keyboard.
+pub fn scroll_to_highlighted_select_option(
+    select_query: Query<Ref<SelectComponent>>,
+    dropdown_query: Query<Ref<SelectDropdownComponent>>,
+    mut viewport_query: Query<(&ScrollAreaViewport, &mut ScrollPosition)>,
 ) {
-    // Using relative positioning now - no coordinate conversion needed
+    for dropdown in &dropdown_query {
+        let Ok(select) = select_query.get(dropdown.select_entity) else {
+            continue;
+        };
+        if !dropdown.is_added() && !select.is_changed() {
+            continue;
+        }
+        let rows = select.rows();
+        let Some(index) = select.highlighted.filter(|&index| index < rows.len()) else {
+            continue;
+        };
+        let row_top = select.calculate_row_top(&rows, index);
+        let row_height = select.calculate_row_height(&rows[index]);
+        for (viewport, mut position) in &mut viewport_query {
+            if viewport.area != dropdown.scroll_area {
+                continue;
+            }
+            let scroll = scroll_row_into_view(
+                position.offset_y,
+                row_top,
+                row_height,
+                dropdown.height - DROPDOWN_CHROME,
+            );
+            if position.offset_y != scroll {
+                position.offset_y = scroll;
+            }
+        }
+    }
 }
 
 /// Handles option click to select value
@@ -688,7 +1106,7 @@ fn on_select_option_click(
                 });
 
                 // Close dropdown
-                commands.entity(dropdown_entity).despawn();
+                close_select_dropdown(&mut commands, dropdown_entity);
 
                 info!("✅ Selected option: {} ({})", option.label, option.value);
                 
@@ -741,19 +1159,181 @@ pub fn update_select_trigger_text(
     mut text_query: Query<&mut bevy::ui::widget::Text>,
 ) {
     for event in select_change_events.read() {
-        if let Ok(select) = select_query.get(event.select_entity) {
-            if let Ok(children) = children_query.get(event.select_entity) {
-                for child in children {
-                    if let Ok(mut text) = text_query.get_mut(*child) {
-                        text.0 = event.selected_label.clone();
-                        break;
-                    }
+        if select_query.contains(event.select_entity) {
+            set_select_trigger_label(
+                event.select_entity,
+                &event.selected_label,
+                &children_query,
+                &mut text_query,
+            );
+        }
+    }
+}
+
+/// Sets the text shown in a select's trigger.
+pub(crate) fn set_select_trigger_label(
+    select_entity: Entity,
+    label: &str,
+    children_query: &Query<&Children>,
+    text_query: &mut Query<&mut bevy::ui::widget::Text>,
+) {
+    if let Ok(children) = children_query.get(select_entity) {
+        for child in children {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.0 = label.to_string();
+                break;
+            }
+        }
+    }
+}
+
+/// Closes select dropdowns dismissed with Escape
+pub fn dismiss_select_dropdowns(
+    mut commands: Commands,
+    mut dismiss_events: EventReader<DismissEvent>,
+    dropdown_query: Query<(Entity, &SelectDropdownComponent)>,
+    mut select_query: Query<&mut SelectComponent>,
+    mut select_open_events: EventWriter<SelectOpenEvent>,
+) {
+    for event in dismiss_events.read() {
+        let Ok(mut select) = select_query.get_mut(event.layer) else {
+            continue;
+        };
+        select.open = false;
+        select.state = SelectState::Closed;
+
+        select_open_events.write(SelectOpenEvent {
+            select_entity: event.layer,
+            open: false,
+        });
+
+        for (dropdown_entity, dropdown) in &dropdown_query {
+            if dropdown.select_entity == event.layer {
+                close_select_dropdown(&mut commands, dropdown_entity);
+            }
+        }
+    }
+}
+
+/// Moves the highlight of open selects with Up / Down, skipping group
+/// headers and separators, and selects the highlighted option with Enter.
+pub fn handle_select_keyboard(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut select_query: Query<(Entity, &mut SelectComponent)>,
+    dropdown_query: Query<(Entity, &SelectDropdownComponent)>,
+    mut select_change_events: EventWriter<SelectChangeEvent>,
+    mut select_open_events: EventWriter<SelectOpenEvent>,
+) {
+    for (select_entity, mut select) in &mut select_query {
+        if !select.open {
+            continue;
+        }
+        let rows = select.rows();
+
+        if keyboard.just_pressed(KeyCode::ArrowDown) {
+            select.highlighted = next_select_item(&rows, select.highlighted, 1);
+        } else if keyboard.just_pressed(KeyCode::ArrowUp) {
+            select.highlighted = next_select_item(&rows, select.highlighted, -1);
+        } else if keyboard.just_pressed(KeyCode::Enter) {
+            let Some(SelectItem::Option { value, label, .. }) =
+                select.highlighted.and_then(|index| rows.get(index))
+            else {
+                continue;
+            };
+            let previous_value = select.selected_value.replace(value.clone());
+            select.open = false;
+            select.state = SelectState::Closed;
+
+            select_change_events.write(SelectChangeEvent {
+                select_entity,
+                selected_value: value.clone(),
+                previous_value,
+                selected_label: label.clone(),
+            });
+            select_open_events.write(SelectOpenEvent {
+                select_entity,
+                open: false,
+            });
+
+            for (dropdown_entity, dropdown) in &dropdown_query {
+                if dropdown.select_entity == select_entity {
+                    close_select_dropdown(&mut commands, dropdown_entity);
                 }
             }
         }
     }
 }
 
+/// Highlights the first option of open selects whose label starts with the
+/// letters typed in quick succession.
+pub fn handle_select_typeahead(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    time: Res<Time>,
+    mut select_query: Query<&mut SelectComponent>,
+) {
+    let typed: String = keyboard_events
+        .read()
+        .filter(|event| event.state.is_pressed())
+        .filter_map(|event| match &event.logical_key {
+            Key::Character(text) => Some(text.as_str()),
+            Key::Space => Some(" "),
+            _ => None,
+        })
+        .collect();
+    if typed.is_empty() {
+        return;
+    }
+
+    let now = time.elapsed();
+    for mut select in &mut select_query {
+        if !select.open {
+            continue;
+        }
+        // A leading space doesn't start a prefix
+        let typed = if select.typeahead.prefix(now).is_empty() {
+            typed.trim_start()
+        } else {
+            typed.as_str()
+        };
+        if typed.is_empty() {
+            continue;
+        }
+
+        let rows = select.rows();
+        let labels = rows.iter().map(|row| match row {
+            SelectItem::Option { label, .. } => Some(label.as_str()),
+            _ => None,
+        });
+        if let Some(index) = typeahead_match(select.typeahead.push(typed, now), labels) {
+            select.highlighted = Some(index);
+        }
+    }
+}
+
+/// Paints the highlighted and the selected option of open dropdowns
+pub fn update_select_option_highlights(
+    select_query: Query<&SelectComponent, Changed<SelectComponent>>,
+    mut option_query: Query<(&SelectOptionComponent, &mut BackgroundColor)>,
+) {
+    for (option, mut bg_color) in &mut option_query {
+        let Some(select) = option
+            .select_entity
+            .and_then(|entity| select_query.get(entity).ok())
+        else {
+            continue;
+        };
+        let palette = select.get_color_palette();
+        bg_color.0 = if select.highlighted == Some(option.index) {
+            palette.bg_hover
+        } else if select.selected_value.as_ref() == Some(&option.value) {
+            palette.bg_subtle
+        } else {
+            Color::NONE
+        };
+    }
+}
+
 /// Detects clicks outside of open dropdowns to close them
 pub fn handle_click_outside_select(
     mut commands: Commands,
@@ -782,10 +1362,207 @@ pub fn handle_click_outside_select(
                         open: false,
                     });
 
-                    commands.entity(dropdown_entity).despawn();
+                    close_select_dropdown(&mut commands, dropdown_entity);
                     info!("Closed dropdown due to outside click");
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn spawn_open_select(world: &mut World, builder: SelectBuilder) -> Entity {
+        let select = world.spawn(builder.build()).id();
+        world.get_mut::<SelectComponent>(select).unwrap().open = true;
+        select
+    }
+
+    fn press(world: &mut World, key: KeyCode) {
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        keyboard.press(key);
+        world.insert_resource(keyboard);
+        world.run_system_once(handle_select_keyboard).unwrap();
+    }
+
+    fn highlighted(world: &World, select: Entity) -> Option<usize> {
+        world.get::<SelectComponent>(select).unwrap().highlighted
+    }
+
+    #[test]
+    fn test_navigation_skips_group_headers() {
+        let mut world = World::new();
+        world.init_resource::<Events<SelectChangeEvent>>();
+        world.init_resource::<Events<SelectOpenEvent>>();
+
+        let select = spawn_open_select(
+            &mut world,
+            SelectBuilder::new()
+                .group(
+                    "Fruits",
+                    vec![
+                        SelectOption::new("apple", "Apple"),
+                        SelectOption::new("pear", "Pear"),
+                    ],
+                )
+                .separator()
+                .group("Vegetables", vec![SelectOption::new("leek", "Leek")]),
+        );
+
+        // Row 0 is the "Fruits" header
+        press(&mut world, KeyCode::ArrowDown);
+        assert_eq!(highlighted(&world, select), Some(1));
+
+        // The separator and the "Vegetables" header are skipped as well
+        press(&mut world, KeyCode::ArrowDown);
+        press(&mut world, KeyCode::ArrowDown);
+        assert_eq!(highlighted(&world, select), Some(5));
+
+        // Wrapping around lands on the first option again
+        press(&mut world, KeyCode::ArrowDown);
+        assert_eq!(highlighted(&world, select), Some(1));
+
+        press(&mut world, KeyCode::Enter);
+        let component = world.get::<SelectComponent>(select).unwrap();
+        assert_eq!(component.selected_value.as_deref(), Some("apple"));
+        assert!(!component.open);
+        // Grouped options stay selectable by value, e.g. for bindings
+        assert_eq!(component.options.len(), 3);
+    }
+
+    #[test]
+    fn test_typing_highlights_matching_option() {
+        let mut world = World::new();
+        world.init_resource::<Events<KeyboardInput>>();
+        world.init_resource::<Time>();
+        let window = world.spawn_empty().id();
+
+        let select = spawn_open_select(
+            &mut world,
+            SelectBuilder::new()
+                .option("blue", "Blue")
+                .option("green", "Green")
+                .option("grey", "Grey"),
+        );
+
+        let type_text = |world: &mut World, text: &str| {
+            world.send_event(KeyboardInput {
+                key_code: KeyCode::KeyG,
+                logical_key: Key::Character(text.into()),
+                state: bevy::input::ButtonState::Pressed,
+                text: Some(text.into()),
+                repeat: false,
+                window,
+            });
+            world.run_system_once(handle_select_typeahead).unwrap();
+            // A new reader would see the key again otherwise
+            world.resource_mut::<Events<KeyboardInput>>().clear();
+        };
+
+        type_text(&mut world, "g");
+        assert_eq!(highlighted(&world, select), Some(1));
+        type_text(&mut world, "r");
+        type_text(&mut world, "e");
+        type_text(&mut world, "y");
+        assert_eq!(highlighted(&world, select), Some(2));
+    }
+
+    #[test]
+    fn test_flat_options_navigate_in_order() {
+        let mut world = World::new();
+        world.init_resource::<Events<SelectChangeEvent>>();
+        world.init_resource::<Events<SelectOpenEvent>>();
+
+        let select = spawn_open_select(
+            &mut world,
+            SelectBuilder::new()
+                .option("red", "Red")
+                .option("green", "Green"),
+        );
+
+        press(&mut world, KeyCode::ArrowDown);
+        assert_eq!(highlighted(&world, select), Some(0));
+        press(&mut world, KeyCode::ArrowUp);
+        assert_eq!(highlighted(&world, select), Some(1));
+    }
+
+    fn spawn_dropdown(world: &mut World, select: Entity) -> Entity {
+        let component = world.get::<SelectComponent>(select).unwrap().clone();
+        spawn_select_dropdown(&mut world.commands(), select, &component);
+        world.flush();
+        world
+            .query_filtered::<Entity, With<SelectDropdownComponent>>()
+            .single(world)
+            .unwrap()
+    }
+
+    fn long_select(count: usize) -> SelectBuilder {
+        (0..count).fold(SelectBuilder::new(), |builder, i| {
+            builder.option(format!("option{i}"), format!("Option {i}"))
+        })
+    }
+
+    #[test]
+    fn test_dropdown_height_never_exceeds_max() {
+        let mut world = World::new();
+        for (count, max) in [(30, 120.0), (2, 120.0), (30, 20.0)] {
+            let select = world
+                .spawn(long_select(count).max_dropdown_height(max).build())
+                .id();
+            let dropdown = spawn_dropdown(&mut world, select);
+
+            let node = world.get::<Node>(dropdown).unwrap();
+            let Val::Px(height) = node.height else {
+                panic!("dropdown height isn't in pixels");
+            };
+            assert!(height <= max);
+            assert_eq!(node.max_height, Val::Px(max));
+            world.despawn(select);
+        }
+
+        // Short lists keep their natural height; by default eight options fit
+        let select = world.spawn(long_select(2).build()).id();
+        let dropdown = spawn_dropdown(&mut world, select);
+        assert_eq!(world.get::<Node>(dropdown).unwrap().height, Val::Px(82.0));
+        let component = world.get::<SelectComponent>(select).unwrap();
+        assert_eq!(component.dropdown_max_height(), 8.0 * 36.0 + 10.0);
+    }
+
+    #[test]
+    fn test_highlighted_option_scrolls_into_view() {
+        let mut world = World::new();
+        world.init_resource::<Events<SelectChangeEvent>>();
+        world.init_resource::<Events<SelectOpenEvent>>();
+        let select = spawn_open_select(&mut world, long_select(20).max_dropdown_height(118.0));
+        spawn_dropdown(&mut world, select);
+        let scroll = |world: &mut World| {
+            world
+                .run_system_once(scroll_to_highlighted_select_option)
+                .unwrap();
+            world
+                .query_filtered::<&ScrollPosition, With<ScrollAreaViewport>>()
+                .single(world)
+                .unwrap()
+                .offset_y
+        };
+
+        // Three 36px rows are visible; Up without a highlight lands on the last row
+        press(&mut world, KeyCode::ArrowUp);
+        assert_eq!(scroll(&mut world), 19.0 * 36.0 + 36.0 - 108.0);
+        press(&mut world, KeyCode::ArrowDown);
+        assert_eq!(scroll(&mut world), 0.0);
+    }
+
+    #[test]
+    fn test_dropdown_opens_upward_without_room_below() {
+        // Fits below the trigger
+        assert_eq!(select_dropdown_top(100.0, 40.0, 0.0, 200.0, 600.0), 0.0);
+        // Near the bottom edge it ends at the trigger's bottom instead
+        assert_eq!(select_dropdown_top(500.0, 40.0, 0.0, 200.0, 600.0), -160.0);
+        // Never above the window
+        assert_eq!(select_dropdown_top(100.0, 40.0, 0.0, 800.0, 600.0), -100.0);
+    }
+}
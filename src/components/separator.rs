@@ -1,15 +1,14 @@
-use crate::{
-    theme::{
-        color::{theme_mode, ThemeMode, UiColorPalette, UiColorPalettes},
-        layout::UiLayout,
-    },
-};
+use crate::theme::color::{theme_mode, ThemeMode, UiColorPalette, UiColorPalettes};
+use crate::utilities::naming::Namespace;
 use bevy::prelude::*;
 
 #[derive(Component, Debug, Clone)]
 pub struct SeparatorComponent {
     pub orientation: SeparatorOrientation,
     pub color: UiColorPalette,
+    /// Follows the flex direction of the parent: vertical in a row,
+    /// horizontal in a column. Outside flex containers `orientation` is kept.
+    pub auto_orientation: bool,
 }
 
 impl Default for SeparatorComponent {
@@ -21,6 +20,7 @@ impl Default for SeparatorComponent {
             } else {
                 UiColorPalettes::light_mode().gray
             },
+            auto_orientation: false,
         }
     }
 }
@@ -32,6 +32,30 @@ pub enum SeparatorOrientation {
     Vertical,
 }
 
+impl SeparatorOrientation {
+    /// Orientation that crosses the main axis of a flex `parent`, or `None`
+    /// if it doesn't lay out its children as flex.
+    pub fn across(parent: &Node) -> Option<Self> {
+        if parent.display != Display::Flex {
+            return None;
+        }
+        Some(match parent.flex_direction {
+            FlexDirection::Row | FlexDirection::RowReverse => SeparatorOrientation::Vertical,
+            FlexDirection::Column | FlexDirection::ColumnReverse => {
+                SeparatorOrientation::Horizontal
+            }
+        })
+    }
+
+    /// Width and height of a separator line.
+    fn size(self) -> (Val, Val) {
+        match self {
+            SeparatorOrientation::Horizontal => (Val::Percent(100.0), Val::Px(1.0)),
+            SeparatorOrientation::Vertical => (Val::Px(1.0), Val::Percent(100.0)),
+        }
+    }
+}
+
 pub struct SeparatorBuilder {
     name: String,
     separator: SeparatorComponent,
@@ -65,6 +89,14 @@ impl SeparatorBuilder {
         self
     }
 
+    /// Takes the orientation from the parent's flex direction, see
+    /// [`SeparatorComponent::auto_orientation`]. The orientation set on the
+    /// builder is used when the parent isn't a flex container.
+    pub fn auto_orientation(mut self) -> Self {
+        self.separator.auto_orientation = true;
+        self
+    }
+
     pub fn color(mut self, color: UiColorPalette) -> Self {
         self.separator.color = color;
         self
@@ -80,19 +112,7 @@ impl SeparatorBuilder {
 
 impl SeparatorBuilder {
     fn calculate_style(&self) -> Node {
-        let layout = UiLayout::default();
-
-        let (width, height) = match self.separator.orientation {
-            SeparatorOrientation::Horizontal => {
-                let width = Val::Percent(100.0);
-
-                (width, Val::Px(1.0))
-            }
-            SeparatorOrientation::Vertical => {
-                let height = Val::Percent(100.0);
-                (Val::Px(1.0), height)
-            }
-        };
+        let (width, height) = self.separator.orientation.size();
 
         Node {
             width,
@@ -113,3 +133,59 @@ impl Default for SeparatorBuilder {
 }
 
 // Note: SeparatorBuilder already has a direct build() method that returns impl Bundle
+
+/// System that turns auto-oriented separators across their flex parent.
+pub fn resolve_separator_orientations(
+    mut separators: Query<(&mut SeparatorComponent, &mut Node, &ChildOf)>,
+    parents: Query<&Node, Without<SeparatorComponent>>,
+) {
+    for (mut separator, mut node, child_of) in &mut separators {
+        if !separator.auto_orientation {
+            continue;
+        }
+        let Some(orientation) = parents
+            .get(child_of.parent())
+            .ok()
+            .and_then(SeparatorOrientation::across)
+        else {
+            continue;
+        };
+        if separator.orientation == orientation {
+            continue;
+        }
+        separator.orientation = orientation;
+        (node.width, node.height) = orientation.size();
+    }
+}
+
+impl Namespace for SeparatorBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::flex::FlexComponent, test_support::test_app};
+
+    #[test]
+    fn test_auto_orientation_crosses_flex_row() {
+        let mut app = test_app();
+        let separator = app
+            .world_mut()
+            .spawn(FlexComponent::row("toolbar").build())
+            .with_children(|row| {
+                row.spawn(SeparatorBuilder::new().auto_orientation().build());
+            })
+            .id();
+        let separator = app.world().get::<Children>(separator).unwrap()[0];
+        app.update();
+
+        let component = app.world().get::<SeparatorComponent>(separator).unwrap();
+        assert_eq!(component.orientation, SeparatorOrientation::Vertical);
+        let node = app.world().get::<Node>(separator).unwrap();
+        assert_eq!(node.width, Val::Px(1.0));
+        assert_eq!(node.height, Val::Percent(100.0));
+    }
+}
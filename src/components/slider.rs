@@ -1,8 +1,17 @@
 use crate::{
+    components::{
+        a11y::{A11yState, A11yValueText, UiA11y, UiA11yValue, UiRole},
+        focus::{Focusable, UiFocus},
+    },
     theme::color::{accent_palette, theme, UiColorPalette},
+    utilities::naming::{strip_namespace, Namespace},
 };
 use bevy::{ecs::spawn::SpawnWith, prelude::*};
 use bevy_picking::prelude::{Click, Drag, DragEnd, DragStart, Pickable, Pointer};
+use std::{sync::Arc, time::Duration};
+
+/// Share of the range Page Up / Page Down move a slider by
+pub const SLIDER_PAGE_FRACTION: f32 = 0.1;
 
 #[derive(Event, Debug, Clone)]
 pub struct SliderValueChangeEvent {
@@ -47,6 +56,16 @@ impl Default for SliderComponent {
     }
 }
 
+impl A11yState for SliderComponent {
+    fn a11y_value(&self) -> UiA11yValue {
+        UiA11yValue::Number {
+            value: self.value,
+            min: self.min,
+            max: self.max,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SliderSize {
     Size1, // Small - 8px track height, 16px thumb
@@ -89,9 +108,73 @@ pub struct TrackRef(pub Entity);
 #[derive(Component, Debug)]
 pub struct ThumbRef(pub Entity);
 
+/// Rate limiting of a slider's events while it is dragged.
+///
+/// With a commit debounce, a [`SliderValueCommitEvent`] also fires once the
+/// value has stopped changing for that long, not only on release. With a
+/// change throttle, at most one [`SliderValueChangeEvent`] fires per interval;
+/// the latest held-back value is sent when the interval ends.
+#[derive(Component, Debug, Default)]
+pub struct SliderEventPacing {
+    /// Restarted by every change; commits when it finishes
+    commit_debounce: Option<Timer>,
+    /// Whether a change happened since the last commit
+    commit_pending: bool,
+    /// Running while change events are held back
+    change_throttle: Option<Timer>,
+    /// Latest change held back by the throttle
+    pending_change: Option<SliderValueChangeEvent>,
+}
+
+impl SliderEventPacing {
+    pub fn new(commit_debounce: Option<Duration>, change_throttle: Option<Duration>) -> Self {
+        Self {
+            commit_debounce: commit_debounce.map(|delay| Timer::new(delay, TimerMode::Once)),
+            commit_pending: false,
+            change_throttle: change_throttle.map(|interval| {
+                // Starts finished so the first change goes through
+                let mut timer = Timer::new(interval, TimerMode::Once);
+                timer.tick(interval);
+                timer
+            }),
+            pending_change: None,
+        }
+    }
+
+    /// Records a change, returning it if it may be sent right away.
+    fn record_change(&mut self, event: SliderValueChangeEvent) -> Option<SliderValueChangeEvent> {
+        if let Some(debounce) = &mut self.commit_debounce {
+            debounce.reset();
+            self.commit_pending = true;
+        }
+
+        match &mut self.change_throttle {
+            Some(throttle) if !throttle.finished() => {
+                self.pending_change = Some(event);
+                None
+            }
+            Some(throttle) => {
+                throttle.reset();
+                Some(event)
+            }
+            None => Some(event),
+        }
+    }
+
+    /// Ends a drag, returning the held-back change to send before the commit.
+    fn finish(&mut self) -> Option<SliderValueChangeEvent> {
+        self.commit_pending = false;
+        self.pending_change.take()
+    }
+}
+
 pub struct SliderBuilder {
     name: String,
     slider: SliderComponent,
+    a11y_label: Option<String>,
+    value_text: A11yValueText,
+    commit_debounce: Option<Duration>,
+    change_throttle: Option<Duration>,
 }
 
 impl SliderBuilder {
@@ -99,6 +182,10 @@ impl SliderBuilder {
         Self {
             name: "Slider".to_string(),
             slider: SliderComponent::default(),
+            a11y_label: None,
+            value_text: A11yValueText::default(),
+            commit_debounce: None,
+            change_throttle: None,
         }
     }
 
@@ -182,6 +269,42 @@ impl SliderBuilder {
         self
     }
 
+    /// Overrides the [`UiA11y`] label, which defaults to the name
+    pub fn a11y_label(mut self, label: impl Into<String>) -> Self {
+        self.a11y_label = Some(label.into());
+        self
+    }
+
+    /// Formats the value announced in [`UiA11y::value_text`] from the value,
+    /// minimum and maximum; a percentage of the range by default
+    ///
+    /// ```text
+    /// SliderBuilder::new().range(0.0, 20.0).value_text_formatter(|value, _, max| {
+    ///     format!("{value} of {max}")
+    /// })
+    /// ```
+    pub fn value_text_formatter(
+        mut self,
+        formatter: impl Fn(f32, f32, f32) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.value_text.formatter = Some(Arc::new(formatter));
+        self
+    }
+
+    /// Also commits the value once dragging has paused for `delay`, not only
+    /// on release (see [`SliderEventPacing`])
+    pub fn commit_debounce(mut self, delay: Duration) -> Self {
+        self.commit_debounce = Some(delay);
+        self
+    }
+
+    /// Sends at most one change event per `interval` while dragging (see
+    /// [`SliderEventPacing`])
+    pub fn throttle_changes(mut self, interval: Duration) -> Self {
+        self.change_throttle = Some(interval);
+        self
+    }
+
     pub fn build(self) -> impl Bundle {
         let mut final_slider = self.slider.clone();
         final_slider.value = final_slider.value.clamp(final_slider.min, final_slider.max);
@@ -207,9 +330,20 @@ impl SliderBuilder {
         let thumb_background = self.calculate_thumb_background();
         let thumb_border_radius = self.calculate_thumb_border_radius();
 
+        let a11y_label = self
+            .a11y_label
+            .clone()
+            .unwrap_or_else(|| strip_namespace(&name_clone).to_string());
+        let a11y = UiA11y::new(UiRole::Slider, a11y_label).value(final_slider.a11y_value());
+        let focusable = Focusable::new().disabled(final_slider.disabled);
+
         let bundle = (
             Name::new(name_clone),
             final_slider.clone(),
+            a11y,
+            self.value_text.clone(),
+            SliderEventPacing::new(self.commit_debounce, self.change_throttle),
+            focusable,
             root_node,
             root_background,
             BorderColor(theme().red.solid),
@@ -496,34 +630,54 @@ fn calculate_track_rect(transform: &GlobalTransform, node: &Node) -> Rect {
     }
 }
 
-fn calculate_slider_value_from_computed_size(
-    mouse_position: Vec2,
-    track_transform: &GlobalTransform,
-    computed_node: &ComputedNode,
+/// Value of `slider` at the logical `pointer` position on a track with
+/// physical `track_center` and `track_size`.
+///
+/// UI coordinates grow downward, so a vertical track maps its bottom edge to
+/// `min` and its top edge to `max`.
+pub fn slider_value_at(
+    pointer: Vec2,
+    track_center: Vec2,
+    track_size: Vec2,
+    inverse_scale_factor: f32,
     slider: &SliderComponent,
 ) -> f32 {
-    // Get computed track dimensions
-    let track_width = computed_node.size().x;
-    let track_height = computed_node.size().y;
+    let center = track_center * inverse_scale_factor;
+    let size = track_size * inverse_scale_factor;
 
-    // Get track center position in world coordinates
-    let track_center = track_transform.translation().truncate();
-
-    // Calculate relative position from track start
     let percentage = match slider.orientation {
         SliderOrientation::Horizontal => {
-            let track_left = track_center.x - track_width / 2.0;
-            let relative_x = mouse_position.x - track_left;
-            (relative_x / track_width).clamp(0.0, 1.0)
+            if size.x <= 0.0 {
+                return slider.value;
+            }
+            let track_left = center.x - size.x / 2.0;
+            (pointer.x - track_left) / size.x
         }
         SliderOrientation::Vertical => {
-            let track_bottom = track_center.y - track_height / 2.0;
-            let relative_y = mouse_position.y - track_bottom;
-            (relative_y / track_height).clamp(0.0, 1.0)
+            if size.y <= 0.0 {
+                return slider.value;
+            }
+            let track_bottom = center.y + size.y / 2.0;
+            (track_bottom - pointer.y) / size.y
         }
     };
 
-    slider.min + percentage * (slider.max - slider.min)
+    slider.min + percentage.clamp(0.0, 1.0) * (slider.max - slider.min)
+}
+
+fn calculate_slider_value_from_computed_size(
+    mouse_position: Vec2,
+    track_transform: &GlobalTransform,
+    computed_node: &ComputedNode,
+    slider: &SliderComponent,
+) -> f32 {
+    slider_value_at(
+        mouse_position,
+        track_transform.translation().truncate(),
+        computed_node.size(),
+        computed_node.inverse_scale_factor(),
+        slider,
+    )
 }
 
 fn calculate_slider_value_from_position(
@@ -556,8 +710,9 @@ fn calculate_slider_value_from_position(
             (relative_x / track_width).clamp(0.0, 1.0)
         }
         SliderOrientation::Vertical => {
-            let track_bottom = track_center.y - track_height / 2.0;
-            let relative_y = mouse_position.y - track_bottom;
+            // UI y grows downward; values grow upward
+            let track_bottom = track_center.y + track_height / 2.0;
+            let relative_y = track_bottom - mouse_position.y;
             (relative_y / track_height).clamp(0.0, 1.0)
         }
     };
@@ -601,7 +756,7 @@ fn send_slider_events(
 
 // System to handle thumb dragging
 pub fn handle_slider_drag(
-    mut sliders: Query<&mut SliderComponent>,
+    mut sliders: Query<(&mut SliderComponent, Option<&mut SliderEventPacing>)>,
     mut thumbs: Query<(&mut SliderThumb, &ThumbRef), With<SliderThumb>>,
     _thumb_transforms: Query<&GlobalTransform, With<SliderThumb>>,
     tracks: Query<(&TrackRef, &Node, &GlobalTransform, &ComputedNode), With<SliderTrack>>,
@@ -632,7 +787,7 @@ pub fn handle_slider_drag(
                 if let Some((_track_ref, _track_node, track_transform, computed_node)) =
                     track_result
                 {
-                    if let Ok(mut slider) = sliders.get_mut(slider_entity) {
+                    if let Ok((mut slider, pacing)) = sliders.get_mut(slider_entity) {
                         let new_value = calculate_slider_value_from_computed_size(
                             event.pointer_location.position,
                             track_transform,
@@ -647,11 +802,18 @@ pub fn handle_slider_drag(
                             new_value.clamp(slider.min, slider.max)
                         };
 
-                        events.write(SliderValueChangeEvent {
+                        let event = SliderValueChangeEvent {
                             slider_entity,
                             value: slider.value,
                             thumb_index: thumb.index,
-                        });
+                        };
+                        let event = match pacing {
+                            Some(mut pacing) => pacing.record_change(event),
+                            None => Some(event),
+                        };
+                        if let Some(event) = event {
+                            events.write(event);
+                        }
                     }
                 }
             }
@@ -666,7 +828,10 @@ pub fn handle_slider_drag(
 
                 // Send commit event
                 let slider_entity = thumb_ref.0;
-                if let Ok(slider) = sliders.get(slider_entity) {
+                if let Ok((slider, pacing)) = sliders.get_mut(slider_entity) {
+                    if let Some(change) = pacing.and_then(|mut pacing| pacing.finish()) {
+                        events.write(change);
+                    }
                     commit_events.write(SliderValueCommitEvent {
                         slider_entity,
                         value: slider.value,
@@ -678,6 +843,39 @@ pub fn handle_slider_drag(
     }
 }
 
+/// System sending the throttled change events and debounced commits of
+/// sliders being dragged.
+pub fn pace_slider_events(
+    time: Res<Time>,
+    mut sliders: Query<(Entity, &SliderComponent, &mut SliderEventPacing)>,
+    mut events: EventWriter<SliderValueChangeEvent>,
+    mut commit_events: EventWriter<SliderValueCommitEvent>,
+) {
+    for (slider_entity, slider, mut pacing) in &mut sliders {
+        let pacing = &mut *pacing;
+
+        if let Some(throttle) = &mut pacing.change_throttle {
+            if throttle.tick(time.delta()).finished() {
+                if let Some(change) = pacing.pending_change.take() {
+                    events.write(change);
+                    throttle.reset();
+                }
+            }
+        }
+
+        if let (true, Some(debounce)) = (pacing.commit_pending, &mut pacing.commit_debounce) {
+            if debounce.tick(time.delta()).finished() {
+                pacing.commit_pending = false;
+                commit_events.write(SliderValueCommitEvent {
+                    slider_entity,
+                    value: slider.value,
+                    thumb_index: 0,
+                });
+            }
+        }
+    }
+}
+
 fn snap_to_step(value: f32, step: f32, min: f32, max: f32) -> f32 {
     if step <= 0.0 {
         return value.clamp(min, max);
@@ -784,6 +982,60 @@ fn calculate_value_from_click(
     }
 }
 
+/// Value a key press moves `slider` to: arrows move by one step (Right/Up
+/// toward `max`), Page Up / Page Down by [`SLIDER_PAGE_FRACTION`] of the
+/// range in whole steps, Home/End to `min` / `max`.
+pub fn slider_key_value(slider: &SliderComponent, key: KeyCode) -> Option<f32> {
+    let range = slider.max - slider.min;
+    let page = if slider.step > 0.0 {
+        let steps = (range * SLIDER_PAGE_FRACTION / slider.step).round();
+        steps.max(1.0) * slider.step
+    } else {
+        range * SLIDER_PAGE_FRACTION
+    };
+    let value = match key {
+        KeyCode::ArrowRight | KeyCode::ArrowUp => slider.value + slider.step,
+        KeyCode::ArrowLeft | KeyCode::ArrowDown => slider.value - slider.step,
+        KeyCode::PageUp => slider.value + page,
+        KeyCode::PageDown => slider.value - page,
+        KeyCode::Home => slider.min,
+        KeyCode::End => slider.max,
+        _ => return None,
+    };
+    Some(snap_to_step(value, slider.step, slider.min, slider.max))
+}
+
+/// Moves the keyboard-focused slider with the arrow, Page Up / Page Down and
+/// Home / End keys, see [`slider_key_value`]. Each press sends a change and a
+/// commit event.
+pub fn handle_slider_keyboard(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    focus: Res<UiFocus>,
+    mut sliders: Query<&mut SliderComponent>,
+    mut events: EventWriter<SliderValueChangeEvent>,
+    mut commit_events: EventWriter<SliderValueCommitEvent>,
+) {
+    let Some(slider_entity) = focus.focused else {
+        return;
+    };
+    let Ok(mut slider) = sliders.get_mut(slider_entity) else {
+        return;
+    };
+    if slider.disabled {
+        return;
+    }
+
+    for key in keyboard.get_just_pressed() {
+        let Some(value) = slider_key_value(&slider, *key) else {
+            continue;
+        };
+        if value != slider.value {
+            slider.value = value;
+            send_slider_events(slider_entity, value, &mut events, &mut commit_events);
+        }
+    }
+}
+
 // System to update range and thumb when slider value changes
 pub fn update_slider_visuals(
     sliders: Query<Entity, (With<SliderComponent>, Changed<SliderComponent>)>,
@@ -838,3 +1090,152 @@ pub fn update_slider_visuals(
         }
     }
 }
+
+impl Namespace for SliderBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup(
+        commit_debounce: Option<Duration>,
+        change_throttle: Option<Duration>,
+    ) -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<SliderValueChangeEvent>>();
+        world.init_resource::<Events<SliderValueCommitEvent>>();
+        let slider = world
+            .spawn((
+                SliderComponent::default(),
+                SliderEventPacing::new(commit_debounce, change_throttle),
+            ))
+            .id();
+        (world, slider)
+    }
+
+    /// Drags the slider to `value`, then lets `elapsed` pass.
+    fn drag_to(world: &mut World, slider: Entity, value: f32, elapsed: Duration) {
+        world.get_mut::<SliderComponent>(slider).unwrap().value = value;
+        let change = world
+            .get_mut::<SliderEventPacing>(slider)
+            .unwrap()
+            .record_change(SliderValueChangeEvent {
+                slider_entity: slider,
+                value,
+                thumb_index: 0,
+            });
+        if let Some(change) = change {
+            world.send_event(change);
+        }
+        world.resource_mut::<Time>().advance_by(elapsed);
+        world.run_system_once(pace_slider_events).unwrap();
+    }
+
+    fn drain<E: Event>(world: &mut World) -> Vec<E> {
+        world.resource_mut::<Events<E>>().drain().collect()
+    }
+
+    #[test]
+    fn test_commit_fires_once_after_changes_pause() {
+        let (mut world, slider) = setup(Some(Duration::from_millis(100)), None);
+
+        for step in 1..=10 {
+            drag_to(&mut world, slider, step as f32, Duration::from_millis(20));
+        }
+        assert!(drain::<SliderValueCommitEvent>(&mut world).is_empty());
+        assert_eq!(drain::<SliderValueChangeEvent>(&mut world).len(), 10);
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(150));
+        world.run_system_once(pace_slider_events).unwrap();
+        world.run_system_once(pace_slider_events).unwrap();
+
+        let commits = drain::<SliderValueCommitEvent>(&mut world);
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].value, 10.0);
+    }
+
+    #[test]
+    fn test_throttle_limits_change_events() {
+        let (mut world, slider) = setup(None, Some(Duration::from_millis(50)));
+
+        for step in 1..=10 {
+            drag_to(&mut world, slider, step as f32, Duration::from_millis(10));
+        }
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(50));
+        world.run_system_once(pace_slider_events).unwrap();
+
+        let changes = drain::<SliderValueChangeEvent>(&mut world);
+        // 100ms of dragging allow at most one change per 50ms interval
+        assert!(changes.len() <= 3, "{} changes", changes.len());
+        assert_eq!(changes.first().unwrap().value, 1.0);
+        // The last value is never swallowed
+        assert_eq!(changes.last().unwrap().value, 10.0);
+    }
+
+    #[test]
+    fn test_vertical_slider_grows_upward() {
+        let slider = SliderBuilder::new().vertical().slider;
+        // 200px track from y = 0 (top) to y = 200 (bottom), at scale factor 2
+        let center = Vec2::new(20.0, 200.0);
+        let size = Vec2::new(24.0, 400.0);
+
+        let near_top = slider_value_at(Vec2::new(10.0, 4.0), center, size, 0.5, &slider);
+        assert!((near_top - 98.0).abs() < 1e-3, "{near_top}");
+
+        let bottom = slider_value_at(Vec2::new(10.0, 210.0), center, size, 0.5, &slider);
+        assert_eq!(bottom, slider.min);
+    }
+
+    #[test]
+    fn test_arrow_keys_move_focused_slider_one_step() {
+        let mut world = World::new();
+        world.init_resource::<Events<SliderValueChangeEvent>>();
+        world.init_resource::<Events<SliderValueCommitEvent>>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        let slider = world
+            .spawn(
+                SliderBuilder::new()
+                    .range(0.0, 10.0)
+                    .step(0.5)
+                    .value(5.0)
+                    .build(),
+            )
+            .id();
+        world.insert_resource(UiFocus {
+            focused: Some(slider),
+            focus_visible: true,
+        });
+        let press = |world: &mut World, key: KeyCode| {
+            let mut keyboard = world.resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.reset_all();
+            keyboard.press(key);
+            world.run_system_once(handle_slider_keyboard).unwrap();
+            world.get::<SliderComponent>(slider).unwrap().value
+        };
+
+        assert_eq!(press(&mut world, KeyCode::ArrowRight), 5.5);
+        assert_eq!(press(&mut world, KeyCode::ArrowLeft), 5.0);
+        assert_eq!(press(&mut world, KeyCode::ArrowDown), 4.5);
+        assert_eq!(drain::<SliderValueChangeEvent>(&mut world).len(), 3);
+        assert_eq!(drain::<SliderValueCommitEvent>(&mut world).len(), 3);
+
+        assert_eq!(press(&mut world, KeyCode::PageUp), 5.5);
+        assert_eq!(press(&mut world, KeyCode::End), 10.0);
+        assert_eq!(press(&mut world, KeyCode::ArrowRight), 10.0);
+        assert_eq!(press(&mut world, KeyCode::Home), 0.0);
+
+        // Only the focused slider listens
+        world.resource_mut::<UiFocus>().focused = None;
+        assert_eq!(press(&mut world, KeyCode::ArrowRight), 0.0);
+    }
+}
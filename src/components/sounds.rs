@@ -0,0 +1,250 @@
+//! Audio feedback for interactions.
+//!
+//! [`play_ui_sounds`] plays the sounds of [`UiSounds`] when controls emit
+//! their events: a click for buttons and selects, toggle-on / toggle-off for
+//! switches, checkboxes, toggles and radios. [`play_hover_sound`] plays the
+//! hover sound when the pointer enters a control, debounced so sweeping across
+//! a row of buttons doesn't stack up sounds. Everything is gated by
+//! [`UiSoundSettings`].
+//!
+//! Controls built with `.mute_sounds()` stay silent. Sounds without an
+//! associated control event (like an error) can be played with
+//! [`PlayUiSound`]:
+//!
+//! ```rust
+//! use ui::components::{PlayUiSound, UiSound};
+//!
+//! fn reject_input(mut sounds: EventWriter<PlayUiSound>) {
+//!     sounds.write(PlayUiSound::new(UiSound::Error));
+//! }
+//! ```
+
+use crate::assets::audio::SoundEffect;
+use crate::components::{
+    button::ButtonClickEvent, checkbox::CheckboxChangeEvent, radio::RadioChangeEvent,
+    select::SelectChangeEvent, switch::SwitchChangeEvent, toggle::ToggleChangeEvent,
+};
+use bevy::{audio::Volume, prelude::*};
+use bevy_picking::prelude::{Over, Pointer};
+use serde::{Deserialize, Serialize};
+
+/// Minimum time between two hover sounds, in seconds
+pub const HOVER_SOUND_DEBOUNCE: f32 = 0.08;
+
+/// The kinds of interaction sounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UiSound {
+    Hover,
+    Click,
+    ToggleOn,
+    ToggleOff,
+    Error,
+}
+
+impl UiSound {
+    /// `ToggleOn` or `ToggleOff` for the new state of a two-state control.
+    pub fn toggle(on: bool) -> Self {
+        if on {
+            Self::ToggleOn
+        } else {
+            Self::ToggleOff
+        }
+    }
+}
+
+/// Sound handles per interaction; `None` keeps that interaction silent.
+///
+/// Only the click sound is set up by default.
+#[derive(Resource, Debug, Clone)]
+pub struct UiSounds {
+    pub hover: Option<Handle<AudioSource>>,
+    pub click: Option<Handle<AudioSource>>,
+    pub toggle_on: Option<Handle<AudioSource>>,
+    pub toggle_off: Option<Handle<AudioSource>>,
+    pub error: Option<Handle<AudioSource>>,
+}
+
+impl FromWorld for UiSounds {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        Self {
+            hover: None,
+            click: Some(asset_server.load("audio/sfx/tap.ogg")),
+            toggle_on: None,
+            toggle_off: None,
+            error: None,
+        }
+    }
+}
+
+impl UiSounds {
+    pub fn get(&self, sound: UiSound) -> Option<&Handle<AudioSource>> {
+        match sound {
+            UiSound::Hover => self.hover.as_ref(),
+            UiSound::Click => self.click.as_ref(),
+            UiSound::ToggleOn => self.toggle_on.as_ref(),
+            UiSound::ToggleOff => self.toggle_off.as_ref(),
+            UiSound::Error => self.error.as_ref(),
+        }
+    }
+}
+
+/// Global switch and volume for interaction sounds.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiSoundSettings {
+    pub enabled: bool,
+    /// Linear volume, 1.0 being the unchanged sound
+    pub volume: f32,
+}
+
+impl Default for UiSoundSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            volume: 1.0,
+        }
+    }
+}
+
+/// Whether a control opts out of interaction sounds.
+///
+/// Part of every control bundle; set through the builders' `.mute_sounds()`.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MuteSounds(pub bool);
+
+/// Event to play an interaction sound directly.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PlayUiSound {
+    pub sound: UiSound,
+    /// Control the sound belongs to; muted controls don't play it
+    pub source: Option<Entity>,
+}
+
+impl PlayUiSound {
+    pub fn new(sound: UiSound) -> Self {
+        Self {
+            sound,
+            source: None,
+        }
+    }
+
+    pub fn source(mut self, source: Entity) -> Self {
+        self.source = Some(source);
+        self
+    }
+}
+
+/// Whether a hover sound at `now` is far enough from the last one at
+/// `last_played`.
+pub fn hover_sound_due(last_played: Option<f32>, now: f32) -> bool {
+    last_played.is_none_or(|last| now - last >= HOVER_SOUND_DEBOUNCE)
+}
+
+/// System that plays the interaction sounds for control events.
+#[allow(clippy::too_many_arguments)]
+pub fn play_ui_sounds(
+    mut commands: Commands,
+    sounds: Res<UiSounds>,
+    settings: Res<UiSoundSettings>,
+    muted: Query<&MuteSounds>,
+    mut button_clicks: EventReader<ButtonClickEvent>,
+    mut switch_changes: EventReader<SwitchChangeEvent>,
+    mut checkbox_changes: EventReader<CheckboxChangeEvent>,
+    mut toggle_changes: EventReader<ToggleChangeEvent>,
+    mut radio_changes: EventReader<RadioChangeEvent>,
+    mut select_changes: EventReader<SelectChangeEvent>,
+    mut requests: EventReader<PlayUiSound>,
+) {
+    let requests: Vec<PlayUiSound> = button_clicks
+        .read()
+        .map(|event| PlayUiSound::new(UiSound::Click).source(event.button_entity))
+        .chain(switch_changes.read().map(|event| {
+            PlayUiSound::new(UiSound::toggle(event.checked)).source(event.switch_entity)
+        }))
+        .chain(checkbox_changes.read().map(|event| {
+            PlayUiSound::new(UiSound::toggle(event.checked)).source(event.checkbox_entity)
+        }))
+        .chain(toggle_changes.read().map(|event| {
+            PlayUiSound::new(UiSound::toggle(event.pressed)).source(event.toggle_entity)
+        }))
+        .chain(
+            radio_changes
+                .read()
+                .map(|event| PlayUiSound::new(UiSound::ToggleOn).source(event.radio_entity)),
+        )
+        .chain(
+            select_changes
+                .read()
+                .map(|event| PlayUiSound::new(UiSound::Click).source(event.select_entity)),
+        )
+        .chain(requests.read().copied())
+        .collect();
+
+    if !settings.enabled {
+        return;
+    }
+
+    for request in requests {
+        let is_muted = request
+            .source
+            .and_then(|entity| muted.get(entity).ok())
+            .is_some_and(|mute| mute.0);
+        if is_muted {
+            continue;
+        }
+        if let Some(handle) = sounds.get(request.sound) {
+            commands.spawn(ui_sound(handle.clone(), &settings));
+        }
+    }
+}
+
+/// Plays the hover sound when the pointer enters an unmuted control.
+pub fn play_hover_sound(
+    trigger: Trigger<Pointer<Over>>,
+    mut commands: Commands,
+    sounds: Res<UiSounds>,
+    settings: Res<UiSoundSettings>,
+    time: Res<Time>,
+    muted: Query<&MuteSounds>,
+    mut last_played: Local<Option<f32>>,
+) {
+    // The event bubbles up the hierarchy; only controls themselves make a sound
+    let is_unmuted_control = muted.get(trigger.target()).is_ok_and(|mute| !mute.0);
+    if !settings.enabled || !is_unmuted_control {
+        return;
+    }
+    let Some(handle) = sounds.hover.as_ref() else {
+        return;
+    };
+
+    let now = time.elapsed_secs();
+    if !hover_sound_due(*last_played, now) {
+        return;
+    }
+    *last_played = Some(now);
+    commands.spawn(ui_sound(handle.clone(), &settings));
+}
+
+fn ui_sound(handle: Handle<AudioSource>, settings: &UiSoundSettings) -> impl Bundle {
+    (
+        AudioPlayer(handle),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(settings.volume)),
+        SoundEffect,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hover_sound_is_debounced() {
+        assert!(hover_sound_due(None, 0.0));
+        assert!(!hover_sound_due(
+            Some(1.0),
+            1.0 + HOVER_SOUND_DEBOUNCE / 2.0
+        ));
+        assert!(hover_sound_due(Some(1.0), 1.0 + HOVER_SOUND_DEBOUNCE));
+    }
+}
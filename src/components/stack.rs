@@ -0,0 +1,304 @@
+//! Stacks: short builders for the most common flex layouts.
+//!
+//! [`VStack`] lays its children out top to bottom, [`HStack`] left to right,
+//! vertically centered, and [`ZStack`] on top of each other, later children
+//! in front. All three build a transparent [`FlexComponent`], so everything
+//! a flex container can do stays available through [`StackBuilder::flex`]:
+//!
+//! ```rust
+//! parent
+//!     .spawn(VStack::new("settings").spacing(12.0).leading().build())
+//!     .with_children(|parent| {
+//!         parent.spawn(HStack::new("volume").spacing(8.0).build());
+//!         parent.spawn(HStack::new("brightness").spacing(8.0).build());
+//!     });
+//!
+//! // Badge over the corner of an avatar
+//! parent.spawn(ZStack::new("avatar").top().trailing().build());
+//! ```
+//!
+//! Alignment shortcuts name edges rather than flex axes: `leading` and
+//! `trailing` are the left and right, `top` and `bottom` the vertical ones.
+//! The first child of a [`ZStack`] stays in the flow and gives the stack its
+//! size; the later ones are positioned absolutely over it by
+//! [`stack_zstack_children`], and aligned like this within the stack.
+
+use bevy::prelude::*;
+
+use crate::{
+    components::flex::{FlexBuilder, FlexComponent},
+    utilities::{
+        naming::Namespace,
+        node_edit::{NodeEdits, WithNode},
+        sizing::NodeSizing,
+    },
+};
+
+/// Column stack, children aligned to the leading edge by default.
+pub struct VStack;
+
+impl VStack {
+    /// Builder of a column stack named `name`.
+    pub fn new(name: impl AsRef<str>) -> StackBuilder {
+        StackBuilder::new(
+            FlexComponent::column(name).align_start(),
+            StackAxis::Vertical,
+        )
+    }
+}
+
+/// Row stack, children centered vertically by default.
+pub struct HStack;
+
+impl HStack {
+    /// Builder of a row stack named `name`.
+    pub fn new(name: impl AsRef<str>) -> StackBuilder {
+        StackBuilder::new(
+            FlexComponent::row(name).align_center(),
+            StackAxis::Horizontal,
+        )
+    }
+}
+
+/// Stack of layers: children are placed on top of each other, centered by
+/// default.
+pub struct ZStack;
+
+impl ZStack {
+    /// Builder of a layered stack named `name`.
+    pub fn new(name: impl AsRef<str>) -> StackBuilder {
+        StackBuilder::new(FlexComponent::center(name), StackAxis::Depth)
+    }
+}
+
+/// Stack container, added by [`StackBuilder::build`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct StackComponent {
+    pub axis: StackAxis,
+}
+
+/// Direction a stack places its children in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackAxis {
+    Vertical,
+    Horizontal,
+    Depth,
+}
+
+/// Builder for [`VStack`], [`HStack`] and [`ZStack`].
+pub struct StackBuilder {
+    flex: FlexBuilder,
+    axis: StackAxis,
+}
+
+impl StackBuilder {
+    fn new(flex: FlexBuilder, axis: StackAxis) -> Self {
+        Self {
+            flex: flex.background_color(Color::NONE),
+            axis,
+        }
+    }
+
+    /// Space between children in pixels
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.flex = self.flex.gap(spacing);
+        self
+    }
+
+    /// Padding on all sides
+    pub fn padding(mut self, padding: Val) -> Self {
+        self.flex = self.flex.padding(padding);
+        self
+    }
+
+    /// Applies any other flex option.
+    pub fn flex(mut self, configure: impl FnOnce(FlexBuilder) -> FlexBuilder) -> Self {
+        self.flex = configure(self.flex);
+        self
+    }
+
+    // === ALIGNMENT ===
+
+    /// Aligns children to the left edge
+    pub fn leading(self) -> Self {
+        self.align_horizontal(FlexAlign::Start)
+    }
+
+    /// Aligns children to the right edge
+    pub fn trailing(self) -> Self {
+        self.align_horizontal(FlexAlign::End)
+    }
+
+    /// Aligns children to the top edge
+    pub fn top(self) -> Self {
+        self.align_vertical(FlexAlign::Start)
+    }
+
+    /// Aligns children to the bottom edge
+    pub fn bottom(self) -> Self {
+        self.align_vertical(FlexAlign::End)
+    }
+
+    /// Centers children on both axes
+    pub fn center(self) -> Self {
+        self.align_horizontal(FlexAlign::Center)
+            .align_vertical(FlexAlign::Center)
+    }
+
+    /// Stretches children across the stack, e.g. full-width rows in a
+    /// [`VStack`]
+    pub fn stretch(mut self) -> Self {
+        self.flex = self.flex.align_stretch();
+        self
+    }
+
+    fn align_horizontal(mut self, align: FlexAlign) -> Self {
+        self.flex = match self.axis {
+            // Cross axis of a column
+            StackAxis::Vertical => self.flex.align_items(align.items()),
+            StackAxis::Horizontal | StackAxis::Depth => self.flex.justify_content(align.content()),
+        };
+        self
+    }
+
+    fn align_vertical(mut self, align: FlexAlign) -> Self {
+        self.flex = match self.axis {
+            StackAxis::Vertical => self.flex.justify_content(align.content()),
+            StackAxis::Horizontal | StackAxis::Depth => self.flex.align_items(align.items()),
+        };
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        (self.flex.build(), StackComponent { axis: self.axis })
+    }
+}
+
+impl NodeSizing for StackBuilder {
+    fn node_mut(&mut self) -> &mut Node {
+        self.flex.node_mut()
+    }
+}
+
+impl Namespace for StackBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        self.flex.name_mut()
+    }
+}
+
+impl WithNode for StackBuilder {
+    fn node_edits_mut(&mut self) -> &mut NodeEdits {
+        self.flex.node_edits_mut()
+    }
+}
+
+/// Position along one axis, for both flex alignment properties.
+#[derive(Debug, Clone, Copy)]
+enum FlexAlign {
+    Start,
+    Center,
+    End,
+}
+
+impl FlexAlign {
+    fn items(self) -> AlignItems {
+        match self {
+            FlexAlign::Start => AlignItems::Start,
+            FlexAlign::Center => AlignItems::Center,
+            FlexAlign::End => AlignItems::End,
+        }
+    }
+
+    fn content(self) -> JustifyContent {
+        match self {
+            FlexAlign::Start => JustifyContent::Start,
+            FlexAlign::Center => JustifyContent::Center,
+            FlexAlign::End => JustifyContent::End,
+        }
+    }
+}
+
+/// Positions all but the first child of [`ZStack`]s absolutely, so they
+/// overlap the first one, which sizes the stack.
+pub fn stack_zstack_children(
+    stacks: Query<(&StackComponent, &Children), Changed<Children>>,
+    mut nodes: Query<&mut Node>,
+) {
+    for (stack, children) in &stacks {
+        if stack.axis != StackAxis::Depth {
+            continue;
+        }
+        for child in children.iter().skip(1) {
+            if let Ok(mut node) = nodes.get_mut(child) {
+                if node.position_type != PositionType::Absolute {
+                    node.position_type = PositionType::Absolute;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_app;
+
+    fn built_node(builder: StackBuilder) -> Node {
+        let mut world = World::new();
+        let entity = world.spawn(builder.build()).id();
+        world.get::<Node>(entity).unwrap().clone()
+    }
+
+    #[test]
+    fn test_vstack_is_column_flex() {
+        let node = built_node(VStack::new("list").spacing(8.0));
+        assert_eq!(node.display, Display::Flex);
+        assert_eq!(node.flex_direction, FlexDirection::Column);
+        assert_eq!(node.row_gap, Val::Px(8.0));
+        assert_eq!(node.align_items, AlignItems::Start);
+
+        let node = built_node(VStack::new("list").trailing().bottom());
+        assert_eq!(node.align_items, AlignItems::End);
+        assert_eq!(node.justify_content, JustifyContent::End);
+    }
+
+    #[test]
+    fn test_hstack_is_centered_row_flex() {
+        let node = built_node(HStack::new("toolbar"));
+        assert_eq!(node.flex_direction, FlexDirection::Row);
+        assert_eq!(node.align_items, AlignItems::Center);
+
+        let node = built_node(HStack::new("toolbar").top().trailing());
+        assert_eq!(node.align_items, AlignItems::Start);
+        assert_eq!(node.justify_content, JustifyContent::End);
+    }
+
+    #[test]
+    fn test_zstack_layers_children_over_the_first() {
+        let mut app = test_app();
+        let stack = app
+            .world_mut()
+            .spawn(ZStack::new("avatar").build())
+            .with_children(|parent| {
+                parent.spawn(Node::default());
+                parent.spawn(Node::default());
+            })
+            .id();
+        app.update();
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<StackComponent>(stack).unwrap().axis,
+            StackAxis::Depth
+        );
+        assert_eq!(
+            world.get::<Node>(stack).unwrap().position_type,
+            PositionType::Relative
+        );
+        let position = |child: &Entity| world.get::<Node>(*child).unwrap().position_type;
+        let children = world.get::<Children>(stack).unwrap();
+        // The first child sizes the stack, the others overlap it
+        assert_eq!(position(&children[0]), PositionType::Relative);
+        assert_eq!(position(&children[1]), PositionType::Absolute);
+    }
+}
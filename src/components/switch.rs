@@ -1,6 +1,23 @@
+use std::time::Duration;
+
 use crate::{
+    assets::icons::interface::Interface,
+    components::{
+        a11y::{A11yState, UiA11y, UiA11yValue, UiRole},
+        disabled::DisabledInteraction,
+        focus::Focusable,
+        sounds::MuteSounds,
+        text::Text as UiText,
+        validation::Validate,
+    },
     theme::{
-        color::{accent_palette, UiColorPalette},
+        color::{accent_palette, disabled_color, disabled_colors, theme, UiColorPalette},
+        motion::UiMotion,
+    },
+    utilities::{
+        naming::{strip_namespace, Namespace},
+        side_label::{LabelSide, SideLabel},
+        transition::{color_transitions, Easing},
     },
 };
 use bevy::prelude::*;
@@ -28,9 +45,15 @@ pub struct SwitchComponent {
     pub color: UiColorPalette,
     pub checked: bool,
     pub disabled: bool,
+    /// Whether the switch must be on for a form to be valid
+    pub required: bool,
     pub high_contrast: bool,
     pub current_state: SwitchState,
     pub is_pressed: bool, // Track if currently pressed
+    pub on_label: Option<String>,
+    pub off_label: Option<String>,
+    /// Glyphs shown on the thumb as `(on, off)`, e.g. a check and a cross
+    pub thumb_icons: Option<(&'static str, &'static str)>,
 }
 
 impl Default for SwitchComponent {
@@ -41,13 +64,48 @@ impl Default for SwitchComponent {
             color: accent_palette(),
             checked: false,
             disabled: false,
+            required: false,
             high_contrast: false,
             current_state: SwitchState::Normal,
             is_pressed: false,
+            on_label: None,
+            off_label: None,
+            thumb_icons: None,
         }
     }
 }
 
+// Colors follow through `update_switch_styling`
+impl A11yState for SwitchComponent {
+    fn a11y_value(&self) -> UiA11yValue {
+        UiA11yValue::Checked(self.checked)
+    }
+}
+
+impl DisabledInteraction for SwitchComponent {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+}
+
+impl Validate for SwitchComponent {
+    fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn validate(&self) -> bool {
+        !self.required || self.checked
+    }
+
+    fn valid_border_color(&self) -> BorderColor {
+        self.get_styling(self.current_state).border_color
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum SwitchSize {
     /// Small size - 16px height
@@ -83,6 +141,15 @@ impl SwitchSize {
     pub fn thumb_padding(self) -> f32 {
         (self.height() - self.thumb_size()) / 2.0
     }
+
+    /// Left offset of the thumb in the on or off position.
+    pub fn thumb_x(self, checked: bool) -> f32 {
+        if checked {
+            self.width() - self.thumb_padding() - self.thumb_size()
+        } else {
+            0.0
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -96,6 +163,20 @@ pub enum SwitchVariant {
     Soft,
 }
 
+/// Marker for the glyph rendered on top of the switch thumb.
+#[derive(Component, Debug)]
+pub struct SwitchThumbIcon;
+
+/// State label placed beside the switch track.
+#[derive(Component, Debug)]
+pub struct SwitchLabel {
+    /// `true` for the "on" label, `false` for the "off" label
+    pub on: bool,
+}
+
+/// Horizontal gap between the switch track and its state labels.
+const SWITCH_LABEL_GAP: f32 = 8.0;
+
 #[derive(Component, Debug)]
 pub struct SwitchThumb {
     pub target_x: f32,
@@ -114,6 +195,9 @@ impl Default for SwitchThumb {
 pub struct SwitchBuilder {
     name: String,
     switch: SwitchComponent,
+    a11y_label: Option<String>,
+    mute_sounds: bool,
+    transition: Option<(Duration, Easing)>,
 }
 
 impl SwitchBuilder {
@@ -121,6 +205,9 @@ impl SwitchBuilder {
         Self {
             name: format!("{}_Switch", name.into()),
             switch: SwitchComponent::default(),
+            a11y_label: None,
+            mute_sounds: false,
+            transition: None,
         }
     }
 
@@ -174,10 +261,52 @@ impl SwitchBuilder {
         self
     }
 
+    /// Requires the switch to be on for a form to be valid
+    pub fn required(mut self) -> Self {
+        self.switch.required = true;
+        self
+    }
+
+    pub fn mute_sounds(mut self) -> Self {
+        self.mute_sounds = true;
+        self
+    }
+
+    /// Fades the track between its on and off colors instead of switching
+    /// at once.
+    pub fn transition(mut self, duration: Duration, easing: Easing) -> Self {
+        self.transition = Some((duration, easing));
+        self
+    }
+
     pub fn high_contrast(mut self) -> Self {
         self.switch.high_contrast = true;
         self
     }
+
+    /// Label shown to the right of the track, highlighted while checked.
+    pub fn on_label(mut self, label: impl Into<String>) -> Self {
+        self.switch.on_label = Some(label.into());
+        self
+    }
+
+    /// Label shown to the left of the track, highlighted while unchecked.
+    pub fn off_label(mut self, label: impl Into<String>) -> Self {
+        self.switch.off_label = Some(label.into());
+        self
+    }
+
+    /// Interface icon glyphs displayed on the thumb for the on and off state.
+    pub fn thumb_icon(mut self, on_glyph: &'static str, off_glyph: &'static str) -> Self {
+        self.switch.thumb_icons = Some((on_glyph, off_glyph));
+        self
+    }
+
+    /// Overrides the [`UiA11y`] label, which defaults to the name
+    pub fn a11y_label(mut self, label: impl Into<String>) -> Self {
+        self.a11y_label = Some(label.into());
+        self
+    }
 }
 
 impl SwitchComponent {
@@ -196,6 +325,9 @@ impl SwitchComponent {
             background_color: self.calculate_background_color(current_state),
             border_color: self.calculate_border_color(current_state),
             thumb_color: self.calculate_thumb_color(current_state),
+            thumb_icon_color: self.calculate_thumb_icon_color(),
+            on_label_color: self.calculate_label_color(true),
+            off_label_color: self.calculate_label_color(false),
         }
     }
 
@@ -228,21 +360,14 @@ impl SwitchComponent {
             // Disabled state
             (_, _, SwitchState::Disabled) => {
                 if self.checked {
-                    self.color.solid
+                    disabled_color(self.color.solid)
                 } else {
-                    self.color.bg_subtle
+                    disabled_colors(&self.color).0
                 }
             }
         };
 
-        let mut bg_color = BackgroundColor(base_color);
-
-        if state == SwitchState::Disabled {
-            let srgba = bg_color.0.to_srgba();
-            bg_color.0 = Color::srgba(srgba.red, srgba.green, srgba.blue, 0.6);
-        }
-
-        bg_color
+        BackgroundColor(base_color)
     }
 
     fn calculate_border_color(&self, state: SwitchState) -> BorderColor {
@@ -264,14 +389,36 @@ impl SwitchComponent {
                 }
             }
             (SwitchVariant::Classic, SwitchState::Active) => BorderColor(self.color.solid),
-            (SwitchVariant::Classic, SwitchState::Disabled) => {
-                let color = if self.checked {
-                    self.color.solid
-                } else {
-                    self.color.border
-                };
-                BorderColor(color.with_alpha(0.6))
-            }
+            (SwitchVariant::Classic, SwitchState::Disabled) => BorderColor(if self.checked {
+                disabled_color(self.color.solid)
+            } else {
+                disabled_colors(&self.color).1
+            }),
+        }
+    }
+
+    /// Glyph for the thumb in the current checked state, if thumb icons are configured.
+    pub fn thumb_glyph(&self) -> Option<&'static str> {
+        self.thumb_icons
+            .map(|(on, off)| if self.checked { on } else { off })
+    }
+
+    fn calculate_label_color(&self, on: bool) -> Color {
+        let gray = theme().gray;
+        let color = if on == self.checked { gray.text_contrast } else { gray.text };
+
+        if self.disabled {
+            disabled_color(color)
+        } else {
+            color
+        }
+    }
+
+    fn calculate_thumb_icon_color(&self) -> Color {
+        if self.checked {
+            self.color.solid
+        } else {
+            theme().gray.solid
         }
     }
 
@@ -295,6 +442,9 @@ pub struct SwitchStyling {
     pub background_color: BackgroundColor,
     pub border_color: BorderColor,
     pub thumb_color: Color,
+    pub thumb_icon_color: Color,
+    pub on_label_color: Color,
+    pub off_label_color: Color,
 }
 
 impl SwitchBuilder {
@@ -338,7 +488,17 @@ impl SwitchBuilder {
         let border_radius = self.calculate_border_radius();
         let size = self.switch.size;
         let checked = self.switch.checked;
-        let thumb_color = self.switch.get_styling(SwitchState::Normal).thumb_color;
+        let styling = self.switch.get_styling(SwitchState::Normal);
+        let thumb_color = styling.thumb_color;
+        let on_label = self.switch.on_label.clone();
+        let off_label = self.switch.off_label.clone();
+        let thumb_glyph = self.switch.thumb_glyph();
+        let focusable = Focusable::new().disabled(self.switch.disabled);
+        let a11y_label = self.a11y_label.clone().unwrap_or_else(|| {
+            let name = strip_namespace(&self.name);
+            name.strip_suffix("_Switch").unwrap_or(name).to_string()
+        });
+        let a11y = UiA11y::new(UiRole::Switch, a11y_label).value(self.switch.a11y_value());
 
         (
             Name::new(self.name.clone()),
@@ -348,12 +508,22 @@ impl SwitchBuilder {
             border_color,
             border_radius,
             Pickable::default(),
+            focusable,
+            MuteSounds(self.mute_sounds),
+            color_transitions(self.transition),
+            a11y,
             Button,
             Interaction::None,
             SwitchChildSpawner {
                 size,
                 checked,
                 thumb_color,
+                thumb_glyph,
+                thumb_icon_color: styling.thumb_icon_color,
+                on_label,
+                off_label,
+                on_label_color: styling.on_label_color,
+                off_label_color: styling.off_label_color,
             },
         )
     }
@@ -364,6 +534,12 @@ pub struct SwitchChildSpawner {
     size: SwitchSize,
     checked: bool,
     thumb_color: Color,
+    thumb_glyph: Option<&'static str>,
+    thumb_icon_color: Color,
+    on_label: Option<String>,
+    off_label: Option<String>,
+    on_label_color: Color,
+    off_label_color: Color,
 }
 
 pub fn spawn_switch_children(
@@ -372,14 +548,10 @@ pub fn spawn_switch_children(
 ) {
     for (entity, spawner) in &query {
         let thumb_size = spawner.size.thumb_size();
-        let target_x = if spawner.checked {
-            spawner.size.width() - spawner.size.thumb_padding() - thumb_size
-        } else {
-            0.0
-        };
+        let target_x = spawner.size.thumb_x(spawner.checked);
 
         commands.entity(entity).with_children(|parent| {
-            parent.spawn((
+            let mut thumb = parent.spawn((
                 Name::new("SwitchThumb"),
                 SwitchThumb {
                     target_x,
@@ -391,12 +563,54 @@ pub fn spawn_switch_children(
                     position_type: PositionType::Absolute,
                     left: Val::Px(target_x),
                     top: Val::Px((spawner.size.height() - thumb_size) / 2.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
                     ..default()
                 },
                 BackgroundColor(spawner.thumb_color),
                 BorderRadius::all(Val::Px(thumb_size / 2.0)),
                 Pickable::IGNORE,
             ));
+
+            if let Some(glyph) = spawner.thumb_glyph {
+                thumb.with_children(|thumb| {
+                    thumb
+                        .spawn((
+                            Name::new("SwitchThumbIcon"),
+                            Interface::new(glyph).build(),
+                            SwitchThumbIcon,
+                            Pickable::IGNORE,
+                        ))
+                        .insert((
+                            TextFont {
+                                font_size: thumb_size * 0.75,
+                                ..default()
+                            },
+                            TextColor(spawner.thumb_icon_color),
+                        ));
+                });
+            }
+
+            for (label, on, color) in [
+                (&spawner.off_label, false, spawner.off_label_color),
+                (&spawner.on_label, true, spawner.on_label_color),
+            ] {
+                let Some(label) = label else {
+                    continue;
+                };
+
+                // Anchored just outside the track, which makes room for it
+                let side = if on { LabelSide::Right } else { LabelSide::Left };
+                let side_label = SideLabel::new(side, SWITCH_LABEL_GAP);
+                parent
+                    .spawn((
+                        Name::new(if on { "SwitchOnLabel" } else { "SwitchOffLabel" }),
+                        UiText::label(label.clone()).build(),
+                        SwitchLabel { on },
+                        Pickable::IGNORE,
+                    ))
+                    .insert((side_label.node(), side_label, TextColor(color)));
+            }
         });
 
         // Remove the spawner component as it's no longer needed
@@ -419,27 +633,25 @@ pub fn setup_switch_interactions(
     }
 }
 
-// Separate system to update thumb positions when switch state changes
-pub fn update_switch_thumb_positions(
+/// Moves the thumb of switches whose `checked` was set from user code, e.g.
+/// to keep a "mute" switch in sync with the audio settings. Clicks move the
+/// thumb themselves; no [`SwitchChangeEvent`] is sent for outside changes.
+pub fn update_switch_from_external(
     switches_query: Query<(Entity, &SwitchComponent), Changed<SwitchComponent>>,
     children_query: Query<&Children>,
-    mut thumb_query: Query<&mut SwitchThumb>,
+    mut thumb_query: Query<(&mut Node, &mut SwitchThumb)>,
 ) {
     for (entity, switch) in &switches_query {
-        if let Ok(children) = children_query.get(entity) {
-            for child in children.iter() {
-                if let Ok(mut thumb) = thumb_query.get_mut(child) {
-                    let size = switch.size;
-                    let new_target_x = if switch.checked {
-                        size.width() - size.thumb_padding() - size.thumb_size()
-                    } else {
-                        0.0
-                    };
-                    
-                    // Only update if target changed to prevent unnecessary animations
-                    if (thumb.target_x - new_target_x).abs() > 0.1 {
-                        thumb.target_x = new_target_x;
-                    }
+        let Ok(children) = children_query.get(entity) else {
+            continue;
+        };
+        let target_x = switch.size.thumb_x(switch.checked);
+        for child in children.iter() {
+            if let Ok((mut node, mut thumb)) = thumb_query.get_mut(child) {
+                // Hover and press changes leave the thumb where it is
+                if (thumb.target_x - target_x).abs() > 0.1 {
+                    thumb.target_x = target_x;
+                    node.left = Val::Px(target_x);
                 }
             }
         }
@@ -448,13 +660,14 @@ pub fn update_switch_thumb_positions(
 
 pub fn animate_switch_thumbs(
     time: Res<Time>,
+    motion: Res<UiMotion>,
     mut thumb_query: Query<(&mut Node, &SwitchThumb)>,
 ) {
     for (mut node, thumb) in thumb_query.iter_mut() {
         if let Val::Px(current_x) = node.left {
             let diff = thumb.target_x - current_x;
             
-            if diff.abs() > 0.5 {
+            if diff.abs() > 0.5 && !motion.reduced {
                 // Calculate new position with smooth easing
                 let movement = diff * thumb.animation_speed * time.delta_secs();
                 let new_x = current_x + movement;
@@ -541,12 +754,7 @@ fn on_switch_release(
             if let Ok(children) = children_query.get(entity) {
                 for child in children.iter() {
                     if let Ok((mut node, mut thumb)) = thumb_query.get_mut(child) {
-                        let size = switch.size;
-                        let new_x = if switch.checked {
-                            size.width() - size.thumb_padding() - size.thumb_size()
-                        } else {
-                            0.0
-                        };
+                        let new_x = switch.size.thumb_x(switch.checked);
                         thumb.target_x = new_x;
                         node.left = Val::Px(new_x); // Set position immediately
                     }
@@ -563,12 +771,17 @@ fn on_switch_release(
     }
 }
 
+/// Thumb icons, apart from the labels that share their text color.
+type SwitchThumbIconFilter = (With<SwitchThumbIcon>, Without<SwitchLabel>);
+
 // System to update visual styling when switch state changes
 pub fn update_switch_styling(
     switches_query: Query<(Entity, &SwitchComponent), Changed<SwitchComponent>>,
     mut bg_colors: Query<&mut BackgroundColor, (With<SwitchComponent>, Without<SwitchThumb>)>,
     children_query: Query<&Children>,
     mut thumb_bg_query: Query<&mut BackgroundColor, (With<SwitchThumb>, Without<SwitchComponent>)>,
+    mut thumb_icons: Query<(&mut Text, &mut TextColor), SwitchThumbIconFilter>,
+    mut labels: Query<(&SwitchLabel, &mut TextColor), Without<SwitchThumbIcon>>,
 ) {
     for (entity, switch) in &switches_query {
         let styling = switch.get_styling(switch.current_state);
@@ -584,9 +797,78 @@ pub fn update_switch_styling(
                 if let Ok(mut thumb_bg) = thumb_bg_query.get_mut(child) {
                     *thumb_bg = BackgroundColor(styling.thumb_color);
                 }
+
+                if let Ok((label, mut text_color)) = labels.get_mut(child) {
+                    *text_color = TextColor(if label.on {
+                        styling.on_label_color
+                    } else {
+                        styling.off_label_color
+                    });
+                }
+
+                // Swap the thumb glyph to match the checked state
+                let (Some(glyph), Ok(thumb_children)) =
+                    (switch.thumb_glyph(), children_query.get(child))
+                else {
+                    continue;
+                };
+                for icon in thumb_children.iter() {
+                    if let Ok((mut text, mut text_color)) = thumb_icons.get_mut(icon) {
+                        if text.0 != glyph {
+                            text.0 = glyph.to_string();
+                        }
+                        *text_color = TextColor(styling.thumb_icon_color);
+                    }
+                }
             }
         }
     }
 }
 
-pub type Switch = SwitchComponent;
\ No newline at end of file
+pub type Switch = SwitchComponent;
+
+impl Namespace for SwitchBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{drain_events, test_app};
+
+    #[test]
+    fn test_checked_set_from_code_moves_thumb_and_restyles() {
+        let mut app = test_app();
+        let switch = app
+            .world_mut()
+            .spawn(SwitchBuilder::new("Mute").build())
+            .id();
+        app.update();
+
+        app.world_mut()
+            .get_mut::<SwitchComponent>(switch)
+            .unwrap()
+            .checked = true;
+        app.update();
+
+        let component = app.world().get::<SwitchComponent>(switch).unwrap();
+        let expected = component.get_styling(SwitchState::Normal).background_color;
+        assert_eq!(
+            *app.world().get::<BackgroundColor>(switch).unwrap(),
+            expected
+        );
+
+        let thumb = app
+            .world()
+            .get::<Children>(switch)
+            .unwrap()
+            .iter()
+            .find(|child| app.world().get::<SwitchThumb>(*child).is_some())
+            .unwrap();
+        let left = app.world().get::<Node>(thumb).unwrap().left;
+        assert_eq!(left, Val::Px(SwitchSize::Size2.thumb_x(true)));
+        assert!(drain_events::<SwitchChangeEvent>(&mut app).is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+//! Builder of the Table component.
+
+use super::core::{
+    CellContent, ColumnDef, TableBody, TableComponent, TableHeaderCell, TableRow, TableSortCaret,
+};
+use crate::{
+    assets::{ChevronsUpDown, Interface},
+    components::{grid::Grid, text::Text as UiText},
+    theme::{
+        color::{TextColor as TextColorEnum, UiColorPalette},
+        layout::UiLayout,
+        typography::TextSize,
+    },
+    utilities::naming::Namespace,
+};
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
+use bevy_picking::prelude::Pickable;
+
+pub struct TableBuilder {
+    name: String,
+    table: TableComponent,
+    width: Val,
+}
+
+impl TableComponent {
+    pub fn new(name: impl Into<String>) -> TableBuilder {
+        TableBuilder::new(name)
+    }
+}
+
+impl TableBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: format!("{}_Table", name.into()),
+            table: TableComponent::default(),
+            width: Val::Percent(100.0),
+        }
+    }
+
+    pub fn columns(mut self, columns: Vec<ColumnDef>) -> Self {
+        self.table.columns = columns;
+        self
+    }
+
+    pub fn set_rows(mut self, rows: Vec<Vec<CellContent>>) -> Self {
+        self.table.rows = rows;
+        self
+    }
+
+    /// Zebra striping: every other row uses the palette's `bg_subtle`.
+    pub fn striped(mut self) -> Self {
+        self.table.striped = true;
+        self
+    }
+
+    pub fn color(mut self, color: UiColorPalette) -> Self {
+        self.table.color = color;
+        self
+    }
+
+    pub fn width(mut self, width: Val) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+/// Padding of header and body cells.
+fn cell_style(justify_content: JustifyContent) -> Node {
+    let layout = UiLayout::default();
+    Node {
+        padding: UiRect::axes(Val::Px(layout.padding.base), Val::Px(layout.padding.sm)),
+        column_gap: Val::Px(layout.gap.xs),
+        align_items: AlignItems::Center,
+        justify_content,
+        min_width: Val::Px(0.0),
+        overflow: Overflow::clip(),
+        ..default()
+    }
+}
+
+/// Grid row sharing the table's column tracks, so cells line up across rows.
+pub(super) fn row_grid(name: String, table: &TableComponent) -> impl Bundle {
+    Grid::new(name)
+        .columns_sizes(table.column_sizes())
+        .fill_width()
+        .background_color(Color::NONE)
+        .border_color(Color::NONE)
+        .build()
+}
+
+/// Spawns a data row with one cell per column.
+pub(super) fn spawn_table_row(
+    commands: &mut Commands,
+    table_entity: Entity,
+    table: &TableComponent,
+    index: usize,
+) -> Entity {
+    let row = &table.rows[index];
+    let text_color = TextColorEnum::Custom(table.color.text);
+
+    commands
+        .spawn((
+            row_grid(format!("TableRow{index}"), table),
+            TableRow {
+                table: table_entity,
+                index,
+            },
+            Interaction::default(),
+        ))
+        .insert(BackgroundColor(table.row_background(index)))
+        .with_children(|parent| {
+            for column in 0..table.columns.len() {
+                let (content, justify) = match row.get(column) {
+                    Some(cell @ CellContent::Number { .. }) => {
+                        (cell.display_text(), JustifyContent::FlexEnd)
+                    }
+                    Some(cell) => (cell.display_text(), JustifyContent::FlexStart),
+                    None => (String::new(), JustifyContent::FlexStart),
+                };
+                parent
+                    .spawn((cell_style(justify), Pickable::IGNORE))
+                    .with_children(|cell| {
+                        cell.spawn((
+                            UiText::body(content)
+                                .size(TextSize::Sm)
+                                .color(text_color)
+                                .build(),
+                            Pickable::IGNORE,
+                        ));
+                    });
+            }
+        })
+        .id()
+}
+
+impl TableBuilder {
+    fn calculate_style(&self) -> Node {
+        Node {
+            width: self.width,
+            flex_direction: FlexDirection::Column,
+            border: UiRect::all(Val::Px(UiLayout::default().border.xs)),
+            overflow: Overflow::clip(),
+            ..default()
+        }
+    }
+
+    /// Builds the table with its header; body rows are spawned by
+    /// [`update_table_body`](super::update_table_body).
+    pub fn build(self) -> impl Bundle {
+        let node = self.calculate_style();
+        let table = self.table.clone();
+        let name = self.name.clone();
+
+        (
+            Name::new(self.name),
+            self.table,
+            node,
+            BackgroundColor(Color::NONE),
+            BorderColor(table.color.border),
+            BorderRadius::all(Val::Px(UiLayout::default().radius.base)),
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                let table_entity = parent.target_entity();
+                let palette = table.color.clone();
+
+                parent
+                    .spawn(row_grid(format!("{name}_Header"), &table))
+                    .insert((BackgroundColor(palette.bg), BorderRadius::ZERO))
+                    .with_children(|header| {
+                        for (index, column) in table.columns.iter().enumerate() {
+                            header
+                                .spawn((
+                                    cell_style(JustifyContent::FlexStart),
+                                    TableHeaderCell {
+                                        table: table_entity,
+                                        column: index,
+                                    },
+                                    BackgroundColor(Color::NONE),
+                                    Interaction::default(),
+                                ))
+                                .with_children(|cell| {
+                                    cell.spawn((
+                                        UiText::label(column.title.clone())
+                                            .size(TextSize::Sm)
+                                            .color(TextColorEnum::Custom(palette.text_contrast))
+                                            .build(),
+                                        Pickable::IGNORE,
+                                    ));
+                                    if column.sortable {
+                                        cell.spawn((
+                                            Interface::new(ChevronsUpDown)
+                                                .size(TextSize::Sm)
+                                                .color(TextColorEnum::Custom(palette.text))
+                                                .build(),
+                                            TableSortCaret {
+                                                table: table_entity,
+                                                column: index,
+                                            },
+                                            Pickable::IGNORE,
+                                        ));
+                                    }
+                                });
+                        }
+                    });
+
+                parent.spawn((
+                    Name::new(format!("{name}_Body")),
+                    TableBody {
+                        table: table_entity,
+                    },
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        ..default()
+                    },
+                ));
+            })),
+        )
+    }
+}
+
+pub type Table = TableComponent;
+
+impl Namespace for TableBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
@@ -0,0 +1,267 @@
+//! Core types of the Table component.
+
+use crate::{
+    components::grid::GridTrackSize,
+    theme::color::{theme, UiColorPalette},
+};
+use bevy::prelude::*;
+use std::cmp::Ordering;
+
+/// Event emitted when a sortable header cell is clicked.
+///
+/// The table only tracks the sort state; the app is expected to sort its
+/// data (e.g. with [`sort_rows`]) and pass it back via
+/// [`TableComponent::set_rows`].
+#[derive(Event, Debug, Clone)]
+pub struct TableSortEvent {
+    pub table_entity: Entity,
+    pub column: usize,
+    pub direction: SortDirection,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    pub fn toggled(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+}
+
+/// Content of a single table cell.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellContent {
+    Text(String),
+    /// Right-aligned number, shown with `precision` decimal places
+    Number {
+        value: f64,
+        precision: usize,
+    },
+}
+
+impl CellContent {
+    pub fn number(value: f64, precision: usize) -> Self {
+        CellContent::Number { value, precision }
+    }
+
+    pub fn display_text(&self) -> String {
+        match self {
+            CellContent::Text(text) => text.clone(),
+            CellContent::Number { value, precision } => format!("{value:.precision$}"),
+        }
+    }
+
+    /// Orders numbers numerically and text case-insensitively; numbers sort
+    /// before text.
+    pub fn compare(&self, other: &CellContent) -> Ordering {
+        match (self, other) {
+            (CellContent::Number { value: a, .. }, CellContent::Number { value: b, .. }) => {
+                a.total_cmp(b)
+            }
+            (CellContent::Text(a), CellContent::Text(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+            (CellContent::Number { .. }, CellContent::Text(_)) => Ordering::Less,
+            (CellContent::Text(_), CellContent::Number { .. }) => Ordering::Greater,
+        }
+    }
+}
+
+impl From<&str> for CellContent {
+    fn from(text: &str) -> Self {
+        CellContent::Text(text.to_string())
+    }
+}
+
+impl From<String> for CellContent {
+    fn from(text: String) -> Self {
+        CellContent::Text(text)
+    }
+}
+
+impl From<i32> for CellContent {
+    fn from(value: i32) -> Self {
+        CellContent::number(value as f64, 0)
+    }
+}
+
+impl From<f64> for CellContent {
+    fn from(value: f64) -> Self {
+        CellContent::number(value, 2)
+    }
+}
+
+/// Stable sort of `rows` by the cells in `column`; rows missing the column
+/// sort last.
+pub fn sort_rows(rows: &mut [Vec<CellContent>], column: usize, direction: SortDirection) {
+    rows.sort_by(|a, b| {
+        let ordering = match (a.get(column), b.get(column)) {
+            (Some(a), Some(b)) => a.compare(b),
+            (Some(_), None) => return Ordering::Less,
+            (None, Some(_)) => return Ordering::Greater,
+            (None, None) => return Ordering::Equal,
+        };
+        match direction {
+            SortDirection::Ascending => ordering,
+            SortDirection::Descending => ordering.reverse(),
+        }
+    });
+}
+
+/// Definition of a table column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnDef {
+    pub title: String,
+    pub width: GridTrackSize,
+    pub sortable: bool,
+}
+
+impl ColumnDef {
+    /// A flexible (`1fr`), non-sortable column.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            width: GridTrackSize::Fr(1.0),
+            sortable: false,
+        }
+    }
+
+    pub fn width(mut self, width: GridTrackSize) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+}
+
+/// Data table with a header row and one grid row per data row.
+///
+/// Changing `rows` (or the component in general) rebuilds the body rows.
+#[derive(Component, Debug, Clone)]
+pub struct TableComponent {
+    pub columns: Vec<ColumnDef>,
+    pub rows: Vec<Vec<CellContent>>,
+    /// Currently sorted column and direction
+    pub sort: Option<(usize, SortDirection)>,
+    /// Use `bg_subtle` on every other row
+    pub striped: bool,
+    pub color: UiColorPalette,
+}
+
+impl Default for TableComponent {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            sort: None,
+            striped: false,
+            color: theme().gray,
+        }
+    }
+}
+
+impl TableComponent {
+    pub fn set_rows(&mut self, rows: Vec<Vec<CellContent>>) {
+        self.rows = rows;
+    }
+
+    /// Column widths as grid tracks, shared by the header and all rows.
+    pub fn column_sizes(&self) -> Vec<GridTrackSize> {
+        self.columns
+            .iter()
+            .map(|column| column.width.clone())
+            .collect()
+    }
+
+    /// Sort direction a click on `column`'s header selects: ascending first,
+    /// then toggling. `None` for non-sortable columns.
+    pub fn next_sort(&self, column: usize) -> Option<SortDirection> {
+        if !self.columns.get(column)?.sortable {
+            return None;
+        }
+        Some(match self.sort {
+            Some((sorted, direction)) if sorted == column => direction.toggled(),
+            _ => SortDirection::Ascending,
+        })
+    }
+
+    /// Background of the row at `index` when not hovered.
+    pub fn row_background(&self, index: usize) -> Color {
+        if self.striped && index % 2 == 1 {
+            self.color.bg_subtle
+        } else {
+            Color::NONE
+        }
+    }
+}
+
+/// Clickable header cell.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TableHeaderCell {
+    pub table: Entity,
+    pub column: usize,
+}
+
+/// Sort caret inside a sortable header cell.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TableSortCaret {
+    pub table: Entity,
+    pub column: usize,
+}
+
+/// Container of the data rows.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TableBody {
+    pub table: Entity,
+}
+
+/// A data row.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TableRow {
+    pub table: Entity,
+    pub index: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_sort_toggles_and_skips_unsortable_columns() {
+        let mut table = TableComponent {
+            columns: vec![ColumnDef::new("Name").sortable(), ColumnDef::new("Notes")],
+            ..default()
+        };
+        assert_eq!(table.next_sort(0), Some(SortDirection::Ascending));
+        assert_eq!(table.next_sort(1), None);
+        assert_eq!(table.next_sort(5), None);
+
+        table.sort = Some((0, SortDirection::Ascending));
+        assert_eq!(table.next_sort(0), Some(SortDirection::Descending));
+    }
+
+    #[test]
+    fn test_sort_rows_numeric_and_descending() {
+        let mut rows = vec![
+            vec![CellContent::from("b"), CellContent::from(10)],
+            vec![CellContent::from("A"), CellContent::from(9)],
+            vec![CellContent::from("c"), CellContent::from(100)],
+        ];
+
+        sort_rows(&mut rows, 1, SortDirection::Ascending);
+        let numbers: Vec<String> = rows.iter().map(|row| row[1].display_text()).collect();
+        assert_eq!(numbers, vec!["9", "10", "100"]);
+
+        sort_rows(&mut rows, 0, SortDirection::Descending);
+        let names: Vec<String> = rows.iter().map(|row| row[0].display_text()).collect();
+        assert_eq!(names, vec!["c", "b", "A"]);
+    }
+}
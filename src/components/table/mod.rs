@@ -0,0 +1,35 @@
+//! Table Component for Forge UI
+//!
+//! A data table laid out with the Grid component: a header row and one grid
+//! row per data row, all sharing the same column tracks.
+//!
+//! ## Features
+//!
+//! - **Columns**: Title, width as [`GridTrackSize`](crate::components::GridTrackSize) and sortability via [`ColumnDef`]
+//! - **Sorting**: Clicking a sortable header toggles the caret and emits [`TableSortEvent`];
+//!   the app sorts its data and hands it back with [`TableComponent::set_rows`]
+//! - **Zebra Striping**: `.striped()` uses `bg_subtle` on alternating rows
+//! - **Row Hover**: Rows highlight with `bg_hover` under the cursor
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ui::components::{CellContent, ColumnDef, GridTrackSize, Table};
+//!
+//! let table = Table::new("Inventory")
+//!     .columns(vec![
+//!         ColumnDef::new("Item").sortable(),
+//!         ColumnDef::new("Qty").width(GridTrackSize::Px(80.0)).sortable(),
+//!     ])
+//!     .set_rows(vec![vec![CellContent::from("Sword"), CellContent::from(1)]])
+//!     .striped()
+//!     .build();
+//! ```
+
+pub mod builder;
+pub mod core;
+pub mod systems;
+
+pub use builder::*;
+pub use core::*;
+pub use systems::*;
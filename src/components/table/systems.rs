@@ -0,0 +1,115 @@
+//! Interaction and update systems of the Table component.
+
+use super::{
+    builder::spawn_table_row,
+    core::{
+        SortDirection, TableBody, TableComponent, TableHeaderCell, TableRow, TableSortCaret,
+        TableSortEvent,
+    },
+};
+use crate::assets::{ChevronDown, ChevronUp, ChevronsUpDown};
+use bevy::prelude::*;
+
+/// Updates the sort state when a sortable header cell is pressed.
+pub fn handle_table_header_clicks(
+    cells: Query<(&Interaction, &TableHeaderCell), Changed<Interaction>>,
+    mut tables: Query<&mut TableComponent>,
+    mut events: EventWriter<TableSortEvent>,
+) {
+    for (interaction, cell) in &cells {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut table) = tables.get_mut(cell.table) else {
+            continue;
+        };
+        if let Some(direction) = table.next_sort(cell.column) {
+            table.sort = Some((cell.column, direction));
+            events.write(TableSortEvent {
+                table_entity: cell.table,
+                column: cell.column,
+                direction,
+            });
+        }
+    }
+}
+
+/// Highlights sortable header cells on hover.
+pub fn update_table_header_styles(
+    mut cells: Query<(&Interaction, &TableHeaderCell, &mut BackgroundColor), Changed<Interaction>>,
+    tables: Query<&TableComponent>,
+) {
+    for (interaction, cell, mut background) in &mut cells {
+        let Ok(table) = tables.get(cell.table) else {
+            continue;
+        };
+        let sortable = table
+            .columns
+            .get(cell.column)
+            .is_some_and(|column| column.sortable);
+
+        background.0 = match interaction {
+            Interaction::Pressed if sortable => table.color.bg_active,
+            Interaction::Hovered if sortable => table.color.bg_hover,
+            _ => Color::NONE,
+        };
+    }
+}
+
+/// Respawns the body rows and updates the sort carets of changed tables.
+pub fn update_table_body(
+    mut commands: Commands,
+    tables: Query<(Entity, &TableComponent), Changed<TableComponent>>,
+    bodies: Query<(Entity, &TableBody, Option<&Children>)>,
+    mut carets: Query<(&TableSortCaret, &mut Text, &mut TextColor)>,
+) {
+    for (table_entity, table) in &tables {
+        for (body_entity, body, children) in &bodies {
+            if body.table != table_entity {
+                continue;
+            }
+            for child in children.into_iter().flat_map(|children| children.iter()) {
+                commands.entity(child).despawn();
+            }
+            let rows: Vec<Entity> = (0..table.rows.len())
+                .map(|index| spawn_table_row(&mut commands, table_entity, table, index))
+                .collect();
+            commands.entity(body_entity).add_children(&rows);
+        }
+
+        for (caret, mut text, mut color) in &mut carets {
+            if caret.table != table_entity {
+                continue;
+            }
+            let (glyph, caret_color) = match table.sort {
+                Some((column, SortDirection::Ascending)) if column == caret.column => {
+                    (ChevronUp, table.color.text_contrast)
+                }
+                Some((column, SortDirection::Descending)) if column == caret.column => {
+                    (ChevronDown, table.color.text_contrast)
+                }
+                _ => (ChevronsUpDown, table.color.text),
+            };
+            if text.0 != glyph {
+                text.0 = glyph.to_string();
+            }
+            color.0 = caret_color;
+        }
+    }
+}
+
+/// Highlights hovered rows, falling back to the zebra stripe color.
+pub fn update_table_row_colors(
+    mut rows: Query<(&Interaction, &TableRow, &mut BackgroundColor), Changed<Interaction>>,
+    tables: Query<&TableComponent>,
+) {
+    for (interaction, row, mut background) in &mut rows {
+        let Ok(table) = tables.get(row.table) else {
+            continue;
+        };
+        background.0 = match interaction {
+            Interaction::Hovered | Interaction::Pressed => table.color.bg_hover,
+            Interaction::None => table.row_background(row.index),
+        };
+    }
+}
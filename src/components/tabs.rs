@@ -1,16 +1,126 @@
-use bevy::prelude::*;
+//! Tabs Component for Forge UI
+//!
+//! A row of trigger buttons switching between content panels.
+//!
+//! ## Panel mounting
+//!
+//! By default every panel is passed in via [`TabsBuilder::contents`] and
+//! mounted up front; switching tabs only toggles visibility.
+//!
+//! With [`TabsBuilder::lazy`] panels are spawned by a callback the first time
+//! their tab is activated, which saves memory (and spawn time) for tabs that
+//! are never opened. Inactive lazy panels are despawned on switch unless
+//! [`TabsBuilder::keep_alive`] is set: keep-alive trades that memory for
+//! preserving per-panel state such as scroll positions and entered text.
+//!
+//! ## Active indicator
+//!
+//! [`TabsBuilder::indicator`] adds an underline bar or a pill background that
+//! slides to the active trigger instead of recoloring the trigger itself.
+//! It follows the active trigger however it was activated: by click or with
+//! the arrow keys while a trigger has keyboard focus.
+//!
+//! ## Panel transitions
+//!
+//! [`TabsBuilder::panel_transition`] animates switching panels: with
+//! [`TabsPanelTransition::Slide`] the panels follow the direction of travel
+//! like mobile tab views: selecting a tab to the right brings its panel in
+//! from the right while the old one leaves to the left; with
+//! [`TabsPanelTransition::Fade`] they cross-fade. Both panels stay mounted
+//! until the transition ends, also for [`lazy`](TabsBuilder::lazy) tabs. With
+//! reduced motion panels switch at once.
+//!
+//! ## Value binding
+//!
+//! [`TabsBuilder::bind`] keeps the active tab in sync with a field of an app
+//! resource, see [`binding`](crate::components::binding).
+//!
+//! ## Controlled value
+//!
+//! Every [`TabsRoot`] carries a [`TabsValue`] with the active tab's value.
+//! [`TabsBuilder::value`] opens the given tab first and makes the tabs
+//! controlled: user code can then switch tabs by writing the field, e.g. to
+//! restore the open tab from a saved state or deep link, and
+//! [`sync_controlled_tabs`] shows the matching panel. Clicks keep working and
+//! keep the field up to date.
+//!
+//! ```rust
+//! fn open_settings(mut tabs: Query<&mut TabsValue>) {
+//!     for mut tabs in &mut tabs {
+//!         tabs.value = "Settings".to_string();
+//!     }
+//! }
+//! ```
+//!
+//! ## Closing and adding tabs
+//!
+//! [`TabsBuilder::closeable`] puts a close glyph on every trigger, shown while
+//! the trigger is hovered or focused, or always with
+//! [`TabsBuilder::close_visibility`]. Clicking it, or pressing Ctrl+W (Cmd+W)
+//! while the trigger has keyboard focus, emits [`TabCloseEvent`] and despawns
+//! the tab; closing the active tab activates its right neighbour, or its left
+//! one for the last tab.
+//!
+//! [`TabsBuilder::with_add_button`] appends a "+" button emitting
+//! [`TabAddEvent`]; answer it with [`add_tab`]:
+//!
+//! ```rust
+//! fn add_tabs(mut commands: Commands, mut events: EventReader<TabAddEvent>) {
+//!     for event in events.read() {
+//!         let content = commands.spawn(Text::body("New tab").build()).id();
+//!         add_tab(&mut commands, event.tabs, "Untitled", Some(content));
+//!     }
+//! }
+//! ```
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_picking::prelude::{Click, Pickable, Pointer};
 
 use crate::{
+    assets::{Plus, X},
     components::{
-        button::{ButtonBuilder, ButtonVariant, ButtonSize, ButtonClickEvent},
+        a11y::{UiA11y, UiA11yValue, UiRole},
+        binding::{value_binder, ValueBinder},
+        button::{
+            button_click_event, Button as UiButton, ButtonBuilder, ButtonClickEvent, ButtonSize,
+            ButtonState, ButtonVariant,
+        },
+        focus::UiFocus,
     },
-    theme::color::accent_palette,
+    theme::{color::accent_palette, motion::UiMotion, typography::FontFamily},
+    utilities::transition::Easing,
 };
 
+/// Height of the underline indicator
+pub const TAB_UNDERLINE_HEIGHT: f32 = 2.0;
+/// How fast the indicator closes in on the active trigger (per second)
+pub const TAB_INDICATOR_SPEED: f32 = 20.0;
+/// Duration of panel transitions
+pub const TAB_PANEL_TRANSITION_DURATION: Duration = Duration::from_millis(200);
+
 // A marker component for the root of a tabs system.
 #[derive(Component, Default, Debug)]
 pub struct TabsRoot;
 
+/// Value of the active tab of a tabs system, stored on the [`TabsRoot`].
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TabsValue {
+    pub value: String,
+    /// Whether writing `value` from user code switches the tab
+    pub is_controlled: bool,
+}
+
+/// Value requested with [`TabsBuilder::value`], kept on the [`TabsRoot`]
+/// until it has been checked against the triggers.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct TabsInitialValue(pub String);
+
 // A marker component for the list of tab triggers.
 #[derive(Component, Default, Debug)]
 pub struct TabsList;
@@ -20,6 +130,10 @@ pub struct TabsList;
 pub struct TabTrigger {
     // The entity of the content panel this trigger controls.
     pub content: Entity,
+    // The value identifying this tab (its label).
+    pub value: String,
+    // The root entity of the tabs system this trigger belongs to.
+    pub tabs: Entity,
 }
 
 // A marker component for a tab content panel.
@@ -30,6 +144,207 @@ pub struct TabContent;
 #[derive(Component, Default, Debug)]
 pub struct ActiveTab;
 
+/// How the active trigger is highlighted, stored on the [`TabsRoot`].
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TabsIndicator {
+    /// The active trigger itself is filled with the accent color
+    #[default]
+    None,
+    /// A bar along the bottom of the list slides to the active trigger
+    Underline,
+    /// A rounded background slides behind the active trigger
+    Pill,
+}
+
+/// How panels change when switching tabs, stored on the [`TabsRoot`].
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TabsPanelTransition {
+    /// The panels switch at once
+    #[default]
+    None,
+    /// The panels slide in the direction of travel
+    Slide,
+    /// The panels cross-fade
+    Fade,
+}
+
+/// Direction of travel along the triggers when switching tabs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabSlideDirection {
+    /// To a tab further left; its panel enters from the left
+    Left,
+    /// To a tab further right; its panel enters from the right
+    Right,
+}
+
+impl TabSlideDirection {
+    /// Direction of travel from the tab at index `from` to the one at `to`.
+    pub fn between(from: usize, to: usize) -> Self {
+        if to > from {
+            TabSlideDirection::Right
+        } else {
+            TabSlideDirection::Left
+        }
+    }
+}
+
+/// Colors of a node of a fading panel before the fade.
+#[derive(Debug, Clone, Copy)]
+struct FadedColors {
+    background: Option<Color>,
+    border: Option<Color>,
+    text: Option<Color>,
+}
+
+/// Running transition of a panel wrapper, removed when it ends.
+#[derive(Component, Debug, Clone)]
+pub struct TabPanelAnimation {
+    pub transition: TabsPanelTransition,
+    pub direction: TabSlideDirection,
+    /// Whether the panel is leaving; it is hidden when the transition ends
+    pub outgoing: bool,
+    elapsed: Duration,
+    /// Tabs system and value of a lazy panel to unmount once it has left
+    unmount: Option<(Entity, String)>,
+}
+
+impl TabPanelAnimation {
+    fn new(transition: TabsPanelTransition, direction: TabSlideDirection, outgoing: bool) -> Self {
+        Self {
+            transition,
+            direction,
+            outgoing,
+            elapsed: Duration::ZERO,
+            unmount: None,
+        }
+    }
+
+    /// How far the panel is shown, from `0.0` to `1.0`, eased.
+    pub fn shown(&self) -> f32 {
+        let t = self.elapsed.as_secs_f32() / TAB_PANEL_TRANSITION_DURATION.as_secs_f32();
+        let t = Easing::EaseOut.apply(t);
+        if self.outgoing {
+            1.0 - t
+        } else {
+            t
+        }
+    }
+
+    /// Horizontal offset of the panel in percent of its width while sliding.
+    fn slide_offset(&self) -> f32 {
+        // Incoming panels come from the side of travel, outgoing ones leave
+        // toward the other side
+        let side = match self.direction {
+            TabSlideDirection::Right => 100.0,
+            TabSlideDirection::Left => -100.0,
+        };
+        if self.outgoing {
+            -side * (1.0 - self.shown())
+        } else {
+            side * (1.0 - self.shown())
+        }
+    }
+}
+
+/// When the close glyph of a closeable tab shows, stored on the [`TabsRoot`].
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TabCloseVisibility {
+    /// While the trigger is hovered or has keyboard focus
+    #[default]
+    Hover,
+    /// On every trigger at all times
+    Always,
+}
+
+/// Event emitted when a closeable tab is closed; its trigger and panel are
+/// despawned afterwards.
+#[derive(Event, Debug, Clone)]
+pub struct TabCloseEvent {
+    // The root entity of the tabs system the tab belonged to.
+    pub tabs: Entity,
+    // The value of the closed tab.
+    pub value: String,
+}
+
+/// Event emitted when the add button of a tabs system is clicked.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TabAddEvent {
+    pub tabs: Entity,
+}
+
+/// Close glyph of a closeable tab trigger.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TabCloseButton {
+    pub trigger: Entity,
+}
+
+/// The "+" button at the end of a [`TabsList`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TabAddButton {
+    pub tabs: Entity,
+}
+
+/// The list and panel container of a tabs system, for [`add_tab`].
+#[derive(Component, Debug, Clone, Copy)]
+struct TabsParts {
+    list: Entity,
+    panels: Entity,
+}
+
+/// The sliding indicator node inside a [`TabsList`].
+#[derive(Component, Debug)]
+pub struct TabIndicatorNode {
+    // The root entity of the tabs system this indicator belongs to.
+    pub tabs: Entity,
+    // Current offset from the list's left edge and width, in logical pixels.
+    left: f32,
+    width: f32,
+    // Whether the indicator was placed yet; the first placement snaps.
+    placed: bool,
+}
+
+/// Spawns the content of a lazily mounted panel for the given tab value.
+pub type TabPanelSpawner = Arc<dyn Fn(&mut Commands, &str) -> Entity + Send + Sync>;
+
+/// Registry of lazily mounted panels, stored on the [`TabsRoot`].
+///
+/// Maps each tab value to its spawned content, `None` while unmounted.
+#[derive(Component, Clone)]
+pub struct TabPanelRegistry {
+    pub panels: HashMap<String, Option<Entity>>,
+    pub spawner: TabPanelSpawner,
+    // Keep inactive panels mounted (hidden) instead of despawning them.
+    pub keep_alive: bool,
+}
+
+impl TabPanelRegistry {
+    pub fn is_mounted(&self, value: &str) -> bool {
+        matches!(self.panels.get(value), Some(Some(_)))
+    }
+
+    /// Spawns the panel for `value` into `wrapper` unless already mounted.
+    fn mount(&mut self, commands: &mut Commands, value: &str, wrapper: Entity) {
+        if self.is_mounted(value) {
+            return;
+        }
+        let content = (self.spawner)(commands, value);
+        commands.entity(wrapper).add_child(content);
+        self.panels.insert(value.to_string(), Some(content));
+    }
+
+    /// Despawns the panel for `value`, unless panels are kept alive.
+    fn unmount(&mut self, commands: &mut Commands, value: &str) {
+        if self.keep_alive {
+            return;
+        }
+        if let Some(slot) = self.panels.get_mut(value) {
+            if let Some(content) = slot.take() {
+                commands.entity(content).despawn();
+            }
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct TabTriggerBuilder {
     label: String,
@@ -54,6 +369,14 @@ impl TabTriggerBuilder {
 pub struct TabsBuilder {
     triggers: Vec<String>,
     contents: Vec<Entity>,
+    spawner: Option<TabPanelSpawner>,
+    keep_alive: bool,
+    indicator: TabsIndicator,
+    panel_transition: TabsPanelTransition,
+    binding: Option<ValueBinder>,
+    close: Option<TabCloseVisibility>,
+    add_button: bool,
+    value: Option<String>,
 }
 
 impl TabsBuilder {
@@ -71,38 +394,93 @@ impl TabsBuilder {
         self
     }
 
+    /// Spawns each panel with `spawner` when its tab is first activated,
+    /// instead of mounting [`contents`](Self::contents) up front.
+    ///
+    /// The spawner receives the tab's value (its trigger label).
+    pub fn lazy(
+        mut self,
+        spawner: impl Fn(&mut Commands, &str) -> Entity + Send + Sync + 'static,
+    ) -> Self {
+        self.spawner = Some(Arc::new(spawner));
+        self
+    }
+
+    /// Keeps lazily spawned panels alive (hidden) when switching away,
+    /// preserving their state. Only affects [`lazy`](Self::lazy) tabs.
+    pub fn keep_alive(mut self) -> Self {
+        self.keep_alive = true;
+        self
+    }
+
+    /// Highlights the active trigger with a sliding underline or pill.
+    pub fn indicator(mut self, indicator: TabsIndicator) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Animates switching panels; see [Panel transitions](self#panel-transitions).
+    pub fn panel_transition(mut self, transition: TabsPanelTransition) -> Self {
+        self.panel_transition = transition;
+        self
+    }
+
+    /// Opens the tab `value` (its trigger label) first and makes the tabs
+    /// controlled through their [`TabsValue`].
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Puts a close glyph on every trigger, emitting [`TabCloseEvent`].
+    pub fn closeable(mut self) -> Self {
+        self.close.get_or_insert_default();
+        self
+    }
+
+    /// Makes the tabs closeable, showing the close glyphs as `visibility`.
+    pub fn close_visibility(mut self, visibility: TabCloseVisibility) -> Self {
+        self.close = Some(visibility);
+        self
+    }
+
+    /// Appends a "+" button to the triggers, emitting [`TabAddEvent`].
+    pub fn with_add_button(mut self) -> Self {
+        self.add_button = true;
+        self
+    }
+
+    /// Binds the active tab (its trigger label) to a field of resource `R`
+    /// (see [`binding`](crate::components::binding)); needs a
+    /// `ValueBindingPlugin::<R>`.
+    pub fn bind<R: Resource>(mut self, accessor: fn(&mut R) -> &mut String) -> Self {
+        self.binding = Some(value_binder(accessor));
+        self
+    }
+
     pub fn build(self, commands: &mut Commands) -> Entity {
-        let content_entities = self.contents.clone();
+        let root = commands.spawn_empty().id();
+        if let Some(bind) = &self.binding {
+            bind(&mut commands.entity(root));
+        }
+        let active = self
+            .value
+            .as_ref()
+            .and_then(|value| self.triggers.iter().position(|trigger| trigger == value))
+            .unwrap_or(0);
+
+        // Lazy tabs start with empty wrappers that are filled on activation
+        let content_entities: Vec<Option<Entity>> = if self.spawner.is_some() {
+            vec![None; self.triggers.len()]
+        } else {
+            self.contents.iter().copied().map(Some).collect()
+        };
 
         // Create wrapper containers for each content panel to ensure consistent positioning
         let wrapped_content_entities: Vec<Entity> = content_entities
             .iter()
             .enumerate()
-            .map(|(i, &content_entity)| {
-                let wrapper = commands
-                    .spawn((
-                        Node {
-                            position_type: PositionType::Absolute,
-                            left: Val::Px(0.0),
-                            top: Val::Px(0.0),
-                            width: Val::Percent(100.0),
-                            height: Val::Auto,
-                            ..default()
-                        },
-                        TabContent,
-                    ))
-                    .add_child(content_entity)
-                    .id();
-
-                // Apply visibility and ActiveTab to the wrapper instead of the content
-                if i == 0 {
-                    commands.entity(wrapper).insert(ActiveTab);
-                } else {
-                    commands.entity(wrapper).insert(Visibility::Hidden);
-                }
-
-                wrapper
-            })
+            .map(|(i, &content_entity)| spawn_panel_wrapper(commands, content_entity, i == active))
             .collect();
 
         let trigger_entities: Vec<Entity> = self
@@ -110,25 +488,16 @@ impl TabsBuilder {
             .iter()
             .enumerate()
             .map(|(i, label)| {
-                let wrapper_entity = wrapped_content_entities[i];
-                let trigger_button = ButtonBuilder::new(label)
-                    .variant(ButtonVariant::Soft)
-                    .size(ButtonSize::Default)
-                    .text(label);
-
-                let trigger = commands
-                    .spawn((
-                        trigger_button.build(),
-                        TabTrigger {
-                            content: wrapper_entity,
-                        },
-                    ))
-                    .id();
-
-                if i == 0 {
-                    commands.entity(trigger).insert(ActiveTab);
-                }
-
+                let trigger = commands.spawn_empty().id();
+                insert_trigger(
+                    commands,
+                    trigger,
+                    root,
+                    label,
+                    wrapped_content_entities[i],
+                    i == active,
+                    self.close,
+                );
                 trigger
             })
             .collect();
@@ -142,12 +511,32 @@ impl TabsBuilder {
                     align_items: AlignItems::Center,
                     min_height: Val::Px(40.0),
                     padding: UiRect::all(Val::Px(4.0)),
+                    position_type: PositionType::Relative,
                     ..default()
                 },
             ))
-            .add_children(&trigger_entities)
             .id();
 
+        // The indicator goes first so it is drawn behind the triggers
+        if let Some(indicator) = spawn_indicator(commands, self.indicator, root) {
+            commands.entity(tabs_list).add_child(indicator);
+        }
+        commands.entity(tabs_list).add_children(&trigger_entities);
+        if self.add_button {
+            let add_button = commands
+                .spawn((
+                    ButtonBuilder::new("TabAdd")
+                        .variant(ButtonVariant::Ghost)
+                        .size(ButtonSize::Small)
+                        .icon_only(FontFamily::InterfaceFont, Plus)
+                        .accessible_name("Add tab")
+                        .build(),
+                    TabAddButton { tabs: root },
+                ))
+                .id();
+            commands.entity(tabs_list).add_child(add_button);
+        }
+
         // Create a content container that will hold all wrapped content panels
         let content_container = commands
             .spawn((
@@ -156,15 +545,53 @@ impl TabsBuilder {
                     height: Val::Auto,
                     position_type: PositionType::Relative,
                     min_height: Val::Px(200.0), // Ensure minimum height for consistent layout
+                    // Sliding panels are cut off at the edges
+                    overflow: if self.panel_transition == TabsPanelTransition::Slide {
+                        Overflow::clip()
+                    } else {
+                        Overflow::default()
+                    },
                     ..default()
                 },
             ))
             .add_children(&wrapped_content_entities)
             .id();
 
-        let root = commands
-            .spawn((
+        if let Some(spawner) = self.spawner {
+            let mut registry = TabPanelRegistry {
+                panels: self.triggers.iter().map(|t| (t.clone(), None)).collect(),
+                spawner,
+                keep_alive: self.keep_alive,
+            };
+            if let (Some(value), Some(&wrapper)) = (
+                self.triggers.get(active),
+                wrapped_content_entities.get(active),
+            ) {
+                registry.mount(commands, value, wrapper);
+            }
+            commands.entity(root).insert(registry);
+        }
+        if let Some(close) = self.close {
+            commands.entity(root).insert(close);
+        }
+        if let Some(value) = &self.value {
+            commands.entity(root).insert(TabsInitialValue(value.clone()));
+        }
+
+        commands
+            .entity(root)
+            .insert((
                 TabsRoot,
+                TabsValue {
+                    value: self.triggers.get(active).cloned().unwrap_or_default(),
+                    is_controlled: self.value.is_some(),
+                },
+                TabsParts {
+                    list: tabs_list,
+                    panels: content_container,
+                },
+                self.indicator,
+                self.panel_transition,
                 Node {
                     flex_direction: FlexDirection::Column,
                     row_gap: Val::Px(20.0),
@@ -173,51 +600,524 @@ impl TabsBuilder {
                 },
             ))
             .add_child(tabs_list)
-            .add_child(content_container)
-            .id();
+            .add_child(content_container);
 
         root
     }
 }
 
+/// Spawns the wrapper of a content panel, which keeps panels positioned
+/// consistently; only the active one is visible.
+fn spawn_panel_wrapper(commands: &mut Commands, content: Option<Entity>, active: bool) -> Entity {
+    let wrapper = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Auto,
+                ..default()
+            },
+            TabContent,
+        ))
+        .add_children(content.as_slice())
+        .id();
+
+    // Apply visibility and ActiveTab to the wrapper instead of the content
+    if active {
+        commands.entity(wrapper).insert(ActiveTab);
+    } else {
+        commands.entity(wrapper).insert(Visibility::Hidden);
+    }
+    wrapper
+}
+
+/// Makes `trigger` the trigger of tab `label` of `tabs`, switching to the
+/// panel `wrapper`, with a close glyph when `close` is set.
+fn insert_trigger(
+    commands: &mut Commands,
+    trigger: Entity,
+    tabs: Entity,
+    label: &str,
+    wrapper: Entity,
+    active: bool,
+    close: Option<TabCloseVisibility>,
+) {
+    let trigger_button = ButtonBuilder::new(label)
+        .variant(ButtonVariant::Soft)
+        .size(ButtonSize::Default)
+        .text(label);
+
+    commands.entity(trigger).insert((
+        trigger_button.build(),
+        TabTrigger {
+            content: wrapper,
+            value: label.to_string(),
+            tabs,
+        },
+    ));
+    // Replaces the button role the trigger got from its button bundle
+    commands
+        .entity(trigger)
+        .insert(UiA11y::new(UiRole::Tab, label).value(UiA11yValue::Selected(active)));
+
+    if active {
+        commands.entity(trigger).insert(ActiveTab);
+    }
+
+    if let Some(close) = close {
+        let close_button = commands
+            .spawn((
+                ButtonBuilder::new("TabClose")
+                    .variant(ButtonVariant::Ghost)
+                    .size(ButtonSize::Small)
+                    .icon_only(FontFamily::InterfaceFont, X)
+                    .accessible_name(format!("Close {label}"))
+                    .build(),
+                TabCloseButton { trigger },
+            ))
+            // Keep the click from also activating the tab being closed
+            .observe(|mut trigger: Trigger<Pointer<Click>>| trigger.propagate(false))
+            .id();
+        if close == TabCloseVisibility::Hover {
+            commands.entity(close_button).insert(Visibility::Hidden);
+        }
+        commands.entity(trigger).add_child(close_button);
+    }
+}
+
+/// Adds a tab labeled `label` to the end of the tabs system `tabs` and
+/// returns its trigger; the tab stays inactive until clicked.
+///
+/// `content` is the panel to show. Lazy tabs pass `None`, their panel is
+/// spawned by the spawner on activation.
+pub fn add_tab(
+    commands: &mut Commands,
+    tabs: Entity,
+    label: &str,
+    content: Option<Entity>,
+) -> Entity {
+    let trigger = commands.spawn_empty().id();
+    let label = label.to_string();
+    commands.queue(move |world: &mut World| {
+        let Some(parts) = world.get::<TabsParts>(tabs).copied() else {
+            warn!("add_tab: {tabs:?} is not a tabs root");
+            return;
+        };
+        let close = world.get::<TabCloseVisibility>(tabs).copied();
+        if let Some(mut registry) = world.get_mut::<TabPanelRegistry>(tabs) {
+            registry.panels.insert(label.clone(), None);
+        }
+
+        {
+            let mut commands = world.commands();
+            let wrapper = spawn_panel_wrapper(&mut commands, content, false);
+            commands.entity(parts.panels).add_child(wrapper);
+            insert_trigger(&mut commands, trigger, tabs, &label, wrapper, false, close);
+        }
+        world.flush();
+
+        // New triggers go before the add button
+        let siblings: Vec<Entity> = world
+            .get::<Children>(parts.list)
+            .map(|children| children.to_vec())
+            .unwrap_or_default();
+        let index = siblings
+            .iter()
+            .position(|child| world.get::<TabAddButton>(*child).is_some())
+            .unwrap_or(siblings.len());
+        world
+            .entity_mut(parts.list)
+            .insert_children(index, &[trigger]);
+    });
+    trigger
+}
+
+/// Spawns the (not yet placed) indicator node for `indicator`, if any.
+fn spawn_indicator(
+    commands: &mut Commands,
+    indicator: TabsIndicator,
+    tabs: Entity,
+) -> Option<Entity> {
+    let (node, color, radius) = match indicator {
+        TabsIndicator::None => return None,
+        TabsIndicator::Underline => (
+            Node {
+                bottom: Val::Px(0.0),
+                height: Val::Px(TAB_UNDERLINE_HEIGHT),
+                ..default()
+            },
+            accent_palette().solid,
+            BorderRadius::all(Val::Px(TAB_UNDERLINE_HEIGHT / 2.0)),
+        ),
+        TabsIndicator::Pill => (
+            // Matches the list padding, so the pill covers the trigger
+            Node {
+                top: Val::Px(4.0),
+                bottom: Val::Px(4.0),
+                ..default()
+            },
+            accent_palette().bg_active,
+            BorderRadius::all(Val::Px(6.0)),
+        ),
+    };
+
+    let indicator = commands
+        .spawn((
+            Name::new("Tabs Indicator"),
+            TabIndicatorNode {
+                tabs,
+                left: 0.0,
+                width: 0.0,
+                placed: false,
+            },
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                width: Val::Px(0.0),
+                ..node
+            },
+            BackgroundColor(color),
+            radius,
+            // Hidden until the first layout of the active trigger is known
+            Visibility::Hidden,
+            Pickable::IGNORE,
+        ))
+        .id();
+    Some(indicator)
+}
+
+/// Marks the tab of `trigger` active and shows its panel, mounting it first
+/// for lazy tabs.
+fn activate_tab(
+    commands: &mut Commands,
+    entity: Entity,
+    trigger: &TabTrigger,
+    registry: Option<&mut TabPanelRegistry>,
+    panels: &mut Query<&mut Visibility, With<TabContent>>,
+) {
+    if let Some(registry) = registry {
+        registry.mount(commands, &trigger.value, trigger.content);
+    }
+    commands.entity(entity).insert(ActiveTab);
+    commands.entity(trigger.content).insert(ActiveTab);
+    if let Ok(mut visibility) = panels.get_mut(trigger.content) {
+        *visibility = Visibility::Inherited;
+    }
+}
+
+/// Index of the tab to activate when the active tab at `index` of `len`
+/// tabs is closed: the next one, or the previous one for the last tab.
+pub fn tab_after_close(index: usize, len: usize) -> Option<usize> {
+    if index + 1 < len {
+        Some(index + 1)
+    } else {
+        index.checked_sub(1)
+    }
+}
+
+/// Panel transitions of tabs systems and the order of their triggers.
+#[derive(SystemParam)]
+pub struct TabPanelSwitches<'w, 's> {
+    transitions: Query<'w, 's, &'static TabsPanelTransition>,
+    parents: Query<'w, 's, &'static ChildOf>,
+    children: Query<'w, 's, &'static Children>,
+}
+
+impl TabPanelSwitches<'_, '_> {
+    /// Transition and direction of travel from the active tab of
+    /// `trigger`'s tabs system to `entity`, unless panels switch at once.
+    fn get(
+        &self,
+        entity: Entity,
+        trigger: &TabTrigger,
+        active_query: &Query<(Entity, &TabTrigger), With<ActiveTab>>,
+    ) -> Option<(TabsPanelTransition, TabSlideDirection)> {
+        let transition = *self.transitions.get(trigger.tabs).ok()?;
+        if transition == TabsPanelTransition::None {
+            return None;
+        }
+        let (active, _) = active_query
+            .iter()
+            .find(|(_, active)| active.tabs == trigger.tabs)?;
+        let list = self.parents.get(entity).ok()?.parent();
+        let triggers = self.children.get(list).ok()?;
+        let index = |trigger: Entity| triggers.iter().position(|child| child == trigger);
+        let direction = TabSlideDirection::between(index(active)?, index(entity)?);
+        Some((transition, direction))
+    }
+}
+
+/// Deactivates the active tab of the tabs system of `trigger`, then
+/// activates `trigger`. With a panel `switch` both panels animate, and the
+/// outgoing one is hidden (and unmounted) when its animation ends.
+fn switch_tab(
+    commands: &mut Commands,
+    entity: Entity,
+    trigger: &TabTrigger,
+    active_query: &Query<(Entity, &TabTrigger), With<ActiveTab>>,
+    mut registry: Option<&mut TabPanelRegistry>,
+    panels: &mut Query<&mut Visibility, With<TabContent>>,
+    switch: Option<(TabsPanelTransition, TabSlideDirection)>,
+) {
+    for (active_trigger_entity, active_trigger) in active_query {
+        if active_trigger.tabs != trigger.tabs {
+            continue;
+        }
+        if let Some((transition, direction)) = switch {
+            let mut leaving = TabPanelAnimation::new(transition, direction, true);
+            if registry
+                .as_ref()
+                .is_some_and(|registry| !registry.keep_alive)
+            {
+                leaving.unmount = Some((active_trigger.tabs, active_trigger.value.clone()));
+            }
+            commands.entity(active_trigger.content).insert(leaving);
+        } else {
+            if let Some(registry) = registry.as_deref_mut() {
+                registry.unmount(commands, &active_trigger.value);
+            }
+            if let Ok(mut visibility) = panels.get_mut(active_trigger.content) {
+                *visibility = Visibility::Hidden;
+            }
+        }
+        commands.entity(active_trigger_entity).remove::<ActiveTab>();
+        commands
+            .entity(active_trigger.content)
+            .remove::<ActiveTab>();
+    }
+
+    activate_tab(commands, entity, trigger, registry, panels);
+    if let Some((transition, direction)) = switch {
+        commands
+            .entity(trigger.content)
+            .insert(TabPanelAnimation::new(transition, direction, false));
+    }
+}
+
+/// Writes the value of the newly active `trigger` to its [`TabsValue`].
+pub(crate) fn mirror_tabs_value(values: &mut Query<&mut TabsValue>, trigger: &TabTrigger) {
+    if let Ok(mut value) = values.get_mut(trigger.tabs) {
+        if value.value != trigger.value {
+            value.value.clone_from(&trigger.value);
+        }
+    }
+}
+
+/// Switches tabs the way a click on their trigger does, e.g. from code.
+#[derive(SystemParam)]
+pub struct TabSwitcher<'w, 's> {
+    commands: Commands<'w, 's>,
+    active: Query<'w, 's, (Entity, &'static TabTrigger), With<ActiveTab>>,
+    panels: Query<'w, 's, &'static mut Visibility, With<TabContent>>,
+    registries: Query<'w, 's, &'static mut TabPanelRegistry>,
+    switches: TabPanelSwitches<'w, 's>,
+}
+
+impl TabSwitcher<'_, '_> {
+    /// Activates the tab of trigger `entity`; returns `false` when it was
+    /// active already.
+    pub fn switch_to(&mut self, entity: Entity, trigger: &TabTrigger) -> bool {
+        if self.active.contains(entity) {
+            return false;
+        }
+        let switch = self.switches.get(entity, trigger, &self.active);
+        let mut registry = self.registries.get_mut(trigger.tabs).ok();
+        switch_tab(
+            &mut self.commands,
+            entity,
+            trigger,
+            &self.active,
+            registry.as_deref_mut(),
+            &mut self.panels,
+            switch,
+        );
+        true
+    }
+}
+
 pub fn handle_trigger_clicks(
-    mut commands: Commands,
     mut button_events: EventReader<ButtonClickEvent>,
     trigger_query: Query<&TabTrigger>,
-    mut tabs_query: Query<&mut Visibility, With<TabContent>>,
-    active_query: Query<(Entity, &TabTrigger), With<ActiveTab>>,
+    mut values: Query<&mut TabsValue>,
+    mut tabs: TabSwitcher,
 ) {
     for event in button_events.read() {
         // Check if this button is a tab trigger
         if let Ok(trigger) = trigger_query.get(event.button_entity) {
             info!("Tab trigger clicked: {:?}", event.button_entity);
-            
-            // Deactivate all old tabs
-            for (active_trigger_entity, active_trigger) in &active_query {
-                commands.entity(active_trigger_entity).remove::<ActiveTab>();
-                commands.entity(active_trigger.content).remove::<ActiveTab>();
-                if let Ok(mut visibility) = tabs_query.get_mut(active_trigger.content) {
-                    *visibility = Visibility::Hidden;
-                }
+            if !tabs.switch_to(event.button_entity, trigger) {
+                continue;
             }
+            mirror_tabs_value(&mut values, trigger);
 
-            // Activate the new tab
-            commands.entity(event.button_entity).insert(ActiveTab);
-            commands.entity(trigger.content).insert(ActiveTab);
-            if let Ok(mut visibility) = tabs_query.get_mut(trigger.content) {
-                *visibility = Visibility::Inherited;
-            }
-            
             info!("Tab switched to content: {:?}", trigger.content);
         }
     }
 }
 
+/// Switches controlled tabs to the tab named by their [`TabsValue`] when user
+/// code changed it; unknown values are ignored.
+pub fn sync_controlled_tabs(
+    roots: Query<(Entity, &TabsValue), Changed<TabsValue>>,
+    triggers: Query<(Entity, &TabTrigger)>,
+    mut tabs: TabSwitcher,
+) {
+    for (root, value) in &roots {
+        if !value.is_controlled {
+            continue;
+        }
+        if let Some((entity, trigger)) = triggers
+            .iter()
+            .find(|(_, trigger)| trigger.tabs == root && trigger.value == value.value)
+        {
+            tabs.switch_to(entity, trigger);
+        }
+    }
+}
+
+/// Closes the tab of each clicked close glyph: emits [`TabCloseEvent`],
+/// despawns its trigger and panel, and hands the active state and the focus
+/// on to a neighbouring tab.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_tab_close_clicks(
+    mut commands: Commands,
+    mut button_events: EventReader<ButtonClickEvent>,
+    close_buttons: Query<&TabCloseButton>,
+    triggers: Query<(&TabTrigger, &ChildOf, Has<ActiveTab>)>,
+    children: Query<&Children>,
+    mut panels: Query<&mut Visibility, With<TabContent>>,
+    mut registries: Query<&mut TabPanelRegistry>,
+    mut values: Query<&mut TabsValue>,
+    mut focus: ResMut<UiFocus>,
+    mut close_events: EventWriter<TabCloseEvent>,
+) {
+    let mut closed = HashSet::new();
+    for event in button_events.read() {
+        let Ok(close_button) = close_buttons.get(event.button_entity) else {
+            continue;
+        };
+        let entity = close_button.trigger;
+        let Ok((trigger, child_of, active)) = triggers.get(entity) else {
+            continue;
+        };
+        if !closed.insert(entity) {
+            continue;
+        }
+
+        // The remaining tabs in list order, to find the neighbour
+        let siblings: Vec<Entity> = children
+            .get(child_of.parent())
+            .map(|children| {
+                children
+                    .iter()
+                    .filter(|sibling| {
+                        *sibling == entity
+                            || (triggers.contains(*sibling) && !closed.contains(sibling))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let neighbour = siblings
+            .iter()
+            .position(|sibling| *sibling == entity)
+            .and_then(|index| tab_after_close(index, siblings.len()))
+            .map(|index| siblings[index]);
+
+        let mut registry = registries.get_mut(trigger.tabs).ok();
+        if let Some(registry) = registry.as_mut() {
+            registry.panels.remove(&trigger.value);
+        }
+        if let Some(neighbour) = neighbour.filter(|_| active) {
+            if let Ok((neighbour_trigger, ..)) = triggers.get(neighbour) {
+                activate_tab(
+                    &mut commands,
+                    neighbour,
+                    neighbour_trigger,
+                    registry.as_deref_mut(),
+                    &mut panels,
+                );
+                mirror_tabs_value(&mut values, neighbour_trigger);
+            }
+        }
+        if focus.focused == Some(entity) {
+            focus.focused = neighbour;
+        }
+
+        close_events.write(TabCloseEvent {
+            tabs: trigger.tabs,
+            value: trigger.value.clone(),
+        });
+        commands.entity(trigger.content).despawn();
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Emits [`TabAddEvent`] when an add button is clicked.
+pub fn handle_tab_add_clicks(
+    mut button_events: EventReader<ButtonClickEvent>,
+    add_buttons: Query<&TabAddButton>,
+    mut add_events: EventWriter<TabAddEvent>,
+) {
+    for event in button_events.read() {
+        if let Ok(add_button) = add_buttons.get(event.button_entity) {
+            add_events.write(TabAddEvent {
+                tabs: add_button.tabs,
+            });
+        }
+    }
+}
+
+/// Shows the close glyphs of [`TabCloseVisibility::Hover`] tabs while their
+/// trigger (or the glyph itself) is hovered or the trigger has keyboard focus.
+pub fn show_tab_close_buttons(
+    focus: Res<UiFocus>,
+    mut close_buttons: Query<(&TabCloseButton, &UiButton, &mut Visibility)>,
+    triggers: Query<(&TabTrigger, &UiButton)>,
+    close_visibilities: Query<&TabCloseVisibility>,
+) {
+    let hovered = |button: &UiButton| {
+        matches!(
+            button.current_state,
+            ButtonState::Hover | ButtonState::Active
+        )
+    };
+    for (close_button, button, mut visibility) in &mut close_buttons {
+        let Ok((trigger, trigger_button)) = triggers.get(close_button.trigger) else {
+            continue;
+        };
+        let shown = match close_visibilities.get(trigger.tabs) {
+            Ok(TabCloseVisibility::Hover) => {
+                hovered(button)
+                    || hovered(trigger_button)
+                    || focus.focused == Some(close_button.trigger)
+            }
+            _ => true,
+        };
+        visibility.set_if_neq(if shown {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+}
+
 pub fn style_active_triggers(
-    mut triggers_query: Query<(&mut BackgroundColor, &mut BorderColor, Option<&ActiveTab>), With<TabTrigger>>,
+    mut triggers_query: Query<(
+        &TabTrigger,
+        &mut BackgroundColor,
+        &mut BorderColor,
+        Option<&ActiveTab>,
+    )>,
+    indicators: Query<&TabsIndicator>,
 ) {
-    for (mut bg_color, mut border_color, is_active) in &mut triggers_query {
-        if is_active.is_some() {
+    for (trigger, mut bg_color, mut border_color, is_active) in &mut triggers_query {
+        // With an indicator, the indicator alone marks the active trigger
+        let has_indicator = indicators
+            .get(trigger.tabs)
+            .is_ok_and(|indicator| *indicator != TabsIndicator::None);
+        if is_active.is_some() && !has_indicator {
             *bg_color = BackgroundColor(accent_palette().solid);
             *border_color = BorderColor(accent_palette().solid);
         } else {
@@ -227,10 +1127,573 @@ pub fn style_active_triggers(
     }
 }
 
+/// Arrow keys activate the previous / next trigger while a trigger has
+/// keyboard focus, moving the focus along; Ctrl+W (Cmd+W) clicks the close
+/// glyph of the focused trigger.
+pub fn handle_tabs_keyboard(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<UiFocus>,
+    triggers: Query<(&ChildOf, &UiButton), With<TabTrigger>>,
+    close_buttons: Query<(Entity, &TabCloseButton, &UiButton)>,
+    children: Query<&Children>,
+    mut click_events: EventWriter<ButtonClickEvent>,
+) {
+    let command = keyboard.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+    if command && keyboard.just_pressed(KeyCode::KeyW) {
+        if let Some(event) = close_buttons
+            .iter()
+            .find(|(_, close_button, _)| Some(close_button.trigger) == focus.focused)
+            .and_then(|(entity, _, button)| button_click_event(entity, button))
+        {
+            click_events.write(event);
+        }
+        return;
+    }
+
+    let step: isize = if keyboard.just_pressed(KeyCode::ArrowRight) {
+        1
+    } else if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        -1
+    } else {
+        return;
+    };
+    let Some(focused) = focus.focused else {
+        return;
+    };
+    let Ok((child_of, _)) = triggers.get(focused) else {
+        return;
+    };
+    let Ok(siblings) = children.get(child_of.parent()) else {
+        return;
+    };
+
+    let list: Vec<Entity> = siblings
+        .iter()
+        .filter(|entity| {
+            triggers
+                .get(*entity)
+                .is_ok_and(|(_, button)| button.is_interactive())
+        })
+        .collect();
+    let Some(index) = list.iter().position(|entity| *entity == focused) else {
+        return;
+    };
+    let next = list[(index as isize + step).rem_euclid(list.len() as isize) as usize];
+    if next == focused {
+        return;
+    }
+
+    focus.focused = Some(next);
+    focus.focus_visible = true;
+    if let Some(event) = triggers
+        .get(next)
+        .ok()
+        .and_then(|(_, button)| button_click_event(next, button))
+    {
+        click_events.write(event);
+    }
+}
+
+/// Offset from the list's left edge and width of a trigger, in logical
+/// pixels, from the physical centers and widths of list and trigger.
+pub fn indicator_span(
+    list_center_x: f32,
+    list_width: f32,
+    trigger_center_x: f32,
+    trigger_width: f32,
+    inverse_scale_factor: f32,
+) -> (f32, f32) {
+    let list_left = list_center_x - list_width / 2.0;
+    let trigger_left = trigger_center_x - trigger_width / 2.0;
+    (
+        (trigger_left - list_left) * inverse_scale_factor,
+        trigger_width * inverse_scale_factor,
+    )
+}
+
+/// Moves `current` toward `target` for a frame of `delta` seconds, arriving
+/// exactly once within half a pixel.
+pub fn approach_indicator(current: f32, target: f32, delta: f32) -> f32 {
+    let next = target + (current - target) * (-TAB_INDICATOR_SPEED * delta).exp();
+    if (next - target).abs() < 0.5 {
+        target
+    } else {
+        next
+    }
+}
+
+/// Slides each tabs indicator toward the layout of its active trigger.
+///
+/// The first placement snaps, so the indicator doesn't grow in from zero.
+pub fn animate_tab_indicators(
+    time: Res<Time>,
+    motion: Res<UiMotion>,
+    mut indicators: Query<(&mut TabIndicatorNode, &mut Node, &mut Visibility, &ChildOf)>,
+    lists: Query<(&ComputedNode, &GlobalTransform), With<TabsList>>,
+    active_triggers: Query<(&TabTrigger, &ComputedNode, &GlobalTransform), With<ActiveTab>>,
+) {
+    for (mut indicator, mut node, mut visibility, child_of) in &mut indicators {
+        let Ok((list_computed, list_transform)) = lists.get(child_of.parent()) else {
+            continue;
+        };
+        let Some((_, trigger_computed, trigger_transform)) = active_triggers
+            .iter()
+            .find(|(trigger, _, _)| trigger.tabs == indicator.tabs)
+        else {
+            continue;
+        };
+        // Not laid out yet
+        if trigger_computed.size().x <= 0.0 {
+            continue;
+        }
+
+        let (left, width) = indicator_span(
+            list_transform.translation().x,
+            list_computed.size().x,
+            trigger_transform.translation().x,
+            trigger_computed.size().x,
+            list_computed.inverse_scale_factor(),
+        );
+        if indicator.placed && indicator.left == left && indicator.width == width {
+            continue;
+        }
+        // Reduced motion snaps like the first placement
+        if indicator.placed && !motion.reduced {
+            let delta = time.delta_secs();
+            indicator.left = approach_indicator(indicator.left, left, delta);
+            indicator.width = approach_indicator(indicator.width, width, delta);
+        } else {
+            indicator.left = left;
+            indicator.width = width;
+            if !indicator.placed {
+                indicator.placed = true;
+                *visibility = Visibility::Inherited;
+            }
+        }
+
+        node.left = Val::Px(indicator.left);
+        node.width = Val::Px(indicator.width);
+    }
+}
+
+/// Colors of a fading panel's nodes before the fade, kept until the last
+/// fade of the panel ends so interrupted fades restore them too.
+#[derive(Component, Debug, Clone)]
+pub struct FadedPanelColors(Vec<(Entity, FadedColors)>);
+
+/// Transition state of a tab panel, played by [`animate_tab_panels`].
+type TabPanelTransition = (
+    Entity,
+    &'static mut TabPanelAnimation,
+    &'static mut Node,
+    &'static mut Visibility,
+    Option<&'static FadedPanelColors>,
+);
+
+/// Colors of a node in a fading panel.
+type FadedNodeColors = (
+    Option<&'static mut BackgroundColor>,
+    Option<&'static mut BorderColor>,
+    Option<&'static mut TextColor>,
+);
+
+/// Plays panel transitions. Outgoing panels are hidden, and lazy ones
+/// unmounted, when theirs ends.
+pub fn animate_tab_panels(
+    mut commands: Commands,
+    time: Res<Time>,
+    motion: Res<UiMotion>,
+    mut panels: Query<TabPanelTransition, With<TabContent>>,
+    children: Query<&Children>,
+    mut colors: Query<FadedNodeColors>,
+    mut registries: Query<&mut TabPanelRegistry>,
+) {
+    for (entity, mut animation, mut node, mut visibility, faded) in &mut panels {
+        // Reduced motion ends every transition in the frame it starts
+        let delta = if motion.reduced {
+            TAB_PANEL_TRANSITION_DURATION
+        } else {
+            time.delta()
+        };
+        animation.elapsed = (animation.elapsed + delta).min(TAB_PANEL_TRANSITION_DURATION);
+        let done = animation.elapsed == TAB_PANEL_TRANSITION_DURATION;
+
+        match animation.transition {
+            TabsPanelTransition::Slide => {
+                node.left = if done {
+                    Val::Px(0.0)
+                } else {
+                    Val::Percent(animation.slide_offset())
+                };
+            }
+            TabsPanelTransition::Fade => {
+                let faded = match faded {
+                    Some(FadedPanelColors(faded)) => faded.clone(),
+                    None => {
+                        let faded: Vec<_> = std::iter::once(entity)
+                            .chain(children.iter_descendants(entity))
+                            .filter_map(|node| {
+                                let (background, border, text) = colors.get(node).ok()?;
+                                let colors = FadedColors {
+                                    background: background.map(|background| background.0),
+                                    border: border.map(|border| border.0),
+                                    text: text.map(|text| text.0),
+                                };
+                                Some((node, colors))
+                            })
+                            .collect();
+                        commands
+                            .entity(entity)
+                            .insert(FadedPanelColors(faded.clone()));
+                        faded
+                    }
+                };
+                // Ended fades restore the colors, also of hidden panels
+                let shown = if done { 1.0 } else { animation.shown() };
+                let fade = |color: Color| color.with_alpha(color.alpha() * shown);
+                for (node, original) in faded {
+                    let Ok((background, border, text)) = colors.get_mut(node) else {
+                        continue;
+                    };
+                    if let (Some(mut background), Some(color)) = (background, original.background) {
+                        background.0 = fade(color);
+                    }
+                    if let (Some(mut border), Some(color)) = (border, original.border) {
+                        border.0 = fade(color);
+                    }
+                    if let (Some(mut text), Some(color)) = (text, original.text) {
+                        text.0 = fade(color);
+                    }
+                }
+                if done {
+                    commands.entity(entity).remove::<FadedPanelColors>();
+                }
+            }
+            TabsPanelTransition::None => {}
+        }
+
+        if !done {
+            continue;
+        }
+        if animation.outgoing {
+            *visibility = Visibility::Hidden;
+            if let Some((tabs, value)) = animation.unmount.take() {
+                if let Ok(mut registry) = registries.get_mut(tabs) {
+                    registry.unmount(&mut commands, &value);
+                }
+            }
+        }
+        commands.entity(entity).remove::<TabPanelAnimation>();
+    }
+}
+
 pub struct TabsPlugin;
 
 impl Plugin for TabsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (handle_trigger_clicks, style_active_triggers));
+        app.init_resource::<UiMotion>().add_systems(
+            Update,
+            (
+                sync_controlled_tabs,
+                handle_tabs_keyboard,
+                handle_tab_close_clicks,
+                handle_tab_add_clicks,
+                handle_trigger_clicks,
+                style_active_triggers,
+                show_tab_close_buttons,
+                animate_tab_indicators,
+                animate_tab_panels,
+            )
+                .chain(),
+        );
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::sync::Mutex;
+
+    fn trigger_for(world: &mut World, value: &str) -> Entity {
+        world
+            .query::<(Entity, &TabTrigger)>()
+            .iter(world)
+            .find(|(_, trigger)| trigger.value == value)
+            .map(|(entity, _)| entity)
+            .unwrap()
+    }
+
+    fn click(world: &mut World, button_entity: Entity) {
+        world.send_event(ButtonClickEvent {
+            button_entity,
+            button_variant: ButtonVariant::Soft,
+        });
+        world.run_system_once(handle_trigger_clicks).unwrap();
+    }
+
+    #[test]
+    fn test_lazy_panel_not_spawned_until_activated() {
+        let mut world = World::new();
+        world.init_resource::<Events<ButtonClickEvent>>();
+
+        let spawned = Arc::new(Mutex::new(Vec::new()));
+        let log = spawned.clone();
+        let root = {
+            let mut commands = world.commands();
+            TabsBuilder::new()
+                .triggers(vec!["One", "Two"])
+                .lazy(move |commands, value| {
+                    log.lock().unwrap().push(value.to_string());
+                    commands.spawn(Node::default()).id()
+                })
+                .build(&mut commands)
+        };
+        world.flush();
+
+        assert_eq!(*spawned.lock().unwrap(), vec!["One"]);
+        let registry = world.get::<TabPanelRegistry>(root).unwrap();
+        assert!(!registry.is_mounted("Two"));
+
+        let two = trigger_for(&mut world, "Two");
+        click(&mut world, two);
+
+        assert_eq!(*spawned.lock().unwrap(), vec!["One", "Two"]);
+        let registry = world.get::<TabPanelRegistry>(root).unwrap();
+        assert!(registry.is_mounted("Two"));
+        // Without keep-alive the previous panel is despawned
+        assert!(!registry.is_mounted("One"));
+    }
+
+    #[test]
+    fn test_keep_alive_caches_panels() {
+        let mut world = World::new();
+        world.init_resource::<Events<ButtonClickEvent>>();
+
+        let root = {
+            let mut commands = world.commands();
+            TabsBuilder::new()
+                .triggers(vec!["One", "Two"])
+                .lazy(|commands, _| commands.spawn(Node::default()).id())
+                .keep_alive()
+                .build(&mut commands)
+        };
+        world.flush();
+
+        let one_panel = world.get::<TabPanelRegistry>(root).unwrap().panels["One"];
+        let one = trigger_for(&mut world, "One");
+        let two = trigger_for(&mut world, "Two");
+        click(&mut world, two);
+        click(&mut world, one);
+
+        let registry = world.get::<TabPanelRegistry>(root).unwrap();
+        assert!(registry.is_mounted("Two"));
+        assert_eq!(registry.panels["One"], one_panel);
+    }
+
+    #[test]
+    fn test_setting_controlled_value_switches_panel() {
+        let mut world = World::new();
+        world.init_resource::<Events<ButtonClickEvent>>();
+
+        let contents: Vec<Entity> = (0..3).map(|_| world.spawn(Node::default()).id()).collect();
+        let root = {
+            let mut commands = world.commands();
+            TabsBuilder::new()
+                .triggers(vec!["One", "Two", "Three"])
+                .contents(contents)
+                .value("Two")
+                .build(&mut commands)
+        };
+        world.flush();
+
+        let two = trigger_for(&mut world, "Two");
+        let three = trigger_for(&mut world, "Three");
+        assert!(world.get::<ActiveTab>(two).is_some());
+        assert!(world.get::<TabsValue>(root).unwrap().is_controlled);
+
+        world.get_mut::<TabsValue>(root).unwrap().value = "Three".to_string();
+        world.run_system_once(sync_controlled_tabs).unwrap();
+
+        assert!(world.get::<ActiveTab>(two).is_none());
+        assert!(world.get::<ActiveTab>(three).is_some());
+        let panel =
+            |world: &World, trigger: Entity| world.get::<TabTrigger>(trigger).unwrap().content;
+        assert_eq!(
+            world.get::<Visibility>(panel(&world, three)),
+            Some(&Visibility::Inherited)
+        );
+        assert_eq!(
+            world.get::<Visibility>(panel(&world, two)),
+            Some(&Visibility::Hidden)
+        );
+
+        // Clicks keep the value up to date
+        click(&mut world, two);
+        assert_eq!(world.get::<TabsValue>(root).unwrap().value, "Two");
+    }
+
+    fn close(world: &mut World, trigger: Entity) {
+        let close_button = world
+            .query::<(Entity, &TabCloseButton)>()
+            .iter(world)
+            .find(|(_, close_button)| close_button.trigger == trigger)
+            .map(|(entity, _)| entity)
+            .unwrap();
+        world.send_event(ButtonClickEvent {
+            button_entity: close_button,
+            button_variant: ButtonVariant::Ghost,
+        });
+        world.run_system_once(handle_tab_close_clicks).unwrap();
+    }
+
+    #[test]
+    fn test_closing_active_tab_activates_neighbour() {
+        let mut world = World::new();
+        world.init_resource::<Events<ButtonClickEvent>>();
+        world.init_resource::<Events<TabCloseEvent>>();
+        world.init_resource::<UiFocus>();
+
+        let contents: Vec<Entity> = (0..3).map(|_| world.spawn(Node::default()).id()).collect();
+        {
+            let mut commands = world.commands();
+            TabsBuilder::new()
+                .triggers(vec!["One", "Two", "Three"])
+                .contents(contents)
+                .closeable()
+                .build(&mut commands);
+        }
+        world.flush();
+
+        let two = trigger_for(&mut world, "Two");
+        let three = trigger_for(&mut world, "Three");
+        click(&mut world, two);
+        world.resource_mut::<UiFocus>().focused = Some(two);
+
+        close(&mut world, two);
+        assert!(world.get_entity(two).is_err());
+        assert!(world.get::<ActiveTab>(three).is_some());
+        let panel = world.get::<TabTrigger>(three).unwrap().content;
+        assert_eq!(world.get::<Visibility>(panel), Some(&Visibility::Inherited));
+        assert_eq!(world.resource::<UiFocus>().focused, Some(three));
+        let closed: Vec<String> = world
+            .resource_mut::<Events<TabCloseEvent>>()
+            .drain()
+            .map(|event| event.value)
+            .collect();
+        assert_eq!(closed, vec!["Two"]);
+
+        // The last tab falls back to its left neighbour
+        let one = trigger_for(&mut world, "One");
+        close(&mut world, three);
+        assert!(world.get::<ActiveTab>(one).is_some());
+    }
+
+    fn spawn_sliding_tabs(world: &mut World) {
+        world.init_resource::<Events<ButtonClickEvent>>();
+        let contents: Vec<Entity> = (0..3).map(|_| world.spawn(Node::default()).id()).collect();
+        {
+            let mut commands = world.commands();
+            TabsBuilder::new()
+                .triggers(vec!["One", "Two", "Three"])
+                .contents(contents)
+                .panel_transition(TabsPanelTransition::Slide)
+                .build(&mut commands);
+        }
+        world.flush();
+    }
+
+    fn panel_animation(world: &World, trigger: Entity) -> &TabPanelAnimation {
+        let panel = world.get::<TabTrigger>(trigger).unwrap().content;
+        world.get::<TabPanelAnimation>(panel).unwrap()
+    }
+
+    #[test]
+    fn test_selecting_higher_index_tab_slides_right() {
+        let mut world = World::new();
+        spawn_sliding_tabs(&mut world);
+        let one = trigger_for(&mut world, "One");
+        let three = trigger_for(&mut world, "Three");
+
+        click(&mut world, three);
+        let incoming = panel_animation(&world, three);
+        assert_eq!(incoming.direction, TabSlideDirection::Right);
+        assert!(!incoming.outgoing);
+        let outgoing = panel_animation(&world, one);
+        assert_eq!(outgoing.direction, TabSlideDirection::Right);
+        assert!(outgoing.outgoing);
+
+        // Going back travels left
+        click(&mut world, one);
+        assert_eq!(
+            panel_animation(&world, one).direction,
+            TabSlideDirection::Left
+        );
+    }
+
+    #[test]
+    fn test_outgoing_panel_hides_when_transition_ends() {
+        let mut world = World::new();
+        spawn_sliding_tabs(&mut world);
+        world.init_resource::<Time>();
+        world.insert_resource(UiMotion { reduced: true });
+        let one = trigger_for(&mut world, "One");
+        let two = trigger_for(&mut world, "Two");
+        let panel =
+            |world: &World, trigger: Entity| world.get::<TabTrigger>(trigger).unwrap().content;
+
+        click(&mut world, two);
+        // Both panels show while the transition runs
+        assert_eq!(
+            world.get::<Visibility>(panel(&world, one)),
+            Some(&Visibility::Inherited)
+        );
+
+        // Reduced motion ends it in the next frame
+        world.run_system_once(animate_tab_panels).unwrap();
+        assert_eq!(
+            world.get::<Visibility>(panel(&world, one)),
+            Some(&Visibility::Hidden)
+        );
+        assert!(world.get::<TabPanelAnimation>(panel(&world, two)).is_none());
+        assert_eq!(
+            world.get::<Node>(panel(&world, two)).unwrap().left,
+            Val::Px(0.0)
+        );
+    }
+
+    #[test]
+    fn test_tab_after_close_prefers_next() {
+        assert_eq!(tab_after_close(1, 3), Some(2));
+        assert_eq!(tab_after_close(2, 3), Some(1));
+        assert_eq!(tab_after_close(0, 1), None);
+    }
+
+    #[test]
+    fn test_indicator_span_is_relative_to_the_list() {
+        // A 200px list centered at 300 starts at 200, an 80px trigger
+        // centered at 260 at 220: 20 physical pixels in, 10 logical ones at a
+        // scale factor of 2
+        let (left, width) = indicator_span(300.0, 200.0, 260.0, 80.0, 0.5);
+        assert_eq!(left, 10.0);
+        assert_eq!(width, 40.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_indicator_approaches_without_overshooting() {
+        let mut left = 0.0;
+        for _ in 0..10 {
+            let next = approach_indicator(left, 100.0, 1.0 / 60.0);
+            assert!(next > left && next <= 100.0);
+            left = next;
+        }
+        for _ in 0..100 {
+            left = approach_indicator(left, 100.0, 1.0 / 60.0);
+        }
+        assert_eq!(left, 100.0);
+    }
+}
@@ -23,6 +23,37 @@
 //! - **Label**: Small text for UI labels (medium weight)
 //! - **Caption**: Smallest text for metadata (muted color)
 //!
+//! ## Documentation Presets
+//!
+//! - [`Text::code`]: Inline mono text on a subtle background
+//! - [`Text::code_block`]: Multiline mono text in a padded, rounded box
+//! - [`Text::blockquote`]: Indented text with an accent bar on the left
+//!
+//! ## Emphasis and Lines
+//!
+//! [`TextBuilder::bold`] and [`TextBuilder::italic`] emphasize a whole text;
+//! [`TextBuilder::underline`] and [`TextBuilder::strikethrough`] draw a line
+//! under or through it in the text color. Bevy text has no line decorations,
+//! so the lines are thin overlay nodes placed from the text's glyph layout by
+//! `apply_text_decorations`. They are absolutely positioned siblings of the
+//! text rather than children, since a child node would turn the text node
+//! from a measured leaf into a flex container. Only the first line of a
+//! wrapped text is decorated.
+//!
+//! ## Rich Text
+//!
+//! [`Text::rich`] builds one text from several [`Span`]s, each spawned as a
+//! `TextSpan` child with its own weight and color:
+//!
+//! ```rust
+//! let message = Text::rich(vec![
+//!     Span::text("Hello "),
+//!     Span::bold("world"),
+//!     Span::colored("!", theme().red),
+//! ])
+//! .build();
+//! ```
+//!
 //! ## Examples
 //!
 //! ### Basic Usage
@@ -90,14 +121,29 @@
 //! - Supports multiple font families: Sans, Serif, Mono
 //! - Multiple weights: Light, Regular, Medium, Bold (+ Italic variants)
 
-use bevy::prelude::*;
+use std::{collections::HashMap, time::Duration};
+
+use bevy::{
+    ecs::spawn::SpawnWith,
+    prelude::*,
+    text::{LineHeight, TextLayoutInfo},
+};
+use bevy_picking::prelude::Pickable;
 
 use crate::{
+    components::{
+        text_fit::TextFit,
+        text_marquee::TextMarquee,
+        text_selection::{reparent_text_overlay, text_overlay_anchor, SelectableText},
+    },
+    plugin::UiConfig,
     theme::{
-        color::{TextColor, TextContrastLevel},
+        breakpoints::{ActiveBreakpoint, Breakpoint, Responsive},
+        color::{accent_palette, theme, TextColor, TextContrastLevel, UiColorPalette},
+        layout::UiLayout,
         typography::{
-            FontAssets, FontSize, FontFamily, TextSize, TextVariant, TextWeight,
-            get_font_handle, get_font_size_pixels, get_effective_text_size,
+            FontAssets, FontFallbackWarnings, FontSize, FontFamily, TextSize, TextVariant,
+            TextWeight, get_font_handle, get_font_size_pixels, get_effective_text_size,
             get_effective_text_weight, get_effective_font_family,
         },
     },
@@ -145,6 +191,8 @@ pub struct TextBuilder {
     color: Option<TextColor>,
     /// Whether text should be italicized
     italic: bool,
+    /// Height of each line
+    line_height: LineHeight,
     /// Text alignment within its container
     align: Option<JustifyText>,
     /// Background color for intelligent contrast calculation
@@ -153,6 +201,20 @@ pub struct TextBuilder {
     contrast_level: Option<TextContrastLevel>,
     /// Whether color was explicitly set (disables auto-contrast)
     explicit_color_set: bool,
+    /// Text sizes per breakpoint, applied by `apply_responsive_text_sizes`
+    responsive_size: Responsive<TextSize>,
+    /// Space between the text and its background edge
+    padding: UiRect,
+    /// Border widths, drawn in the decoration's border color
+    border: UiRect,
+    /// Background, border color and radius behind the text
+    decoration: TextDecoration,
+    /// Whether the text can be selected and copied with the pointer
+    selectable: bool,
+    /// Scrolling of overflowing text (disabled unless set)
+    marquee: TextMarquee,
+    /// Size bounds when fitting the container (disabled unless set)
+    fit: TextFit,
 }
 
 impl TextBuilder {
@@ -178,10 +240,18 @@ impl TextBuilder {
             family: None,
             color: None,
             italic: false,
+            line_height: LineHeight::default(),
             align: None,
             background_context: None,
             contrast_level: Some(TextContrastLevel::High),
             explicit_color_set: false,
+            responsive_size: Responsive::default(),
+            padding: UiRect::ZERO,
+            border: UiRect::ZERO,
+            decoration: TextDecoration::default(),
+            selectable: false,
+            marquee: TextMarquee::default(),
+            fit: TextFit::default(),
         }
     }
 
@@ -210,6 +280,28 @@ impl TextBuilder {
         self
     }
 
+    /// Sets the text size per breakpoint, updated whenever the
+    /// [`ActiveBreakpoint`] changes.
+    /// 
+    /// The narrowest size is used until the active breakpoint is known.
+    /// 
+    /// # Arguments
+    /// * `sizes` - `(Breakpoint, TextSize)` pairs, each applying from its breakpoint upwards
+    /// 
+    /// # Example
+    /// ```rust
+    /// let title = Text::new("Dashboard")
+    ///     .size_responsive([(Breakpoint::Base, TextSize::Base), (Breakpoint::Lg, TextSize::Xl)])
+    ///     .build();
+    /// ```
+    pub fn size_responsive(mut self, sizes: impl Into<Responsive<TextSize>>) -> Self {
+        self.responsive_size = sizes.into();
+        if let Some(size) = self.responsive_size.resolve(Breakpoint::Base) {
+            self.size = Some(size);
+        }
+        self
+    }
+
     /// Sets the text weight, overriding the variant's default weight.
     /// 
     /// # Arguments
@@ -228,6 +320,13 @@ impl TextBuilder {
         self
     }
 
+    /// Sets the height of each line, e.g. `LineHeight::Px(20.0)` for a fixed
+    /// line grid. Defaults to 1.2 times the font size.
+    pub fn line_height(mut self, line_height: LineHeight) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
     /// Sets an explicit text color, disabling automatic contrast optimization.
     /// 
     /// When a color is explicitly set, the intelligent contrast system is disabled
@@ -249,6 +348,27 @@ impl TextBuilder {
         self
     }
 
+    /// Makes the text bold, for strong emphasis.
+    pub fn bold(self) -> Self {
+        self.weight(TextWeight::Bold)
+    }
+
+    /// Draws a line under the text, in the text color.
+    ///
+    /// Only the first line of a wrapped text is underlined.
+    pub fn underline(mut self) -> Self {
+        self.decoration.underline = true;
+        self
+    }
+
+    /// Draws a line through the text, in the text color.
+    ///
+    /// Only the first line of a wrapped text is struck through.
+    pub fn strikethrough(mut self) -> Self {
+        self.decoration.strikethrough = true;
+        self
+    }
+
     /// Sets the text alignment within its container.
     /// 
     /// # Arguments
@@ -316,6 +436,69 @@ impl TextBuilder {
         self
     }
 
+    /// Draws a background behind the text.
+    pub fn background(mut self, color: Color) -> Self {
+        self.decoration.background = Some(color);
+        self
+    }
+
+    /// Sets the space between the text and its background edge.
+    pub fn padding(mut self, padding: UiRect) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Draws a border of the given widths around the text.
+    pub fn border(mut self, border: UiRect, color: Color) -> Self {
+        self.border = border;
+        self.decoration.border_color = Some(color);
+        self
+    }
+
+    /// Rounds the corners of the background and border.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.decoration.radius = radius;
+        self
+    }
+
+    /// Lets the user select the text by dragging across it and copy the
+    /// selection with Ctrl+C (single-line texts only).
+    pub fn selectable(mut self) -> Self {
+        self.selectable = true;
+        self
+    }
+
+    /// Keeps the text on one line and, while it overflows its parent, scrolls
+    /// it back and forth, pausing at each end and while hovered.
+    pub fn marquee(mut self) -> Self {
+        self.marquee.enabled = true;
+        self
+    }
+
+    /// Sets the marquee scroll speed in logical pixels per second.
+    pub fn marquee_speed(mut self, speed: f32) -> Self {
+        self.marquee.speed = speed;
+        self
+    }
+
+    /// Sets how long the marquee rests at each end.
+    pub fn marquee_pause(mut self, pause: Duration) -> Self {
+        self.marquee.pause = pause;
+        self
+    }
+
+    /// Keeps the text on one line and sizes it to the largest size from
+    /// `min` to `max` that fits its parent's width, see
+    /// [`text_fit`](crate::components::text_fit).
+    pub fn fit_text(mut self, min: TextSize, max: TextSize) -> Self {
+        self.fit = TextFit {
+            enabled: true,
+            min,
+            max,
+        };
+        self
+    }
+
     /// Disables automatic contrast optimization.
     /// 
     /// Forces the system to use explicit colors only, without automatic
@@ -365,6 +548,24 @@ impl TextBuilder {
 }
 
 impl TextBuilder {
+    /// Resolves the text color from the explicit color or the variant's default.
+    fn effective_color(&self) -> Color {
+        let effective_color = self.color.unwrap_or_else(|| match self.variant {
+            TextVariant::Display => TextColor::Default,
+            TextVariant::Title => TextColor::Default,
+            TextVariant::Body => TextColor::Default,
+            TextVariant::Label => TextColor::Default,
+            TextVariant::Caption => TextColor::Muted,
+        });
+        self.map_color(effective_color)
+    }
+
+    /// Resolves the font size in pixels from the size override or the variant.
+    fn font_size(&self) -> f32 {
+        let effective_size = get_effective_text_size(self.variant, self.size);
+        get_font_size_pixels(&FontSize::default(), effective_size)
+    }
+
     /// Builds the text configuration into a Bevy Bundle.
     /// 
     /// This method performs the complex logic of:
@@ -383,20 +584,25 @@ impl TextBuilder {
     /// - TextLayout: Alignment and layout
     /// - Node: UI layout node
     /// - TextFontInfo: Font metadata for the font system
+    /// - ResponsiveTextSize: Sizes per breakpoint (empty unless set)
+    /// - TextDecoration: Background and border colors (empty unless set)
+    /// - SelectableText: Pointer selection state (disabled unless set)
+    /// - TextMarquee: Scrolling of overflowing text (disabled unless set)
+    /// - TextFit: Size bounds for fitting the container (disabled unless set)
     pub fn build(self) -> impl Bundle {
-        let effective_size = get_effective_text_size(self.variant, self.size);
         let effective_weight = get_effective_text_weight(self.variant, self.weight);
         let effective_family = get_effective_font_family(self.variant, self.family);
-        let effective_color = self.color.unwrap_or_else(|| match self.variant {
-            TextVariant::Display => TextColor::Default,
-            TextVariant::Title => TextColor::Default,
-            TextVariant::Body => TextColor::Default,
-            TextVariant::Label => TextColor::Default,
-            TextVariant::Caption => TextColor::Muted,
-        });
+        let color = self.effective_color();
+        let font_size = self.font_size();
 
-        let color = self.map_color(effective_color);
-        let font_size = get_font_size_pixels(&FontSize::default(), effective_size);
+        // Marquee text scrolls and fitted text shrinks instead of wrapping
+        let linebreak = if self.marquee.enabled || self.fit.enabled {
+            LineBreak::NoWrap
+        } else {
+            LineBreak::default()
+        };
+        let mut marquee = self.marquee;
+        marquee.reset();
 
         // Font handle will be updated by the apply_text_fonts system
         let font_handle = Handle::<Font>::default();
@@ -406,15 +612,152 @@ impl TextBuilder {
             TextFont {
                 font: font_handle,
                 font_size,
+                line_height: self.line_height,
                 ..default()
             },
             bevy::prelude::TextColor(color),
-            TextLayout::new_with_justify(self.align.unwrap_or(JustifyText::Left)),
-            Node::default(),
+            TextLayout::new(self.align.unwrap_or(JustifyText::Left), linebreak),
+            Node {
+                padding: self.padding,
+                border: self.border,
+                ..default()
+            },
             TextFontInfo {
                 family: effective_family,
                 weight: effective_weight,
             },
+            ResponsiveTextSize(self.responsive_size),
+            self.decoration,
+            SelectableText::new(self.selectable),
+            marquee,
+            self.fit,
+        )
+    }
+}
+
+/// One run of inline text in a [`Text::rich`] text.
+///
+/// Runs without their own weight or color use those of the text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    /// The text of the run
+    pub content: String,
+    /// Override text weight (if different from the text's weight)
+    pub weight: Option<TextWeight>,
+    /// Override color (if different from the text's color)
+    pub color: Option<Color>,
+}
+
+impl Span {
+    /// Creates a run styled like the surrounding text.
+    pub fn text(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            weight: None,
+            color: None,
+        }
+    }
+
+    /// Creates a bold run.
+    pub fn bold(content: impl Into<String>) -> Self {
+        Self::text(content).weight(TextWeight::Bold)
+    }
+
+    /// Creates a run in the text color of `palette`.
+    pub fn colored(content: impl Into<String>, palette: UiColorPalette) -> Self {
+        Self::text(content).color(palette.text)
+    }
+
+    /// Sets the weight of the run.
+    pub fn weight(mut self, weight: TextWeight) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Sets the color of the run.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// Builder for texts made of several [`Span`]s, created with [`Text::rich`].
+///
+/// The text itself is styled like any other text; its spans inherit the font
+/// size, family, weight and color unless they override them.
+#[derive(Debug, Clone)]
+pub struct RichTextBuilder {
+    text: TextBuilder,
+    spans: Vec<Span>,
+}
+
+impl RichTextBuilder {
+    /// Sets the semantic text variant.
+    pub fn variant(mut self, variant: TextVariant) -> Self {
+        self.text = self.text.variant(variant);
+        self
+    }
+
+    /// Sets the text size, overriding the variant's default size.
+    pub fn size(mut self, size: TextSize) -> Self {
+        self.text = self.text.size(size);
+        self
+    }
+
+    /// Sets the weight of spans without their own weight.
+    pub fn weight(mut self, weight: TextWeight) -> Self {
+        self.text = self.text.weight(weight);
+        self
+    }
+
+    /// Sets the font family of all spans.
+    pub fn family(mut self, family: FontFamily) -> Self {
+        self.text = self.text.family(family);
+        self
+    }
+
+    /// Sets the color of spans without their own color.
+    pub fn color(mut self, color: TextColor) -> Self {
+        self.text = self.text.color(color);
+        self
+    }
+
+    /// Sets the text alignment within its container.
+    pub fn align(mut self, align: JustifyText) -> Self {
+        self.text = self.text.align(align);
+        self
+    }
+
+    /// Builds the text with one `TextSpan` child per span.
+    ///
+    /// The children carry their own `TextFont`, `TextColor` and
+    /// [`TextFontInfo`], so `apply_text_fonts` loads the font of each weight.
+    pub fn build(self) -> impl Bundle {
+        let family = get_effective_font_family(self.text.variant, self.text.family);
+        let weight = get_effective_text_weight(self.text.variant, self.text.weight);
+        let color = self.text.effective_color();
+        let font_size = self.text.font_size();
+        let spans = self.spans;
+
+        (
+            self.text.build(),
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                for span in spans {
+                    parent.spawn((
+                        TextSpan::new(span.content),
+                        TextFont {
+                            font: Handle::<Font>::default(),
+                            font_size,
+                            ..default()
+                        },
+                        bevy::prelude::TextColor(span.color.unwrap_or(color)),
+                        TextFontInfo {
+                            family,
+                            weight: span.weight.unwrap_or(weight),
+                        },
+                    ));
+                }
+            })),
         )
     }
 }
@@ -435,6 +778,62 @@ pub struct TextFontInfo {
     pub weight: TextWeight,
 }
 
+/// Text sizes per breakpoint set with [`TextBuilder::size_responsive`].
+/// 
+/// Empty for texts with a fixed size.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ResponsiveTextSize(pub Responsive<TextSize>);
+
+/// Background, border color, corner radius and lines of a decorated text.
+///
+/// Applied as `BackgroundColor`, `BorderColor` and `BorderRadius` by
+/// `apply_text_decorations`; kept separate from the text bundle so plain
+/// texts can still be spawned with their own background. The underline and
+/// strikethrough are drawn as [`TextDecorationLine`] overlays.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct TextDecoration {
+    pub background: Option<Color>,
+    pub border_color: Option<Color>,
+    pub radius: f32,
+    pub underline: bool,
+    pub strikethrough: bool,
+}
+
+/// Kind of line drawn by a [`TextDecorationLine`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextLineKind {
+    Underline,
+    Strikethrough,
+}
+
+/// Overlay drawing the underline or strikethrough of `text`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TextDecorationLine {
+    pub text: Entity,
+    pub kind: TextLineKind,
+}
+
+/// Baseline position as a fraction of the font size below the top of the font box
+const TEXT_ASCENT: f32 = 0.8;
+/// Underline offset below the baseline, as a fraction of the font size
+const UNDERLINE_OFFSET: f32 = 0.1;
+/// Strikethrough offset above the baseline, as a fraction of the font size
+const STRIKETHROUGH_OFFSET: f32 = 0.3;
+
+/// Top of a decoration line (and its thickness) relative to the top of a
+/// `line_height` tall text line set in `font_size`, in logical pixels.
+///
+/// The font box is centered in the line, with the baseline at its ascent.
+pub fn text_line_offset(kind: TextLineKind, line_height: f32, font_size: f32) -> (f32, f32) {
+    let thickness = (font_size / 16.0).max(1.0);
+    let baseline = (line_height - font_size) / 2.0 + font_size * TEXT_ASCENT;
+    let center = match kind {
+        TextLineKind::Underline => baseline + font_size * UNDERLINE_OFFSET,
+        TextLineKind::Strikethrough => baseline - font_size * STRIKETHROUGH_OFFSET,
+    };
+    (center - thickness / 2.0, thickness)
+}
+
 /// Main Text component interface providing semantic text creation methods.
 /// 
 /// The Text struct serves as the primary entry point for creating text components
@@ -479,6 +878,20 @@ impl Text {
         TextBuilder::new(content)
     }
 
+    /// Creates a text made of several differently styled runs.
+    ///
+    /// # Arguments
+    /// * `spans` - The runs of the text, in reading order
+    ///
+    /// # Returns
+    /// A RichTextBuilder ready for customization
+    pub fn rich(spans: impl IntoIterator<Item = Span>) -> RichTextBuilder {
+        RichTextBuilder {
+            text: TextBuilder::new(""),
+            spans: spans.into_iter().collect(),
+        }
+    }
+
     // === Semantic Text Variants ===
     
     /// Creates display text - the largest, most prominent text style.
@@ -556,7 +969,46 @@ impl Text {
     /// # Arguments
     /// * `content` - The text content to display
     pub fn code(content: impl Into<String>) -> TextBuilder {
-        TextBuilder::new(content).family(FontFamily::Mono)
+        let layout = UiLayout::default();
+        TextBuilder::new(content)
+            .family(FontFamily::Mono)
+            .background(theme().gray.bg_subtle)
+            .padding(UiRect::horizontal(Val::Px(layout.padding.xs)))
+            .radius(layout.radius.sm)
+    }
+
+    /// Creates a multiline code block.
+    ///
+    /// Monospace text on a subtle background with padding on all sides,
+    /// a fine border and rounded corners.
+    ///
+    /// # Arguments
+    /// * `content` - The code to display, lines separated by `\n`
+    pub fn code_block(content: impl Into<String>) -> TextBuilder {
+        let layout = UiLayout::default();
+        let palette = theme().gray;
+        TextBuilder::new(content)
+            .family(FontFamily::Mono)
+            .background(palette.bg_subtle)
+            .padding(UiRect::all(Val::Px(layout.padding.base)))
+            .border(UiRect::all(Val::Px(layout.border.xs)), palette.line)
+            .radius(layout.radius.base)
+    }
+
+    /// Creates a blockquote - quoted text set off by an accent bar.
+    ///
+    /// Indented body text with a border on the left in the accent color.
+    ///
+    /// # Arguments
+    /// * `content` - The quoted text
+    pub fn blockquote(content: impl Into<String>) -> TextBuilder {
+        let layout = UiLayout::default();
+        TextBuilder::new(content)
+            .padding(UiRect::left(Val::Px(layout.padding.base)))
+            .border(
+                UiRect::left(Val::Px(layout.border.base)),
+                accent_palette().border,
+            )
     }
 
     // === Accessibility-Focused Methods ===
@@ -609,6 +1061,8 @@ pub fn apply_text_fonts(
     mut commands: Commands,
     mut text_query: Query<(Entity, &TextFontInfo, &mut TextFont), With<TextFontInfo>>,
     font_assets: Option<Res<FontAssets>>,
+    config: Option<Res<UiConfig>>,
+    mut fallback_warnings: Local<FontFallbackWarnings>,
 ) {
     // Wait for font assets to be loaded
     let Some(font_assets) = font_assets else {
@@ -629,6 +1083,14 @@ pub fn apply_text_fonts(
     for (entity, font_info, mut text_font) in text_query.iter_mut() {
         // Get the appropriate font handle based on family and weight
         let font_handle = get_font_handle(&font_assets, font_info.family, font_info.weight);
+        if let Some(warning) = fallback_warnings.unavailable_warning(font_info.family) {
+            warn!("{warning}");
+        }
+        if config.as_ref().is_some_and(|config| config.warn_font_fallback) {
+            if let Some(warning) = fallback_warnings.warning(font_info.family, font_info.weight) {
+                warn!("{warning}");
+            }
+        }
 
         debug!(
             "Applying font to entity {:?}: family={:?}, weight={:?}, font_handle={:?}",
@@ -645,4 +1107,260 @@ pub fn apply_text_fonts(
         // This prevents the entity from being processed again
         commands.entity(entity).remove::<TextFontInfo>();
     }
-}
\ No newline at end of file
+}
+
+/// Horizontal extent (left, right) and height of the first line of a text
+/// layout, in logical pixels relative to the text's content box.
+fn first_line_extent(info: &TextLayoutInfo, inverse_scale_factor: f32) -> Option<(f32, f32, f32)> {
+    let lines = info.glyphs.iter().map(|glyph| glyph.line_index + 1).max()?;
+    let (left, right) = info
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.line_index == 0)
+        .map(|glyph| {
+            (
+                glyph.position.x - glyph.size.x / 2.0,
+                glyph.position.x + glyph.size.x / 2.0,
+            )
+        })
+        .reduce(|(left, right), (l, r)| (left.min(l), right.max(r)))?;
+    let line_height = info.size.y / lines as f32;
+    Some((
+        left * inverse_scale_factor,
+        right * inverse_scale_factor,
+        line_height * inverse_scale_factor,
+    ))
+}
+
+/// System that applies the [`TextDecoration`] of new decorated texts, and
+/// keeps the underline and strikethrough overlays on their texts, spawning
+/// and despawning them as needed.
+#[allow(clippy::type_complexity)]
+pub fn apply_text_decorations(
+    mut commands: Commands,
+    query: Query<(Entity, &TextDecoration), Added<TextDecoration>>,
+    texts: Query<(
+        Entity,
+        &TextDecoration,
+        &TextLayoutInfo,
+        &TextFont,
+        &bevy::prelude::TextColor,
+        &ComputedNode,
+        &GlobalTransform,
+        &InheritedVisibility,
+        Option<&ChildOf>,
+    )>,
+    nodes: Query<(&GlobalTransform, &ComputedNode)>,
+    mut lines: Query<(
+        Entity,
+        &TextDecorationLine,
+        &mut Node,
+        &mut BackgroundColor,
+        Option<&ChildOf>,
+    )>,
+) {
+    for (entity, decoration) in &query {
+        let mut entity = commands.entity(entity);
+        if let Some(background) = decoration.background {
+            entity.insert(BackgroundColor(background));
+        }
+        if let Some(border_color) = decoration.border_color {
+            entity.insert(BorderColor(border_color));
+        }
+        if decoration.radius > 0.0 {
+            entity.insert(BorderRadius::all(Val::Px(decoration.radius)));
+        }
+    }
+
+    let mut existing: HashMap<(Entity, TextLineKind), Entity> = lines
+        .iter()
+        .map(|(line, decoration_line, ..)| ((decoration_line.text, decoration_line.kind), line))
+        .collect();
+
+    for (entity, decoration, info, font, color, computed, transform, visibility, child_of) in &texts
+    {
+        if !visibility.get() {
+            continue;
+        }
+        let kinds = [
+            (TextLineKind::Underline, decoration.underline),
+            (TextLineKind::Strikethrough, decoration.strikethrough),
+        ];
+        if kinds.iter().all(|(_, enabled)| !enabled) {
+            continue;
+        }
+        let Some((left, right, line_height)) =
+            first_line_extent(info, computed.inverse_scale_factor())
+        else {
+            continue;
+        };
+        let (parent, origin) = text_overlay_anchor(transform, computed, child_of, &nodes);
+
+        for (kind, _) in kinds.into_iter().filter(|(_, enabled)| *enabled) {
+            let (top, thickness) = text_line_offset(kind, line_height, font.font_size);
+            let node = Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(origin.x + left),
+                top: Val::Px(origin.y + top),
+                width: Val::Px(right - left),
+                height: Val::Px(thickness),
+                ..default()
+            };
+            match existing
+                .remove(&(entity, kind))
+                .and_then(|line| lines.get_mut(line).ok())
+            {
+                Some((line, _, mut line_node, mut background, current)) => {
+                    if *line_node != node {
+                        *line_node = node;
+                    }
+                    background.set_if_neq(BackgroundColor(color.0));
+                    reparent_text_overlay(&mut commands, line, current, parent);
+                }
+                None => {
+                    // Spawned after the text, so it is drawn above it
+                    let mut line = commands.spawn((
+                        Name::new("TextDecorationLine"),
+                        TextDecorationLine { text: entity, kind },
+                        node,
+                        BackgroundColor(color.0),
+                        Pickable::IGNORE,
+                    ));
+                    if let Some(parent) = parent {
+                        line.insert(ChildOf(parent));
+                    }
+                }
+            }
+        }
+    }
+
+    // Lines of hidden, undecorated or despawned texts
+    for line in existing.into_values() {
+        commands.entity(line).despawn();
+    }
+}
+
+/// System that resizes responsive texts for the active breakpoint.
+/// 
+/// Runs for every text whose [`ResponsiveTextSize`] is non-empty when it is
+/// spawned or changed, and for all of them when the [`ActiveBreakpoint`] changes.
+pub fn apply_responsive_text_sizes(
+    active: Res<ActiveBreakpoint>,
+    mut text_query: Query<(Ref<ResponsiveTextSize>, &mut TextFont)>,
+) {
+    for (responsive, mut text_font) in text_query.iter_mut() {
+        if !active.is_changed() && !responsive.is_changed() {
+            continue;
+        }
+        let Some(size) = responsive.0.resolve(active.0) else {
+            continue;
+        };
+
+        let font_size = get_font_size_pixels(&FontSize::default(), size);
+        if text_font.font_size != font_size {
+            text_font.font_size = font_size;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_is_mono_on_subtle_background() {
+        let code = Text::code("let x = 1;");
+        assert_eq!(code.family, Some(FontFamily::Mono));
+        assert_eq!(code.decoration.background, Some(theme().gray.bg_subtle));
+        assert_eq!(code.padding.top, Val::ZERO);
+        assert!(code.padding.left != Val::ZERO);
+    }
+
+    #[test]
+    fn test_code_block_is_padded_and_rounded() {
+        let block = Text::code_block("fn main() {\n    run();\n}");
+        assert_eq!(block.family, Some(FontFamily::Mono));
+        assert_eq!(block.decoration.background, Some(theme().gray.bg_subtle));
+        assert!(block.padding.top != Val::ZERO && block.padding.left != Val::ZERO);
+        assert!(block.decoration.radius > 0.0);
+    }
+
+    #[test]
+    fn test_blockquote_has_left_accent_border() {
+        let quote = Text::blockquote("Simplicity is prerequisite for reliability.");
+        assert_eq!(quote.decoration.border_color, Some(accent_palette().border));
+        assert!(quote.border.left != Val::ZERO);
+        assert_eq!(quote.border.right, Val::ZERO);
+        assert_eq!(quote.decoration.background, None);
+    }
+
+    #[test]
+    fn test_decoration_lines_sit_under_and_through_text() {
+        // 20px font on a 24px line: baseline at 2 + 16 = 18px
+        let (underline, thickness) = text_line_offset(TextLineKind::Underline, 24.0, 20.0);
+        let (strikethrough, _) = text_line_offset(TextLineKind::Strikethrough, 24.0, 20.0);
+
+        assert_eq!(thickness, 1.25);
+        assert!((underline + thickness / 2.0 - 20.0).abs() < 1e-4);
+        assert!((strikethrough + thickness / 2.0 - 12.0).abs() < 1e-4);
+        assert!(strikethrough < underline);
+    }
+
+    #[test]
+    fn test_underline_and_strikethrough_set_decoration() {
+        let text = Text::body("Deprecated").strikethrough().bold();
+        assert!(text.decoration.strikethrough);
+        assert!(!text.decoration.underline);
+        assert_eq!(text.weight, Some(TextWeight::Bold));
+        assert!(Text::body("Link").underline().decoration.underline);
+    }
+
+    #[test]
+    fn test_rich_text_spawns_styled_spans() {
+        let mut world = World::new();
+        let red = theme().red;
+        let text = world
+            .spawn(
+                Text::rich(vec![
+                    Span::text("Hello "),
+                    Span::bold("world"),
+                    Span::colored("!", red.clone()),
+                ])
+                .build(),
+            )
+            .id();
+
+        assert_eq!(world.get::<bevy::ui::widget::Text>(text).unwrap().0, "");
+        let base_color = world.get::<bevy::prelude::TextColor>(text).unwrap().0;
+        let font_size = world.get::<TextFont>(text).unwrap().font_size;
+
+        let children: Vec<Entity> = world.get::<Children>(text).unwrap().to_vec();
+        let spans: Vec<(String, TextWeight, Color, f32)> = children
+            .iter()
+            .map(|child| {
+                (
+                    world.get::<TextSpan>(*child).unwrap().0.clone(),
+                    world.get::<TextFontInfo>(*child).unwrap().weight,
+                    world.get::<bevy::prelude::TextColor>(*child).unwrap().0,
+                    world.get::<TextFont>(*child).unwrap().font_size,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            spans,
+            vec![
+                ("Hello ".to_string(), TextWeight::Regular, base_color, font_size),
+                ("world".to_string(), TextWeight::Bold, base_color, font_size),
+                ("!".to_string(), TextWeight::Regular, red.text, font_size),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_presets_stay_customizable() {
+        let code = Text::code("x").size(TextSize::Xs).background(Color::BLACK);
+        assert_eq!(code.size, Some(TextSize::Xs));
+        assert_eq!(code.decoration.background, Some(Color::BLACK));
+    }
+}
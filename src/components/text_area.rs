@@ -0,0 +1,862 @@
+use crate::{
+    components::{
+        focus::{FocusChangeEvent, Focusable},
+        scroll_area::{ScrollArea, ScrollAreaViewport},
+        text::Text as UiText,
+        text_field::TextFieldSize,
+    },
+    theme::{
+        color::{accent_palette, theme, TextColor as TextColorEnum, UiColorPalette},
+        layout::UiLayout,
+        typography::{get_font_size_pixels, FontSize},
+    },
+    utilities::naming::Namespace,
+};
+use bevy::{
+    ecs::spawn::SpawnWith,
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+    text::{LineHeight, TextLayoutInfo},
+};
+use bevy_picking::prelude::Pickable;
+
+/// Event emitted whenever the text of a text area changes.
+#[derive(Event, Debug, Clone)]
+pub struct TextAreaChangeEvent {
+    pub text_area_entity: Entity,
+    pub value: String,
+}
+
+/// Multiline, word-wrapped text entry.
+///
+/// The caret position is a character index into `value`.
+#[derive(Component, Debug, Clone)]
+pub struct TextAreaComponent {
+    pub value: String,
+    pub placeholder: String,
+    /// Caret position
+    pub cursor: usize,
+    pub focused: bool,
+    pub disabled: bool,
+    /// Maximum number of characters, newlines included
+    pub max_length: Option<usize>,
+    /// Visible rows; also the minimum height when auto-growing
+    pub rows: usize,
+    /// Grow with the content up to this many rows, then scroll
+    pub max_rows: Option<usize>,
+    pub size: TextFieldSize,
+    pub color: UiColorPalette,
+    /// Horizontal position kept across consecutive Up/Down moves
+    goal_x: Option<f32>,
+}
+
+impl Default for TextAreaComponent {
+    fn default() -> Self {
+        Self {
+            value: String::new(),
+            placeholder: String::new(),
+            cursor: 0,
+            focused: false,
+            disabled: false,
+            max_length: None,
+            rows: 3,
+            max_rows: None,
+            size: TextFieldSize::Size2,
+            color: accent_palette(),
+            goal_x: None,
+        }
+    }
+}
+
+/// Line height relative to the font size.
+const LINE_HEIGHT_FACTOR: f32 = 1.4;
+const CARET_WIDTH: f32 = 1.5;
+const CARET_BLINK_RATE: f32 = 2.0;
+
+/// A laid out glyph in logical pixels, relative to the text's top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextAreaGlyph {
+    pub byte_index: usize,
+    /// Visual (wrapped) line
+    pub line: usize,
+    pub left: f32,
+    pub right: f32,
+}
+
+/// Where the caret is drawn when placed before a character (or at the end).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CaretStop {
+    pub line: usize,
+    pub x: f32,
+}
+
+/// Computes one caret stop per character boundary of `value`, so
+/// `stops[cursor]` is the caret location for a character index.
+///
+/// Newlines have no glyphs; their lines are derived from the text itself,
+/// which keeps empty lines reachable.
+pub fn caret_stops(value: &str, glyphs: &[TextAreaGlyph]) -> Vec<CaretStop> {
+    let mut stops = Vec::with_capacity(value.len() + 1);
+    let mut glyphs = glyphs.iter().peekable();
+    let mut line = 0;
+    let mut x = 0.0;
+
+    for (byte_index, c) in value.char_indices() {
+        while glyphs.next_if(|g| g.byte_index < byte_index).is_some() {}
+
+        match glyphs.next_if(|g| g.byte_index == byte_index) {
+            Some(glyph) => {
+                line = glyph.line;
+                stops.push(CaretStop {
+                    line,
+                    x: glyph.left,
+                });
+                x = glyph.right;
+            }
+            None => stops.push(CaretStop { line, x }),
+        }
+
+        if c == '\n' {
+            line += 1;
+            x = 0.0;
+        }
+    }
+    stops.push(CaretStop { line, x });
+    stops
+}
+
+impl TextAreaComponent {
+    pub fn new(name: impl Into<String>) -> TextAreaBuilder {
+        TextAreaBuilder::new(name)
+    }
+
+    pub fn char_len(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(char_index)
+            .map(|(index, _)| index)
+            .unwrap_or(self.value.len())
+    }
+
+    pub fn line_height(&self) -> f32 {
+        get_font_size_pixels(&FontSize::default(), self.size.text_size()) * LINE_HEIGHT_FACTOR
+    }
+
+    /// Rows shown for content spanning `line_count` visual lines.
+    pub fn visible_rows(&self, line_count: usize) -> usize {
+        let max_rows = self.max_rows.unwrap_or(self.rows).max(self.rows);
+        line_count.clamp(self.rows, max_rows)
+    }
+
+    /// Outer height for `rows` visible rows, including padding and border.
+    pub fn height_for_rows(&self, rows: usize) -> f32 {
+        let layout = UiLayout::default();
+        rows as f32 * self.line_height() + 2.0 * (layout.padding.sm + layout.border.xs)
+    }
+
+    /// Inserts `text` at the caret. Newlines are kept, other control
+    /// characters dropped. Returns whether the value changed.
+    pub fn insert_text(&mut self, text: &str) -> bool {
+        self.goal_x = None;
+        let available = self
+            .max_length
+            .map_or(usize::MAX, |max| max.saturating_sub(self.char_len()));
+        let inserted: String = text
+            .chars()
+            .filter(|c| *c == '\n' || !c.is_control())
+            .take(available)
+            .collect();
+        if inserted.is_empty() {
+            return false;
+        }
+
+        let index = self.byte_index(self.cursor);
+        self.value.insert_str(index, &inserted);
+        self.cursor += inserted.chars().count();
+        true
+    }
+
+    /// Deletes the character before the caret.
+    pub fn backspace(&mut self) -> bool {
+        self.goal_x = None;
+        if self.cursor == 0 {
+            return false;
+        }
+        self.cursor -= 1;
+        let index = self.byte_index(self.cursor);
+        self.value.remove(index);
+        true
+    }
+
+    /// Deletes the character after the caret.
+    pub fn delete(&mut self) -> bool {
+        self.goal_x = None;
+        if self.cursor >= self.char_len() {
+            return false;
+        }
+        let index = self.byte_index(self.cursor);
+        self.value.remove(index);
+        true
+    }
+
+    pub fn move_left(&mut self) {
+        self.goal_x = None;
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        self.goal_x = None;
+        self.cursor = (self.cursor + 1).min(self.char_len());
+    }
+
+    /// Moves the caret `delta` visual lines up (negative) or down, keeping
+    /// its horizontal position across consecutive moves. Moving past the
+    /// first or last line jumps to the start or end of the text.
+    pub fn move_vertical(&mut self, stops: &[CaretStop], delta: isize) {
+        let Some(current) = stops.get(self.cursor) else {
+            return;
+        };
+        let goal_x = *self.goal_x.get_or_insert(current.x);
+        let Some(target) = current.line.checked_add_signed(delta) else {
+            self.cursor = 0;
+            return;
+        };
+
+        let closest = stops
+            .iter()
+            .enumerate()
+            .filter(|(_, stop)| stop.line == target)
+            .min_by(|(_, a), (_, b)| (a.x - goal_x).abs().total_cmp(&(b.x - goal_x).abs()));
+        self.cursor = match closest {
+            Some((index, _)) => index,
+            None => self.char_len(),
+        };
+    }
+
+    /// Moves the caret to the start of its visual line.
+    pub fn move_line_start(&mut self, stops: &[CaretStop]) {
+        self.goal_x = None;
+        if let Some(current) = stops.get(self.cursor) {
+            self.cursor = stops
+                .iter()
+                .position(|stop| stop.line == current.line)
+                .unwrap_or(self.cursor);
+        }
+    }
+
+    /// Moves the caret to the end of its visual line.
+    pub fn move_line_end(&mut self, stops: &[CaretStop]) {
+        self.goal_x = None;
+        if let Some(current) = stops.get(self.cursor) {
+            self.cursor = stops
+                .iter()
+                .rposition(|stop| stop.line == current.line)
+                .unwrap_or(self.cursor);
+        }
+    }
+
+    pub fn move_home(&mut self) {
+        self.goal_x = None;
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.goal_x = None;
+        self.cursor = self.char_len();
+    }
+}
+
+/// Child entities of a text area, resolved once at build time.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TextAreaParts {
+    pub text: Entity,
+    pub caret: Entity,
+    pub placeholder: Entity,
+    pub scroll_area: Entity,
+}
+
+/// Glyph layout of the text area's text, mirrored from [`TextLayoutInfo`].
+#[derive(Component, Debug, Clone, Default)]
+pub struct TextAreaLayout {
+    pub glyphs: Vec<TextAreaGlyph>,
+}
+
+/// Marker for the text entity showing the value.
+#[derive(Component, Debug)]
+pub struct TextAreaText;
+
+/// Marker for the blinking caret.
+#[derive(Component, Debug)]
+pub struct TextAreaCaret;
+
+pub struct TextAreaBuilder {
+    name: String,
+    text_area: TextAreaComponent,
+    width: Val,
+}
+
+impl TextAreaBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: format!("{}_TextArea", name.into()),
+            text_area: TextAreaComponent::default(),
+            width: Val::Px(320.0),
+        }
+    }
+
+    /// Initial text, truncated to `max_length` when built.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.text_area.value = value.into();
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.text_area.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.text_area.max_length = Some(max_length);
+        self
+    }
+
+    /// Number of visible rows (at least one).
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.text_area.rows = rows.max(1);
+        self
+    }
+
+    /// Grows with the content up to `max_rows` rows before scrolling.
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.text_area.max_rows = Some(max_rows);
+        self
+    }
+
+    pub fn size(mut self, size: TextFieldSize) -> Self {
+        self.text_area.size = size;
+        self
+    }
+
+    pub fn color(mut self, color: UiColorPalette) -> Self {
+        self.text_area.color = color;
+        self
+    }
+
+    pub fn width(mut self, width: Val) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.text_area.disabled = true;
+        self
+    }
+}
+
+impl TextAreaBuilder {
+    fn calculate_style(&self) -> Node {
+        let layout = UiLayout::default();
+        Node {
+            width: self.width,
+            height: Val::Px(self.text_area.height_for_rows(self.text_area.rows)),
+            padding: UiRect::all(Val::Px(layout.padding.sm)),
+            border: UiRect::all(Val::Px(layout.border.xs)),
+            flex_direction: FlexDirection::Column,
+            overflow: Overflow::clip(),
+            ..default()
+        }
+    }
+
+    /// Text area bundle. Its text, caret and placeholder are spawned in a
+    /// vertical scroll area when the bundle is inserted.
+    pub fn build(mut self) -> impl Bundle {
+        if let Some(max_length) = self.text_area.max_length {
+            self.text_area.value = self.text_area.value.chars().take(max_length).collect();
+        }
+        self.text_area.cursor = self.text_area.char_len();
+
+        let node = self.calculate_style();
+        let focusable = Focusable::new().disabled(self.text_area.disabled);
+        let palette = self.text_area.color.clone();
+        let text_size = self.text_area.size.text_size();
+        let line_height = self.text_area.line_height();
+        let background = if self.text_area.disabled {
+            palette.bg_subtle.with_alpha(0.5)
+        } else {
+            palette.bg_subtle
+        };
+        let name = self.name.clone();
+        let value = self.text_area.value.clone();
+        let placeholder = self.text_area.placeholder.clone();
+        let caret_color = palette.solid;
+        let text_color = palette.text_contrast;
+
+        (
+            Name::new(self.name),
+            self.text_area,
+            TextAreaLayout::default(),
+            node,
+            BackgroundColor(background),
+            BorderColor(palette.border),
+            BorderRadius::all(Val::Px(UiLayout::default().radius.sm)),
+            Pickable::default(),
+            Interaction::default(),
+            focusable,
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                let root = parent.target_entity();
+                let world = parent.world_mut();
+
+                let text = world
+                    .spawn((
+                        UiText::body(value)
+                            .size(text_size)
+                            .color(TextColorEnum::Custom(text_color))
+                            .line_height(LineHeight::Px(line_height))
+                            .build(),
+                        TextAreaText,
+                        Pickable::IGNORE,
+                    ))
+                    // Full width so the text wraps at the area's edge
+                    .insert(Node {
+                        width: Val::Percent(100.0),
+                        ..default()
+                    })
+                    .id();
+
+                let placeholder = world
+                    .spawn((
+                        UiText::body(placeholder)
+                            .size(text_size)
+                            .color(TextColorEnum::Custom(theme().gray.text))
+                            .line_height(LineHeight::Px(line_height))
+                            .build(),
+                        Pickable::IGNORE,
+                    ))
+                    .insert(Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(0.0),
+                        top: Val::Px(0.0),
+                        ..default()
+                    })
+                    .id();
+
+                let caret = world
+                    .spawn((
+                        Name::new("TextAreaCaret"),
+                        TextAreaCaret,
+                        Node {
+                            position_type: PositionType::Absolute,
+                            width: Val::Px(CARET_WIDTH),
+                            height: Val::Px(line_height),
+                            ..default()
+                        },
+                        BackgroundColor(caret_color),
+                        Visibility::Hidden,
+                        Pickable::IGNORE,
+                    ))
+                    .id();
+
+                let content = world
+                    .spawn((
+                        Node {
+                            position_type: PositionType::Relative,
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Column,
+                            flex_shrink: 0.0,
+                            ..default()
+                        },
+                        Pickable::IGNORE,
+                    ))
+                    .add_children(&[text, placeholder, caret])
+                    .id();
+
+                let scroll_area = ScrollArea::new(name)
+                    .vertical()
+                    .child(content)
+                    .build(&mut world.commands());
+                world.flush();
+
+                world
+                    .entity_mut(root)
+                    .insert(TextAreaParts {
+                        text,
+                        caret,
+                        placeholder,
+                        scroll_area,
+                    })
+                    .add_child(scroll_area);
+            })),
+        )
+    }
+}
+
+/// Focuses a text area when it is pressed and blurs all others on any mouse press.
+pub fn handle_text_area_focus(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut areas: Query<(&Interaction, &mut TextAreaComponent)>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    for (interaction, mut area) in &mut areas {
+        let focused = *interaction == Interaction::Pressed && !area.disabled;
+        if focused && !area.focused {
+            area.focused = true;
+            area.cursor = area.char_len();
+        } else if !focused && area.focused {
+            area.focused = false;
+        }
+    }
+}
+
+/// Starts or stops editing when keyboard navigation moves the focus.
+pub fn follow_text_area_keyboard_focus(
+    mut focus_events: EventReader<FocusChangeEvent>,
+    mut areas: Query<&mut TextAreaComponent>,
+) {
+    for event in focus_events.read() {
+        if let Some(mut area) = event.previous.and_then(|entity| areas.get_mut(entity).ok()) {
+            area.focused = false;
+        }
+        if let Some(mut area) = event.focused.and_then(|entity| areas.get_mut(entity).ok()) {
+            if !area.disabled {
+                area.focused = true;
+                area.cursor = area.char_len();
+            }
+        }
+    }
+}
+
+/// Applies keyboard input to the focused text area.
+pub fn handle_text_area_keyboard(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut areas: Query<(Entity, &mut TextAreaComponent, &TextAreaLayout)>,
+    mut change_events: EventWriter<TextAreaChangeEvent>,
+) {
+    let Some((entity, mut area, layout)) = areas.iter_mut().find(|(_, area, _)| area.focused)
+    else {
+        keyboard_events.clear();
+        return;
+    };
+
+    let command = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+
+    for event in keyboard_events.read() {
+        if !event.state.is_pressed() || !area.focused {
+            continue;
+        }
+
+        // Glyphs lag one frame behind edits; stops still follow the text's newlines
+        let changed = match &event.logical_key {
+            Key::Character(_) if command => false,
+            Key::Character(text) => area.insert_text(text),
+            Key::Space => area.insert_text(" "),
+            Key::Enter => area.insert_text("\n"),
+            Key::Backspace => area.backspace(),
+            Key::Delete => area.delete(),
+            Key::ArrowLeft => {
+                area.move_left();
+                false
+            }
+            Key::ArrowRight => {
+                area.move_right();
+                false
+            }
+            Key::ArrowUp => {
+                let stops = caret_stops(&area.value, &layout.glyphs);
+                area.move_vertical(&stops, -1);
+                false
+            }
+            Key::ArrowDown => {
+                let stops = caret_stops(&area.value, &layout.glyphs);
+                area.move_vertical(&stops, 1);
+                false
+            }
+            Key::Home if command => {
+                area.move_home();
+                false
+            }
+            Key::End if command => {
+                area.move_end();
+                false
+            }
+            Key::Home => {
+                let stops = caret_stops(&area.value, &layout.glyphs);
+                area.move_line_start(&stops);
+                false
+            }
+            Key::End => {
+                let stops = caret_stops(&area.value, &layout.glyphs);
+                area.move_line_end(&stops);
+                false
+            }
+            Key::Escape => {
+                area.focused = false;
+                false
+            }
+            _ => false,
+        };
+
+        if changed {
+            change_events.write(TextAreaChangeEvent {
+                text_area_entity: entity,
+                value: area.value.clone(),
+            });
+        }
+    }
+}
+
+/// Mirrors the laid out glyphs of each text area's text into [`TextAreaLayout`].
+pub fn sync_text_area_layout(
+    mut areas: Query<(&TextAreaParts, &mut TextAreaLayout)>,
+    texts: Query<(&TextLayoutInfo, &ComputedNode), Changed<TextLayoutInfo>>,
+) {
+    for (parts, mut layout) in &mut areas {
+        let Ok((info, computed)) = texts.get(parts.text) else {
+            continue;
+        };
+
+        // Glyph positions are physical pixels and centered on the glyph
+        let scale = computed.inverse_scale_factor();
+        let mut glyphs: Vec<TextAreaGlyph> = info
+            .glyphs
+            .iter()
+            .map(|glyph| TextAreaGlyph {
+                byte_index: glyph.byte_index,
+                line: glyph.line_index,
+                left: (glyph.position.x - glyph.size.x / 2.0) * scale,
+                right: (glyph.position.x + glyph.size.x / 2.0) * scale,
+            })
+            .collect();
+        glyphs.sort_by_key(|glyph| glyph.byte_index);
+        layout.glyphs = glyphs;
+    }
+}
+
+/// Height of a line of text set in `font`, in logical pixels.
+fn line_height_px(font: &TextFont) -> f32 {
+    match font.line_height {
+        LineHeight::Px(px) => px,
+        LineHeight::RelativeToFont(factor) => factor * font.font_size,
+    }
+}
+
+/// Text area state shown by [`update_text_area_display`].
+type TextAreaDisplayState = (
+    &'static TextAreaComponent,
+    &'static TextAreaParts,
+    &'static TextAreaLayout,
+    &'static mut Node,
+    &'static mut BorderColor,
+);
+
+/// Text areas whose value, focus or layout changed.
+type TextAreaDisplayChanged = Or<(Changed<TextAreaComponent>, Changed<TextAreaLayout>)>;
+
+/// Mirrors value, caret and focus state into the text area's children,
+/// grows it with its content and keeps the caret scrolled into view.
+pub fn update_text_area_display(
+    mut areas: Query<TextAreaDisplayState, TextAreaDisplayChanged>,
+    mut texts: Query<(&mut Text, &TextFont), With<TextAreaText>>,
+    mut nodes: Query<&mut Node, Without<TextAreaComponent>>,
+    mut viewports: Query<(&ScrollAreaViewport, &ComputedNode, &mut ScrollPosition)>,
+) {
+    for (area, parts, layout, mut node, mut border) in &mut areas {
+        border.0 = if area.focused {
+            area.color.solid
+        } else {
+            area.color.border
+        };
+
+        // Lines are as tall as the text lays them out
+        let mut line_height = area.line_height();
+        if let Ok((mut text, font)) = texts.get_mut(parts.text) {
+            if text.0 != area.value {
+                text.0 = area.value.clone();
+            }
+            line_height = line_height_px(font);
+        }
+
+        if let Ok(mut placeholder) = nodes.get_mut(parts.placeholder) {
+            placeholder.display = if area.value.is_empty() {
+                Display::Flex
+            } else {
+                Display::None
+            };
+        }
+
+        let stops = caret_stops(&area.value, &layout.glyphs);
+        let caret = stops[area.cursor.min(stops.len() - 1)];
+        let caret_top = caret.line as f32 * line_height;
+
+        if let Ok(mut caret_node) = nodes.get_mut(parts.caret) {
+            caret_node.left = Val::Px(caret.x);
+            caret_node.top = Val::Px(caret_top);
+        }
+
+        let line_count = stops.last().map_or(1, |stop| stop.line + 1);
+        let height = Val::Px(area.height_for_rows(area.visible_rows(line_count)));
+        if node.height != height {
+            node.height = height;
+        }
+
+        if !area.focused {
+            continue;
+        }
+        for (viewport, computed, mut scroll) in &mut viewports {
+            if viewport.area != parts.scroll_area {
+                continue;
+            }
+            let visible = computed.size().y * computed.inverse_scale_factor();
+            if caret_top < scroll.offset_y {
+                scroll.offset_y = caret_top;
+            } else if caret_top + line_height > scroll.offset_y + visible {
+                scroll.offset_y = caret_top + line_height - visible;
+            }
+        }
+    }
+}
+
+/// Blinks the caret of the focused text area.
+pub fn blink_text_area_caret(
+    time: Res<Time>,
+    areas: Query<(&TextAreaComponent, &TextAreaParts)>,
+    mut carets: Query<&mut Visibility, With<TextAreaCaret>>,
+) {
+    let blink_on = ((time.elapsed_secs() * CARET_BLINK_RATE) as u32).is_multiple_of(2);
+
+    for (area, parts) in &areas {
+        if let Ok(mut visibility) = carets.get_mut(parts.caret) {
+            let target = if area.focused && blink_on {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+            if *visibility != target {
+                *visibility = target;
+            }
+        }
+    }
+}
+
+pub type TextArea = TextAreaComponent;
+
+impl Namespace for TextAreaBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(value: &str) -> TextAreaComponent {
+        TextAreaComponent {
+            value: value.to_string(),
+            cursor: value.chars().count(),
+            ..default()
+        }
+    }
+
+    /// Monospaced glyphs, 10px wide, wrapped after `wrap` characters per line.
+    fn glyphs(value: &str, wrap: usize) -> Vec<TextAreaGlyph> {
+        let mut result = Vec::new();
+        let (mut line, mut column) = (0, 0);
+        for (byte_index, c) in value.char_indices() {
+            if c == '\n' {
+                line += 1;
+                column = 0;
+                continue;
+            }
+            if column == wrap {
+                line += 1;
+                column = 0;
+            }
+            let left = column as f32 * 10.0;
+            result.push(TextAreaGlyph {
+                byte_index,
+                line,
+                left,
+                right: left + 10.0,
+            });
+            column += 1;
+        }
+        result
+    }
+
+    #[test]
+    fn test_caret_stops_follow_wraps_and_empty_lines() {
+        let value = "abcde\n\nxy";
+        let stops = caret_stops(value, &glyphs(value, 3));
+
+        assert_eq!(stops.len(), value.chars().count() + 1);
+        assert_eq!(stops[3], CaretStop { line: 1, x: 0.0 });
+        // Before the first newline, after "de"
+        assert_eq!(stops[5], CaretStop { line: 1, x: 20.0 });
+        // The empty line
+        assert_eq!(stops[6], CaretStop { line: 2, x: 0.0 });
+        assert_eq!(stops[9], CaretStop { line: 3, x: 20.0 });
+    }
+
+    #[test]
+    fn test_vertical_moves_keep_column() {
+        let value = "abcd\nx\nabcd";
+        let stops = caret_stops(value, &glyphs(value, 10));
+        let mut a = area(value);
+        a.cursor = 3;
+
+        a.move_vertical(&stops, 1);
+        assert_eq!(a.cursor, 6, "clamped to the end of the short line");
+        a.move_vertical(&stops, 1);
+        assert_eq!(a.cursor, 10, "column restored on the long line");
+        a.move_vertical(&stops, 1);
+        assert_eq!(a.cursor, a.char_len());
+    }
+
+    #[test]
+    fn test_enter_inserts_newline_but_drops_other_controls() {
+        let mut a = area("ab");
+        a.max_length = Some(4);
+        assert!(a.insert_text("\n\tc"));
+        assert_eq!(a.value, "ab\nc");
+        assert!(!a.insert_text("d"));
+    }
+
+    #[test]
+    fn test_auto_grow_rows_and_deterministic_height() {
+        let mut a = area("");
+        a.rows = 2;
+        assert_eq!(a.visible_rows(1), 2);
+        assert_eq!(a.visible_rows(5), 2);
+
+        a.max_rows = Some(4);
+        assert_eq!(a.visible_rows(3), 3);
+        assert_eq!(a.visible_rows(9), 4);
+        let row = a.height_for_rows(3) - a.height_for_rows(2);
+        assert!((row - a.line_height()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_text_is_laid_out_at_the_row_height() {
+        let mut world = World::new();
+        let area = world.spawn(TextAreaBuilder::new("notes").build()).id();
+
+        let text = world.get::<TextAreaParts>(area).unwrap().text;
+        let font = world.get::<TextFont>(text).unwrap();
+        let row = world.get::<TextAreaComponent>(area).unwrap().line_height();
+        assert!(matches!(font.line_height, LineHeight::Px(px) if px == row));
+        assert!((line_height_px(font) - row).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,683 @@
+use crate::{
+    components::{
+        focus::{FocusChangeEvent, Focusable},
+        text::Text as UiText,
+    },
+    theme::{
+        color::{accent_palette, theme, TextColor as TextColorEnum, UiColorPalette},
+        layout::UiLayout,
+        radix_theme::ThemeTokens,
+        typography::TextSize,
+    },
+    utilities::naming::Namespace,
+};
+use bevy::{
+    ecs::spawn::SpawnWith,
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+};
+use bevy_picking::prelude::Pickable;
+
+/// Event emitted whenever the text of a field changes.
+#[derive(Event, Debug, Clone)]
+pub struct TextFieldChangeEvent {
+    pub text_field_entity: Entity,
+    pub value: String,
+}
+
+/// Event emitted when Enter is pressed in a focused field.
+#[derive(Event, Debug, Clone)]
+pub struct TextFieldSubmitEvent {
+    pub text_field_entity: Entity,
+    pub value: String,
+}
+
+/// Single-line text entry.
+///
+/// Cursor and selection positions are character indices into `value`.
+#[derive(Component, Debug, Clone)]
+pub struct TextFieldComponent {
+    pub value: String,
+    pub placeholder: String,
+    /// Caret position
+    pub cursor: usize,
+    /// Other end of the selection; the selection spans anchor..cursor
+    pub selection_anchor: Option<usize>,
+    pub focused: bool,
+    pub disabled: bool,
+    /// Render every character as a bullet
+    pub password: bool,
+    /// Maximum number of characters
+    pub max_length: Option<usize>,
+    pub size: TextFieldSize,
+    pub color: UiColorPalette,
+}
+
+impl Default for TextFieldComponent {
+    fn default() -> Self {
+        Self {
+            value: String::new(),
+            placeholder: String::new(),
+            cursor: 0,
+            selection_anchor: None,
+            focused: false,
+            disabled: false,
+            password: false,
+            max_length: None,
+            size: TextFieldSize::Size2,
+            color: accent_palette(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextFieldSize {
+    Size1,
+    #[default]
+    Size2,
+    Size3,
+}
+
+impl TextFieldSize {
+    pub fn text_size(self) -> TextSize {
+        match self {
+            TextFieldSize::Size1 => TextSize::Sm,
+            TextFieldSize::Size2 => TextSize::Base,
+            TextFieldSize::Size3 => TextSize::Lg,
+        }
+    }
+
+    pub fn height(self) -> f32 {
+        match self {
+            TextFieldSize::Size1 => 24.0,
+            TextFieldSize::Size2 => 32.0,
+            TextFieldSize::Size3 => 40.0,
+        }
+    }
+}
+
+const PASSWORD_BULLET: char = '•';
+const CARET_WIDTH: f32 = 1.5;
+const CARET_BLINK_RATE: f32 = 2.0;
+
+impl TextFieldComponent {
+    pub fn new(name: impl Into<String>) -> TextFieldBuilder {
+        TextFieldBuilder::new(name)
+    }
+
+    pub fn char_len(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(char_index)
+            .map(|(index, _)| index)
+            .unwrap_or(self.value.len())
+    }
+
+    /// Selected character range, `None` when nothing is selected.
+    pub fn selection_range(&self) -> Option<(usize, usize)> {
+        let anchor = self.selection_anchor?;
+        (anchor != self.cursor).then(|| (anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    pub fn selected_text(&self) -> Option<&str> {
+        let (start, end) = self.selection_range()?;
+        Some(&self.value[self.byte_index(start)..self.byte_index(end)])
+    }
+
+    /// Text as displayed, with characters masked in password mode.
+    pub fn display_value(&self) -> String {
+        if self.password {
+            PASSWORD_BULLET.to_string().repeat(self.char_len())
+        } else {
+            self.value.clone()
+        }
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        let Some((start, end)) = self.selection_range() else {
+            self.selection_anchor = None;
+            return false;
+        };
+        let range = self.byte_index(start)..self.byte_index(end);
+        self.value.replace_range(range, "");
+        self.cursor = start;
+        self.selection_anchor = None;
+        true
+    }
+
+    /// Inserts `text` at the caret, replacing the selection.
+    ///
+    /// Control characters are dropped and the text is truncated to
+    /// `max_length`. Returns whether the value changed.
+    pub fn insert_text(&mut self, text: &str) -> bool {
+        let mut changed = self.delete_selection();
+
+        let available = self
+            .max_length
+            .map_or(usize::MAX, |max| max.saturating_sub(self.char_len()));
+        let inserted: String = text
+            .chars()
+            .filter(|c| !c.is_control())
+            .take(available)
+            .collect();
+
+        if !inserted.is_empty() {
+            let index = self.byte_index(self.cursor);
+            self.value.insert_str(index, &inserted);
+            self.cursor += inserted.chars().count();
+            changed = true;
+        }
+        changed
+    }
+
+    /// Deletes the selection or the character before the caret.
+    pub fn backspace(&mut self) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+        if self.cursor == 0 {
+            return false;
+        }
+        self.cursor -= 1;
+        let index = self.byte_index(self.cursor);
+        self.value.remove(index);
+        true
+    }
+
+    /// Deletes the selection or the character after the caret.
+    pub fn delete(&mut self) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+        if self.cursor >= self.char_len() {
+            return false;
+        }
+        let index = self.byte_index(self.cursor);
+        self.value.remove(index);
+        true
+    }
+
+    /// Starts or drops the selection before the caret moves.
+    fn prepare_move(&mut self, extend_selection: bool) {
+        if extend_selection {
+            self.selection_anchor.get_or_insert(self.cursor);
+        } else {
+            self.selection_anchor = None;
+        }
+    }
+
+    pub fn move_left(&mut self, extend_selection: bool) {
+        // Without shift a selection collapses to its start
+        if let (false, Some((start, _))) = (extend_selection, self.selection_range()) {
+            self.cursor = start;
+            self.selection_anchor = None;
+            return;
+        }
+        self.prepare_move(extend_selection);
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self, extend_selection: bool) {
+        if let (false, Some((_, end))) = (extend_selection, self.selection_range()) {
+            self.cursor = end;
+            self.selection_anchor = None;
+            return;
+        }
+        self.prepare_move(extend_selection);
+        self.cursor = (self.cursor + 1).min(self.char_len());
+    }
+
+    pub fn move_home(&mut self, extend_selection: bool) {
+        self.prepare_move(extend_selection);
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self, extend_selection: bool) {
+        self.prepare_move(extend_selection);
+        self.cursor = self.char_len();
+    }
+
+    pub fn select_all(&mut self) {
+        self.selection_anchor = Some(0);
+        self.cursor = self.char_len();
+    }
+}
+
+/// Part of the rendered value. Segments are laid out in declaration order.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextFieldSegment {
+    /// Unselected text before the selection/caret
+    Before,
+    /// Selected text left of the caret
+    SelectedBefore,
+    /// Selected text right of the caret
+    SelectedAfter,
+    /// Unselected text after the selection/caret
+    After,
+}
+
+/// Marker for the blinking caret.
+#[derive(Component, Debug)]
+pub struct TextFieldCaret;
+
+/// Marker for the placeholder text.
+#[derive(Component, Debug)]
+pub struct TextFieldPlaceholder;
+
+pub struct TextFieldBuilder {
+    name: String,
+    text_field: TextFieldComponent,
+    width: Val,
+}
+
+impl TextFieldBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: format!("{}_TextField", name.into()),
+            text_field: TextFieldComponent::default(),
+            width: Val::Px(240.0),
+        }
+    }
+
+    /// Initial text, truncated to `max_length` when built.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.text_field.value = value.into();
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.text_field.placeholder = placeholder.into();
+        self
+    }
+
+    /// Masks every character, e.g. for passwords.
+    pub fn password(mut self) -> Self {
+        self.text_field.password = true;
+        self
+    }
+
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.text_field.max_length = Some(max_length);
+        self
+    }
+
+    pub fn size(mut self, size: TextFieldSize) -> Self {
+        self.text_field.size = size;
+        self
+    }
+
+    pub fn color(mut self, color: UiColorPalette) -> Self {
+        self.text_field.color = color;
+        self
+    }
+
+    pub fn width(mut self, width: Val) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.text_field.disabled = true;
+        self
+    }
+}
+
+impl TextFieldBuilder {
+    fn calculate_style(&self) -> Node {
+        let layout = UiLayout::default();
+        Node {
+            width: self.width,
+            height: Val::Px(self.text_field.size.height()),
+            padding: UiRect::horizontal(Val::Px(layout.padding.sm)),
+            border: UiRect::all(Val::Px(layout.border.xs)),
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            overflow: Overflow::clip(),
+            ..default()
+        }
+    }
+
+    pub fn build(mut self) -> impl Bundle {
+        if let Some(max_length) = self.text_field.max_length {
+            self.text_field.value = self.text_field.value.chars().take(max_length).collect();
+        }
+        self.text_field.cursor = self.text_field.char_len();
+
+        let node = self.calculate_style();
+        let field = self.text_field.clone();
+        let palette = field.color.clone();
+        let background = if field.disabled {
+            palette.bg_subtle.with_alpha(0.5)
+        } else {
+            palette.bg_subtle
+        };
+
+        (
+            Name::new(self.name),
+            self.text_field,
+            node,
+            BackgroundColor(background),
+            BorderColor(palette.border),
+            BorderRadius::all(Val::Px(UiLayout::default().radius.sm)),
+            Pickable::default(),
+            Interaction::default(),
+            Focusable::new().disabled(field.disabled),
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                let text_size = field.size.text_size();
+                let text_color = TextColorEnum::Custom(palette.text_contrast);
+                let spawn_segment = |parent: &mut ChildSpawner, segment: TextFieldSegment| {
+                    let content = match segment {
+                        TextFieldSegment::Before => field.display_value(),
+                        _ => String::new(),
+                    };
+                    parent.spawn((
+                        UiText::body(content)
+                            .size(text_size)
+                            .color(text_color)
+                            .build(),
+                        segment,
+                        // Highlights the segment while it is selected
+                        BackgroundColor(Color::NONE),
+                        Pickable::IGNORE,
+                    ));
+                };
+
+                spawn_segment(parent, TextFieldSegment::Before);
+                spawn_segment(parent, TextFieldSegment::SelectedBefore);
+                parent.spawn((
+                    Name::new("TextFieldCaret"),
+                    TextFieldCaret,
+                    Node {
+                        width: Val::Px(CARET_WIDTH),
+                        height: Val::Percent(60.0),
+                        flex_shrink: 0.0,
+                        ..default()
+                    },
+                    BackgroundColor(palette.solid),
+                    Visibility::Hidden,
+                    Pickable::IGNORE,
+                ));
+                spawn_segment(parent, TextFieldSegment::SelectedAfter);
+                spawn_segment(parent, TextFieldSegment::After);
+
+                parent.spawn((
+                    UiText::body(field.placeholder.clone())
+                        .size(text_size)
+                        .color(TextColorEnum::Custom(theme().gray.text))
+                        .build(),
+                    TextFieldPlaceholder,
+                    Pickable::IGNORE,
+                ));
+            })),
+        )
+    }
+}
+
+/// Focuses a field when it is pressed and blurs all others on any mouse press.
+pub fn handle_text_field_focus(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut fields: Query<(&Interaction, &mut TextFieldComponent)>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    for (interaction, mut field) in &mut fields {
+        let focused = *interaction == Interaction::Pressed && !field.disabled;
+        if focused && !field.focused {
+            field.focused = true;
+            field.selection_anchor = None;
+            field.cursor = field.char_len();
+        } else if !focused && field.focused {
+            field.focused = false;
+            field.selection_anchor = None;
+        }
+    }
+}
+
+/// Starts or stops editing when keyboard navigation moves the focus.
+pub fn follow_text_field_keyboard_focus(
+    mut focus_events: EventReader<FocusChangeEvent>,
+    mut fields: Query<&mut TextFieldComponent>,
+) {
+    for event in focus_events.read() {
+        if let Some(mut field) = event
+            .previous
+            .and_then(|entity| fields.get_mut(entity).ok())
+        {
+            field.focused = false;
+            field.selection_anchor = None;
+        }
+        if let Some(mut field) = event.focused.and_then(|entity| fields.get_mut(entity).ok()) {
+            if !field.disabled {
+                field.focused = true;
+                field.selection_anchor = None;
+                field.cursor = field.char_len();
+            }
+        }
+    }
+}
+
+/// Applies keyboard input to the focused field.
+pub fn handle_text_field_keyboard(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut fields: Query<(Entity, &mut TextFieldComponent)>,
+    mut change_events: EventWriter<TextFieldChangeEvent>,
+    mut submit_events: EventWriter<TextFieldSubmitEvent>,
+) {
+    let Some((entity, mut field)) = fields.iter_mut().find(|(_, field)| field.focused) else {
+        keyboard_events.clear();
+        return;
+    };
+
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let command = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+
+    for event in keyboard_events.read() {
+        if !event.state.is_pressed() || !field.focused {
+            continue;
+        }
+
+        let changed = match &event.logical_key {
+            Key::Character(text) if command => {
+                if text.eq_ignore_ascii_case("a") {
+                    field.select_all();
+                }
+                false
+            }
+            Key::Character(text) => field.insert_text(text),
+            Key::Space => field.insert_text(" "),
+            Key::Backspace => field.backspace(),
+            Key::Delete => field.delete(),
+            Key::ArrowLeft => {
+                field.move_left(shift);
+                false
+            }
+            Key::ArrowRight => {
+                field.move_right(shift);
+                false
+            }
+            Key::Home => {
+                field.move_home(shift);
+                false
+            }
+            Key::End => {
+                field.move_end(shift);
+                false
+            }
+            Key::Enter => {
+                submit_events.write(TextFieldSubmitEvent {
+                    text_field_entity: entity,
+                    value: field.value.clone(),
+                });
+                false
+            }
+            Key::Escape => {
+                field.focused = false;
+                field.selection_anchor = None;
+                false
+            }
+            _ => false,
+        };
+
+        if changed {
+            change_events.write(TextFieldChangeEvent {
+                text_field_entity: entity,
+                value: field.value.clone(),
+            });
+        }
+    }
+}
+
+/// Mirrors value, selection and focus state into the field's children.
+pub fn update_text_field_display(
+    mut fields: Query<
+        (&TextFieldComponent, &Children, &mut BorderColor),
+        Changed<TextFieldComponent>,
+    >,
+    mut segments: Query<(&TextFieldSegment, &mut Text, &mut BackgroundColor)>,
+    mut placeholders: Query<&mut Node, With<TextFieldPlaceholder>>,
+    tokens: Res<ThemeTokens>,
+) {
+    for (field, children, mut border) in &mut fields {
+        border.0 = if field.focused {
+            field.color.solid
+        } else {
+            field.color.border
+        };
+
+        let display: Vec<char> = field.display_value().chars().collect();
+        let (start, end) = field
+            .selection_range()
+            .unwrap_or((field.cursor, field.cursor));
+        let slice = |from: usize, to: usize| display[from..to].iter().collect::<String>();
+
+        for child in children.iter() {
+            if let Ok((segment, mut text, mut background)) = segments.get_mut(child) {
+                let (content, selected) = match segment {
+                    TextFieldSegment::Before => (slice(0, start), false),
+                    TextFieldSegment::SelectedBefore => (slice(start, field.cursor), true),
+                    TextFieldSegment::SelectedAfter => (slice(field.cursor, end), true),
+                    TextFieldSegment::After => (slice(end, display.len()), false),
+                };
+                background.0 = if selected && !content.is_empty() {
+                    tokens.system.selection
+                } else {
+                    Color::NONE
+                };
+                if text.0 != content {
+                    text.0 = content;
+                }
+            }
+
+            if let Ok(mut node) = placeholders.get_mut(child) {
+                node.display = if display.is_empty() {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+            }
+        }
+    }
+}
+
+/// Blinks the caret of the focused field.
+pub fn blink_text_field_caret(
+    time: Res<Time>,
+    fields: Query<(&TextFieldComponent, &Children)>,
+    mut carets: Query<&mut Visibility, With<TextFieldCaret>>,
+) {
+    let blink_on = ((time.elapsed_secs() * CARET_BLINK_RATE) as u32).is_multiple_of(2);
+
+    for (field, children) in &fields {
+        let visible = field.focused && field.selection_range().is_none() && blink_on;
+        for child in children.iter() {
+            if let Ok(mut visibility) = carets.get_mut(child) {
+                let target = if visible {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                };
+                if *visibility != target {
+                    *visibility = target;
+                }
+            }
+        }
+    }
+}
+
+pub type TextField = TextFieldComponent;
+
+impl Namespace for TextFieldBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(value: &str) -> TextFieldComponent {
+        TextFieldComponent {
+            value: value.to_string(),
+            cursor: value.chars().count(),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_backspace_and_delete_at_boundaries() {
+        let mut f = field("ab");
+        assert!(!f.delete());
+        f.move_home(false);
+        assert!(!f.backspace());
+        assert!(f.delete());
+        assert_eq!(f.value, "b");
+        assert_eq!(f.cursor, 0);
+    }
+
+    #[test]
+    fn test_shift_selection_replaced_by_insert() {
+        let mut f = field("hello");
+        f.move_left(true);
+        f.move_left(true);
+        assert_eq!(f.selected_text(), Some("lo"));
+
+        f.insert_text("p!");
+        assert_eq!(f.value, "help!");
+        assert_eq!(f.selection_range(), None);
+    }
+
+    #[test]
+    fn test_max_length_and_multibyte_chars() {
+        let mut f = field("äö");
+        f.max_length = Some(3);
+        f.insert_text("üx");
+        assert_eq!(f.value, "äöü");
+
+        f.move_home(false);
+        f.move_right(false);
+        assert!(f.backspace());
+        assert_eq!(f.value, "öü");
+    }
+
+    #[test]
+    fn test_arrow_without_shift_collapses_selection() {
+        let mut f = field("abc");
+        f.select_all();
+        f.move_left(false);
+        assert_eq!(f.cursor, 0);
+        assert_eq!(f.selection_range(), None);
+    }
+}
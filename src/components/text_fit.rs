@@ -0,0 +1,156 @@
+//! Font sizes that fit a single-line text into its container.
+//!
+//! Texts built with [`TextBuilder::fit_text`] don't wrap. Each frame
+//! [`fit_texts_to_containers`] picks the largest [`TextSize`] between the
+//! given bounds at which the text fits its parent's content box, so the text
+//! grows and shrinks with its container, e.g. on window resizes. When even
+//! the smallest bound overflows, the text keeps that size.
+//!
+//! The width is measured from the text's current layout, which scales with
+//! the font size, so a text settles on its size within a frame or two.
+//! Fitting takes precedence over [`TextBuilder::size_responsive`].
+//!
+//! ```rust
+//! parent
+//!     .spawn(Node { width: Val::Percent(100.0), ..default() })
+//!     .with_children(|header| {
+//!         header.spawn(
+//!             Text::display("Quarterly results")
+//!                 .fit_text(TextSize::Xl, TextSize::X6l)
+//!                 .build(),
+//!         );
+//!     });
+//! ```
+//!
+//! [`TextBuilder::fit_text`]: crate::components::text::TextBuilder::fit_text
+//! [`TextBuilder::size_responsive`]: crate::components::text::TextBuilder::size_responsive
+
+use bevy::{prelude::*, text::TextLayoutInfo};
+
+use crate::theme::typography::{get_font_size_pixels, FontSize, TextSize};
+
+/// Overflow in logical pixels ignored to absorb rounding
+const FIT_TOLERANCE: f32 = 0.5;
+
+/// Size bounds of a text fitted to its container.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextFit {
+    /// Whether the font size follows the container
+    pub enabled: bool,
+    /// Smallest size, kept even when the text overflows at it
+    pub min: TextSize,
+    /// Largest size
+    pub max: TextSize,
+}
+
+impl Default for TextFit {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min: TextSize::Xs,
+            max: TextSize::X9l,
+        }
+    }
+}
+
+/// Largest size from `min` to `max` at which a text `width_per_px` wide per
+/// pixel of font size fits into `container_width`, or `min` if none does.
+pub fn fit_text_size(
+    width_per_px: f32,
+    container_width: f32,
+    min: TextSize,
+    max: TextSize,
+    font_size: &FontSize,
+) -> TextSize {
+    let index = |size| TextSize::ALL.iter().position(|s| *s == size).unwrap();
+    let (low, high) = (index(min), index(max));
+    if low >= high {
+        return min;
+    }
+    TextSize::ALL[low..=high]
+        .iter()
+        .rev()
+        .copied()
+        .find(|size| {
+            width_per_px * get_font_size_pixels(font_size, *size) <= container_width + FIT_TOLERANCE
+        })
+        .unwrap_or(min)
+}
+
+/// System that sets the font size of fitted texts for their container width.
+pub fn fit_texts_to_containers(
+    mut texts: Query<(
+        &TextFit,
+        &TextLayoutInfo,
+        &ComputedNode,
+        &ChildOf,
+        &mut TextFont,
+    )>,
+    containers: Query<&ComputedNode>,
+) {
+    let font_sizes = FontSize::default();
+    for (fit, info, computed, child_of, mut text_font) in &mut texts {
+        if !fit.enabled || text_font.font_size <= 0.0 {
+            continue;
+        }
+        let Ok(container) = containers.get(child_of.parent()) else {
+            continue;
+        };
+
+        // Layout sizes are physical pixels
+        let scale = computed.inverse_scale_factor();
+        let text_width = info.size.x * scale;
+        let (border, padding) = (container.border(), container.padding());
+        let container_width =
+            (container.size().x - border.left - border.right - padding.left - padding.right)
+                * scale;
+        // Not laid out yet
+        if text_width <= 0.0 || container_width <= 0.0 {
+            continue;
+        }
+
+        let size = fit_text_size(
+            text_width / text_font.font_size,
+            container_width,
+            fit.min,
+            fit.max,
+            &font_sizes,
+        );
+        let font_size = get_font_size_pixels(&font_sizes, size);
+        if text_font.font_size != font_size {
+            text_font.font_size = font_size;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_picks_largest_fitting_size() {
+        let sizes = FontSize::default();
+        // A text 5px wide per pixel of font size, e.g. a short heading
+        let width_per_px = 5.0;
+        let fits = |container_width| {
+            fit_text_size(
+                width_per_px,
+                container_width,
+                TextSize::Sm,
+                TextSize::X4l,
+                &sizes,
+            )
+        };
+
+        // Exactly as wide as at X2l, and a bit less than at X3l
+        let x2l = width_per_px * get_font_size_pixels(&sizes, TextSize::X2l);
+        let x3l = width_per_px * get_font_size_pixels(&sizes, TextSize::X3l);
+        assert_eq!(fits(x2l), TextSize::X2l);
+        assert_eq!(fits(x3l - 1.0), TextSize::X2l);
+
+        // Wide containers stop at the upper bound
+        assert_eq!(fits(10_000.0), TextSize::X4l);
+        // Narrow containers keep the lower bound, even though it overflows
+        assert_eq!(fits(10.0), TextSize::Sm);
+    }
+}
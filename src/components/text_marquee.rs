@@ -0,0 +1,254 @@
+//! Horizontal auto-scrolling of single-line text that overflows its container.
+//!
+//! Texts built with [`TextBuilder::marquee`] don't wrap. While the laid out
+//! text is wider than its parent's content box, [`animate_text_marquees`]
+//! scrolls it to its end and back, resting for `pause` at each end, and holds
+//! it while hovered so it can be read. Once the text fits it snaps back to
+//! its start.
+//!
+//! The parent is the visible window: its horizontal overflow is clipped, and it
+//! should only hold the text.
+//!
+//! ```rust
+//! parent
+//!     .spawn(Node { width: Val::Px(120.0), ..default() })
+//!     .with_children(|cell| {
+//!         cell.spawn(Text::label("A rather long track title").marquee().build());
+//!     });
+//! ```
+//!
+//! [`TextBuilder::marquee`]: crate::components::text::TextBuilder::marquee
+
+use std::time::Duration;
+
+use bevy::{prelude::*, text::TextLayoutInfo};
+use bevy_picking::prelude::{Out, Over, Pointer};
+
+use crate::theme::motion::UiMotion;
+
+/// Default scroll speed in logical pixels per second
+pub const MARQUEE_SPEED: f32 = 30.0;
+/// Default rest at each end
+pub const MARQUEE_PAUSE: Duration = Duration::from_millis(1500);
+/// Overflow in logical pixels ignored to absorb rounding
+const MARQUEE_FIT_TOLERANCE: f32 = 0.5;
+
+/// Marquee settings and scroll state of a text.
+#[derive(Component, Debug, Clone)]
+pub struct TextMarquee {
+    /// Whether the text scrolls when it overflows
+    pub enabled: bool,
+    /// Scroll speed in logical pixels per second
+    pub speed: f32,
+    /// Rest at each end before scrolling on
+    pub pause: Duration,
+    /// Whether the pointer is over the text, holding the scroll
+    pub hovered: bool,
+    /// Current scroll distance in logical pixels
+    offset: f32,
+    /// Whether the text scrolls back to its start
+    returning: bool,
+    pause_timer: Timer,
+}
+
+impl Default for TextMarquee {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            speed: MARQUEE_SPEED,
+            pause: MARQUEE_PAUSE,
+            hovered: false,
+            offset: 0.0,
+            returning: false,
+            pause_timer: Timer::new(MARQUEE_PAUSE, TimerMode::Once),
+        }
+    }
+}
+
+impl TextMarquee {
+    /// Current scroll distance in logical pixels.
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Scrolls by `delta` towards the current end, resting at each end.
+    pub fn advance(&mut self, delta: Duration, overflow: f32) {
+        self.offset = self.offset.min(overflow);
+        if !self.pause_timer.finished() {
+            self.pause_timer.tick(delta);
+            return;
+        }
+
+        let step = self.speed * delta.as_secs_f32();
+        let (offset, at_end) = if self.returning {
+            let offset = (self.offset - step).max(0.0);
+            (offset, offset <= 0.0)
+        } else {
+            let offset = (self.offset + step).min(overflow);
+            (offset, offset >= overflow)
+        };
+        self.offset = offset;
+        if at_end {
+            self.returning = !self.returning;
+            self.pause_timer = Timer::new(self.pause, TimerMode::Once);
+        }
+    }
+
+    /// Returns to the start, resting there before scrolling again.
+    pub fn reset(&mut self) {
+        self.offset = 0.0;
+        self.returning = false;
+        self.pause_timer = Timer::new(self.pause, TimerMode::Once);
+    }
+
+    /// Whether the marquee is at rest at its start.
+    fn is_reset(&self) -> bool {
+        self.offset == 0.0 && !self.returning && self.pause_timer.elapsed().is_zero()
+    }
+}
+
+/// How far a text of `text_width` sticks out of `container_width`, `None`
+/// when it fits.
+pub fn marquee_overflow(text_width: f32, container_width: f32) -> Option<f32> {
+    let overflow = text_width - container_width;
+    (overflow > MARQUEE_FIT_TOLERANCE).then_some(overflow)
+}
+
+/// System that clips the containers of new marquee texts and pauses them on
+/// hover.
+pub fn setup_text_marquees(
+    mut commands: Commands,
+    marquees: Query<(Entity, &TextMarquee, Option<&ChildOf>), Added<TextMarquee>>,
+    mut containers: Query<&mut Node>,
+) {
+    for (entity, marquee, child_of) in &marquees {
+        if !marquee.enabled {
+            continue;
+        }
+        if let Some(mut container) =
+            child_of.and_then(|child_of| containers.get_mut(child_of.parent()).ok())
+        {
+            container.overflow.x = OverflowAxis::Clip;
+        }
+        commands
+            .entity(entity)
+            .observe(on_marquee_hover_start)
+            .observe(on_marquee_hover_end);
+    }
+}
+
+fn on_marquee_hover_start(trigger: Trigger<Pointer<Over>>, mut marquees: Query<&mut TextMarquee>) {
+    if let Ok(mut marquee) = marquees.get_mut(trigger.target()) {
+        marquee.hovered = true;
+    }
+}
+
+fn on_marquee_hover_end(trigger: Trigger<Pointer<Out>>, mut marquees: Query<&mut TextMarquee>) {
+    if let Ok(mut marquee) = marquees.get_mut(trigger.target()) {
+        marquee.hovered = false;
+    }
+}
+
+/// System that scrolls overflowing marquee texts and resets fitting ones.
+pub fn animate_text_marquees(
+    time: Res<Time>,
+    motion: Res<UiMotion>,
+    mut marquees: Query<(
+        &mut TextMarquee,
+        &TextLayoutInfo,
+        &ComputedNode,
+        &ChildOf,
+        &mut Node,
+    )>,
+    containers: Query<&ComputedNode>,
+) {
+    for (mut marquee, info, computed, child_of, mut node) in &mut marquees {
+        if !marquee.enabled {
+            continue;
+        }
+        let Ok(container) = containers.get(child_of.parent()) else {
+            continue;
+        };
+
+        // Layout sizes are physical pixels
+        let scale = computed.inverse_scale_factor();
+        let (border, padding) = (container.border(), container.padding());
+        let container_width =
+            container.size().x - border.left - border.right - padding.left - padding.right;
+        match marquee_overflow(info.size.x * scale, container_width * scale) {
+            // Reduced motion keeps the start of the text in view
+            Some(_) if motion.reduced && !marquee.is_reset() => marquee.reset(),
+            Some(overflow) if !marquee.hovered && !motion.reduced => {
+                marquee.advance(time.delta(), overflow)
+            }
+            Some(_) => {}
+            None if !marquee.is_reset() => marquee.reset(),
+            None => {}
+        }
+
+        let left = Val::Px(-marquee.offset);
+        if node.left != left {
+            node.left = left;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marquee() -> TextMarquee {
+        let mut marquee = TextMarquee {
+            enabled: true,
+            speed: 10.0,
+            pause: Duration::from_secs(1),
+            ..default()
+        };
+        marquee.reset();
+        marquee
+    }
+
+    #[test]
+    fn test_overflow_only_when_text_is_wider() {
+        assert_eq!(marquee_overflow(150.0, 100.0), Some(50.0));
+        assert_eq!(marquee_overflow(80.0, 100.0), None);
+        assert_eq!(marquee_overflow(100.0, 100.0), None);
+        // Sub-pixel differences from rounding count as fitting
+        assert_eq!(marquee_overflow(100.3, 100.0), None);
+    }
+
+    #[test]
+    fn test_scrolls_to_end_and_back_with_pauses() {
+        let mut marquee = marquee();
+        let second = Duration::from_secs(1);
+
+        // Rest at the start, then 10px per second up to the 20px overflow
+        marquee.advance(second, 20.0);
+        assert_eq!(marquee.offset(), 0.0);
+        marquee.advance(second, 20.0);
+        assert_eq!(marquee.offset(), 10.0);
+        marquee.advance(second, 20.0);
+        assert_eq!(marquee.offset(), 20.0);
+
+        // Rest at the end, then scroll back
+        marquee.advance(second, 20.0);
+        assert_eq!(marquee.offset(), 20.0);
+        marquee.advance(second, 20.0);
+        assert_eq!(marquee.offset(), 10.0);
+        marquee.advance(second, 20.0);
+        assert_eq!(marquee.offset(), 0.0);
+        marquee.advance(second, 20.0);
+        assert_eq!(marquee.offset(), 0.0);
+    }
+
+    #[test]
+    fn test_shrinking_overflow_clamps_offset() {
+        let mut marquee = marquee();
+        marquee.advance(Duration::from_secs(1), 50.0);
+        marquee.advance(Duration::from_secs(3), 50.0);
+        assert_eq!(marquee.offset(), 30.0);
+
+        marquee.advance(Duration::ZERO, 12.0);
+        assert_eq!(marquee.offset(), 12.0);
+    }
+}
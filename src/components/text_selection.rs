@@ -0,0 +1,548 @@
+//! Pointer selection and copying of rendered text.
+//!
+//! Texts built with [`TextBuilder::selectable`] are selected by dragging
+//! across them; Ctrl+C (Cmd+C on macOS) copies the selection to the clipboard
+//! and emits a [`CopyEvent`]. Every finished drag emits a [`TextSelectEvent`].
+//! Pressing anywhere drops the selection.
+//!
+//! Hit-testing uses the glyph positions of Bevy's text layout and only looks
+//! at their x coordinate, so selection is limited to single-line texts. For
+//! multiline texts the pointer's line would be picked first from the glyphs'
+//! `line` (and their y extent), with one highlight per covered line; the
+//! range math below already works on byte ranges of the whole text.
+//!
+//! The highlight is a translucent overlay in the theme's
+//! [`SystemColors::selection`], drawn above the text. It is an absolutely
+//! positioned sibling of the text rather than a child, because a child node
+//! would turn the text node from a measured leaf into a flex container; as a
+//! sibling it still scrolls, clips and stacks with the text.
+//!
+//! ```rust
+//! commands.spawn(Text::code("sk-live-0123456789").selectable().build());
+//! ```
+//!
+//! [`TextBuilder::selectable`]: crate::components::text::TextBuilder::selectable
+//! [`SystemColors::selection`]: crate::theme::radix_theme::SystemColors::selection
+
+use std::ops::Range;
+
+use bevy::{prelude::*, text::TextLayoutInfo};
+use bevy_picking::prelude::{Drag, DragEnd, Pickable, Pointer, PointerButton, Pressed};
+
+use crate::{
+    components::copy_button::{CopyEvent, SystemClipboard},
+    theme::radix_theme::ThemeTokens,
+};
+
+/// Pointer selection state of a text.
+#[derive(Component, Debug, Clone, Default)]
+pub struct SelectableText {
+    /// Whether the text reacts to the pointer at all
+    pub enabled: bool,
+    /// Selected byte range of the text
+    pub selection: Option<Range<usize>>,
+    /// Byte offset where the current drag started
+    anchor: Option<usize>,
+    /// Overlay drawing the selection
+    highlight: Option<Entity>,
+}
+
+impl SelectableText {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            ..default()
+        }
+    }
+
+    /// Drops the selection and any drag in progress.
+    pub fn clear(&mut self) {
+        self.selection = None;
+        self.anchor = None;
+    }
+}
+
+/// Event emitted when a drag across a selectable text ends with a selection.
+#[derive(Event, Debug, Clone)]
+pub struct TextSelectEvent {
+    pub text_entity: Entity,
+    /// Selected byte range of the text
+    pub range: Range<usize>,
+}
+
+/// Overlay highlighting the selection of `text`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TextSelectionHighlight {
+    pub text: Entity,
+}
+
+/// A laid out glyph in logical pixels, relative to the left edge of the text's
+/// content box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelectionGlyph {
+    /// Byte range of the character the glyph draws
+    pub byte_start: usize,
+    pub byte_end: usize,
+    /// Visual (wrapped) line
+    pub line: usize,
+    pub left: f32,
+    pub right: f32,
+}
+
+/// Glyphs of `text` in byte order, from its layout.
+///
+/// Only glyphs of the root text are kept; spans are not selectable.
+pub fn selection_glyphs(
+    text: &str,
+    info: &TextLayoutInfo,
+    inverse_scale_factor: f32,
+) -> Vec<SelectionGlyph> {
+    // Glyph positions are physical pixels and centered on the glyph
+    let mut glyphs: Vec<SelectionGlyph> = info
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.span_index == 0)
+        .map(|glyph| {
+            let char_len = text
+                .get(glyph.byte_index..)
+                .and_then(|rest| rest.chars().next())
+                .map_or(0, char::len_utf8);
+            SelectionGlyph {
+                byte_start: glyph.byte_index,
+                byte_end: glyph.byte_index + char_len,
+                line: glyph.line_index,
+                left: (glyph.position.x - glyph.size.x / 2.0) * inverse_scale_factor,
+                right: (glyph.position.x + glyph.size.x / 2.0) * inverse_scale_factor,
+            }
+        })
+        .collect();
+    glyphs.sort_by_key(|glyph| glyph.byte_start);
+    glyphs
+}
+
+/// Byte offset of the character boundary closest to `x`.
+///
+/// The caret goes before the first glyph whose center lies right of `x`, or
+/// to the end of the text (`text_len`) past the last glyph.
+pub fn caret_at(glyphs: &[SelectionGlyph], text_len: usize, x: f32) -> usize {
+    glyphs
+        .iter()
+        .find(|glyph| x < (glyph.left + glyph.right) / 2.0)
+        .map_or(text_len, |glyph| glyph.byte_start)
+}
+
+/// Range between a drag's anchor and the caret, `None` when empty.
+pub fn selection_between(anchor: usize, caret: usize) -> Option<Range<usize>> {
+    (anchor != caret).then(|| anchor.min(caret)..anchor.max(caret))
+}
+
+/// Horizontal extent (left, right) of the glyphs in `range`.
+pub fn highlight_span(glyphs: &[SelectionGlyph], range: &Range<usize>) -> Option<(f32, f32)> {
+    glyphs
+        .iter()
+        .filter(|glyph| range.contains(&glyph.byte_start))
+        .fold(None::<(f32, f32)>, |span, glyph| match span {
+            None => Some((glyph.left, glyph.right)),
+            Some((left, right)) => Some((left.min(glyph.left), right.max(glyph.right))),
+        })
+}
+
+/// Content box of a text node (inside its padding and border), in logical
+/// window coordinates; glyph positions are relative to its top-left corner.
+pub(crate) fn text_content_rect(transform: &GlobalTransform, computed: &ComputedNode) -> Rect {
+    let (border, padding) = (computed.border(), computed.padding());
+    let center = transform.translation().truncate();
+    let min = center - computed.size() / 2.0
+        + Vec2::new(border.left + padding.left, border.top + padding.top);
+    let max = center + computed.size() / 2.0
+        - Vec2::new(border.right + padding.right, border.bottom + padding.bottom);
+    let scale = computed.inverse_scale_factor();
+    Rect::from_corners(min * scale, max * scale)
+}
+
+/// UI parent to attach an overlay of a text to, and the position of the
+/// text's content box in that parent, in logical pixels.
+///
+/// Overlays are absolutely positioned, so the position is relative to the
+/// parent's padding box. Texts without a UI parent get root overlays placed
+/// in window coordinates.
+pub(crate) fn text_overlay_anchor(
+    transform: &GlobalTransform,
+    computed: &ComputedNode,
+    child_of: Option<&ChildOf>,
+    nodes: &Query<(&GlobalTransform, &ComputedNode)>,
+) -> (Option<Entity>, Vec2) {
+    let content = text_content_rect(transform, computed).min;
+    let Some((parent, (parent_transform, parent_computed))) = child_of
+        .and_then(|child_of| Some(child_of.parent()).zip(nodes.get(child_of.parent()).ok()))
+    else {
+        return (None, content);
+    };
+    let border = parent_computed.border();
+    let padding_box = parent_transform.translation().truncate() - parent_computed.size() / 2.0
+        + Vec2::new(border.left, border.top);
+    (
+        Some(parent),
+        content - padding_box * parent_computed.inverse_scale_factor(),
+    )
+}
+
+/// Moves `overlay` to `parent` (the root for `None`) unless it is there already.
+pub(crate) fn reparent_text_overlay(
+    commands: &mut Commands,
+    overlay: Entity,
+    current: Option<&ChildOf>,
+    parent: Option<Entity>,
+) {
+    match parent {
+        Some(parent) if current.map(ChildOf::parent) != Some(parent) => {
+            commands.entity(overlay).insert(ChildOf(parent));
+        }
+        None if current.is_some() => {
+            commands.entity(overlay).remove::<ChildOf>();
+        }
+        _ => {}
+    }
+}
+
+/// Caret offset under the pointer at `position` (logical window coordinates).
+fn caret_at_pointer(
+    text: &str,
+    info: &TextLayoutInfo,
+    computed: &ComputedNode,
+    transform: &GlobalTransform,
+    position: Vec2,
+) -> usize {
+    let glyphs = selection_glyphs(text, info, computed.inverse_scale_factor());
+    let x = position.x - text_content_rect(transform, computed).min.x;
+    caret_at(&glyphs, text.len(), x)
+}
+
+/// System that selects text while the pointer is dragged across it.
+pub fn handle_text_selection_drag(
+    mut press_events: EventReader<Pointer<Pressed>>,
+    mut drag_events: EventReader<Pointer<Drag>>,
+    mut drag_end_events: EventReader<Pointer<DragEnd>>,
+    mut texts: Query<(
+        Entity,
+        &mut SelectableText,
+        &Text,
+        &TextLayoutInfo,
+        &ComputedNode,
+        &GlobalTransform,
+    )>,
+    mut select_events: EventWriter<TextSelectEvent>,
+) {
+    for event in press_events.read() {
+        if event.button != PointerButton::Primary {
+            continue;
+        }
+        // A press anywhere drops the current selection and may start a new one
+        for (entity, mut selectable, text, info, computed, transform) in &mut texts {
+            if entity == event.target && selectable.enabled {
+                selectable.selection = None;
+                selectable.anchor = Some(caret_at_pointer(
+                    text,
+                    info,
+                    computed,
+                    transform,
+                    event.pointer_location.position,
+                ));
+            } else if selectable.selection.is_some() || selectable.anchor.is_some() {
+                selectable.clear();
+            }
+        }
+    }
+
+    for event in drag_events.read() {
+        let Ok((_, mut selectable, text, info, computed, transform)) = texts.get_mut(event.target)
+        else {
+            continue;
+        };
+        let Some(anchor) = selectable.anchor else {
+            continue;
+        };
+
+        let caret = caret_at_pointer(
+            text,
+            info,
+            computed,
+            transform,
+            event.pointer_location.position,
+        );
+        let selection = selection_between(anchor, caret);
+        if selectable.selection != selection {
+            selectable.selection = selection;
+        }
+    }
+
+    for event in drag_end_events.read() {
+        let Ok((entity, mut selectable, ..)) = texts.get_mut(event.target) else {
+            continue;
+        };
+        if selectable.anchor.take().is_none() {
+            continue;
+        }
+        if let Some(range) = selectable.selection.clone() {
+            select_events.write(TextSelectEvent {
+                text_entity: entity,
+                range,
+            });
+        }
+    }
+}
+
+/// System that copies the selected text on Ctrl+C / Cmd+C.
+pub fn copy_selected_text(
+    keys: Res<ButtonInput<KeyCode>>,
+    texts: Query<(Entity, &SelectableText, &Text)>,
+    mut clipboard: NonSendMut<SystemClipboard>,
+    mut copy_events: EventWriter<CopyEvent>,
+) {
+    let command = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+    if !command || !keys.just_pressed(KeyCode::KeyC) {
+        return;
+    }
+
+    for (entity, selectable, text) in &texts {
+        let Some(selected) = selectable
+            .selection
+            .clone()
+            .and_then(|range| text.0.get(range))
+        else {
+            continue;
+        };
+
+        let success = clipboard.set_text(selected);
+        copy_events.write(CopyEvent {
+            copy_entity: entity,
+            text: selected.to_string(),
+            success,
+        });
+    }
+}
+
+/// System that places the highlight overlays over the selections, spawning
+/// and despawning them as selections appear and disappear.
+#[allow(clippy::type_complexity)]
+pub fn update_text_selection_highlights(
+    mut commands: Commands,
+    mut texts: Query<(
+        Entity,
+        &mut SelectableText,
+        &Text,
+        &TextLayoutInfo,
+        &ComputedNode,
+        &GlobalTransform,
+        Option<&ChildOf>,
+    )>,
+    nodes: Query<(&GlobalTransform, &ComputedNode)>,
+    mut highlights: Query<(Entity, &TextSelectionHighlight, &mut Node, Option<&ChildOf>)>,
+    tokens: Res<ThemeTokens>,
+) {
+    for (entity, mut selectable, text, info, computed, transform, child_of) in &mut texts {
+        let span = selectable.selection.as_ref().and_then(|range| {
+            let glyphs = selection_glyphs(text, info, computed.inverse_scale_factor());
+            highlight_span(&glyphs, range)
+        });
+
+        let Some((left, right)) = span else {
+            // The overlay is despawned below once no text owns it
+            if selectable.highlight.is_some() {
+                selectable.highlight = None;
+            }
+            continue;
+        };
+
+        let (parent, origin) = text_overlay_anchor(transform, computed, child_of, &nodes);
+        let node = Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(origin.x + left),
+            top: Val::Px(origin.y),
+            width: Val::Px(right - left),
+            height: Val::Px(text_content_rect(transform, computed).height()),
+            ..default()
+        };
+        match selectable
+            .highlight
+            .and_then(|highlight| highlights.get_mut(highlight).ok())
+        {
+            Some((highlight, _, mut highlight_node, current)) => {
+                if *highlight_node != node {
+                    *highlight_node = node;
+                }
+                reparent_text_overlay(&mut commands, highlight, current, parent);
+            }
+            None => {
+                // Spawned after the text, so it is drawn above it
+                let mut highlight = commands.spawn((
+                    Name::new("TextSelectionHighlight"),
+                    TextSelectionHighlight { text: entity },
+                    node,
+                    BackgroundColor(tokens.system.selection),
+                    Pickable::IGNORE,
+                ));
+                if let Some(parent) = parent {
+                    highlight.insert(ChildOf(parent));
+                }
+                selectable.highlight = Some(highlight.id());
+            }
+        }
+    }
+
+    // Overlays of cleared selections or despawned texts
+    for (highlight, selection, ..) in &highlights {
+        let owned = texts
+            .get(selection.text)
+            .is_ok_and(|(_, selectable, ..)| selectable.highlight == Some(highlight));
+        if !owned {
+            commands.entity(highlight).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Glyphs of a single line of 10px wide characters.
+    fn glyphs(text: &str) -> Vec<SelectionGlyph> {
+        text.char_indices()
+            .enumerate()
+            .map(|(i, (byte_index, c))| SelectionGlyph {
+                byte_start: byte_index,
+                byte_end: byte_index + c.len_utf8(),
+                line: 0,
+                left: i as f32 * 10.0,
+                right: (i + 1) as f32 * 10.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_caret_snaps_to_nearest_boundary() {
+        let text = "hello world";
+        let glyphs = glyphs(text);
+
+        assert_eq!(caret_at(&glyphs, text.len(), -5.0), 0);
+        assert_eq!(caret_at(&glyphs, text.len(), 4.0), 0);
+        assert_eq!(caret_at(&glyphs, text.len(), 6.0), 1);
+        assert_eq!(caret_at(&glyphs, text.len(), 104.0), 10);
+        assert_eq!(caret_at(&glyphs, text.len(), 500.0), text.len());
+        assert_eq!(caret_at(&[], 0, 20.0), 0);
+    }
+
+    #[test]
+    fn test_selection_orders_anchor_and_caret() {
+        assert_eq!(selection_between(2, 5), Some(2..5));
+        assert_eq!(selection_between(5, 2), Some(2..5));
+        assert_eq!(selection_between(3, 3), None);
+    }
+
+    #[test]
+    fn test_highlight_covers_selected_glyphs() {
+        let glyphs = glyphs("hello world");
+
+        assert_eq!(highlight_span(&glyphs, &(6..11)), Some((60.0, 110.0)));
+        assert_eq!(highlight_span(&glyphs, &(0..1)), Some((0.0, 10.0)));
+        assert_eq!(highlight_span(&glyphs, &(20..30)), None);
+    }
+
+    #[test]
+    fn test_multibyte_ranges_stay_on_char_boundaries() {
+        let text = "héllo";
+        let glyphs = glyphs(text);
+
+        // 'é' takes two bytes, so the caret after it is at byte 3
+        let caret = caret_at(&glyphs, text.len(), 16.0);
+        assert_eq!(caret, 3);
+        let range = selection_between(0, caret).unwrap();
+        assert_eq!(&text[range], "hé");
+    }
+
+    #[test]
+    fn test_overlays_are_placed_in_the_text_parent() {
+        use bevy::{ecs::system::RunSystemOnce, sprite::BorderRect};
+
+        let mut world = World::new();
+        // A 200x100 parent with a 2px border at the window's top-left corner
+        let parent = world
+            .spawn((
+                ComputedNode {
+                    size: Vec2::new(200.0, 100.0),
+                    border: BorderRect {
+                        left: 2.0,
+                        right: 2.0,
+                        top: 2.0,
+                        bottom: 2.0,
+                    },
+                    inverse_scale_factor: 1.0,
+                    ..default()
+                },
+                GlobalTransform::from_translation(Vec3::new(100.0, 50.0, 0.0)),
+            ))
+            .id();
+        // A 50x20 text with its top-left corner at (25, 20)
+        let text = world
+            .spawn((
+                ComputedNode {
+                    size: Vec2::new(50.0, 20.0),
+                    inverse_scale_factor: 1.0,
+                    ..default()
+                },
+                GlobalTransform::from_translation(Vec3::new(50.0, 30.0, 0.0)),
+                ChildOf(parent),
+            ))
+            .id();
+
+        let anchor = |world: &mut World| {
+            world
+                .run_system_once(
+                    move |texts: Query<(&GlobalTransform, &ComputedNode, Option<&ChildOf>)>,
+                          nodes: Query<(&GlobalTransform, &ComputedNode)>| {
+                        let (transform, computed, child_of) = texts.get(text).unwrap();
+                        text_overlay_anchor(transform, computed, child_of, &nodes)
+                    },
+                )
+                .unwrap()
+        };
+        assert_eq!(anchor(&mut world), (Some(parent), Vec2::new(23.0, 18.0)));
+
+        world.entity_mut(text).remove::<ChildOf>();
+        assert_eq!(anchor(&mut world), (None, Vec2::new(25.0, 20.0)));
+    }
+
+    #[test]
+    fn test_ctrl_c_copies_selection() {
+        let mut world = World::new();
+        world.init_non_send_resource::<SystemClipboard>();
+        world.init_resource::<Events<CopyEvent>>();
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::ControlLeft);
+        keys.press(KeyCode::KeyC);
+        world.insert_resource(keys);
+        let text = world
+            .spawn((
+                Text::new("hello world"),
+                SelectableText {
+                    selection: Some(6..11),
+                    ..SelectableText::new(true)
+                },
+            ))
+            .id();
+        world.spawn((Text::new("unselected"), SelectableText::new(true)));
+
+        let system = world.register_system(copy_selected_text);
+        world.run_system(system).unwrap();
+
+        let events: Vec<CopyEvent> = world.resource_mut::<Events<CopyEvent>>().drain().collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].copy_entity, text);
+        assert_eq!(events[0].text, "world");
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
     assets::InterfaceIconId,
-    theme::color::{accent_palette, UiColorPalette},
+    components::{disabled::DisabledInteraction, sounds::MuteSounds},
+    theme::color::{accent_palette, disabled_color, disabled_colors, UiColorPalette},
+    utilities::naming::Namespace,
 };
 use bevy::prelude::*;
 use bevy_picking::prelude::{Click, Out, Over, Pickable, Pointer, Pressed, Released};
@@ -144,6 +146,17 @@ impl Default for ToggleComponent {
     }
 }
 
+// Colors follow through `update_toggle_styling`
+impl DisabledInteraction for ToggleComponent {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+}
+
 /// Size variants for toggle components.
 ///
 /// Each size variant provides different dimensions appropriate for various UI contexts.
@@ -282,6 +295,7 @@ pub struct ToggleBuilder {
     toggle: ToggleComponent,
     text: Option<String>,
     icon: Option<InterfaceIconId>,
+    mute_sounds: bool,
 }
 
 impl ToggleBuilder {
@@ -299,6 +313,7 @@ impl ToggleBuilder {
             toggle: ToggleComponent::default(),
             text: None,
             icon: None,
+            mute_sounds: false,
         }
     }
 
@@ -394,6 +409,12 @@ impl ToggleBuilder {
         self
     }
 
+    /// Keeps the toggle silent, whatever the global sound settings.
+    pub fn mute_sounds(mut self) -> Self {
+        self.mute_sounds = true;
+        self
+    }
+
     /// Enables high contrast mode for improved accessibility.
     ///
     /// High contrast mode uses stronger color differences to improve
@@ -522,21 +543,14 @@ impl ToggleComponent {
             // Disabled state
             (_, _, ToggleState::Disabled) => {
                 if self.pressed {
-                    self.color.bg_subtle
+                    disabled_colors(&self.color).0
                 } else {
                     Color::NONE
                 }
             }
         };
 
-        let mut bg_color = BackgroundColor(base_color);
-
-        if state == ToggleState::Disabled {
-            let srgba = bg_color.0.to_srgba();
-            bg_color.0 = Color::srgba(srgba.red, srgba.green, srgba.blue, 0.5);
-        }
-
-        bg_color
+        BackgroundColor(base_color)
     }
 
     /// Calculates the border color for a given state.
@@ -560,14 +574,11 @@ impl ToggleComponent {
                 }
             }
             (ToggleVariant::Outline, ToggleState::Active) => BorderColor(self.color.solid),
-            (ToggleVariant::Outline, ToggleState::Disabled) => {
-                let color = if self.pressed {
-                    self.color.solid
-                } else {
-                    self.color.border
-                };
-                BorderColor(color.with_alpha(0.5))
-            }
+            (ToggleVariant::Outline, ToggleState::Disabled) => BorderColor(if self.pressed {
+                disabled_color(self.color.solid)
+            } else {
+                disabled_colors(&self.color).1
+            }),
             _ => BorderColor(Color::NONE),
         }
     }
@@ -577,7 +588,7 @@ impl ToggleComponent {
     /// Text colors are adjusted based on the background to ensure proper
     /// contrast and readability across all variants and states.
     fn calculate_text_color(&self, state: ToggleState) -> Color {
-        match (self.pressed, self.variant, state) {
+        let color = match (self.pressed, self.variant, state) {
             // Pressed states
             (true, ToggleVariant::Solid, _) => self.color.text_contrast,
             (true, ToggleVariant::Soft, _) => self.color.text,
@@ -586,13 +597,13 @@ impl ToggleComponent {
 
             // Unpressed states
             (false, _, _) => self.color.text,
-            // Disabled state overrides
-        }
-        .with_alpha(if state == ToggleState::Disabled {
-            0.5
+        };
+
+        if state == ToggleState::Disabled {
+            disabled_color(color)
         } else {
-            1.0
-        })
+            color
+        }
     }
 }
 
@@ -686,12 +697,14 @@ impl ToggleBuilder {
         let border_radius = self.calculate_border_radius();
 
         let bundle = (
+            Name::new(self.name),
             self.toggle.clone(),
             node,
             background_color,
             border_color,
             border_radius,
             Pickable::default(),
+            MuteSounds(self.mute_sounds),
             Button,
             Interaction::None,
         );
@@ -914,6 +927,11 @@ fn on_toggle_release(
 /// - Applies theme colors from the configured palette
 /// - Propagates color changes to child text and icon elements
 /// - Maintains visual consistency across state transitions
+///
+/// This is also the path for outside changes: app logic can set `pressed`
+/// directly, e.g. to keep a "mute" toggle in sync with the audio settings,
+/// and the toggle is restyled in the same frame. No [`ToggleChangeEvent`] is
+/// sent for such changes.
 pub fn update_toggle_styling(
     toggles_query: Query<(Entity, &ToggleComponent), Changed<ToggleComponent>>,
     mut bg_colors: Query<&mut BackgroundColor, (With<ToggleComponent>, Without<Text>)>,
@@ -990,3 +1008,38 @@ fn update_children_colors(
 /// let toggle2 = ToggleComponent::new("long").build();
 /// ```
 pub type Toggle = ToggleComponent;
+
+impl Namespace for ToggleBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_app;
+
+    #[test]
+    fn test_pressed_set_from_code_restyles_toggle() {
+        let mut app = test_app();
+        let toggle = app
+            .world_mut()
+            .spawn(ToggleBuilder::new("Mute").text("Mute").build())
+            .id();
+        app.update();
+        let released = *app.world().get::<BackgroundColor>(toggle).unwrap();
+
+        app.world_mut()
+            .get_mut::<ToggleComponent>(toggle)
+            .unwrap()
+            .pressed = true;
+        app.update();
+
+        let component = app.world().get::<ToggleComponent>(toggle).unwrap();
+        let expected = component.get_styling(ToggleState::Normal).background_color;
+        let background = *app.world().get::<BackgroundColor>(toggle).unwrap();
+        assert_eq!(background, expected);
+        assert_ne!(background, released);
+    }
+}
@@ -0,0 +1,414 @@
+use crate::{
+    components::{
+        radio::SelectionMode,
+        toggle::{
+            ToggleBuilder, ToggleChangeEvent, ToggleComponent, ToggleSize, ToggleState,
+            ToggleVariant,
+        },
+    },
+    theme::color::{accent_palette, UiColorPalette},
+    utilities::naming::Namespace,
+};
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
+use bevy_picking::prelude::Pickable;
+
+/// Event emitted when the selection of a toggle group changes.
+#[derive(Event, Debug, Clone)]
+pub struct ToggleGroupChangeEvent {
+    pub group_entity: Entity,
+    pub group_name: String,
+    /// The value of the member that was toggled
+    pub value: String,
+    /// Whether that member is now pressed
+    pub pressed: bool,
+    /// All values selected after the change
+    pub selected_values: Vec<String>,
+}
+
+/// Layout direction of a toggle group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToggleGroupOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+/// A set of toggles acting as a segmented control.
+///
+/// In [`SelectionMode::Single`] pressing one member releases all others,
+/// similar to a radio group; in [`SelectionMode::Multiple`] members toggle
+/// independently.
+#[derive(Component, Debug, Clone)]
+pub struct ToggleGroupComponent {
+    pub name: String,
+    pub selection_mode: SelectionMode,
+    pub selected_values: Vec<String>,
+    pub orientation: ToggleGroupOrientation,
+    pub disabled: bool,
+    /// Index of the member that receives keyboard input
+    pub focused_index: Option<usize>,
+}
+
+impl Default for ToggleGroupComponent {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            selection_mode: SelectionMode::Single,
+            selected_values: Vec::new(),
+            orientation: ToggleGroupOrientation::Horizontal,
+            disabled: false,
+            focused_index: None,
+        }
+    }
+}
+
+impl ToggleGroupComponent {
+    pub fn new(name: impl Into<String>) -> ToggleGroupBuilder {
+        ToggleGroupBuilder::new(name)
+    }
+
+    pub fn is_selected(&self, value: &str) -> bool {
+        self.selected_values.iter().any(|v| v == value)
+    }
+}
+
+/// Member of a toggle group, attached next to its [`ToggleComponent`].
+#[derive(Component, Debug, Clone)]
+pub struct ToggleGroupItem {
+    pub group: Entity,
+    pub value: String,
+    pub index: usize,
+    pub count: usize,
+}
+
+pub struct ToggleGroupBuilder {
+    name: String,
+    group: ToggleGroupComponent,
+    items: Vec<(String, ToggleBuilder)>,
+    size: ToggleSize,
+    variant: ToggleVariant,
+    color: UiColorPalette,
+}
+
+impl ToggleGroupBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            name: format!("{}_ToggleGroup", name),
+            group: ToggleGroupComponent {
+                name,
+                ..default()
+            },
+            items: Vec::new(),
+            size: ToggleSize::Size2,
+            variant: ToggleVariant::Outline,
+            color: accent_palette(),
+        }
+    }
+
+    /// Adds a text member identified by `value`.
+    pub fn item(self, value: impl Into<String>, label: impl Into<String>) -> Self {
+        let value = value.into();
+        let toggle = ToggleBuilder::new(value.clone()).text(label);
+        self.item_with(value, toggle)
+    }
+
+    /// Adds a member from a custom toggle builder (e.g. icon-only toggles).
+    ///
+    /// The group's size, variant and color are applied to every member.
+    pub fn item_with(mut self, value: impl Into<String>, toggle: ToggleBuilder) -> Self {
+        self.items.push((value.into(), toggle));
+        self
+    }
+
+    pub fn single(mut self) -> Self {
+        self.group.selection_mode = SelectionMode::Single;
+        self
+    }
+
+    pub fn multiple(mut self) -> Self {
+        self.group.selection_mode = SelectionMode::Multiple;
+        self
+    }
+
+    /// Initially selected value; may be called repeatedly in multiple mode.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        let value = value.into();
+        if self.group.selection_mode == SelectionMode::Single {
+            self.group.selected_values.clear();
+        }
+        if !self.group.is_selected(&value) {
+            self.group.selected_values.push(value);
+        }
+        self
+    }
+
+    pub fn horizontal(mut self) -> Self {
+        self.group.orientation = ToggleGroupOrientation::Horizontal;
+        self
+    }
+
+    pub fn vertical(mut self) -> Self {
+        self.group.orientation = ToggleGroupOrientation::Vertical;
+        self
+    }
+
+    pub fn size(mut self, size: ToggleSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn variant(mut self, variant: ToggleVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn color(mut self, color: UiColorPalette) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.group.disabled = true;
+        self
+    }
+}
+
+impl ToggleGroupBuilder {
+    fn calculate_style(&self) -> Node {
+        Node {
+            flex_direction: match self.group.orientation {
+                ToggleGroupOrientation::Horizontal => FlexDirection::Row,
+                ToggleGroupOrientation::Vertical => FlexDirection::Column,
+            },
+            align_items: AlignItems::Stretch,
+            ..default()
+        }
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let node = self.calculate_style();
+        let count = self.items.len();
+        let group = self.group.clone();
+
+        let items: Vec<(String, ToggleBuilder)> = self
+            .items
+            .into_iter()
+            .map(|(value, toggle)| {
+                let mut toggle = toggle
+                    .size(self.size)
+                    .variant(self.variant)
+                    .color(self.color.clone());
+                if group.is_selected(&value) {
+                    toggle = toggle.pressed();
+                }
+                if group.disabled {
+                    toggle = toggle.disabled();
+                }
+                (value, toggle)
+            })
+            .collect();
+
+        (
+            Name::new(self.name),
+            self.group,
+            node,
+            Pickable::default(),
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                let group_entity = parent.target_entity();
+                for (index, (value, toggle)) in items.into_iter().enumerate() {
+                    parent.spawn((
+                        toggle.build(),
+                        ToggleGroupItem {
+                            group: group_entity,
+                            value,
+                            index,
+                            count,
+                        },
+                    ));
+                }
+            })),
+        )
+    }
+}
+
+/// Joins adjacent members: inner corners lose their radius and neighbouring
+/// borders overlap so they are drawn only once.
+pub fn style_toggle_group_items(
+    mut items: Query<(&ToggleGroupItem, &mut Node, &mut BorderRadius), Added<ToggleGroupItem>>,
+    groups: Query<&ToggleGroupComponent>,
+) {
+    for (item, mut node, mut radius) in &mut items {
+        let Ok(group) = groups.get(item.group) else {
+            continue;
+        };
+
+        let first = item.index == 0;
+        let last = item.index + 1 == item.count;
+        let outer = radius.top_left;
+        let corner = |keep: bool| if keep { outer } else { Val::Px(0.0) };
+
+        match group.orientation {
+            ToggleGroupOrientation::Horizontal => {
+                *radius = BorderRadius {
+                    top_left: corner(first),
+                    bottom_left: corner(first),
+                    top_right: corner(last),
+                    bottom_right: corner(last),
+                };
+                if !first {
+                    node.margin.left = -node.border.left;
+                }
+            }
+            ToggleGroupOrientation::Vertical => {
+                *radius = BorderRadius {
+                    top_left: corner(first),
+                    top_right: corner(first),
+                    bottom_left: corner(last),
+                    bottom_right: corner(last),
+                };
+                if !first {
+                    node.margin.top = -node.border.top;
+                }
+            }
+        }
+    }
+}
+
+/// Enforces the group's selection mode whenever a member is toggled.
+pub fn update_toggle_groups(
+    mut toggle_events: EventReader<ToggleChangeEvent>,
+    mut groups: Query<(Entity, &mut ToggleGroupComponent)>,
+    items: Query<&ToggleGroupItem>,
+    mut toggles: Query<(Entity, &ToggleGroupItem, &mut ToggleComponent)>,
+    mut group_events: EventWriter<ToggleGroupChangeEvent>,
+) {
+    for event in toggle_events.read() {
+        let Ok(item) = items.get(event.toggle_entity) else {
+            continue;
+        };
+        let group_entity = item.group;
+
+        // The last interacted group owns keyboard focus
+        for (entity, mut group) in &mut groups {
+            let focused = (entity == group_entity).then_some(item.index);
+            if group.focused_index != focused {
+                group.focused_index = focused;
+            }
+        }
+
+        let Ok((_, mut group)) = groups.get_mut(group_entity) else {
+            continue;
+        };
+
+        match group.selection_mode {
+            SelectionMode::Single => {
+                group.selected_values.clear();
+                if event.pressed {
+                    group.selected_values.push(item.value.clone());
+
+                    // Release all siblings
+                    for (entity, sibling, mut toggle) in &mut toggles {
+                        if sibling.group == group_entity
+                            && entity != event.toggle_entity
+                            && toggle.pressed
+                        {
+                            toggle.pressed = false;
+                        }
+                    }
+                }
+            }
+            SelectionMode::Multiple => {
+                if event.pressed {
+                    if !group.is_selected(&item.value) {
+                        group.selected_values.push(item.value.clone());
+                    }
+                } else {
+                    group.selected_values.retain(|v| v != &item.value);
+                }
+            }
+        }
+
+        group_events.write(ToggleGroupChangeEvent {
+            group_entity,
+            group_name: group.name.clone(),
+            value: item.value.clone(),
+            pressed: event.pressed,
+            selected_values: group.selected_values.clone(),
+        });
+    }
+}
+
+/// Arrow keys move focus between the members of the focused group,
+/// Space/Enter toggles the focused member.
+pub fn handle_toggle_group_keyboard(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut groups: Query<(Entity, &mut ToggleGroupComponent)>,
+    mut toggles: Query<(Entity, &ToggleGroupItem, &mut ToggleComponent)>,
+    mut toggle_events: EventWriter<ToggleChangeEvent>,
+) {
+    for (group_entity, mut group) in &mut groups {
+        let Some(focused) = group.focused_index else {
+            continue;
+        };
+        if group.disabled {
+            continue;
+        }
+
+        let (prev, next) = match group.orientation {
+            ToggleGroupOrientation::Horizontal => (KeyCode::ArrowLeft, KeyCode::ArrowRight),
+            ToggleGroupOrientation::Vertical => (KeyCode::ArrowUp, KeyCode::ArrowDown),
+        };
+        let count = toggles
+            .iter()
+            .filter(|(_, item, _)| item.group == group_entity)
+            .count();
+        if count == 0 {
+            continue;
+        }
+
+        let new_focus = if keyboard.just_pressed(next) {
+            (focused + 1) % count
+        } else if keyboard.just_pressed(prev) {
+            (focused + count - 1) % count
+        } else {
+            focused
+        };
+
+        if new_focus != focused {
+            group.focused_index = Some(new_focus);
+            for (_, item, mut toggle) in &mut toggles {
+                if item.group != group_entity || toggle.disabled {
+                    continue;
+                }
+                // Reuse the hover styling as the focus highlight
+                toggle.current_state = if item.index == new_focus {
+                    ToggleState::Hover
+                } else {
+                    ToggleState::Normal
+                };
+            }
+        }
+
+        if keyboard.just_pressed(KeyCode::Space) || keyboard.just_pressed(KeyCode::Enter) {
+            for (entity, item, mut toggle) in &mut toggles {
+                if item.group == group_entity && item.index == new_focus && !toggle.disabled {
+                    toggle.pressed = !toggle.pressed;
+                    toggle_events.write(ToggleChangeEvent {
+                        toggle_entity: entity,
+                        pressed: toggle.pressed,
+                        size: toggle.size,
+                    });
+                }
+            }
+        }
+    }
+}
+
+pub type ToggleGroup = ToggleGroupComponent;
+
+impl Namespace for ToggleGroupBuilder {
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
@@ -0,0 +1,247 @@
+//! Required-state validation for checkboxes, switches and radio groups.
+//!
+//! A control marked `.required()` gets a [`ValidationState`] that
+//! [`update_validation_states`] keeps in sync with [`Validate::validate`]:
+//! a required checkbox or switch must be checked, a required radio group must
+//! have a selection. Every change of validity sends a
+//! [`ValidationChangedEvent`].
+//!
+//! Invalid controls look normal until their errors are shown, typically when
+//! a form is submitted; from then on [`style_validation_errors`] draws their
+//! border in the error color until the constraint is met.
+//!
+//! ```rust
+//! let terms = commands
+//!     .spawn(CheckboxBuilder::new("Terms").label("I accept the terms").required().build())
+//!     .id();
+//!
+//! fn submit(mut commands: Commands, states: Query<&ValidationState>) {
+//!     if states.iter().all(|state| state.valid) {
+//!         // send the form
+//!     } else {
+//!         commands.queue(show_validation_errors());
+//!     }
+//! }
+//! ```
+
+use bevy::{ecs::system::Command, prelude::*};
+
+use crate::theme::color::error_palette;
+
+/// Message of a required control that isn't filled in
+pub const REQUIRED_MESSAGE: &str = "This field is required";
+
+/// Component of a control that can be required.
+pub trait Validate: Component {
+    fn is_required(&self) -> bool;
+
+    /// Whether the control meets its constraint; optional controls always do.
+    fn validate(&self) -> bool;
+
+    /// Border color of the control while it shows no error.
+    fn valid_border_color(&self) -> BorderColor;
+
+    fn validation_message(&self) -> &str {
+        REQUIRED_MESSAGE
+    }
+}
+
+/// Validity of a required control, inserted by [`update_validation_states`].
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ValidationState {
+    pub valid: bool,
+    /// Why the control is invalid, `None` while it is valid
+    pub message: Option<String>,
+    /// Whether an invalid control shows its error styling
+    pub show_errors: bool,
+    /// Whether the error border is currently drawn
+    error_styled: bool,
+}
+
+impl ValidationState {
+    pub fn valid() -> Self {
+        Self {
+            valid: true,
+            message: None,
+            show_errors: false,
+            error_styled: false,
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Self {
+            valid: false,
+            message: Some(message.into()),
+            ..Self::valid()
+        }
+    }
+
+    /// Shows the error styling while the control is invalid.
+    pub fn show_errors(&mut self) {
+        self.show_errors = true;
+    }
+
+    pub fn hide_errors(&mut self) {
+        self.show_errors = false;
+    }
+
+    /// Whether the control is drawn as invalid.
+    pub fn shows_error(&self) -> bool {
+        !self.valid && self.show_errors
+    }
+}
+
+/// Event sent when a control becomes valid or invalid.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct ValidationChangedEvent {
+    pub entity: Entity,
+    pub valid: bool,
+    pub message: Option<String>,
+}
+
+/// Command showing the errors of every validated control, e.g. on submit.
+pub fn show_validation_errors() -> impl Command {
+    |world: &mut World| {
+        let mut states = world.query::<&mut ValidationState>();
+        for mut state in states.iter_mut(world) {
+            state.show_errors();
+        }
+    }
+}
+
+/// Inserts and updates the [`ValidationState`] of required `C` controls.
+pub fn update_validation_states<C: Validate>(
+    mut commands: Commands,
+    mut controls: Query<(Entity, &C, Option<&mut ValidationState>), Changed<C>>,
+    mut events: EventWriter<ValidationChangedEvent>,
+) {
+    for (entity, control, state) in &mut controls {
+        let valid = control.validate();
+        let message = (!valid).then(|| control.validation_message().to_string());
+
+        match state {
+            Some(mut state) => {
+                if state.valid == valid {
+                    continue;
+                }
+                state.valid = valid;
+                state.message = message.clone();
+            }
+            None if control.is_required() => {
+                let state = match &message {
+                    Some(message) => ValidationState::invalid(message.clone()),
+                    None => ValidationState::valid(),
+                };
+                commands.entity(entity).insert(state);
+            }
+            None => continue,
+        }
+
+        events.write(ValidationChangedEvent {
+            entity,
+            valid,
+            message,
+        });
+    }
+}
+
+/// Draws the border of invalid `C` controls that show their errors in the
+/// error color, and restores it once they are valid or hide their errors.
+pub fn style_validation_errors<C: Validate>(
+    mut controls: Query<(&C, &mut ValidationState, &mut BorderColor)>,
+) {
+    let error_color = error_palette().border_hover;
+    for (control, mut state, mut border) in &mut controls {
+        if state.shows_error() {
+            // Interaction styling may have recolored the border this frame
+            if border.0 != error_color {
+                border.0 = error_color;
+            }
+            if !state.error_styled {
+                state.error_styled = true;
+            }
+        } else if state.error_styled {
+            *border = control.valid_border_color();
+            state.error_styled = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::radio::{RadioGroupBuilder, RadioGroupComponent},
+        test_support::{drain_events, test_app},
+    };
+
+    fn validation_state(app: &App, entity: Entity) -> Option<ValidationState> {
+        app.world().get::<ValidationState>(entity).cloned()
+    }
+
+    #[test]
+    fn test_required_radio_group_without_selection_is_invalid() {
+        let mut app = test_app();
+        let group = app
+            .world_mut()
+            .spawn(RadioGroupBuilder::new("Plan").required().build())
+            .id();
+        app.update();
+
+        let state = validation_state(&app, group).unwrap();
+        assert!(!state.valid);
+        assert_eq!(state.message.as_deref(), Some(REQUIRED_MESSAGE));
+        let events = drain_events::<ValidationChangedEvent>(&mut app);
+        assert_eq!(events.len(), 1);
+        assert!(!events[0].valid);
+
+        // Errors only show once asked for
+        let border = app.world().get::<BorderColor>(group).unwrap().0;
+        assert_ne!(border, error_palette().border_hover);
+        show_validation_errors().apply(app.world_mut());
+        app.update();
+        let border = app.world().get::<BorderColor>(group).unwrap().0;
+        assert_eq!(border, error_palette().border_hover);
+    }
+
+    #[test]
+    fn test_required_radio_group_with_selection_is_valid() {
+        let mut app = test_app();
+        let group = app
+            .world_mut()
+            .spawn(RadioGroupBuilder::new("Plan").required().build())
+            .id();
+        app.update();
+        show_validation_errors().apply(app.world_mut());
+        app.update();
+        drain_events::<ValidationChangedEvent>(&mut app);
+
+        app.world_mut()
+            .get_mut::<RadioGroupComponent>(group)
+            .unwrap()
+            .selected_value = Some("pro".into());
+        app.update();
+
+        let state = validation_state(&app, group).unwrap();
+        assert!(state.valid);
+        assert_eq!(state.message, None);
+        let events = drain_events::<ValidationChangedEvent>(&mut app);
+        assert_eq!(events.len(), 1);
+        assert!(events[0].valid);
+        let border = app.world().get::<BorderColor>(group).unwrap().0;
+        assert_eq!(border, Color::NONE);
+    }
+
+    #[test]
+    fn test_optional_controls_get_no_validation_state() {
+        let mut app = test_app();
+        let group = app
+            .world_mut()
+            .spawn(RadioGroupBuilder::new("Plan").build())
+            .id();
+        app.update();
+
+        assert!(validation_state(&app, group).is_none());
+        assert!(drain_events::<ValidationChangedEvent>(&mut app).is_empty());
+    }
+}
@@ -0,0 +1,382 @@
+//! Virtual List Component for Forge UI
+//!
+//! A scrolling list of many equally tall rows that only keeps the rows in
+//! view (plus a few rows of overscan above and below) as entities.
+//!
+//! The list is a [`ScrollArea`](crate::components::scroll_area) whose content
+//! is sized to `count * item_height`, so wheel input and the scrollbar behave
+//! as if every row existed. Rows are spawned by a callback for their index and
+//! positioned absolutely inside the content.
+//!
+//! ## Recycling
+//!
+//! By default rows scrolled out of view are despawned and the rows scrolled
+//! into view are spawned. With [`VirtualListBuilder::update_item`] rows are
+//! recycled instead: a row leaving the window is moved to an index entering
+//! it, and the update callback rebinds its content to the new index.
+//!
+//! ## Item height
+//!
+//! Rows are [`item_height`](VirtualListBuilder::item_height) tall. Without a
+//! fixed height, rows size to their content and the height of the first laid
+//! out row is used for all of them, starting from an estimate.
+//!
+//! ```rust
+//! let list = VirtualListBuilder::new("Log")
+//!     .count(10_000)
+//!     .item_height(24.0)
+//!     .height(Val::Px(400.0))
+//!     .spawn_item(|commands, index| {
+//!         commands.spawn(Text::body(format!("Line {index}")).build()).id()
+//!     })
+//!     .build(&mut commands);
+//! ```
+
+use std::{collections::HashMap, ops::Range, sync::Arc};
+
+use bevy::prelude::*;
+
+use crate::components::scroll_area::{ScrollAreaBuilder, ScrollAreaViewport};
+
+/// Rows kept beyond each edge of the viewport
+pub const VIRTUAL_LIST_OVERSCAN: usize = 3;
+/// Height assumed for rows sized to their content until one is measured
+pub const VIRTUAL_LIST_ESTIMATED_HEIGHT: f32 = 32.0;
+
+/// Spawns the row for an index and returns it.
+pub type VirtualListSpawner = Arc<dyn Fn(&mut Commands, usize) -> Entity + Send + Sync>;
+
+/// Rebinds a recycled row (as returned by the spawner) to a new index.
+pub type VirtualListUpdater = Arc<dyn Fn(&mut Commands, Entity, usize) + Send + Sync>;
+
+/// Event emitted when the rows instantiated by a virtual list change.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VirtualListRangeEvent {
+    // The scroll area root of the list, as returned by the builder.
+    pub list: Entity,
+    // First instantiated index.
+    pub start: usize,
+    // One past the last instantiated index.
+    pub end: usize,
+}
+
+/// State of a virtual list, stored on the content node inside the scroll
+/// area's viewport.
+#[derive(Component, Clone)]
+pub struct VirtualList {
+    // The scroll area root of the list.
+    pub list: Entity,
+    pub count: usize,
+    pub item_height: f32,
+    // Whether rows size to their content, until the first one is measured.
+    pub measure: bool,
+    pub overscan: usize,
+    pub spawner: VirtualListSpawner,
+    pub updater: Option<VirtualListUpdater>,
+    // Instantiated range and its row slots by index.
+    range: Range<usize>,
+    slots: HashMap<usize, Entity>,
+}
+
+impl VirtualList {
+    /// The range of indices currently instantiated.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Offset of row `index` from the top of the content.
+    fn top(&self, index: usize) -> Val {
+        Val::Px(index as f32 * self.item_height)
+    }
+}
+
+/// Positions one row of a virtual list; the row's content is its child.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VirtualListItem {
+    pub index: usize,
+    // The entity returned by the spawner.
+    pub content: Entity,
+}
+
+/// Range of rows to instantiate for a list scrolled by `scroll_offset`, with
+/// `overscan` rows beyond each edge of a `viewport_height` tall viewport.
+pub fn visible_range(
+    scroll_offset: f32,
+    viewport_height: f32,
+    item_height: f32,
+    count: usize,
+    overscan: usize,
+) -> Range<usize> {
+    if count == 0 || item_height <= 0.0 {
+        return 0..0;
+    }
+    let first = (scroll_offset.max(0.0) / item_height).floor() as usize;
+    let last = ((scroll_offset.max(0.0) + viewport_height.max(0.0)) / item_height).ceil() as usize;
+    let start = first.saturating_sub(overscan).min(count);
+    let end = (last + overscan).min(count).max(start);
+    start..end
+}
+
+pub struct VirtualListBuilder {
+    scroll_area: ScrollAreaBuilder,
+    count: usize,
+    item_height: Option<f32>,
+    estimated_height: f32,
+    overscan: usize,
+    spawner: Option<VirtualListSpawner>,
+    updater: Option<VirtualListUpdater>,
+}
+
+impl VirtualListBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            scroll_area: ScrollAreaBuilder::new(name).vertical(),
+            count: 0,
+            item_height: None,
+            estimated_height: VIRTUAL_LIST_ESTIMATED_HEIGHT,
+            overscan: VIRTUAL_LIST_OVERSCAN,
+            spawner: None,
+            updater: None,
+        }
+    }
+
+    /// Total number of rows.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Fixes the height of every row, in logical pixels.
+    pub fn item_height(mut self, height: f32) -> Self {
+        self.item_height = Some(height);
+        self
+    }
+
+    /// Height assumed for rows sized to their content until one is laid out.
+    pub fn estimated_item_height(mut self, height: f32) -> Self {
+        self.estimated_height = height;
+        self
+    }
+
+    /// Rows kept beyond each edge of the viewport.
+    pub fn overscan(mut self, rows: usize) -> Self {
+        self.overscan = rows;
+        self
+    }
+
+    /// Spawns the row for an index when it scrolls into view.
+    pub fn spawn_item(
+        mut self,
+        spawner: impl Fn(&mut Commands, usize) -> Entity + Send + Sync + 'static,
+    ) -> Self {
+        self.spawner = Some(Arc::new(spawner));
+        self
+    }
+
+    /// Recycles rows scrolling out of view, rebinding them to the index
+    /// scrolling in with `updater` instead of spawning a new row.
+    pub fn update_item(
+        mut self,
+        updater: impl Fn(&mut Commands, Entity, usize) + Send + Sync + 'static,
+    ) -> Self {
+        self.updater = Some(Arc::new(updater));
+        self
+    }
+
+    pub fn width(mut self, width: Val) -> Self {
+        self.scroll_area = self.scroll_area.width(width);
+        self
+    }
+
+    pub fn height(mut self, height: Val) -> Self {
+        self.scroll_area = self.scroll_area.height(height);
+        self
+    }
+
+    /// Spawns the list and returns the root entity of its scroll area.
+    ///
+    /// Rows are spawned once the viewport is laid out.
+    pub fn build(self, commands: &mut Commands) -> Entity {
+        let spawner = self.spawner.unwrap_or_else(|| {
+            warn!("VirtualListBuilder: no spawn_item callback, rows stay empty");
+            Arc::new(|commands: &mut Commands, _| commands.spawn(Node::default()).id())
+        });
+        let item_height = self.item_height.unwrap_or(self.estimated_height);
+
+        let content = commands
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(self.count as f32 * item_height),
+                flex_shrink: 0.0,
+                position_type: PositionType::Relative,
+                ..default()
+            })
+            .id();
+        let root = self.scroll_area.child(content).build(commands);
+
+        commands.entity(content).insert(VirtualList {
+            list: root,
+            count: self.count,
+            item_height,
+            measure: self.item_height.is_none(),
+            overscan: self.overscan,
+            spawner,
+            updater: self.updater,
+            range: 0..0,
+            slots: HashMap::new(),
+        });
+        root
+    }
+}
+
+/// Spawns the slot positioning row `index` of `list`, with its content.
+fn spawn_slot(commands: &mut Commands, list: &VirtualList, index: usize) -> Entity {
+    let content = (list.spawner)(commands, index);
+    commands
+        .spawn((
+            Name::new(format!("Virtual List Item {index}")),
+            VirtualListItem { index, content },
+            Node {
+                position_type: PositionType::Absolute,
+                top: list.top(index),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: if list.measure {
+                    Val::Auto
+                } else {
+                    Val::Px(list.item_height)
+                },
+                ..default()
+            },
+        ))
+        .add_child(content)
+        .id()
+}
+
+/// Keeps the rows of each virtual list in sync with its scroll position and
+/// viewport size, and the content tall enough for all rows.
+pub fn update_virtual_lists(
+    mut commands: Commands,
+    mut lists: Query<(Entity, &mut VirtualList, &mut Node, &ChildOf)>,
+    viewports: Query<(&ComputedNode, &ScrollPosition), With<ScrollAreaViewport>>,
+    mut items: Query<(&mut VirtualListItem, &mut Node, &ComputedNode), Without<VirtualList>>,
+    mut range_events: EventWriter<VirtualListRangeEvent>,
+) {
+    for (content, mut list, mut node, child_of) in &mut lists {
+        let Ok((viewport, scroll)) = viewports.get(child_of.parent()) else {
+            continue;
+        };
+
+        // Rows sized to their content: take the height of the first laid out row
+        let mut remeasured = false;
+        if list.measure {
+            let measured = list.slots.values().find_map(|slot| {
+                let (_, _, computed) = items.get(*slot).ok()?;
+                let height = computed.size().y * computed.inverse_scale_factor();
+                (height > 0.0).then_some(height)
+            });
+            if let Some(height) = measured {
+                list.item_height = height;
+                list.measure = false;
+                remeasured = true;
+            }
+        }
+
+        let content_height = Val::Px(list.count as f32 * list.item_height);
+        if node.height != content_height {
+            node.height = content_height;
+        }
+
+        let viewport_height = viewport.size().y * viewport.inverse_scale_factor();
+        let range = visible_range(
+            scroll.offset_y,
+            viewport_height,
+            list.item_height,
+            list.count,
+            list.overscan,
+        );
+        if range == list.range && !remeasured {
+            continue;
+        }
+
+        let mut leaving: Vec<(usize, Entity)> = list
+            .slots
+            .iter()
+            .filter(|(index, _)| !range.contains(*index))
+            .map(|(index, slot)| (*index, *slot))
+            .collect();
+        leaving.sort_unstable_by_key(|(index, _)| *index);
+        for (index, _) in &leaving {
+            list.slots.remove(index);
+        }
+
+        for index in range.clone() {
+            if let Some(slot) = list.slots.get(&index) {
+                if remeasured {
+                    if let Ok((_, mut slot_node, _)) = items.get_mut(*slot) {
+                        slot_node.top = list.top(index);
+                    }
+                }
+                continue;
+            }
+
+            let recycled = list
+                .updater
+                .clone()
+                .and_then(|updater| Some((updater, leaving.pop()?.1)));
+            let slot = match recycled {
+                Some((updater, slot)) => {
+                    if let Ok((mut item, mut slot_node, _)) = items.get_mut(slot) {
+                        item.index = index;
+                        slot_node.top = list.top(index);
+                        updater(&mut commands, item.content, index);
+                    }
+                    slot
+                }
+                None => {
+                    let slot = spawn_slot(&mut commands, &list, index);
+                    commands.entity(content).add_child(slot);
+                    slot
+                }
+            };
+            list.slots.insert(index, slot);
+        }
+        for (_, slot) in leaving {
+            commands.entity(slot).despawn();
+        }
+
+        if range != list.range {
+            range_events.write(VirtualListRangeEvent {
+                list: list.list,
+                start: range.start,
+                end: range.end,
+            });
+            list.range = range;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_range_at_top() {
+        // 10 rows of 20px fit a 200px viewport, plus 3 rows of overscan below
+        assert_eq!(visible_range(0.0, 200.0, 20.0, 1000, 3), 0..13);
+    }
+
+    #[test]
+    fn test_visible_range_scrolled() {
+        // Scrolled to the middle of row 50, which is partly visible
+        assert_eq!(visible_range(1010.0, 200.0, 20.0, 1000, 3), 47..64);
+        assert_eq!(visible_range(1010.0, 200.0, 20.0, 1000, 0), 50..61);
+    }
+
+    #[test]
+    fn test_visible_range_clamped_to_count() {
+        assert_eq!(visible_range(19_900.0, 200.0, 20.0, 1000, 3), 992..1000);
+        assert_eq!(visible_range(0.0, 200.0, 20.0, 4, 3), 0..4);
+        assert_eq!(visible_range(500.0, 200.0, 20.0, 0, 3), 0..0);
+    }
+}
@@ -3,3 +3,5 @@ pub mod components;
 pub mod plugin;
 pub mod theme;
 pub mod utilities;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_support;
@@ -1,7 +1,22 @@
 use crate::assets::{audio, AssetsPlugin};
 use crate::components::ComponentsPlugin;
-use crate::theme::typography::load_font_assets;
+use crate::theme::breakpoints::{update_active_breakpoint, ActiveBreakpoint, Breakpoints};
+use crate::theme::deferred_style::apply_deferred_theme_styles;
+use crate::theme::density::{update_density_tokens, UiDensity};
+use crate::theme::layout::refresh_elevation_shadows;
+use crate::theme::motion::UiMotion;
+use crate::theme::presets::{ThemeChangedEvent, ThemePresets};
+use crate::theme::radix_theme::{
+    apply_panel_backgrounds, update_panel_backgrounds, update_theme_tokens, RadixTheme, ThemeTokens,
+};
+use crate::theme::settings::{load_ui_settings, UiSettings};
+use crate::theme::typography::{load_font_assets, FontAssets};
+use crate::utilities::{
+    contrast::{find_low_contrast_text, warn_low_contrast_text},
+    naming::apply_name_prefix,
+};
 use bevy::prelude::*;
+use std::path::PathBuf;
 
 #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum UiState {
@@ -17,6 +32,35 @@ pub(crate) const SPACING_FACTOR: f32 = 0.25;
 pub(crate) const HIGH_CONTRAST: bool = false;
 pub(crate) const SCALING: f32 = 1.0;
 
+/// Startup options of [`ForgeUiPlugin`]; insert before adding the plugin.
+///
+/// ```rust
+/// App::new()
+///     .insert_resource(UiConfig {
+///         skip_asset_loading: true,
+///         ..default()
+///     })
+///     .add_plugins(ForgeUiPlugin);
+/// ```
+#[derive(Resource, Debug, Clone, Default)]
+pub struct UiConfig {
+    /// Skips font loading and starts in [`UiState::Ready`], for headless apps
+    /// such as tests and servers. Fonts are placeholder handles that never load.
+    pub skip_asset_loading: bool,
+    /// Logs a warning, once per family and weight, when a text asks for a
+    /// font weight its family doesn't have and gets a fallback.
+    pub warn_font_fallback: bool,
+    /// Namespace prepended to the [`Name`] of every UI node, e.g. `hud`
+    /// names a button `hud/submit_Button`.
+    pub name_prefix: Option<String>,
+    /// Logs a warning, once per text, when a text's color fails WCAG AA
+    /// contrast on its background. See [`crate::utilities::contrast`].
+    pub warn_low_contrast: bool,
+    /// RON file the player's [`UiSettings`] are loaded from and applied at
+    /// startup. See [`crate::theme::settings`].
+    pub settings_path: Option<PathBuf>,
+}
+
 pub struct ForgeUiPlugin;
 
 impl ForgeUiPlugin {
@@ -30,8 +74,43 @@ impl Plugin for ForgeUiPlugin {
         app
             // Initialize state system - start with LoadingAssets
             .init_state::<UiState>()
+            .init_resource::<UiConfig>()
+            .init_resource::<RadixTheme>()
+            .init_resource::<ThemePresets>()
+            .add_event::<ThemeChangedEvent>()
+            .init_resource::<ThemeTokens>()
+            .init_resource::<UiDensity>()
+            .init_resource::<UiMotion>()
+            .init_resource::<UiSettings>()
+            .init_resource::<Breakpoints>()
+            .init_resource::<ActiveBreakpoint>()
             // Add asset loading in startup systems
-            .add_systems(Startup, load_font_assets)
+            .add_systems(
+                Startup,
+                (
+                    load_font_assets.run_if(not(skips_asset_loading)),
+                    insert_placeholder_assets.run_if(skips_asset_loading),
+                    load_ui_settings.run_if(loads_settings),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    apply_panel_backgrounds,
+                    update_panel_backgrounds.run_if(resource_changed::<RadixTheme>),
+                    update_theme_tokens.run_if(resource_changed::<RadixTheme>),
+                    update_density_tokens.run_if(resource_changed::<UiDensity>),
+                    apply_deferred_theme_styles
+                        .after(update_theme_tokens)
+                        .after(update_density_tokens),
+                    update_active_breakpoint,
+                    refresh_elevation_shadows,
+                    apply_name_prefix,
+                    find_low_contrast_text
+                        .pipe(warn_low_contrast_text)
+                        .run_if(warns_low_contrast),
+                ),
+            )
             // Add all plugin systems
             .add_plugins((AssetsPlugin, ComponentsPlugin, audio::plugin));
 
@@ -41,3 +120,52 @@ impl Plugin for ForgeUiPlugin {
         );
     }
 }
+
+fn skips_asset_loading(config: Res<UiConfig>) -> bool {
+    config.skip_asset_loading
+}
+
+fn warns_low_contrast(config: Res<UiConfig>) -> bool {
+    config.warn_low_contrast
+}
+
+fn loads_settings(config: Res<UiConfig>) -> bool {
+    config.settings_path.is_some()
+}
+
+/// Inserts placeholder fonts and skips the loading states.
+fn insert_placeholder_assets(mut commands: Commands, mut next_state: ResMut<NextState<UiState>>) {
+    commands.insert_resource(FontAssets::default());
+    next_state.set(UiState::Ready);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::text::{Text, TextFontInfo},
+        test_support::headless_app,
+    };
+
+    #[test]
+    fn test_headless_app_is_ready_within_two_frames() {
+        let mut app = headless_app();
+        app.update();
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<State<UiState>>().get(),
+            UiState::Ready
+        );
+    }
+
+    #[test]
+    fn test_texts_get_placeholder_fonts() {
+        let mut app = headless_app();
+        app.update();
+        let text = app.world_mut().spawn(Text::body("Hello").build()).id();
+        app.update();
+
+        assert!(app.world().get::<TextFontInfo>(text).is_none());
+    }
+}
@@ -0,0 +1,175 @@
+//! Headless [`App`] for testing components with the whole [`ForgeUiPlugin`].
+//!
+//! Available to the crate's own tests and, with the `test-util` feature, to
+//! downstream crates. [`test_app`] builds an app without window, renderer or
+//! audio device, skips font loading with [`UiConfig::skip_asset_loading`]
+//! (placeholder fonts), and is already in [`UiState::Ready`]. Time advances
+//! by [`FRAME`] per update, so timers and animations behave the same on
+//! every run.
+//!
+//! Each [`App::update`] is one frame; [`advance_frames`] runs several.
+//! Pointer input is simulated by setting [`Interaction`], which drives the
+//! components reacting to `Changed<Interaction>` (checkboxes, radios, links,
+//! …). `Pointer<…>` observers need real picking and don't fire from it;
+//! [`pointer_click`] triggers a click on them directly. Events are read back
+//! with [`drain_events`]:
+//!
+//! ```rust
+//! let mut app = test_app();
+//! let checkbox = app.world_mut().spawn(CheckboxBuilder::new("Terms").build()).id();
+//! advance_frames(&mut app, 1);
+//!
+//! click(&mut app, checkbox);
+//! let events = drain_events::<CheckboxChangeEvent>(&mut app);
+//! assert!(events[0].checked);
+//! ```
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::{
+    audio::AudioSource,
+    input::InputPlugin,
+    render::camera::{ManualTextureViewHandle, NormalizedRenderTarget},
+    state::app::StatesPlugin,
+    time::TimeUpdateStrategy,
+    window::WindowPlugin,
+};
+use bevy_picking::{
+    backend::HitData,
+    events::{Click, Pointer},
+    pointer::{Location, PointerButton, PointerId},
+    DefaultPickingPlugins,
+};
+
+use crate::plugin::{ForgeUiPlugin, UiConfig, UiState};
+
+/// Time that passes in one update of a [`test_app`]
+pub const FRAME: Duration = Duration::from_millis(16);
+
+/// Headless app with [`ForgeUiPlugin`] that hasn't run yet; it reaches
+/// [`UiState::Ready`] in its first update.
+pub fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        StatesPlugin,
+        AssetPlugin::default(),
+        InputPlugin,
+        WindowPlugin {
+            primary_window: None,
+            ..default()
+        },
+        TransformPlugin,
+        DefaultPickingPlugins,
+    ))
+    .init_asset::<Font>()
+    .init_asset::<Image>()
+    .init_asset::<TextureAtlasLayout>()
+    .init_asset::<AudioSource>()
+    .init_resource::<GlobalVolume>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+    .insert_resource(UiConfig {
+        skip_asset_loading: true,
+        ..default()
+    })
+    .add_plugins(ForgeUiPlugin);
+    app
+}
+
+/// Headless app with [`ForgeUiPlugin`], in [`UiState::Ready`].
+pub fn test_app() -> App {
+    let mut app = headless_app();
+    app.update();
+    app
+}
+
+/// Runs `frames` updates.
+pub fn advance_frames(app: &mut App, frames: u32) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+/// Sets the [`Interaction`] of `entity` and runs one update.
+pub fn set_interaction(app: &mut App, entity: Entity, interaction: Interaction) {
+    app.world_mut().entity_mut(entity).insert(interaction);
+    app.update();
+}
+
+/// Moves the pointer onto `entity`.
+pub fn hover(app: &mut App, entity: Entity) {
+    set_interaction(app, entity, Interaction::Hovered);
+}
+
+/// Hovers, presses and releases `entity`, one frame each.
+pub fn click(app: &mut App, entity: Entity) {
+    hover(app, entity);
+    set_interaction(app, entity, Interaction::Pressed);
+    set_interaction(app, entity, Interaction::Hovered);
+}
+
+/// Triggers a primary mouse [`Pointer<Click>`] on `entity`, as picking would
+/// after a press and release on it, and runs one update.
+pub fn pointer_click(app: &mut App, entity: Entity) {
+    let location = Location {
+        target: NormalizedRenderTarget::TextureView(ManualTextureViewHandle(0)),
+        position: Vec2::ZERO,
+    };
+    let click = Click {
+        button: PointerButton::Primary,
+        hit: HitData::new(entity, 0.0, None, None),
+        duration: Duration::ZERO,
+    };
+    app.world_mut().trigger_targets(
+        Pointer::new(PointerId::Mouse, location, entity, click),
+        entity,
+    );
+    app.update();
+}
+
+/// Takes all pending `E` events, oldest first.
+pub fn drain_events<E: Event>(app: &mut App) -> Vec<E> {
+    app.world_mut()
+        .resource_mut::<Events<E>>()
+        .drain()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::checkbox::{CheckboxBuilder, CheckboxChangeEvent, CheckboxComponent};
+
+    #[test]
+    fn test_app_starts_ready() {
+        let app = test_app();
+        assert_eq!(
+            *app.world().resource::<State<UiState>>().get(),
+            UiState::Ready
+        );
+    }
+
+    #[test]
+    fn test_click_toggles_checkbox() {
+        let mut app = test_app();
+        let checkbox = app
+            .world_mut()
+            .spawn(CheckboxBuilder::new("Terms").build())
+            .id();
+        advance_frames(&mut app, 1);
+
+        click(&mut app, checkbox);
+
+        let events = drain_events::<CheckboxChangeEvent>(&mut app);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].checkbox_entity, checkbox);
+        assert!(events[0].checked);
+        assert!(
+            app.world()
+                .get::<CheckboxComponent>(checkbox)
+                .unwrap()
+                .checked
+        );
+    }
+}
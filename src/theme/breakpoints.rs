@@ -0,0 +1,192 @@
+//! Responsive breakpoints.
+//!
+//! [`Breakpoints`] holds the minimum window widths of the Radix breakpoints and
+//! [`ActiveBreakpoint`] the one matching the primary window, recomputed when
+//! the window is resized. User systems can branch on `Res<ActiveBreakpoint>`.
+//!
+//! Builders accept [`Responsive`] values, mobile-first lists of
+//! `(Breakpoint, value)` pairs; components carrying them are updated whenever
+//! the active breakpoint changes.
+//!
+//! ```rust
+//! use ui::{
+//!     components::text::Text,
+//!     theme::{breakpoints::Breakpoint, typography::TextSize},
+//! };
+//!
+//! let title = Text::new("Dashboard")
+//!     .size_responsive([(Breakpoint::Base, TextSize::Lg), (Breakpoint::Md, TextSize::X2l)])
+//!     .build();
+//! ```
+
+use bevy::{prelude::*, window::PrimaryWindow, window::WindowResized};
+
+/// Named window width ranges, from narrowest to widest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Breakpoint {
+    /// Any width below [`Breakpoint::Xs`]
+    #[default]
+    Base,
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+/// Minimum logical window width (in px) at which each breakpoint starts.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct Breakpoints {
+    pub xs: f32,
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+    pub xl: f32,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            xs: 520.0,
+            sm: 768.0,
+            md: 1024.0,
+            lg: 1280.0,
+            xl: 1640.0,
+        }
+    }
+}
+
+impl Breakpoints {
+    pub fn min_width(&self, breakpoint: Breakpoint) -> f32 {
+        match breakpoint {
+            Breakpoint::Base => 0.0,
+            Breakpoint::Xs => self.xs,
+            Breakpoint::Sm => self.sm,
+            Breakpoint::Md => self.md,
+            Breakpoint::Lg => self.lg,
+            Breakpoint::Xl => self.xl,
+        }
+    }
+
+    /// The widest breakpoint whose minimum width is at most `width`; a width
+    /// exactly on a boundary belongs to the wider breakpoint.
+    pub fn breakpoint_for_width(&self, width: f32) -> Breakpoint {
+        [
+            Breakpoint::Xl,
+            Breakpoint::Lg,
+            Breakpoint::Md,
+            Breakpoint::Sm,
+            Breakpoint::Xs,
+        ]
+        .into_iter()
+        .find(|breakpoint| width >= self.min_width(*breakpoint))
+        .unwrap_or(Breakpoint::Base)
+    }
+}
+
+/// Breakpoint matching the current width of the primary window.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Deref)]
+pub struct ActiveBreakpoint(pub Breakpoint);
+
+/// A value that depends on the active breakpoint.
+///
+/// Each entry applies from its breakpoint upwards until a wider entry takes
+/// over. Below the narrowest entry, that entry's value is used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Responsive<T> {
+    values: Vec<(Breakpoint, T)>,
+}
+
+impl<T> Default for Responsive<T> {
+    fn default() -> Self {
+        Self { values: Vec::new() }
+    }
+}
+
+impl<T: Clone> Responsive<T> {
+    pub fn new(values: impl IntoIterator<Item = (Breakpoint, T)>) -> Self {
+        let mut values: Vec<_> = values.into_iter().collect();
+        values.sort_by_key(|(breakpoint, _)| *breakpoint);
+        Self { values }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Value for `active`, or `None` if no values were given.
+    pub fn resolve(&self, active: Breakpoint) -> Option<T> {
+        self.values
+            .iter()
+            .rev()
+            .find(|(breakpoint, _)| *breakpoint <= active)
+            .or_else(|| self.values.first())
+            .map(|(_, value)| value.clone())
+    }
+}
+
+impl<T: Clone, const N: usize> From<[(Breakpoint, T); N]> for Responsive<T> {
+    fn from(values: [(Breakpoint, T); N]) -> Self {
+        Self::new(values)
+    }
+}
+
+impl<T: Clone> From<Vec<(Breakpoint, T)>> for Responsive<T> {
+    fn from(values: Vec<(Breakpoint, T)>) -> Self {
+        Self::new(values)
+    }
+}
+
+/// Recomputes [`ActiveBreakpoint`] when the primary window is resized or the
+/// [`Breakpoints`] changed.
+pub fn update_active_breakpoint(
+    mut resize_events: EventReader<WindowResized>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    breakpoints: Res<Breakpoints>,
+    mut active: ResMut<ActiveBreakpoint>,
+) {
+    let resized = resize_events.read().count() > 0;
+    if !resized && !breakpoints.is_changed() {
+        return;
+    }
+    let Ok(window) = windows.single() else {
+        return;
+    };
+
+    active.set_if_neq(ActiveBreakpoint(
+        breakpoints.breakpoint_for_width(window.width()),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boundary_widths_select_wider_breakpoint() {
+        let breakpoints = Breakpoints::default();
+        assert_eq!(breakpoints.breakpoint_for_width(0.0), Breakpoint::Base);
+        assert_eq!(breakpoints.breakpoint_for_width(519.9), Breakpoint::Base);
+        assert_eq!(breakpoints.breakpoint_for_width(520.0), Breakpoint::Xs);
+        assert_eq!(breakpoints.breakpoint_for_width(767.9), Breakpoint::Xs);
+        assert_eq!(breakpoints.breakpoint_for_width(768.0), Breakpoint::Sm);
+        assert_eq!(breakpoints.breakpoint_for_width(1024.0), Breakpoint::Md);
+        assert_eq!(breakpoints.breakpoint_for_width(1279.9), Breakpoint::Md);
+        assert_eq!(breakpoints.breakpoint_for_width(1280.0), Breakpoint::Lg);
+        assert_eq!(breakpoints.breakpoint_for_width(1640.0), Breakpoint::Xl);
+        assert_eq!(breakpoints.breakpoint_for_width(4000.0), Breakpoint::Xl);
+    }
+
+    #[test]
+    fn test_responsive_value_applies_upwards() {
+        let columns = Responsive::from([(Breakpoint::Lg, 4), (Breakpoint::Sm, 2)]);
+        // Below the narrowest entry, the narrowest value is used
+        assert_eq!(columns.resolve(Breakpoint::Base), Some(2));
+        assert_eq!(columns.resolve(Breakpoint::Sm), Some(2));
+        assert_eq!(columns.resolve(Breakpoint::Md), Some(2));
+        assert_eq!(columns.resolve(Breakpoint::Lg), Some(4));
+        assert_eq!(columns.resolve(Breakpoint::Xl), Some(4));
+
+        assert_eq!(Responsive::<u16>::default().resolve(Breakpoint::Md), None);
+    }
+}
@@ -192,9 +192,8 @@ pub fn accent_palette() -> UiColorPalette {
 /// 
 /// # Fallback Behavior
 /// 
-/// If an unrecognized palette or `None` is provided, the system falls back
-/// to the default Indigo palette, ensuring the application never enters
-/// an invalid state.
+/// If `None` is provided, the system falls back to the default Indigo
+/// palette, ensuring the application never enters an invalid state.
 /// 
 /// # Thread Safety
 /// 
@@ -226,83 +225,27 @@ pub fn accent_palette() -> UiColorPalette {
 /// - Consider batching multiple theming changes together
 /// - The write lock briefly blocks all palette access
 pub fn set_accent_palette(palette: Option<UiColorPalettesName>) {
-    *ACCENT_PALETTE.write().unwrap() = if theme_mode() == ThemeMode::Dark {
-        match palette {
-            // === Neutral Color Palettes ===
-            Some(UiColorPalettesName::Gray) => UiColorPalettes::dark_mode().gray,
-            Some(UiColorPalettesName::Mauve) => UiColorPalettes::dark_mode().mauve,
-            Some(UiColorPalettesName::Slate) => UiColorPalettes::dark_mode().slate,
-            Some(UiColorPalettesName::Sage) => UiColorPalettes::dark_mode().sage,
-            Some(UiColorPalettesName::Olive) => UiColorPalettes::dark_mode().olive,
-            Some(UiColorPalettesName::Sand) => UiColorPalettes::dark_mode().sand,
-            
-            // === Red Family Palettes ===
-            Some(UiColorPalettesName::Tomato) => UiColorPalettes::dark_mode().tomato,
-            Some(UiColorPalettesName::Red) => UiColorPalettes::dark_mode().red,
-            Some(UiColorPalettesName::Ruby) => UiColorPalettes::dark_mode().ruby,
-            Some(UiColorPalettesName::Crimson) => UiColorPalettes::dark_mode().crimson,
-            
-            // === Pink/Purple Family Palettes ===
-            Some(UiColorPalettesName::Pink) => UiColorPalettes::dark_mode().pink,
-            Some(UiColorPalettesName::Plum) => UiColorPalettes::dark_mode().plum,
-            Some(UiColorPalettesName::Purple) => UiColorPalettes::dark_mode().purple,
-            Some(UiColorPalettesName::Violet) => UiColorPalettes::dark_mode().violet,
-            Some(UiColorPalettesName::Iris) => UiColorPalettes::dark_mode().iris,
-            
-            // === Blue Family Palettes ===
-            Some(UiColorPalettesName::Indigo) => UiColorPalettes::dark_mode().indigo,
-            Some(UiColorPalettesName::Blue) => UiColorPalettes::dark_mode().blue,
-            Some(UiColorPalettesName::Cyan) => UiColorPalettes::dark_mode().cyan,
-            
-            // === Green Family Palettes ===
-            Some(UiColorPalettesName::Teal) => UiColorPalettes::dark_mode().teal,
-            Some(UiColorPalettesName::Jade) => UiColorPalettes::dark_mode().jade,
-            Some(UiColorPalettesName::Green) => UiColorPalettes::dark_mode().green,
-            
-            // === Fallback to Default ===
-            _ => {
-                // Default to indigo for unrecognized palettes or None
-                UiColorPalettes::dark_mode().indigo
-            }
-        }
+    let palettes = if theme_mode() == ThemeMode::Dark {
+        UiColorPalettes::dark_mode()
     } else {
-        match palette {
-            // === Neutral Color Palettes ===
-            Some(UiColorPalettesName::Gray) => UiColorPalettes::light_mode().gray,
-            Some(UiColorPalettesName::Mauve) => UiColorPalettes::light_mode().mauve,
-            Some(UiColorPalettesName::Slate) => UiColorPalettes::light_mode().slate,
-            Some(UiColorPalettesName::Sage) => UiColorPalettes::light_mode().sage,
-            Some(UiColorPalettesName::Olive) => UiColorPalettes::light_mode().olive,
-            Some(UiColorPalettesName::Sand) => UiColorPalettes::light_mode().sand,
-            
-            // === Red Family Palettes ===
-            Some(UiColorPalettesName::Tomato) => UiColorPalettes::light_mode().tomato,
-            Some(UiColorPalettesName::Red) => UiColorPalettes::light_mode().red,
-            Some(UiColorPalettesName::Ruby) => UiColorPalettes::light_mode().ruby,
-            Some(UiColorPalettesName::Crimson) => UiColorPalettes::light_mode().crimson,
-            
-            // === Pink/Purple Family Palettes ===
-            Some(UiColorPalettesName::Pink) => UiColorPalettes::light_mode().pink,
-            Some(UiColorPalettesName::Plum) => UiColorPalettes::light_mode().plum,
-            Some(UiColorPalettesName::Purple) => UiColorPalettes::light_mode().purple,
-            Some(UiColorPalettesName::Violet) => UiColorPalettes::light_mode().violet,
-            Some(UiColorPalettesName::Iris) => UiColorPalettes::light_mode().iris,
-            
-            // === Blue Family Palettes ===
-            Some(UiColorPalettesName::Indigo) => UiColorPalettes::light_mode().indigo,
-            Some(UiColorPalettesName::Blue) => UiColorPalettes::light_mode().blue,
-            Some(UiColorPalettesName::Cyan) => UiColorPalettes::light_mode().cyan,
-            
-            // === Green Family Palettes ===
-            Some(UiColorPalettesName::Teal) => UiColorPalettes::light_mode().teal,
-            Some(UiColorPalettesName::Jade) => UiColorPalettes::light_mode().jade,
-            Some(UiColorPalettesName::Green) => UiColorPalettes::light_mode().green,
-            
-            // === Fallback to Default ===
-            _ => {
-                // Default to indigo for unrecognized palettes or None
-                UiColorPalettes::light_mode().indigo
-            }
-        }
-    }
+        UiColorPalettes::light_mode()
+    };
+    let name = palette.unwrap_or(UiColorPalettesName::Indigo);
+    *ACCENT_PALETTE.write().unwrap() = palettes.palette(name).clone();
+}
+
+/// Sets the global accent color palette to a custom palette.
+///
+/// Unlike [`set_accent_palette`] the palette is used as is, so it has to
+/// match the current theme mode, e.g. one made by
+/// [`UiColorPalette::generate`] with [`theme_mode()`].
+///
+/// # Example
+///
+/// ```rust
+/// let brand = Color::srgb_u8(0xE0, 0x5A, 0x2B);
+/// set_custom_accent_palette(UiColorPalette::generate(brand, theme_mode()));
+/// ```
+pub fn set_custom_accent_palette(palette: UiColorPalette) {
+    *ACCENT_PALETTE.write().unwrap() = palette;
 }
@@ -0,0 +1,27 @@
+//! Shared colors of disabled controls.
+//!
+//! Disabled controls keep the hues of their palette but fade them to
+//! [`DISABLED_ALPHA`], so buttons, checkboxes, radios, switches, toggles and
+//! selects all look disabled the same way. Neutral parts use
+//! [`disabled_colors`]; parts that stay in a variant color, like the fill of a
+//! checked switch, go through [`disabled_color`].
+
+use super::UiColorPalette;
+use bevy::prelude::*;
+
+/// Opacity of the colors of a disabled control
+pub const DISABLED_ALPHA: f32 = 0.5;
+
+/// `color` faded like a disabled control; transparent colors stay transparent.
+pub fn disabled_color(color: Color) -> Color {
+    color.with_alpha(color.alpha() * DISABLED_ALPHA)
+}
+
+/// Background, border and text color of a disabled control in `palette`.
+pub fn disabled_colors(palette: &UiColorPalette) -> (Color, Color, Color) {
+    (
+        disabled_color(palette.bg_subtle),
+        disabled_color(palette.border),
+        disabled_color(palette.text),
+    )
+}
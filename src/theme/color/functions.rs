@@ -35,6 +35,7 @@
 //! - **Batch Processing**: Efficient for processing multiple color combinations
 
 use super::{TextContrastLevel, UiColorPalette};
+use crate::utilities::contrast;
 use bevy::prelude::*;
 
 impl UiColorPalette {
@@ -88,23 +89,7 @@ impl UiColorPalette {
     /// - Suitable for accessibility compliance validation
     /// - Results match professional color analysis tools
     pub fn calculate_luminance(color: &Color) -> f32 {
-        let srgba = color.to_srgba();
-
-        // Gamma correction for sRGB color space
-        let gamma_correct = |c: f32| {
-            if c <= 0.03928 {
-                c / 12.92
-            } else {
-                ((c + 0.055) / 1.055).powf(2.4)
-            }
-        };
-
-        let r = gamma_correct(srgba.red);
-        let g = gamma_correct(srgba.green);
-        let b = gamma_correct(srgba.blue);
-
-        // Relative luminance according to WCAG 2.1
-        0.2126 * r + 0.7152 * g + 0.0722 * b
+        contrast::relative_luminance(*color)
     }
 
     /// Calculate contrast ratio between two colors according to WCAG standards
@@ -165,13 +150,7 @@ impl UiColorPalette {
     /// - Theme color validation
     /// - Design system color approval workflows
     pub fn calculate_contrast_ratio(color1: &Color, color2: &Color) -> f32 {
-        let lum1 = Self::calculate_luminance(color1);
-        let lum2 = Self::calculate_luminance(color2);
-
-        let lighter = lum1.max(lum2);
-        let darker = lum1.min(lum2);
-
-        (lighter + 0.05) / (darker + 0.05)
+        contrast::contrast_ratio(*color1, *color2)
     }
 
     /// Automatically select the optimal text color for a given background
@@ -0,0 +1,268 @@
+//! Generation of a full color palette from a single base color.
+//!
+//! [`UiColorPalette::generate`] derives a Radix-like 12-step scale from one
+//! brand color, so an accent doesn't have to be authored step by step. The
+//! scale is built in Oklch, where lightness is perceptually even:
+//!
+//! - **Hue** is the base color's hue on every step.
+//! - **Lightness** follows a fixed curve per theme mode: from near white down
+//!   to dark text in light mode, from near black up to light text in dark mode.
+//!   The solid steps 9 and 10 take the base color's lightness, clamped between
+//!   steps 8 and 11 so the scale always runs in one direction.
+//! - **Chroma** ramps up from almost gray backgrounds to the full base chroma
+//!   on the solid steps, and eases off again for the text steps. Colors outside
+//!   the sRGB gamut lose chroma until they fit, keeping their lightness.
+//!
+//! Alpha variants reproduce the opaque steps when drawn over white (light
+//! mode) or black (dark mode).
+//!
+//! ```rust
+//! let brand = Color::srgb_u8(0xE0, 0x5A, 0x2B);
+//! let palette = UiColorPalette::generate(brand, theme_mode());
+//! set_custom_accent_palette(palette);
+//! ```
+
+use super::{structs::UiColorPalette, theme_mode::ThemeMode};
+use bevy::prelude::*;
+
+/// Oklch lightness of steps 1–12 in light mode; steps 9 and 10 are replaced
+/// by the base color's lightness.
+const LIGHT_LIGHTNESS: [f32; 12] = [
+    0.993, 0.982, 0.958, 0.929, 0.897, 0.859, 0.808, 0.741, 0.640, 0.600, 0.520, 0.320,
+];
+
+/// Oklch lightness of steps 1–12 in dark mode; steps 9 and 10 are replaced by
+/// the base color's lightness.
+const DARK_LIGHTNESS: [f32; 12] = [
+    0.180, 0.210, 0.260, 0.290, 0.330, 0.370, 0.430, 0.510, 0.620, 0.660, 0.800, 0.930,
+];
+
+/// Share of the base chroma on steps 1–12 in light mode.
+const LIGHT_CHROMA: [f32; 12] = [
+    0.02, 0.06, 0.14, 0.22, 0.30, 0.38, 0.48, 0.62, 1.0, 1.0, 0.85, 0.45,
+];
+
+/// Share of the base chroma on steps 1–12 in dark mode.
+const DARK_CHROMA: [f32; 12] = [
+    0.10, 0.14, 0.28, 0.40, 0.48, 0.55, 0.62, 0.75, 1.0, 1.0, 0.85, 0.40,
+];
+
+/// Lightness kept between neighbouring steps when placing the solid steps.
+const SOLID_MARGIN: f32 = 0.02;
+
+/// Lightness change from the solid step to its hover step.
+const SOLID_HOVER_SHIFT: f32 = 0.03;
+
+/// Solid steps lighter than this get dark instead of white text.
+const LIGHT_SOLID_LIGHTNESS: f32 = 0.7;
+
+/// Opacity of the translucent surface color.
+const SURFACE_ALPHA: f32 = 0.8;
+
+impl UiColorPalette {
+    /// Derives a full palette for `mode` from a single base color.
+    ///
+    /// The base color becomes the solid step 9 (its lightness clamped to keep
+    /// the scale ordered); see the module docs for the curves used.
+    pub fn generate(base: Color, mode: ThemeMode) -> UiColorPalette {
+        let base = Oklcha::from(base);
+        let (mut lightness, chroma, backdrop) = match mode {
+            ThemeMode::Light => (LIGHT_LIGHTNESS, LIGHT_CHROMA, Srgba::WHITE),
+            ThemeMode::Dark => (DARK_LIGHTNESS, DARK_CHROMA, Srgba::BLACK),
+        };
+
+        // Keep the solid steps strictly between the border and text steps
+        let (low, high) = if lightness[7] > lightness[10] {
+            (lightness[10], lightness[7])
+        } else {
+            (lightness[7], lightness[10])
+        };
+        let solid = base
+            .lightness
+            .clamp(low + 2.0 * SOLID_MARGIN, high - 2.0 * SOLID_MARGIN);
+        let hover_shift = match mode {
+            ThemeMode::Light => -SOLID_HOVER_SHIFT,
+            ThemeMode::Dark => SOLID_HOVER_SHIFT,
+        };
+        lightness[8] = solid;
+        lightness[9] = (solid + hover_shift).clamp(low + SOLID_MARGIN, high - SOLID_MARGIN);
+
+        let steps: [Srgba; 12] =
+            std::array::from_fn(|i| in_gamut(lightness[i], base.chroma * chroma[i], base.hue));
+        let alpha = steps.map(|step| Color::from(alpha_over(step, backdrop)));
+        let opaque = steps.map(Color::from);
+
+        UiColorPalette {
+            base: opaque[0],
+            bg_subtle: opaque[1],
+            bg: opaque[2],
+            bg_hover: opaque[3],
+            bg_active: opaque[4],
+            line: opaque[5],
+            border: opaque[6],
+            border_hover: opaque[7],
+            solid: opaque[8],
+            solid_hover: opaque[9],
+            text: opaque[10],
+            text_contrast: opaque[11],
+            high_contrast: if solid > LIGHT_SOLID_LIGHTNESS {
+                Color::from(in_gamut(
+                    LIGHT_LIGHTNESS[11],
+                    base.chroma * LIGHT_CHROMA[11],
+                    base.hue,
+                ))
+            } else {
+                Color::WHITE
+            },
+            surface: opaque[1].with_alpha(SURFACE_ALPHA),
+            indicator: opaque[8],
+            track: opaque[8],
+            base_a: alpha[0],
+            bg_subtle_a: alpha[1],
+            bg_a: alpha[2],
+            bg_hover_a: alpha[3],
+            bg_active_a: alpha[4],
+            line_a: alpha[5],
+            border_a: alpha[6],
+            border_hover_a: alpha[7],
+            solid_a: alpha[8],
+            solid_hover_a: alpha[9],
+            text_a: alpha[10],
+            text_contrast_a: alpha[11],
+        }
+    }
+}
+
+/// sRGB color of the given Oklch coordinates, with chroma reduced until the
+/// color fits the sRGB gamut.
+fn in_gamut(lightness: f32, chroma: f32, hue: f32) -> Srgba {
+    let fits = |c: f32| {
+        let srgb = Srgba::from(Oklcha::new(lightness, c, hue, 1.0));
+        [srgb.red, srgb.green, srgb.blue]
+            .iter()
+            .all(|channel| (-0.001..=1.001).contains(channel))
+    };
+
+    let mut chroma = chroma;
+    while chroma > 0.0005 && !fits(chroma) {
+        chroma *= 0.95;
+    }
+    let srgb = Srgba::from(Oklcha::new(lightness, chroma, hue, 1.0));
+    Srgba::new(
+        srgb.red.clamp(0.0, 1.0),
+        srgb.green.clamp(0.0, 1.0),
+        srgb.blue.clamp(0.0, 1.0),
+        1.0,
+    )
+}
+
+/// Most transparent color that looks like `target` when drawn over `backdrop`.
+fn alpha_over(target: Srgba, backdrop: Srgba) -> Srgba {
+    let channels = [
+        (target.red, backdrop.red),
+        (target.green, backdrop.green),
+        (target.blue, backdrop.blue),
+    ];
+    let alpha = channels
+        .iter()
+        .map(|&(target, backdrop)| {
+            if target > backdrop {
+                (target - backdrop) / (1.0 - backdrop)
+            } else if target < backdrop {
+                (backdrop - target) / backdrop
+            } else {
+                0.0
+            }
+        })
+        .fold(0.0_f32, f32::max)
+        .clamp(0.0, 1.0);
+    if alpha <= 0.0 {
+        return backdrop.with_alpha(0.0);
+    }
+
+    let solve =
+        |target: f32, backdrop: f32| (backdrop + (target - backdrop) / alpha).clamp(0.0, 1.0);
+    Srgba::new(
+        solve(target.red, backdrop.red),
+        solve(target.green, backdrop.green),
+        solve(target.blue, backdrop.blue),
+        alpha,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lightness(palette: &UiColorPalette) -> Vec<f32> {
+        (1..=12)
+            .map(|n| Oklcha::from(palette.step(n)).lightness)
+            .collect()
+    }
+
+    fn bases() -> [Color; 4] {
+        [
+            Color::srgb(0.0, 0.5647059, 1.0),
+            Color::srgb_u8(0xE0, 0x5A, 0x2B),
+            // Very light and very dark bases get their solid step clamped
+            Color::srgb_u8(0xFF, 0xE6, 0x29),
+            Color::srgb_u8(0x1A, 0x1F, 0x3A),
+        ]
+    }
+
+    #[test]
+    fn test_light_lightness_decreases_across_steps() {
+        for base in bases() {
+            let steps = lightness(&UiColorPalette::generate(base, ThemeMode::Light));
+            for pair in steps.windows(2) {
+                assert!(pair[0] > pair[1], "{base:?}: {steps:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_dark_lightness_increases_across_steps() {
+        for base in bases() {
+            let steps = lightness(&UiColorPalette::generate(base, ThemeMode::Dark));
+            for pair in steps.windows(2) {
+                assert!(pair[0] < pair[1], "{base:?}: {steps:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_solid_step_keeps_base_color() {
+        let base = Color::srgb(0.0, 0.5647059, 1.0);
+        let solid = UiColorPalette::generate(base, ThemeMode::Light)
+            .solid
+            .to_srgba();
+        let expected = base.to_srgba();
+
+        assert!((solid.red - expected.red).abs() < 0.01);
+        assert!((solid.green - expected.green).abs() < 0.01);
+        assert!((solid.blue - expected.blue).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_light_and_dark_variants_differ() {
+        let base = Color::srgb_u8(0xE0, 0x5A, 0x2B);
+        let light = UiColorPalette::generate(base, ThemeMode::Light);
+        let dark = UiColorPalette::generate(base, ThemeMode::Dark);
+
+        assert_ne!(light.base, dark.base);
+        assert_ne!(light.text_contrast, dark.text_contrast);
+    }
+
+    #[test]
+    fn test_alpha_steps_match_opaque_steps_over_backdrop() {
+        let palette = UiColorPalette::generate(Color::srgb_u8(0xE0, 0x5A, 0x2B), ThemeMode::Light);
+        let opaque = palette.bg_active.to_srgba();
+        let alpha = palette.bg_active_a.to_srgba();
+        let composite = |channel: f32| channel * alpha.alpha + (1.0 - alpha.alpha);
+
+        assert!(alpha.alpha < 1.0);
+        assert!((composite(alpha.red) - opaque.red).abs() < 0.01);
+        assert!((composite(alpha.green) - opaque.green).abs() < 0.01);
+        assert!((composite(alpha.blue) - opaque.blue).abs() < 0.01);
+    }
+}
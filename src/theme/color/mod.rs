@@ -1,7 +1,9 @@
 mod accent;
 mod dark_theme;
+mod disabled;
 mod error;
 mod functions;
+mod generate;
 mod light_theme;
 mod structs;
 mod success;
@@ -10,6 +12,7 @@ pub mod theme_mode;
 mod warning;
 
 pub use accent::*;
+pub use disabled::*;
 pub use error::*;
 pub use structs::*;
 pub use success::*;
@@ -53,6 +53,7 @@
 //! ```
 
 use bevy::prelude::*;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Complete collection of all available color palettes in the Forge UI system.
 /// 
@@ -268,6 +269,140 @@ pub enum UiColorPalettesName {
     /// Vibrant orange for call-to-action
     Orange,
 }
+
+impl UiColorPalettesName {
+    /// Every palette name, in declaration order
+    pub const ALL: [UiColorPalettesName; 31] = [
+        UiColorPalettesName::Gray,
+        UiColorPalettesName::Mauve,
+        UiColorPalettesName::Slate,
+        UiColorPalettesName::Sage,
+        UiColorPalettesName::Olive,
+        UiColorPalettesName::Sand,
+        UiColorPalettesName::Tomato,
+        UiColorPalettesName::Red,
+        UiColorPalettesName::Ruby,
+        UiColorPalettesName::Crimson,
+        UiColorPalettesName::Pink,
+        UiColorPalettesName::Plum,
+        UiColorPalettesName::Purple,
+        UiColorPalettesName::Violet,
+        UiColorPalettesName::Iris,
+        UiColorPalettesName::Indigo,
+        UiColorPalettesName::Blue,
+        UiColorPalettesName::Cyan,
+        UiColorPalettesName::Teal,
+        UiColorPalettesName::Jade,
+        UiColorPalettesName::Green,
+        UiColorPalettesName::Grass,
+        UiColorPalettesName::Bronze,
+        UiColorPalettesName::Brown,
+        UiColorPalettesName::Gold,
+        UiColorPalettesName::Sky,
+        UiColorPalettesName::Mint,
+        UiColorPalettesName::Lime,
+        UiColorPalettesName::Yellow,
+        UiColorPalettesName::Amber,
+        UiColorPalettesName::Orange,
+    ];
+
+    /// Lowercase name of the palette, e.g. `"indigo"`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UiColorPalettesName::Gray => "gray",
+            UiColorPalettesName::Mauve => "mauve",
+            UiColorPalettesName::Slate => "slate",
+            UiColorPalettesName::Sage => "sage",
+            UiColorPalettesName::Olive => "olive",
+            UiColorPalettesName::Sand => "sand",
+            UiColorPalettesName::Tomato => "tomato",
+            UiColorPalettesName::Red => "red",
+            UiColorPalettesName::Ruby => "ruby",
+            UiColorPalettesName::Crimson => "crimson",
+            UiColorPalettesName::Pink => "pink",
+            UiColorPalettesName::Plum => "plum",
+            UiColorPalettesName::Purple => "purple",
+            UiColorPalettesName::Violet => "violet",
+            UiColorPalettesName::Iris => "iris",
+            UiColorPalettesName::Indigo => "indigo",
+            UiColorPalettesName::Blue => "blue",
+            UiColorPalettesName::Cyan => "cyan",
+            UiColorPalettesName::Teal => "teal",
+            UiColorPalettesName::Jade => "jade",
+            UiColorPalettesName::Green => "green",
+            UiColorPalettesName::Grass => "grass",
+            UiColorPalettesName::Bronze => "bronze",
+            UiColorPalettesName::Brown => "brown",
+            UiColorPalettesName::Gold => "gold",
+            UiColorPalettesName::Sky => "sky",
+            UiColorPalettesName::Mint => "mint",
+            UiColorPalettesName::Lime => "lime",
+            UiColorPalettesName::Yellow => "yellow",
+            UiColorPalettesName::Amber => "amber",
+            UiColorPalettesName::Orange => "orange",
+        }
+    }
+
+    /// Palette name from its lowercase [`as_str`](Self::as_str) name.
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|palette| palette.as_str() == name)
+    }
+}
+
+/// Serializes as the lowercase palette name, e.g. `"slate"`.
+impl Serialize for UiColorPalettesName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for UiColorPalettesName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Self::parse(&value)
+            .ok_or_else(|| de::Error::custom(format!("unknown color palette `{value}`")))
+    }
+}
+
+impl UiColorPalettes {
+    /// Palette called `name`.
+    pub fn palette(&self, name: UiColorPalettesName) -> &UiColorPalette {
+        match name {
+            UiColorPalettesName::Gray => &self.gray,
+            UiColorPalettesName::Mauve => &self.mauve,
+            UiColorPalettesName::Slate => &self.slate,
+            UiColorPalettesName::Sage => &self.sage,
+            UiColorPalettesName::Olive => &self.olive,
+            UiColorPalettesName::Sand => &self.sand,
+            UiColorPalettesName::Tomato => &self.tomato,
+            UiColorPalettesName::Red => &self.red,
+            UiColorPalettesName::Ruby => &self.ruby,
+            UiColorPalettesName::Crimson => &self.crimson,
+            UiColorPalettesName::Pink => &self.pink,
+            UiColorPalettesName::Plum => &self.plum,
+            UiColorPalettesName::Purple => &self.purple,
+            UiColorPalettesName::Violet => &self.violet,
+            UiColorPalettesName::Iris => &self.iris,
+            UiColorPalettesName::Indigo => &self.indigo,
+            UiColorPalettesName::Blue => &self.blue,
+            UiColorPalettesName::Cyan => &self.cyan,
+            UiColorPalettesName::Teal => &self.teal,
+            UiColorPalettesName::Jade => &self.jade,
+            UiColorPalettesName::Green => &self.green,
+            UiColorPalettesName::Grass => &self.grass,
+            UiColorPalettesName::Bronze => &self.bronze,
+            UiColorPalettesName::Brown => &self.brown,
+            UiColorPalettesName::Gold => &self.gold,
+            UiColorPalettesName::Sky => &self.sky,
+            UiColorPalettesName::Mint => &self.mint,
+            UiColorPalettesName::Lime => &self.lime,
+            UiColorPalettesName::Yellow => &self.yellow,
+            UiColorPalettesName::Amber => &self.amber,
+            UiColorPalettesName::Orange => &self.orange,
+        }
+    }
+}
+
 /// Resource wrapper for the complete color palette collection.
 /// 
 /// This newtype wrapper allows UiColorPalettes to be used as a Bevy resource,
@@ -342,7 +477,7 @@ pub struct UiColorPalettesData(pub UiColorPalettes);
 /// let modal_bg = blue.bg_a;          // Semi-transparent background
 /// let overlay_border = blue.border_a; // Semi-transparent border
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Reflect)]
 pub struct UiColorPalette {
     // === Opaque Background Colors ===
     /// Step 1: App background - The main application background color
@@ -412,6 +547,97 @@ pub struct UiColorPalette {
     /// Track elements for sliders, progress bars, and similar components
     pub track: Color,
 }
+
+impl UiColorPalette {
+    /// Color of step `n` (1–12) of the Radix scale.
+    ///
+    /// Out-of-range steps are clamped to 1 or 12, with a warning logged once.
+    ///
+    /// # Example
+    /// ```rust
+    /// let blue = theme().blue;
+    /// assert_eq!(blue.step(9), blue.solid);
+    /// ```
+    pub fn step(&self, n: u8) -> Color {
+        if !(1..=12).contains(&n) {
+            warn_once!("Color scale step {n} is out of range 1..=12 and was clamped");
+        }
+        match n.clamp(1, 12) {
+            1 => self.base,
+            2 => self.bg_subtle,
+            3 => self.bg,
+            4 => self.bg_hover,
+            5 => self.bg_active,
+            6 => self.line,
+            7 => self.border,
+            8 => self.border_hover,
+            9 => self.solid,
+            10 => self.solid_hover,
+            11 => self.text,
+            _ => self.text_contrast,
+        }
+    }
+
+    /// Step 1: app background
+    pub fn step_1(&self) -> Color {
+        self.step(1)
+    }
+
+    /// Step 2: subtle background
+    pub fn step_2(&self) -> Color {
+        self.step(2)
+    }
+
+    /// Step 3: UI element background
+    pub fn step_3(&self) -> Color {
+        self.step(3)
+    }
+
+    /// Step 4: hovered UI element background
+    pub fn step_4(&self) -> Color {
+        self.step(4)
+    }
+
+    /// Step 5: active / selected UI element background
+    pub fn step_5(&self) -> Color {
+        self.step(5)
+    }
+
+    /// Step 6: subtle borders and separators
+    pub fn step_6(&self) -> Color {
+        self.step(6)
+    }
+
+    /// Step 7: UI element border and focus rings
+    pub fn step_7(&self) -> Color {
+        self.step(7)
+    }
+
+    /// Step 8: hovered UI element border
+    pub fn step_8(&self) -> Color {
+        self.step(8)
+    }
+
+    /// Step 9: solid backgrounds
+    pub fn step_9(&self) -> Color {
+        self.step(9)
+    }
+
+    /// Step 10: hovered solid backgrounds
+    pub fn step_10(&self) -> Color {
+        self.step(10)
+    }
+
+    /// Step 11: low-contrast text
+    pub fn step_11(&self) -> Color {
+        self.step(11)
+    }
+
+    /// Step 12: high-contrast text
+    pub fn step_12(&self) -> Color {
+        self.step(12)
+    }
+}
 /// Basic color palette for fundamental colors (white, black).
 /// 
 /// UiColorPaletteBasic provides a simplified color palette structure
@@ -517,3 +743,25 @@ pub enum TextContrastLevel {
     /// **Visual weight**: Maximum, highest priority content
     Accessible,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_matches_semantic_fields() {
+        let blue = UiColorPalettes::light_mode().blue;
+        assert_eq!(blue.step(9), blue.solid);
+        assert_eq!(blue.step_9(), blue.solid);
+        assert_eq!(blue.step(1), blue.base);
+        assert_eq!(blue.step(12), blue.text_contrast);
+    }
+
+    #[test]
+    fn test_out_of_range_steps_clamp() {
+        let gray = UiColorPalettes::dark_mode().gray;
+        assert_eq!(gray.step(0), gray.step(1));
+        assert_eq!(gray.step(13), gray.step(12));
+        assert_eq!(gray.step(u8::MAX), gray.text_contrast);
+    }
+}
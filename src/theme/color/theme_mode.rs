@@ -1,4 +1,5 @@
 use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
 use std::sync::RwLock;
 
 use crate::theme::color::UiColorPalettes;
@@ -78,7 +79,8 @@ use crate::theme::color::UiColorPalettes;
 /// - Write operations panic with same message for consistency
 /// - Poisoning can only occur if a thread panics while holding the lock
 /// - In practice, poisoning is extremely rare for simple enum operations
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ThemeMode {
     /// Light theme for well-lit environments and daytime use
     Light,
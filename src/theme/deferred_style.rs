@@ -0,0 +1,193 @@
+//! Theme levels resolved after spawn.
+//!
+//! Builders run without resource access, so they can't read the active
+//! [`UiLayout`] from [`ThemeTokens`]. Level-based builder methods such as
+//! `pad(SpacingLevel::Base)`, `gap_level(..)` or `rounded()` record the level
+//! in a [`DeferredThemeStyle`] instead; [`apply_deferred_theme_styles`] turns
+//! it into pixels on spawn and again whenever the tokens change, e.g. after
+//! switching the [`UiDensity`](crate::theme::density::UiDensity).
+//!
+//! Unlike the other builder methods, levels don't follow call order: being
+//! resolved last, they take precedence over pixel values set on the same
+//! property, before or after them. `.pad(SpacingLevel::Base)
+//! .padding_x(Val::Px(4.0))` keeps the base padding on all four sides; leave
+//! the level out to set pixel values. Custom builders follow the same
+//! pattern:
+//!
+//! ```rust
+//! impl PanelBuilder {
+//!     pub fn pad(mut self, level: SpacingLevel) -> Self {
+//!         self.deferred = self.deferred.pad(level);
+//!         self
+//!     }
+//!
+//!     pub fn build(self) -> impl Bundle {
+//!         (Name::new(self.name), self.node, self.deferred)
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+
+use crate::{
+    components::box_component::{RadiusLevel, SpacingLevel},
+    theme::{layout::UiLayout, radix_theme::ThemeTokens},
+};
+
+/// Spacing and radius levels of a node, resolved against
+/// [`ThemeTokens::layout`] by [`apply_deferred_theme_styles`].
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeferredThemeStyle {
+    pub padding_top: Option<SpacingLevel>,
+    pub padding_right: Option<SpacingLevel>,
+    pub padding_bottom: Option<SpacingLevel>,
+    pub padding_left: Option<SpacingLevel>,
+    /// Row and column gap between children
+    pub gap: Option<SpacingLevel>,
+    /// Radius of all four corners
+    pub radius: Option<RadiusLevel>,
+}
+
+impl DeferredThemeStyle {
+    pub fn pad(self, level: SpacingLevel) -> Self {
+        self.pad_x(level).pad_y(level)
+    }
+
+    pub fn pad_x(mut self, level: SpacingLevel) -> Self {
+        self.padding_left = Some(level);
+        self.padding_right = Some(level);
+        self
+    }
+
+    pub fn pad_y(mut self, level: SpacingLevel) -> Self {
+        self.padding_top = Some(level);
+        self.padding_bottom = Some(level);
+        self
+    }
+
+    pub fn gap(mut self, level: SpacingLevel) -> Self {
+        self.gap = Some(level);
+        self
+    }
+
+    pub fn radius(mut self, level: RadiusLevel) -> Self {
+        self.radius = Some(level);
+        self
+    }
+
+    /// Whether no level is set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Writes the pixel values of the set levels in `layout` to `node` and
+    /// `radius`; unset properties are left alone.
+    pub fn apply(&self, layout: &UiLayout, node: &mut Node, radius: &mut BorderRadius) {
+        let padding = |level: Option<SpacingLevel>, side: &mut Val| {
+            if let Some(level) = level {
+                *side = level.to_val(&layout.padding);
+            }
+        };
+        padding(self.padding_top, &mut node.padding.top);
+        padding(self.padding_right, &mut node.padding.right);
+        padding(self.padding_bottom, &mut node.padding.bottom);
+        padding(self.padding_left, &mut node.padding.left);
+
+        if let Some(gap) = self.gap {
+            let gap = gap.to_val(&layout.gap);
+            node.row_gap = gap;
+            node.column_gap = gap;
+        }
+        if let Some(level) = self.radius {
+            *radius = BorderRadius::all(level.to_val(&layout.radius));
+        }
+    }
+}
+
+/// Resolves new or changed [`DeferredThemeStyle`]s, and all of them after
+/// the [`ThemeTokens`] changed.
+pub fn apply_deferred_theme_styles(
+    tokens: Res<ThemeTokens>,
+    mut styles: Query<(Ref<DeferredThemeStyle>, &mut Node, &mut BorderRadius)>,
+) {
+    let tokens_changed = tokens.is_changed();
+    for (style, mut node, mut radius) in &mut styles {
+        if style.is_empty() || !(tokens_changed || style.is_changed()) {
+            continue;
+        }
+        style.apply(&tokens.layout, &mut node, &mut radius);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::{box_component::BoxComponent, flex::FlexComponent},
+        test_support::test_app,
+        theme::density::UiDensity,
+    };
+
+    #[test]
+    fn test_box_pad_resolves_to_theme_padding() {
+        let mut app = test_app();
+        let entity = app
+            .world_mut()
+            .spawn(BoxComponent::new("padded").pad(SpacingLevel::Base).build())
+            .id();
+        app.update();
+
+        let expected = app.world().resource::<ThemeTokens>().layout.padding.base;
+        let node = app.world().get::<Node>(entity).unwrap();
+        assert_eq!(node.padding, UiRect::all(Val::Px(expected)));
+    }
+
+    #[test]
+    fn test_levels_win_over_pixel_values_in_any_order() {
+        let mut app = test_app();
+        let entity = app
+            .world_mut()
+            .spawn(
+                BoxComponent::new("padded")
+                    .pad(SpacingLevel::Base)
+                    .padding_x(Val::Px(4.0))
+                    .build(),
+            )
+            .id();
+        app.update();
+
+        let expected = app.world().resource::<ThemeTokens>().layout.padding.base;
+        let node = app.world().get::<Node>(entity).unwrap();
+        assert_eq!(node.padding, UiRect::all(Val::Px(expected)));
+    }
+
+    #[test]
+    fn test_levels_follow_token_changes() {
+        let mut app = test_app();
+        let entity = app
+            .world_mut()
+            .spawn(
+                FlexComponent::row("toolbar")
+                    .gap_level(SpacingLevel::Lg)
+                    .rounded()
+                    .build(),
+            )
+            .id();
+        app.update();
+
+        let layout = app.world().resource::<ThemeTokens>().layout.clone();
+        let node = app.world().get::<Node>(entity).unwrap();
+        assert_eq!(node.column_gap, Val::Px(layout.gap.lg));
+        let radius = app.world().get::<BorderRadius>(entity).unwrap();
+        assert_eq!(radius.top_left, Val::Px(layout.radius.base));
+
+        // Like a density switch, without touching the global density
+        let compact = UiLayout::for_density(&UiDensity::compact());
+        app.world_mut().resource_mut::<ThemeTokens>().layout = compact.clone();
+        app.update();
+
+        let node = app.world().get::<Node>(entity).unwrap();
+        assert_eq!(node.column_gap, Val::Px(compact.gap.lg));
+        assert!(compact.gap.lg < layout.gap.lg);
+    }
+}
@@ -0,0 +1,167 @@
+//! Runtime UI scale and density.
+//!
+//! [`UiDensity`] lets players pick a compact UI or scale the whole UI at
+//! runtime. `scale` multiplies every size (spacing, radii, borders, shadows
+//! and fonts) on top of the compile-time `SCALING`; [`Density`] only shrinks
+//! spacing and control sizes (checkbox and radio boxes, button paddings),
+//! leaving text at its size.
+//!
+//! The resource is mirrored into a global read by [`UiLayout::default`] and
+//! [`FontSize::default`], so every component built afterwards uses the new
+//! values. Already spawned components keep their size; rebuild them (e.g.
+//! respawn the settings screen) after changing the density.
+//!
+//! ```rust
+//! fn use_compact_ui(mut density: ResMut<UiDensity>) {
+//!     density.density = Density::Compact;
+//! }
+//! ```
+
+use std::sync::RwLock;
+
+use bevy::prelude::*;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    plugin::SCALING,
+    theme::{layout::UiLayout, radix_theme::ThemeTokens, typography::FontSize},
+};
+
+/// Share of the comfortable spacing used by the compact density
+pub const COMPACT_SPACING_FACTOR: f32 = 0.75;
+/// Share of the comfortable control size used by the compact density
+pub const COMPACT_CONTROL_FACTOR: f32 = 0.875;
+
+/// How tightly components are packed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Density {
+    #[default]
+    Comfortable,
+    /// Tighter spacing and smaller controls for information-dense screens
+    Compact,
+}
+
+impl Density {
+    /// Multiplier of paddings, margins and gaps.
+    pub fn spacing_factor(self) -> f32 {
+        match self {
+            Density::Comfortable => 1.0,
+            Density::Compact => COMPACT_SPACING_FACTOR,
+        }
+    }
+
+    /// Multiplier of fixed control sizes, e.g. checkbox and radio boxes.
+    pub fn control_factor(self) -> f32 {
+        match self {
+            Density::Comfortable => 1.0,
+            Density::Compact => COMPACT_CONTROL_FACTOR,
+        }
+    }
+}
+
+/// Runtime scale and density of the UI.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct UiDensity {
+    pub density: Density,
+    /// Multiplier of all sizes, on top of the compile-time `SCALING`
+    pub scale: f32,
+}
+
+impl Default for UiDensity {
+    fn default() -> Self {
+        Self {
+            density: Density::Comfortable,
+            scale: 1.0,
+        }
+    }
+}
+
+impl UiDensity {
+    pub fn compact() -> Self {
+        Self {
+            density: Density::Compact,
+            ..default()
+        }
+    }
+
+    /// Multiplier of sizes that only follow the scale: fonts, radii, borders
+    /// and shadows.
+    pub fn scale(&self) -> f32 {
+        SCALING * self.scale
+    }
+
+    /// Multiplier of paddings, margins and gaps.
+    pub fn spacing_scale(&self) -> f32 {
+        self.scale() * self.density.spacing_factor()
+    }
+
+    /// Multiplier of fixed control sizes.
+    pub fn control_scale(&self) -> f32 {
+        self.scale() * self.density.control_factor()
+    }
+}
+
+/// Density used by components built from now on; mirrors the [`UiDensity`]
+/// resource.
+pub static UI_DENSITY: Lazy<RwLock<UiDensity>> = Lazy::new(|| RwLock::new(UiDensity::default()));
+
+/// Current global density.
+pub fn ui_density() -> UiDensity {
+    *UI_DENSITY.read().expect("UI_DENSITY poisoned")
+}
+
+/// Sets the global density; prefer changing the [`UiDensity`] resource.
+pub fn set_ui_density(density: UiDensity) {
+    *UI_DENSITY.write().expect("UI_DENSITY poisoned") = density;
+}
+
+/// Applies a changed [`UiDensity`] globally and recomputes the layout and font
+/// sizes in [`ThemeTokens`].
+pub fn update_density_tokens(density: Res<UiDensity>, mut tokens: ResMut<ThemeTokens>) {
+    set_ui_density(*density);
+    tokens.layout = UiLayout::for_density(&density);
+    tokens.font_size = FontSize::for_density(&density);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_reduces_spacing_and_controls() {
+        let comfortable = UiDensity::default();
+        let compact = UiDensity::compact();
+
+        let comfortable_layout = UiLayout::for_density(&comfortable);
+        let compact_layout = UiLayout::for_density(&compact);
+        assert!(compact_layout.padding.base < comfortable_layout.padding.base);
+        assert!(compact_layout.gap.base < comfortable_layout.gap.base);
+        assert!(compact.control_scale() < comfortable.control_scale());
+        // Density leaves text and corners alone
+        assert_eq!(compact_layout.radius.base, comfortable_layout.radius.base);
+        assert_eq!(
+            FontSize::for_density(&compact).base,
+            FontSize::for_density(&comfortable).base
+        );
+    }
+
+    #[test]
+    fn test_scale_applies_proportionally() {
+        let base = UiLayout::for_density(&UiDensity::default());
+        let scaled = UiDensity {
+            scale: 1.5,
+            ..default()
+        };
+        let layout = UiLayout::for_density(&scaled);
+
+        assert_eq!(layout.padding.base, base.padding.base * 1.5);
+        assert_eq!(layout.radius.base, base.radius.base * 1.5);
+        assert_eq!(layout.border.xs, base.border.xs * 1.5);
+        assert_eq!(
+            FontSize::for_density(&scaled).base,
+            FontSize::for_density(&UiDensity::default()).base * 1.5
+        );
+    }
+}
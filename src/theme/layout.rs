@@ -12,6 +12,7 @@
 //! - **Accessibility Support**: Configurable scaling for visual accessibility needs
 //! - **Design Token System**: Semantic spacing scales with mathematical progression
 //! - **Performance Optimized**: Pre-calculated values for efficient runtime performance
+//! - **Elevation Scale**: Drop shadows for raised surfaces, resolved per theme mode
 //!
 //! # Scaling Architecture
 //!
@@ -59,8 +60,22 @@
 //! - **Memory Efficient**: Minimal struct size with maximum flexibility
 //! - **Cache Friendly**: Predictable access patterns for layout calculations
 
-use crate::plugin::{FONT_SIZE_BASE, SCALING, SPACING_FACTOR};
-use bevy::reflect::Reflect;
+use crate::{
+    plugin::{FONT_SIZE_BASE, SPACING_FACTOR},
+    theme::{
+        color::{theme_mode, ThemeMode},
+        density::{ui_density, UiDensity},
+    },
+};
+use bevy::{
+    color::Color,
+    ecs::{component::Component, system::Query},
+    reflect::Reflect,
+    ui::{BoxShadow, ShadowStyle, Val},
+};
+
+/// Share of the light-mode shadow opacity used in dark mode
+pub const DARK_SHADOW_OPACITY_FACTOR: f32 = 0.5;
 
 /// Master layout configuration with dynamic scaling and comprehensive spacing control
 ///
@@ -75,6 +90,7 @@ use bevy::reflect::Reflect;
 /// - **gap**: Spacing between child elements in flex and grid layouts
 /// - **radius**: Border radius values for corner styling and visual hierarchy
 /// - **border**: Border width values for component styling and emphasis
+/// - **shadow**: Drop shadow values for each elevation level
 ///
 /// # Dynamic Scaling
 ///
@@ -119,8 +135,10 @@ pub struct UiLayout {
     pub gap: UiSpacing,
     /// Border radius values for corner styling (calculated with FONT_SIZE_BASE)
     pub radius: UiRadius,
-    /// Border width values for component emphasis (fixed pixel values)
+    /// Border width values for component emphasis (pixel values following the scale, not the density)
     pub border: UiSpacing,
+    /// Drop shadow values per elevation level (calculated with SCALING)
+    pub shadow: UiShadowScale,
 }
 
 /// Dynamic spacing scale with linear progression and responsive scaling
@@ -308,6 +326,115 @@ pub struct UiRadius {
     pub full: f32,
 }
 
+/// Drop shadow parameters of a single elevation level
+///
+/// Light is assumed to come from above, so shadows only fall downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Default)]
+pub struct UiShadow {
+    /// Vertical offset in pixels
+    pub offset_y: f32,
+    /// Blur radius in pixels
+    pub blur: f32,
+    /// Spread radius in pixels
+    pub spread: f32,
+    /// Shadow opacity in light mode
+    pub opacity: f32,
+}
+
+/// Elevation scale, from barely raised surfaces to floating overlays
+///
+/// Assuming SCALING = 1.0:
+/// - **sm**: 1px offset, 3px blur - Cards resting on the page
+/// - **md**: 4px offset, 8px blur - Raised cards and hover cards
+/// - **lg**: 8px offset, 16px blur - Popovers and menus
+/// - **xl**: 16px offset, 32px blur - Dialogs above everything else
+#[derive(Debug, Clone, Reflect, Default)]
+pub struct UiShadowScale {
+    pub sm: UiShadow,
+    pub md: UiShadow,
+    pub lg: UiShadow,
+    pub xl: UiShadow,
+}
+
+/// Elevation level of a surface, applied as a drop shadow
+///
+/// Builders of boxes, cards and hover cards accept an elevation through
+/// `.elevation()`; [`resolve_shadow`] turns it into a `BoxShadow`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum Elevation {
+    /// Flat, no shadow
+    #[default]
+    None,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+impl UiShadowScale {
+    /// Shadow parameters of `elevation`; `None` for a flat surface
+    pub fn get(&self, elevation: Elevation) -> Option<UiShadow> {
+        match elevation {
+            Elevation::None => None,
+            Elevation::Sm => Some(self.sm),
+            Elevation::Md => Some(self.md),
+            Elevation::Lg => Some(self.lg),
+            Elevation::Xl => Some(self.xl),
+        }
+    }
+}
+
+/// Resolve an elevation into the `BoxShadow` to draw in the given theme mode.
+///
+/// Light mode uses the full shadow opacity of the level; on dark backgrounds
+/// shadows are barely visible and look muddy when strong, so dark mode uses
+/// [`DARK_SHADOW_OPACITY_FACTOR`] of it.
+pub fn resolve_shadow(elevation: Elevation, mode: ThemeMode) -> BoxShadow {
+    let Some(shadow) = UiLayout::default().shadow.get(elevation) else {
+        return BoxShadow(Vec::new());
+    };
+    let opacity = match mode {
+        ThemeMode::Light => shadow.opacity,
+        ThemeMode::Dark => shadow.opacity * DARK_SHADOW_OPACITY_FACTOR,
+    };
+
+    BoxShadow(vec![ShadowStyle {
+        color: Color::srgba(0.0, 0.0, 0.0, opacity),
+        x_offset: Val::ZERO,
+        y_offset: Val::Px(shadow.offset_y),
+        spread_radius: Val::Px(shadow.spread),
+        blur_radius: Val::Px(shadow.blur),
+    }])
+}
+
+/// Elevation of a surface, keeping its `BoxShadow` resolved in the current
+/// theme mode.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ElevationShadow {
+    pub elevation: Elevation,
+    /// Theme mode the shadow was resolved in
+    mode: ThemeMode,
+}
+
+impl ElevationShadow {
+    /// The elevation with its shadow resolved in the current theme mode.
+    pub fn bundle(elevation: Elevation) -> (Self, BoxShadow) {
+        let mode = theme_mode();
+        (Self { elevation, mode }, resolve_shadow(elevation, mode))
+    }
+}
+
+/// System that resolves elevation shadows again after the theme mode changed.
+pub fn refresh_elevation_shadows(mut shadows: Query<(&mut ElevationShadow, &mut BoxShadow)>) {
+    let mode = theme_mode();
+    for (mut elevated, mut shadow) in &mut shadows {
+        if elevated.mode != mode {
+            elevated.mode = mode;
+            *shadow = resolve_shadow(elevated.elevation, mode);
+        }
+    }
+}
+
 impl Default for UiLayout {
     /// Create the default layout configuration with dynamic scaling
     ///
@@ -372,68 +499,182 @@ impl Default for UiLayout {
     /// let emphasis_border = layout.border.lg;   // 5px
     /// ```
     fn default() -> Self {
+        UiLayout::for_density(&ui_density())
+    }
+}
+
+impl UiLayout {
+    /// Layout for `density`; [`UiLayout::default`] uses the current global density.
+    pub fn for_density(density: &UiDensity) -> Self {
+        let spacing = SPACING_FACTOR * density.spacing_scale();
+        let scale = density.scale();
+
         UiLayout {
             // Padding: Internal component spacing with linear progression
             padding: UiSpacing {
-                xs: 1.0 * SPACING_FACTOR * SCALING,   // Minimal internal spacing
-                sm: 2.0 * SPACING_FACTOR * SCALING,   // Compact component padding
-                base: 3.0 * SPACING_FACTOR * SCALING, // Standard component padding
-                lg: 4.0 * SPACING_FACTOR * SCALING,   // Comfortable component padding
-                xl: 5.0 * SPACING_FACTOR * SCALING,   // Generous component padding
-                x2l: 6.0 * SPACING_FACTOR * SCALING,  // Spacious component padding
-                x3l: 7.0 * SPACING_FACTOR * SCALING,  // Hero-level component padding
-                x4l: 8.0 * SPACING_FACTOR * SCALING,  // Maximum component padding
-                x5l: 9.0 * SPACING_FACTOR * SCALING,  // Architectural component padding
+                xs: 1.0 * spacing,   // Minimal internal spacing
+                sm: 2.0 * spacing,   // Compact component padding
+                base: 3.0 * spacing, // Standard component padding
+                lg: 4.0 * spacing,   // Comfortable component padding
+                xl: 5.0 * spacing,   // Generous component padding
+                x2l: 6.0 * spacing,  // Spacious component padding
+                x3l: 7.0 * spacing,  // Hero-level component padding
+                x4l: 8.0 * spacing,  // Maximum component padding
+                x5l: 9.0 * spacing,  // Architectural component padding
             },
             // Margin: External component spacing with linear progression
             margin: UiSpacing {
-                xs: 1.0 * SPACING_FACTOR * SCALING,   // Minimal external spacing
-                sm: 2.0 * SPACING_FACTOR * SCALING,   // Compact component margins
-                base: 3.0 * SPACING_FACTOR * SCALING, // Standard component margins
-                lg: 4.0 * SPACING_FACTOR * SCALING,   // Comfortable separation
-                xl: 5.0 * SPACING_FACTOR * SCALING,   // Significant separation
-                x2l: 6.0 * SPACING_FACTOR * SCALING,  // Major section separation
-                x3l: 7.0 * SPACING_FACTOR * SCALING,  // Prominent layout breaks
-                x4l: 8.0 * SPACING_FACTOR * SCALING,  // Page-level margins
-                x5l: 9.0 * SPACING_FACTOR * SCALING,  // Architectural margins
+                xs: 1.0 * spacing,   // Minimal external spacing
+                sm: 2.0 * spacing,   // Compact component margins
+                base: 3.0 * spacing, // Standard component margins
+                lg: 4.0 * spacing,   // Comfortable separation
+                xl: 5.0 * spacing,   // Significant separation
+                x2l: 6.0 * spacing,  // Major section separation
+                x3l: 7.0 * spacing,  // Prominent layout breaks
+                x4l: 8.0 * spacing,  // Page-level margins
+                x5l: 9.0 * spacing,  // Architectural margins
             },
             // Gap: Child element spacing with linear progression
             gap: UiSpacing {
-                xs: 1.0 * SPACING_FACTOR * SCALING,   // Minimal element gaps
-                sm: 2.0 * SPACING_FACTOR * SCALING,   // Compact layout gaps
-                base: 3.0 * SPACING_FACTOR * SCALING, // Standard layout gaps
-                lg: 4.0 * SPACING_FACTOR * SCALING,   // Comfortable layout gaps
-                xl: 5.0 * SPACING_FACTOR * SCALING,   // Spacious layout gaps
-                x2l: 6.0 * SPACING_FACTOR * SCALING,  // Major layout gaps
-                x3l: 7.0 * SPACING_FACTOR * SCALING,  // Prominent layout gaps
-                x4l: 8.0 * SPACING_FACTOR * SCALING,  // Architectural layout gaps
-                x5l: 9.0 * SPACING_FACTOR * SCALING,  // Maximum layout gaps
+                xs: 1.0 * spacing,   // Minimal element gaps
+                sm: 2.0 * spacing,   // Compact layout gaps
+                base: 3.0 * spacing, // Standard layout gaps
+                lg: 4.0 * spacing,   // Comfortable layout gaps
+                xl: 5.0 * spacing,   // Spacious layout gaps
+                x2l: 6.0 * spacing,  // Major layout gaps
+                x3l: 7.0 * spacing,  // Prominent layout gaps
+                x4l: 8.0 * spacing,  // Architectural layout gaps
+                x5l: 9.0 * spacing,  // Maximum layout gaps
             },
             // Radius: Typography-relative border radius with fractional progression
             radius: UiRadius {
-                none: 0.0,                                  // Sharp corners
-                xs: 0.125 * FONT_SIZE_BASE * SCALING,      // Minimal rounding (1/8 font size)
-                sm: 0.25 * FONT_SIZE_BASE * SCALING,       // Small rounding (1/4 font size)
-                base: 0.375 * FONT_SIZE_BASE * SCALING,    // Standard rounding (3/8 font size)
-                lg: 0.5 * FONT_SIZE_BASE * SCALING,        // Large rounding (1/2 font size)
-                xl: 0.75 * FONT_SIZE_BASE * SCALING,       // Extra large rounding (3/4 font size)
-                x2l: 1.0 * FONT_SIZE_BASE * SCALING,       // Double large (equal to font size)
-                x3l: 1.5 * FONT_SIZE_BASE * SCALING,       // Triple large (1.5× font size)
-                x4l: 2.0 * FONT_SIZE_BASE * SCALING,       // Quadruple large (2× font size)
-                full: f32::MAX,                             // Fully rounded (maximum value)
+                none: 0.0,                            // Sharp corners
+                xs: 0.125 * FONT_SIZE_BASE * scale,   // Minimal rounding (1/8 font size)
+                sm: 0.25 * FONT_SIZE_BASE * scale,    // Small rounding (1/4 font size)
+                base: 0.375 * FONT_SIZE_BASE * scale, // Standard rounding (3/8 font size)
+                lg: 0.5 * FONT_SIZE_BASE * scale,     // Large rounding (1/2 font size)
+                xl: 0.75 * FONT_SIZE_BASE * scale,    // Extra large rounding (3/4 font size)
+                x2l: 1.0 * FONT_SIZE_BASE * scale,    // Double large (equal to font size)
+                x3l: 1.5 * FONT_SIZE_BASE * scale,    // Triple large (1.5× font size)
+                x4l: 2.0 * FONT_SIZE_BASE * scale,    // Quadruple large (2× font size)
+                full: f32::MAX,                       // Fully rounded (maximum value)
             },
-            // Border: Fixed pixel values with design-optimized progression
+            // Border: Pixel values with design-optimized progression, unaffected by density
             border: UiSpacing {
-                xs: 1.0,   // Hairline border for subtle definition
-                sm: 2.0,   // Thin border for clear separation
-                base: 3.0, // Standard border for normal emphasis
-                lg: 5.0,   // Thick border for strong emphasis
-                xl: 7.0,   // Very thick border for major emphasis
-                x2l: 9.0,  // Extra thick border for special cases
-                x3l: 12.0, // Prominent border for hero elements
-                x4l: 15.0, // Heavy border for maximum emphasis
-                x5l: 19.0, // Architectural border for extreme cases
+                xs: 1.0 * scale,   // Hairline border for subtle definition
+                sm: 2.0 * scale,   // Thin border for clear separation
+                base: 3.0 * scale, // Standard border for normal emphasis
+                lg: 5.0 * scale,   // Thick border for strong emphasis
+                xl: 7.0 * scale,   // Very thick border for major emphasis
+                x2l: 9.0 * scale,  // Extra thick border for special cases
+                x3l: 12.0 * scale, // Prominent border for hero elements
+                x4l: 15.0 * scale, // Heavy border for maximum emphasis
+                x5l: 19.0 * scale, // Architectural border for extreme cases
+            },
+            // Shadow: Offset and blur double with every elevation level
+            shadow: UiShadowScale {
+                sm: UiShadow {
+                    offset_y: 1.0 * scale,
+                    blur: 3.0 * scale,
+                    spread: 0.0,
+                    opacity: 0.12,
+                },
+                md: UiShadow {
+                    offset_y: 4.0 * scale,
+                    blur: 8.0 * scale,
+                    spread: 0.0,
+                    opacity: 0.15,
+                },
+                lg: UiShadow {
+                    offset_y: 8.0 * scale,
+                    blur: 16.0 * scale,
+                    spread: 0.0,
+                    opacity: 0.18,
+                },
+                xl: UiShadow {
+                    offset_y: 16.0 * scale,
+                    blur: 32.0 * scale,
+                    spread: 0.0,
+                    opacity: 0.22,
+                },
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::SCALING;
+    use bevy::{color::Alpha, ecs::system::RunSystemOnce, prelude::World};
+
+    fn shadow_style(elevation: Elevation, mode: ThemeMode) -> ShadowStyle {
+        let shadow = resolve_shadow(elevation, mode);
+        assert_eq!(shadow.0.len(), 1);
+        shadow.0[0]
+    }
+
+    #[test]
+    fn test_elevation_levels_map_to_shadow_parameters() {
+        let expected = [
+            (Elevation::Sm, 1.0, 3.0, 0.12),
+            (Elevation::Md, 4.0, 8.0, 0.15),
+            (Elevation::Lg, 8.0, 16.0, 0.18),
+            (Elevation::Xl, 16.0, 32.0, 0.22),
+        ];
+        for (elevation, offset_y, blur, opacity) in expected {
+            let style = shadow_style(elevation, ThemeMode::Light);
+            assert_eq!(style.x_offset, Val::ZERO);
+            assert_eq!(style.y_offset, Val::Px(offset_y * SCALING));
+            assert_eq!(style.blur_radius, Val::Px(blur * SCALING));
+            assert_eq!(style.spread_radius, Val::Px(0.0));
+            assert_eq!(style.color, Color::srgba(0.0, 0.0, 0.0, opacity));
+        }
+    }
+
+    #[test]
+    fn test_no_elevation_has_no_shadow() {
+        for mode in [ThemeMode::Light, ThemeMode::Dark] {
+            assert!(resolve_shadow(Elevation::None, mode).0.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_dark_mode_shadows_are_subtler() {
+        for elevation in [Elevation::Sm, Elevation::Md, Elevation::Lg, Elevation::Xl] {
+            let light = shadow_style(elevation, ThemeMode::Light);
+            let dark = shadow_style(elevation, ThemeMode::Dark);
+            assert_eq!(light.y_offset, dark.y_offset);
+            assert_eq!(light.blur_radius, dark.blur_radius);
+            assert_eq!(
+                dark.color.alpha(),
+                light.color.alpha() * DARK_SHADOW_OPACITY_FACTOR
+            );
+        }
+    }
+
+    #[test]
+    fn test_shadows_follow_the_theme_mode() {
+        let mode = theme_mode();
+        let stale = match mode {
+            ThemeMode::Light => ThemeMode::Dark,
+            ThemeMode::Dark => ThemeMode::Light,
+        };
+        let mut world = World::new();
+        let surface = world
+            .spawn((
+                ElevationShadow {
+                    elevation: Elevation::Md,
+                    mode: stale,
+                },
+                resolve_shadow(Elevation::Md, stale),
+            ))
+            .id();
+
+        world.run_system_once(refresh_elevation_shadows).unwrap();
+
+        let shadow = world.get::<BoxShadow>(surface).unwrap();
+        assert_eq!(shadow.0, resolve_shadow(Elevation::Md, mode).0);
+        assert_eq!(world.get::<ElevationShadow>(surface).unwrap().mode, mode);
+    }
+}
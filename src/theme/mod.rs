@@ -1,5 +1,12 @@
 // crates/forge_ui/src/theme/mod.rs
 pub mod appearance;
+pub mod breakpoints;
 pub mod color;
+pub mod deferred_style;
+pub mod density;
 pub mod layout;
+pub mod motion;
+pub mod presets;
+pub mod radix_theme;
+pub mod settings;
 pub mod typography;
@@ -0,0 +1,25 @@
+//! Reduced motion.
+//!
+//! [`UiMotion`] lets players turn animations off, for accessibility or on
+//! slow hardware. With `reduced` set, animation systems skip to their end
+//! state or hold still:
+//!
+//! - Color and layout [`Transition`](crate::utilities::transition::Transition)s,
+//!   switch thumbs and tab indicators jump to their target, and hover cards
+//!   open without scaling in.
+//! - Loading spinners, indeterminate progress and text marquees stand still.
+//!
+//! ```rust
+//! fn apply_settings(settings: Res<Settings>, mut motion: ResMut<UiMotion>) {
+//!     motion.reduced = settings.reduce_motion;
+//! }
+//! ```
+
+use bevy::prelude::*;
+
+/// Whether UI animations play.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub struct UiMotion {
+    /// Skips animations to their end state or holds them still
+    pub reduced: bool,
+}
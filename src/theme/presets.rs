@@ -0,0 +1,211 @@
+//! Named theme presets.
+//!
+//! [`ThemePresets`] holds named [`RadixTheme`] configurations, so a settings
+//! screen can list the available themes and switch between them by name.
+//! [`apply_theme_preset`] replaces the active [`RadixTheme`], which recomputes
+//! the [`ThemeTokens`](crate::theme::radix_theme::ThemeTokens) and restyles
+//! the accent-following components, and emits [`ThemeChangedEvent`].
+//!
+//! A `"default"` preset with the default theme is always registered. Presets
+//! can also be read from RON, e.g. files shipped with the game:
+//!
+//! ```rust
+//! fn register_themes(mut presets: ResMut<ThemePresets>) {
+//!     presets.register(
+//!         "amber",
+//!         RadixTheme {
+//!             accent_color: UiColorPalettesName::Amber,
+//!             ..default()
+//!         },
+//!     );
+//!     // (accent: "#B58900", panel_background: translucent)
+//!     presets
+//!         .register_ron("solarized", include_str!("../themes/solarized.ron"))
+//!         .unwrap();
+//! }
+//!
+//! fn use_amber(mut commands: Commands) {
+//!     commands.queue(apply_theme_preset("amber"));
+//! }
+//! ```
+
+use bevy::{ecs::system::Command, prelude::*};
+use serde::Deserialize;
+
+use crate::theme::radix_theme::{AccentColor, PanelBackground, RadixTheme};
+
+/// Name of the preset registered by default
+pub const DEFAULT_THEME_PRESET: &str = "default";
+
+/// Event emitted when [`apply_theme_preset`] switched the theme.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ThemeChangedEvent {
+    pub preset_name: String,
+}
+
+/// Registry of named [`RadixTheme`]s, in registration order.
+#[derive(Resource, Debug, Clone)]
+pub struct ThemePresets {
+    presets: Vec<(String, RadixTheme)>,
+    active: Option<String>,
+}
+
+impl Default for ThemePresets {
+    fn default() -> Self {
+        Self {
+            presets: vec![(DEFAULT_THEME_PRESET.to_string(), RadixTheme::default())],
+            active: None,
+        }
+    }
+}
+
+/// Theme preset as written in RON.
+#[derive(Deserialize)]
+struct ThemePresetFile {
+    accent: AccentColor,
+    #[serde(default)]
+    panel_background: PanelBackground,
+}
+
+impl ThemePresets {
+    /// Registers `theme` as `name`, replacing a preset of the same name.
+    pub fn register(&mut self, name: impl Into<String>, theme: RadixTheme) -> &mut Self {
+        let name = name.into();
+        match self.presets.iter_mut().find(|(preset, _)| *preset == name) {
+            Some((_, preset)) => *preset = theme,
+            None => self.presets.push((name, theme)),
+        }
+        self
+    }
+
+    /// Registers a preset written in RON as `(accent: "amber")`, with an
+    /// optional `panel_background: translucent`.
+    pub fn register_ron(
+        &mut self,
+        name: impl Into<String>,
+        source: &str,
+    ) -> Result<&mut Self, ron::error::SpannedError> {
+        let file: ThemePresetFile = ron::from_str(source)?;
+        let mut theme = RadixTheme {
+            panel_background: file.panel_background,
+            ..default()
+        };
+        theme.set_accent(file.accent);
+        Ok(self.register(name, theme))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&RadixTheme> {
+        self.presets
+            .iter()
+            .find(|(preset, _)| preset == name)
+            .map(|(_, theme)| theme)
+    }
+
+    /// Names of all presets, in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.presets.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Name of the preset applied last, if any.
+    pub fn active(&self) -> Option<&str> {
+        self.active.as_deref()
+    }
+}
+
+/// Command replacing the active [`RadixTheme`] with the preset `name`;
+/// unknown names are ignored with a warning.
+///
+/// ```rust
+/// commands.queue(apply_theme_preset("high-contrast"));
+/// ```
+pub fn apply_theme_preset(name: impl Into<String>) -> impl Command {
+    let name = name.into();
+    move |world: &mut World| {
+        let Some(theme) = world
+            .get_resource::<ThemePresets>()
+            .and_then(|presets| presets.get(&name))
+            .cloned()
+        else {
+            warn!("Unknown theme preset `{name}`");
+            return;
+        };
+
+        world.resource_mut::<ThemePresets>().active = Some(name.clone());
+        world.insert_resource(theme);
+        world.send_event(ThemeChangedEvent { preset_name: name });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_support::{drain_events, test_app},
+        theme::{
+            color::{theme_mode, UiColorPalettesName},
+            radix_theme::ThemeTokens,
+        },
+    };
+
+    #[test]
+    fn test_switching_presets_updates_tokens() {
+        let mut app = test_app();
+        let brand = Color::srgb_u8(0xB5, 0x89, 0x00);
+        app.world_mut()
+            .resource_mut::<ThemePresets>()
+            .register(
+                "amber",
+                RadixTheme {
+                    accent_color: UiColorPalettesName::Amber,
+                    ..default()
+                },
+            )
+            .register_ron("solarized", "(accent: \"#B58900\")")
+            .unwrap();
+
+        let preset_names = |app: &mut App| -> Vec<String> {
+            drain_events::<ThemeChangedEvent>(app)
+                .into_iter()
+                .map(|event| event.preset_name)
+                .collect()
+        };
+
+        apply_theme_preset("amber").apply(app.world_mut());
+        app.update();
+        assert_eq!(
+            app.world().resource::<ThemeTokens>().accent,
+            AccentColor::Palette(UiColorPalettesName::Amber).palette(theme_mode())
+        );
+        assert_eq!(preset_names(&mut app), vec!["amber"]);
+
+        apply_theme_preset("solarized").apply(app.world_mut());
+        app.update();
+        assert_eq!(
+            app.world().resource::<ThemeTokens>().accent,
+            AccentColor::Custom(brand).palette(theme_mode())
+        );
+        assert_eq!(
+            app.world().resource::<ThemePresets>().active(),
+            Some("solarized")
+        );
+        assert_eq!(preset_names(&mut app), vec!["solarized"]);
+    }
+
+    #[test]
+    fn test_register_replaces_same_name() {
+        let mut presets = ThemePresets::default();
+        presets
+            .register_ron(
+                "dark",
+                "(accent: \"crimson\", panel_background: translucent)",
+            )
+            .unwrap()
+            .register_ron("dark", "(accent: \"teal\")")
+            .unwrap();
+
+        assert_eq!(presets.names().collect::<Vec<_>>(), vec!["default", "dark"]);
+        let dark = presets.get("dark").unwrap();
+        assert_eq!(dark.accent_color, UiColorPalettesName::Teal);
+        assert_eq!(dark.panel_background, PanelBackground::Solid);
+    }
+}
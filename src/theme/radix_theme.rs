@@ -0,0 +1,599 @@
+//! Runtime theme settings shared by all components.
+//!
+//! [`RadixTheme`] is a Bevy resource mirroring the props of the Radix `<Theme>`
+//! root. Components that depend on one of its settings carry a marker component
+//! and are restyled by a system running on `resource_changed::<RadixTheme>`.
+
+use crate::theme::{
+    color::{
+        accent_palette, set_custom_accent_palette, theme, theme_mode, ThemeMode, UiColorPalette,
+        UiColorPalettes, UiColorPalettesName,
+    },
+    layout::UiLayout,
+    typography::FontSize,
+};
+use bevy::prelude::*;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Background style of panel-like surfaces (cards, hover cards, …).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PanelBackground {
+    /// Fully opaque panel background
+    #[default]
+    Solid,
+    /// See-through panel background letting the content behind shine through
+    Translucent,
+}
+
+/// Accent of the theme: a built-in palette or one generated from a brand color.
+///
+/// Serializes as the palette name (`"indigo"`) or as the brand color's hex
+/// code (`"#E05A2B"`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccentColor {
+    Palette(UiColorPalettesName),
+    /// Palette generated with [`UiColorPalette::generate`]
+    Custom(Color),
+}
+
+impl AccentColor {
+    /// Lowercase name of the accent, `"custom"` for a generated one.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccentColor::Palette(name) => name.as_str(),
+            AccentColor::Custom(_) => "custom",
+        }
+    }
+
+    /// Accent palette for `mode`.
+    pub fn palette(&self, mode: ThemeMode) -> UiColorPalette {
+        match *self {
+            AccentColor::Palette(name) => {
+                let palettes = match mode {
+                    ThemeMode::Light => UiColorPalettes::light_mode(),
+                    ThemeMode::Dark => UiColorPalettes::dark_mode(),
+                };
+                palettes.palette(name).clone()
+            }
+            AccentColor::Custom(base) => UiColorPalette::generate(base, mode),
+        }
+    }
+}
+
+impl Serialize for AccentColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            AccentColor::Palette(name) => serializer.serialize_str(name.as_str()),
+            AccentColor::Custom(base) => serializer.serialize_str(&base.to_srgba().to_hex()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AccentColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        if value.starts_with('#') {
+            Srgba::hex(&value)
+                .map(|base| AccentColor::Custom(base.into()))
+                .map_err(de::Error::custom)
+        } else {
+            UiColorPalettesName::parse(&value)
+                .map(AccentColor::Palette)
+                .ok_or_else(|| de::Error::custom(format!("unknown accent color `{value}`")))
+        }
+    }
+}
+
+/// Global theme settings.
+#[derive(Resource, Debug, Clone)]
+pub struct RadixTheme {
+    /// Palette used by every component that follows the accent color
+    pub accent_color: UiColorPalettesName,
+    /// Brand color the accent palette is generated from, replacing `accent_color`
+    pub custom_accent: Option<Color>,
+    /// Neutral palette of backgrounds, borders and muted text in
+    /// [`ThemeTokens`]: gray, mauve, slate, sage, olive or sand
+    pub gray_color: UiColorPalettesName,
+    pub panel_background: PanelBackground,
+}
+
+impl RadixTheme {
+    /// Current accent; a custom accent wins over `accent_color`.
+    pub fn accent(&self) -> AccentColor {
+        match self.custom_accent {
+            Some(base) => AccentColor::Custom(base),
+            None => AccentColor::Palette(self.accent_color),
+        }
+    }
+
+    /// Sets the accent, clearing the custom accent for built-in palettes.
+    pub fn set_accent(&mut self, accent: AccentColor) {
+        match accent {
+            AccentColor::Palette(name) => {
+                self.accent_color = name;
+                self.custom_accent = None;
+            }
+            AccentColor::Custom(base) => self.custom_accent = Some(base),
+        }
+    }
+}
+
+impl Default for RadixTheme {
+    fn default() -> Self {
+        Self {
+            accent_color: UiColorPalettesName::Indigo,
+            custom_accent: None,
+            gray_color: UiColorPalettesName::Gray,
+            panel_background: PanelBackground::Solid,
+        }
+    }
+}
+
+/// Palettes resolved from [`RadixTheme`] for the current theme mode, and the
+/// layout and font sizes resolved from the [`UiDensity`](crate::theme::density::UiDensity).
+///
+/// Besides the raw palettes, semantic getters name what a color is for:
+///
+/// ```rust
+/// fn style(tokens: Res<ThemeTokens>) {
+///     let fill = tokens.accent().solid();
+///     let divider = tokens.gray().line();
+///     let panel = tokens.bg().subtle();
+///     let heading = tokens.text().contrast();
+/// }
+/// ```
+///
+/// | Getter                                   | Palette step                 |
+/// |------------------------------------------|------------------------------|
+/// | `accent()` / `gray()` `.base()`          | 1 `base`                     |
+/// | `accent()` / `gray()` `.subtle()`        | 2 `bg_subtle`                |
+/// | `accent()` / `gray()` `.bg()`            | 3 `bg`                       |
+/// | `accent()` / `gray()` `.bg_hover()`      | 4 `bg_hover`                 |
+/// | `accent()` / `gray()` `.bg_active()`     | 5 `bg_active`                |
+/// | `accent()` / `gray()` `.line()`          | 6 `line`                     |
+/// | `accent()` / `gray()` `.border()`        | 7 `border`                   |
+/// | `accent()` / `gray()` `.border_hover()`  | 8 `border_hover`             |
+/// | `accent()` / `gray()` `.solid()`         | 9 `solid`                    |
+/// | `accent()` / `gray()` `.solid_hover()`   | 10 `solid_hover`             |
+/// | `accent()` / `gray()` `.text()`          | 11 `text`                    |
+/// | `accent()` / `gray()` `.contrast()`      | 12 `text_contrast`           |
+/// | `bg().app()`                             | gray 1 `base`                |
+/// | `bg().subtle()`                          | gray 2 `bg_subtle`           |
+/// | `bg().element()`                         | gray 3 `bg`                  |
+/// | `bg().hover()`                           | gray 4 `bg_hover`            |
+/// | `bg().active()`                          | gray 5 `bg_active`           |
+/// | `text().muted()`                         | gray 11 `text`               |
+/// | `text().contrast()`                      | gray 12 `text_contrast`      |
+/// | `text().accent()`                        | accent 11 `text`             |
+/// | `text().accent_contrast()`               | accent 12 `text_contrast`    |
+///
+/// Colors drawn over any component (scrollbars, selections and focus rings)
+/// are resolved once per theme change into [`ThemeTokens::system`].
+#[derive(Resource, Debug, Clone)]
+pub struct ThemeTokens {
+    pub accent: UiColorPalette,
+    pub gray: UiColorPalette,
+    pub system: SystemColors,
+    pub layout: UiLayout,
+    pub font_size: FontSize,
+}
+
+impl Default for ThemeTokens {
+    fn default() -> Self {
+        let accent = accent_palette();
+        let gray = theme().gray;
+        Self {
+            system: SystemColors::new(&accent, &gray),
+            accent,
+            gray,
+            layout: UiLayout::default(),
+            font_size: FontSize::default(),
+        }
+    }
+}
+
+impl ThemeTokens {
+    /// Tokens for `theme` in `mode`, with the default layout and font sizes.
+    pub fn from_theme(theme: &RadixTheme, mode: ThemeMode) -> Self {
+        let accent = theme.accent().palette(mode);
+        let palettes = match mode {
+            ThemeMode::Light => UiColorPalettes::light_mode(),
+            ThemeMode::Dark => UiColorPalettes::dark_mode(),
+        };
+        let gray = palettes.palette(theme.gray_color).clone();
+        Self {
+            system: SystemColors::new(&accent, &gray),
+            accent,
+            gray,
+            layout: UiLayout::default(),
+            font_size: FontSize::default(),
+        }
+    }
+
+    /// Steps of the accent palette.
+    pub fn accent(&self) -> PaletteTokens<'_> {
+        PaletteTokens(&self.accent)
+    }
+
+    /// Steps of the neutral gray palette.
+    pub fn gray(&self) -> PaletteTokens<'_> {
+        PaletteTokens(&self.gray)
+    }
+
+    /// Neutral backgrounds, from the app background to active elements.
+    pub fn bg(&self) -> BackgroundTokens<'_> {
+        BackgroundTokens(&self.gray)
+    }
+
+    /// Text colors.
+    pub fn text(&self) -> TextTokens<'_> {
+        TextTokens {
+            gray: &self.gray,
+            accent: &self.accent,
+        }
+    }
+}
+
+/// Colors of the chrome drawn over components, shared so scrollbars, text
+/// selections and focus rings look alike everywhere.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemColors {
+    /// Scrollbar track (gray step 2)
+    pub scrollbar_track: Color,
+    /// Idle scrollbar thumb (gray step 8)
+    pub scrollbar_thumb: Color,
+    /// Hovered or dragged scrollbar thumb (accent step 10)
+    pub scrollbar_thumb_active: Color,
+    /// Translucent highlight behind selected text (accent step 5, alpha)
+    pub selection: Color,
+    /// Keyboard focus outline (accent step 8)
+    pub focus_ring: Color,
+}
+
+impl SystemColors {
+    /// System colors taken from the steps of the `accent` and `gray`
+    /// palettes of one theme mode.
+    pub fn new(accent: &UiColorPalette, gray: &UiColorPalette) -> Self {
+        Self {
+            scrollbar_track: gray.bg_subtle,
+            scrollbar_thumb: gray.border_hover,
+            scrollbar_thumb_active: accent.solid_hover,
+            selection: accent.bg_active_a,
+            focus_ring: accent.border_hover,
+        }
+    }
+}
+
+/// Semantic view on the twelve steps of a palette.
+#[derive(Debug, Clone, Copy)]
+pub struct PaletteTokens<'a>(&'a UiColorPalette);
+
+impl PaletteTokens<'_> {
+    /// Step 1: app background
+    pub fn base(&self) -> Color {
+        self.0.base
+    }
+
+    /// Step 2: subtle background
+    pub fn subtle(&self) -> Color {
+        self.0.bg_subtle
+    }
+
+    /// Step 3: element background
+    pub fn bg(&self) -> Color {
+        self.0.bg
+    }
+
+    /// Step 4: hovered element background
+    pub fn bg_hover(&self) -> Color {
+        self.0.bg_hover
+    }
+
+    /// Step 5: active / selected element background
+    pub fn bg_active(&self) -> Color {
+        self.0.bg_active
+    }
+
+    /// Step 6: separators and subtle borders
+    pub fn line(&self) -> Color {
+        self.0.line
+    }
+
+    /// Step 7: element border
+    pub fn border(&self) -> Color {
+        self.0.border
+    }
+
+    /// Step 8: hovered element border and focus rings
+    pub fn border_hover(&self) -> Color {
+        self.0.border_hover
+    }
+
+    /// Step 9: solid fill
+    pub fn solid(&self) -> Color {
+        self.0.solid
+    }
+
+    /// Step 10: hovered solid fill
+    pub fn solid_hover(&self) -> Color {
+        self.0.solid_hover
+    }
+
+    /// Step 11: low-contrast text
+    pub fn text(&self) -> Color {
+        self.0.text
+    }
+
+    /// Step 12: high-contrast text
+    pub fn contrast(&self) -> Color {
+        self.0.text_contrast
+    }
+}
+
+/// Neutral background colors (gray palette steps 1–5).
+#[derive(Debug, Clone, Copy)]
+pub struct BackgroundTokens<'a>(&'a UiColorPalette);
+
+impl BackgroundTokens<'_> {
+    /// Step 1: app background
+    pub fn app(&self) -> Color {
+        self.0.base
+    }
+
+    /// Step 2: subtle background, e.g. panels and sidebars
+    pub fn subtle(&self) -> Color {
+        self.0.bg_subtle
+    }
+
+    /// Step 3: element background
+    pub fn element(&self) -> Color {
+        self.0.bg
+    }
+
+    /// Step 4: hovered element background
+    pub fn hover(&self) -> Color {
+        self.0.bg_hover
+    }
+
+    /// Step 5: active / selected element background
+    pub fn active(&self) -> Color {
+        self.0.bg_active
+    }
+}
+
+/// Text colors (palette steps 11 and 12).
+#[derive(Debug, Clone, Copy)]
+pub struct TextTokens<'a> {
+    gray: &'a UiColorPalette,
+    accent: &'a UiColorPalette,
+}
+
+impl TextTokens<'_> {
+    /// Gray step 11: secondary text and descriptions
+    pub fn muted(&self) -> Color {
+        self.gray.text
+    }
+
+    /// Gray step 12: primary text
+    pub fn contrast(&self) -> Color {
+        self.gray.text_contrast
+    }
+
+    /// Accent step 11: links and accented text
+    pub fn accent(&self) -> Color {
+        self.accent.text
+    }
+
+    /// Accent step 12: high-contrast accented text
+    pub fn accent_contrast(&self) -> Color {
+        self.accent.text_contrast
+    }
+}
+
+/// Recomputes [`ThemeTokens`] and the global accent palette after [`RadixTheme`] changed.
+pub fn update_theme_tokens(theme: Res<RadixTheme>, mut tokens: ResMut<ThemeTokens>) {
+    let resolved = ThemeTokens::from_theme(&theme, theme_mode());
+    set_custom_accent_palette(resolved.accent.clone());
+
+    if tokens.accent != resolved.accent {
+        tokens.accent = resolved.accent;
+    }
+    if tokens.gray != resolved.gray {
+        tokens.gray = resolved.gray;
+    }
+    if tokens.system != resolved.system {
+        tokens.system = resolved.system;
+    }
+}
+
+/// Marks a component whose palette follows [`RadixTheme::accent_color`].
+///
+/// Components built with the accent palette (the default of most builders,
+/// or `.accent()`) are tagged on spawn; components given an explicit palette
+/// keep it when the accent color changes, even if it equals the accent.
+#[derive(Component, Debug, Default)]
+pub struct UsesAccentPalette;
+
+/// Component whose builder decides whether its palette follows the accent.
+pub trait AccentPaletteUser: Component {
+    /// Whether the component was built with the accent palette.
+    fn follows_accent(&self) -> bool;
+}
+
+/// Tags newly spawned `C` components that were built with the accent palette.
+pub fn tag_accent_palette_users<C: AccentPaletteUser>(
+    mut commands: Commands,
+    query: Query<(Entity, &C), Added<C>>,
+) {
+    for (entity, component) in &query {
+        if component.follows_accent() {
+            commands.entity(entity).insert(UsesAccentPalette);
+        }
+    }
+}
+
+/// Opacity of a translucent panel.
+pub const TRANSLUCENT_PANEL_ALPHA: f32 = 0.8;
+
+/// How strongly the subtle background tints a translucent panel.
+///
+/// Bevy UI has no backdrop blur; tinting towards the subtle step softens the
+/// content behind the panel, which comes close to the frosted glass look.
+const TRANSLUCENT_PANEL_OVERLAY: f32 = 0.35;
+
+/// Resolves the translucent background of a panel drawn with `palette`.
+pub fn resolve_panel_background(palette: &UiColorPalette) -> Color {
+    let overlay = palette
+        .bg
+        .to_srgba()
+        .mix(&palette.bg_subtle.to_srgba(), TRANSLUCENT_PANEL_OVERLAY);
+    Color::from(overlay).with_alpha(TRANSLUCENT_PANEL_ALPHA)
+}
+
+/// Marks an entity whose [`BackgroundColor`] follows [`RadixTheme::panel_background`].
+#[derive(Component, Debug, Clone)]
+pub struct PanelSurface {
+    pub palette: UiColorPalette,
+    /// The panel's own background, kept while panels are solid
+    pub solid: Color,
+    /// Inactive panels keep their own background (e.g. ghost cards)
+    pub active: bool,
+}
+
+impl PanelSurface {
+    /// Creates an active panel whose solid background is `solid`.
+    pub fn new(palette: UiColorPalette, solid: Color) -> Self {
+        Self {
+            palette,
+            solid,
+            active: true,
+        }
+    }
+
+    /// The background of this panel under `panel`.
+    pub fn background(&self, panel: PanelBackground) -> Color {
+        match panel {
+            PanelBackground::Solid => self.solid,
+            PanelBackground::Translucent => resolve_panel_background(&self.palette),
+        }
+    }
+}
+
+/// Applies the current panel background to newly spawned panels.
+pub fn apply_panel_backgrounds(
+    theme: Res<RadixTheme>,
+    mut panels: Query<(&PanelSurface, &mut BackgroundColor), Added<PanelSurface>>,
+) {
+    for (panel, mut background) in panels.iter_mut().filter(|(panel, _)| panel.active) {
+        background.0 = panel.background(theme.panel_background);
+    }
+}
+
+/// Restyles every existing panel after [`RadixTheme`] changed.
+pub fn update_panel_backgrounds(
+    theme: Res<RadixTheme>,
+    mut panels: Query<(&PanelSurface, &mut BackgroundColor)>,
+) {
+    for (panel, mut background) in panels.iter_mut().filter(|(panel, _)| panel.active) {
+        background.0 = panel.background(theme.panel_background);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{button::Button, ButtonBuilder};
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_only_accent_built_components_follow_the_accent() {
+        let mut world = World::new();
+        let default = world.spawn(ButtonBuilder::new("default").build()).id();
+        let accent = world
+            .spawn(ButtonBuilder::new("accent").accent().build())
+            .id();
+        // Equal to the accent, but chosen explicitly
+        let explicit = world
+            .spawn(
+                ButtonBuilder::new("explicit")
+                    .color(accent_palette())
+                    .build(),
+            )
+            .id();
+        world
+            .run_system_once(tag_accent_palette_users::<Button>)
+            .unwrap();
+
+        assert!(world.get::<UsesAccentPalette>(default).is_some());
+        assert!(world.get::<UsesAccentPalette>(accent).is_some());
+        assert!(world.get::<UsesAccentPalette>(explicit).is_none());
+    }
+
+    #[test]
+    fn test_semantic_getters_map_to_palette_steps() {
+        let tokens = ThemeTokens::default();
+
+        assert_eq!(tokens.accent().solid(), tokens.accent.solid);
+        assert_eq!(tokens.accent().subtle(), tokens.accent.bg_subtle);
+        assert_eq!(tokens.gray().border(), tokens.gray.border);
+        assert_eq!(tokens.bg().app(), tokens.gray.base);
+        assert_eq!(tokens.bg().subtle(), tokens.gray.bg_subtle);
+        assert_eq!(tokens.text().muted(), tokens.gray.text);
+        assert_eq!(tokens.text().contrast(), tokens.gray.text_contrast);
+        assert_eq!(tokens.text().accent(), tokens.accent.text);
+    }
+
+    #[test]
+    fn test_tokens_from_custom_accent() {
+        let brand = Color::srgb_u8(0x00, 0x90, 0xFF);
+        let theme = RadixTheme {
+            custom_accent: Some(brand),
+            ..default()
+        };
+        assert_eq!(theme.accent(), AccentColor::Custom(brand));
+        assert_eq!(theme.accent().as_str(), "custom");
+
+        let tokens = ThemeTokens {
+            accent: theme.accent().palette(ThemeMode::Light),
+            ..default()
+        };
+        let solid = tokens.accent().solid().to_srgba();
+        let expected = brand.to_srgba();
+        assert!((solid.red - expected.red).abs() < 0.01);
+        assert!((solid.green - expected.green).abs() < 0.01);
+        assert!((solid.blue - expected.blue).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_system_colors_follow_mode() {
+        let theme = RadixTheme::default();
+        let light = ThemeTokens::from_theme(&theme, ThemeMode::Light);
+        let dark = ThemeTokens::from_theme(&theme, ThemeMode::Dark);
+
+        assert_eq!(light.system.focus_ring, light.accent.border_hover);
+        assert_eq!(dark.system.focus_ring, dark.accent.border_hover);
+        assert_eq!(light.system.scrollbar_track, light.gray.bg_subtle);
+
+        // Each mode takes the steps of its own palettes
+        assert_ne!(light.system.scrollbar_track, dark.system.scrollbar_track);
+        assert_ne!(light.system.scrollbar_thumb, dark.system.scrollbar_thumb);
+        assert_ne!(light.system.selection, dark.system.selection);
+        assert_ne!(light.system.focus_ring, dark.system.focus_ring);
+    }
+
+    #[test]
+    fn test_accent_serialization_round_trips() {
+        for accent in [
+            AccentColor::Palette(UiColorPalettesName::Amber),
+            AccentColor::Custom(Color::srgb_u8(0xE0, 0x5A, 0x2B)),
+        ] {
+            let serialized = ron::to_string(&accent).unwrap();
+            assert_eq!(ron::from_str::<AccentColor>(&serialized).unwrap(), accent);
+        }
+        assert_eq!(
+            ron::to_string(&AccentColor::Palette(UiColorPalettesName::Indigo)).unwrap(),
+            "\"indigo\""
+        );
+    }
+}
@@ -0,0 +1,266 @@
+//! Player UI settings persisted to disk.
+//!
+//! [`UiSettings`] collects what a settings screen usually offers: theme mode,
+//! accent and gray palettes, scale, density, reduced motion and sounds. It
+//! is saved as RON and, with [`UiConfig::settings_path`](crate::plugin::UiConfig)
+//! set, loaded and applied at startup by [`load_ui_settings`]:
+//!
+//! ```rust
+//! App::new()
+//!     .insert_resource(UiConfig {
+//!         settings_path: Some("config/ui.ron".into()),
+//!         ..default()
+//!     })
+//!     .add_plugins(ForgeUiPlugin);
+//!
+//! fn use_dark_mode(mut commands: Commands, mut settings: ResMut<UiSettings>) {
+//!     settings.theme_mode = ThemeMode::Dark;
+//!     let settings = settings.clone();
+//!     commands.queue(move |world: &mut World| settings.apply(world));
+//!     if let Err(error) = settings.save("config/ui.ron") {
+//!         warn!("Couldn't save UI settings: {error}");
+//!     }
+//! }
+//! ```
+//!
+//! A missing file loads the defaults; an unreadable or corrupt one logs a
+//! warning and loads the defaults too. Fields missing from the file keep
+//! their default, so files written by older versions still load. Without a
+//! file, startup applies nothing and the app's own setup stays in place.
+
+use std::{fs, io, path::Path};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    components::sounds::UiSoundSettings,
+    plugin::UiConfig,
+    theme::{
+        color::{set_theme_mode, ThemeMode, UiColorPalettesName},
+        density::{Density, UiDensity},
+        motion::UiMotion,
+        radix_theme::{AccentColor, RadixTheme},
+    },
+};
+
+/// UI settings of the player, as stored on disk.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiSettings {
+    pub theme_mode: ThemeMode,
+    pub accent: AccentColor,
+    /// Neutral palette, see [`RadixTheme::gray_color`]
+    pub gray: UiColorPalettesName,
+    /// Multiplier of all sizes, see [`UiDensity::scale`]
+    pub scale: f32,
+    pub density: Density,
+    pub reduced_motion: bool,
+    pub sounds: UiSoundSettings,
+}
+
+impl Default for UiSettings {
+    fn default() -> Self {
+        let theme = RadixTheme::default();
+        let density = UiDensity::default();
+        Self {
+            theme_mode: ThemeMode::Light,
+            accent: theme.accent(),
+            gray: theme.gray_color,
+            scale: density.scale,
+            density: density.density,
+            reduced_motion: false,
+            sounds: UiSoundSettings::default(),
+        }
+    }
+}
+
+impl UiSettings {
+    /// Reads the settings at `path`, falling back to the defaults when the
+    /// file is missing, unreadable or corrupt.
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let source = match fs::read_to_string(path) {
+            Ok(source) => source,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Self::default(),
+            Err(error) => {
+                warn!("Couldn't read UI settings {}: {error}", path.display());
+                return Self::default();
+            }
+        };
+        ron::from_str(&source).unwrap_or_else(|error| {
+            warn!("Corrupt UI settings {}: {error}", path.display());
+            Self::default()
+        })
+    }
+
+    /// Writes the settings to `path` as RON, creating missing directories.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let source = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(io::Error::other)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, source)
+    }
+
+    /// Applies the settings to the theme mode and to the [`RadixTheme`],
+    /// [`UiDensity`], [`UiMotion`] and [`UiSoundSettings`] resources.
+    pub fn apply(&self, world: &mut World) {
+        set_theme_mode(self.theme_mode);
+        self.apply_resources(world);
+    }
+
+    /// Applies everything but the process-wide theme mode.
+    fn apply_resources(&self, world: &mut World) {
+        // Also marks the theme changed, which resolves the tokens for the mode
+        let mut theme = world.resource_mut::<RadixTheme>();
+        theme.set_accent(self.accent);
+        theme.gray_color = self.gray;
+
+        *world.resource_mut::<UiDensity>() = UiDensity {
+            density: self.density,
+            scale: self.scale,
+        };
+        world.resource_mut::<UiMotion>().reduced = self.reduced_motion;
+        if let Some(mut sounds) = world.get_resource_mut::<UiSoundSettings>() {
+            *sounds = self.sounds;
+        }
+    }
+}
+
+/// Startup system loading the [`UiSettings`] at
+/// [`UiConfig::settings_path`](crate::plugin::UiConfig) and applying them,
+/// if the file exists.
+pub fn load_ui_settings(world: &mut World) {
+    load_ui_settings_with(world, set_theme_mode);
+}
+
+/// [`load_ui_settings`], handing the stored theme mode to `set_mode`.
+///
+/// Tests pass their own `set_mode`, as switching the process-wide theme mode
+/// would change the colors of the tests running alongside.
+fn load_ui_settings_with(world: &mut World, set_mode: impl FnOnce(ThemeMode)) {
+    let Some(path) = world.resource::<UiConfig>().settings_path.clone() else {
+        return;
+    };
+    if !path.exists() {
+        return;
+    }
+    let settings = UiSettings::load(&path);
+    set_mode(settings.theme_mode);
+    settings.apply_resources(world);
+    world.insert_resource(settings);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::{
+        test_support::headless_app,
+        theme::{color::theme_mode, radix_theme::ThemeTokens},
+    };
+
+    /// Directory of its own below the temp dir for the test `name`.
+    fn temp_settings_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("ui-settings-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn test_settings_round_trip() {
+        let dir = temp_settings_dir("round_trip");
+        let path = dir.join("ui.ron");
+        let settings = UiSettings {
+            theme_mode: ThemeMode::Dark,
+            accent: AccentColor::Custom(Color::srgb_u8(0xE0, 0x5A, 0x2B)),
+            gray: UiColorPalettesName::Slate,
+            scale: 1.25,
+            density: Density::Compact,
+            reduced_motion: true,
+            sounds: UiSoundSettings {
+                enabled: false,
+                volume: 0.5,
+            },
+        };
+        settings.save(&path).unwrap();
+
+        assert_eq!(UiSettings::load(&path), settings);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_or_corrupt_file_loads_defaults() {
+        let dir = temp_settings_dir("corrupt");
+        let path = dir.join("ui.ron");
+        assert_eq!(UiSettings::load(&path), UiSettings::default());
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "(theme_mode: purple, scale:").unwrap();
+        assert_eq!(UiSettings::load(&path), UiSettings::default());
+
+        // Missing fields keep their defaults
+        fs::write(&path, "(scale: 2.0)").unwrap();
+        let settings = UiSettings::load(&path);
+        assert_eq!(settings.scale, 2.0);
+        assert_eq!(settings.theme_mode, ThemeMode::Light);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_startup_applies_stored_theme_mode() {
+        let dir = temp_settings_dir("startup");
+        let path = dir.join("ui.ron");
+        let stored = UiSettings {
+            theme_mode: ThemeMode::Dark,
+            accent: AccentColor::Palette(UiColorPalettesName::Crimson),
+            gray: UiColorPalettesName::Sand,
+            reduced_motion: true,
+            ..default()
+        };
+        stored.save(&path).unwrap();
+
+        // Startup runs without a settings path, then loads them like it would
+        let mut app = headless_app();
+        app.update();
+        app.world_mut().resource_mut::<UiConfig>().settings_path = Some(path.clone());
+        let mut mode = None;
+        load_ui_settings_with(app.world_mut(), |stored| mode = Some(stored));
+        app.update();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(mode, Some(ThemeMode::Dark));
+        let world = app.world();
+        assert_eq!(*world.resource::<UiSettings>(), stored);
+        assert!(world.resource::<UiMotion>().reduced);
+        let theme = world.resource::<RadixTheme>();
+        assert_eq!(theme.accent(), stored.accent);
+        assert_eq!(theme.gray_color, stored.gray);
+        let resolved = ThemeTokens::from_theme(theme, theme_mode());
+        let tokens = world.resource::<ThemeTokens>();
+        assert_eq!(tokens.accent, resolved.accent);
+        assert_eq!(tokens.gray, resolved.gray);
+    }
+
+    #[test]
+    fn test_startup_without_file_keeps_the_app_setup() {
+        let path = temp_settings_dir("missing").join("ui.ron");
+
+        let mut app = headless_app();
+        app.update();
+        app.world_mut().resource_mut::<UiConfig>().settings_path = Some(path);
+        app.world_mut()
+            .resource_mut::<RadixTheme>()
+            .set_accent(AccentColor::Palette(UiColorPalettesName::Crimson));
+        let mut mode = None;
+        load_ui_settings_with(app.world_mut(), |stored| mode = Some(stored));
+
+        assert_eq!(mode, None);
+        assert_eq!(
+            app.world().resource::<RadixTheme>().accent(),
+            AccentColor::Palette(UiColorPalettesName::Crimson)
+        );
+    }
+}
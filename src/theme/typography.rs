@@ -247,8 +247,12 @@
 //! - Component integration maintains design consistency
 //! - Theme switching preserves typography relationships
 
-use crate::plugin::{FONT_SIZE_BASE, SCALING};
+use crate::{
+    plugin::FONT_SIZE_BASE,
+    theme::density::{ui_density, UiDensity},
+};
 use bevy::prelude::*;
+use std::collections::HashSet;
 
 /// Text variant that defines semantic meaning and establishes default styling for different text purposes.
 ///
@@ -439,6 +443,25 @@ pub enum TextSize {
     X9l,
 }
 
+impl TextSize {
+    /// All sizes, from smallest to largest.
+    pub const ALL: [TextSize; 13] = [
+        TextSize::Xs,
+        TextSize::Sm,
+        TextSize::Base,
+        TextSize::Lg,
+        TextSize::Xl,
+        TextSize::X2l,
+        TextSize::X3l,
+        TextSize::X4l,
+        TextSize::X5l,
+        TextSize::X6l,
+        TextSize::X7l,
+        TextSize::X8l,
+        TextSize::X9l,
+    ];
+}
+
 /// Text weight variants providing semantic font weight control with accessibility considerations.
 ///
 /// TextWeight offers carefully selected font weight options that balance visual hierarchy
@@ -465,7 +488,7 @@ pub enum TextSize {
 ///
 /// Light weights should be used carefully with sufficient contrast ratios.
 /// Bold weights provide excellent accessibility for emphasis and hierarchy.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum TextWeight {
     /// Light font weight for subtle, refined text styling.
     ///
@@ -529,7 +552,7 @@ pub enum TextWeight {
 /// - Good contrast and readability
 /// - Support for screen readers
 /// - Compliance with accessibility guidelines
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum FontFamily {
     /// Sans-serif font family (Roboto) - clean, modern, highly readable.
     ///
@@ -563,6 +586,9 @@ pub enum FontFamily {
     Mono,
 
     InterfaceFont,
+
+    // Controller icon fonts, loaded with their `icons-*` feature. The variants
+    // exist either way; without the font they render in Sans.
     GenericController,
     KeyboardMouse,
     NintendoSwitch,
@@ -577,6 +603,31 @@ pub enum FontFamily {
     Touch,
     Indicators,
 }
+
+impl FontFamily {
+    /// Whether the family's font is loaded. Controller icon fonts need their
+    /// `icons-*` cargo feature.
+    pub fn is_available(self) -> bool {
+        match self {
+            FontFamily::Sans | FontFamily::Serif | FontFamily::Mono | FontFamily::InterfaceFont => {
+                true
+            }
+            FontFamily::GenericController => cfg!(feature = "icons-generic"),
+            FontFamily::KeyboardMouse => cfg!(feature = "icons-keyboard-mouse"),
+            FontFamily::NintendoSwitch => cfg!(feature = "icons-switch"),
+            FontFamily::NintendoSwitch2 => cfg!(feature = "icons-switch-2"),
+            FontFamily::PlayStation => cfg!(feature = "icons-playstation"),
+            FontFamily::Xbox => cfg!(feature = "icons-xbox"),
+            FontFamily::SteamController => cfg!(feature = "icons-steam-controller"),
+            FontFamily::SteamDeck => cfg!(feature = "icons-steam-deck"),
+            FontFamily::Gamecube => cfg!(feature = "icons-gamecube"),
+            FontFamily::Wii => cfg!(feature = "icons-wii"),
+            FontFamily::WiiU => cfg!(feature = "icons-wii-u"),
+            FontFamily::Touch => cfg!(feature = "icons-touch"),
+            FontFamily::Indicators => cfg!(feature = "icons-indicators"),
+        }
+    }
+}
 /// Font size configuration structure implementing the complete type scale system.
 ///
 /// FontSize provides the concrete pixel values for all text sizes in the typography system.
@@ -653,33 +704,42 @@ impl Default for FontSize {
     /// proportionally for different screen sizes or user preferences while
     /// maintaining the mathematical relationships between sizes.
     fn default() -> Self {
+        FontSize::for_density(&ui_density())
+    }
+}
+
+impl FontSize {
+    /// Type scale for `density`, which only applies its scale to text.
+    pub fn for_density(density: &UiDensity) -> Self {
+        let scale = density.scale();
+
         Self {
             // 0.75× base - minimum readable size for captions
-            xs: 0.75 * FONT_SIZE_BASE * SCALING,
+            xs: 0.75 * FONT_SIZE_BASE * scale,
             // 0.875× base - comfortable size for UI labels
-            sm: 0.875 * FONT_SIZE_BASE * SCALING,
+            sm: 0.875 * FONT_SIZE_BASE * scale,
             // 1.0× base - foundation size optimized for body text
-            base: 1.0 * FONT_SIZE_BASE * SCALING,
+            base: 1.0 * FONT_SIZE_BASE * scale,
             // 1.125× base - subtle emphasis for lead text
-            lg: 1.125 * FONT_SIZE_BASE * SCALING,
+            lg: 1.125 * FONT_SIZE_BASE * scale,
             // 1.25× base - clear step up for subheadings
-            xl: 1.25 * FONT_SIZE_BASE * SCALING,
+            xl: 1.25 * FONT_SIZE_BASE * scale,
             // 1.5× base - significant increase for titles
-            x2l: 1.5 * FONT_SIZE_BASE * SCALING,
+            x2l: 1.5 * FONT_SIZE_BASE * scale,
             // 1.875× base - major heading size
-            x3l: 1.875 * FONT_SIZE_BASE * SCALING,
+            x3l: 1.875 * FONT_SIZE_BASE * scale,
             // 2.25× base - prominent heading size
-            x4l: 2.25 * FONT_SIZE_BASE * SCALING,
+            x4l: 2.25 * FONT_SIZE_BASE * scale,
             // 3.0× base - display text for hero content
-            x5l: 3.0 * FONT_SIZE_BASE * SCALING,
+            x5l: 3.0 * FONT_SIZE_BASE * scale,
             // 3.75× base - large display text
-            x6l: 3.75 * FONT_SIZE_BASE * SCALING,
+            x6l: 3.75 * FONT_SIZE_BASE * scale,
             // 4.5× base - massive display text
-            x7l: 4.5 * FONT_SIZE_BASE * SCALING,
+            x7l: 4.5 * FONT_SIZE_BASE * scale,
             // 6.0× base - ultra large display text
-            x8l: 6.0 * FONT_SIZE_BASE * SCALING,
+            x8l: 6.0 * FONT_SIZE_BASE * scale,
             // 8.0× base - maximum impact display text
-            x9l: 8.0 * FONT_SIZE_BASE * SCALING,
+            x9l: 8.0 * FONT_SIZE_BASE * scale,
         }
     }
 }
@@ -721,7 +781,7 @@ impl Default for FontSize {
 /// - **Unicode comprehensive**: Support for international characters
 /// - **Performance tested**: Efficient loading and rendering characteristics
 /// - **Accessibility friendly**: Clear character distinction and readability
-#[derive(Resource, Debug, Clone)]
+#[derive(Resource, Debug, Clone, Default)]
 pub struct FontAssets {
     // === Sans-Serif Family (Roboto) ===
     // Complete weight range for maximum design flexibility
@@ -749,6 +809,7 @@ pub struct FontAssets {
     pub mono_bold: Handle<Font>,
 
     // === Icons ===
+    // Controller fonts without their `icons-*` feature keep the default handle
     pub interface: Handle<Font>,
     pub generic_controller: Handle<Font>,
     pub keyboard_mouse: Handle<Font>,
@@ -803,6 +864,10 @@ pub struct FontAssets {
 /// - Medium → Roboto Mono Regular (fallback for consistency)
 /// - Bold → Roboto Mono Bold
 ///
+/// **Controller icon families**: a single weight, compiled in with their
+/// `icons-*` cargo feature. Without it the family falls back to Sans (see
+/// [`FontFamily::is_available`]).
+///
 /// # Performance
 ///
 /// Font handles are cloned (which is efficient for Bevy handles) rather than
@@ -827,6 +892,10 @@ pub fn get_font_handle(
     family: FontFamily,
     weight: TextWeight,
 ) -> Handle<Font> {
+    if !family.is_available() {
+        return get_font_handle(assets, FontFamily::Sans, weight);
+    }
+
     match family {
         FontFamily::Sans => {
             // Sans family has all weight variants available
@@ -928,6 +997,48 @@ pub fn get_font_handle(
     }
 }
 
+/// Weight [`get_font_handle`] uses instead of `weight`, `None` when the
+/// family has that weight.
+///
+/// Serif and mono fonts only come in regular and bold; light and medium fall
+/// back to regular. Icon fonts have a single weight and are not reported.
+pub fn font_weight_fallback(family: FontFamily, weight: TextWeight) -> Option<TextWeight> {
+    match (family, weight) {
+        (FontFamily::Serif | FontFamily::Mono, TextWeight::Light | TextWeight::Medium) => {
+            Some(TextWeight::Regular)
+        }
+        _ => None,
+    }
+}
+
+/// Font fallbacks already reported, so each is warned about once.
+#[derive(Debug, Default)]
+pub struct FontFallbackWarnings {
+    warned: HashSet<(FontFamily, TextWeight)>,
+    unavailable: HashSet<FontFamily>,
+}
+
+impl FontFallbackWarnings {
+    /// Warning for a `family` / `weight` fallback that wasn't reported yet.
+    pub fn warning(&mut self, family: FontFamily, weight: TextWeight) -> Option<String> {
+        let fallback = font_weight_fallback(family, weight)?;
+        self.warned.insert((family, weight)).then(|| {
+            format!("{family:?} font has no {weight:?} weight, using {fallback:?} instead")
+        })
+    }
+
+    /// Warning for a `family` whose font is not compiled in, the first time
+    /// it's used.
+    pub fn unavailable_warning(&mut self, family: FontFamily) -> Option<String> {
+        if family.is_available() {
+            return None;
+        }
+        self.unavailable
+            .insert(family)
+            .then(|| format!("{family:?} font needs its icons-* feature, using Sans instead"))
+    }
+}
+
 /// Retrieves the exact pixel value for a given text size from the font size configuration.
 ///
 /// This function provides efficient access to the calculated font sizes in pixels,
@@ -1235,6 +1346,9 @@ pub fn get_effective_font_family(
 ///     └── RobotoMono-Bold.ttf    # Mono Bold
 /// ```
 ///
+/// Controller icon fonts under `assets/ui/icons/controllers/` are only loaded
+/// for the devices enabled with `icons-*` cargo features (all by default).
+///
 /// # System Integration
 ///
 /// This system should be added to the Startup schedule:
@@ -1271,7 +1385,8 @@ pub fn get_effective_font_family(
 pub fn load_font_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
     info!("Loading font assets for typography system...");
 
-    let font_assets = FontAssets {
+    #[allow(unused_mut)]
+    let mut font_assets = FontAssets {
         // === Sans-Serif Family (Roboto) ===
         // Complete weight range for maximum design flexibility
         sans_light: asset_server.load("fonts/Roboto-Light.ttf"),
@@ -1289,30 +1404,162 @@ pub fn load_font_assets(mut commands: Commands, asset_server: Res<AssetServer>)
         mono_regular: asset_server.load("fonts/RobotoMono-Regular.ttf"),
         mono_bold: asset_server.load("fonts/RobotoMono-Bold.ttf"),
         interface: asset_server.load("ui/icons/interface/lucide.ttf"),
-        generic_controller: asset_server
-            .load("ui/icons/controllers/generic/kenney_input_generic.otf"),
-        keyboard_mouse: asset_server
-            .load("ui/icons/controllers/keyboard_mouse/kenney_input_keyboard_&_mouse.ttf"),
-        nintendo_switch: asset_server
-            .load("ui/icons/controllers/nintendo_switch/kenney_input_nintendo_switch.ttf"),
-        nintendo_switch_2: asset_server
-            .load("ui/icons/controllers/nintendo_switch_2/kenney_input_nintendo_switch_2.ttf"),
-        playstation: asset_server
-            .load("ui/icons/controllers/playstation_series/kenney_input_playstation_series.ttf"),
-        xbox: asset_server.load("ui/icons/controllers/xbox_series/kenney_input_xbox_series.ttf"),
-        steam_controller: asset_server
-            .load("ui/icons/controllers/steam_controller/kenney_input_steam_controller.ttf"),
-        steam_deck: asset_server
-            .load("ui/icons/controllers/steam_deck/kenney_input_steam_deck.ttf"),
-        gamecube: asset_server
-            .load("ui/icons/controllers/nintendo_gamecube/kenney_input_nintendo_gamecube.ttf"),
-        wii: asset_server.load("ui/icons/controllers/nintendo_wii/kenney_input_nintendo_wii.ttf"),
-        wii_u: asset_server
-            .load("ui/icons/controllers/nintendo_wiiu/kenney_input_nintendo_wii_u.ttf"),
-        touch: asset_server.load("ui/icons/controllers/touch/kenney_input_touch.ttf"),
-        indicators: asset_server.load("ui/icons/controllers/indicators/kenney_input_flairs.ttf"),
+        ..default()
     };
 
+    // === Controller Icons ===
+    // Only the devices enabled with `icons-*` features
+    #[cfg(feature = "icons-generic")]
+    {
+        font_assets.generic_controller =
+            asset_server.load("ui/icons/controllers/generic/kenney_input_generic.otf");
+    }
+    #[cfg(feature = "icons-keyboard-mouse")]
+    {
+        font_assets.keyboard_mouse = asset_server
+            .load("ui/icons/controllers/keyboard_mouse/kenney_input_keyboard_&_mouse.ttf");
+    }
+    #[cfg(feature = "icons-switch")]
+    {
+        font_assets.nintendo_switch = asset_server
+            .load("ui/icons/controllers/nintendo_switch/kenney_input_nintendo_switch.ttf");
+    }
+    #[cfg(feature = "icons-switch-2")]
+    {
+        font_assets.nintendo_switch_2 = asset_server
+            .load("ui/icons/controllers/nintendo_switch_2/kenney_input_nintendo_switch_2.ttf");
+    }
+    #[cfg(feature = "icons-playstation")]
+    {
+        font_assets.playstation = asset_server
+            .load("ui/icons/controllers/playstation_series/kenney_input_playstation_series.ttf");
+    }
+    #[cfg(feature = "icons-xbox")]
+    {
+        font_assets.xbox =
+            asset_server.load("ui/icons/controllers/xbox_series/kenney_input_xbox_series.ttf");
+    }
+    #[cfg(feature = "icons-steam-controller")]
+    {
+        font_assets.steam_controller = asset_server
+            .load("ui/icons/controllers/steam_controller/kenney_input_steam_controller.ttf");
+    }
+    #[cfg(feature = "icons-steam-deck")]
+    {
+        font_assets.steam_deck =
+            asset_server.load("ui/icons/controllers/steam_deck/kenney_input_steam_deck.ttf");
+    }
+    #[cfg(feature = "icons-gamecube")]
+    {
+        font_assets.gamecube = asset_server
+            .load("ui/icons/controllers/nintendo_gamecube/kenney_input_nintendo_gamecube.ttf");
+    }
+    #[cfg(feature = "icons-wii")]
+    {
+        font_assets.wii =
+            asset_server.load("ui/icons/controllers/nintendo_wii/kenney_input_nintendo_wii.ttf");
+    }
+    #[cfg(feature = "icons-wii-u")]
+    {
+        font_assets.wii_u =
+            asset_server.load("ui/icons/controllers/nintendo_wiiu/kenney_input_nintendo_wii_u.ttf");
+    }
+    #[cfg(feature = "icons-touch")]
+    {
+        font_assets.touch = asset_server.load("ui/icons/controllers/touch/kenney_input_touch.ttf");
+    }
+    #[cfg(feature = "icons-indicators")]
+    {
+        font_assets.indicators =
+            asset_server.load("ui/icons/controllers/indicators/kenney_input_flairs.ttf");
+    }
+
     commands.insert_resource(font_assets);
     info!("Font assets resource created and typography system initialized");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::weak_handle;
+
+    #[test]
+    fn test_fallback_only_for_missing_weights() {
+        assert_eq!(
+            font_weight_fallback(FontFamily::Serif, TextWeight::Medium),
+            Some(TextWeight::Regular)
+        );
+        assert_eq!(
+            font_weight_fallback(FontFamily::Mono, TextWeight::Light),
+            Some(TextWeight::Regular)
+        );
+        assert_eq!(
+            font_weight_fallback(FontFamily::Mono, TextWeight::Bold),
+            None
+        );
+        assert_eq!(
+            font_weight_fallback(FontFamily::Sans, TextWeight::Light),
+            None
+        );
+    }
+
+    #[test]
+    fn test_warns_once_per_combination() {
+        let mut warnings = FontFallbackWarnings::default();
+        let requests = [
+            (FontFamily::Serif, TextWeight::Medium),
+            (FontFamily::Serif, TextWeight::Medium),
+            (FontFamily::Mono, TextWeight::Medium),
+            (FontFamily::Serif, TextWeight::Light),
+            (FontFamily::Sans, TextWeight::Medium),
+            (FontFamily::Mono, TextWeight::Medium),
+        ];
+        let warned: Vec<String> = requests
+            .into_iter()
+            .filter_map(|(family, weight)| warnings.warning(family, weight))
+            .collect();
+
+        assert_eq!(
+            warned,
+            [
+                "Serif font has no Medium weight, using Regular instead",
+                "Mono font has no Medium weight, using Regular instead",
+                "Serif font has no Light weight, using Regular instead",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_controller_fonts_follow_features() {
+        let assets = FontAssets {
+            sans_medium: weak_handle!("8f3c4ba1-57b0-4e7e-9d5a-0c1f2e6a7b01"),
+            xbox: weak_handle!("8f3c4ba1-57b0-4e7e-9d5a-0c1f2e6a7b02"),
+            touch: weak_handle!("8f3c4ba1-57b0-4e7e-9d5a-0c1f2e6a7b03"),
+            ..default()
+        };
+        let handle = |family| get_font_handle(&assets, family, TextWeight::Medium);
+
+        // Families without their feature render in Sans
+        let sans = assets.sans_medium.clone();
+        let xbox = if cfg!(feature = "icons-xbox") {
+            assets.xbox.clone()
+        } else {
+            sans.clone()
+        };
+        let touch = if cfg!(feature = "icons-touch") {
+            assets.touch.clone()
+        } else {
+            sans
+        };
+        assert_eq!(handle(FontFamily::Xbox), xbox);
+        assert_eq!(handle(FontFamily::Touch), touch);
+
+        let mut warnings = FontFallbackWarnings::default();
+        assert_eq!(warnings.unavailable_warning(FontFamily::Sans), None);
+        assert_eq!(
+            warnings.unavailable_warning(FontFamily::Xbox).is_some(),
+            !cfg!(feature = "icons-xbox")
+        );
+        assert_eq!(warnings.unavailable_warning(FontFamily::Xbox), None);
+    }
+}
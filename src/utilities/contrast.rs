@@ -0,0 +1,176 @@
+//! WCAG contrast checks for text and background colors.
+//!
+//! [`contrast_ratio`] computes the WCAG 2.x contrast ratio of two colors,
+//! from 1:1 (identical) to 21:1 (black on white), and [`meets_aa`] checks it
+//! against the AA minimum: 4.5:1 for body text, 3:1 for large text. Both are
+//! pure, for validating custom or generated palettes:
+//!
+//! ```rust
+//! use bevy::prelude::Color;
+//! use ui::utilities::contrast::meets_aa;
+//!
+//! assert!(meets_aa(Color::BLACK, Color::WHITE, false));
+//! ```
+//!
+//! With [`UiConfig::warn_low_contrast`](crate::plugin::UiConfig) set,
+//! [`find_low_contrast_text`] piped into [`warn_low_contrast_text`] also
+//! logs a warning for each text whose color fails AA on the background
+//! behind it, checked again when the text color or a background behind it
+//! changes. Colors are compared as they are; translucent colors are not
+//! blended with what is below them.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+/// Minimum AA contrast ratio of body text
+pub const AA_NORMAL_TEXT: f32 = 4.5;
+
+/// Minimum AA contrast ratio of large text
+pub const AA_LARGE_TEXT: f32 = 3.0;
+
+/// Font size from which text counts as large (18pt)
+pub const LARGE_TEXT_SIZE: f32 = 24.0;
+
+/// Relative luminance of `color`, from 0.0 (black) to 1.0 (white).
+pub fn relative_luminance(color: Color) -> f32 {
+    let srgba = color.to_srgba();
+    let linear = |c: f32| {
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(srgba.red) + 0.7152 * linear(srgba.green) + 0.0722 * linear(srgba.blue)
+}
+
+/// WCAG contrast ratio of `fg` on `bg`, from 1.0 to 21.0. The order of the
+/// colors doesn't matter.
+pub fn contrast_ratio(fg: Color, bg: Color) -> f32 {
+    let fg = relative_luminance(fg);
+    let bg = relative_luminance(bg);
+    (fg.max(bg) + 0.05) / (fg.min(bg) + 0.05)
+}
+
+/// Whether `fg` text on `bg` meets WCAG AA; `large` text (18pt, or 14pt
+/// bold) needs less contrast.
+pub fn meets_aa(fg: Color, bg: Color, large: bool) -> bool {
+    let minimum = if large { AA_LARGE_TEXT } else { AA_NORMAL_TEXT };
+    contrast_ratio(fg, bg) >= minimum
+}
+
+/// Texts whose color newly fails AA on the nearest visible background of it
+/// or its ancestors, with their contrast ratio. Each text is reported once.
+#[allow(clippy::type_complexity)]
+pub fn find_low_contrast_text(
+    texts: Query<(Entity, Ref<TextColor>, &TextFont), With<Text>>,
+    parents: Query<&ChildOf>,
+    backgrounds: Query<&BackgroundColor>,
+    changed_backgrounds: Query<Entity, Changed<BackgroundColor>>,
+    mut reported: Local<HashSet<Entity>>,
+) -> Vec<(Entity, f32)> {
+    // Forget despawned texts
+    reported.retain(|entity| texts.contains(*entity));
+    let changed_backgrounds: HashSet<Entity> = changed_backgrounds.iter().collect();
+
+    let mut failing = Vec::new();
+    for (entity, text_color, text_font) in &texts {
+        if reported.contains(&entity) {
+            continue;
+        }
+        let background_changed = !changed_backgrounds.is_empty()
+            && std::iter::once(entity)
+                .chain(parents.iter_ancestors(entity))
+                .any(|entity| changed_backgrounds.contains(&entity));
+        if !text_color.is_changed() && !background_changed {
+            continue;
+        }
+        let background = std::iter::once(entity)
+            .chain(parents.iter_ancestors(entity))
+            .filter_map(|entity| backgrounds.get(entity).ok())
+            .map(|background| background.0)
+            .find(|color| color.alpha() > 0.0);
+        let Some(background) = background else {
+            continue;
+        };
+
+        let large = text_font.font_size >= LARGE_TEXT_SIZE;
+        if !meets_aa(text_color.0, background, large) {
+            reported.insert(entity);
+            failing.push((entity, contrast_ratio(text_color.0, background)));
+        }
+    }
+    failing
+}
+
+/// Logs a warning for each text found by [`find_low_contrast_text`], which
+/// it is piped from.
+pub fn warn_low_contrast_text(In(failing): In<Vec<(Entity, f32)>>, names: Query<&Name>) {
+    for (entity, ratio) in failing {
+        let name = names
+            .get(entity)
+            .map_or_else(|_| entity.to_string(), |name| name.to_string());
+        warn!(
+            "Text \"{name}\" has a contrast ratio of {ratio:.2}:1 on its background, below WCAG AA"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_black_on_white_is_21_to_1() {
+        let ratio = contrast_ratio(Color::BLACK, Color::WHITE);
+        assert!((ratio - 21.0).abs() < 1e-3);
+        assert_eq!(ratio, contrast_ratio(Color::WHITE, Color::BLACK));
+        assert!(meets_aa(Color::BLACK, Color::WHITE, false));
+    }
+
+    #[test]
+    fn test_identical_colors_are_1_to_1() {
+        let gray = Color::srgb_u8(0x80, 0x80, 0x80);
+        assert!((contrast_ratio(gray, gray) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_mid_gray_on_white() {
+        // #777777 on white is 4.48:1: AA for large text only
+        let gray = Color::srgb_u8(0x77, 0x77, 0x77);
+        let ratio = contrast_ratio(gray, Color::WHITE);
+        assert!((ratio - 4.48).abs() < 0.01);
+        assert!(!meets_aa(gray, Color::WHITE, false));
+        assert!(meets_aa(gray, Color::WHITE, true));
+
+        // #767676 is the lightest gray that passes for body text
+        let darker = Color::srgb_u8(0x76, 0x76, 0x76);
+        assert!(meets_aa(darker, Color::WHITE, false));
+    }
+
+    #[test]
+    fn test_background_changes_recheck_text() {
+        let mut world = World::new();
+        let panel = world.spawn(BackgroundColor(Color::BLACK)).id();
+        let text = world
+            .spawn((Text::new("Hint"), TextColor(Color::srgb(0.9, 0.9, 0.9))))
+            .id();
+        world.entity_mut(panel).add_child(text);
+        let system = world.register_system(find_low_contrast_text);
+
+        // Light gray passes on black, and fails once the panel turns white
+        assert!(world.run_system(system).unwrap().is_empty());
+        world
+            .entity_mut(panel)
+            .insert(BackgroundColor(Color::WHITE));
+        let failing = world.run_system(system).unwrap();
+        assert_eq!(failing.len(), 1);
+        assert_eq!(failing[0].0, text);
+        // Reported once
+        world
+            .entity_mut(panel)
+            .insert(BackgroundColor(Color::WHITE));
+        assert!(world.run_system(system).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,294 @@
+//! Directional focus selection between arbitrary rects.
+//!
+//! [`next_focus`] picks the entity an arrow key or D-pad press should move
+//! the focus to. It is pure, so any navigation scheme can build on it: the
+//! caller collects the rects of the focusable candidates (in one coordinate
+//! space, y pointing down) and decides what happens when nothing lies in
+//! the pressed direction. There is no wrap-around.
+
+use bevy::prelude::*;
+
+/// Weight of the gap across the direction; a candidate off the current row
+/// (or column) has to be much closer to win over one in it
+const CROSS_GAP_WEIGHT: f32 = 10.0;
+/// Weight of the center offset across the direction, preferring the best
+/// aligned of the candidates in the same row (or column)
+const CROSS_CENTER_WEIGHT: f32 = 0.5;
+/// Weight of an overlap with the current rect along the direction
+const OVERLAP_WEIGHT: f32 = 2.0;
+
+/// Direction the focus moves in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl NavDirection {
+    fn is_horizontal(self) -> bool {
+        matches!(self, NavDirection::Left | NavDirection::Right)
+    }
+
+    fn is_forward(self) -> bool {
+        matches!(self, NavDirection::Down | NavDirection::Right)
+    }
+}
+
+/// Extent of a rect along an axis.
+#[derive(Clone, Copy)]
+struct Span {
+    min: f32,
+    max: f32,
+}
+
+impl Span {
+    fn center(self) -> f32 {
+        (self.min + self.max) / 2.0
+    }
+
+    /// Distance between the spans, 0 when they overlap.
+    fn gap(self, other: Span) -> f32 {
+        (other.min - self.max).max(self.min - other.max).max(0.0)
+    }
+}
+
+/// Spans of `rect` along and across `direction`.
+fn spans(rect: Rect, direction: NavDirection) -> (Span, Span) {
+    let x = Span {
+        min: rect.min.x,
+        max: rect.max.x,
+    };
+    let y = Span {
+        min: rect.min.y,
+        max: rect.max.y,
+    };
+    if direction.is_horizontal() {
+        (x, y)
+    } else {
+        (y, x)
+    }
+}
+
+/// Cost of moving from `current` to `candidate`, `None` when the candidate
+/// doesn't lie in `direction`.
+fn focus_cost(current: Rect, candidate: Rect, direction: NavDirection) -> Option<f32> {
+    let (main, cross) = spans(current, direction);
+    let (candidate_main, candidate_cross) = spans(candidate, direction);
+
+    // Signed distance of the centers, positive in the direction
+    let sign = if direction.is_forward() { 1.0 } else { -1.0 };
+    if sign * (candidate_main.center() - main.center()) <= 0.0 {
+        return None;
+    }
+
+    // Gap between the facing edges; negative when the rects overlap
+    let gap = if direction.is_forward() {
+        candidate_main.min - main.max
+    } else {
+        main.min - candidate_main.max
+    };
+    let distance = if gap >= 0.0 {
+        gap
+    } else {
+        -gap * OVERLAP_WEIGHT
+    };
+
+    Some(
+        distance
+            + CROSS_GAP_WEIGHT * cross.gap(candidate_cross)
+            + CROSS_CENTER_WEIGHT * (candidate_cross.center() - cross.center()).abs(),
+    )
+}
+
+/// Entity to focus when moving from `current` in `direction`.
+///
+/// Only candidates whose center lies beyond `current`'s center in the
+/// direction count. Of those, the one with the smallest gap wins, where
+/// distance across the direction weighs much more than distance along it,
+/// and overlapping `current` along the direction counts double. Equally
+/// good candidates resolve to the first in `candidates`. Returns `None` when
+/// nothing lies in the direction.
+pub fn next_focus(
+    current: Rect,
+    candidates: &[(Entity, Rect)],
+    direction: NavDirection,
+) -> Option<Entity> {
+    candidates
+        .iter()
+        .filter_map(|(entity, rect)| Some((focus_cost(current, *rect, direction)?, *entity)))
+        // `min_by` keeps the first of equal elements
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, entity)| entity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entities(world: &mut World, count: usize) -> Vec<Entity> {
+        (0..count).map(|_| world.spawn_empty().id()).collect()
+    }
+
+    /// Three by two grid of 100×40 cells with 20px gaps, row by row
+    fn grid(world: &mut World) -> Vec<(Entity, Rect)> {
+        let entities = entities(world, 6);
+        (0..6)
+            .map(|index| {
+                let min = Vec2::new((index % 3) as f32 * 120.0, (index / 3) as f32 * 60.0);
+                (
+                    entities[index],
+                    Rect::from_corners(min, min + Vec2::new(100.0, 40.0)),
+                )
+            })
+            .collect()
+    }
+
+    fn without(cells: &[(Entity, Rect)], index: usize) -> Vec<(Entity, Rect)> {
+        let mut cells = cells.to_vec();
+        cells.remove(index);
+        cells
+    }
+
+    #[test]
+    fn test_moves_to_grid_neighbours() {
+        let mut world = World::new();
+        let cells = grid(&mut world);
+        let candidates = without(&cells, 1);
+        let from = cells[1].1;
+
+        assert_eq!(
+            next_focus(from, &candidates, NavDirection::Right),
+            Some(cells[2].0)
+        );
+        assert_eq!(
+            next_focus(from, &candidates, NavDirection::Left),
+            Some(cells[0].0)
+        );
+        assert_eq!(
+            next_focus(from, &candidates, NavDirection::Down),
+            Some(cells[4].0)
+        );
+    }
+
+    #[test]
+    fn test_no_wrap_around_at_edges() {
+        let mut world = World::new();
+        let cells = grid(&mut world);
+
+        assert_eq!(
+            next_focus(cells[1].1, &without(&cells, 1), NavDirection::Up),
+            None
+        );
+        assert_eq!(
+            next_focus(cells[5].1, &without(&cells, 5), NavDirection::Right),
+            None
+        );
+        assert_eq!(next_focus(cells[0].1, &[], NavDirection::Down), None);
+    }
+
+    #[test]
+    fn test_prefers_aligned_over_closer_diagonal() {
+        let mut world = World::new();
+        let [diagonal, aligned] = entities(&mut world, 2)[..] else {
+            unreachable!()
+        };
+        let from = Rect::new(0.0, 0.0, 100.0, 40.0);
+        let candidates = [
+            // Closer, but a row below
+            (diagonal, Rect::new(110.0, 50.0, 210.0, 90.0)),
+            (aligned, Rect::new(200.0, 0.0, 300.0, 40.0)),
+        ];
+
+        assert_eq!(
+            next_focus(from, &candidates, NavDirection::Right),
+            Some(aligned)
+        );
+    }
+
+    #[test]
+    fn test_prefers_best_aligned_in_same_row() {
+        let mut world = World::new();
+        let [offset, centered] = entities(&mut world, 2)[..] else {
+            unreachable!()
+        };
+        let from = Rect::new(0.0, 0.0, 100.0, 40.0);
+        let candidates = [
+            (offset, Rect::new(150.0, 20.0, 250.0, 60.0)),
+            (centered, Rect::new(150.0, 0.0, 250.0, 40.0)),
+        ];
+
+        assert_eq!(
+            next_focus(from, &candidates, NavDirection::Right),
+            Some(centered)
+        );
+    }
+
+    #[test]
+    fn test_equidistant_candidates_resolve_to_first() {
+        let mut world = World::new();
+        let [left, right] = entities(&mut world, 2)[..] else {
+            unreachable!()
+        };
+        let from = Rect::new(100.0, 0.0, 200.0, 40.0);
+        let below_left = Rect::new(0.0, 60.0, 100.0, 100.0);
+        let below_right = Rect::new(200.0, 60.0, 300.0, 100.0);
+
+        assert_eq!(
+            next_focus(
+                from,
+                &[(left, below_left), (right, below_right)],
+                NavDirection::Down
+            ),
+            Some(left)
+        );
+        assert_eq!(
+            next_focus(
+                from,
+                &[(right, below_right), (left, below_left)],
+                NavDirection::Down
+            ),
+            Some(right)
+        );
+    }
+
+    #[test]
+    fn test_overlap_is_penalized() {
+        let mut world = World::new();
+        let [overlapping, separate] = entities(&mut world, 2)[..] else {
+            unreachable!()
+        };
+        let from = Rect::new(0.0, 0.0, 100.0, 40.0);
+        let candidates = [
+            // Starts 15px inside the current rect
+            (overlapping, Rect::new(85.0, 0.0, 185.0, 40.0)),
+            (separate, Rect::new(120.0, 0.0, 220.0, 40.0)),
+        ];
+
+        assert_eq!(
+            next_focus(from, &candidates, NavDirection::Right),
+            Some(separate)
+        );
+    }
+
+    #[test]
+    fn test_candidates_behind_center_are_ignored() {
+        let mut world = World::new();
+        let [contained] = entities(&mut world, 1)[..] else {
+            unreachable!()
+        };
+        let from = Rect::new(0.0, 0.0, 100.0, 40.0);
+        // Centered on the current rect: in no direction
+        let candidates = [(contained, Rect::new(25.0, 10.0, 75.0, 30.0))];
+
+        for direction in [
+            NavDirection::Up,
+            NavDirection::Down,
+            NavDirection::Left,
+            NavDirection::Right,
+        ] {
+            assert_eq!(next_focus(from, &candidates, direction), None);
+        }
+    }
+}
@@ -1,10 +1,34 @@
 use bevy::prelude::*;
 
+pub mod contrast;
+pub mod focus_nav;
+pub mod naming;
+pub mod node_edit;
+pub mod overlay;
+pub mod placement;
 pub mod portal;
+pub mod show_when;
+pub mod side_label;
+pub mod sizing;
+pub mod text_measure;
 pub mod text_styling;
+pub mod transition;
+pub mod typeahead;
 pub mod ui_root;
 
+pub use contrast::*;
+pub use focus_nav::*;
+pub use naming::*;
+pub use node_edit::*;
+pub use overlay::*;
+pub use placement::*;
 pub use portal::*;
+pub use show_when::*;
+pub use side_label::*;
+pub use sizing::*;
+pub use text_measure::*;
 pub use text_styling::*;
+pub use transition::*;
+pub use typeahead::*;
 pub use ui_root::*;
 
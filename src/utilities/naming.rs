@@ -0,0 +1,110 @@
+//! Namespaced entity names for debugging.
+//!
+//! Every builder sets a [`Name`], but in a larger app many entities end up
+//! with the same generic one. [`Namespace::namespace`] prefixes the name of
+//! a single component, and [`UiConfig::name_prefix`] every UI node:
+//!
+//! ```rust
+//! use ui::utilities::Namespace;
+//!
+//! // Named "settings/submit_Button"
+//! parent.spawn(ButtonBuilder::new("submit").namespace("settings").build());
+//!
+//! // Names every UI node "hud/…", including the namespaced ones above
+//! app.insert_resource(UiConfig {
+//!     name_prefix: Some("hud".into()),
+//!     ..default()
+//! });
+//! ```
+//!
+//! Namespaces nest from the outside in: calling `.namespace("a")` after
+//! `.namespace("b")` gives `a/b/…`.
+
+use bevy::prelude::*;
+
+use crate::plugin::UiConfig;
+
+/// Separator between namespaces and the name
+pub const NAMESPACE_SEPARATOR: char = '/';
+
+/// `.namespace()` for builders, which prefixes the entity [`Name`].
+pub trait Namespace: Sized {
+    /// The entity name the builder spawns with.
+    fn name_mut(&mut self) -> &mut String;
+
+    /// Prefixes the entity name with `namespace/`.
+    fn namespace(mut self, namespace: impl AsRef<str>) -> Self {
+        let name = self.name_mut();
+        *name = namespaced(namespace, name.as_str());
+        self
+    }
+}
+
+/// `name` within `namespace`, e.g. `settings/submit_Button`.
+pub fn namespaced(namespace: impl AsRef<str>, name: impl AsRef<str>) -> String {
+    format!(
+        "{}{NAMESPACE_SEPARATOR}{}",
+        namespace.as_ref(),
+        name.as_ref()
+    )
+}
+
+/// `name` without its namespaces, for labels derived from the name.
+pub fn strip_namespace(name: &str) -> &str {
+    name.rsplit_once(NAMESPACE_SEPARATOR)
+        .map_or(name, |(_, name)| name)
+}
+
+/// Prefixes the names of new UI nodes with [`UiConfig::name_prefix`].
+pub fn apply_name_prefix(
+    config: Res<UiConfig>,
+    mut names: Query<&mut Name, (Added<Name>, With<Node>)>,
+) {
+    let Some(prefix) = config.name_prefix.as_deref() else {
+        return;
+    };
+    for mut name in &mut names {
+        let prefixed = namespaced(prefix, name.as_str());
+        name.set(prefixed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::button::ButtonBuilder, test_support::test_app};
+
+    #[test]
+    fn test_namespace_prefixes_built_name() {
+        let mut world = World::new();
+        let button = world
+            .spawn(ButtonBuilder::new("submit").namespace("settings").build())
+            .id();
+        assert_eq!(
+            world.get::<Name>(button).unwrap().as_str(),
+            "settings/submit_Button"
+        );
+
+        assert_eq!(strip_namespace("app/settings/submit"), "submit");
+        assert_eq!(strip_namespace("submit"), "submit");
+    }
+
+    #[test]
+    fn test_config_prefixes_ui_nodes() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<UiConfig>().name_prefix = Some("hud".into());
+        let button = app
+            .world_mut()
+            .spawn(ButtonBuilder::new("pause").namespace("menu").build())
+            .id();
+        let other = app.world_mut().spawn(Name::new("Player")).id();
+        app.update();
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<Name>(button).unwrap().as_str(),
+            "hud/menu/pause_Button"
+        );
+        assert_eq!(world.get::<Name>(other).unwrap().as_str(), "Player");
+    }
+}
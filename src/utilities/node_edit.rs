@@ -0,0 +1,119 @@
+//! Raw [`Node`] edits for builders.
+//!
+//! Builders cover the common [`Node`] fields. [`WithNode::with_node`] sets
+//! any other field without forking the builder. Edits run last in `build`,
+//! after the builder has set its own fields, so they can also override
+//! those:
+//!
+//! ```rust
+//! use ui::utilities::WithNode;
+//!
+//! let list = FlexComponent::column("list")
+//!     .with_node(|node| node.overflow = Overflow::scroll_y())
+//!     .build();
+//! ```
+//!
+//! Builders support it by holding [`NodeEdits`], implementing
+//! [`WithNode::node_edits_mut`] and applying the edits to their node at the
+//! end of `build`.
+
+use std::fmt;
+
+use bevy::prelude::*;
+
+/// Edit of a builder's [`Node`].
+type NodeEdit = Box<dyn FnOnce(&mut Node) + Send + Sync>;
+
+/// Edits of a builder's [`Node`], in the order they were added.
+#[derive(Default)]
+pub struct NodeEdits(Vec<NodeEdit>);
+
+impl NodeEdits {
+    pub fn push(&mut self, edit: impl FnOnce(&mut Node) + Send + Sync + 'static) {
+        self.0.push(Box::new(edit));
+    }
+
+    /// Runs the edits on `node`.
+    pub fn apply(self, node: &mut Node) {
+        for edit in self.0 {
+            edit(node);
+        }
+    }
+}
+
+impl fmt::Debug for NodeEdits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NodeEdits({})", self.0.len())
+    }
+}
+
+/// Raw [`Node`] edits, for builders holding [`NodeEdits`].
+pub trait WithNode: Sized {
+    /// The edits applied to the built node.
+    fn node_edits_mut(&mut self) -> &mut NodeEdits;
+
+    /// Edits the built node after the builder has set its fields.
+    fn with_node(mut self, edit: impl FnOnce(&mut Node) + Send + Sync + 'static) -> Self {
+        self.node_edits_mut().push(edit);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{
+        box_component::BoxComponent, button::ButtonBuilder, card::CardBuilder, flex::FlexComponent,
+        grid::GridComponent, section::SectionComponent, stack::VStack,
+    };
+
+    fn built_node(bundle: impl Bundle) -> Node {
+        let mut world = World::new();
+        let entity = world.spawn(bundle).id();
+        world.get::<Node>(entity).unwrap().clone()
+    }
+
+    #[test]
+    fn test_with_node_sets_overflow_of_built_node() {
+        let clip = |node: &mut Node| node.overflow = Overflow::clip();
+        let nodes = [
+            built_node(BoxComponent::new("box").with_node(clip).build()),
+            built_node(FlexComponent::row("flex").with_node(clip).build()),
+            built_node(GridComponent::columns("grid", 2).with_node(clip).build()),
+            built_node(VStack::new("stack").with_node(clip).build()),
+            built_node(SectionComponent::new("section").with_node(clip).build()),
+            built_node(CardBuilder::new("card").with_node(clip).build()),
+            built_node(ButtonBuilder::new("button").with_node(clip).build()),
+        ];
+        for node in nodes {
+            assert_eq!(node.overflow, Overflow::clip());
+        }
+    }
+
+    #[test]
+    fn test_with_node_overrides_builder_values() {
+        let node = built_node(
+            GridComponent::columns("grid", 2)
+                .with_node(|node| {
+                    node.display = Display::Block;
+                    node.grid_template_columns.clear();
+                })
+                .build(),
+        );
+        assert_eq!(node.display, Display::Block);
+        assert!(node.grid_template_columns.is_empty());
+    }
+
+    #[test]
+    fn test_edits_run_in_order() {
+        let mut edits = NodeEdits::default();
+        edits.push(|node| node.width = Val::Px(10.0));
+        edits.push(|node| node.width = Val::Px(20.0));
+        edits.push(|node| node.overflow = Overflow::clip());
+
+        let mut node = Node::default();
+        edits.apply(&mut node);
+        assert_eq!(node.width, Val::Px(20.0));
+        assert_eq!(node.overflow, Overflow::clip());
+    }
+}
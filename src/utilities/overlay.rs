@@ -0,0 +1,422 @@
+//! Enter and exit animations of overlays.
+//!
+//! Dropdowns and other floating content carry an [`OverlayTransition`] to
+//! animate in when spawned and out when closed. Its state machine runs
+//! Opening → Open → Closing → Closed: [`close_overlay`] starts Closing (from
+//! wherever an unfinished opening got to), and [`animate_overlays`] despawns
+//! the overlay once the exit animation completes, instead of right away:
+//!
+//! ```rust
+//! let dropdown = commands
+//!     .spawn((dropdown_bundle, OverlayTransition::new(OverlayAnimation::fade_scale())))
+//!     .id();
+//! // Later, instead of despawning it:
+//! close_overlay(&mut commands, dropdown);
+//! ```
+//!
+//! The background, border and text colors of the overlay and its
+//! descendants fade; [`OverlayEffect::FadeScale`] also grows it from slightly
+//! smaller than its size, and [`OverlayEffect::Slide`] moves it in from one
+//! side by offsetting its margin. A closing overlay no longer reacts to the
+//! pointer. With [`UiMotion::reduced`] overlays open at once and close
+//! without delay.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_picking::prelude::Pickable;
+
+use crate::{
+    theme::motion::UiMotion,
+    utilities::{placement::PlacementSide, transition::Easing},
+};
+
+/// Duration of overlay animations unless set otherwise
+pub const DEFAULT_OVERLAY_DURATION: Duration = Duration::from_millis(150);
+
+/// Scale a [`OverlayEffect::FadeScale`] overlay grows from
+const SCALE_FROM: f32 = 0.95;
+
+/// Distance a [`OverlayEffect::Slide`] overlay slides in from
+const SLIDE_DISTANCE: f32 = 8.0;
+
+/// How an overlay moves while it fades.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlayEffect {
+    /// Grows from slightly smaller than its size
+    FadeScale,
+    /// Slides in from the given side
+    Slide(PlacementSide),
+}
+
+/// Enter animation of an overlay, played in reverse on close.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverlayAnimation {
+    pub effect: OverlayEffect,
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+impl OverlayAnimation {
+    pub fn fade_scale() -> Self {
+        Self {
+            effect: OverlayEffect::FadeScale,
+            duration: DEFAULT_OVERLAY_DURATION,
+            easing: Easing::EaseOut,
+        }
+    }
+
+    /// Slides in from `from`, e.g. [`PlacementSide::Top`] for content below
+    /// its trigger.
+    pub fn slide(from: PlacementSide) -> Self {
+        Self {
+            effect: OverlayEffect::Slide(from),
+            ..Self::fade_scale()
+        }
+    }
+
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+/// Lifecycle of an animated overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverlayState {
+    #[default]
+    Opening,
+    Open,
+    Closing,
+    /// Exit animation done; the overlay is despawned
+    Closed,
+}
+
+/// Colors of a node of an overlay when open.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OverlayColors {
+    background: Option<Color>,
+    border: Option<Color>,
+    text: Option<Color>,
+}
+
+/// Values the animation starts from and ends on when open.
+#[derive(Debug, Clone, PartialEq)]
+struct OverlayRest {
+    /// Colors of the overlay and its descendants
+    colors: Vec<(Entity, OverlayColors)>,
+    margin: UiRect,
+}
+
+/// Plays the [`OverlayAnimation`] of its overlay.
+#[derive(Component, Debug, Clone)]
+pub struct OverlayTransition {
+    pub animation: OverlayAnimation,
+    state: OverlayState,
+    /// Time spent in the current state
+    elapsed: Duration,
+    /// Captured in the first frame
+    rest: Option<OverlayRest>,
+}
+
+impl OverlayTransition {
+    pub fn new(animation: OverlayAnimation) -> Self {
+        Self {
+            animation,
+            state: OverlayState::Opening,
+            elapsed: Duration::ZERO,
+            rest: None,
+        }
+    }
+
+    pub fn state(&self) -> OverlayState {
+        self.state
+    }
+
+    /// How far the overlay is open, from `0.0` (closed) to `1.0` (open),
+    /// before easing.
+    pub fn openness(&self) -> f32 {
+        let t = if self.animation.duration.is_zero() {
+            1.0
+        } else {
+            (self.elapsed.as_secs_f32() / self.animation.duration.as_secs_f32()).min(1.0)
+        };
+        match self.state {
+            OverlayState::Opening => t,
+            OverlayState::Open => 1.0,
+            OverlayState::Closing => 1.0 - t,
+            OverlayState::Closed => 0.0,
+        }
+    }
+
+    /// Starts the exit animation, reversing an unfinished enter animation
+    /// from where it got to. Returns whether there is anything to animate.
+    pub fn close(&mut self) -> bool {
+        if self.animation.duration.is_zero() {
+            return false;
+        }
+        match self.state {
+            OverlayState::Opening => {
+                // As far into closing as opening had left to go
+                self.elapsed = self.animation.duration.saturating_sub(self.elapsed);
+                self.state = OverlayState::Closing;
+                true
+            }
+            OverlayState::Open => {
+                self.elapsed = Duration::ZERO;
+                self.state = OverlayState::Closing;
+                true
+            }
+            OverlayState::Closing => true,
+            OverlayState::Closed => false,
+        }
+    }
+
+    /// Advances the state machine by `delta`.
+    pub fn step(&mut self, delta: Duration) {
+        self.elapsed += delta;
+        if self.elapsed < self.animation.duration {
+            return;
+        }
+        self.state = match self.state {
+            OverlayState::Opening => OverlayState::Open,
+            OverlayState::Closing => OverlayState::Closed,
+            state => state,
+        };
+        self.elapsed = Duration::ZERO;
+    }
+}
+
+/// Closes `entity`: overlays with an [`OverlayTransition`] play their exit
+/// animation and are despawned when it ends, anything else is despawned
+/// right away.
+pub fn close_overlay(commands: &mut Commands, entity: Entity) {
+    commands.queue(move |world: &mut World| {
+        let reduced = world
+            .get_resource::<UiMotion>()
+            .is_some_and(|motion| motion.reduced);
+        let Ok(mut overlay) = world.get_entity_mut(entity) else {
+            return;
+        };
+        let animates = !reduced
+            && overlay
+                .get_mut::<OverlayTransition>()
+                .is_some_and(|mut transition| transition.close());
+        if !animates {
+            overlay.despawn();
+            return;
+        }
+
+        // Nothing of a closing overlay can be hovered or clicked anymore
+        let mut children = world.query::<&Children>();
+        let nodes: Vec<Entity> = std::iter::once(entity)
+            .chain(children.query(world).iter_descendants(entity))
+            .collect();
+        for node in nodes {
+            world.entity_mut(node).insert(Pickable::IGNORE);
+        }
+    });
+}
+
+fn with_alpha(color: Color, factor: f32) -> Color {
+    let alpha = color.alpha();
+    color.with_alpha(alpha * factor)
+}
+
+/// Adds `offset` pixels to a pixel margin; other margins are kept.
+fn offset_margin(margin: Val, offset: f32) -> Val {
+    match margin {
+        Val::Px(px) => Val::Px(px + offset),
+        margin => margin,
+    }
+}
+
+/// Advances overlay animations and despawns overlays whose exit animation
+/// ended.
+#[allow(clippy::type_complexity)]
+pub fn animate_overlays(
+    mut commands: Commands,
+    time: Res<Time>,
+    motion: Res<UiMotion>,
+    mut query: Query<(Entity, &mut OverlayTransition, &mut Transform, &mut Node)>,
+    children: Query<&Children>,
+    mut colors: Query<(
+        Option<&mut BackgroundColor>,
+        Option<&mut BorderColor>,
+        Option<&mut TextColor>,
+    )>,
+) {
+    for (entity, mut transition, mut transform, mut node) in &mut query {
+        if transition.state == OverlayState::Open && transition.rest.is_none() {
+            continue;
+        }
+        let rest = transition
+            .rest
+            .get_or_insert_with(|| OverlayRest {
+                colors: std::iter::once(entity)
+                    .chain(children.iter_descendants(entity))
+                    .filter_map(|node| {
+                        let (background, border, text) = colors.get(node).ok()?;
+                        let colors = OverlayColors {
+                            background: background.map(|background| background.0),
+                            border: border.map(|border| border.0),
+                            text: text.map(|text| text.0),
+                        };
+                        Some((node, colors))
+                    })
+                    .collect(),
+                margin: node.margin,
+            })
+            .clone();
+
+        // Reduced motion finishes every animation in the frame it starts
+        let delta = if motion.reduced {
+            transition.animation.duration
+        } else {
+            time.delta()
+        };
+        transition.step(delta);
+
+        match transition.state {
+            OverlayState::Closed => {
+                commands.entity(entity).despawn();
+                continue;
+            }
+            OverlayState::Open => {
+                // Hand the overlay back to its own systems
+                transition.rest = None;
+            }
+            _ => {}
+        }
+
+        let shown = transition.animation.easing.apply(transition.openness());
+        for (node, original) in &rest.colors {
+            let Ok((background, border, text)) = colors.get_mut(*node) else {
+                continue;
+            };
+            if let (Some(mut background), Some(color)) = (background, original.background) {
+                background.0 = with_alpha(color, shown);
+            }
+            if let (Some(mut border), Some(color)) = (border, original.border) {
+                border.0 = with_alpha(color, shown);
+            }
+            if let (Some(mut text), Some(color)) = (text, original.text) {
+                text.0 = with_alpha(color, shown);
+            }
+        }
+        match transition.animation.effect {
+            OverlayEffect::FadeScale => {
+                let scale = SCALE_FROM + (1.0 - SCALE_FROM) * shown;
+                transform.scale = Vec3::new(scale, scale, 1.0);
+            }
+            OverlayEffect::Slide(from) => {
+                let offset = SLIDE_DISTANCE * (1.0 - shown);
+                let mut margin = rest.margin;
+                match from {
+                    PlacementSide::Top => margin.top = offset_margin(margin.top, -offset),
+                    PlacementSide::Bottom => margin.top = offset_margin(margin.top, offset),
+                    PlacementSide::Left => margin.left = offset_margin(margin.left, -offset),
+                    PlacementSide::Right => margin.left = offset_margin(margin.left, offset),
+                }
+                node.margin = margin;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{advance_frames, test_app, FRAME};
+
+    fn spawn_overlay(app: &mut App, duration: Duration) -> Entity {
+        app.world_mut()
+            .spawn((
+                Node::default(),
+                BackgroundColor(Color::WHITE),
+                OverlayTransition::new(OverlayAnimation::fade_scale().duration(duration)),
+            ))
+            .with_child((Text::new("Item"), TextColor(Color::BLACK)))
+            .id()
+    }
+
+    fn close(app: &mut App, overlay: Entity) {
+        let world = app.world_mut();
+        close_overlay(&mut world.commands(), overlay);
+        world.flush();
+    }
+
+    #[test]
+    fn test_close_delays_despawn_until_exit_ends() {
+        let mut app = test_app();
+        let duration = FRAME * 10;
+        let overlay = spawn_overlay(&mut app, duration);
+        advance_frames(&mut app, 12);
+        let state = app
+            .world()
+            .get::<OverlayTransition>(overlay)
+            .unwrap()
+            .state();
+        assert_eq!(state, OverlayState::Open);
+
+        close(&mut app, overlay);
+        advance_frames(&mut app, 8);
+        let world = app.world();
+        let transition = world.get::<OverlayTransition>(overlay).unwrap();
+        assert_eq!(transition.state(), OverlayState::Closing);
+        let alpha = world.get::<BackgroundColor>(overlay).unwrap().0.alpha();
+        assert!(alpha > 0.0 && alpha < 1.0);
+        // Its content fades along and ignores the pointer
+        let item = world.get::<Children>(overlay).unwrap()[0];
+        assert_eq!(world.get::<TextColor>(item).unwrap().0.alpha(), alpha);
+        assert_eq!(world.get::<Pickable>(overlay), Some(&Pickable::IGNORE));
+        assert_eq!(world.get::<Pickable>(item), Some(&Pickable::IGNORE));
+
+        advance_frames(&mut app, 3);
+        assert!(app.world().get_entity(overlay).is_err());
+    }
+
+    #[test]
+    fn test_reduced_motion_despawns_at_once() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<UiMotion>().reduced = true;
+        let overlay = spawn_overlay(&mut app, FRAME * 10);
+        advance_frames(&mut app, 1);
+        let state = app
+            .world()
+            .get::<OverlayTransition>(overlay)
+            .unwrap()
+            .state();
+        assert_eq!(state, OverlayState::Open);
+
+        close(&mut app, overlay);
+        assert!(app.world().get_entity(overlay).is_err());
+    }
+
+    #[test]
+    fn test_closing_while_opening_reverses_from_current_point() {
+        let mut transition =
+            OverlayTransition::new(OverlayAnimation::fade_scale().duration(FRAME * 4));
+        transition.step(FRAME);
+        assert_eq!(transition.openness(), 0.25);
+
+        assert!(transition.close());
+        assert_eq!(transition.state(), OverlayState::Closing);
+        assert!((transition.openness() - 0.25).abs() < 1e-4);
+
+        transition.step(FRAME);
+        assert_eq!(transition.state(), OverlayState::Closed);
+    }
+
+    #[test]
+    fn test_zero_duration_does_not_animate_close() {
+        let mut transition =
+            OverlayTransition::new(OverlayAnimation::fade_scale().duration(Duration::ZERO));
+        assert!(!transition.close());
+    }
+}
@@ -0,0 +1,250 @@
+//! Collision-aware placement of floating content next to an anchor.
+//!
+//! Shared by hover cards and other floating content (popovers, tooltips):
+//! [`place_floating`] puts content of a given size on the preferred side of
+//! an anchor rect, flips it to the opposite side when the preferred side would
+//! overflow the bounds, and shifts it along the cross axis to stay inside.
+//! All rects are in logical UI pixels with the origin at the top left.
+
+use bevy::prelude::*;
+
+/// Side of the anchor the content is placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlacementSide {
+    Top,
+    Right,
+    #[default]
+    Bottom,
+    Left,
+}
+
+impl PlacementSide {
+    pub fn opposite(self) -> Self {
+        match self {
+            PlacementSide::Top => PlacementSide::Bottom,
+            PlacementSide::Right => PlacementSide::Left,
+            PlacementSide::Bottom => PlacementSide::Top,
+            PlacementSide::Left => PlacementSide::Right,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, PlacementSide::Top | PlacementSide::Bottom)
+    }
+}
+
+/// Alignment of the content with the anchor along the cross axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlacementAlign {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+/// How to place floating content.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlacementOptions {
+    /// Preferred side
+    pub side: PlacementSide,
+    /// Gap between anchor and content
+    pub side_offset: f32,
+    pub align: PlacementAlign,
+    /// Cross-axis shift applied after aligning
+    pub align_offset: f32,
+    /// Whether to flip and shift the content to keep it inside the bounds
+    pub avoid_collisions: bool,
+    /// Minimum distance kept from the bounds' edges
+    pub collision_padding: f32,
+}
+
+impl Default for PlacementOptions {
+    fn default() -> Self {
+        Self {
+            side: PlacementSide::default(),
+            side_offset: 0.0,
+            align: PlacementAlign::default(),
+            align_offset: 0.0,
+            avoid_collisions: true,
+            collision_padding: 0.0,
+        }
+    }
+}
+
+/// Where floating content ended up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placement {
+    /// Top-left corner of the content
+    pub position: Vec2,
+    /// Side actually used; the opposite of the preferred one when flipped
+    pub side: PlacementSide,
+}
+
+/// Top-left corner of `content` placed on `side` of `anchor`, before any
+/// collision handling.
+fn position_on_side(
+    anchor: Rect,
+    content: Vec2,
+    side: PlacementSide,
+    options: &PlacementOptions,
+) -> Vec2 {
+    let aligned = |start: f32, end: f32, length: f32| {
+        options.align_offset
+            + match options.align {
+                PlacementAlign::Start => start,
+                PlacementAlign::Center => (start + end - length) / 2.0,
+                PlacementAlign::End => end - length,
+            }
+    };
+
+    match side {
+        PlacementSide::Top => Vec2::new(
+            aligned(anchor.min.x, anchor.max.x, content.x),
+            anchor.min.y - options.side_offset - content.y,
+        ),
+        PlacementSide::Bottom => Vec2::new(
+            aligned(anchor.min.x, anchor.max.x, content.x),
+            anchor.max.y + options.side_offset,
+        ),
+        PlacementSide::Left => Vec2::new(
+            anchor.min.x - options.side_offset - content.x,
+            aligned(anchor.min.y, anchor.max.y, content.y),
+        ),
+        PlacementSide::Right => Vec2::new(
+            anchor.max.x + options.side_offset,
+            aligned(anchor.min.y, anchor.max.y, content.y),
+        ),
+    }
+}
+
+/// Whether content at `position` sticks out of `bounds` on the main axis of
+/// `side`.
+fn overflows(position: Vec2, content: Vec2, side: PlacementSide, bounds: Rect) -> bool {
+    match side {
+        PlacementSide::Top => position.y < bounds.min.y,
+        PlacementSide::Bottom => position.y + content.y > bounds.max.y,
+        PlacementSide::Left => position.x < bounds.min.x,
+        PlacementSide::Right => position.x + content.x > bounds.max.x,
+    }
+}
+
+/// Places `content_size` next to `anchor` within `bounds`.
+///
+/// With collision avoidance the content flips to the opposite side when only
+/// that side fits, and is shifted along the cross axis to stay
+/// `collision_padding` away from the bounds' edges. Content larger than the
+/// bounds sticks to their start.
+pub fn place_floating(
+    anchor: Rect,
+    content_size: Vec2,
+    bounds: Rect,
+    options: &PlacementOptions,
+) -> Placement {
+    let mut side = options.side;
+    let mut position = position_on_side(anchor, content_size, side, options);
+    if !options.avoid_collisions {
+        return Placement { position, side };
+    }
+
+    let padding = options.collision_padding;
+    let inner = Rect::from_corners(bounds.min + padding, bounds.max - padding);
+    if overflows(position, content_size, side, inner) {
+        let flipped = side.opposite();
+        let flipped_position = position_on_side(anchor, content_size, flipped, options);
+        if !overflows(flipped_position, content_size, flipped, inner) {
+            side = flipped;
+            position = flipped_position;
+        }
+    }
+
+    let shift = |value: f32, min: f32, max: f32, length: f32| value.min(max - length).max(min);
+    if side.is_vertical() {
+        position.x = shift(position.x, inner.min.x, inner.max.x, content_size.x);
+    } else {
+        position.y = shift(position.y, inner.min.y, inner.max.y, content_size.y);
+    }
+
+    Placement { position, side }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Rect = Rect {
+        min: Vec2::ZERO,
+        max: Vec2::new(800.0, 600.0),
+    };
+    const CONTENT: Vec2 = Vec2::new(200.0, 100.0);
+
+    fn options(side: PlacementSide) -> PlacementOptions {
+        PlacementOptions {
+            side,
+            side_offset: 4.0,
+            collision_padding: 10.0,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_fitting_content_keeps_preferred_side() {
+        let anchor = Rect::new(350.0, 250.0, 450.0, 290.0);
+        let placement = place_floating(anchor, CONTENT, WINDOW, &options(PlacementSide::Bottom));
+
+        assert_eq!(placement.side, PlacementSide::Bottom);
+        assert_eq!(placement.position, Vec2::new(300.0, 294.0));
+    }
+
+    #[test]
+    fn test_overflowing_side_flips_to_opposite() {
+        // Too close to the bottom edge for the content below
+        let anchor = Rect::new(350.0, 540.0, 450.0, 580.0);
+        let placement = place_floating(anchor, CONTENT, WINDOW, &options(PlacementSide::Bottom));
+        assert_eq!(placement.side, PlacementSide::Top);
+        assert_eq!(placement.position, Vec2::new(300.0, 436.0));
+
+        // Too close to the left edge for the content on the left
+        let anchor = Rect::new(20.0, 250.0, 120.0, 290.0);
+        let placement = place_floating(anchor, CONTENT, WINDOW, &options(PlacementSide::Left));
+        assert_eq!(placement.side, PlacementSide::Right);
+        assert_eq!(placement.position.x, 124.0);
+    }
+
+    #[test]
+    fn test_keeps_side_when_neither_fits() {
+        let anchor = Rect::new(350.0, 200.0, 450.0, 400.0);
+        let tall = Vec2::new(200.0, 250.0);
+        let placement = place_floating(anchor, tall, WINDOW, &options(PlacementSide::Bottom));
+
+        assert_eq!(placement.side, PlacementSide::Bottom);
+    }
+
+    #[test]
+    fn test_shifts_along_cross_axis_into_bounds() {
+        // Centered below an anchor at the right edge, the content would stick out
+        let anchor = Rect::new(740.0, 100.0, 790.0, 140.0);
+        let placement = place_floating(anchor, CONTENT, WINDOW, &options(PlacementSide::Bottom));
+        assert_eq!(placement.position, Vec2::new(590.0, 144.0));
+
+        let anchor = Rect::new(100.0, 0.0, 150.0, 20.0);
+        let placement = place_floating(anchor, CONTENT, WINDOW, &options(PlacementSide::Right));
+        assert_eq!(placement.position, Vec2::new(154.0, 10.0));
+    }
+
+    #[test]
+    fn test_disabled_collision_avoidance_keeps_raw_position() {
+        let anchor = Rect::new(350.0, 540.0, 450.0, 580.0);
+        let placement = place_floating(
+            anchor,
+            CONTENT,
+            WINDOW,
+            &PlacementOptions {
+                avoid_collisions: false,
+                ..options(PlacementSide::Bottom)
+            },
+        );
+
+        assert_eq!(placement.side, PlacementSide::Bottom);
+        assert_eq!(placement.position, Vec2::new(300.0, 584.0));
+    }
+}
@@ -1,7 +1,120 @@
-use bevy::prelude::*;
+//! Portals render UI content outside of its place in the hierarchy.
+//!
+//! Content is moved either into a named [`UIRoot`] or into the root of a
+//! [`PortalLayer`]. The layer roots are full-window nodes kept in
+//! [`PortalRoots`], one per layer, stacked with [`GlobalZIndex`] so that
+//! overlapping overlays never fight over the same depth:
+//!
+//! | Layer                    | Content                        | `GlobalZIndex` |
+//! |--------------------------|--------------------------------|----------------|
+//! | [`PortalLayer::Default`] | Content lifted out of clipping | 100            |
+//! | [`PortalLayer::Overlay`] | Dropdowns, hover cards         | 200            |
+//! | [`PortalLayer::Modal`]   | Dialogs and their backdrops    | 300            |
+//! | [`PortalLayer::Tooltip`] | Tooltips                       | 400            |
+//!
+//! Within a layer, later content draws above earlier content.
+//!
+//! Portaled content no longer sits below the entity it belongs to, so it
+//! isn't despawned along with it. A [`PortalOwner`] links the content to its
+//! owner, e.g. the [`Portal`] that moved it or the trigger of a hover card;
+//! [`despawn_orphaned_portal_content`] despawns the content once the owner is
+//! gone.
+
+use std::collections::HashMap;
+
+use bevy::{ecs::entity::Entities, prelude::*};
+use bevy_picking::prelude::Pickable;
 
 use crate::utilities::ui_root::UIRoot;
 
+/// Layer portal content renders into; each layer draws above the previous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum PortalLayer {
+    /// Content lifted out of its parent, e.g. to escape clipping
+    #[default]
+    Default,
+    /// Dropdowns, popovers and hover cards
+    Overlay,
+    /// Dialogs and their backdrops
+    Modal,
+    /// Tooltips, above everything else
+    Tooltip,
+}
+
+impl PortalLayer {
+    /// All layers, from bottom to top.
+    pub const ALL: [PortalLayer; 4] = [
+        PortalLayer::Default,
+        PortalLayer::Overlay,
+        PortalLayer::Modal,
+        PortalLayer::Tooltip,
+    ];
+
+    /// Stacking of the layer's root among all UI.
+    pub fn global_z_index(self) -> GlobalZIndex {
+        GlobalZIndex(match self {
+            PortalLayer::Default => 100,
+            PortalLayer::Overlay => 200,
+            PortalLayer::Modal => 300,
+            PortalLayer::Tooltip => 400,
+        })
+    }
+}
+
+/// Marks the root node of a portal layer.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PortalRoot {
+    pub layer: PortalLayer,
+}
+
+/// Root entities of the portal layers, spawned on first use.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PortalRoots {
+    roots: HashMap<PortalLayer, Entity>,
+}
+
+impl PortalRoots {
+    /// The root of `layer`, if it has been spawned.
+    pub fn get(&self, layer: PortalLayer) -> Option<Entity> {
+        self.roots.get(&layer).copied()
+    }
+
+    /// The root of `layer`, spawning it if it doesn't exist (anymore).
+    ///
+    /// A new root is recorded as soon as its entity is reserved, so content
+    /// portaled again before the spawn is applied shares it.
+    pub fn root(
+        &mut self,
+        commands: &mut Commands,
+        entities: &Entities,
+        layer: PortalLayer,
+    ) -> Entity {
+        if let Some(root) = self.get(layer).filter(|root| entities.contains(*root)) {
+            return root;
+        }
+        let root = commands.spawn_empty().id();
+        self.roots.insert(layer, root);
+        commands.entity(root).insert(portal_root(layer));
+        root
+    }
+}
+
+/// Creates the full-window root node of a portal layer.
+pub fn portal_root(layer: PortalLayer) -> impl Bundle {
+    (
+        Name::new(format!("Portal_{:?}_Root", layer)),
+        PortalRoot { layer },
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        layer.global_z_index(),
+        Pickable::IGNORE,
+    )
+}
+
 /// A portal component that renders its children in a different part of the UI hierarchy.
 ///
 /// Similar to React portals, this allows you to render UI elements outside of their
@@ -9,7 +122,8 @@ use crate::utilities::ui_root::UIRoot;
 /// and overlays that need to appear above other content.
 ///
 /// The portal automatically moves its children to a target container (UIRoot) when spawned.
-/// By default, it targets the first available UIRoot, but you can specify a named container.
+/// By default, it targets the first available UIRoot, but you can specify a named container
+/// or a [`PortalLayer`], whose root is taken from [`PortalRoots`].
 ///
 /// # Example
 /// ```rust
@@ -42,6 +156,8 @@ use crate::utilities::ui_root::UIRoot;
 #[derive(Component, Debug, Clone)]
 pub struct Portal {
     pub container: Option<String>,
+    /// Layer to render into; takes precedence over `container`
+    pub layer: Option<PortalLayer>,
 }
 
 impl Portal {
@@ -69,6 +185,7 @@ impl Portal {
 #[derive(Default)]
 pub struct PortalBuilder {
     container: Option<String>,
+    layer: Option<PortalLayer>,
 }
 
 impl PortalBuilder {
@@ -96,6 +213,20 @@ impl PortalBuilder {
         self
     }
 
+    /// Renders the portal content into the root of `layer`, above the content
+    /// of all lower layers.
+    ///
+    /// # Example
+    /// ```rust
+    /// let tooltip_portal = Portal::new()
+    ///     .portal_layer(PortalLayer::Tooltip)
+    ///     .build();
+    /// ```
+    pub fn portal_layer(mut self, layer: PortalLayer) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
     /// Builds the portal bundle with the configured settings.
     ///
     /// # Returns
@@ -104,6 +235,7 @@ impl PortalBuilder {
         (
             Portal {
                 container: self.container,
+                layer: self.layer,
             },
             Node::default(),
         )
@@ -119,12 +251,31 @@ pub struct PortalContent {
     pub portal_entity: Entity,
 }
 
+/// Entity owning portaled content; the content is despawned when its owner
+/// is.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortalOwner(pub Entity);
+
+/// System despawning portal content whose [`PortalOwner`] no longer exists.
+pub fn despawn_orphaned_portal_content(
+    mut commands: Commands,
+    content_query: Query<(Entity, &PortalOwner)>,
+    entities: &Entities,
+) {
+    for (content, PortalOwner(owner)) in content_query.iter() {
+        if !entities.contains(*owner) {
+            commands.entity(content).despawn();
+        }
+    }
+}
+
 /// System that handles the portal logic, moving children to target containers.
 ///
 /// This system runs when Portal components change and automatically:
-/// 1. Finds the target container (UIRoot) based on the portal configuration
+/// 1. Finds the target container (UIRoot or layer root) based on the portal configuration
 /// 2. Moves all children of the portal entity to the target container
-/// 3. Marks moved entities with PortalContent for tracking
+/// 3. Marks moved entities with PortalContent for tracking, and the portal
+///    as their [`PortalOwner`]
 ///
 /// The system ensures that portal content appears in the correct UI layer
 /// while maintaining the logical parent-child relationships for updates.
@@ -134,9 +285,13 @@ pub fn portal_system(
     ui_root_query: Query<Entity, With<UIRoot>>,
     name_query: Query<&Name>,
     portal_content_query: Query<(Entity, &mut PortalContent)>,
+    mut portal_roots: ResMut<PortalRoots>,
+    entities: &Entities,
 ) {
     for (portal_entity, portal, children) in portal_query.iter() {
-        let target_entity = if let Some(container_name) = &portal.container {
+        let target_entity = if let Some(layer) = portal.layer {
+            Some(portal_roots.root(&mut commands, entities, layer))
+        } else if let Some(container_name) = &portal.container {
             // Try to find the named container first
             ui_root_query
                 .iter()
@@ -159,7 +314,7 @@ pub fn portal_system(
                 if portal_content_query.get(child).is_err() {
                     commands
                         .entity(child)
-                        .insert(PortalContent { portal_entity });
+                        .insert((PortalContent { portal_entity }, PortalOwner(portal_entity)));
 
                     // Move child to the target container
                     commands.entity(target).add_child(child);
@@ -172,7 +327,9 @@ pub fn portal_system(
 
 /// Plugin that adds portal functionality to the Bevy app.
 ///
-/// Add this plugin to enable portal rendering in your application.
+/// Add this plugin to enable portal rendering in your application. The
+/// orphaned content of portals and hover cards is despawned by
+/// [`despawn_orphaned_portal_content`], which the `ComponentsPlugin` runs.
 ///
 /// # Example
 /// ```rust
@@ -190,6 +347,110 @@ pub struct PortalPlugin;
 
 impl Plugin for PortalPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, portal_system);
+        app.init_resource::<PortalRoots>()
+            .add_systems(Update, portal_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_layers_stack_in_order() {
+        let z = |layer: PortalLayer| layer.global_z_index().0;
+        assert!(z(PortalLayer::Default) < z(PortalLayer::Overlay));
+        assert!(z(PortalLayer::Overlay) < z(PortalLayer::Modal));
+        assert!(z(PortalLayer::Modal) < z(PortalLayer::Tooltip));
+    }
+
+    #[test]
+    fn test_layer_portal_moves_content_to_layer_root() {
+        let mut world = World::new();
+        world.init_resource::<PortalRoots>();
+        let content = world.spawn(Node::default()).id();
+        let portal = world
+            .spawn(Portal::new().portal_layer(PortalLayer::Modal).build())
+            .add_child(content)
+            .id();
+
+        world.run_system_once(portal_system).unwrap();
+        // Content of a second portal on the same layer shares its root
+        let other = world.spawn(Node::default()).id();
+        world
+            .spawn(Portal::new().portal_layer(PortalLayer::Modal).build())
+            .add_child(other);
+        world.run_system_once(portal_system).unwrap();
+
+        let root = world
+            .resource::<PortalRoots>()
+            .get(PortalLayer::Modal)
+            .unwrap();
+        assert_eq!(world.get::<ChildOf>(content).unwrap().parent(), root);
+        assert_eq!(world.get::<ChildOf>(other).unwrap().parent(), root);
+        assert!(world
+            .get::<Children>(portal)
+            .is_none_or(|children| children.is_empty()));
+        assert_eq!(world.get::<GlobalZIndex>(root).unwrap().0, 300);
+        assert_eq!(
+            world.resource::<PortalRoots>().get(PortalLayer::Tooltip),
+            None
+        );
+    }
+
+    #[test]
+    fn test_portals_share_a_layer_root_within_a_frame() {
+        let mut world = World::new();
+        world.init_resource::<PortalRoots>();
+        let contents: Vec<Entity> = (0..2)
+            .map(|_| {
+                let content = world.spawn(Node::default()).id();
+                world
+                    .spawn(Portal::new().portal_layer(PortalLayer::Tooltip).build())
+                    .add_child(content);
+                content
+            })
+            .collect();
+
+        // Both portals are moved by the same run, before the root is spawned
+        world.run_system_once(portal_system).unwrap();
+
+        let root = world
+            .resource::<PortalRoots>()
+            .get(PortalLayer::Tooltip)
+            .unwrap();
+        for content in contents {
+            assert_eq!(world.get::<ChildOf>(content).unwrap().parent(), root);
+        }
+        let mut roots = world.query::<&PortalRoot>();
+        assert_eq!(roots.iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn test_content_despawns_with_its_portal() {
+        let mut world = World::new();
+        world.init_resource::<PortalRoots>();
+        let content = world.spawn(Node::default()).id();
+        let portal = world
+            .spawn(Portal::new().portal_layer(PortalLayer::Overlay).build())
+            .add_child(content)
+            .id();
+        world.run_system_once(portal_system).unwrap();
+        assert_eq!(
+            world.get::<PortalOwner>(content),
+            Some(&PortalOwner(portal))
+        );
+
+        world
+            .run_system_once(despawn_orphaned_portal_content)
+            .unwrap();
+        assert!(world.get_entity(content).is_ok());
+
+        world.despawn(portal);
+        world
+            .run_system_once(despawn_orphaned_portal_content)
+            .unwrap();
+        assert!(world.get_entity(content).is_err());
     }
 }
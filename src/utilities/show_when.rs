@@ -0,0 +1,157 @@
+//! Showing nodes depending on an app state.
+//!
+//! A node with a [`ShowWhen<S>`] is shown while the [`State<S>`] equals its
+//! state and hidden otherwise, so panels follow the app's screens without a
+//! system of their own. Hidden nodes get [`Display::None`], taking no room
+//! in the layout, and [`Visibility::Hidden`]; showing them restores their
+//! previous display.
+//!
+//! The component is generic over the state type, so [`update_show_when`]
+//! has to be added for every state enum that nodes depend on:
+//!
+//! ```rust
+//! #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+//! enum Screen {
+//!     #[default]
+//!     Menu,
+//!     Game,
+//! }
+//!
+//! app.init_state::<Screen>()
+//!     .add_systems(Update, update_show_when::<Screen>);
+//!
+//! commands.spawn((
+//!     FlexComponent::column("main_menu").build(),
+//!     ShowWhen::in_state(Screen::Menu),
+//! ));
+//! ```
+//!
+//! For visibility known when building, layout builders offer
+//! `.visible_if(bool)`, which only sets the initial [`Visibility`].
+
+use bevy::prelude::*;
+
+/// Shows its node only while the [`State<S>`] is `state`.
+#[derive(Component, Debug, Clone)]
+pub struct ShowWhen<S: States> {
+    pub state: S,
+    /// Display to restore, `Some` while the node is hidden
+    hidden_display: Option<Display>,
+}
+
+impl<S: States> ShowWhen<S> {
+    pub fn in_state(state: S) -> Self {
+        Self {
+            state,
+            hidden_display: None,
+        }
+    }
+
+    /// Whether the node is hidden.
+    pub fn is_hidden(&self) -> bool {
+        self.hidden_display.is_some()
+    }
+
+    fn show(&mut self, node: &mut Node, visibility: &mut Visibility) {
+        if let Some(display) = self.hidden_display.take() {
+            node.display = display;
+        }
+        *visibility = Visibility::Inherited;
+    }
+
+    fn hide(&mut self, node: &mut Node, visibility: &mut Visibility) {
+        if self.hidden_display.is_none() {
+            self.hidden_display = Some(node.display);
+            node.display = Display::None;
+        }
+        *visibility = Visibility::Hidden;
+    }
+}
+
+/// Shows and hides [`ShowWhen<S>`] nodes when the state `S` changes, and
+/// new ones right away. Without a `State<S>` all of them are hidden.
+pub fn update_show_when<S: States>(
+    state: Option<Res<State<S>>>,
+    mut nodes: Query<(&mut ShowWhen<S>, &mut Node, &mut Visibility)>,
+) {
+    let state_changed = state.as_ref().is_none_or(|state| state.is_changed());
+    for (mut show_when, mut node, mut visibility) in &mut nodes {
+        if !state_changed && !show_when.is_added() {
+            continue;
+        }
+        let visible = state
+            .as_ref()
+            .is_some_and(|state| *state.get() == show_when.state);
+        if visible != show_when.is_hidden() && !show_when.is_added() {
+            continue;
+        }
+        // Only the stored display changes, which no one else watches
+        let show_when = show_when.bypass_change_detection();
+        if visible {
+            show_when.show(&mut node, &mut visibility);
+        } else {
+            show_when.hide(&mut node, &mut visibility);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::flex::FlexComponent, test_support::test_app};
+
+    #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    enum Screen {
+        #[default]
+        Menu,
+        Game,
+    }
+
+    #[test]
+    fn test_state_transitions_toggle_node() {
+        let mut app = test_app();
+        app.init_state::<Screen>()
+            .add_systems(Update, update_show_when::<Screen>);
+        let menu = app
+            .world_mut()
+            .spawn((
+                FlexComponent::column("menu").build(),
+                ShowWhen::in_state(Screen::Menu),
+            ))
+            .id();
+        let hud = app
+            .world_mut()
+            .spawn((
+                FlexComponent::row("hud").build(),
+                ShowWhen::in_state(Screen::Game),
+            ))
+            .id();
+        app.update();
+
+        let shown = |app: &App, entity: Entity| {
+            let node = app.world().get::<Node>(entity).unwrap();
+            let visibility = app.world().get::<Visibility>(entity).unwrap();
+            match (node.display, visibility) {
+                (Display::Flex, Visibility::Inherited) => true,
+                (Display::None, Visibility::Hidden) => false,
+                other => panic!("inconsistent display and visibility {other:?}"),
+            }
+        };
+        assert!(shown(&app, menu));
+        assert!(!shown(&app, hud));
+
+        app.world_mut()
+            .resource_mut::<NextState<Screen>>()
+            .set(Screen::Game);
+        app.update();
+        assert!(!shown(&app, menu));
+        assert!(shown(&app, hud));
+
+        app.world_mut()
+            .resource_mut::<NextState<Screen>>()
+            .set(Screen::Menu);
+        app.update();
+        assert!(shown(&app, menu));
+        assert!(!shown(&app, hud));
+    }
+}
@@ -0,0 +1,113 @@
+//! Labels placed beside a control.
+//!
+//! Some controls, like the switch with its on/off labels, place labels
+//! absolutely just outside their own box, so the labels take no room in the
+//! layout. A [`SideLabel`] anchors such a label and grows the control's
+//! margin on its side to the label's laid out width, so it doesn't overlap
+//! the control's neighbours, whatever its font or script.
+//!
+//! ```rust
+//! use ui::utilities::{LabelSide, SideLabel};
+//!
+//! let label = SideLabel::new(LabelSide::Right, 8.0);
+//! commands.entity(control).with_child((
+//!     Text::new("Wi-Fi"),
+//!     label.node(),
+//!     label,
+//! ));
+//! ```
+
+use bevy::prelude::*;
+
+/// Side of a control a [`SideLabel`] is placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelSide {
+    Left,
+    Right,
+}
+
+/// Label placed outside its parent control, on `side`.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SideLabel {
+    pub side: LabelSide,
+    /// Gap between the control and the label
+    pub gap: f32,
+}
+
+impl SideLabel {
+    pub fn new(side: LabelSide, gap: f32) -> Self {
+        Self { side, gap }
+    }
+
+    /// Node anchoring the label just outside the control on its side.
+    pub fn node(&self) -> Node {
+        let outside = Val::Percent(100.0);
+        match self.side {
+            LabelSide::Left => Node {
+                position_type: PositionType::Absolute,
+                right: outside,
+                margin: UiRect::right(Val::Px(self.gap)),
+                ..default()
+            },
+            LabelSide::Right => Node {
+                position_type: PositionType::Absolute,
+                left: outside,
+                margin: UiRect::left(Val::Px(self.gap)),
+                ..default()
+            },
+        }
+    }
+}
+
+/// System that sets the margin of controls on the side of their
+/// [`SideLabel`]s to the labels' width plus gap.
+pub fn reserve_side_label_space(
+    labels: Query<(&SideLabel, &ComputedNode, &ChildOf), Changed<ComputedNode>>,
+    mut nodes: Query<&mut Node>,
+) {
+    for (label, computed, child_of) in &labels {
+        let Ok(mut node) = nodes.get_mut(child_of.parent()) else {
+            continue;
+        };
+        let space = Val::Px(computed.size().x * computed.inverse_scale_factor() + label.gap);
+        let margin = match label.side {
+            LabelSide::Left => node.margin.left,
+            LabelSide::Right => node.margin.right,
+        };
+        if margin != space {
+            match label.side {
+                LabelSide::Left => node.margin.left = space,
+                LabelSide::Right => node.margin.right = space,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_controls_make_room_for_their_labels() {
+        let mut world = World::new();
+        let control = world.spawn(Node::default()).id();
+        let label = SideLabel::new(LabelSide::Left, 8.0);
+        world.spawn((
+            label.node(),
+            label,
+            ComputedNode {
+                size: Vec2::new(120.0, 20.0),
+                inverse_scale_factor: 0.5,
+                ..default()
+            },
+            ChildOf(control),
+        ));
+
+        world.run_system_once(reserve_side_label_space).unwrap();
+
+        let margin = world.get::<Node>(control).unwrap().margin;
+        assert_eq!(margin.left, Val::Px(68.0));
+        assert_eq!(margin.right, Val::ZERO);
+    }
+}
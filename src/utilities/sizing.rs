@@ -0,0 +1,235 @@
+//! Unit-specific size methods for layout builders.
+//!
+//! The builders of boxes, flex and grid containers take [`Val`]s for their
+//! sizes. [`NodeSizing`] adds a method per size property and unit on top,
+//! so the unit is part of the name:
+//!
+//! ```rust
+//! use ui::utilities::NodeSizing;
+//!
+//! let sidebar = FlexComponent::column("sidebar")
+//!     .width_px(280.0)
+//!     .height_vh(100.0)
+//!     .max_width_pct(30.0)
+//!     .build();
+//! ```
+//!
+//! Other builders with a [`Node`] get all methods by implementing
+//! [`NodeSizing::node_mut`].
+
+use bevy::prelude::*;
+
+/// Width and height methods per unit, for builders holding a [`Node`].
+pub trait NodeSizing: Sized {
+    /// The node the methods write to.
+    fn node_mut(&mut self) -> &mut Node;
+
+    // === WIDTH ===
+
+    /// Sets the width in logical pixels.
+    fn width_px(mut self, value: f32) -> Self {
+        self.node_mut().width = Val::Px(value);
+        self
+    }
+
+    /// Sets the width in percent of the parent.
+    fn width_pct(mut self, percent: f32) -> Self {
+        self.node_mut().width = Val::Percent(percent);
+        self
+    }
+
+    /// Sets the width in percent of the viewport width.
+    fn width_vw(mut self, vw: f32) -> Self {
+        self.node_mut().width = Val::Vw(vw);
+        self
+    }
+
+    /// Sets the width in percent of the viewport height.
+    fn width_vh(mut self, vh: f32) -> Self {
+        self.node_mut().width = Val::Vh(vh);
+        self
+    }
+
+    // === HEIGHT ===
+
+    /// Sets the height in logical pixels.
+    fn height_px(mut self, value: f32) -> Self {
+        self.node_mut().height = Val::Px(value);
+        self
+    }
+
+    /// Sets the height in percent of the parent.
+    fn height_pct(mut self, percent: f32) -> Self {
+        self.node_mut().height = Val::Percent(percent);
+        self
+    }
+
+    /// Sets the height in percent of the viewport width.
+    fn height_vw(mut self, vw: f32) -> Self {
+        self.node_mut().height = Val::Vw(vw);
+        self
+    }
+
+    /// Sets the height in percent of the viewport height.
+    fn height_vh(mut self, vh: f32) -> Self {
+        self.node_mut().height = Val::Vh(vh);
+        self
+    }
+
+    // === MIN WIDTH ===
+
+    /// Sets the minimum width in logical pixels.
+    fn min_width_px(mut self, value: f32) -> Self {
+        self.node_mut().min_width = Val::Px(value);
+        self
+    }
+
+    /// Sets the minimum width in percent of the parent.
+    fn min_width_pct(mut self, percent: f32) -> Self {
+        self.node_mut().min_width = Val::Percent(percent);
+        self
+    }
+
+    /// Sets the minimum width in percent of the viewport width.
+    fn min_width_vw(mut self, vw: f32) -> Self {
+        self.node_mut().min_width = Val::Vw(vw);
+        self
+    }
+
+    /// Sets the minimum width in percent of the viewport height.
+    fn min_width_vh(mut self, vh: f32) -> Self {
+        self.node_mut().min_width = Val::Vh(vh);
+        self
+    }
+
+    // === MIN HEIGHT ===
+
+    /// Sets the minimum height in logical pixels.
+    fn min_height_px(mut self, value: f32) -> Self {
+        self.node_mut().min_height = Val::Px(value);
+        self
+    }
+
+    /// Sets the minimum height in percent of the parent.
+    fn min_height_pct(mut self, percent: f32) -> Self {
+        self.node_mut().min_height = Val::Percent(percent);
+        self
+    }
+
+    /// Sets the minimum height in percent of the viewport width.
+    fn min_height_vw(mut self, vw: f32) -> Self {
+        self.node_mut().min_height = Val::Vw(vw);
+        self
+    }
+
+    /// Sets the minimum height in percent of the viewport height.
+    fn min_height_vh(mut self, vh: f32) -> Self {
+        self.node_mut().min_height = Val::Vh(vh);
+        self
+    }
+
+    // === MAX WIDTH ===
+
+    /// Sets the maximum width in logical pixels.
+    fn max_width_px(mut self, value: f32) -> Self {
+        self.node_mut().max_width = Val::Px(value);
+        self
+    }
+
+    /// Sets the maximum width in percent of the parent.
+    fn max_width_pct(mut self, percent: f32) -> Self {
+        self.node_mut().max_width = Val::Percent(percent);
+        self
+    }
+
+    /// Sets the maximum width in percent of the viewport width.
+    fn max_width_vw(mut self, vw: f32) -> Self {
+        self.node_mut().max_width = Val::Vw(vw);
+        self
+    }
+
+    /// Sets the maximum width in percent of the viewport height.
+    fn max_width_vh(mut self, vh: f32) -> Self {
+        self.node_mut().max_width = Val::Vh(vh);
+        self
+    }
+
+    // === MAX HEIGHT ===
+
+    /// Sets the maximum height in logical pixels.
+    fn max_height_px(mut self, value: f32) -> Self {
+        self.node_mut().max_height = Val::Px(value);
+        self
+    }
+
+    /// Sets the maximum height in percent of the parent.
+    fn max_height_pct(mut self, percent: f32) -> Self {
+        self.node_mut().max_height = Val::Percent(percent);
+        self
+    }
+
+    /// Sets the maximum height in percent of the viewport width.
+    fn max_height_vw(mut self, vw: f32) -> Self {
+        self.node_mut().max_height = Val::Vw(vw);
+        self
+    }
+
+    /// Sets the maximum height in percent of the viewport height.
+    fn max_height_vh(mut self, vh: f32) -> Self {
+        self.node_mut().max_height = Val::Vh(vh);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{
+        box_component::BoxComponent, flex::FlexComponent, grid::GridComponent,
+    };
+
+    fn spawned_node(bundle: impl Bundle) -> Node {
+        let mut world = World::new();
+        let entity = world.spawn(bundle).id();
+        world.get::<Node>(entity).unwrap().clone()
+    }
+
+    #[test]
+    fn test_sizes_use_their_unit() {
+        let node = spawned_node(
+            BoxComponent::new("panel")
+                .width_pct(50.0)
+                .height_px(120.0)
+                .min_width_vw(20.0)
+                .max_height_vh(80.0)
+                .build(),
+        );
+        assert_eq!(node.width, Val::Percent(50.0));
+        assert_eq!(node.height, Val::Px(120.0));
+        assert_eq!(node.min_width, Val::Vw(20.0));
+        assert_eq!(node.max_height, Val::Vh(80.0));
+    }
+
+    #[test]
+    fn test_flex_and_grid_builders_size_their_node() {
+        let node = spawned_node(
+            FlexComponent::row("toolbar")
+                .width_vw(80.0)
+                .min_height_px(32.0)
+                .build(),
+        );
+        assert_eq!(node.width, Val::Vw(80.0));
+        assert_eq!(node.min_height, Val::Px(32.0));
+
+        let node = spawned_node(
+            GridComponent::new("gallery")
+                .height_vh(100.0)
+                .max_width_pct(75.0)
+                .min_height_vh(50.0)
+                .build(),
+        );
+        assert_eq!(node.height, Val::Vh(100.0));
+        assert_eq!(node.max_width, Val::Percent(75.0));
+        assert_eq!(node.min_height, Val::Vh(50.0));
+    }
+}
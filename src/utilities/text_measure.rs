@@ -0,0 +1,207 @@
+//! Measuring text without spawning it.
+//!
+//! [`TextMeasure`] lays out a string with the same text shaping Bevy renders
+//! with and returns its size in logical pixels, e.g. to size a column to its
+//! longest label or to check whether a text needs truncating before
+//! spawning it:
+//!
+//! ```rust
+//! fn size_column(mut measure: TextMeasure, labels: Res<Labels>) {
+//!     let font = TextFont::from_font_size(16.0);
+//!     let widest = labels
+//!         .iter()
+//!         .map(|label| measure.measure_text(label, &font, None).x)
+//!         .fold(0.0, f32::max);
+//! }
+//! ```
+//!
+//! With a `max_width` the text wraps at word boundaries like a text node of
+//! that width, and the height grows with the lines. Fonts that haven't
+//! loaded yet measure as [`Vec2::ZERO`].
+//!
+//! Measuring uses the font face Bevy's text pipeline loaded for the font,
+//! with its weight, style and stretch. Fonts no text has been laid out in
+//! yet are added to the font database once and found there afterwards.
+//!
+//! Components reacting to their own laid out text, such as fitted and
+//! marquee texts, read [`TextLayoutInfo`](bevy::text::TextLayoutInfo)
+//! instead.
+
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::*,
+    text::{
+        cosmic_text::{
+            fontdb::{Source, ID},
+            Attrs, Buffer, Family, FontSystem, Metrics, Shaping,
+        },
+        CosmicFontSystem, LineHeight, TextPipeline,
+    },
+};
+
+/// System parameter measuring strings in a [`TextFont`].
+#[derive(SystemParam)]
+pub struct TextMeasure<'w> {
+    font_system: ResMut<'w, CosmicFontSystem>,
+    fonts: Res<'w, Assets<Font>>,
+    text_pipeline: Res<'w, TextPipeline>,
+}
+
+impl TextMeasure<'_> {
+    /// Size of `text` in `font`, wrapped at `max_width` if given.
+    pub fn measure_text(&mut self, text: &str, font: &TextFont, max_width: Option<f32>) -> Vec2 {
+        let id = font.font.id();
+        let Some(face) = self.text_pipeline.get_font_id(id).or_else(|| {
+            let asset = self.fonts.get(id)?;
+            load_font_face(&mut self.font_system, asset)
+        }) else {
+            return Vec2::ZERO;
+        };
+
+        measure_text_in(
+            &mut self.font_system,
+            face,
+            text,
+            font.font_size,
+            line_height(font),
+            max_width,
+        )
+    }
+}
+
+/// Line height of `font` in logical pixels.
+pub fn line_height(font: &TextFont) -> f32 {
+    match font.line_height {
+        LineHeight::Px(px) => px,
+        LineHeight::RelativeToFont(scale) => scale * font.font_size,
+    }
+}
+
+/// Face of `font` in the font database, adding the font if it isn't there
+/// yet.
+///
+/// Like Bevy's text pipeline, takes the last face of a font collection.
+pub fn load_font_face(font_system: &mut FontSystem, font: &Font) -> Option<ID> {
+    let loaded = font_system
+        .db()
+        .faces()
+        .filter(|face| match &face.source {
+            Source::Binary(data) => (**data).as_ref().as_ptr() == font.data.as_ptr(),
+            _ => false,
+        })
+        .last();
+    if let Some(face) = loaded {
+        return Some(face.id);
+    }
+    let ids = font_system
+        .db_mut()
+        .load_font_source(Source::Binary(font.data.clone()));
+    ids.last().copied()
+}
+
+/// Size of `text` in the loaded font `face`, wrapped at `max_width` if given.
+pub fn measure_text_in(
+    font_system: &mut FontSystem,
+    face: ID,
+    text: &str,
+    font_size: f32,
+    line_height: f32,
+    max_width: Option<f32>,
+) -> Vec2 {
+    if text.is_empty() || font_size <= 0.0 {
+        return Vec2::ZERO;
+    }
+    let Some((family, weight, style, stretch)) = font_system.db().face(face).and_then(|info| {
+        let (family, _) = info.families.first()?;
+        Some((family.clone(), info.weight, info.style, info.stretch))
+    }) else {
+        return Vec2::ZERO;
+    };
+
+    let mut buffer = Buffer::new(font_system, Metrics::new(font_size, line_height));
+    buffer.set_size(font_system, max_width, None);
+    buffer.set_text(
+        font_system,
+        text,
+        Attrs::new()
+            .family(Family::Name(&family))
+            .weight(weight)
+            .style(style)
+            .stretch(stretch),
+        Shaping::Advanced,
+    );
+    buffer.shape_until_scroll(font_system, false);
+
+    buffer.layout_runs().fold(Vec2::ZERO, |size, run| {
+        Vec2::new(size.x.max(run.line_w), size.y + run.line_height)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::text::cosmic_text::fontdb::{Database, Style, Weight};
+
+    /// Font system with only Roboto, instead of the system fonts
+    fn roboto() -> (FontSystem, ID) {
+        let mut font_system = FontSystem::new_with_locale_and_db("en-US".into(), Database::new());
+        let face = load_font_face(&mut font_system, &roboto_font()).unwrap();
+        (font_system, face)
+    }
+
+    fn roboto_font() -> Font {
+        Font::try_from_bytes(include_bytes!("../../assets/fonts/Roboto-Regular.ttf").to_vec())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_width_grows_with_length() {
+        let (mut font_system, face) = roboto();
+        let widths: Vec<f32> = ["", "W", "Wo", "Wor", "Word", "Words"]
+            .into_iter()
+            .map(|text| measure_text_in(&mut font_system, face, text, 16.0, 20.0, None).x)
+            .collect();
+
+        assert_eq!(widths[0], 0.0);
+        assert!(
+            widths.windows(2).all(|pair| pair[0] < pair[1]),
+            "{widths:?}"
+        );
+
+        // Twice the font size, about twice the width
+        let large = measure_text_in(&mut font_system, face, "Words", 32.0, 40.0, None).x;
+        assert!((large / widths[5] - 2.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_fonts_are_loaded_once() {
+        let mut font_system = FontSystem::new_with_locale_and_db("en-US".into(), Database::new());
+        let font = roboto_font();
+        let face = load_font_face(&mut font_system, &font).unwrap();
+
+        assert_eq!(load_font_face(&mut font_system, &font), Some(face));
+        assert_eq!(font_system.db().len(), 1);
+        let info = font_system.db().face(face).unwrap();
+        assert_eq!(info.weight, Weight::NORMAL);
+        assert_eq!(info.style, Style::Normal);
+    }
+
+    #[test]
+    fn test_max_width_wraps_lines() {
+        let (mut font_system, face) = roboto();
+        let text = "one two three four five six";
+        let single = measure_text_in(&mut font_system, face, text, 16.0, 20.0, None);
+        assert_eq!(single.y, 20.0);
+
+        let wrapped = measure_text_in(
+            &mut font_system,
+            face,
+            text,
+            16.0,
+            20.0,
+            Some(single.x / 2.0),
+        );
+        assert!(wrapped.x <= single.x / 2.0);
+        assert!(wrapped.y >= 40.0);
+    }
+}
@@ -0,0 +1,259 @@
+//! Smooth transitions between styling states.
+//!
+//! Styling systems write their target values (a hover background, a checked
+//! border, …) straight into the components. An entity with a
+//! [`Transition<T>`] catches each such change of `T` in
+//! [`animate_transitions`] and tweens from the value shown before to the new
+//! target over the transition's duration, with its [`Easing`]. The styling
+//! systems themselves stay unaware of the animation.
+//!
+//! Buttons, radios and switches opt in with `.transition(duration, easing)`;
+//! any other entity can carry the components directly:
+//!
+//! ```rust
+//! commands.spawn((
+//!     my_panel_bundle,
+//!     Transition::<BackgroundColor>::new(Duration::from_millis(150), Easing::EaseOut),
+//! ));
+//! ```
+//!
+//! [`Tween`] is implemented for [`BackgroundColor`], [`BorderColor`] and
+//! [`Node`], whose size and offsets are tweened while every other property
+//! switches at once.
+
+use std::time::Duration;
+
+use bevy::{ecs::component::Mutable, prelude::*};
+
+use crate::theme::motion::UiMotion;
+
+/// Easing curve of a [`Transition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    /// Starts slowly, then speeds up
+    EaseIn,
+    /// Starts quickly, then slows down
+    EaseOut,
+    /// Slow at both ends
+    EaseInOut,
+}
+
+impl Easing {
+    /// Eased progress for linear progress `t` in `0.0..=1.0`.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// Component value that can be tweened.
+pub trait Tween: Component<Mutability = Mutable> + Clone + PartialEq {
+    /// Value at progress `t` from `from` to `to`.
+    fn interpolate(from: &Self, to: &Self, t: f32) -> Self;
+}
+
+fn mix_colors(from: Color, to: Color, t: f32) -> Color {
+    from.to_srgba().mix(&to.to_srgba(), t).into()
+}
+
+impl Tween for BackgroundColor {
+    fn interpolate(from: &Self, to: &Self, t: f32) -> Self {
+        BackgroundColor(mix_colors(from.0, to.0, t))
+    }
+}
+
+impl Tween for BorderColor {
+    fn interpolate(from: &Self, to: &Self, t: f32) -> Self {
+        BorderColor(mix_colors(from.0, to.0, t))
+    }
+}
+
+/// Interpolates values of the same unit; others switch to `to` at once.
+fn mix_vals(from: Val, to: Val, t: f32) -> Val {
+    let mix = |from: f32, to: f32| from + (to - from) * t;
+    match (from, to) {
+        (Val::Px(from), Val::Px(to)) => Val::Px(mix(from, to)),
+        (Val::Percent(from), Val::Percent(to)) => Val::Percent(mix(from, to)),
+        (Val::Vw(from), Val::Vw(to)) => Val::Vw(mix(from, to)),
+        (Val::Vh(from), Val::Vh(to)) => Val::Vh(mix(from, to)),
+        _ => to,
+    }
+}
+
+impl Tween for Node {
+    fn interpolate(from: &Self, to: &Self, t: f32) -> Self {
+        Node {
+            width: mix_vals(from.width, to.width, t),
+            height: mix_vals(from.height, to.height, t),
+            left: mix_vals(from.left, to.left, t),
+            right: mix_vals(from.right, to.right, t),
+            top: mix_vals(from.top, to.top, t),
+            bottom: mix_vals(from.bottom, to.bottom, t),
+            ..to.clone()
+        }
+    }
+}
+
+/// Tweens component `T` of its entity whenever another system changes it.
+#[derive(Component, Debug, Clone)]
+pub struct Transition<T: Tween> {
+    pub duration: Duration,
+    pub easing: Easing,
+    /// Value written last, `None` before the first frame
+    shown: Option<T>,
+    /// Start and target of the running tween
+    tween: Option<(T, T)>,
+    elapsed: Duration,
+}
+
+impl<T: Tween> Transition<T> {
+    pub fn new(duration: Duration, easing: Easing) -> Self {
+        Self {
+            duration,
+            easing,
+            shown: None,
+            tween: None,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// A transition that applies changes at once.
+    pub fn instant() -> Self {
+        Self::new(Duration::ZERO, Easing::Linear)
+    }
+
+    /// Whether a tween is running.
+    pub fn is_running(&self) -> bool {
+        self.tween.is_some()
+    }
+
+    /// Advances the transition by `delta` given the current `value`, and
+    /// returns the value to show, if it differs from `value`.
+    pub fn step(&mut self, value: &T, delta: Duration) -> Option<T> {
+        // Some styling systems rewrite their target every frame
+        let same_target = self.tween.as_ref().is_some_and(|(_, to)| to == value);
+        if self.shown.as_ref() != Some(value) && !same_target {
+            // A styling system set a new target
+            match self.shown.take() {
+                Some(shown) if !self.duration.is_zero() => {
+                    self.tween = Some((shown, value.clone()));
+                    self.elapsed = Duration::ZERO;
+                }
+                _ => {
+                    self.tween = None;
+                    self.shown = Some(value.clone());
+                    return None;
+                }
+            }
+        }
+
+        let (from, to) = self.tween.as_ref()?;
+        self.elapsed += delta;
+        let t = (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0);
+        let next = T::interpolate(from, to, self.easing.apply(t));
+        if t >= 1.0 {
+            self.tween = None;
+        }
+        self.shown = Some(next.clone());
+        (next != *value).then_some(next)
+    }
+}
+
+/// Instant [`Transition`]s of the background and border color, or tweens
+/// over `transition`.
+pub fn color_transitions(
+    transition: Option<(Duration, Easing)>,
+) -> (Transition<BackgroundColor>, Transition<BorderColor>) {
+    match transition {
+        Some((duration, easing)) => (
+            Transition::new(duration, easing),
+            Transition::new(duration, easing),
+        ),
+        None => (Transition::instant(), Transition::instant()),
+    }
+}
+
+/// Tweens `T` toward the values styling systems wrote this frame.
+pub fn animate_transitions<T: Tween>(
+    time: Res<Time>,
+    motion: Res<UiMotion>,
+    mut query: Query<(&mut T, &mut Transition<T>)>,
+) {
+    for (mut value, mut transition) in &mut query {
+        // Reduced motion ends every tween in the frame it starts
+        let delta = if motion.reduced {
+            transition.duration
+        } else {
+            time.delta()
+        };
+        if let Some(next) = transition.step(&value, delta) {
+            *value = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_transition_is_halfway_at_half_time() {
+        let black = BackgroundColor(Color::srgb(0.0, 0.0, 0.0));
+        let white = BackgroundColor(Color::srgb(1.0, 1.0, 1.0));
+        let mut transition =
+            Transition::<BackgroundColor>::new(Duration::from_millis(200), Easing::Linear);
+
+        // The first value is shown as is
+        assert_eq!(transition.step(&black, Duration::ZERO), None);
+
+        // A styling system switches to white; half the duration passes
+        let halfway = transition.step(&white, Duration::from_millis(100)).unwrap();
+        let srgba = halfway.0.to_srgba();
+        assert!((srgba.red - 0.5).abs() < 1e-4);
+        assert!((srgba.green - 0.5).abs() < 1e-4);
+        assert!(transition.is_running());
+
+        // The tween ends on the target
+        let end = transition
+            .step(&halfway, Duration::from_millis(100))
+            .unwrap();
+        assert_eq!(end, white);
+        assert!(!transition.is_running());
+        assert_eq!(transition.step(&end, Duration::from_millis(16)), None);
+    }
+
+    #[test]
+    fn test_easing_midpoints() {
+        assert_eq!(Easing::Linear.apply(0.5), 0.5);
+        assert_eq!(Easing::EaseIn.apply(0.5), 0.25);
+        assert_eq!(Easing::EaseOut.apply(0.5), 0.75);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        for easing in [Easing::EaseIn, Easing::EaseOut, Easing::EaseInOut] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_instant_transition_keeps_values() {
+        let mut transition = Transition::<BorderColor>::instant();
+        let red = BorderColor(Color::srgb(1.0, 0.0, 0.0));
+        let blue = BorderColor(Color::srgb(0.0, 0.0, 1.0));
+        assert_eq!(transition.step(&red, Duration::ZERO), None);
+        assert_eq!(transition.step(&blue, Duration::from_millis(16)), None);
+        assert!(!transition.is_running());
+    }
+}
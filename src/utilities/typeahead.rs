@@ -0,0 +1,123 @@
+//! Jumping to list entries by typing their first letters.
+//!
+//! A [`Typeahead`] collects the characters typed in quick succession into a
+//! prefix; a pause longer than its timeout starts a new one. With
+//! [`typeahead_match`] the prefix picks the first entry whose label starts
+//! with it, as native selects and menus do. Selects keep one per dropdown;
+//! other lists and menus can hold their own:
+//!
+//! ```rust
+//! let prefix = typeahead.push("gr", time.elapsed());
+//! let labels = ["Blue", "Green", "Grey"].map(Some);
+//! assert_eq!(typeahead_match(prefix, labels), Some(1));
+//! ```
+
+use std::time::Duration;
+
+/// Pause after which typing starts a new prefix
+pub const TYPEAHEAD_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Characters typed in quick succession.
+#[derive(Debug, Clone)]
+pub struct Typeahead {
+    /// Pause after which typing starts a new prefix
+    pub timeout: Duration,
+    buffer: String,
+    /// Time of the last input, `None` before any
+    last_input: Option<Duration>,
+}
+
+impl Default for Typeahead {
+    fn default() -> Self {
+        Self::new(TYPEAHEAD_TIMEOUT)
+    }
+}
+
+impl Typeahead {
+    /// Empty typeahead whose prefix expires after `timeout` without input.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            buffer: String::new(),
+            last_input: None,
+        }
+    }
+
+    /// Whether the prefix has expired at time `now`.
+    pub fn is_expired(&self, now: Duration) -> bool {
+        self.last_input
+            .is_none_or(|last| now.saturating_sub(last) > self.timeout)
+    }
+
+    /// Adds `text` typed at time `now` and returns the prefix, which starts
+    /// over after a pause longer than the timeout.
+    pub fn push(&mut self, text: &str, now: Duration) -> &str {
+        if self.is_expired(now) {
+            self.buffer.clear();
+        }
+        self.buffer.push_str(text);
+        self.last_input = Some(now);
+        &self.buffer
+    }
+
+    /// The prefix typed so far, empty once it has expired at time `now`.
+    pub fn prefix(&self, now: Duration) -> &str {
+        if self.is_expired(now) {
+            ""
+        } else {
+            &self.buffer
+        }
+    }
+
+    /// Forgets the prefix, so the next input starts a new one.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.last_input = None;
+    }
+}
+
+/// Index of the first label starting with `prefix`, ignoring case. Entries
+/// without a label (headers, separators, …) are skipped.
+pub fn typeahead_match<'a>(
+    prefix: &str,
+    labels: impl IntoIterator<Item = Option<&'a str>>,
+) -> Option<usize> {
+    if prefix.is_empty() {
+        return None;
+    }
+    let prefix = prefix.to_lowercase();
+    labels
+        .into_iter()
+        .position(|label| label.is_some_and(|label| label.to_lowercase().starts_with(&prefix)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LABELS: [Option<&str>; 5] = [None, Some("Apple"), Some("Apricot"), None, Some("Banana")];
+
+    #[test]
+    fn test_prefix_matches_first_label() {
+        assert_eq!(typeahead_match("a", LABELS), Some(1));
+        assert_eq!(typeahead_match("apr", LABELS), Some(2));
+        assert_eq!(typeahead_match("B", LABELS), Some(4));
+        assert_eq!(typeahead_match("c", LABELS), None);
+        assert_eq!(typeahead_match("", LABELS), None);
+    }
+
+    #[test]
+    fn test_buffer_expires_after_pause() {
+        let mut typeahead = Typeahead::default();
+        let at = Duration::from_millis;
+
+        assert_eq!(typeahead.push("a", at(0)), "a");
+        assert_eq!(typeahead.push("p", at(300)), "ap");
+        assert_eq!(typeahead.push("r", at(700)), "apr");
+        assert_eq!(typeahead.prefix(at(1100)), "apr");
+
+        // More than 500ms after the last key
+        assert_eq!(typeahead.prefix(at(1201)), "");
+        assert_eq!(typeahead.push("b", at(1201)), "b");
+    }
+}